@@ -13,7 +13,7 @@ struct WebServerConfig {
     #[builder(required)]
     host: String, // Can accept &str via Into
 
-    #[builder(required)]
+    #[builder(required, impl_into = false)] // Override: numeric literals need the exact type
     port: u16,
 
     #[builder(default = String::from("web"))]
@@ -55,7 +55,7 @@ fn main() {
     // Ergonomic usage with automatic conversions
     let server_config = WebServerConfig::builder()
         .host("localhost") // &str -> String
-        .port(8080u16) // u16 literal
+        .port(8080) // Plain literal, no suffix needed
         .name("api-server") // &str -> String
         .log_file("/tmp/server.log") // &str -> PathBuf
         .allowed_origins(vec!["https://example.com".to_string()])
@@ -107,7 +107,7 @@ fn main() {
     // Show different ways to provide values
     let flexible_config = WebServerConfig::builder()
         .host("0.0.0.0".to_string()) // String -> String (also works)
-        .port(3000u16) // u16 literal
+        .port(3000) // Plain literal, no suffix needed
         .name("flexible-server") // &str -> String
         .log_file(PathBuf::from("/custom/path.log")) // PathBuf -> PathBuf (also works)
         .allowed_origins(vec!["*".to_string()])
@@ -14,14 +14,24 @@
 //! - **Generation Context** - Information needed for code generation
 //!
 
-use crate::attributes::{parse_field_attributes, FieldAttributes};
+use crate::attributes::struct_attrs::PrefixScope;
+use crate::attributes::{
+    parse_field_attributes_in_struct, FieldAttributes, SetterInputVia, StructAttributes,
+};
 use crate::utils::field_utils::{
-    resolve_effective_impl_into, resolve_setter_parameter_config, DefaultConfig, SetterConfig,
+    converted_default, extract_option_inner_type, extract_resolve_dependencies,
+    extract_vec_inner_type, from_input_converter, is_scalar_primitive_type, nested_builder_alias,
+    resolve_clone_from_ref_parameter_config, resolve_converter_into_parameter_config,
+    resolve_dyn_into_parameter_config, resolve_effective_impl_into,
+    resolve_into_iter_parameter_config, resolve_setter_parameter_config, wrap_converter,
+    DefaultConfig, SetterConfig, SetterParameterConfig,
 };
 use crate::utils::identifiers::strip_raw_identifier_prefix;
+use crate::utils::std_converters::{expand_std_converter, std_converter_name};
 use crate::validation::error_messages::ErrorMessages;
-use quote::quote;
+use quote::{quote, quote_spanned};
 use std::borrow::Cow;
+use syn::spanned::Spanned;
 use syn::{Ident, Type};
 
 /// Complete information about a struct field for builder generation.
@@ -56,9 +66,38 @@ pub struct FieldInfo {
 
     /// Parsed builder attributes for this field
     attributes: FieldAttributes,
+
+    /// Lines of the field's doc comments, propagated to its setter
+    docs: Vec<String>,
+
+    /// The field's `#[deprecated]` attribute, propagated to its setter
+    deprecated: Option<syn::Attribute>,
+
+    /// Whether setters carry generated doc comments (off under `no_docs`)
+    generate_docs: bool,
+
+    /// Whether the struct-level setter prefix skips this field (listed in
+    /// `setter_prefix(except(...))`)
+    setter_prefix_exempt: bool,
+
+    /// Whether the struct-level setter prefix only applies to optional fields
+    /// (`prefix_scope = "optional"`)
+    prefix_optional_only: bool,
+
+    /// Whether the builder stores this field's value in a `Box` (struct-level `boxed`)
+    boxed: bool,
 }
 
 impl FieldInfo {
+    /// Creates a new `FieldInfo` with default struct-level settings.
+    ///
+    /// Only used by tests; struct analysis goes through
+    /// [`FieldInfo::from_syn_field_in_struct`] so struct-level defaults apply.
+    #[cfg(test)]
+    pub fn from_syn_field(name: Ident, ty: Type, attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        Self::from_syn_field_in_struct(name, ty, attrs, &StructAttributes::default())
+    }
+
     /// Creates a new `FieldInfo` from syn field components.
     ///
     /// This is the primary constructor for `FieldInfo`, parsing attributes
@@ -69,6 +108,7 @@ impl FieldInfo {
     /// * `name` - The field's identifier
     /// * `ty` - The field's type
     /// * `attrs` - The field's attribute list for parsing
+    /// * `struct_attributes` - The parsed struct-level attributes (e.g. `all_required`)
     ///
     /// # Returns
     ///
@@ -83,13 +123,65 @@ impl FieldInfo {
     /// - Invalid attribute syntax or combinations
     /// - Conflicting field configurations
     /// - Missing required attribute values
-    pub fn from_syn_field(name: Ident, ty: Type, attrs: &[syn::Attribute]) -> syn::Result<Self> {
-        let attributes = parse_field_attributes(attrs)?;
+    pub fn from_syn_field_in_struct(
+        name: Ident,
+        ty: Type,
+        attrs: &[syn::Attribute],
+        struct_attributes: &StructAttributes,
+    ) -> syn::Result<Self> {
+        let mut attributes = parse_field_attributes_in_struct(attrs, struct_attributes)?;
+        // Built-in converters become closures, so no later stage distinguishes them
+        let std_converter = attributes
+            .converter
+            .as_ref()
+            .and_then(std_converter_name)
+            .cloned();
+        if let Some(converter_name) = std_converter {
+            let field_name = strip_raw_identifier_prefix(&name.to_string()).into_owned();
+            attributes.converter = Some(expand_std_converter(&converter_name, &field_name, &ty)?);
+        }
+        // So do wrapped newtypes, through their constructor
+        if let Some(wrap) = attributes.wrap.take() {
+            attributes.converter =
+                Some(wrap_converter(&wrap.inner, wrap.constructor.as_ref(), &ty)?);
+        }
+        // So do setter inputs converted with From; TryFrom makes the setter fallible
+        if attributes.via != Some(SetterInputVia::TryFrom) {
+            if let Some(input) = &attributes.setter_input {
+                attributes.converter = Some(from_input_converter(input, &ty));
+            }
+        }
+        // A default in the converter's input type becomes one of the field type,
+        // so the initialization, lite and fill paths need not know about it
+        if attributes.default_through_converter {
+            if let (Some(converter), Some(default)) =
+                (&attributes.converter, attributes.default_value.take())
+            {
+                attributes.default_value = Some(converted_default(converter, &default));
+            }
+        }
+        if attributes.dyn_into {
+            resolve_dyn_into_parameter_config(&ty)?;
+        }
+        let docs = extract_doc_lines(attrs);
+        let deprecated = attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("deprecated"))
+            .cloned();
+
+        let setter_prefix_exempt = struct_attributes
+            .is_setter_prefix_exempt(&strip_raw_identifier_prefix(&name.to_string()));
 
         let field_info = Self {
             name,
             ty,
             attributes,
+            docs,
+            deprecated,
+            generate_docs: !struct_attributes.get_no_docs(),
+            setter_prefix_exempt,
+            prefix_optional_only: struct_attributes.get_prefix_scope() == PrefixScope::Optional,
+            boxed: struct_attributes.get_boxed(),
         };
 
         // Validate the field configuration
@@ -98,6 +190,13 @@ impl FieldInfo {
         Ok(field_info)
     }
 
+    /// Resolves the field's `required(cfg(...))` for one side of the predicate.
+    ///
+    /// See [`FieldAttributes::apply_required_cfg`].
+    pub fn apply_required_cfg(&mut self, enabled: bool) {
+        self.attributes.apply_required_cfg(enabled);
+    }
+
     // Basic accessors
 
     /// Returns the field's name identifier.
@@ -115,6 +214,508 @@ impl FieldInfo {
         &self.attributes
     }
 
+    /// Returns the lines of the field's doc comments.
+    pub fn docs(&self) -> &[String] {
+        &self.docs
+    }
+
+    /// Returns the first non-empty line of the field's doc comments, if any.
+    pub fn doc_summary(&self) -> Option<&str> {
+        self.docs
+            .iter()
+            .map(|line| line.trim())
+            .find(|line| !line.is_empty())
+    }
+
+    /// Returns `true` if the field is marked `#[deprecated]`.
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated.is_some()
+    }
+
+    /// Generates the field's `#[deprecated]` attribute for its setter.
+    ///
+    /// The attribute is copied verbatim, so `since` and `note` are preserved
+    /// and callers configuring the field through the builder get the same
+    /// warning as code accessing the field directly.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the attribute, or empty if the field is not
+    /// deprecated.
+    pub fn setter_deprecation_tokens(&self) -> proc_macro2::TokenStream {
+        match &self.deprecated {
+            Some(attr) => quote! { #attr },
+            None => quote! {},
+        }
+    }
+
+    /// Generates the `#[cfg(...)]` attribute gating the field's setter.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the attribute, or empty if the field has no
+    /// `#[builder(cfg(...))]` predicate.
+    pub fn setter_cfg_tokens(&self) -> proc_macro2::TokenStream {
+        match &self.attributes.cfg {
+            Some(predicate) => quote! { #[cfg(#predicate)] },
+            None => quote! {},
+        }
+    }
+
+    /// Generates the field's doc comments and search aliases for its setter.
+    ///
+    /// The lines are preceded by an empty doc line so they form a separate
+    /// paragraph below the generated setter summary. The field name and every
+    /// `alias` that differ from the setter name become `#[doc(alias)]`s, so
+    /// searching for `max_conns` finds the setter `with_max_conns`.
+    ///
+    /// # Arguments
+    ///
+    /// * `setter_name` - The final setter name, possibly a raw identifier
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the attributes, or empty if the field has
+    /// no documentation and no alias is needed.
+    pub fn setter_doc_tokens(&self, setter_name: &str) -> proc_macro2::TokenStream {
+        let setter_name = strip_raw_identifier_prefix(setter_name);
+        let mut aliases: Vec<String> = Vec::new();
+        for alias in std::iter::once(self.clean_name()).chain(self.attributes.aliases.clone()) {
+            if alias != setter_name && !aliases.contains(&alias) {
+                aliases.push(alias);
+            }
+        }
+
+        let lines = self.docs();
+        let docs = if lines.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                #[doc = ""]
+                #(#[doc = #lines])*
+            }
+        };
+
+        quote! {
+            #docs
+            #(#[doc(alias = #aliases)])*
+        }
+    }
+
+    /// Generates a `#[doc]` attribute with generated setter documentation.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the attribute, or `#[allow(missing_docs)]`
+    /// under `no_docs`.
+    fn generated_doc_tokens(&self, doc: &str) -> proc_macro2::TokenStream {
+        if self.generate_docs {
+            quote! { #[doc = #doc] }
+        } else {
+            quote! { #[allow(missing_docs)] }
+        }
+    }
+
+    /// Returns the fields read by this field's `resolve` closure.
+    ///
+    /// # Returns
+    ///
+    /// The identifiers of the closure's field parameters, or an empty vector if
+    /// the field has no `#[builder(resolve = ...)]`.
+    pub fn resolve_dependencies(&self) -> Vec<Ident> {
+        self.attributes
+            .resolve
+            .as_ref()
+            .and_then(extract_resolve_dependencies)
+            .unwrap_or_default()
+    }
+
+    /// Generates the build-time statement that applies this field's `resolve` closure.
+    ///
+    /// The statement moves the field's value out of the builder and borrows
+    /// the fields the closure reads, binding the result to a local that
+    /// [`Self::build_value_tokens`] refers to.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the `let` statement, or empty if the field
+    /// has no `resolve` closure.
+    pub fn generate_resolve_statement(&self) -> proc_macro2::TokenStream {
+        let Some(resolve) = &self.attributes.resolve else {
+            return quote! {};
+        };
+
+        let stored = self.stored_value_tokens();
+        let resolved_ident = self.resolved_value_ident();
+        let dependencies = self.resolve_dependencies();
+
+        quote! {
+            let #resolved_ident = (#resolve)(#stored, #(&self.#dependencies),*);
+        }
+    }
+
+    /// Generates the build-time statement that runs this field's `default_from`
+    /// or `computed` closure.
+    ///
+    /// The statement only borrows the builder, calling a `default_from` closure
+    /// when the field was never set and a `computed` closure unconditionally,
+    /// and binds the result to a local that [`Self::build_value_tokens`] refers
+    /// to. These statements run before any `resolve` statement moves a field
+    /// out of the builder.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the `let` statement, or empty if the field
+    /// has neither closure.
+    pub fn generate_computed_statement(&self) -> proc_macro2::TokenStream {
+        let field_name = self.name();
+        let field_type = self.field_type();
+        let computed_ident = self.computed_value_ident();
+
+        if let Some(computed) = &self.attributes.computed {
+            quote! {
+                let #computed_ident = {
+                    let computed: fn(&Self) -> #field_type = #computed;
+                    computed(&self)
+                };
+            }
+        } else if let Some(default_from) = &self.attributes.default_from {
+            quote! {
+                let #computed_ident = self.#field_name.is_none().then(|| {
+                    let default_from: fn(&Self) -> #field_type = #default_from;
+                    default_from(&self)
+                });
+            }
+        } else {
+            quote! {}
+        }
+    }
+
+    /// Generates the build-time statements that check this field's value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The local holding the built struct
+    /// * `error_ident` - The struct's validation error type
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the checks of [`Self::generate_value_checks`]
+    /// on the built struct's field, or empty if the field's value is not
+    /// checked or its checks run in the setter under `validate_on_set`.
+    pub fn generate_validation_statement(
+        &self,
+        value: &Ident,
+        error_ident: &Ident,
+    ) -> proc_macro2::TokenStream {
+        if self.attributes.validate_on_set {
+            return quote! {};
+        }
+        let field_name = self.name();
+        self.generate_value_checks(&quote! { #value.#field_name }, error_ident)
+    }
+
+    /// Generates the statements that check a value of this field.
+    ///
+    /// The statements run the field's `non_empty`, `range` and `validate`
+    /// checks in that order and return the validation error, naming the
+    /// field, for the first one that rejects the value. The error records
+    /// `Location::caller()`, so the method running the checks is
+    /// `#[track_caller]` to report where it was called.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - An expression of the field's type to check
+    /// * `error_ident` - The struct's validation error type
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the checks, or empty if the field's value is
+    /// not checked.
+    pub fn generate_value_checks(
+        &self,
+        value: &proc_macro2::TokenStream,
+        error_ident: &Ident,
+    ) -> proc_macro2::TokenStream {
+        let field_type = self.field_type();
+        let name = self.clean_name();
+        let custom_message = self
+            .attributes
+            .validate_message
+            .as_ref()
+            .map(syn::LitStr::value);
+        let check = |condition: proc_macro2::TokenStream, message: String| {
+            let message = custom_message.clone().unwrap_or(message);
+            quote! {
+                if !(#condition) {
+                    return ::core::result::Result::Err(#error_ident {
+                        field: #name,
+                        message: #message,
+                        location: ::core::panic::Location::caller(),
+                    });
+                }
+            }
+        };
+
+        let mut checks = proc_macro2::TokenStream::new();
+        if self.attributes.non_empty {
+            checks.extend(check(
+                quote! { !#value.is_empty() },
+                "must not be empty".to_string(),
+            ));
+        }
+        if let Some(range) = &self.attributes.range {
+            // `1 ..= 100` reads better in the message as `1..=100`
+            let description = quote! { #range }.to_string().replace(' ', "");
+            checks.extend(check(
+                quote! { (#range).contains(&#value) },
+                format!("must be in range {description}"),
+            ));
+        }
+        if let Some(validate) = &self.attributes.validate {
+            let condition = check(
+                quote! { validate(&#value) },
+                "validation failed".to_string(),
+            );
+            checks.extend(quote! {
+                let validate: fn(&#field_type) -> bool = #validate;
+                #condition
+            });
+        }
+        checks
+    }
+
+    /// Generates the expression holding this field's final value in the build method.
+    ///
+    /// # Returns
+    ///
+    /// The local bound by [`Self::generate_resolve_statement`] for resolved
+    /// fields, the value taken out of its `Zeroizing` wrapper for sensitive
+    /// fields, the stored value or the result of the deferred closure for
+    /// `lazy_setter` fields, the set value or the computed default for
+    /// `default_from` fields, the local bound by [`Self::generate_computed_statement`]
+    /// for `computed` fields, the set value or the `__base` value for fields
+    /// taken from the struct-level `base`, the set or filled value or the
+    /// default for fields tracked for `fill_from`, otherwise `self.field`.
+    pub fn build_value_tokens(&self) -> proc_macro2::TokenStream {
+        if self.attributes.resolve.is_some() {
+            let resolved_ident = self.resolved_value_ident();
+            quote! { #resolved_ident }
+        } else if self.attributes.computed.is_some() {
+            let computed_ident = self.computed_value_ident();
+            quote! { #computed_ident }
+        } else if self.attributes.default_from.is_some() {
+            let stored = self.stored_value_tokens();
+            let default_ident = self.computed_value_ident();
+            quote! {
+                match (#stored, #default_ident) {
+                    (::core::option::Option::Some(value), _)
+                    | (::core::option::Option::None, ::core::option::Option::Some(value)) => value,
+                    (::core::option::Option::None, ::core::option::Option::None) => {
+                        ::core::unreachable!()
+                    }
+                }
+            }
+        } else if self.attributes.from_base {
+            let field_name = self.name();
+            let stored = self.stored_value_tokens();
+            quote! {
+                match #stored {
+                    ::core::option::Option::Some(value) => value,
+                    ::core::option::Option::None => __base.#field_name,
+                }
+            }
+        } else if self.attributes.fill_from {
+            let stored = self.stored_value_tokens();
+            let default_value = self.default_value_tokens();
+            quote! {
+                match #stored {
+                    ::core::option::Option::Some(value) => value,
+                    ::core::option::Option::None => #default_value,
+                }
+            }
+        } else if self.attributes.lazy_setter {
+            let stored = self.stored_value_tokens();
+            quote! {
+                match #stored {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(deferred) => deferred(),
+                }
+            }
+        } else if self.attributes.sensitive {
+            // The emptied wrapper is wiped again when it goes out of scope
+            let stored = self.stored_value_tokens();
+            quote! {
+                {
+                    let mut __sensitive = #stored;
+                    ::core::mem::take(&mut *__sensitive)
+                }
+            }
+        } else {
+            self.stored_value_tokens()
+        }
+    }
+
+    /// Generates the `fill_from` statement copying this field from the fallback.
+    ///
+    /// # Arguments
+    ///
+    /// * `fallback` - The parameter holding a reference to the fallback instance
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` cloning the fallback's value into the builder if the
+    /// field is still unset, or empty if the builder does not track whether
+    /// the field was set or the field is not part of the struct.
+    pub fn generate_fill_from_statement(&self, fallback: &Ident) -> proc_macro2::TokenStream {
+        if !self.attributes.tracks_unset() || self.attributes.builder_only {
+            return quote! {};
+        }
+
+        let field_name = self.name();
+        let stored = self.stored_value_tokens();
+        quote! {
+            if #stored.is_none() {
+                #stored = ::core::option::Option::Some(
+                    ::core::clone::Clone::clone(&#fallback.#field_name)
+                );
+            }
+        }
+    }
+
+    /// Generates the expression moving this field's stored value out of the builder.
+    ///
+    /// # Returns
+    ///
+    /// `*self.field` for fields the builder keeps in a `Box`, otherwise `self.field`.
+    fn stored_value_tokens(&self) -> proc_macro2::TokenStream {
+        let field_name = self.name();
+        if self.is_boxed() {
+            quote! { (*self.#field_name) }
+        } else {
+            quote! { self.#field_name }
+        }
+    }
+
+    /// Generates the type the builder stores this field's value as once it is set.
+    ///
+    /// # Returns
+    ///
+    /// `::zeroize::Zeroizing<T>` for `#[builder(sensitive)]` fields, a value or
+    /// deferred closure for `#[builder(lazy_setter)]` fields, `Option<T>` for
+    /// `#[builder(default_from)]` fields and fields taken from the struct-level
+    /// `base` or tracked for `fill_from`, `PhantomData<T>` for `#[builder(computed)]`
+    /// fields (which hold no value), otherwise the field type itself. Under
+    /// the struct-level `boxed` the type is wrapped in a `Box`.
+    pub fn builder_storage_type(&self) -> proc_macro2::TokenStream {
+        let storage_type = self.unboxed_storage_type();
+        if self.is_boxed() {
+            quote! { Box<#storage_type> }
+        } else {
+            storage_type
+        }
+    }
+
+    /// Generates the type the builder stores this field as before it is set.
+    ///
+    /// # Returns
+    ///
+    /// `Option<Box<T>>` under the struct-level `boxed`, otherwise `Option<T>`.
+    pub fn unset_storage_type(&self) -> proc_macro2::TokenStream {
+        let field_type = self.field_type();
+        if self.is_boxed() {
+            quote! { ::core::option::Option<Box<#field_type>> }
+        } else {
+            quote! { ::core::option::Option<#field_type> }
+        }
+    }
+
+    /// Generates the storage type of [`Self::builder_storage_type`] without the `boxed` wrapper.
+    fn unboxed_storage_type(&self) -> proc_macro2::TokenStream {
+        let field_type = self.field_type();
+        if self.attributes.sensitive {
+            quote! { ::zeroize::Zeroizing<#field_type> }
+        } else if self.attributes.lazy_setter {
+            // `Box` comes from the prelude (or an `alloc` import in no_std crates)
+            quote! {
+                ::core::result::Result<#field_type, Box<dyn ::core::ops::FnOnce() -> #field_type>>
+            }
+        } else if self.attributes.tracks_unset() {
+            quote! { ::core::option::Option<#field_type> }
+        } else if self.attributes.computed.is_some() {
+            quote! { ::core::marker::PhantomData<#field_type> }
+        } else {
+            quote! { #field_type }
+        }
+    }
+
+    /// Wraps a value of the field type for storage in the builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Expression producing a value of the field type
+    ///
+    /// # Returns
+    ///
+    /// The expression wrapped in `::zeroize::Zeroizing::new` for sensitive
+    /// fields, in `Ok` for `lazy_setter` fields, in `Some` for `default_from`
+    /// fields and fields taken from the struct-level `base`, otherwise the
+    /// expression unchanged. Under the struct-level `boxed` the result is
+    /// moved into a `Box`.
+    pub fn wrap_builder_value(&self, value: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let value = if self.attributes.sensitive {
+            quote! { ::zeroize::Zeroizing::new(#value) }
+        } else if self.attributes.lazy_setter {
+            quote! { ::core::result::Result::Ok(#value) }
+        } else if self.attributes.tracks_unset() {
+            quote! { ::core::option::Option::Some(#value) }
+        } else {
+            quote! { #value }
+        };
+        self.box_builder_value(value)
+    }
+
+    /// The builder value deferring to `closure` for `lazy_setter` fields.
+    pub fn lazy_builder_value(
+        &self,
+        closure: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        self.box_builder_value(quote! { ::core::result::Result::Err(Box::new(#closure)) })
+    }
+
+    /// Moves a stored value into a `Box` for fields the builder keeps on the heap.
+    fn box_builder_value(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        if self.is_boxed() {
+            quote! { Box::new(#value) }
+        } else {
+            value
+        }
+    }
+
+    /// Returns `true` if the builder keeps this field's value in a `Box`.
+    ///
+    /// Fields are boxed under the struct-level `boxed` or their own
+    /// `boxed_field`. `computed` fields hold no value, so they are never boxed.
+    pub fn is_boxed(&self) -> bool {
+        (self.boxed || self.attributes.boxed_field) && self.attributes.computed.is_none()
+    }
+
+    /// Returns the local variable name for this field's computed value or default.
+    fn computed_value_ident(&self) -> Ident {
+        Ident::new(
+            &format!("__computed_{}", self.clean_name()),
+            proc_macro2::Span::call_site(),
+        )
+    }
+
+    /// Returns the local variable name for this field's resolved value.
+    fn resolved_value_ident(&self) -> Ident {
+        Ident::new(
+            &format!("__resolved_{}", self.clean_name()),
+            proc_macro2::Span::call_site(),
+        )
+    }
+
     // Classification methods
 
     /// Returns `true` if this field is required.
@@ -146,33 +747,274 @@ impl FieldInfo {
             name,
             ty,
             attributes,
+            docs: Vec::new(),
+            deprecated: None,
+            generate_docs: true,
+            setter_prefix_exempt: false,
+            prefix_optional_only: false,
+            boxed: false,
+        }
+    }
+
+    /// Checks if a setter method should be generated for this field.
+    ///
+    /// Fields with the `skip_setter` attribute don't get setter methods
+    /// generated and are only initialized with their default values, and
+    /// `computed` fields are always set by the build method.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a setter should be generated, `false` if it should be skipped.
+    ///
+    pub fn should_generate_setter(&self) -> bool {
+        !self.attributes.skip_setter && self.attributes.computed.is_none()
+    }
+
+    /// Checks if this field's setter accepts `impl IntoIterator<Item = impl Into<T>>`.
+    ///
+    /// The field-level `into_iter` setting overrides the struct-level one, and
+    /// only `Vec<T>` fields are affected.
+    ///
+    /// # Arguments
+    ///
+    /// * `struct_into_iter` - Whether struct-level into_iter is enabled
+    pub fn uses_into_iter(&self, struct_into_iter: bool) -> bool {
+        self.attributes.into_iter.unwrap_or(struct_into_iter)
+            && self.attributes.converter.is_none()
+            && !self.attributes.transparent
+            && extract_vec_inner_type(&self.ty).is_some()
+    }
+
+    /// Returns the setter parameter configuration of a converter chained with `impl_into`.
+    ///
+    /// Only a field-level `impl_into` chains with the field's converter; the
+    /// struct-level setting leaves converter setters unchanged.
+    ///
+    /// # Returns
+    ///
+    /// The `impl Into<I>` configuration, or `None` if the field has no
+    /// converter or no field-level `impl_into`.
+    pub fn converter_into_parameter_config(&self) -> Option<SetterParameterConfig> {
+        let converter = self.attributes.converter.as_ref()?;
+        if self.attributes.impl_into != Some(true) {
+            return None;
+        }
+        resolve_converter_into_parameter_config(converter)
+    }
+
+    /// Gets the parameter configuration of a `clone_from_ref` setter.
+    ///
+    /// # Returns
+    ///
+    /// The borrowed parameter configuration, or `None` if the field does not
+    /// use `clone_from_ref`.
+    pub fn clone_from_ref_parameter_config(&self) -> Option<SetterParameterConfig> {
+        self.attributes
+            .clone_from_ref
+            .then(|| resolve_clone_from_ref_parameter_config(&self.ty))
+    }
+
+    /// Gets the parameter configuration of a `dyn_into` setter.
+    ///
+    /// # Returns
+    ///
+    /// The `impl Trait` configuration, or `None` if the field does not use
+    /// `dyn_into`. The field type was checked when the field was analyzed.
+    pub fn dyn_into_parameter_config(&self) -> Option<SetterParameterConfig> {
+        if !self.attributes.dyn_into {
+            return None;
+        }
+        resolve_dyn_into_parameter_config(&self.ty).ok()
+    }
+
+    /// Checks if struct-level `option_into` applies to this field's setter.
+    ///
+    /// Only `Option<T>` fields without a converter are affected; their setter
+    /// then behaves as with `impl_into`, accepting `impl Into<Option<T>>`.
+    ///
+    /// # Arguments
+    ///
+    /// * `struct_option_into` - Whether struct-level option_into is enabled
+    pub fn uses_option_into(&self, struct_option_into: bool) -> bool {
+        struct_option_into
+            && self.attributes.converter.is_none()
+            && !self.attributes.transparent
+            && extract_option_inner_type(&self.ty).is_some()
+    }
+
+    /// Gets the parameter configuration of this field's regular, non-const setter.
+    ///
+    /// `setter_input`, `dyn_into` and `clone_from_ref` take precedence over
+    /// `into_iter`, which takes precedence over `impl_into`.
+    ///
+    /// # Arguments
+    ///
+    /// * `use_impl_into` - Whether the setter takes `impl Into<T>`, as resolved
+    ///   from the field-level, struct-level and `option_into` settings
+    /// * `struct_into_iter` - Whether struct-level into_iter is enabled
+    ///
+    /// # Returns
+    ///
+    /// The parameter type and the expression turning it into the field value.
+    pub fn setter_parameter_config(
+        &self,
+        use_impl_into: bool,
+        struct_into_iter: bool,
+    ) -> SetterParameterConfig {
+        let field_type = self.field_type();
+        self.try_from_parameter_config()
+            .or_else(|| self.dyn_into_parameter_config())
+            .or_else(|| self.clone_from_ref_parameter_config())
+            .or_else(|| {
+                self.uses_into_iter(struct_into_iter)
+                    .then(|| resolve_into_iter_parameter_config(field_type))
+                    .flatten()
+            })
+            .or_else(|| self.converter_into_parameter_config())
+            .unwrap_or_else(|| {
+                resolve_setter_parameter_config(
+                    field_type,
+                    self.attributes.converter.as_ref(),
+                    use_impl_into,
+                )
+            })
+    }
+
+    /// Gets the parameter configuration of a setter that keeps its argument
+    /// for the regular setter, as the dynamic builder's setters do.
+    ///
+    /// The parameter is the regular setter's, see [`Self::setter_parameter_config`],
+    /// and the expression turns it into [`Self::setter_input_type`]: the
+    /// argument itself for `setter_input` and converter setters, otherwise the
+    /// field value the regular setter would store. The regular setter accepts
+    /// that value in turn, or a reference to it for `clone_from_ref`.
+    ///
+    /// # Arguments
+    ///
+    /// * `use_impl_into` - Whether the setter takes `impl Into<T>`
+    /// * `struct_into_iter` - Whether struct-level into_iter is enabled
+    ///
+    /// # Returns
+    ///
+    /// The parameter type and the expression producing the value to keep.
+    pub fn stored_input_parameter_config(
+        &self,
+        use_impl_into: bool,
+        struct_into_iter: bool,
+    ) -> SetterParameterConfig {
+        let input_type = self.setter_input_type();
+        if self.converter_into_parameter_config().is_some() {
+            return SetterParameterConfig {
+                param_type: quote! { impl ::core::convert::Into<#input_type> },
+                field_assignment_expr: quote! { ::core::convert::Into::into(value) },
+            };
+        }
+        if self.try_from_input().is_some() || self.attributes.converter.is_some() {
+            return SetterParameterConfig {
+                param_type: input_type,
+                field_assignment_expr: quote! { value },
+            };
+        }
+        self.setter_parameter_config(use_impl_into, struct_into_iter)
+    }
+
+    /// Returns the type this field's setter is called with.
+    ///
+    /// This is the converter's parameter type for fields with a `converter`,
+    /// the closure's input type for `accumulate` fields, and the field type
+    /// otherwise, which every other setter accepts.
+    pub fn setter_input_type(&self) -> proc_macro2::TokenStream {
+        use crate::utils::field_utils::{extract_accumulator_parameter_type, extract_closure_info};
+
+        if let Some(input_type) = self.try_from_input() {
+            return quote! { #input_type };
+        }
+        if let Some(input_type) = self
+            .attributes
+            .accumulate
+            .as_ref()
+            .and_then(extract_accumulator_parameter_type)
+        {
+            return input_type;
+        }
+        match self
+            .attributes
+            .converter
+            .as_ref()
+            .and_then(extract_closure_info)
+        {
+            Some(closure_info) => closure_info.param_type,
+            None => {
+                let field_type = self.field_type();
+                quote! { #field_type }
+            }
+        }
+    }
+
+    /// Returns the `setter_input` type of a field whose setter converts with `TryFrom`.
+    ///
+    /// Inputs converted with `From` are turned into a `converter` when the
+    /// field is analyzed, so the setter only handles `via = TryFrom` inputs.
+    pub fn try_from_input(&self) -> Option<&Type> {
+        if self.attributes.via == Some(SetterInputVia::TryFrom) {
+            self.attributes.setter_input.as_ref()
+        } else {
+            None
         }
     }
 
-    /// Checks if this field is optional.
+    /// Gets the parameter configuration of a setter converting with `TryFrom`.
     ///
-    /// Optional fields have default values and don't need to be explicitly
-    /// set before calling build().
+    /// The assignment expression uses `?`, so it belongs in a setter that
+    /// returns [`Self::try_from_error_type`].
     ///
     /// # Returns
     ///
-    /// `true` if the field is optional, `false` if it's required.
-    ///
-    pub fn is_optional(&self) -> bool {
-        !self.is_required()
+    /// The `setter_input` configuration, or `None` if the setter does not
+    /// convert with `TryFrom`.
+    pub fn try_from_parameter_config(&self) -> Option<SetterParameterConfig> {
+        let input_type = self.try_from_input()?;
+        let field_type = self.field_type();
+        Some(SetterParameterConfig {
+            param_type: quote! { #input_type },
+            field_assignment_expr: quote! {
+                <#field_type as ::core::convert::TryFrom<#input_type>>::try_from(value)?
+            },
+        })
     }
 
-    /// Checks if a setter method should be generated for this field.
+    /// Gets the error type returned by a setter converting with `TryFrom`.
     ///
-    /// Fields with the `skip_setter` attribute don't get setter methods
-    /// generated and are only initialized with their default values.
+    /// # Returns
+    ///
+    /// `<FieldType as TryFrom<Input>>::Error`, or `None` if the setter does not
+    /// convert with `TryFrom`.
+    pub fn try_from_error_type(&self) -> Option<proc_macro2::TokenStream> {
+        let input_type = self.try_from_input()?;
+        let field_type = self.field_type();
+        Some(quote! { <#field_type as ::core::convert::TryFrom<#input_type>>::Error })
+    }
+
+    /// Generates the `#[track_caller]` attribute of a setter running the field's checks.
     ///
     /// # Returns
     ///
-    /// `true` if a setter should be generated, `false` if it should be skipped.
+    /// `#[track_caller]` under `validate_on_set`, so the validation error
+    /// records where the setter was called, otherwise empty.
+    pub fn setter_track_caller_tokens(&self) -> proc_macro2::TokenStream {
+        if self.attributes.validate_on_set {
+            quote! { #[track_caller] }
+        } else {
+            quote! {}
+        }
+    }
+
+    /// Returns `true` if the setter returns a `Result`.
     ///
-    pub fn should_generate_setter(&self) -> bool {
-        !self.attributes.skip_setter
+    /// Setters fail when they run the field's checks under `validate_on_set`
+    /// or convert their argument with `TryFrom`.
+    pub fn has_fallible_setter(&self) -> bool {
+        self.attributes.validate_on_set || self.try_from_input().is_some()
     }
 
     /// Returns `true` if this field has a custom default value.
@@ -209,9 +1051,13 @@ impl FieldInfo {
     ///
     /// This method applies the setter prefix precedence rules:
     /// 1. Field-level setter_prefix takes highest priority
-    /// 2. Struct-level setter_prefix takes second priority  
+    /// 2. Struct-level setter_prefix takes second priority, unless the field
+    ///    is listed in `setter_prefix(except(...))`
     /// 3. No prefix (original name) is the fallback
     ///
+    /// A prefix is joined to the name without its `r#`, so the raw field
+    /// `r#type` with the prefix `with_` gets the setter `with_type`.
+    ///
     /// # Arguments
     ///
     /// * `struct_setter_prefix` - Optional struct-level setter prefix from struct attributes
@@ -224,16 +1070,20 @@ impl FieldInfo {
         // Get base setter name (field name or custom setter_name)
         let base_name = self.setter_name();
 
-        // Apply setter prefix with proper priority
-        if let Some(field_prefix) = &self.attributes().setter_prefix {
-            // Field-level setter_prefix wins over everything
-            format!("{field_prefix}{base_name}")
-        } else if let Some(struct_prefix) = struct_setter_prefix {
-            // Struct-level setter_prefix applies if no field-level prefix
-            format!("{struct_prefix}{base_name}")
-        } else {
-            // No prefix - use the base name
-            base_name
+        // Field-level setter_prefix wins over the struct-level one. Whether the
+        // field is required is only known once `required(cfg(...))` is resolved
+        let struct_prefix_skipped =
+            self.setter_prefix_exempt || (self.prefix_optional_only && self.is_required());
+        let prefix = self
+            .attributes()
+            .setter_prefix
+            .as_deref()
+            .or_else(|| struct_setter_prefix.filter(|_| !struct_prefix_skipped));
+
+        match prefix {
+            Some(prefix) => format!("{prefix}{}", strip_raw_identifier_prefix(&base_name)),
+            // No prefix - use the base name, which may be a raw identifier
+            None => base_name,
         }
     }
 
@@ -264,6 +1114,10 @@ impl FieldInfo {
         // Generate documentation comment
         let doc_comment = if self.attributes().required {
             format!("Sets the required field `{clean_name}`.")
+        } else if self.attributes().accumulate.is_some() {
+            format!(
+                "Adds a value to the optional field `{clean_name}` with its `accumulate` closure."
+            )
         } else {
             format!("Sets the optional field `{clean_name}`.")
         };
@@ -272,7 +1126,7 @@ impl FieldInfo {
             setter_name,
             _field_name: Cow::Owned(field_name_str.clone()),
             _clean_name: clean_name.into_owned().into(),
-            skip_setter: self.attributes().skip_setter,
+            skip_setter: !self.should_generate_setter(),
             doc_comment,
         }
     }
@@ -338,42 +1192,72 @@ impl FieldInfo {
         let field_name = self.name();
 
         if is_required_unset {
-            // Required field in unset state - initialize as None
+            // Required field in unset state
             Ok(quote! {
                 #field_name: ::core::option::Option::None,
             })
+        } else if self.attributes.tracks_unset() {
+            // Default resolved at build time
+            let value = self.box_builder_value(quote! { ::core::option::Option::None });
+            Ok(quote! {
+                #field_name: #value,
+            })
+        } else if self.attributes.computed.is_some() {
+            // Computed fields hold no value until the build method runs
+            Ok(quote! {
+                #field_name: ::core::marker::PhantomData,
+            })
         } else {
             // Optional field or required field in set state
-            let default_config = self.create_default_config();
-
-            if let Some(default_expr) = default_config.default_expression {
-                // Use custom default value
-                Ok(quote! {
-                    #field_name: #default_expr,
-                })
-            } else {
-                // Use Default::default() with helpful error message
-                let field_name_str = self.name().to_string();
-                let clean_name = strip_raw_identifier_prefix(&field_name_str);
-                let field_type = self.field_type();
-
-                let helpful_message = format!(
-                    "Field `{}` does not have a custom default and its type may not implement `Default`. \
-                    Solutions: \
-                    1. Add `#[builder(default = \"...\")]` with a custom default value, \
-                    2. Ensure the field type implements `Default`, or \
-                    3. Add `#[derive(Default)]` to your custom types.",
-                    &*clean_name
-                );
+            let value = self.wrap_builder_value(&self.default_value_tokens());
+            Ok(quote! {
+                #field_name: #value,
+            })
+        }
+    }
 
-                Ok(quote! {
-                    #field_name: {
-                        // This will generate a helpful error if Default is not implemented
-                        #[allow(unused)]
-                        const _HELP: &str = #helpful_message;
-                        <#field_type as ::core::default::Default>::default()
-                    },
-                })
+    /// Generates the expression producing this field's default value.
+    ///
+    /// # Returns
+    ///
+    /// The custom `default` expression bound to a local of the field type, or
+    /// `Default::default()` with a helpful message for types that do not
+    /// implement `Default`.
+    fn default_value_tokens(&self) -> proc_macro2::TokenStream {
+        let default_config = self.create_default_config();
+
+        if let Some(default_expr) = default_config.default_expression {
+            // The binding is spanned to the expression, so a wrong-typed default
+            // is reported once at the attribute rather than in every builder state
+            let field_type = self.field_type();
+            quote_spanned! {default_expr.span()=>
+                {
+                    let __default: #field_type = #default_expr;
+                    __default
+                }
+            }
+        } else {
+            // Use Default::default() with helpful error message
+            let field_name_str = self.name().to_string();
+            let clean_name = strip_raw_identifier_prefix(&field_name_str);
+            let field_type = self.field_type();
+
+            let helpful_message = format!(
+                "Field `{}` does not have a custom default and its type may not implement `Default`. \
+                Solutions: \
+                1. Add `#[builder(default = <value>)]` with a custom default value, \
+                2. Ensure the field type implements `Default`, or \
+                3. Add `#[derive(Default)]` to your custom types.",
+                &*clean_name
+            );
+
+            quote! {
+                {
+                    // This will generate a helpful error if Default is not implemented
+                    #[allow(unused)]
+                    const _HELP: &str = #helpful_message;
+                    <#field_type as ::core::default::Default>::default()
+                }
             }
         }
     }
@@ -388,6 +1272,7 @@ impl FieldInfo {
     /// * `return_type` - The type that the setter method should return
     /// * `struct_setter_prefix` - Optional struct-level setter prefix from struct attributes
     /// * `struct_impl_into` - Whether struct-level impl_into is enabled
+    /// * `struct_into_iter` - Whether struct-level into_iter is enabled
     /// * `is_const` - Whether to generate const-compatible methods
     ///
     /// # Returns
@@ -406,9 +1291,14 @@ impl FieldInfo {
         return_type: &Type,
         struct_setter_prefix: Option<&str>,
         struct_impl_into: bool,
+        struct_into_iter: bool,
         is_const: bool,
+        error_ident: &Ident,
     ) -> syn::Result<proc_macro2::TokenStream> {
-        use crate::utils::field_utils::{extract_closure_info, generate_const_converter_fn_name};
+        use crate::utils::field_utils::{
+            extract_accumulator_parameter_type, extract_closure_info,
+            generate_const_converter_fn_name, type_first_closure_parameter,
+        };
 
         let config = self.create_setter_config(struct_setter_prefix);
 
@@ -438,7 +1328,13 @@ impl FieldInfo {
             syn::parse_str::<Ident>(&config.setter_name)?
         };
 
-        let doc_comment = &config.doc_comment;
+        let doc_comment = self.generated_doc_tokens(&config.doc_comment);
+        let field_docs = self.setter_doc_tokens(&config.setter_name);
+        let deprecation = self.setter_deprecation_tokens();
+        let cfg = self.setter_cfg_tokens();
+        let nested_setter =
+            self.generate_nested_setter_method(&setter_ident, &quote! { #return_type })?;
+        let nested_each = self.generate_nested_each_method(&quote! { #return_type })?;
 
         // Handle const builders with converters specially
         if is_const {
@@ -453,15 +1349,22 @@ impl FieldInfo {
                     // Const-compatible pattern with generated const fn
                     // Note: const fn is generated as associated fn, must call with Self::
                     return Ok(quote! {
+                        #cfg
                         #[doc(hidden)]
                         const fn #const_fn_name(#param_name: #param_type) -> #field_type {
                             #body
                         }
 
-                        #[doc = #doc_comment]
+                        #doc_comment
+                        #field_docs
+                        #deprecation
+                        #cfg
                         pub const fn #setter_ident(self, value: #param_type) -> #return_type {
                             Self { #field_name: Self::#const_fn_name(value), ..self }
                         }
+
+                        #nested_setter
+                        #nested_each
                     });
                 }
             }
@@ -472,28 +1375,391 @@ impl FieldInfo {
             let field_assignment_expr = param_config.field_assignment_expr;
 
             Ok(quote! {
-                #[doc = #doc_comment]
+                #doc_comment
+                #field_docs
+                #deprecation
+                #cfg
                 pub const fn #setter_ident(self, value: #param_type) -> #return_type {
                     Self { #field_name: #field_assignment_expr, ..self }
                 }
+
+                #nested_setter
+                #nested_each
+            })
+        } else if let Some(accumulate) = &self.attributes().accumulate {
+            // Accumulating setters extend the value the builder already holds
+            let param_type = extract_accumulator_parameter_type(accumulate).unwrap_or_else(
+                || quote! { /* Error: Unable to parse accumulate parameter type */ },
+            );
+            let accumulate =
+                type_first_closure_parameter(accumulate, &syn::parse_quote! { &mut #field_type });
+            let accumulated = if self.is_boxed() {
+                quote! { *self.#field_name }
+            } else {
+                quote! { self.#field_name }
+            };
+
+            Ok(quote! {
+                #doc_comment
+                #field_docs
+                #deprecation
+                #cfg
+                pub fn #setter_ident(mut self, value: #param_type) -> #return_type {
+                    (#accumulate)(&mut #accumulated, value);
+                    self
+                }
+
+                #nested_each
             })
         } else {
             // Regular (non-const) pattern
-            let param_config =
-                resolve_setter_parameter_config(field_type, converter, use_impl_into);
+            let param_config = self.setter_parameter_config(use_impl_into, struct_into_iter);
             let param_type = param_config.param_type;
-            let field_assignment_expr = param_config.field_assignment_expr;
+
+            if self.has_fallible_setter() {
+                // Validating and TryFrom setters check the converted value before storing it
+                let conversion = param_config.field_assignment_expr;
+                let (error_type, checks, errors_doc) = match self.try_from_error_type() {
+                    Some(error_type) => (
+                        error_type,
+                        quote! {},
+                        "\n# Errors\n\nReturns the conversion error if the value cannot be converted.",
+                    ),
+                    None => (
+                        quote! { #error_ident },
+                        self.generate_value_checks(&quote! { value }, error_ident),
+                        "\n# Errors\n\nReturns the validation error if the field's checks reject the value.",
+                    ),
+                };
+                let stored_value = self.wrap_builder_value(&quote! { value });
+                let errors_doc = self.generated_doc_tokens(errors_doc);
+                let track_caller = self.setter_track_caller_tokens();
+                return Ok(quote! {
+                    #doc_comment
+                    #field_docs
+                    #errors_doc
+                    #deprecation
+                    #cfg
+                    #track_caller
+                    pub fn #setter_ident(mut self, value: #param_type)
+                        -> ::core::result::Result<#return_type, #error_type>
+                    {
+                        let value: #field_type = #conversion;
+                        #checks
+                        self.#field_name = #stored_value;
+                        ::core::result::Result::Ok(self)
+                    }
+                });
+            }
+
+            let field_assignment_expr =
+                self.wrap_builder_value(&param_config.field_assignment_expr);
+
+            let lazy_value = self.lazy_builder_value(&quote! { value });
+            let lazy_setter = self.generate_lazy_setter_method(
+                &setter_ident,
+                &quote! { #return_type },
+                &quote! {
+                    let mut builder = self;
+                    builder.#field_name = #lazy_value;
+                    builder
+                },
+            )?;
+            let try_setter =
+                self.generate_try_setter_method(&setter_ident, &quote! { #return_type })?;
+            let ref_setter =
+                self.generate_ref_setter_method(&setter_ident, &quote! { #return_type })?;
 
             Ok(quote! {
-                #[doc = #doc_comment]
+                #doc_comment
+                #field_docs
+                #deprecation
+                #cfg
                 pub fn #setter_ident(mut self, value: #param_type) -> #return_type {
                     self.#field_name = #field_assignment_expr;
                     self
                 }
+
+                #nested_setter
+                #nested_each
+                #lazy_setter
+                #try_setter
+                #ref_setter
             })
         }
     }
 
+    /// Generates the `{setter}_with` method for a `#[builder(nested)]` field.
+    ///
+    /// The method hands a fresh builder of the field's type to a closure and
+    /// passes the built value to the field's regular setter, so it returns
+    /// whatever that setter returns.
+    ///
+    /// # Arguments
+    ///
+    /// * `setter_ident` - The field's regular setter method
+    /// * `return_type` - The return type of the regular setter
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<proc_macro2::TokenStream>` containing the method, or empty
+    /// tokens if the field is not nested.
+    pub fn generate_nested_setter_method(
+        &self,
+        setter_ident: &Ident,
+        return_type: &proc_macro2::TokenStream,
+    ) -> syn::Result<proc_macro2::TokenStream> {
+        if !self.attributes.nested {
+            return Ok(quote! {});
+        }
+
+        let field_type = self.field_type();
+        let (Some(start_type), Some(ready_type)) = (
+            nested_builder_alias(field_type, "BuilderStart"),
+            nested_builder_alias(field_type, "BuilderReady"),
+        ) else {
+            return Err(syn::Error::new_spanned(
+                field_type,
+                "#[builder(nested)] requires a struct type deriving TypeStateBuilder",
+            ));
+        };
+
+        let setter_name = strip_raw_identifier_prefix(&setter_ident.to_string()).into_owned();
+        let with_ident = syn::parse_str::<Ident>(&format!("{setter_name}_with"))?;
+        let doc = self.generated_doc_tokens(&format!(
+            "Sets the field `{}` by configuring a new builder in a closure.",
+            self.clean_name()
+        ));
+        let deprecation = self.setter_deprecation_tokens();
+        let cfg = self.setter_cfg_tokens();
+
+        Ok(quote! {
+            #doc
+            #deprecation
+            #cfg
+            pub fn #with_ident<__F>(self, configure: __F) -> #return_type
+            where
+                __F: ::core::ops::FnOnce(#start_type) -> #ready_type,
+            {
+                self.#setter_ident(<#field_type>::__build_nested(configure))
+            }
+        })
+    }
+
+    /// Generates the element method of a `#[builder(nested_each)]` field.
+    ///
+    /// The method passes a fresh builder of the `Vec`'s element type to a
+    /// closure, builds whatever complete builder the closure returns and pushes
+    /// the element onto the vector the builder holds.
+    ///
+    /// # Arguments
+    ///
+    /// * `return_type` - The return type of the field's regular setter
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<proc_macro2::TokenStream>` containing the method, or empty
+    /// tokens if the field has no `nested_each` method.
+    pub fn generate_nested_each_method(
+        &self,
+        return_type: &proc_macro2::TokenStream,
+    ) -> syn::Result<proc_macro2::TokenStream> {
+        let Some(method_name) = &self.attributes.nested_each else {
+            return Ok(quote! {});
+        };
+
+        let field_type = self.field_type();
+        let element_type = extract_vec_inner_type(field_type).ok_or_else(|| {
+            syn::Error::new_spanned(
+                field_type,
+                "#[builder(nested_each)] requires a `Vec<T>` field whose element type derives TypeStateBuilder",
+            )
+        })?;
+        let (Some(start_type), Some(ready_type)) = (
+            nested_builder_alias(element_type, "BuilderStart"),
+            nested_builder_alias(element_type, "BuilderReady"),
+        ) else {
+            return Err(syn::Error::new_spanned(
+                element_type,
+                "#[builder(nested_each)] requires a struct element type deriving TypeStateBuilder",
+            ));
+        };
+
+        let field_name = self.name();
+        let method_ident = syn::parse_str::<Ident>(method_name)?;
+        let elements = if self.is_boxed() {
+            quote! { (*self.#field_name) }
+        } else {
+            quote! { self.#field_name }
+        };
+        let doc = self.generated_doc_tokens(&format!(
+            "Adds an element to the field `{}`, configured through a new builder in a closure.",
+            self.clean_name()
+        ));
+        let deprecation = self.setter_deprecation_tokens();
+        let cfg = self.setter_cfg_tokens();
+
+        Ok(quote! {
+            #doc
+            #deprecation
+            #cfg
+            pub fn #method_ident<__F>(mut self, configure: __F) -> #return_type
+            where
+                __F: ::core::ops::FnOnce(#start_type) -> #ready_type,
+            {
+                #elements.push(<#element_type>::__build_nested(configure));
+                self
+            }
+        })
+    }
+
+    /// Generates the `try_{setter}` method for a `#[builder(try_setter)]` field.
+    ///
+    /// The method converts its argument with `TryFrom` and passes the result to
+    /// the field's regular setter, returning the conversion error unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `setter_ident` - The field's regular setter method
+    /// * `return_type` - The return type of the regular setter
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<proc_macro2::TokenStream>` containing the method, or empty
+    /// tokens if the field has no try setter.
+    pub fn generate_try_setter_method(
+        &self,
+        setter_ident: &Ident,
+        return_type: &proc_macro2::TokenStream,
+    ) -> syn::Result<proc_macro2::TokenStream> {
+        if !self.attributes.try_setter {
+            return Ok(quote! {});
+        }
+
+        let field_type = self.field_type();
+        let setter_name = strip_raw_identifier_prefix(&setter_ident.to_string()).into_owned();
+        let try_ident = syn::parse_str::<Ident>(&format!("try_{setter_name}"))?;
+        let doc = self.generated_doc_tokens(&format!(
+            "Sets the field `{}` to a value converted with `TryFrom`.\n\n\
+             # Errors\n\n\
+             Returns the conversion error if the value cannot be converted.",
+            self.clean_name()
+        ));
+        let deprecation = self.setter_deprecation_tokens();
+        let cfg = self.setter_cfg_tokens();
+
+        Ok(quote! {
+            #doc
+            #deprecation
+            #cfg
+            pub fn #try_ident<__V>(
+                self,
+                value: __V,
+            ) -> ::core::result::Result<#return_type, <#field_type as ::core::convert::TryFrom<__V>>::Error>
+            where
+                #field_type: ::core::convert::TryFrom<__V>,
+            {
+                let value = <#field_type as ::core::convert::TryFrom<__V>>::try_from(value)?;
+                ::core::result::Result::Ok(self.#setter_ident(value))
+            }
+        })
+    }
+
+    /// Generates the `{setter}_ref` method for a `#[builder(setter(also_ref))]` field.
+    ///
+    /// The method clones the referenced value and passes it to the field's
+    /// regular setter, so it returns whatever that setter returns.
+    ///
+    /// # Arguments
+    ///
+    /// * `setter_ident` - The field's regular setter method
+    /// * `return_type` - The return type of the regular setter
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<proc_macro2::TokenStream>` containing the method, or empty
+    /// tokens if the field has no by-reference setter.
+    pub fn generate_ref_setter_method(
+        &self,
+        setter_ident: &Ident,
+        return_type: &proc_macro2::TokenStream,
+    ) -> syn::Result<proc_macro2::TokenStream> {
+        if !self.attributes.also_ref {
+            return Ok(quote! {});
+        }
+
+        let field_type = self.field_type();
+        let setter_name = strip_raw_identifier_prefix(&setter_ident.to_string()).into_owned();
+        let ref_ident = syn::parse_str::<Ident>(&format!("{setter_name}_ref"))?;
+        let doc = self.generated_doc_tokens(&format!(
+            "Sets the field `{}` to a clone of the referenced value.",
+            self.clean_name()
+        ));
+        let deprecation = self.setter_deprecation_tokens();
+        let cfg = self.setter_cfg_tokens();
+
+        Ok(quote! {
+            #doc
+            #deprecation
+            #cfg
+            pub fn #ref_ident(self, value: &#field_type) -> #return_type
+            where
+                #field_type: ::core::clone::Clone,
+            {
+                self.#setter_ident(::core::clone::Clone::clone(value))
+            }
+        })
+    }
+
+    /// Generates the `{setter}_with` method for a `#[builder(lazy_setter)]` field.
+    ///
+    /// The method takes the closure as `value` and stores it via `body`, which
+    /// is expected to use [`Self::lazy_builder_value`] for the field.
+    ///
+    /// # Arguments
+    ///
+    /// * `setter_ident` - The field's regular setter method
+    /// * `return_type` - The return type of the regular setter
+    /// * `body` - The method body storing the closure
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<proc_macro2::TokenStream>` containing the method, or empty
+    /// tokens if the field has no lazy setter.
+    pub fn generate_lazy_setter_method(
+        &self,
+        setter_ident: &Ident,
+        return_type: &proc_macro2::TokenStream,
+        body: &proc_macro2::TokenStream,
+    ) -> syn::Result<proc_macro2::TokenStream> {
+        if !self.attributes.lazy_setter {
+            return Ok(quote! {});
+        }
+
+        let field_type = self.field_type();
+        let setter_name = strip_raw_identifier_prefix(&setter_ident.to_string()).into_owned();
+        let with_ident = syn::parse_str::<Ident>(&format!("{setter_name}_with"))?;
+        let doc = self.generated_doc_tokens(&format!(
+            "Sets the field `{}` to a value computed by a closure when the builder is built.\n\n\
+             The closure is not called if the field is set again before building.",
+            self.clean_name()
+        ));
+        let deprecation = self.setter_deprecation_tokens();
+        let cfg = self.setter_cfg_tokens();
+
+        Ok(quote! {
+            #doc
+            #deprecation
+            #cfg
+            pub fn #with_ident<__F>(self, value: __F) -> #return_type
+            where
+                __F: ::core::ops::FnOnce() -> #field_type + 'static,
+            {
+                #body
+            }
+        })
+    }
+
     // Validation methods
 
     /// Validates the field's configuration for consistency.
@@ -542,16 +1808,90 @@ impl FieldInfo {
             ));
         }
 
-        // Fields that skip setters must have default values
-        if self.attributes().skip_setter && self.attributes().default_value.is_none() {
+        // Defaults are expressions, so a string literal can never initialize a
+        // numeric, bool or char field; point at the literal with the typed form
+        if let Some(syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        })) = &self.attributes().default_value
+        {
+            if is_scalar_primitive_type(self.field_type()) {
+                let field_type = self.field_type();
+                let value = lit.value();
+                let help = match syn::parse_str::<syn::Expr>(&value) {
+                    Ok(_) => {
+                        format!("write the value without quotes: #[builder(default = {value})]")
+                    }
+                    Err(_) => {
+                        "write the default as a Rust expression of the field type".to_string()
+                    }
+                };
+                return Err(ErrorMessages::structured_error(
+                    lit,
+                    &format!(
+                        "String literal used as default for `{}` field",
+                        quote! { #field_type }
+                    ),
+                    Some("defaults are Rust expressions, checked against the field type"),
+                    Some(&help),
+                ));
+            }
+        }
+
+        // Transparent fields must be Option<T>, which is what the setter accepts
+        if self.attributes().transparent && extract_option_inner_type(self.field_type()).is_none() {
             return Err(ErrorMessages::structured_error(
-                self.name(),
-                "Fields with #[builder(skip_setter)] must have a default value",
-                Some("#[builder(skip_setter)] requires a way to initialize the field"),
-                Some("add #[builder(default = \"...\")] or remove skip_setter"),
+                self.field_type(),
+                "#[builder(transparent)] can only be used on `Option<T>` fields",
+                Some("transparent marks a required `Option<T>` field that may be set to `None`"),
+                Some("change the field type to `Option<T>` or remove transparent"),
+            ));
+        }
+
+        // Field-level into_iter collects into the field, so it must be a Vec<T>
+        if self.attributes().into_iter == Some(true)
+            && extract_vec_inner_type(self.field_type()).is_none()
+        {
+            return Err(ErrorMessages::structured_error(
+                self.field_type(),
+                "#[builder(into_iter)] can only be used on `Vec<T>` fields",
+                Some("into_iter setters collect any iterable of items convertible into `T`"),
+                Some("change the field type to `Vec<T>` or remove into_iter"),
+            ));
+        }
+
+        // The non_empty check calls `is_empty()` on the value, which `Option` lacks
+        if self.attributes().non_empty && extract_option_inner_type(self.field_type()).is_some() {
+            return Err(ErrorMessages::structured_error(
+                self.field_type(),
+                "#[builder(non_empty)] cannot be used on `Option<T>` fields",
+                Some("the check calls `is_empty()` on the field's value, and `Option` has no such method"),
+                Some("check the inner value with `validate = |value: &Option<_>| value.as_ref().map_or(true, |inner| !inner.is_empty())`"),
+            ));
+        }
+
+        // Nested fields name the builder aliases generated next to the field's type
+        if self.attributes().nested && nested_builder_alias(self.field_type(), "").is_none() {
+            return Err(ErrorMessages::structured_error(
+                self.field_type(),
+                "#[builder(nested)] can only be used on struct types deriving TypeStateBuilder",
+                Some("the `_with` setter configures the field through its type's builder"),
+                Some("use a plain path type such as `Address` or `crate::geo::Address<T>`"),
             ));
         }
 
+        // Resolve closures take the field value followed by other fields by name
+        if let Some(resolve) = &self.attributes().resolve {
+            if extract_resolve_dependencies(resolve).is_none() {
+                return Err(ErrorMessages::structured_error(
+                    resolve,
+                    "#[builder(resolve)] must be a closure taking the field value followed by other fields by name",
+                    Some("each parameter after the first receives a reference to the field with that name"),
+                    Some("example: #[builder(resolve = |path: PathBuf, base_dir: &PathBuf| base_dir.join(path))]"),
+                ));
+            }
+        }
+
         // Validate custom setter name if provided
         if let Some(setter_name) = &self.attributes().setter_name {
             // Try to parse as identifier to ensure it's valid
@@ -569,6 +1909,28 @@ impl FieldInfo {
     // Utility methods for analysis
 }
 
+/// Extracts the lines of `#[doc = "..."]` attributes (`///` comments).
+///
+/// Attributes whose value is not a string literal (e.g. `#[doc = include_str!(..)]`)
+/// and `#[doc(...)]` list attributes are ignored.
+fn extract_doc_lines(attrs: &[syn::Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit_str),
+                        ..
+                    }),
+                ..
+            }) => Some(lit_str.value()),
+            _ => None,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -581,7 +1943,6 @@ mod tests {
             FieldInfo::from_syn_field(parse_quote!(name), parse_quote!(String), &attrs).unwrap();
 
         assert!(field.is_required());
-        assert!(!field.is_optional());
         assert_eq!(field.name().to_string(), "name");
     }
 
@@ -591,12 +1952,11 @@ mod tests {
             FieldInfo::from_syn_field(parse_quote!(age), parse_quote!(Option<u32>), &[]).unwrap();
 
         assert!(!field.is_required());
-        assert!(field.is_optional());
     }
 
     #[test]
     fn test_from_syn_field_with_custom_default() {
-        let attrs = vec![parse_quote!(#[builder(default = "42")])];
+        let attrs = vec![parse_quote!(#[builder(default = 42)])];
         let field =
             FieldInfo::from_syn_field(parse_quote!(count), parse_quote!(i32), &attrs).unwrap();
 
@@ -613,6 +1973,137 @@ mod tests {
 
         assert!(!field.should_generate_setter());
         assert!(field.has_custom_default());
+
+        // Without a default, the field starts from Default::default()
+        let attrs = vec![parse_quote!(#[builder(skip_setter)])];
+        let field =
+            FieldInfo::from_syn_field(parse_quote!(id), parse_quote!(Uuid), &attrs).unwrap();
+        assert!(field.validate_configuration().is_ok());
+        assert!(!field.has_custom_default());
+    }
+
+    #[test]
+    fn test_from_syn_field_collects_docs() {
+        let attrs = vec![
+            parse_quote!(#[doc = ""]),
+            parse_quote!(#[doc = " First line."]),
+            parse_quote!(#[builder(required)]),
+            parse_quote!(#[doc(hidden)]),
+            parse_quote!(#[doc = " Second line."]),
+        ];
+        let field =
+            FieldInfo::from_syn_field(parse_quote!(name), parse_quote!(String), &attrs).unwrap();
+
+        assert_eq!(field.docs(), ["", " First line.", " Second line."]);
+        assert_eq!(field.doc_summary(), Some("First line."));
+
+        let undocumented =
+            FieldInfo::from_syn_field(parse_quote!(name), parse_quote!(String), &[]).unwrap();
+        assert!(undocumented.docs().is_empty());
+        assert!(undocumented.doc_summary().is_none());
+        assert!(undocumented.setter_doc_tokens("name").is_empty());
+    }
+
+    #[test]
+    fn test_setter_doc_tokens_alias_field_and_old_names() {
+        let attrs = vec![parse_quote!(#[builder(alias = "max_connections", alias = "conns")])];
+        let field =
+            FieldInfo::from_syn_field(parse_quote!(conns), parse_quote!(u32), &attrs).unwrap();
+
+        let tokens = field.setter_doc_tokens("with_conns").to_string();
+        assert_eq!(
+            tokens,
+            quote! {
+                #[doc(alias = "conns")]
+                #[doc(alias = "max_connections")]
+            }
+            .to_string()
+        );
+
+        let tokens = field.setter_doc_tokens("conns").to_string();
+        assert!(!tokens.contains("\"conns\""));
+        assert!(tokens.contains("\"max_connections\""));
+
+        let raw = FieldInfo::from_syn_field(parse_quote!(r#type), parse_quote!(u32), &[]).unwrap();
+        assert!(raw.setter_doc_tokens("r#type").is_empty());
+        assert!(raw
+            .setter_doc_tokens("with_type")
+            .to_string()
+            .contains("alias = \"type\""));
+    }
+
+    #[test]
+    fn test_from_syn_field_captures_deprecation() {
+        let attrs = vec![parse_quote!(#[deprecated(since = "1.2.0", note = "use `name`")])];
+        let field =
+            FieldInfo::from_syn_field(parse_quote!(label), parse_quote!(String), &attrs).unwrap();
+
+        assert!(field.is_deprecated());
+        let tokens = field.setter_deprecation_tokens().to_string();
+        assert!(tokens.contains("deprecated"));
+        assert!(tokens.contains("\"1.2.0\""));
+        assert!(tokens.contains("\"use `name`\""));
+
+        let current =
+            FieldInfo::from_syn_field(parse_quote!(name), parse_quote!(String), &[]).unwrap();
+        assert!(!current.is_deprecated());
+        assert!(current.setter_deprecation_tokens().is_empty());
+    }
+
+    #[test]
+    fn test_transparent_requires_option_type() {
+        let attrs = vec![parse_quote!(#[builder(required, transparent)])];
+
+        let option_field =
+            FieldInfo::from_syn_field(parse_quote!(level), parse_quote!(Option<u8>), &attrs);
+        assert!(option_field.is_ok());
+
+        let plain_field = FieldInfo::from_syn_field(parse_quote!(level), parse_quote!(u8), &attrs);
+        assert!(plain_field
+            .unwrap_err()
+            .to_string()
+            .contains("can only be used on `Option<T>` fields"));
+    }
+
+    #[test]
+    fn test_non_empty_rejects_option_type() {
+        let attrs = vec![parse_quote!(#[builder(non_empty)])];
+
+        let vec_field =
+            FieldInfo::from_syn_field(parse_quote!(tags), parse_quote!(Vec<String>), &attrs);
+        assert!(vec_field.is_ok());
+
+        let option_field = FieldInfo::from_syn_field(
+            parse_quote!(tags),
+            parse_quote!(Option<Vec<String>>),
+            &attrs,
+        );
+        assert!(option_field
+            .unwrap_err()
+            .to_string()
+            .contains("#[builder(non_empty)] cannot be used on `Option<T>` fields"));
+    }
+
+    #[test]
+    fn test_into_iter_requires_vec_type() {
+        let attrs = vec![parse_quote!(#[builder(into_iter)])];
+
+        let vec_field =
+            FieldInfo::from_syn_field(parse_quote!(tags), parse_quote!(Vec<String>), &attrs)
+                .unwrap();
+        assert!(vec_field.uses_into_iter(false));
+
+        let plain_field =
+            FieldInfo::from_syn_field(parse_quote!(tags), parse_quote!(String), &attrs);
+        assert!(plain_field
+            .unwrap_err()
+            .to_string()
+            .contains("can only be used on `Vec<T>` fields"));
+
+        // Struct-level into_iter only affects Vec<T> fields
+        let name =
+            FieldInfo::from_syn_field(parse_quote!(name), parse_quote!(String), &[]).unwrap();
+        assert!(!name.uses_into_iter(true));
     }
 
     #[test]
@@ -663,7 +2154,7 @@ mod tests {
 
     #[test]
     fn test_create_default_config() {
-        let custom_attrs = vec![parse_quote!(#[builder(default = "42")])];
+        let custom_attrs = vec![parse_quote!(#[builder(default = 42)])];
         let field =
             FieldInfo::from_syn_field(parse_quote!(count), parse_quote!(i32), &custom_attrs)
                 .unwrap();
@@ -673,6 +2164,16 @@ mod tests {
         assert!(config.default_expression.is_some());
     }
 
+    #[test]
+    fn test_custom_default_is_bound_to_field_type() {
+        let attrs = vec![parse_quote!(#[builder(default = vec![1, 2])])];
+        let field =
+            FieldInfo::from_syn_field(parse_quote!(ids), parse_quote!(Vec<u64>), &attrs).unwrap();
+
+        let code = field.generate_initialization(false).unwrap().to_string();
+        assert!(code.contains("let __default : Vec < u64 > = vec ! [1 , 2] ;"));
+    }
+
     #[test]
     fn test_validation_errors() {
         // Required field with default should fail validation
@@ -731,6 +2232,54 @@ mod tests {
         assert_eq!(field.final_setter_name(Some("with_")), "set_data");
     }
 
+    #[test]
+    fn test_final_setter_name_with_prefix_exception() {
+        let struct_attributes = crate::attributes::parse_struct_attributes(&[parse_quote!(
+            #[builder(setter_prefix = "with_", setter_prefix(except(r#type, id)))]
+        )])
+        .unwrap();
+        let field_info = |name: syn::Ident, attrs: &[syn::Attribute]| {
+            FieldInfo::from_syn_field_in_struct(name, parse_quote!(u32), attrs, &struct_attributes)
+                .unwrap()
+        };
+
+        // Listed fields keep their natural names, others get the prefix
+        assert_eq!(
+            field_info(parse_quote!(r#type), &[]).final_setter_name(Some("with_")),
+            "r#type"
+        );
+        assert_eq!(
+            field_info(parse_quote!(count), &[]).final_setter_name(Some("with_")),
+            "with_count"
+        );
+
+        // A field-level prefix still applies to a listed field
+        let attrs = vec![parse_quote!(#[builder(setter_prefix = "set_")])];
+        assert_eq!(
+            field_info(parse_quote!(id), &attrs).final_setter_name(Some("with_")),
+            "set_id"
+        );
+    }
+
+    #[test]
+    fn test_final_setter_name_with_raw_identifiers() {
+        let field =
+            FieldInfo::from_syn_field(parse_quote!(r#type), parse_quote!(String), &[]).unwrap();
+        assert_eq!(field.final_setter_name(None), "r#type");
+        assert_eq!(field.final_setter_name(Some("with_")), "with_type");
+
+        let attrs = vec![parse_quote!(#[builder(setter_prefix = "set_")])];
+        let field =
+            FieldInfo::from_syn_field(parse_quote!(r#match), parse_quote!(String), &attrs).unwrap();
+        assert_eq!(field.final_setter_name(None), "set_match");
+
+        let attrs = vec![parse_quote!(#[builder(setter_name = "r#loop")])];
+        let field =
+            FieldInfo::from_syn_field(parse_quote!(count), parse_quote!(u32), &attrs).unwrap();
+        assert_eq!(field.final_setter_name(None), "r#loop");
+        assert_eq!(field.final_setter_name(Some("with_")), "with_loop");
+    }
+
     #[test]
     fn test_create_setter_config_with_prefixes() {
         // Test struct-level prefix
@@ -748,4 +2297,88 @@ mod tests {
         let config = field_with_prefix.create_setter_config(Some("with_"));
         assert_eq!(config.setter_name, "set_name");
     }
+
+    #[test]
+    fn test_resolve_generation() {
+        let attrs = vec![
+            parse_quote!(#[builder(resolve = |path: String, base: &String| base.clone() + &path)]),
+        ];
+        let field =
+            FieldInfo::from_syn_field(parse_quote!(path), parse_quote!(String), &attrs).unwrap();
+
+        let names: Vec<_> = field
+            .resolve_dependencies()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(names, ["base"]);
+
+        let statement = field.generate_resolve_statement().to_string();
+        assert!(statement.starts_with("let __resolved_path ="));
+        assert!(statement.contains("(self . path , & self . base)"));
+        assert_eq!(field.build_value_tokens().to_string(), "__resolved_path");
+
+        let plain =
+            FieldInfo::from_syn_field(parse_quote!(path), parse_quote!(String), &[]).unwrap();
+        assert!(plain.generate_resolve_statement().is_empty());
+        assert_eq!(plain.build_value_tokens().to_string(), "self . path");
+    }
+
+    #[test]
+    fn test_resolve_requires_closure_with_named_fields() {
+        let attrs = vec![parse_quote!(#[builder(resolve = str::to_uppercase)])];
+        let field = FieldInfo::from_syn_field(parse_quote!(name), parse_quote!(String), &attrs);
+        assert!(field
+            .unwrap_err()
+            .to_string()
+            .contains("must be a closure taking the field value"));
+    }
+
+    #[test]
+    fn test_string_literal_default_on_scalar_field() {
+        let attrs = vec![parse_quote!(#[builder(default = "42")])];
+        let error = FieldInfo::from_syn_field(parse_quote!(count), parse_quote!(u32), &attrs)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("String literal used as default for `u32` field"));
+        assert!(error.contains("#[builder(default = 42)]"));
+
+        // Typed literals and string fields are accepted
+        let typed = vec![parse_quote!(#[builder(default = 42)])];
+        assert!(FieldInfo::from_syn_field(parse_quote!(count), parse_quote!(u32), &typed).is_ok());
+        assert!(FieldInfo::from_syn_field(parse_quote!(name), parse_quote!(&str), &attrs).is_ok());
+    }
+
+    #[test]
+    fn test_validation_statement_shorthands() {
+        let value: Ident = parse_quote!(__value);
+        let error: Ident = parse_quote!(PortError);
+
+        let attrs = vec![parse_quote!(#[builder(range = 1..=1024)])];
+        let field =
+            FieldInfo::from_syn_field(parse_quote!(port), parse_quote!(u16), &attrs).unwrap();
+        let statement = field
+            .generate_validation_statement(&value, &error)
+            .to_string();
+        assert!(statement.contains("(1 ..= 1024) . contains (& __value . port)"));
+        assert!(statement.contains("\"must be in range 1..=1024\""));
+        assert!(statement.contains("location : :: core :: panic :: Location :: caller ()"));
+
+        let attrs = vec![
+            parse_quote!(#[builder(non_empty, validate = |name: &String| name.len() < 8, validate_message = "bad name")]),
+        ];
+        let field =
+            FieldInfo::from_syn_field(parse_quote!(name), parse_quote!(String), &attrs).unwrap();
+        let statement = field
+            .generate_validation_statement(&value, &error)
+            .to_string();
+        assert!(statement.find("is_empty").unwrap() < statement.find("validate (").unwrap());
+        assert_eq!(statement.matches("\"bad name\"").count(), 2);
+
+        let plain =
+            FieldInfo::from_syn_field(parse_quote!(name), parse_quote!(String), &[]).unwrap();
+        assert!(plain
+            .generate_validation_statement(&value, &error)
+            .is_empty());
+    }
 }
@@ -34,4 +34,4 @@ pub use field_analysis::FieldInfo;
 pub use struct_analysis::StructAnalysis;
 
 // Re-export the main analysis function
-pub use struct_analysis::analyze_struct;
+pub use struct_analysis::{analyze_enum, analyze_struct, analyze_struct_with_required_cfgs};
@@ -16,7 +16,9 @@
 
 use crate::analysis::field_analysis::FieldInfo;
 use crate::attributes::{parse_struct_attributes, StructAttributes};
-use crate::utils::generics::needs_phantom_data;
+use crate::utils::generics::{consolidate_bounds, needs_phantom_data, BoundHelper};
+use crate::utils::identifiers::strip_raw_identifier_prefix;
+use crate::validation::error_messages::ErrorMessages;
 use quote::quote;
 use syn::{DeriveInput, Fields, Generics, Type};
 
@@ -51,9 +53,19 @@ pub struct StructAnalysis {
     /// The visibility of the struct (pub, pub(crate), private, etc.)
     struct_visibility: syn::Visibility,
 
+    /// Whether the struct is marked `#[non_exhaustive]`
+    non_exhaustive: bool,
+
     /// The complete generic parameter list from the struct
     struct_generics: Generics,
 
+    /// The generic parameters for builder impl blocks, with the bounds of
+    /// bound-heavy type parameters replaced by helper traits
+    builder_generics: Generics,
+
+    /// The helper traits referenced by `builder_generics`
+    bound_helpers: Vec<BoundHelper>,
+
     /// Parsed struct-level builder attributes
     struct_attributes: StructAttributes,
 
@@ -62,6 +74,10 @@ pub struct StructAnalysis {
 
     /// Fields that are optional (have default values)
     optional_fields: Vec<FieldInfo>,
+
+    /// The enum variant being built, when `struct_name` names an enum and the
+    /// fields are the variant's
+    variant: Option<syn::Ident>,
 }
 
 impl StructAnalysis {
@@ -74,6 +90,9 @@ impl StructAnalysis {
     /// # Arguments
     ///
     /// * `input` - The parsed derive input from the struct definition
+    /// * `required_cfgs` - Whether the predicate of each `required(cfg(...))`
+    ///   field holds, in declaration order; fields without an entry keep
+    ///   their predicate
     ///
     /// # Returns
     ///
@@ -89,21 +108,121 @@ impl StructAnalysis {
     /// - Invalid field or struct attributes
     /// - Inconsistent attribute combinations
     /// - Generic parameter mismatches
-    fn from_derive_input(input: &DeriveInput) -> syn::Result<Self> {
+    fn from_derive_input(input: &DeriveInput, required_cfgs: &[bool]) -> syn::Result<Self> {
         let struct_name = input.ident.clone();
-        let struct_visibility = input.vis.clone();
+        let struct_visibility = normalize_visibility(&input.vis);
+        let non_exhaustive = input
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("non_exhaustive"));
         let struct_generics = input.generics.clone();
+        let (builder_generics, bound_helpers) = consolidate_bounds(&struct_name, &struct_generics);
         let struct_attributes = parse_struct_attributes(&input.attrs)?;
         let fields = extract_named_fields(input)?;
-        let (required_fields, optional_fields) = parse_fields(fields)?;
+        let (required_fields, optional_fields) =
+            parse_fields(fields, &struct_attributes, required_cfgs)?;
 
         Ok(StructAnalysis {
             struct_name,
             struct_visibility,
+            non_exhaustive,
             struct_generics,
+            builder_generics,
+            bound_helpers,
             struct_attributes,
             required_fields,
             optional_fields,
+            variant: None,
+        })
+    }
+
+    /// Creates the analysis of one enum variant, built like a struct.
+    ///
+    /// The variant's fields are the fields to build, while the name, generics
+    /// and visibility are the enum's, so the build method returns the enum.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The parsed derive input of the enum
+    /// * `variant` - The variant to analyze
+    /// * `struct_attributes` - The builder attributes of the enum
+    ///
+    /// # Returns
+    ///
+    /// A `Result<StructAnalysis, syn::Error>` containing the variant's analysis
+    /// or an error for tuple variants and invalid field attributes.
+    fn from_variant(
+        input: &DeriveInput,
+        variant: &syn::Variant,
+        struct_attributes: &StructAttributes,
+    ) -> syn::Result<Self> {
+        if let Some(attr) = variant
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("builder"))
+        {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "Builder attributes are not supported on enum variants. \
+                 Put them on the enum or on the variant's fields.",
+            ));
+        }
+
+        let (required_fields, optional_fields) =
+            match &variant.fields {
+                Fields::Named(fields_named) => parse_fields(fields_named, struct_attributes, &[])?,
+                Fields::Unit => (Vec::new(), Vec::new()),
+                Fields::Unnamed(_) => return Err(syn::Error::new_spanned(
+                    variant,
+                    "TypeStateBuilder only supports enum variants with named fields or no fields",
+                )),
+            };
+
+        if let Some(field) = required_fields
+            .iter()
+            .chain(&optional_fields)
+            .find(|field| field.attributes().required_cfg.is_some())
+        {
+            return Err(syn::Error::new_spanned(
+                field.name(),
+                "`required(cfg(...))` is not supported on enum variant fields. \
+                 Use `required` or make the field optional.",
+            ));
+        }
+        if let Some((field, check)) = required_fields
+            .iter()
+            .chain(&optional_fields)
+            .find_map(|field| Some((field, field.attributes().validation_attribute()?)))
+        {
+            return Err(syn::Error::new_spanned(
+                field.name(),
+                format!(
+                    "`{check}` is not supported on enum variant fields. \
+                     Validate the enum with a finisher instead."
+                ),
+            ));
+        }
+
+        let struct_name = input.ident.clone();
+        let struct_generics = input.generics.clone();
+        let (builder_generics, bound_helpers) = consolidate_bounds(&struct_name, &struct_generics);
+        let non_exhaustive = input
+            .attrs
+            .iter()
+            .chain(&variant.attrs)
+            .any(|attr| attr.path().is_ident("non_exhaustive"));
+
+        Ok(StructAnalysis {
+            struct_name,
+            struct_visibility: normalize_visibility(&input.vis),
+            non_exhaustive,
+            struct_generics,
+            builder_generics,
+            bound_helpers,
+            struct_attributes: struct_attributes.clone(),
+            required_fields,
+            optional_fields,
+            variant: Some(variant.ident.clone()),
         })
     }
 
@@ -114,11 +233,163 @@ impl StructAnalysis {
         &self.struct_name
     }
 
+    /// Returns the enum variant being built, if this analysis describes one.
+    pub fn variant_name(&self) -> Option<&syn::Ident> {
+        self.variant.as_ref()
+    }
+
+    /// Returns the name the builder types are named after.
+    ///
+    /// This is the struct name, or the enum and variant names joined for an
+    /// enum variant, e.g. `MessagePing` for `Message::Ping`.
+    pub fn builder_base_name(&self) -> String {
+        match &self.variant {
+            Some(variant) => format!(
+                "{}{}",
+                self.struct_name,
+                strip_raw_identifier_prefix(&variant.to_string())
+            ),
+            None => self.struct_name.to_string(),
+        }
+    }
+
+    /// Returns the path the build method constructs the value with.
+    ///
+    /// This is the struct name, or `Enum::Variant` for an enum variant.
+    pub fn constructor_path(&self) -> proc_macro2::TokenStream {
+        let struct_name = &self.struct_name;
+        match &self.variant {
+            Some(variant) => quote! { #struct_name::#variant },
+            None => quote! { #struct_name },
+        }
+    }
+
     /// Returns the struct's visibility (pub, pub(crate), private, etc.).
+    ///
+    /// Restrictions naming the struct's own module or the crate are normalized
+    /// to their short forms, see [`normalize_visibility`].
     pub fn struct_visibility(&self) -> &syn::Visibility {
         &self.struct_visibility
     }
 
+    /// Returns whether the struct is marked `#[non_exhaustive]`.
+    ///
+    /// Other crates cannot construct such a struct with a literal, so its
+    /// builder is the only way to create it outside the defining crate.
+    pub fn is_non_exhaustive(&self) -> bool {
+        self.non_exhaustive
+    }
+
+    /// Returns whether the builder types are marked `#[non_exhaustive]`.
+    ///
+    /// Follows the struct unless `#[builder(non_exhaustive = ...)]` decides.
+    pub fn builder_non_exhaustive(&self) -> bool {
+        self.struct_attributes
+            .get_non_exhaustive()
+            .unwrap_or(self.non_exhaustive)
+    }
+
+    /// Returns the visibility for the generated builder items.
+    ///
+    /// Matches the struct's visibility, adjusted for the extra module level when
+    /// the builder is generated into a child module with `#[builder(module = "...")]`:
+    /// private items become `pub(super)` and `super`-relative restrictions gain
+    /// one more `super`. Restrictions to a `crate::` path name the same module
+    /// from anywhere and are kept as they are.
+    pub fn builder_visibility(&self) -> syn::Visibility {
+        self.visibility_in_builder_module(&self.struct_visibility)
+    }
+
+    /// Returns the visibility of the methods that build the struct without validation.
+    ///
+    /// This is the `#[builder(build_vis = "...")]` visibility, normalized and
+    /// adjusted for the builder module like [`Self::builder_visibility`]. When
+    /// it is not set the methods are `pub`, so only the builder type limits access.
+    pub fn build_visibility(&self) -> syn::Visibility {
+        self.method_visibility(self.struct_attributes.get_build_vis().cloned())
+    }
+
+    /// Returns the visibility of the methods that start the builder.
+    ///
+    /// This is the `#[builder(entry_vis = "...")]` visibility, adjusted like
+    /// [`Self::build_visibility`], or `pub` when it is not set.
+    pub fn entry_visibility(&self) -> syn::Visibility {
+        self.method_visibility(self.struct_attributes.get_entry_vis().cloned())
+    }
+
+    /// Returns the visibility of the method that starts and builds a nested builder.
+    ///
+    /// The method does both, so it is limited by `entry_vis` and `build_vis`.
+    /// When both are set and neither contains the other, it is private.
+    pub fn nested_build_visibility(&self) -> syn::Visibility {
+        let entry_vis = self
+            .struct_attributes
+            .get_entry_vis()
+            .map(normalize_visibility);
+        let build_vis = self
+            .struct_attributes
+            .get_build_vis()
+            .map(normalize_visibility);
+        let narrower = match (entry_vis, build_vis) {
+            (Some(entry_vis), Some(build_vis)) => {
+                let rank = |visibility: &syn::Visibility| match visibility {
+                    syn::Visibility::Public(_) => 2,
+                    syn::Visibility::Restricted(restricted)
+                        if restricted.path.is_ident("crate") =>
+                    {
+                        1
+                    }
+                    _ => 0,
+                };
+                if rank(&entry_vis) > rank(&build_vis) {
+                    Some(build_vis)
+                } else if rank(&build_vis) > rank(&entry_vis) || entry_vis == build_vis {
+                    Some(entry_vis)
+                } else {
+                    Some(syn::Visibility::Inherited)
+                }
+            }
+            (entry_vis, build_vis) => entry_vis.or(build_vis),
+        };
+        self.method_visibility(narrower)
+    }
+
+    /// Returns the visibility of a generated method given its configured visibility.
+    fn method_visibility(&self, visibility: Option<syn::Visibility>) -> syn::Visibility {
+        match visibility {
+            Some(visibility) => {
+                self.visibility_in_builder_module(&normalize_visibility(&visibility))
+            }
+            None => syn::parse_quote!(pub),
+        }
+    }
+
+    /// Adjusts a visibility written for the struct's module to the module the
+    /// builder items are generated into.
+    fn visibility_in_builder_module(&self, visibility: &syn::Visibility) -> syn::Visibility {
+        if self.struct_attributes.get_module().is_none() {
+            return visibility.clone();
+        }
+
+        match visibility {
+            syn::Visibility::Inherited => syn::parse_quote!(pub(super)),
+            syn::Visibility::Restricted(restricted) => {
+                let path = &restricted.path;
+                match path.segments.first() {
+                    Some(first) if first.ident == "self" => {
+                        let rest = path.segments.iter().skip(1);
+                        syn::parse_quote!(pub(in super #(::#rest)*))
+                    }
+                    Some(first) if first.ident == "super" => {
+                        syn::parse_quote!(pub(in super::#path))
+                    }
+                    _ => visibility.clone(),
+                }
+            }
+            visibility => visibility.clone(),
+        }
+    }
+
     /// Returns the struct's generic parameters and constraints.
     pub fn struct_generics(&self) -> &Generics {
         &self.struct_generics
@@ -129,6 +400,14 @@ impl StructAnalysis {
         &self.struct_attributes
     }
 
+    /// Lets other builders nest this one, as `#[builder(nestable)]` does.
+    ///
+    /// `#[builder_fn]` names the states of its arguments builder through the
+    /// nesting aliases, once it has ruled out the options they do not support.
+    pub fn set_nestable(&mut self) {
+        self.struct_attributes.nestable = true;
+    }
+
     // Field accessors
 
     /// Returns the required fields that must be set before building.
@@ -156,6 +435,33 @@ impl StructAnalysis {
             .chain(self.optional_fields.iter())
     }
 
+    /// Returns whether any field's value is checked by `validate`, `non_empty` or
+    /// `range` in the build method, making the build method fallible.
+    pub fn has_validated_fields(&self) -> bool {
+        self.all_fields().any(|field| {
+            field.attributes().validation_attribute().is_some()
+                && !field.attributes().validate_on_set
+        })
+    }
+
+    /// Returns whether any field's checks run in its setter under `validate_on_set`.
+    pub fn has_setter_validated_fields(&self) -> bool {
+        self.all_fields()
+            .any(|field| field.attributes().validate_on_set)
+    }
+
+    /// Returns the predicates of the `required(cfg(...))` fields in declaration order.
+    ///
+    /// Such fields are optional until
+    /// [`analyze_struct_with_required_cfgs`] resolves their predicate, so they
+    /// are all among the optional fields.
+    pub fn required_cfg_predicates(&self) -> Vec<&syn::Meta> {
+        self.optional_fields
+            .iter()
+            .filter_map(|field| field.attributes().required_cfg.as_ref())
+            .collect()
+    }
+
     /// Returns the field with `builder_method` attribute, if any.
     ///
     /// This field's setter becomes the entry point to the builder,
@@ -182,8 +488,8 @@ impl StructAnalysis {
 
     /// Checks if PhantomData is needed for the builder struct.
     ///
-    /// PhantomData is needed when the struct has generic parameters or
-    /// when field types contain generics/lifetimes that need to be tracked.
+    /// PhantomData is needed only when a type parameter or lifetime of the
+    /// struct is not referenced by any field type stored in the builder.
     ///
     /// # Returns
     ///
@@ -199,14 +505,15 @@ impl StructAnalysis {
     /// Generates impl generics tokens for use in impl blocks.
     ///
     /// This includes all generic parameters with their bounds, suitable for
-    /// use in the `impl<...>` part of implementation blocks.
+    /// use in the `impl<...>` part of implementation blocks. Bounds that were
+    /// moved into a helper trait appear as that trait (see [`Self::bound_helpers`]).
     ///
     /// # Returns
     ///
     /// A token stream containing the impl generics.
     ///
     pub fn impl_generics_tokens(&self) -> proc_macro2::TokenStream {
-        let (impl_generics, _ty_generics, _where_clause) = self.struct_generics.split_for_impl();
+        let (impl_generics, _ty_generics, _where_clause) = self.builder_generics.split_for_impl();
         quote! { #impl_generics }
     }
 
@@ -226,18 +533,35 @@ impl StructAnalysis {
 
     /// Generates where clause tokens for use in type definitions.
     ///
-    /// This includes all where clause predicates from the original struct
-    /// definition.
+    /// This includes the where clause predicates from the original struct
+    /// definition that were not moved into a helper trait.
     ///
     /// # Returns
     ///
     /// A token stream containing the where clause, or empty if no where clause.
     ///
     pub fn where_clause_tokens(&self) -> proc_macro2::TokenStream {
-        let (_impl_generics, _ty_generics, where_clause) = self.struct_generics.split_for_impl();
+        let (_impl_generics, _ty_generics, where_clause) = self.builder_generics.split_for_impl();
         quote! { #where_clause }
     }
 
+    /// Returns the where clause predicates for builder impl blocks.
+    ///
+    /// These are the predicates of [`Self::where_clause_tokens`], for combining
+    /// them with further bounds.
+    pub fn builder_where_predicates(&self) -> impl Iterator<Item = &syn::WherePredicate> {
+        self.builder_generics
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| where_clause.predicates.iter())
+    }
+
+    /// Returns the helper traits that state the bounds of bound-heavy type
+    /// parameters once, for the impl generics and where clause to refer to.
+    pub fn bound_helpers(&self) -> &[BoundHelper] {
+        &self.bound_helpers
+    }
+
     // Validation and analysis methods
 
     /// Validates the struct configuration for builder generation.
@@ -300,7 +624,136 @@ impl StructAnalysis {
 /// - Inconsistent attribute combinations
 /// - Generic parameter usage errors
 pub fn analyze_struct(input: &DeriveInput) -> syn::Result<StructAnalysis> {
-    StructAnalysis::from_derive_input(input)
+    StructAnalysis::from_derive_input(input, &[])
+}
+
+/// Analyzes a struct for one side of each `required(cfg(...))` predicate.
+///
+/// Each field with a `required(cfg(...))` attribute is required if its entry
+/// in `required_cfgs` is `true` and optional otherwise, so the analysis
+/// describes the builder generated under that combination of predicates.
+///
+/// # Arguments
+///
+/// * `input` - The parsed derive input from the struct definition
+/// * `required_cfgs` - Whether each predicate holds, in the order of
+///   [`StructAnalysis::required_cfg_predicates`]
+///
+/// # Returns
+///
+/// A `Result<StructAnalysis, syn::Error>` containing the analysis, or an
+/// error if the struct cannot be analyzed.
+pub fn analyze_struct_with_required_cfgs(
+    input: &DeriveInput,
+    required_cfgs: &[bool],
+) -> syn::Result<StructAnalysis> {
+    StructAnalysis::from_derive_input(input, required_cfgs)
+}
+
+/// Analyzes an enum definition, one `StructAnalysis` per variant.
+///
+/// The enum's builder attributes apply to every variant, except those that
+/// generate items named after the enum alone, which would clash between the
+/// variants' builders.
+///
+/// # Arguments
+///
+/// * `input` - The parsed derive input from the enum definition
+///
+/// # Returns
+///
+/// A `Result<Vec<StructAnalysis>, syn::Error>` containing the analysis of
+/// each variant in declaration order.
+///
+/// # Errors
+///
+/// Returns errors for:
+/// - Enums without variants, and tuple variants
+/// - Builder attributes that are not supported on enums
+/// - Invalid field attributes
+pub fn analyze_enum(input: &DeriveInput) -> syn::Result<Vec<StructAnalysis>> {
+    let syn::Data::Enum(data_enum) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "Expected an enum to analyze its variants",
+        ));
+    };
+    if data_enum.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            input,
+            "TypeStateBuilder does not support enums without variants",
+        ));
+    }
+
+    let struct_attributes = parse_struct_attributes(&input.attrs)?;
+    let unsupported = [
+        ("module", struct_attributes.get_module().is_some()),
+        ("stable_names", struct_attributes.get_stable_names()),
+        ("hide_states", struct_attributes.get_hide_states()),
+        ("state_traits", struct_attributes.get_state_traits()),
+        ("setter_trait", struct_attributes.get_setter_trait()),
+        ("lite", struct_attributes.get_lite()),
+        ("no_entry", struct_attributes.get_no_entry()),
+        ("arbitrary", struct_attributes.get_arbitrary()),
+        (
+            "default_struct",
+            struct_attributes.get_default_struct().is_some(),
+        ),
+        ("base", struct_attributes.get_base().is_some()),
+        ("fill_from", struct_attributes.get_fill_from()),
+        ("apply_env", struct_attributes.get_apply_env()),
+        (
+            "impl_trait",
+            !struct_attributes.get_impl_traits().is_empty(),
+        ),
+        ("buildable", struct_attributes.get_buildable().is_some()),
+        (
+            "field_descriptors",
+            struct_attributes.get_field_descriptors().is_some(),
+        ),
+        ("also_dynamic", struct_attributes.get_also_dynamic()),
+        ("nestable", struct_attributes.get_nestable()),
+        (
+            "state_aliases",
+            struct_attributes.get_state_aliases().is_some(),
+        ),
+        (
+            "test_default",
+            struct_attributes.get_test_default().is_some(),
+        ),
+    ];
+    if let Some((attribute, _)) = unsupported.iter().find(|(_, is_set)| *is_set) {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            format!(
+                "`{attribute}` is not supported on enums. \
+                 Enums get one builder per variant, started from the variant selection builder."
+            ),
+        ));
+    }
+
+    let variants = data_enum
+        .variants
+        .iter()
+        .map(|variant| StructAnalysis::from_variant(input, variant, &struct_attributes))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    if let Some(field) = variants
+        .iter()
+        .find_map(|variant| variant.builder_method_field())
+    {
+        return Err(ErrorMessages::structured_error_span(
+            field.name().span(),
+            &format!(
+                "field `{}`: `builder_method` is not supported on enum variants",
+                field.clean_name()
+            ),
+            Some("variant builders are started from the variant selection builder"),
+            Some("remove `builder_method` and select the variant with `builder()`"),
+        ));
+    }
+
+    Ok(variants)
 }
 
 // Helper functions (these were previously standalone functions)
@@ -359,6 +812,9 @@ fn extract_named_fields(input: &DeriveInput) -> syn::Result<&syn::FieldsNamed> {
 /// # Arguments
 ///
 /// * `fields_named` - The named fields from the struct definition
+/// * `struct_attributes` - The struct-level attributes (e.g. `all_required`)
+/// * `required_cfgs` - Whether the predicate of each `required(cfg(...))`
+///   field holds, in declaration order
 ///
 /// # Returns
 ///
@@ -372,9 +828,14 @@ fn extract_named_fields(input: &DeriveInput) -> syn::Result<&syn::FieldsNamed> {
 /// - Invalid field attributes
 /// - Inconsistent attribute combinations
 /// - Missing field names
-fn parse_fields(fields_named: &syn::FieldsNamed) -> syn::Result<(Vec<FieldInfo>, Vec<FieldInfo>)> {
+fn parse_fields(
+    fields_named: &syn::FieldsNamed,
+    struct_attributes: &StructAttributes,
+    required_cfgs: &[bool],
+) -> syn::Result<(Vec<FieldInfo>, Vec<FieldInfo>)> {
     let mut required_fields = Vec::new();
     let mut optional_fields = Vec::new();
+    let mut required_cfgs = required_cfgs.iter();
 
     for field in &fields_named.named {
         let field_name = field
@@ -383,7 +844,17 @@ fn parse_fields(fields_named: &syn::FieldsNamed) -> syn::Result<(Vec<FieldInfo>,
             .ok_or_else(|| syn::Error::new_spanned(field, "Field must have a name"))?
             .clone();
 
-        let field_info = FieldInfo::from_syn_field(field_name, field.ty.clone(), &field.attrs)?;
+        let mut field_info = FieldInfo::from_syn_field_in_struct(
+            field_name,
+            field.ty.clone(),
+            &field.attrs,
+            struct_attributes,
+        )?;
+        if field_info.attributes().required_cfg.is_some() {
+            if let Some(&enabled) = required_cfgs.next() {
+                field_info.apply_required_cfg(enabled);
+            }
+        }
 
         if field_info.is_required() {
             required_fields.push(field_info);
@@ -395,6 +866,35 @@ fn parse_fields(fields_named: &syn::FieldsNamed) -> syn::Result<(Vec<FieldInfo>,
     Ok((required_fields, optional_fields))
 }
 
+/// Normalizes a visibility to the form generated items are declared with.
+///
+/// `pub(self)` and `pub(in self)` are private, `pub(in crate)` is `pub(crate)`
+/// and `pub(in super)` is `pub(super)`. Other restrictions are kept as written,
+/// so the generated items are exactly as visible as the struct.
+///
+/// # Arguments
+///
+/// * `visibility` - The visibility as written on the struct
+///
+/// # Returns
+///
+/// The equivalent visibility in its shortest form.
+pub fn normalize_visibility(visibility: &syn::Visibility) -> syn::Visibility {
+    let syn::Visibility::Restricted(restricted) = visibility else {
+        return visibility.clone();
+    };
+    let path = &restricted.path;
+    if path.is_ident("self") {
+        syn::Visibility::Inherited
+    } else if path.is_ident("crate") {
+        syn::parse_quote!(pub(crate))
+    } else if path.is_ident("super") {
+        syn::parse_quote!(pub(super))
+    } else {
+        visibility.clone()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -494,6 +994,38 @@ mod tests {
         assert_eq!(analysis.optional_fields().len(), 2);
     }
 
+    #[test]
+    fn test_all_required_keeps_accumulate_fields_optional() {
+        let input: DeriveInput = parse_quote! {
+            #[builder(all_required)]
+            struct Order {
+                id: u64,
+                #[builder(accumulate = |lines, line: &str| lines.push(line.to_string()))]
+                lines: Vec<String>,
+            }
+        };
+
+        let analysis = analyze_struct(&input).unwrap();
+        analysis.validate_for_generation().unwrap();
+
+        assert_eq!(analysis.required_fields().len(), 1);
+        assert_eq!(analysis.required_fields()[0].name().to_string(), "id");
+        assert_eq!(analysis.optional_fields()[0].name().to_string(), "lines");
+
+        // An explicit `required` is still rejected on an accumulating field
+        let explicit: DeriveInput = parse_quote! {
+            #[builder(all_required)]
+            struct Order {
+                #[builder(required, accumulate = |lines, line: &str| lines.push(line.to_string()))]
+                lines: Vec<String>,
+            }
+        };
+        assert!(analyze_struct(&explicit)
+            .unwrap_err()
+            .to_string()
+            .contains("required"));
+    }
+
     #[test]
     fn test_extract_named_fields_errors() {
         // Test tuple struct
@@ -518,6 +1050,87 @@ mod tests {
         assert!(extract_named_fields(&enum_input).is_err());
     }
 
+    #[test]
+    fn test_analyze_enum() {
+        let input: DeriveInput = parse_quote! {
+            #[builder(build_method = "create")]
+            enum Message<T> {
+                Ping,
+                Data {
+                    #[builder(required)]
+                    payload: T,
+                    flags: u8,
+                },
+            }
+        };
+        let variants = analyze_enum(&input).unwrap();
+        assert_eq!(variants.len(), 2);
+
+        let data = &variants[1];
+        assert_eq!(data.struct_name().to_string(), "Message");
+        assert_eq!(data.variant_name().unwrap().to_string(), "Data");
+        assert_eq!(data.builder_base_name(), "MessageData");
+        assert_eq!(data.constructor_path().to_string(), "Message :: Data");
+        assert_eq!(data.required_fields().len(), 1);
+        assert_eq!(data.struct_attributes().get_build_method_name(), "create");
+        assert!(variants[0].all_fields().next().is_none());
+
+        let struct_input: DeriveInput = parse_quote!(
+            struct Example {
+                name: String,
+            }
+        );
+        let analysis = analyze_struct(&struct_input).unwrap();
+        assert_eq!(analysis.builder_base_name(), "Example");
+        assert_eq!(analysis.constructor_path().to_string(), "Example");
+    }
+
+    #[test]
+    fn test_analyze_enum_errors() {
+        let error = |input: DeriveInput| analyze_enum(&input).unwrap_err().to_string();
+
+        assert!(error(parse_quote!(
+            enum Empty {}
+        ))
+        .contains("does not support enums without variants"));
+        assert!(error(parse_quote!(
+            enum Tuple {
+                A(String),
+            }
+        ))
+        .contains("named fields or no fields"));
+        assert!(error(parse_quote!(
+            #[builder(module = "message_builder")]
+            enum Message {
+                A,
+            }
+        ))
+        .contains("`module` is not supported on enums"));
+        assert!(error(parse_quote!(
+            #[builder(state_aliases)]
+            enum Message {
+                A,
+            }
+        ))
+        .contains("`state_aliases` is not supported on enums"));
+        assert!(error(parse_quote!(
+            enum Message {
+                #[builder(build_method = "create")]
+                A,
+            }
+        ))
+        .contains("not supported on enum variants"));
+        assert!(error(parse_quote!(
+            enum Message {
+                A {
+                    #[builder(required, builder_method)]
+                    id: u32,
+                },
+            }
+        ))
+        .contains("`builder_method` is not supported on enum variants"));
+    }
+
     #[test]
     fn test_token_generation() {
         let input: DeriveInput = parse_quote! {
@@ -540,4 +1153,151 @@ mod tests {
         assert!(!type_generics.is_empty());
         assert!(!where_clause.is_empty());
     }
+
+    #[test]
+    fn test_builder_visibility_in_module() {
+        let visibility_of = |input: DeriveInput| {
+            let visibility = analyze_struct(&input).unwrap().builder_visibility();
+            quote::quote!(#visibility).to_string()
+        };
+
+        let unchanged: DeriveInput = parse_quote! { struct Example { value: u8 } };
+        assert_eq!(visibility_of(unchanged), "");
+
+        let private: DeriveInput = parse_quote! {
+            #[builder(module = "example_builder")]
+            struct Example { value: u8 }
+        };
+        assert_eq!(visibility_of(private), "pub (super)");
+
+        let parent: DeriveInput = parse_quote! {
+            #[builder(module = "example_builder")]
+            pub(super) struct Example { value: u8 }
+        };
+        assert_eq!(visibility_of(parent), "pub (in super :: super)");
+
+        let crate_wide: DeriveInput = parse_quote! {
+            #[builder(module = "example_builder")]
+            pub(crate) struct Example { value: u8 }
+        };
+        assert_eq!(visibility_of(crate_wide), "pub (crate)");
+
+        let crate_path: DeriveInput = parse_quote! {
+            #[builder(module = "example_builder")]
+            pub(in crate::config) struct Example { value: u8 }
+        };
+        assert_eq!(visibility_of(crate_path), "pub (in crate :: config)");
+
+        let grandparent: DeriveInput = parse_quote! {
+            #[builder(module = "example_builder")]
+            pub(in super::super) struct Example { value: u8 }
+        };
+        assert_eq!(
+            visibility_of(grandparent),
+            "pub (in super :: super :: super)"
+        );
+    }
+
+    #[test]
+    fn test_build_visibility() {
+        let visibility_of = |input: DeriveInput| {
+            let visibility = analyze_struct(&input).unwrap().build_visibility();
+            quote::quote!(#visibility).to_string()
+        };
+
+        let unset: DeriveInput = parse_quote! { struct Example { value: u8 } };
+        assert_eq!(visibility_of(unset), "pub");
+
+        let crate_wide: DeriveInput = parse_quote! {
+            #[builder(build_vis = "pub(crate)")]
+            pub struct Example { value: u8 }
+        };
+        assert_eq!(visibility_of(crate_wide), "pub (crate)");
+
+        let private: DeriveInput = parse_quote! {
+            #[builder(build_vis = "pub(self)")]
+            pub struct Example { value: u8 }
+        };
+        assert_eq!(visibility_of(private), "");
+
+        let parent_in_module: DeriveInput = parse_quote! {
+            #[builder(module = "example_builder", build_vis = "pub(super)")]
+            pub struct Example { value: u8 }
+        };
+        assert_eq!(visibility_of(parent_in_module), "pub (in super :: super)");
+    }
+
+    #[test]
+    fn test_entry_and_nested_build_visibility() {
+        let visibilities_of = |input: DeriveInput| {
+            let analysis = analyze_struct(&input).unwrap();
+            let entry = analysis.entry_visibility();
+            let nested = analysis.nested_build_visibility();
+            (
+                quote::quote!(#entry).to_string(),
+                quote::quote!(#nested).to_string(),
+            )
+        };
+
+        let unset: DeriveInput = parse_quote! { pub struct Example { value: u8 } };
+        assert_eq!(visibilities_of(unset), ("pub".into(), "pub".into()));
+
+        let entry_only: DeriveInput = parse_quote! {
+            #[builder(entry_vis = "pub(crate)")]
+            pub struct Example { value: u8 }
+        };
+        assert_eq!(
+            visibilities_of(entry_only),
+            ("pub (crate)".into(), "pub (crate)".into())
+        );
+
+        let both: DeriveInput = parse_quote! {
+            #[builder(entry_vis = "pub(crate)", build_vis = "pub(super)")]
+            pub struct Example { value: u8 }
+        };
+        assert_eq!(
+            visibilities_of(both),
+            ("pub (crate)".into(), "pub (super)".into())
+        );
+
+        let disjoint: DeriveInput = parse_quote! {
+            #[builder(module = "example_builder", entry_vis = "pub(super)", build_vis = "pub(in crate::a)")]
+            pub struct Example { value: u8 }
+        };
+        assert_eq!(
+            visibilities_of(disjoint),
+            ("pub (in super :: super)".into(), "pub (super)".into())
+        );
+    }
+
+    #[test]
+    fn test_normalize_visibility() {
+        let normalized = |visibility: syn::Visibility| {
+            let visibility = normalize_visibility(&visibility);
+            quote::quote!(#visibility).to_string()
+        };
+
+        assert_eq!(normalized(parse_quote!(pub(self))), "");
+        assert_eq!(normalized(parse_quote!(pub(in self))), "");
+        assert_eq!(normalized(parse_quote!(pub(in crate))), "pub (crate)");
+        assert_eq!(normalized(parse_quote!(pub(in super))), "pub (super)");
+        assert_eq!(
+            normalized(parse_quote!(pub(in crate::config))),
+            "pub (in crate :: config)"
+        );
+        assert_eq!(normalized(parse_quote!(pub)), "pub");
+
+        // In a builder module, `pub(in self)` reaches the struct's module again
+        let input: DeriveInput = parse_quote! {
+            #[builder(module = "example_builder")]
+            pub(in self) struct Example { value: u8 }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let visibility = analysis.builder_visibility();
+        assert_eq!(quote::quote!(#visibility).to_string(), "pub (super)");
+        assert!(matches!(
+            analysis.struct_visibility(),
+            syn::Visibility::Inherited
+        ));
+    }
 }
@@ -7,12 +7,47 @@
 //! # Supported Field Attributes
 //!
 //! - `required` - Marks a field as required in the builder pattern
+//! - `required(cfg(predicate))` - Marks a field as required only when the predicate holds
 //! - `setter_name = "name"` - Specifies a custom name for the setter method
+//! - `alias = "name"` - Adds a `#[doc(alias)]` to the setter, e.g. for an old name; may be repeated
 //! - `setter_prefix = "prefix_"` - Specifies a custom prefix for the setter method
-//! - `default = "expression"` - Provides a custom default value expression
+//! - `default = expression` - Provides a custom default value expression, e.g. `default = 42`
+//! - `default = serde` - Uses the field's `#[serde(default = "path")]` function as the default
 //! - `skip_setter` - Prevents generation of a setter method for this field
 //! - `impl_into` - Uses `impl Into<FieldType>` parameters for ergonomic setters
 //! - `converter = |value: InputType| expression` - Custom conversion logic using closures
+//! - `converter = std_converters::name` - A built-in converter (`std-converters` feature)
+//! - `builder_method` - Uses this field's setter as the builder entry point
+//! - `transparent` - Required `Option<T>` field whose setter accepts `None`
+//! - `into_iter` - `Vec<T>` setter accepts `impl IntoIterator<Item = impl Into<T>>`
+//! - `nested` - Adds a `{setter}_with` method that configures the field through its own builder
+//! - `nested_each = "add_child"` - Adds a method that builds one element of a `Vec<Child>` field through its own builder and pushes it
+//! - `optional` - Keeps the field optional under struct-level `all_required`
+//! - `skip_debug` - Leaves the field out of the builder's `Debug` output
+//! - `skip_env` - Leaves the field out of the struct-level `apply_env` method
+//! - `redact` - Prints the field as `"***"` in the builder's `Debug` output
+//! - `sensitive` - Zeroizes the value held by the builder on drop (`zeroize` feature)
+//! - `cfg(predicate)` - Only generates the setter when the predicate holds
+//! - `resolve = |value, other_field| expression` - Transforms the value at build time using other fields
+//! - `lazy_setter` - Adds a `{setter}_with` method taking a closure that is only run at build time
+//! - `default_from = |builder| expression` - Computes the default at build time from other fields
+//! - `computed = |builder| expression` - Always computes the value at build time, without a setter
+//! - `try_setter` - Adds a `try_{setter}` method accepting any value the field type implements `TryFrom` for
+//! - `setter(also_ref)` - Adds a `{setter}_ref` method taking `&FieldType` and cloning the value
+//! - `clone_from_ref` - Setter takes `&FieldType` (`&str` for `String`, `&[T]` for `Vec<T>`) and stores an owned copy
+//! - `wrap = InnerType` / `wrap(InnerType, with = path)` - Setter takes the inner type of a newtype field and wraps it
+//! - `dyn_into` - Setter of a `Box<dyn Trait>`, `Rc<dyn Trait>` or `Arc<dyn Trait>` field takes `impl Trait` and wraps it
+//! - `setter_input = "Type"` / `setter_input = "Type", via = TryFrom` - Setter takes `Type` and converts it with `From` or `TryFrom`
+//! - `test_default = expression` - The value of a required field in the struct-level `test_builder()`
+//! - `boxed_field` - Keeps the value in a `Box` while the builder moves between states
+//! - `default_through_converter` - The `default` is written in the converter's input type and converted
+//! - `builder_only` - Keeps the field on the builder only, removing it from the struct (`#[type_state_builder]` only)
+//! - `accumulate = |acc, value: InputType| statement` - Setter adds its input to the value instead of replacing it
+//! - `validate = |value: &Type| condition` - Checks the value in the build method, which then returns a `Result`
+//! - `non_empty` - Checks in the build method that the value's `is_empty()` is false
+//! - `range = start..=end` - Checks in the build method that the value lies in the range
+//! - `validate_message = "message"` - The message of the error returned when `validate`, `non_empty` or `range` fails
+//! - `validate_on_set` - Runs the field's checks in its setter, which then returns a `Result`
 //!
 //! # Attribute Validation
 //!
@@ -20,9 +55,51 @@
 //! - Required fields cannot have default values
 //! - Required fields cannot skip setter generation
 //! - Fields that skip setters must have default values
-//! - `converter` is incompatible with `skip_setter` and `impl_into`
+//! - `converter` is incompatible with `skip_setter`
 //! - `impl_into` is incompatible with `skip_setter`
 //! - Setter prefixes are incompatible with `skip_setter`
+//! - `transparent` requires `required` and is incompatible with `converter` and `impl_into`
+//! - `into_iter` is incompatible with `skip_setter` and `converter`
+//! - `optional` is incompatible with `required`
+//! - `nested` is incompatible with `skip_setter`, `converter`, `transparent`, `into_iter`
+//!   and `builder_method`
+//! - `lazy_setter` is incompatible with `skip_setter`, `nested`, `builder_method`,
+//!   `sensitive` and `resolve`
+//! - `default_from` is incompatible with `required`, `default`, `skip_setter`,
+//!   `builder_method`, `sensitive`, `resolve` and `lazy_setter`
+//! - `computed` implies `skip_setter` and is incompatible with every attribute that
+//!   configures a setter or provides the value some other way
+//! - `try_setter` is incompatible with `skip_setter`, `converter` and `builder_method`
+//! - `setter(also_ref)` is incompatible with `skip_setter`, `converter`, `builder_method`,
+//!   `accumulate` and `validate_on_set`
+//! - `clone_from_ref` is incompatible with `skip_setter`, `converter`, `impl_into`, `into_iter`,
+//!   `accumulate` and `setter(also_ref)`
+//! - `wrap` is incompatible with `skip_setter`, `converter`, `transparent`, `into_iter`, `nested`,
+//!   `try_setter`, `setter(also_ref)`, `clone_from_ref` and `accumulate`
+//! - `dyn_into` is incompatible with `skip_setter`, `converter`, `wrap`, `impl_into`, `into_iter`,
+//!   `transparent`, `nested`, `try_setter`, `setter(also_ref)`, `clone_from_ref` and `accumulate`
+//! - `setter_input` is incompatible with `skip_setter`, `converter`, `wrap`, `dyn_into`, `transparent`,
+//!   `into_iter`, `nested`, `try_setter`, `setter(also_ref)`, `clone_from_ref` and `accumulate`;
+//!   `via = TryFrom` also with `impl_into`, `builder_method`, `validate_on_set` and
+//!   `default_through_converter`, and `via` requires `setter_input`
+//! - `builder_only` is incompatible with `skip_setter` and `computed`
+//! - `required(cfg(...))` is incompatible with `required`, `optional`, `transparent`,
+//!   `builder_method`, `skip_setter`, `cfg`, `default_from`, `computed`, `accumulate`,
+//!   `nested_each` and `test_default`
+//! - `boxed_field` is incompatible with `computed`, which holds no value
+//! - `default_through_converter` requires `default` and `converter` or `wrap`
+//! - `validate`, `non_empty` and `range` are incompatible with `builder_only`, and
+//!   `validate_message` requires one of them
+//! - `range` must be a range expression with at least one bound
+//! - `non_empty` cannot be used on `Option<T>` fields, which have no `is_empty()`
+//! - `nested_each` only applies to optional fields whose value the builder holds as is, so it is
+//!   incompatible with `required`, `skip_setter`, `builder_method`, `sensitive`, `lazy_setter`,
+//!   `default_from` and `validate_on_set`
+//! - `validate_on_set` requires `validate`, `non_empty` or `range` and a plain setter, so it is
+//!   incompatible with `builder_method`, `nested`, `lazy_setter`, `try_setter` and `accumulate`
+//! - `accumulate` only applies to optional fields with a plain setter, so it is
+//!   incompatible with `required` and every attribute that replaces the setter or
+//!   how the builder stores the value
 //!
 //! # Converter Attribute
 //!
@@ -30,6 +107,9 @@
 //! It generates a setter that accepts a custom input type and converts it using
 //! the provided closure expression.
 
+use crate::attributes::StructAttributes;
+use crate::utils::field_utils::extract_accumulator_parameter_type;
+use crate::utils::std_converters::{check_std_converter, std_converter_name};
 use crate::validation::error_messages::ErrorMessages;
 
 /// Configuration derived from field-level builder attributes.
@@ -49,7 +129,6 @@ use crate::validation::error_messages::ErrorMessages;
 /// The following combinations are invalid and will cause compilation errors:
 /// - `required = true` and `default_value.is_some()` - Required fields can't have defaults
 /// - `required = true` and `skip_setter = true` - Required fields need setters
-/// - `skip_setter = true` and `default_value.is_none()` - Skipped setters need defaults
 ///
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FieldAttributes {
@@ -62,6 +141,14 @@ pub struct FieldAttributes {
     /// - Prevent build() until set
     pub required: bool,
 
+    /// Configuration predicate under which the field is required, e.g. `feature = "strict"`.
+    ///
+    /// `#[builder(required(cfg(predicate)))]` leaves `required` unset: the macro
+    /// generates the builder once with the field required, under
+    /// `#[cfg(predicate)]`, and once with the field optional, under
+    /// `#[cfg(not(predicate))]`. A `default` only applies to the optional side.
+    pub required_cfg: Option<syn::Meta>,
+
     /// Custom name for the setter method.
     ///
     /// If None, the setter method will use the field name (including raw identifiers).
@@ -69,6 +156,14 @@ pub struct FieldAttributes {
     ///
     pub setter_name: Option<String>,
 
+    /// Additional search names of the setter method.
+    ///
+    /// Each `#[builder(alias = "name")]` adds a `#[doc(alias = "name")]` to
+    /// the setter, next to the field name that every prefixed or renamed
+    /// setter gets, so rustdoc search and editors also find the setter under
+    /// names it had in earlier versions.
+    pub aliases: Vec<String>,
+
     /// Custom prefix for the setter method name.
     ///
     /// If None, the setter method uses its natural name (field name or custom setter_name).
@@ -108,7 +203,7 @@ pub struct FieldAttributes {
     ///
     /// When true:
     /// - No setter method is generated
-    /// - The field must have a default value (either custom or Default::default())
+    /// - The field starts from its `default`, or `Default::default()` without one
     /// - The field is initialized in the constructor and cannot be changed
     ///
     /// This is useful for:
@@ -134,6 +229,13 @@ pub struct FieldAttributes {
     /// This attribute is mutually exclusive with `skip_setter`. Fields that skip
     /// setter generation cannot specify `impl_into` behavior.
     ///
+    /// # Interaction with converter
+    ///
+    /// With a `converter`, `Some(true)` makes the setter accept
+    /// `impl Into<I>`, where `I` is the converter's parameter type, and the
+    /// converter runs on the converted value. Struct-level `impl_into` does not
+    /// change converter setters.
+    ///
     /// See the crate-level documentation for comprehensive usage examples.
     pub impl_into: Option<bool>,
 
@@ -147,8 +249,9 @@ pub struct FieldAttributes {
     ///
     /// # Interaction with other attributes
     ///
-    /// This attribute is mutually exclusive with `impl_into` and `skip_setter`.
-    /// It is compatible with `required`, `setter_name`, `setter_prefix`, and `default`.
+    /// This attribute is mutually exclusive with `skip_setter`. It is compatible
+    /// with `required`, `setter_name`, `setter_prefix`, `default`, and a
+    /// field-level `impl_into`, which converts the input before the closure runs.
     pub converter: Option<syn::Expr>,
 
     /// Whether this field's setter should be the builder entry point.
@@ -160,6 +263,278 @@ pub struct FieldAttributes {
     /// Only one field per struct can have `builder_method`. The field must be
     /// required and cannot be combined with `skip_setter`.
     pub builder_method: bool,
+
+    /// Whether a required `Option<T>` field is set through an `Option<T>` parameter.
+    ///
+    /// When true, the caller must call the setter before building but may pass
+    /// `None` explicitly. The setter always takes `Option<T>` as-is, even when
+    /// struct-level `impl_into` is enabled, so `None` needs no type annotation.
+    ///
+    /// Requires `required` and cannot be combined with `impl_into` or `converter`.
+    /// The field type must be `Option<T>`.
+    pub transparent: bool,
+
+    /// Whether a `Vec<T>` setter accepts `impl IntoIterator<Item = impl Into<T>>`.
+    ///
+    /// - `None` - Inherit from struct-level `into_iter` setting
+    /// - `Some(true)` - Collect any iterable of convertible items
+    /// - `Some(false)` - Use the regular or `impl_into` setter
+    ///
+    /// Takes precedence over `impl_into`. Incompatible with `skip_setter` and
+    /// `converter`. The field type must be `Vec<T>`.
+    pub into_iter: Option<bool>,
+
+    /// Whether the field's type derives `TypeStateBuilder` and gets a closure setter.
+    ///
+    /// When true, a `{setter}_with` method is generated next to the regular
+    /// setter. It passes a fresh builder of the field's type to a closure and
+    /// builds whatever complete builder the closure returns, e.g.
+    /// `.address_with(|b| b.city("Paris").zip("75001"))`.
+    ///
+    /// Incompatible with `skip_setter`, `converter`, `transparent`, `into_iter`
+    /// and `builder_method`.
+    pub nested: bool,
+
+    /// Name of a method that builds one element of a collection field and adds it.
+    ///
+    /// Set by `#[builder(nested_each = "add_child")]` on a field like
+    /// `Vec<Child>` whose element type derives `TypeStateBuilder`. The method
+    /// passes a fresh element builder to a closure, builds the complete builder
+    /// it returns and pushes the element, e.g.
+    /// `.add_child(|b| b.name("a")).add_child(|b| b.name("b"))`. The regular
+    /// setter is kept.
+    pub nested_each: Option<String>,
+
+    /// Whether the field stays optional under struct-level `all_required`.
+    ///
+    /// Fields with a `default` or `skip_setter` are already optional, so this is
+    /// only needed for fields that fall back to `Default::default()`.
+    /// Incompatible with `required`.
+    pub optional: bool,
+
+    /// Whether to leave this field out of the builder's `Debug` output.
+    ///
+    /// Fields whose type does not implement `Debug` already print as a
+    /// placeholder; use this for fields whose values should not be printed,
+    /// or that are too large to be useful in debug output.
+    pub skip_debug: bool,
+
+    /// Whether the struct-level `apply_env` method leaves this field alone.
+    ///
+    /// Needed for optional fields whose setter input does not implement
+    /// `FromStr`, such as collections, or that should never come from the
+    /// environment.
+    pub skip_env: bool,
+
+    /// Whether to print this field as `"***"` in the builder's `Debug` output.
+    ///
+    /// Use this for secrets such as passwords and tokens: the field still
+    /// shows up, so it is visible whether a value is present in the output,
+    /// but its value is never formatted. Incompatible with `skip_debug`.
+    pub redact: bool,
+
+    /// Whether the builder holds this field in `zeroize::Zeroizing`.
+    ///
+    /// Builder states wipe the value when they are dropped without being
+    /// built, and the field is redacted in `Debug` output. Requires the
+    /// `zeroize` feature, and the field type must implement `Zeroize` and
+    /// `Default`. Incompatible with `resolve` and const builders.
+    pub sensitive: bool,
+
+    /// Configuration predicate gating the field's setter, e.g. `feature = "metrics"`.
+    ///
+    /// The setter is emitted under `#[cfg(predicate)]`; when the predicate does
+    /// not hold, the field keeps its default value. Only optional fields can be
+    /// gated, since required fields are part of the builder's state machine.
+    /// Incompatible with `required` and `skip_setter`.
+    pub cfg: Option<syn::Meta>,
+
+    /// Build-time transform that can read other fields of the builder.
+    ///
+    /// The closure receives the field's value followed by references to other
+    /// fields, selected by parameter name, e.g.
+    /// `|path: PathBuf, base_dir: &PathBuf| base_dir.join(path)`. It runs in the
+    /// build method, once every field is known, and returns the final value.
+    /// Referenced fields are passed as set, before their own `resolve` (if any)
+    /// would run, so they cannot have a `resolve` themselves.
+    pub resolve: Option<syn::Expr>,
+
+    /// Whether to generate a `{setter}_with` method that defers computing the value.
+    ///
+    /// The method takes an `impl FnOnce() -> T + 'static` closure that only runs
+    /// in the build method, so an expensive value is never computed when a later
+    /// setter call overrides it. The builder boxes the closure, so the field is
+    /// stored as `Result<T, Box<dyn FnOnce() -> T>>`.
+    pub lazy_setter: bool,
+
+    /// Default computed in the build method when the setter was never called.
+    ///
+    /// The closure receives a reference to the complete builder, e.g.
+    /// `|b| b.max_connections * 2`, so it can read any other field as the
+    /// builder holds it. The field is stored as `Option<T>` until it is built.
+    pub default_from: Option<syn::Expr>,
+
+    /// Value always computed in the build method; the field has no setter.
+    ///
+    /// Like `default_from`, the closure receives a reference to the complete
+    /// builder, e.g. `|b| hash(&b.name)`, but it runs unconditionally.
+    pub computed: Option<syn::Expr>,
+
+    /// Whether to generate a fallible `try_{setter}` method.
+    ///
+    /// The method accepts any `V` for which the field type implements
+    /// `TryFrom<V>` and returns the conversion error instead of the next
+    /// builder, e.g. a `Vec<T>` of the wrong length for a `[T; N]` field.
+    pub try_setter: bool,
+
+    /// Whether to generate a `{setter}_ref` method taking the value by reference.
+    ///
+    /// Set by `#[builder(setter(also_ref))]`. The method clones the value and
+    /// passes it to the regular setter, so callers can keep a large value they
+    /// reuse, e.g. across builders created in a loop.
+    pub also_ref: bool,
+
+    /// Whether the setter borrows its argument and stores an owned copy.
+    ///
+    /// Set by `#[builder(clone_from_ref)]`. `String` fields take `&str`,
+    /// `Vec<T>` fields take `&[T]` and other fields take `&FieldType`, for
+    /// callers that only hold a reference. `impl_into` cannot express this,
+    /// as `&T: Into<T>` generally does not hold.
+    pub clone_from_ref: bool,
+
+    /// Inner type and constructor of a newtype field whose setter wraps its argument.
+    ///
+    /// Set by `#[builder(wrap = u64)]` or `#[builder(wrap(u64, with = UserId::new))]`.
+    /// The setter takes the inner type and passes it to the constructor, which
+    /// defaults to the field type's tuple constructor, so `.id(5)` replaces
+    /// `.id(UserId(5))`. It becomes a `converter` when the field is analyzed.
+    pub wrap: Option<Wrap>,
+
+    /// The type the setter takes instead of the field type.
+    ///
+    /// Set by `#[builder(setter_input = "u32")]`. The setter converts its
+    /// argument with `From`, or with `TryFrom` under `via = TryFrom`, in which
+    /// case it returns the conversion error. A `From` input becomes a
+    /// `converter` when the field is analyzed.
+    pub setter_input: Option<syn::Type>,
+
+    /// The trait converting the `setter_input` into the field type.
+    ///
+    /// Set by `#[builder(via = From)]` or `#[builder(via = TryFrom)]` (None = `From`).
+    pub via: Option<SetterInputVia>,
+
+    /// Whether the setter of a trait object field takes the concrete value.
+    ///
+    /// Set by `#[builder(dyn_into)]` on a `Box<dyn Trait>`, `Rc<dyn Trait>` or
+    /// `Arc<dyn Trait>` field, optionally inside an `Option`. The setter takes
+    /// `impl Trait + 'static` and allocates the pointer itself, so callers
+    /// write `.handler(Logger)` instead of `.handler(Arc::new(Logger))`.
+    pub dyn_into: bool,
+
+    /// Value of a required field in the builder returned by `test_builder()`.
+    ///
+    /// Set by `#[builder(test_default = expression)]` on required fields of
+    /// structs with the struct-level `test_default`, for field types without
+    /// a `Default` implementation or whose default is not a useful fixture.
+    pub test_default: Option<syn::Expr>,
+
+    /// Whether the builder keeps the field's value on the heap.
+    ///
+    /// Set by `#[builder(boxed_field)]` on large fields, so every builder state
+    /// stores a `Box` and the setters move a pointer instead of the value. The
+    /// build method moves the value out of the box, so the struct is unchanged.
+    pub boxed_field: bool,
+
+    /// Whether the `default` is written in the converter's input type.
+    ///
+    /// Set by `#[builder(default_through_converter)]` on fields with a
+    /// `default` and a `converter` (or `wrap`). The default passes through
+    /// the converter like a setter argument, so `default = "8 KiB"` can sit
+    /// next to `converter = |size: &str| parse_size(size)`.
+    pub default_through_converter: bool,
+
+    /// Whether the field only exists on the builder.
+    ///
+    /// The field gets a setter like any other, but the build method leaves it
+    /// out, so it can only feed the `computed`, `default_from` and `resolve`
+    /// closures of other fields. Removing a field from the struct requires the
+    /// `#[type_state_builder]` attribute macro; the derive rejects it.
+    pub builder_only: bool,
+
+    /// Closure that adds the setter's input to the value held by the builder.
+    ///
+    /// The closure receives a mutable reference to the field and the setter's
+    /// argument, e.g. `|tags, tag: &str| tags.push(tag.to_string())`, so each
+    /// call extends the value instead of replacing it. The setter's parameter
+    /// type is taken from the closure's second parameter, which must be typed.
+    pub accumulate: Option<syn::Expr>,
+
+    /// Closure checking the field's final value in the build method.
+    ///
+    /// The closure receives a reference to the value, e.g.
+    /// `|port: &u16| *port != 0`, and returns whether it is valid. Any
+    /// validated field makes the build method return a `Result` whose error
+    /// names the first field that failed.
+    pub validate: Option<syn::Expr>,
+
+    /// Whether the build method checks that the field's value is not empty.
+    ///
+    /// Shorthand for `validate = |value| !value.is_empty()`, for strings,
+    /// collections and any other type with an `is_empty` method.
+    pub non_empty: bool,
+
+    /// Range the field's value must lie in, checked in the build method.
+    ///
+    /// Any range expression with at least one bound, e.g. `1..=100` or
+    /// `0.0..`, checked with the range's `contains` method.
+    pub range: Option<syn::ExprRange>,
+
+    /// The message of the error returned when `validate`, `non_empty` or
+    /// `range` rejects the value.
+    pub validate_message: Option<syn::LitStr>,
+
+    /// Whether the field's checks run in its setter instead of the build method.
+    ///
+    /// The setter returns `Result<NextState, {Struct}ValidationError>`, so a
+    /// rejected value is reported when it is entered. The checks are not
+    /// repeated at build time, and defaults are not checked.
+    pub validate_on_set: bool,
+
+    /// Whether the build method takes the value from the struct-level `base`
+    /// when the setter was never called.
+    ///
+    /// Not written on fields: struct-level `base` sets it on every optional
+    /// field without a default of its own. The field is stored as `Option<T>`
+    /// until it is built, like a `default_from` field.
+    pub from_base: bool,
+
+    /// Whether the builder tracks if the field was set, for struct-level `fill_from`.
+    ///
+    /// Not written on fields: struct-level `fill_from` sets it on every
+    /// optional field that holds its value as is and is not already tracked
+    /// through `default_from` or `base`. The field is stored as `Option<T>`
+    /// and falls back to its default in the build method.
+    pub fill_from: bool,
+}
+
+/// Inner type and constructor given with `#[builder(wrap = ...)]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wrap {
+    /// The type the setter takes
+    pub inner: syn::Type,
+
+    /// Function or tuple struct that builds the field value (None = the field type's own)
+    pub constructor: Option<syn::Path>,
+}
+
+/// Conversion trait given with `#[builder(via = ...)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetterInputVia {
+    /// The setter converts with `From` and cannot fail
+    From,
+
+    /// The setter converts with `TryFrom` and returns the conversion error
+    TryFrom,
 }
 
 impl Default for FieldAttributes {
@@ -167,18 +542,160 @@ impl Default for FieldAttributes {
     fn default() -> Self {
         Self {
             required: false,
+            required_cfg: None,
             setter_name: None,
+            aliases: Vec::new(),
             setter_prefix: None,
             default_value: None,
             skip_setter: false,
             impl_into: None,
             converter: None,
             builder_method: false,
+            transparent: false,
+            into_iter: None,
+            nested: false,
+            nested_each: None,
+            optional: false,
+            skip_debug: false,
+            skip_env: false,
+            redact: false,
+            sensitive: false,
+            cfg: None,
+            resolve: None,
+            lazy_setter: false,
+            default_from: None,
+            computed: None,
+            try_setter: false,
+            also_ref: false,
+            clone_from_ref: false,
+            wrap: None,
+            setter_input: None,
+            via: None,
+            dyn_into: false,
+            test_default: None,
+            boxed_field: false,
+            default_through_converter: false,
+            builder_only: false,
+            accumulate: None,
+            validate: None,
+            non_empty: false,
+            range: None,
+            validate_message: None,
+            validate_on_set: false,
+            from_base: false,
+            fill_from: false,
         }
     }
 }
 
 impl FieldAttributes {
+    /// Applies struct-level `all_required` to this field.
+    ///
+    /// The field becomes required unless it is marked `optional`, has a custom
+    /// `default` or `default_from`, skips its setter, has a `cfg`-gated setter,
+    /// accumulates from its default, or is only required under a `cfg` predicate.
+    fn apply_all_required(&mut self) {
+        if !self.optional
+            && self.required_cfg.is_none()
+            && self.default_value.is_none()
+            && self.default_from.is_none()
+            && self.computed.is_none()
+            && self.accumulate.is_none()
+            && self.nested_each.is_none()
+            && !self.skip_setter
+            && self.cfg.is_none()
+        {
+            self.required = true;
+        }
+    }
+
+    /// Applies struct-level `base` to this field.
+    ///
+    /// The build method takes the field from the base unless it is required,
+    /// has a custom `default`, `default_from` or `computed`, is builder-only,
+    /// accumulates its value, or holds its value in a way of its own
+    /// (`lazy_setter`, `sensitive` and `resolve`).
+    fn apply_base(&mut self) {
+        if !self.required
+            && self.default_value.is_none()
+            && self.default_from.is_none()
+            && self.computed.is_none()
+            && !self.builder_only
+            && self.accumulate.is_none()
+            && self.nested_each.is_none()
+            && !self.lazy_setter
+            && !self.sensitive
+            && self.resolve.is_none()
+        {
+            self.from_base = true;
+        }
+    }
+
+    /// Applies struct-level `fill_from` to this field.
+    ///
+    /// The builder tracks whether the field was set unless it is required,
+    /// already tracked through `default_from` or `base`, has no value of its
+    /// own (`computed`), is builder-only, or changes its value in place
+    /// (`accumulate` and `nested_each`) or holds it in a way of its own
+    /// (`lazy_setter`, `sensitive` and `resolve`).
+    fn apply_fill_from(&mut self) {
+        if !self.required
+            && !self.from_base
+            && self.default_from.is_none()
+            && self.computed.is_none()
+            && !self.builder_only
+            && self.accumulate.is_none()
+            && self.nested_each.is_none()
+            && !self.lazy_setter
+            && !self.sensitive
+            && self.resolve.is_none()
+        {
+            self.fill_from = true;
+        }
+    }
+
+    /// Returns `true` if the builder holds the field as `Option<T>` until it is
+    /// built, so it knows whether the field was set.
+    pub fn tracks_unset(&self) -> bool {
+        self.default_from.is_some() || self.from_base || self.fill_from
+    }
+
+    /// Resolves `required(cfg(...))` for one side of the predicate.
+    ///
+    /// When the predicate holds, the field becomes required and drops the
+    /// `default` and `base` fallback it only has while optional. Either way the
+    /// predicate is cleared, so the field is treated like any other.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether the field's `required` predicate holds
+    pub fn apply_required_cfg(&mut self, enabled: bool) {
+        if self.required_cfg.take().is_some() && enabled {
+            self.required = true;
+            self.default_value = None;
+            self.from_base = false;
+            self.fill_from = false;
+        }
+    }
+
+    /// Returns the first attribute that checks the field's value at build time.
+    ///
+    /// # Returns
+    ///
+    /// `"validate"`, `"non_empty"` or `"range"`, or `None` if the field's value
+    /// is not checked.
+    pub fn validation_attribute(&self) -> Option<&'static str> {
+        if self.validate.is_some() {
+            Some("validate")
+        } else if self.non_empty {
+            Some("non_empty")
+        } else if self.range.is_some() {
+            Some("range")
+        } else {
+            None
+        }
+    }
+
     /// Validates that the field attributes are consistent and valid.
     ///
     /// This method checks that all field-level attributes have valid values
@@ -196,7 +713,6 @@ impl FieldAttributes {
     /// - Setter prefixes are valid identifier beginnings
     /// - Setter names are valid identifiers when provided
     /// - Setter function and skip_setter are mutually exclusive
-    /// - Setter function and impl_into are mutually exclusive
     /// - No duplicate setter functions
     ///
     /// # Errors
@@ -207,9 +723,95 @@ impl FieldAttributes {
     /// - Invalid setter prefix format
     /// - Invalid setter names
     /// - setter function combined with skip_setter
-    /// - setter function combined with impl_into
     /// - duplicate setter attributes
     pub fn validate(&self) -> syn::Result<()> {
+        // Validate that optional and required are mutually exclusive
+        if self.optional && self.required {
+            return Err(ErrorMessages::structured_error_span(
+                proc_macro2::Span::call_site(),
+                "Field-level optional is incompatible with required",
+                Some("#[builder(optional)] keeps a field optional under #[builder(all_required)]"),
+                Some("remove one of these attributes"),
+            ));
+        }
+
+        // Validate that conditionally required fields are plain fields on either side
+        if self.required_cfg.is_some() {
+            let conflict = if self.required {
+                Some("required")
+            } else if self.optional {
+                Some("optional")
+            } else if self.transparent {
+                Some("transparent")
+            } else if self.builder_method {
+                Some("builder_method")
+            } else if self.skip_setter {
+                Some("skip_setter")
+            } else if self.cfg.is_some() {
+                Some("cfg")
+            } else if self.default_from.is_some() {
+                Some("default_from")
+            } else if self.computed.is_some() {
+                Some("computed")
+            } else if self.accumulate.is_some() {
+                Some("accumulate")
+            } else if self.nested_each.is_some() {
+                Some("nested_each")
+            } else if self.test_default.is_some() {
+                Some("test_default")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(ErrorMessages::structured_error_span(
+                    proc_macro2::Span::call_site(),
+                    &format!("Field-level required(cfg(...)) is incompatible with {conflict}"),
+                    Some("#[builder(required(cfg(...)))] fields are required when the predicate holds and optional otherwise"),
+                    Some("remove one of these attributes"),
+                ));
+            }
+        }
+
+        // Validate that redact and skip_debug are mutually exclusive
+        if self.redact && self.skip_debug {
+            return Err(ErrorMessages::structured_error_span(
+                proc_macro2::Span::call_site(),
+                "Field-level redact is incompatible with skip_debug",
+                Some("#[builder(redact)] masks the field's value, #[builder(skip_debug)] omits the field"),
+                Some("remove one of these attributes"),
+            ));
+        }
+
+        // Validate that sensitive values are not moved out of the builder before build
+        if self.sensitive && self.resolve.is_some() {
+            return Err(ErrorMessages::structured_error_span(
+                proc_macro2::Span::call_site(),
+                "Field-level sensitive is incompatible with resolve",
+                Some("#[builder(sensitive)] values stay wrapped in `Zeroizing` until the struct is built"),
+                Some("remove one of these attributes"),
+            ));
+        }
+
+        // Validate that cfg only gates setters of optional fields
+        if self.cfg.is_some() && self.required {
+            return Err(ErrorMessages::structured_error_span(
+                proc_macro2::Span::call_site(),
+                "Field-level cfg is incompatible with required",
+                Some("required fields are part of the builder's state machine, which cannot depend on cfg predicates"),
+                Some("remove `required` so the field falls back to its default when the setter is compiled out"),
+            ));
+        }
+
+        // Validate that cfg has a setter to gate
+        if self.cfg.is_some() && self.skip_setter {
+            return Err(ErrorMessages::structured_error_span(
+                proc_macro2::Span::call_site(),
+                "Field-level cfg is incompatible with skip_setter",
+                Some("#[builder(cfg(...))] gates the field's setter, but skip_setter removes it"),
+                Some("remove one of these attributes"),
+            ));
+        }
+
         // Validate that setter_prefix and skip_setter are mutually exclusive
         if self.setter_prefix.is_some() && self.skip_setter {
             return Err(ErrorMessages::structured_error_span(
@@ -240,100 +842,698 @@ impl FieldAttributes {
             ));
         }
 
-        // Validate that converter and impl_into are mutually exclusive
-        if self.converter.is_some() && self.impl_into.is_some() {
+        // Validate that builder_method and skip_setter are mutually exclusive
+        if self.builder_method && self.skip_setter {
+            return Err(ErrorMessages::structured_error_span(
+                proc_macro2::Span::call_site(),
+                "Field-level builder_method is incompatible with skip_setter",
+                Some("#[builder(builder_method)] and #[builder(skip_setter)] are incompatible"),
+                Some("remove one of these attributes"),
+            ));
+        }
+
+        // Validate that transparent is only used on required fields
+        if self.transparent && !self.required {
             return Err(ErrorMessages::structured_error_span(
                 proc_macro2::Span::call_site(),
-                "Field-level converter is incompatible with impl_into",
-                Some("#[builder(converter)] and #[builder(impl_into)] are incompatible"),
-                Some("use either custom converter or impl_into, not both"),
+                "Field-level transparent requires required",
+                Some("#[builder(transparent)] marks a required `Option<T>` field that may be set to `None`"),
+                Some("add `required` or remove `transparent`"),
             ));
         }
 
-        // Validate that builder_method and skip_setter are mutually exclusive
-        if self.builder_method && self.skip_setter {
+        // Validate that transparent and converter are mutually exclusive
+        if self.transparent && self.converter.is_some() {
             return Err(ErrorMessages::structured_error_span(
                 proc_macro2::Span::call_site(),
-                "Field-level builder_method is incompatible with skip_setter",
-                Some("#[builder(builder_method)] and #[builder(skip_setter)] are incompatible"),
+                "Field-level transparent is incompatible with converter",
+                Some("#[builder(transparent)] setters take the `Option<T>` field type as-is"),
                 Some("remove one of these attributes"),
             ));
         }
 
-        // Validate setter prefix if provided
-        if let Some(setter_prefix) = &self.setter_prefix {
-            if setter_prefix.is_empty() {
-                return Err(syn::Error::new(
+        // Validate that transparent and impl_into are mutually exclusive
+        if self.transparent && self.impl_into.is_some() {
+            return Err(ErrorMessages::structured_error_span(
+                proc_macro2::Span::call_site(),
+                "Field-level transparent is incompatible with impl_into",
+                Some("#[builder(transparent)] setters take the `Option<T>` field type as-is"),
+                Some("remove one of these attributes"),
+            ));
+        }
+
+        // Validate that into_iter and skip_setter are mutually exclusive
+        if self.into_iter.is_some() && self.skip_setter {
+            return Err(ErrorMessages::structured_error_span(
+                proc_macro2::Span::call_site(),
+                "Field-level into_iter is incompatible with skip_setter",
+                Some("#[builder(into_iter)] and #[builder(skip_setter)] are incompatible"),
+                Some("remove one of these attributes"),
+            ));
+        }
+
+        // Validate that into_iter and converter are mutually exclusive
+        if self.into_iter.is_some() && self.converter.is_some() {
+            return Err(ErrorMessages::structured_error_span(
+                proc_macro2::Span::call_site(),
+                "Field-level into_iter is incompatible with converter",
+                Some("#[builder(converter)] and #[builder(into_iter)] are incompatible"),
+                Some("use either custom converter or into_iter, not both"),
+            ));
+        }
+
+        // Validate that nested is not combined with attributes that replace the setter
+        if self.nested {
+            let conflict = if self.skip_setter {
+                Some("skip_setter")
+            } else if self.converter.is_some() {
+                Some("converter")
+            } else if self.transparent {
+                Some("transparent")
+            } else if self.into_iter.is_some() {
+                Some("into_iter")
+            } else if self.builder_method {
+                Some("builder_method")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(ErrorMessages::structured_error_span(
                     proc_macro2::Span::call_site(),
-                    "Setter prefix cannot be empty",
+                    &format!("Field-level nested is incompatible with {conflict}"),
+                    Some("#[builder(nested)] passes the built value to the field's regular setter"),
+                    Some("remove one of these attributes"),
                 ));
             }
+        }
 
-            // Check if the prefix would create valid identifiers when combined with field names
-            // We'll validate this by checking if it starts correctly
-            if setter_prefix.chars().next().is_some_and(|c| c.is_numeric()) {
-                return Err(syn::Error::new(
+        // Validate that nested_each extends the plain value of an optional field
+        if self.nested_each.is_some() {
+            let conflict = if self.required {
+                Some("required")
+            } else if self.skip_setter || self.computed.is_some() {
+                Some("skip_setter")
+            } else if self.builder_method {
+                Some("builder_method")
+            } else if self.sensitive {
+                Some("sensitive")
+            } else if self.lazy_setter {
+                Some("lazy_setter")
+            } else if self.default_from.is_some() {
+                Some("default_from")
+            } else if self.validate_on_set {
+                Some("validate_on_set")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(ErrorMessages::structured_error_span(
                     proc_macro2::Span::call_site(),
-                    format!(
-                        "Invalid setter prefix '{setter_prefix}'. Setter prefixes cannot start with a number. \
-                        Use a valid identifier prefix like 'with_' or 'set_'."
-                    ),
+                    &format!("Field-level nested_each is incompatible with {conflict}"),
+                    Some("#[builder(nested_each)] adds built elements to the collection the builder holds, starting from the field's default"),
+                    Some("remove one of these attributes"),
                 ));
             }
+        }
 
-            // Check for basic identifier validity of prefix
-            // This is a heuristic check - we'll do full validation when combining with field names
-            if !setter_prefix
-                .chars()
-                .all(|c| c.is_alphanumeric() || c == '_')
-            {
-                return Err(syn::Error::new(
+        // Validate that lazy_setter has a regular setter to complement and plain storage
+        if self.lazy_setter {
+            let conflict = if self.skip_setter {
+                Some("skip_setter")
+            } else if self.nested {
+                Some("nested")
+            } else if self.builder_method {
+                Some("builder_method")
+            } else if self.sensitive {
+                Some("sensitive")
+            } else if self.resolve.is_some() {
+                Some("resolve")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(ErrorMessages::structured_error_span(
                     proc_macro2::Span::call_site(),
-                    format!(
-                        "Invalid setter prefix '{setter_prefix}'. Setter prefixes must contain only alphanumeric characters and underscores."
-                    ),
+                    &format!("Field-level lazy_setter is incompatible with {conflict}"),
+                    Some("#[builder(lazy_setter)] stores a closure that only runs in the build method"),
+                    Some("remove one of these attributes"),
                 ));
             }
         }
 
-        // Validate setter name if provided
-        if let Some(setter_name) = &self.setter_name {
-            if setter_name.is_empty() {
-                return Err(syn::Error::new(
+        // Validate that try_setter converts into the field type with a regular setter
+        if self.try_setter {
+            let conflict = if self.skip_setter {
+                Some("skip_setter")
+            } else if self.converter.is_some() {
+                Some("converter")
+            } else if self.builder_method {
+                Some("builder_method")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(ErrorMessages::structured_error_span(
                     proc_macro2::Span::call_site(),
-                    "Setter name cannot be empty",
+                    &format!("Field-level try_setter is incompatible with {conflict}"),
+                    Some("#[builder(try_setter)] converts its argument into the field type and passes it to the regular setter"),
+                    Some("remove one of these attributes"),
                 ));
             }
+        }
 
-            // Try to validate as identifier to ensure it's valid
-            if syn::parse_str::<syn::Ident>(setter_name).is_err() {
-                // If it's not a valid identifier, check if it might be a raw identifier
-                if !setter_name.starts_with("r#") {
-                    return Err(syn::Error::new(
-                        proc_macro2::Span::call_site(),
-                        format!(
-                            "Invalid setter name '{setter_name}'. Setter names must be valid Rust identifiers. \
-                            Use raw identifier syntax (r#name) for keywords."
-                        ),
-                    ));
+        // Validate that also_ref clones the field type into a regular setter
+        if self.also_ref {
+            let conflict = if self.skip_setter || self.computed.is_some() {
+                Some("skip_setter")
+            } else if self.converter.is_some() {
+                Some("converter")
+            } else if self.builder_method {
+                Some("builder_method")
+            } else if self.accumulate.is_some() {
+                Some("accumulate")
+            } else if self.validate_on_set {
+                Some("validate_on_set")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(ErrorMessages::structured_error_span(
+                    proc_macro2::Span::call_site(),
+                    &format!("Field-level setter(also_ref) is incompatible with {conflict}"),
+                    Some("#[builder(setter(also_ref))] clones a `&FieldType` and passes it to the regular setter"),
+                    Some("remove one of these attributes"),
+                ));
+            }
+        }
+
+        // Validate that clone_from_ref is the only conversion of the setter argument
+        if self.clone_from_ref {
+            let conflict = if self.skip_setter || self.computed.is_some() {
+                Some("skip_setter")
+            } else if self.converter.is_some() {
+                Some("converter")
+            } else if self.impl_into == Some(true) {
+                Some("impl_into")
+            } else if self.into_iter == Some(true) {
+                Some("into_iter")
+            } else if self.accumulate.is_some() {
+                Some("accumulate")
+            } else if self.also_ref {
+                Some("setter(also_ref)")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(ErrorMessages::structured_error_span(
+                    proc_macro2::Span::call_site(),
+                    &format!("Field-level clone_from_ref is incompatible with {conflict}"),
+                    Some("#[builder(clone_from_ref)] makes the setter take a reference and clone it into the field"),
+                    Some("remove one of these attributes"),
+                ));
+            }
+        }
+
+        // Validate that wrap is the only conversion of the setter argument
+        if self.wrap.is_some() {
+            let conflict = if self.skip_setter || self.computed.is_some() {
+                Some("skip_setter")
+            } else if self.converter.is_some() {
+                Some("converter")
+            } else if self.transparent {
+                Some("transparent")
+            } else if self.into_iter.is_some() {
+                Some("into_iter")
+            } else if self.nested {
+                Some("nested")
+            } else if self.try_setter {
+                Some("try_setter")
+            } else if self.also_ref {
+                Some("setter(also_ref)")
+            } else if self.clone_from_ref {
+                Some("clone_from_ref")
+            } else if self.accumulate.is_some() {
+                Some("accumulate")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(ErrorMessages::structured_error_span(
+                    proc_macro2::Span::call_site(),
+                    &format!("Field-level wrap is incompatible with {conflict}"),
+                    Some("#[builder(wrap)] makes the setter take the inner type and wrap it into the field type"),
+                    Some("remove one of these attributes"),
+                ));
+            }
+        }
+
+        // Validate that dyn_into is the only conversion of the setter argument
+        if self.dyn_into {
+            let conflict = if self.skip_setter || self.computed.is_some() {
+                Some("skip_setter")
+            } else if self.converter.is_some() {
+                Some("converter")
+            } else if self.wrap.is_some() {
+                Some("wrap")
+            } else if self.impl_into == Some(true) {
+                Some("impl_into")
+            } else if self.into_iter == Some(true) {
+                Some("into_iter")
+            } else if self.transparent {
+                Some("transparent")
+            } else if self.nested {
+                Some("nested")
+            } else if self.try_setter {
+                Some("try_setter")
+            } else if self.also_ref {
+                Some("setter(also_ref)")
+            } else if self.clone_from_ref {
+                Some("clone_from_ref")
+            } else if self.accumulate.is_some() {
+                Some("accumulate")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(ErrorMessages::structured_error_span(
+                    proc_macro2::Span::call_site(),
+                    &format!("Field-level dyn_into is incompatible with {conflict}"),
+                    Some("#[builder(dyn_into)] makes the setter take `impl Trait` and box it into the trait object field"),
+                    Some("remove one of these attributes"),
+                ));
+            }
+        }
+
+        // Validate that setter_input is the only conversion of the setter argument
+        if self.via.is_some() && self.setter_input.is_none() {
+            return Err(ErrorMessages::structured_error_span(
+                proc_macro2::Span::call_site(),
+                "Field-level via requires setter_input",
+                Some("#[builder(via)] names the trait converting the setter_input type"),
+                Some("add `setter_input = \"Type\"` or remove `via`"),
+            ));
+        }
+        if self.setter_input.is_some() {
+            let conflict = if self.skip_setter || self.computed.is_some() {
+                Some("skip_setter")
+            } else if self.converter.is_some() {
+                Some("converter")
+            } else if self.wrap.is_some() {
+                Some("wrap")
+            } else if self.dyn_into {
+                Some("dyn_into")
+            } else if self.transparent {
+                Some("transparent")
+            } else if self.into_iter.is_some() {
+                Some("into_iter")
+            } else if self.nested {
+                Some("nested")
+            } else if self.try_setter {
+                Some("try_setter")
+            } else if self.also_ref {
+                Some("setter(also_ref)")
+            } else if self.clone_from_ref {
+                Some("clone_from_ref")
+            } else if self.accumulate.is_some() {
+                Some("accumulate")
+            } else if self.via == Some(SetterInputVia::TryFrom) {
+                if self.impl_into == Some(true) {
+                    Some("impl_into")
+                } else if self.builder_method {
+                    Some("builder_method")
+                } else if self.validate_on_set {
+                    Some("validate_on_set")
+                } else if self.default_through_converter {
+                    Some("default_through_converter")
+                } else {
+                    None
                 }
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(ErrorMessages::structured_error_span(
+                    proc_macro2::Span::call_site(),
+                    &format!("Field-level setter_input is incompatible with {conflict}"),
+                    Some("#[builder(setter_input)] makes the setter take the named type and convert it with `From` or `TryFrom`"),
+                    Some("remove one of these attributes"),
+                ));
             }
         }
 
-        Ok(())
-    }
-}
+        // Validate that builder-only fields can be set
+        if self.builder_only {
+            let conflict = if self.skip_setter {
+                Some("skip_setter")
+            } else if self.computed.is_some() {
+                Some("computed")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(ErrorMessages::structured_error_span(
+                    proc_macro2::Span::call_site(),
+                    &format!("Field-level builder_only is incompatible with {conflict}"),
+                    Some("#[builder(builder_only)] fields are only read by the build method, so they need a setter"),
+                    Some("remove one of these attributes"),
+                ));
+            }
+        }
 
-/// Parses builder attributes from a field's attribute list.
-///
-/// This function processes all `#[builder(...)]` attributes on a field and
-/// combines them into a single `FieldAttributes` configuration. It validates
-/// that the attribute combinations are valid and returns appropriate errors
-/// for invalid configurations.
-///
-/// # Arguments
-///
-/// * `attrs` - The list of attributes from a struct field
+        // Validate that validated fields end up in the struct
+        if let Some(check) = self.validation_attribute() {
+            if self.builder_only {
+                return Err(ErrorMessages::structured_error_span(
+                    proc_macro2::Span::call_site(),
+                    &format!("Field-level {check} is incompatible with builder_only"),
+                    Some(&format!(
+                        "#[builder({check})] checks the value stored in the built struct"
+                    )),
+                    Some("validate the fields computed from the builder-only field instead"),
+                ));
+            }
+        }
+
+        // Validate that a default passed through the converter has both
+        if self.default_through_converter
+            && (self.default_value.is_none()
+                || (self.converter.is_none() && self.wrap.is_none() && self.setter_input.is_none()))
+        {
+            return Err(ErrorMessages::structured_error_span(
+                proc_macro2::Span::call_site(),
+                "Field-level default_through_converter requires default and converter",
+                Some("#[builder(default_through_converter)] passes the default through the field's converter"),
+                Some("add `default = ...` and `converter = |value: Input| ...` or `wrap = Inner`, or remove `default_through_converter`"),
+            ));
+        }
+
+        // Validate that a validation message has a validation to describe
+        if self.validate_message.is_some() && self.validation_attribute().is_none() {
+            return Err(ErrorMessages::structured_error_span(
+                proc_macro2::Span::call_site(),
+                "Field-level validate_message requires validate, non_empty or range",
+                Some("#[builder(validate_message)] is the error message for a failed validation check"),
+                Some("add `validate = |value| ...`, `non_empty` or `range = ...`, or remove `validate_message`"),
+            ));
+        }
+
+        // Validate that setter validation has checks and a setter to run them in
+        if self.validate_on_set && self.validation_attribute().is_none() {
+            return Err(ErrorMessages::structured_error_span(
+                proc_macro2::Span::call_site(),
+                "Field-level validate_on_set requires validate, non_empty or range",
+                Some("#[builder(validate_on_set)] moves the field's checks into its setter"),
+                Some("add `validate = |value| ...`, `non_empty` or `range = ...`"),
+            ));
+        }
+        if self.validate_on_set {
+            let conflict = if self.skip_setter || self.computed.is_some() {
+                Some("skip_setter")
+            } else if self.builder_method {
+                Some("builder_method")
+            } else if self.nested {
+                Some("nested")
+            } else if self.lazy_setter {
+                Some("lazy_setter")
+            } else if self.try_setter {
+                Some("try_setter")
+            } else if self.accumulate.is_some() {
+                Some("accumulate")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(ErrorMessages::structured_error_span(
+                    proc_macro2::Span::call_site(),
+                    &format!("Field-level validate_on_set is incompatible with {conflict}"),
+                    Some("#[builder(validate_on_set)] makes the field's plain setter return a `Result`"),
+                    Some("remove one of these attributes, or check the value in the build method"),
+                ));
+            }
+        }
+
+        // Validate that accumulate extends the plain value of an optional field
+        if self.accumulate.is_some() {
+            let conflict = if self.required {
+                Some("required")
+            } else if self.skip_setter {
+                Some("skip_setter")
+            } else if self.impl_into.is_some() {
+                Some("impl_into")
+            } else if self.converter.is_some() {
+                Some("converter")
+            } else if self.builder_method {
+                Some("builder_method")
+            } else if self.into_iter.is_some() {
+                Some("into_iter")
+            } else if self.nested {
+                Some("nested")
+            } else if self.sensitive {
+                Some("sensitive")
+            } else if self.lazy_setter {
+                Some("lazy_setter")
+            } else if self.default_from.is_some() {
+                Some("default_from")
+            } else if self.computed.is_some() {
+                Some("computed")
+            } else if self.try_setter {
+                Some("try_setter")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(ErrorMessages::structured_error_span(
+                    proc_macro2::Span::call_site(),
+                    &format!("Field-level accumulate is incompatible with {conflict}"),
+                    Some("#[builder(accumulate)] setters add their input to the value the builder holds, starting from the field's default"),
+                    Some("remove one of these attributes"),
+                ));
+            }
+        }
+
+        // Validate that computed fields have no setter and no other source of values
+        if self.computed.is_some() {
+            let conflict = if self.required {
+                Some("required")
+            } else if self.default_value.is_some() {
+                Some("default")
+            } else if self.default_from.is_some() {
+                Some("default_from")
+            } else if self.skip_setter {
+                Some("skip_setter")
+            } else if self.setter_name.is_some() {
+                Some("setter_name")
+            } else if !self.aliases.is_empty() {
+                Some("alias")
+            } else if self.setter_prefix.is_some() {
+                Some("setter_prefix")
+            } else if self.impl_into.is_some() {
+                Some("impl_into")
+            } else if self.converter.is_some() {
+                Some("converter")
+            } else if self.builder_method {
+                Some("builder_method")
+            } else if self.transparent {
+                Some("transparent")
+            } else if self.into_iter.is_some() {
+                Some("into_iter")
+            } else if self.nested {
+                Some("nested")
+            } else if self.sensitive {
+                Some("sensitive")
+            } else if self.cfg.is_some() {
+                Some("cfg")
+            } else if self.resolve.is_some() {
+                Some("resolve")
+            } else if self.lazy_setter {
+                Some("lazy_setter")
+            } else if self.try_setter {
+                Some("try_setter")
+            } else if self.boxed_field {
+                Some("boxed_field")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                let help = if conflict == "skip_setter" {
+                    "remove skip_setter, which computed implies"
+                } else {
+                    "remove one of these attributes"
+                };
+                return Err(ErrorMessages::structured_error_span(
+                    proc_macro2::Span::call_site(),
+                    &format!("Field-level computed is incompatible with {conflict}"),
+                    Some("#[builder(computed)] fields have no setter and are always computed by the build method"),
+                    Some(help),
+                ));
+            }
+        }
+
+        // Validate that default_from only applies to optional fields with a setter
+        if self.default_from.is_some() {
+            let conflict = if self.required {
+                Some("required")
+            } else if self.default_value.is_some() {
+                Some("default")
+            } else if self.skip_setter {
+                Some("skip_setter")
+            } else if self.builder_method {
+                Some("builder_method")
+            } else if self.sensitive {
+                Some("sensitive")
+            } else if self.resolve.is_some() {
+                Some("resolve")
+            } else if self.lazy_setter {
+                Some("lazy_setter")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(ErrorMessages::structured_error_span(
+                    proc_macro2::Span::call_site(),
+                    &format!("Field-level default_from is incompatible with {conflict}"),
+                    Some("#[builder(default_from)] computes the value of an optional field whose setter was not called"),
+                    Some("remove one of these attributes"),
+                ));
+            }
+        }
+
+        // Validate setter prefix if provided
+        if let Some(setter_prefix) = &self.setter_prefix {
+            if setter_prefix.is_empty() {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    "Setter prefix cannot be empty",
+                ));
+            }
+
+            // Check if the prefix would create valid identifiers when combined with field names
+            // We'll validate this by checking if it starts correctly
+            if setter_prefix.chars().next().is_some_and(|c| c.is_numeric()) {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!(
+                        "Invalid setter prefix '{setter_prefix}'. Setter prefixes cannot start with a number. \
+                        Use a valid identifier prefix like 'with_' or 'set_'."
+                    ),
+                ));
+            }
+
+            // Check for basic identifier validity of prefix
+            // This is a heuristic check - we'll do full validation when combining with field names
+            if !setter_prefix
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_')
+            {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!(
+                        "Invalid setter prefix '{setter_prefix}'. Setter prefixes must contain only alphanumeric characters and underscores."
+                    ),
+                ));
+            }
+        }
+
+        // Validate setter name if provided
+        if let Some(setter_name) = &self.setter_name {
+            if setter_name.is_empty() {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    "Setter name cannot be empty",
+                ));
+            }
+
+            // Try to validate as identifier to ensure it's valid
+            if syn::parse_str::<syn::Ident>(setter_name).is_err() {
+                // If it's not a valid identifier, check if it might be a raw identifier
+                if !setter_name.starts_with("r#") {
+                    return Err(syn::Error::new(
+                        proc_macro2::Span::call_site(),
+                        format!(
+                            "Invalid setter name '{setter_name}'. Setter names must be valid Rust identifiers. \
+                            Use raw identifier syntax (r#name) for keywords."
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses builder attributes from a field's attribute list with default
+/// struct-level settings.
+///
+/// Only used by tests; generation parses fields through
+/// [`parse_field_attributes_in_struct`] so struct-level defaults apply.
+#[cfg(test)]
+pub fn parse_field_attributes(attrs: &[syn::Attribute]) -> syn::Result<FieldAttributes> {
+    parse_field_attributes_in_struct(attrs, &StructAttributes::default())
+}
+
+/// Resolves `#[builder(default = serde)]` from the field's serde attributes.
+///
+/// `#[serde(default = "path")]` becomes a call to `path()`, and a bare
+/// `#[serde(default)]` becomes `Default::default()`, so the builder and
+/// deserialization share a single default.
+///
+/// # Arguments
+///
+/// * `attrs` - The list of attributes from a struct field
+/// * `marker` - The `serde` path in the builder attribute, used for error spans
+///
+/// # Returns
+///
+/// The default expression, or an error if the field has no serde default.
+fn serde_default_expr(attrs: &[syn::Attribute], marker: &syn::Path) -> syn::Result<syn::Expr> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        let metas = attr.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+        )?;
+        for meta in metas {
+            match meta {
+                syn::Meta::Path(path) if path.is_ident("default") => {
+                    return Ok(syn::parse_quote! { ::core::default::Default::default() });
+                }
+                syn::Meta::NameValue(name_value) if name_value.path.is_ident("default") => {
+                    let syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(function),
+                        ..
+                    }) = &name_value.value
+                    else {
+                        return Err(syn::Error::new_spanned(
+                            &name_value.value,
+                            "Expected the serde default function as a string, e.g. `default = \"path\"`",
+                        ));
+                    };
+                    let function: syn::ExprPath = function.parse()?;
+                    return Ok(syn::parse_quote! { #function() });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Err(ErrorMessages::structured_error(
+        marker,
+        "`default = serde` requires a `#[serde(default)]` or `#[serde(default = \"path\")]` attribute on the field",
+        Some("the builder reuses the default that deserialization uses"),
+        Some("add `#[serde(default = \"path\")]` to the field, or give the builder its own `default = <value>`"),
+    ))
+}
+
+/// Parses builder attributes from a field's attribute list.
+///
+/// This function processes all `#[builder(...)]` attributes on a field and
+/// combines them into a single `FieldAttributes` configuration. It validates
+/// that the attribute combinations are valid and returns appropriate errors
+/// for invalid configurations.
+///
+/// # Arguments
+///
+/// * `attrs` - The list of attributes from a struct field
+/// * `struct_attributes` - The parsed struct-level attributes
 ///
 /// # Returns
 ///
@@ -357,8 +1557,12 @@ impl FieldAttributes {
 /// 2. Parses each builder attribute using `syn::parse_nested_meta`
 /// 3. Validates individual attribute values
 /// 4. Combines multiple attributes into a single configuration
-/// 5. Returns the complete configuration or the first error encountered
-pub fn parse_field_attributes(attrs: &[syn::Attribute]) -> syn::Result<FieldAttributes> {
+/// 5. Applies struct-level settings that change field defaults (`all_required`)
+/// 6. Returns the complete configuration or the first error encountered
+pub fn parse_field_attributes_in_struct(
+    attrs: &[syn::Attribute],
+    struct_attributes: &StructAttributes,
+) -> syn::Result<FieldAttributes> {
     let mut field_attributes = FieldAttributes::default();
 
     // Process each attribute in the list
@@ -368,11 +1572,31 @@ pub fn parse_field_attributes(attrs: &[syn::Attribute]) -> syn::Result<FieldAttr
             // Parse the nested meta inside the builder attribute
             attr.parse_nested_meta(|meta| {
                 if meta.path.is_ident("required") {
-                    // #[builder(required)]
+                    // #[builder(required)] or #[builder(required(cfg(predicate)))]
                     // Check for duplicate required attributes
-                    if field_attributes.required {
+                    if field_attributes.required || field_attributes.required_cfg.is_some() {
                         return Err(meta.error("Duplicate required attribute. Only one required is allowed per field"));
                     }
+                    if meta.input.peek(syn::token::Paren) {
+                        let content;
+                        syn::parenthesized!(content in meta.input);
+                        let condition: syn::Meta = content.parse()?;
+                        let predicate = match &condition {
+                            syn::Meta::List(list) if list.path.is_ident("cfg") => {
+                                list.parse_args::<syn::Meta>()?
+                            }
+                            _ => {
+                                return Err(ErrorMessages::structured_error(
+                                    &condition,
+                                    "Unsupported required condition",
+                                    Some("a field can only be required under a configuration predicate"),
+                                    Some("write the condition as `required(cfg(feature = \"...\"))`"),
+                                ));
+                            }
+                        };
+                        field_attributes.required_cfg = Some(predicate);
+                        return Ok(());
+                    }
                     field_attributes.required = true;
                     Ok(())
                 } else if meta.path.is_ident("skip_setter") {
@@ -401,6 +1625,33 @@ pub fn parse_field_attributes(attrs: &[syn::Attribute]) -> syn::Result<FieldAttr
 
                     field_attributes.setter_name = Some(setter_name);
                     Ok(())
+                } else if meta.path.is_ident("alias") {
+                    // #[builder(alias = "name")], repeatable
+                    let value = meta.value()?;
+                    let lit_str: syn::LitStr = value.parse()?;
+                    let alias = lit_str.value();
+
+                    // rustdoc rejects aliases it cannot search for
+                    if alias.trim() != alias
+                        || alias.is_empty()
+                        || alias.contains(['"', '\'', '\\'])
+                    {
+                        return Err(syn::Error::new_spanned(
+                            &lit_str,
+                            "Invalid alias. Aliases must be non-empty, without quotes or surrounding whitespace",
+                        ));
+                    }
+
+                    // Check for duplicate aliases
+                    if field_attributes.aliases.contains(&alias) {
+                        return Err(syn::Error::new_spanned(
+                            &lit_str,
+                            format!("Duplicate alias `{alias}`"),
+                        ));
+                    }
+
+                    field_attributes.aliases.push(alias);
+                    Ok(())
                 } else if meta.path.is_ident("setter_prefix") {
                     // #[builder(setter_prefix = "prefix_")]
                     let value = meta.value()?;
@@ -429,6 +1680,13 @@ pub fn parse_field_attributes(attrs: &[syn::Attribute]) -> syn::Result<FieldAttr
                     }
 
                     let expr: syn::Expr = value.parse()?;
+                    let expr = match &expr {
+                        // #[builder(default = serde)] reuses #[serde(default = "path")]
+                        syn::Expr::Path(path) if path.qself.is_none() && path.path.is_ident("serde") => {
+                            serde_default_expr(attrs, &path.path)?
+                        }
+                        _ => expr,
+                    };
                     field_attributes.default_value = Some(expr);
                     Ok(())
                 } else if meta.path.is_ident("impl_into") {
@@ -459,6 +1717,19 @@ pub fn parse_field_attributes(attrs: &[syn::Attribute]) -> syn::Result<FieldAttr
                         return Err(meta.error("Duplicate converter attribute. Only one converter is allowed per field"));
                     }
 
+                    // #[builder(converter = std_converters::name)]
+                    if let Some(name) = std_converter_name(&expr) {
+                        if !cfg!(feature = "std-converters") {
+                            return Err(ErrorMessages::structured_error(
+                                &expr,
+                                "Built-in converters require the `std-converters` feature",
+                                Some("`std_converters::*` converters are expanded by the macro into closures"),
+                                Some("enable the `std-converters` feature of type-state-builder or write the converter as a closure"),
+                            ));
+                        }
+                        check_std_converter(name)?;
+                    }
+
                     field_attributes.converter = Some(expr);
                     Ok(())
                 } else if meta.path.is_ident("builder_method") {
@@ -469,85 +1740,500 @@ pub fn parse_field_attributes(attrs: &[syn::Attribute]) -> syn::Result<FieldAttr
                     }
                     field_attributes.builder_method = true;
                     Ok(())
-                } else {
-                    // Unknown attribute
-                    Err(meta.error(
-                        "Unknown builder attribute. Supported attributes: required, setter_name, setter_prefix, default, skip_setter, impl_into, converter, builder_method"
-                    ))
-                }
-            })?;
-        }
-    }
+                } else if meta.path.is_ident("transparent") {
+                    // #[builder(transparent)]
+                    // Check for duplicate transparent attributes
+                    if field_attributes.transparent {
+                        return Err(meta.error("Duplicate transparent attribute. Only one transparent is allowed per field"));
+                    }
+                    field_attributes.transparent = true;
+                    Ok(())
+                } else if meta.path.is_ident("into_iter") {
+                    // #[builder(into_iter)] or #[builder(into_iter = true/false)]
+                    // Check for duplicate into_iter attributes
+                    if field_attributes.into_iter.is_some() {
+                        return Err(meta.error("Duplicate into_iter attribute. Only one into_iter is allowed per field"));
+                    }
 
-    // Validate field attribute combinations
-    field_attributes.validate()?;
+                    if meta.input.peek(syn::Token![=]) {
+                        // #[builder(into_iter = true/false)]
+                        let value = meta.value()?;
+                        let lit_bool: syn::LitBool = value.parse()?;
+                        field_attributes.into_iter = Some(lit_bool.value);
+                    } else {
+                        // #[builder(into_iter)] - defaults to true
+                        field_attributes.into_iter = Some(true);
+                    }
+                    Ok(())
+                } else if meta.path.is_ident("nested") {
+                    // #[builder(nested)]
+                    // Check for duplicate nested attributes
+                    if field_attributes.nested {
+                        return Err(meta.error("Duplicate nested attribute. Only one nested is allowed per field"));
+                    }
+                    field_attributes.nested = true;
+                    Ok(())
+                } else if meta.path.is_ident("nested_each") {
+                    // #[builder(nested_each = "add_child")]
+                    let value = meta.value()?;
+                    let lit_str: syn::LitStr = value.parse()?;
+                    let method_name = lit_str.value();
 
-    Ok(field_attributes)
-}
+                    // Validate that the method name is not empty
+                    if method_name.is_empty() {
+                        return Err(meta.error("nested_each method name cannot be empty"));
+                    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use syn::parse_quote;
+                    // Check for duplicate nested_each attributes
+                    if field_attributes.nested_each.is_some() {
+                        return Err(meta.error("Duplicate nested_each attribute. Only one nested_each is allowed per field"));
+                    }
 
-    #[test]
-    fn test_default_field_attributes() {
-        let attrs = FieldAttributes::default();
-        assert!(!attrs.required);
-        assert!(attrs.setter_name.is_none());
-        assert!(attrs.setter_prefix.is_none());
-        assert!(attrs.default_value.is_none());
-        assert!(!attrs.skip_setter);
-        assert!(attrs.impl_into.is_none());
-    }
+                    field_attributes.nested_each = Some(method_name);
+                    Ok(())
+                } else if meta.path.is_ident("optional") {
+                    // #[builder(optional)]
+                    // Check for duplicate optional attributes
+                    if field_attributes.optional {
+                        return Err(meta.error("Duplicate optional attribute. Only one optional is allowed per field"));
+                    }
+                    field_attributes.optional = true;
+                    Ok(())
+                } else if meta.path.is_ident("skip_debug") {
+                    // #[builder(skip_debug)]
+                    // Check for duplicate skip_debug attributes
+                    if field_attributes.skip_debug {
+                        return Err(meta.error("Duplicate skip_debug attribute. Only one skip_debug is allowed per field"));
+                    }
+                    field_attributes.skip_debug = true;
+                    Ok(())
+                } else if meta.path.is_ident("skip_env") {
+                    // #[builder(skip_env)]
+                    if field_attributes.skip_env {
+                        return Err(meta.error("Duplicate skip_env attribute. Only one skip_env is allowed per field"));
+                    }
+                    field_attributes.skip_env = true;
+                    Ok(())
+                } else if meta.path.is_ident("redact") {
+                    // #[builder(redact)]
+                    // Check for duplicate redact attributes
+                    if field_attributes.redact {
+                        return Err(meta.error("Duplicate redact attribute. Only one redact is allowed per field"));
+                    }
+                    field_attributes.redact = true;
+                    Ok(())
+                } else if meta.path.is_ident("sensitive") {
+                    // #[builder(sensitive)]
+                    if !cfg!(feature = "zeroize") {
+                        return Err(ErrorMessages::structured_error(
+                            &meta.path,
+                            "Field-level sensitive requires the `zeroize` feature",
+                            Some("sensitive fields are held in `zeroize::Zeroizing` by the builder"),
+                            Some("enable the `zeroize` feature of type-state-builder and add `zeroize` to your dependencies"),
+                        ));
+                    }
+                    // Check for duplicate sensitive attributes
+                    if field_attributes.sensitive {
+                        return Err(meta.error("Duplicate sensitive attribute. Only one sensitive is allowed per field"));
+                    }
+                    field_attributes.sensitive = true;
+                    Ok(())
+                } else if meta.path.is_ident("cfg") {
+                    // #[builder(cfg(predicate))]
+                    // Check for duplicate cfg attributes
+                    if field_attributes.cfg.is_some() {
+                        return Err(meta.error("Duplicate cfg attribute. Only one cfg is allowed per field; combine predicates with all(...) or any(...)"));
+                    }
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    field_attributes.cfg = Some(content.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("resolve") {
+                    // #[builder(resolve = |value: Type, other_field: &Other| expression)]
+                    let value = meta.value()?;
+                    let expr: syn::Expr = value.parse()?;
 
-    #[test]
-    fn test_parse_required_attribute() {
-        let attrs = vec![parse_quote!(#[builder(required)])];
-        let field_attrs = parse_field_attributes(&attrs).unwrap();
+                    // Check for duplicate resolve attributes
+                    if field_attributes.resolve.is_some() {
+                        return Err(meta.error("Duplicate resolve attribute. Only one resolve is allowed per field"));
+                    }
 
-        assert!(field_attrs.required);
-        assert!(field_attrs.setter_name.is_none());
-        assert!(field_attrs.default_value.is_none());
-        assert!(!field_attrs.skip_setter);
-    }
+                    field_attributes.resolve = Some(expr);
+                    Ok(())
+                } else if meta.path.is_ident("lazy_setter") {
+                    // #[builder(lazy_setter)]
+                    // Check for duplicate lazy_setter attributes
+                    if field_attributes.lazy_setter {
+                        return Err(meta.error("Duplicate lazy_setter attribute. Only one lazy_setter is allowed per field"));
+                    }
+                    field_attributes.lazy_setter = true;
+                    Ok(())
+                } else if meta.path.is_ident("default_from") {
+                    // #[builder(default_from = |builder| expression)]
+                    let value = meta.value()?;
+                    let expr: syn::Expr = value.parse()?;
 
-    #[test]
-    fn test_parse_setter_name_attribute() {
-        let attrs = vec![parse_quote!(#[builder(setter_name = "set_field")])];
-        let field_attrs = parse_field_attributes(&attrs).unwrap();
+                    // Check for duplicate default_from attributes
+                    if field_attributes.default_from.is_some() {
+                        return Err(meta.error("Duplicate default_from attribute. Only one default_from is allowed per field"));
+                    }
 
-        assert!(!field_attrs.required);
-        assert_eq!(field_attrs.setter_name, Some("set_field".to_string()));
-        assert!(field_attrs.default_value.is_none());
-        assert!(!field_attrs.skip_setter);
-    }
+                    field_attributes.default_from = Some(expr);
+                    Ok(())
+                } else if meta.path.is_ident("computed") {
+                    // #[builder(computed = |builder| expression)]
+                    let value = meta.value()?;
+                    let expr: syn::Expr = value.parse()?;
 
-    use proptest::prelude::*;
+                    // Check for duplicate computed attributes
+                    if field_attributes.computed.is_some() {
+                        return Err(meta.error("Duplicate computed attribute. Only one computed is allowed per field"));
+                    }
 
-    /// Strategy that generates valid Rust expressions for testing default value parsing
-    fn expr_strategy() -> impl Strategy<Value = &'static str> {
-        prop_oneof![
-            // Integer literals
-            Just("42"),
-            Just("0"),
-            Just("100_000"),
-            // Float literals
-            Just("3.14"),
-            Just("0.0"),
-            // Boolean literals
-            Just("true"),
-            Just("false"),
-            // String literals (as expressions, not the attribute syntax)
-            Just("\"hello\""),
-            Just("\"\""),
-            // Char literals
-            Just("'a'"),
-            // Function calls
-            Just("Vec::new()"),
-            Just("String::new()"),
-            Just("Default::default()"),
-            Just("std::collections::HashMap::new()"),
+                    field_attributes.computed = Some(expr);
+                    Ok(())
+                } else if meta.path.is_ident("try_setter") {
+                    // #[builder(try_setter)]
+                    // Check for duplicate try_setter attributes
+                    if field_attributes.try_setter {
+                        return Err(meta.error("Duplicate try_setter attribute. Only one try_setter is allowed per field"));
+                    }
+                    field_attributes.try_setter = true;
+                    Ok(())
+                } else if meta.path.is_ident("setter") {
+                    // #[builder(setter(also_ref))]
+                    meta.parse_nested_meta(|option| {
+                        if option.path.is_ident("also_ref") {
+                            // Check for duplicate also_ref options
+                            if field_attributes.also_ref {
+                                return Err(option.error("Duplicate also_ref option. Only one also_ref is allowed per field"));
+                            }
+                            field_attributes.also_ref = true;
+                            Ok(())
+                        } else {
+                            Err(option.error("Unknown setter option. Supported options: also_ref"))
+                        }
+                    })
+                } else if meta.path.is_ident("builder_only") {
+                    // #[builder(builder_only)]
+                    // Check for duplicate builder_only attributes
+                    if field_attributes.builder_only {
+                        return Err(meta.error("Duplicate builder_only attribute. Only one builder_only is allowed per field"));
+                    }
+                    field_attributes.builder_only = true;
+                    Ok(())
+                } else if meta.path.is_ident("accumulate") {
+                    // #[builder(accumulate = |acc, value: Type| statement)]
+                    let value = meta.value()?;
+                    let expr: syn::Expr = value.parse()?;
+
+                    // Check for duplicate accumulate attributes
+                    if field_attributes.accumulate.is_some() {
+                        return Err(meta.error("Duplicate accumulate attribute. Only one accumulate is allowed per field"));
+                    }
+
+                    // The setter's parameter type comes from the closure
+                    if extract_accumulator_parameter_type(&expr).is_none() {
+                        return Err(ErrorMessages::structured_error(
+                            &expr,
+                            "accumulate must be a closure with two parameters, the second of them typed",
+                            Some("the setter passes the field by mutable reference and its own argument to the closure"),
+                            Some("write the closure as `|acc, value: InputType| ...`"),
+                        ));
+                    }
+
+                    field_attributes.accumulate = Some(expr);
+                    Ok(())
+                } else if meta.path.is_ident("validate") {
+                    // #[builder(validate = |value: &Type| condition)]
+                    let value = meta.value()?;
+                    let expr: syn::Expr = value.parse()?;
+
+                    // Check for duplicate validate attributes
+                    if field_attributes.validate.is_some() {
+                        return Err(meta.error("Duplicate validate attribute. Only one validate is allowed per field"));
+                    }
+
+                    field_attributes.validate = Some(expr);
+                    Ok(())
+                } else if meta.path.is_ident("validate_message") {
+                    // #[builder(validate_message = "message")]
+                    let value = meta.value()?;
+                    let message: syn::LitStr = value.parse()?;
+
+                    // Check for duplicate validate_message attributes
+                    if field_attributes.validate_message.is_some() {
+                        return Err(meta.error("Duplicate validate_message attribute. Only one validate_message is allowed per field"));
+                    }
+
+                    field_attributes.validate_message = Some(message);
+                    Ok(())
+                } else if meta.path.is_ident("clone_from_ref") {
+                    // #[builder(clone_from_ref)]
+                    if field_attributes.clone_from_ref {
+                        return Err(meta.error("Duplicate clone_from_ref attribute. Only one clone_from_ref is allowed per field"));
+                    }
+                    field_attributes.clone_from_ref = true;
+                    Ok(())
+                } else if meta.path.is_ident("wrap") {
+                    // #[builder(wrap = InnerType)] or #[builder(wrap(InnerType, with = path))]
+                    if field_attributes.wrap.is_some() {
+                        return Err(meta.error("Duplicate wrap attribute. Only one wrap is allowed per field"));
+                    }
+                    let wrap = if meta.input.peek(syn::token::Paren) {
+                        let content;
+                        syn::parenthesized!(content in meta.input);
+                        let inner: syn::Type = content.parse()?;
+                        let mut constructor = None;
+                        if content.parse::<Option<syn::Token![,]>>()?.is_some() && !content.is_empty() {
+                            let option: syn::Ident = content.parse()?;
+                            if option != "with" {
+                                return Err(syn::Error::new(
+                                    option.span(),
+                                    "Unknown wrap option. Supported options: with",
+                                ));
+                            }
+                            content.parse::<syn::Token![=]>()?;
+                            constructor = Some(content.parse::<syn::Path>()?);
+                            content.parse::<Option<syn::Token![,]>>()?;
+                        }
+                        if !content.is_empty() {
+                            return Err(content.error(
+                                "Expected `wrap(InnerType)` or `wrap(InnerType, with = path)`",
+                            ));
+                        }
+                        Wrap { inner, constructor }
+                    } else {
+                        let inner: syn::Type = meta.value()?.parse()?;
+                        Wrap { inner, constructor: None }
+                    };
+                    field_attributes.wrap = Some(wrap);
+                    Ok(())
+                } else if meta.path.is_ident("test_default") {
+                    // #[builder(test_default = expression)]
+                    let value = meta.value()?;
+                    if field_attributes.test_default.is_some() {
+                        return Err(meta.error("Duplicate test_default attribute. Only one test_default is allowed per field"));
+                    }
+                    field_attributes.test_default = Some(value.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("setter_input") {
+                    // #[builder(setter_input = "u32")] or #[builder(setter_input = u32)]
+                    if field_attributes.setter_input.is_some() {
+                        return Err(meta.error("Duplicate setter_input attribute. Only one setter_input is allowed per field"));
+                    }
+                    let value = meta.value()?;
+                    let input: syn::Type = if value.peek(syn::LitStr) {
+                        value.parse::<syn::LitStr>()?.parse()?
+                    } else {
+                        value.parse()?
+                    };
+                    field_attributes.setter_input = Some(input);
+                    Ok(())
+                } else if meta.path.is_ident("via") {
+                    // #[builder(via = From)] or #[builder(via = TryFrom)]
+                    if field_attributes.via.is_some() {
+                        return Err(meta.error("Duplicate via attribute. Only one via is allowed per field"));
+                    }
+                    let via: syn::Ident = meta.value()?.parse()?;
+                    field_attributes.via = Some(match via.to_string().as_str() {
+                        "From" => SetterInputVia::From,
+                        "TryFrom" => SetterInputVia::TryFrom,
+                        _ => {
+                            return Err(syn::Error::new(
+                                via.span(),
+                                "Unknown via trait. Supported traits: From, TryFrom",
+                            ))
+                        }
+                    });
+                    Ok(())
+                } else if meta.path.is_ident("dyn_into") {
+                    // #[builder(dyn_into)]
+                    if field_attributes.dyn_into {
+                        return Err(meta.error("Duplicate dyn_into attribute. Only one dyn_into is allowed per field"));
+                    }
+                    field_attributes.dyn_into = true;
+                    Ok(())
+                } else if meta.path.is_ident("boxed_field") {
+                    // #[builder(boxed_field)]
+                    if field_attributes.boxed_field {
+                        return Err(meta.error("Duplicate boxed_field attribute. Only one boxed_field is allowed per field"));
+                    }
+                    field_attributes.boxed_field = true;
+                    Ok(())
+                } else if meta.path.is_ident("default_through_converter") {
+                    // #[builder(default_through_converter)]
+                    if field_attributes.default_through_converter {
+                        return Err(meta.error("Duplicate default_through_converter attribute. Only one default_through_converter is allowed per field"));
+                    }
+                    field_attributes.default_through_converter = true;
+                    Ok(())
+                } else if meta.path.is_ident("validate_on_set") {
+                    // #[builder(validate_on_set)]
+                    field_attributes.validate_on_set = true;
+                    Ok(())
+                } else if meta.path.is_ident("non_empty") {
+                    // #[builder(non_empty)]
+                    if field_attributes.non_empty {
+                        return Err(meta.error("Duplicate non_empty attribute. Only one non_empty is allowed per field"));
+                    }
+
+                    field_attributes.non_empty = true;
+                    Ok(())
+                } else if meta.path.is_ident("range") {
+                    // #[builder(range = start..=end)]
+                    let value = meta.value()?;
+                    let expr: syn::Expr = value.parse()?;
+
+                    // Check for duplicate range attributes
+                    if field_attributes.range.is_some() {
+                        return Err(meta.error("Duplicate range attribute. Only one range is allowed per field"));
+                    }
+
+                    // The check calls the range's `contains`, which `..` does not have
+                    let range = match expr {
+                        syn::Expr::Range(range) if range.start.is_some() || range.end.is_some() => range,
+                        other => {
+                            return Err(ErrorMessages::structured_error(
+                                &other,
+                                "range must be a range expression with at least one bound",
+                                Some("the build method checks the value with the range's `contains` method"),
+                                Some("write the range as `start..end`, `start..=end`, `start..` or `..=end`"),
+                            ));
+                        }
+                    };
+
+                    field_attributes.range = Some(range);
+                    Ok(())
+                } else if meta.path.is_ident("flatten") {
+                    // #[builder(flatten)] - recognized only to explain why it is unsupported
+                    Err(ErrorMessages::structured_error(
+                        &meta.path,
+                        "Field-level flatten is not supported",
+                        Some("a derive macro cannot see the fields of another struct, so they cannot be added to this builder's state machine"),
+                        Some("use #[builder(nested)] to configure the field through its own builder"),
+                    ))
+                } else {
+                    // Unknown attribute
+                    Err(meta.error(
+                        "Unknown builder attribute. Supported attributes: required, setter_name, alias, setter_prefix, default, skip_setter, impl_into, converter, builder_method, transparent, into_iter, nested, nested_each, optional, skip_debug, skip_env, redact, sensitive, cfg, resolve, lazy_setter, default_from, computed, try_setter, setter, builder_only, accumulate, validate, non_empty, range, validate_message, validate_on_set, clone_from_ref, wrap, setter_input, via, dyn_into, test_default, boxed_field, default_through_converter"
+                    ))
+                }
+            })?;
+        }
+    }
+
+    // Struct-level all_required makes fields required unless opted out
+    if struct_attributes.get_all_required() {
+        field_attributes.apply_all_required();
+    }
+
+    // Struct-level base provides the fields without a default of their own
+    if struct_attributes.get_base().is_some() {
+        field_attributes.apply_base();
+    }
+
+    // Struct-level fill_from tracks the fields that were not set
+    if struct_attributes.get_fill_from() {
+        field_attributes.apply_fill_from();
+    }
+
+    // Validate field attribute combinations
+    field_attributes.validate()?;
+
+    Ok(field_attributes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_default_field_attributes() {
+        let attrs = FieldAttributes::default();
+        assert!(!attrs.required);
+        assert!(attrs.setter_name.is_none());
+        assert!(attrs.setter_prefix.is_none());
+        assert!(attrs.default_value.is_none());
+        assert!(!attrs.skip_setter);
+        assert!(attrs.impl_into.is_none());
+    }
+
+    #[test]
+    fn test_parse_required_attribute() {
+        let attrs = vec![parse_quote!(#[builder(required)])];
+        let field_attrs = parse_field_attributes(&attrs).unwrap();
+
+        assert!(field_attrs.required);
+        assert!(field_attrs.setter_name.is_none());
+        assert!(field_attrs.default_value.is_none());
+        assert!(!field_attrs.skip_setter);
+    }
+
+    #[test]
+    fn test_parse_alias_attribute() {
+        let attrs = vec![parse_quote!(#[builder(alias = "max_connections", alias = "conns")])];
+        let field_attrs = parse_field_attributes(&attrs).unwrap();
+        assert_eq!(field_attrs.aliases, ["max_connections", "conns"]);
+        assert!(FieldAttributes::default().aliases.is_empty());
+
+        for (attrs, error) in [
+            (
+                vec![parse_quote!(#[builder(alias = "a", alias = "a")])],
+                "Duplicate alias `a`",
+            ),
+            (vec![parse_quote!(#[builder(alias = "")])], "Invalid alias"),
+            (
+                vec![parse_quote!(#[builder(alias = " a")])],
+                "Invalid alias",
+            ),
+            (
+                vec![parse_quote!(#[builder(alias = "a\"b")])],
+                "Invalid alias",
+            ),
+        ] {
+            let message = parse_field_attributes(&attrs).unwrap_err().to_string();
+            assert!(message.contains(error), "{message}");
+        }
+    }
+
+    #[test]
+    fn test_parse_setter_name_attribute() {
+        let attrs = vec![parse_quote!(#[builder(setter_name = "set_field")])];
+        let field_attrs = parse_field_attributes(&attrs).unwrap();
+
+        assert!(!field_attrs.required);
+        assert_eq!(field_attrs.setter_name, Some("set_field".to_string()));
+        assert!(field_attrs.default_value.is_none());
+        assert!(!field_attrs.skip_setter);
+    }
+
+    use proptest::prelude::*;
+
+    /// Strategy that generates valid Rust expressions for testing default value parsing
+    fn expr_strategy() -> impl Strategy<Value = &'static str> {
+        prop_oneof![
+            // Integer literals
+            Just("42"),
+            Just("0"),
+            Just("100_000"),
+            // Float literals
+            Just("3.14"),
+            Just("0.0"),
+            // Boolean literals
+            Just("true"),
+            Just("false"),
+            // String literals (as expressions, not the attribute syntax)
+            Just("\"hello\""),
+            Just("\"\""),
+            // Char literals
+            Just("'a'"),
+            // Function calls
+            Just("Vec::new()"),
+            Just("String::new()"),
+            Just("Default::default()"),
+            Just("std::collections::HashMap::new()"),
             // Method chains
             Just("Vec::with_capacity(10)"),
             Just("String::from(\"test\")"),
@@ -688,6 +2374,39 @@ mod tests {
         assert!(field_attrs.default_value.is_some());
     }
 
+    #[test]
+    fn test_parse_serde_default() {
+        let default_of = |attrs: Vec<syn::Attribute>| {
+            let expr = parse_field_attributes(&attrs)
+                .unwrap()
+                .default_value
+                .unwrap();
+            quote::quote!(#expr).to_string()
+        };
+
+        let attrs = vec![
+            parse_quote!(#[builder(default = serde)]),
+            parse_quote!(#[serde(rename = "p", default = "defaults::port")]),
+        ];
+        assert_eq!(default_of(attrs), "defaults :: port ()");
+
+        let attrs = vec![
+            parse_quote!(#[serde(default)]),
+            parse_quote!(#[builder(default = serde)]),
+        ];
+        assert_eq!(
+            default_of(attrs),
+            ":: core :: default :: Default :: default ()"
+        );
+
+        let attrs = vec![
+            parse_quote!(#[builder(default = serde)]),
+            parse_quote!(#[serde(rename = "p")]),
+        ];
+        let error = parse_field_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`default = serde` requires"), "{error}");
+    }
+
     #[test]
     fn test_parse_unknown_attribute_error() {
         let attrs = vec![parse_quote!(#[builder(unknown_attr)])];
@@ -824,6 +2543,7 @@ mod tests {
             impl_into: None,
             converter: None,
             builder_method: false,
+            ..FieldAttributes::default()
         };
         assert!(valid_attrs.validate().is_ok());
 
@@ -837,6 +2557,7 @@ mod tests {
             impl_into: None,
             converter: None,
             builder_method: false,
+            ..FieldAttributes::default()
         };
         let result = invalid_attrs.validate();
         assert!(result.is_err());
@@ -1305,39 +3026,30 @@ mod tests {
     }
 
     #[test]
-    fn test_validate_converter_with_impl_into_flag_error() {
+    fn test_validate_converter_with_impl_into_flag() {
         let attrs = vec![parse_quote!(#[builder(converter = |x: i32| x, impl_into)])];
-        let result = parse_field_attributes(&attrs);
+        let result = parse_field_attributes(&attrs).unwrap();
 
-        assert!(result.is_err());
-        assert!(result
-            .unwrap_err()
-            .to_string()
-            .contains("Field-level converter is incompatible with impl_into"));
+        assert!(result.converter.is_some());
+        assert_eq!(result.impl_into, Some(true));
     }
 
     #[test]
-    fn test_validate_converter_with_impl_into_true_error() {
+    fn test_validate_converter_with_impl_into_true() {
         let attrs = vec![parse_quote!(#[builder(converter = |x: i32| x, impl_into = true)])];
-        let result = parse_field_attributes(&attrs);
+        let result = parse_field_attributes(&attrs).unwrap();
 
-        assert!(result.is_err());
-        assert!(result
-            .unwrap_err()
-            .to_string()
-            .contains("Field-level converter is incompatible with impl_into"));
+        assert!(result.converter.is_some());
+        assert_eq!(result.impl_into, Some(true));
     }
 
     #[test]
-    fn test_validate_converter_with_impl_into_false_error() {
+    fn test_validate_converter_with_impl_into_false() {
         let attrs = vec![parse_quote!(#[builder(converter = |x: i32| x, impl_into = false)])];
-        let result = parse_field_attributes(&attrs);
+        let result = parse_field_attributes(&attrs).unwrap();
 
-        assert!(result.is_err());
-        assert!(result
-            .unwrap_err()
-            .to_string()
-            .contains("Field-level converter is incompatible with impl_into"));
+        assert!(result.converter.is_some());
+        assert_eq!(result.impl_into, Some(false));
     }
 
     #[test]
@@ -1361,13 +3073,10 @@ mod tests {
             parse_quote!(#[builder(converter = |x: i32| x)]),
             parse_quote!(#[builder(impl_into)]),
         ];
-        let result = parse_field_attributes(&attrs);
+        let result = parse_field_attributes(&attrs).unwrap();
 
-        assert!(result.is_err());
-        assert!(result
-            .unwrap_err()
-            .to_string()
-            .contains("Field-level converter is incompatible with impl_into"));
+        assert!(result.converter.is_some());
+        assert_eq!(result.impl_into, Some(true));
     }
 
     // Valid combinations tests
@@ -1445,6 +3154,7 @@ mod tests {
             impl_into: None,
             converter: Some(syn::parse_str("|value: String| value").unwrap()),
             builder_method: false,
+            ..FieldAttributes::default()
         };
         assert!(valid_attrs.validate().is_ok());
 
@@ -1458,6 +3168,7 @@ mod tests {
             impl_into: None,
             converter: Some(syn::parse_str("|value: String| value").unwrap()),
             builder_method: false,
+            ..FieldAttributes::default()
         };
         assert!(valid_attrs.validate().is_ok());
 
@@ -1471,6 +3182,7 @@ mod tests {
             impl_into: None,
             converter: Some(syn::parse_str("|value: String| value").unwrap()),
             builder_method: false,
+            ..FieldAttributes::default()
         };
         let result = invalid_attrs.validate();
         assert!(result.is_err());
@@ -1479,8 +3191,8 @@ mod tests {
             .to_string()
             .contains("Field-level converter is incompatible with skip_setter"));
 
-        // Invalid: converter with impl_into
-        let invalid_attrs = FieldAttributes {
+        // Valid: converter chained with impl_into
+        let chained_attrs = FieldAttributes {
             required: false,
             setter_name: None,
             setter_prefix: None,
@@ -1489,13 +3201,9 @@ mod tests {
             impl_into: Some(true),
             converter: Some(syn::parse_str("|value: String| value").unwrap()),
             builder_method: false,
+            ..FieldAttributes::default()
         };
-        let result = invalid_attrs.validate();
-        assert!(result.is_err());
-        assert!(result
-            .unwrap_err()
-            .to_string()
-            .contains("Field-level converter is incompatible with impl_into"));
+        assert!(chained_attrs.validate().is_ok());
     }
 
     #[test]
@@ -1509,4 +3217,995 @@ mod tests {
         assert!(default_attrs.impl_into.is_none());
         assert!(default_attrs.converter.is_none());
     }
+
+    // Tests for transparent functionality
+
+    #[test]
+    fn test_parse_transparent_attribute() {
+        let attrs = vec![parse_quote!(#[builder(required, transparent)])];
+        let field_attrs = parse_field_attributes(&attrs).unwrap();
+
+        assert!(field_attrs.required);
+        assert!(field_attrs.transparent);
+        assert!(!FieldAttributes::default().transparent);
+    }
+
+    #[test]
+    fn test_parse_duplicate_transparent_error() {
+        let attrs = vec![parse_quote!(#[builder(required, transparent, transparent)])];
+        let result = parse_field_attributes(&attrs);
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Duplicate transparent attribute"));
+    }
+
+    #[test]
+    fn test_validate_transparent_requires_required() {
+        let attrs = vec![parse_quote!(#[builder(transparent)])];
+        let result = parse_field_attributes(&attrs);
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Field-level transparent requires required"));
+    }
+
+    #[test]
+    fn test_validate_transparent_incompatible_attributes() {
+        let with_converter = vec![parse_quote!(
+            #[builder(required, transparent, converter = |x: u32| Some(x))]
+        )];
+        assert!(parse_field_attributes(&with_converter)
+            .unwrap_err()
+            .to_string()
+            .contains("Field-level transparent is incompatible with converter"));
+
+        let with_impl_into = vec![parse_quote!(#[builder(required, transparent, impl_into)])];
+        assert!(parse_field_attributes(&with_impl_into)
+            .unwrap_err()
+            .to_string()
+            .contains("Field-level transparent is incompatible with impl_into"));
+    }
+
+    #[test]
+    fn test_parse_into_iter_attribute() {
+        let flag = parse_field_attributes(&[parse_quote!(#[builder(into_iter)])]).unwrap();
+        assert_eq!(flag.into_iter, Some(true));
+
+        let disabled =
+            parse_field_attributes(&[parse_quote!(#[builder(into_iter = false)])]).unwrap();
+        assert_eq!(disabled.into_iter, Some(false));
+
+        assert!(FieldAttributes::default().into_iter.is_none());
+    }
+
+    #[test]
+    fn test_parse_duplicate_into_iter_error() {
+        let attrs = vec![
+            parse_quote!(#[builder(into_iter)]),
+            parse_quote!(#[builder(into_iter = false)]),
+        ];
+        assert!(parse_field_attributes(&attrs)
+            .unwrap_err()
+            .to_string()
+            .contains("Duplicate into_iter attribute"));
+    }
+
+    #[test]
+    fn test_validate_into_iter_incompatible_attributes() {
+        let with_skip = vec![parse_quote!(#[builder(into_iter, skip_setter)])];
+        assert!(parse_field_attributes(&with_skip)
+            .unwrap_err()
+            .to_string()
+            .contains("Field-level into_iter is incompatible with skip_setter"));
+
+        let with_converter = vec![parse_quote!(
+            #[builder(into_iter, converter = |v: Vec<u32>| v)]
+        )];
+        assert!(parse_field_attributes(&with_converter)
+            .unwrap_err()
+            .to_string()
+            .contains("Field-level into_iter is incompatible with converter"));
+    }
+
+    #[test]
+    fn test_parse_nested_attribute() {
+        let attrs = vec![parse_quote!(#[builder(required, nested)])];
+        let field_attrs = parse_field_attributes(&attrs).unwrap();
+
+        assert!(field_attrs.nested);
+        assert!(!FieldAttributes::default().nested);
+
+        let duplicate = vec![parse_quote!(#[builder(nested, nested)])];
+        assert!(parse_field_attributes(&duplicate)
+            .unwrap_err()
+            .to_string()
+            .contains("Duplicate nested attribute"));
+    }
+
+    #[test]
+    fn test_validate_nested_incompatible_attributes() {
+        let cases = [
+            (
+                parse_quote!(#[builder(nested, skip_setter, default = Inner::new())]),
+                "skip_setter",
+            ),
+            (
+                parse_quote!(#[builder(nested, converter = |v: u32| Inner::new(v))]),
+                "converter",
+            ),
+            (
+                parse_quote!(#[builder(required, nested, builder_method)]),
+                "builder_method",
+            ),
+        ];
+
+        for (attr, conflict) in cases {
+            let error = parse_field_attributes(&[attr]).unwrap_err().to_string();
+            assert!(
+                error.contains(&format!(
+                    "Field-level nested is incompatible with {conflict}"
+                )),
+                "unexpected error: {error}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_nested_each_attribute() {
+        let attrs = vec![parse_quote!(#[builder(nested_each = "add_child")])];
+        let field_attrs = parse_field_attributes(&attrs).unwrap();
+        assert_eq!(field_attrs.nested_each.as_deref(), Some("add_child"));
+
+        let empty = vec![parse_quote!(#[builder(nested_each = "")])];
+        assert!(parse_field_attributes(&empty).is_err());
+
+        let cases = [
+            (
+                parse_quote!(#[builder(required, nested_each = "add_child")]),
+                "required",
+            ),
+            (
+                parse_quote!(#[builder(nested_each = "add_child", lazy_setter)]),
+                "lazy_setter",
+            ),
+            (
+                parse_quote!(#[builder(nested_each = "add", default_from = |b| Vec::new())]),
+                "default_from",
+            ),
+        ];
+
+        for (attr, conflict) in cases {
+            let error = parse_field_attributes(&[attr]).unwrap_err().to_string();
+            assert!(
+                error.contains(&format!(
+                    "Field-level nested_each is incompatible with {conflict}"
+                )),
+                "unexpected error: {error}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_flatten_explains_nested_alternative() {
+        let attrs = vec![parse_quote!(#[builder(flatten)])];
+        let error = parse_field_attributes(&attrs).unwrap_err().to_string();
+
+        assert!(error.contains("Field-level flatten is not supported"));
+        assert!(error.contains("#[builder(nested)]"));
+    }
+
+    #[test]
+    fn test_all_required_makes_fields_required() {
+        let struct_attrs = StructAttributes {
+            all_required: true,
+            ..StructAttributes::default()
+        };
+        let parse = |attrs: Vec<syn::Attribute>| {
+            parse_field_attributes_in_struct(&attrs, &struct_attrs).unwrap()
+        };
+
+        assert!(parse(vec![]).required);
+        assert!(parse(vec![parse_quote!(#[builder(setter_name = "set")])]).required);
+        assert!(!parse(vec![parse_quote!(#[builder(optional)])]).required);
+        assert!(!parse(vec![parse_quote!(#[builder(default = 1)])]).required);
+        assert!(!parse(vec![parse_quote!(#[builder(default_from = |b| 1)])]).required);
+        assert!(!parse(vec![parse_quote!(#[builder(computed = |b| 1)])]).required);
+        assert!(!parse(vec![parse_quote!(#[builder(skip_setter)])]).required);
+        assert!(!parse(vec![parse_quote!(#[builder(cfg(feature = "x"))])]).required);
+        assert!(
+            !parse(vec![
+                parse_quote!(#[builder(accumulate = |acc, value: u8| acc.push(value))])
+            ])
+            .required
+        );
+
+        // Without all_required, fields stay optional by default
+        assert!(!parse_field_attributes(&[]).unwrap().required);
+    }
+
+    #[test]
+    fn test_base_applies_to_fields_without_defaults() {
+        let struct_attrs = StructAttributes {
+            base: Some(parse_quote!(Config::default())),
+            ..StructAttributes::default()
+        };
+        let parse = |attrs: Vec<syn::Attribute>| {
+            parse_field_attributes_in_struct(&attrs, &struct_attrs).unwrap()
+        };
+
+        assert!(parse(vec![]).from_base);
+        assert!(parse(vec![parse_quote!(#[builder(skip_setter)])]).from_base);
+        assert!(!parse(vec![parse_quote!(#[builder(required)])]).from_base);
+        assert!(!parse(vec![parse_quote!(#[builder(default = 1)])]).from_base);
+        assert!(!parse(vec![parse_quote!(#[builder(default_from = |b| 1)])]).from_base);
+        assert!(!parse(vec![parse_quote!(#[builder(lazy_setter)])]).from_base);
+
+        // Without base, fields keep their own defaults
+        assert!(!parse_field_attributes(&[]).unwrap().from_base);
+    }
+
+    #[test]
+    fn test_fill_from_tracks_fields_holding_plain_values() {
+        let struct_attrs = StructAttributes {
+            fill_from: true,
+            ..StructAttributes::default()
+        };
+        let parse = |attrs: Vec<syn::Attribute>| {
+            parse_field_attributes_in_struct(&attrs, &struct_attrs).unwrap()
+        };
+
+        assert!(parse(vec![]).fill_from);
+        assert!(parse(vec![parse_quote!(#[builder(default = 1)])]).fill_from);
+        assert!(!parse(vec![parse_quote!(#[builder(required)])]).fill_from);
+        assert!(!parse(vec![parse_quote!(#[builder(lazy_setter)])]).fill_from);
+
+        // default_from fields are tracked already
+        let default_from = parse(vec![parse_quote!(#[builder(default_from = |b| 1)])]);
+        assert!(!default_from.fill_from && default_from.tracks_unset());
+
+        assert!(!parse_field_attributes(&[]).unwrap().tracks_unset());
+    }
+
+    #[test]
+    fn test_validate_optional_incompatible_with_required() {
+        let attrs = vec![parse_quote!(#[builder(required, optional)])];
+        assert!(parse_field_attributes(&attrs)
+            .unwrap_err()
+            .to_string()
+            .contains("Field-level optional is incompatible with required"));
+    }
+
+    #[test]
+    fn test_parse_skip_debug_attribute() {
+        let attrs = vec![parse_quote!(#[builder(skip_debug)])];
+        assert!(parse_field_attributes(&attrs).unwrap().skip_debug);
+        assert!(!FieldAttributes::default().skip_debug);
+
+        let duplicate = vec![parse_quote!(#[builder(skip_debug, skip_debug)])];
+        assert!(parse_field_attributes(&duplicate)
+            .unwrap_err()
+            .to_string()
+            .contains("Duplicate skip_debug attribute"));
+    }
+
+    #[test]
+    fn test_parse_cfg_attribute() {
+        let attrs = vec![parse_quote!(#[builder(cfg(feature = "metrics"))])];
+        let cfg = parse_field_attributes(&attrs).unwrap().cfg.unwrap();
+        assert_eq!(
+            quote::quote!(#cfg).to_string(),
+            quote::quote!(feature = "metrics").to_string()
+        );
+
+        let attrs = vec![parse_quote!(#[builder(cfg(all(unix, not(test))))])];
+        assert!(parse_field_attributes(&attrs).unwrap().cfg.is_some());
+
+        let duplicate = vec![parse_quote!(#[builder(cfg(unix), cfg(windows))])];
+        assert!(parse_field_attributes(&duplicate)
+            .unwrap_err()
+            .to_string()
+            .contains("Duplicate cfg attribute"));
+    }
+
+    #[test]
+    fn test_cfg_conflicts() {
+        let required = vec![parse_quote!(#[builder(required, cfg(unix))])];
+        assert!(parse_field_attributes(&required)
+            .unwrap_err()
+            .to_string()
+            .contains("Field-level cfg is incompatible with required"));
+
+        let skip = vec![parse_quote!(#[builder(skip_setter, default = 1, cfg(unix))])];
+        assert!(parse_field_attributes(&skip)
+            .unwrap_err()
+            .to_string()
+            .contains("Field-level cfg is incompatible with skip_setter"));
+    }
+
+    #[test]
+    fn test_parse_resolve_attribute() {
+        let attrs = vec![
+            parse_quote!(#[builder(resolve = |path: String, base: &String| base.clone() + &path)]),
+        ];
+        let resolve = parse_field_attributes(&attrs).unwrap().resolve;
+        assert!(matches!(resolve, Some(syn::Expr::Closure(_))));
+
+        let duplicate = vec![parse_quote!(#[builder(resolve = |v| v, resolve = |v| v)])];
+        assert!(parse_field_attributes(&duplicate)
+            .unwrap_err()
+            .to_string()
+            .contains("Duplicate resolve attribute"));
+    }
+
+    #[test]
+    fn test_parse_redact_attribute() {
+        let attrs = vec![parse_quote!(#[builder(redact)])];
+        assert!(parse_field_attributes(&attrs).unwrap().redact);
+        assert!(!FieldAttributes::default().redact);
+
+        let duplicate = vec![parse_quote!(#[builder(redact, redact)])];
+        assert!(parse_field_attributes(&duplicate)
+            .unwrap_err()
+            .to_string()
+            .contains("Duplicate redact attribute"));
+
+        let conflict = vec![parse_quote!(#[builder(redact, skip_debug)])];
+        assert!(parse_field_attributes(&conflict)
+            .unwrap_err()
+            .to_string()
+            .contains("Field-level redact is incompatible with skip_debug"));
+    }
+
+    #[test]
+    fn test_parse_sensitive_attribute() {
+        let attrs = vec![parse_quote!(#[builder(sensitive)])];
+        let result = parse_field_attributes(&attrs);
+
+        if cfg!(feature = "zeroize") {
+            assert!(result.unwrap().sensitive);
+
+            let conflict = vec![parse_quote!(#[builder(sensitive, resolve = |v| v)])];
+            assert!(parse_field_attributes(&conflict)
+                .unwrap_err()
+                .to_string()
+                .contains("Field-level sensitive is incompatible with resolve"));
+        } else {
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .contains("requires the `zeroize` feature"));
+        }
+    }
+
+    #[test]
+    fn test_parse_default_from_attribute() {
+        let attrs = vec![parse_quote!(#[builder(default_from = |b| b.width * b.height)])];
+        let default_from = parse_field_attributes(&attrs).unwrap().default_from;
+        assert!(matches!(default_from, Some(syn::Expr::Closure(_))));
+
+        let duplicate = vec![parse_quote!(#[builder(default_from = |b| 1, default_from = |b| 2)])];
+        assert!(parse_field_attributes(&duplicate)
+            .unwrap_err()
+            .to_string()
+            .contains("Duplicate default_from attribute"));
+
+        for (attrs, conflict) in [
+            (
+                vec![parse_quote!(#[builder(required, default_from = |b| 1)])],
+                "required",
+            ),
+            (
+                vec![parse_quote!(#[builder(default = 1, default_from = |b| 1)])],
+                "default",
+            ),
+            (
+                vec![parse_quote!(#[builder(lazy_setter, default_from = |b| 1)])],
+                "lazy_setter",
+            ),
+        ] {
+            assert!(parse_field_attributes(&attrs)
+                .unwrap_err()
+                .to_string()
+                .contains(&format!(
+                    "Field-level default_from is incompatible with {conflict}"
+                )));
+        }
+    }
+
+    #[test]
+    fn test_parse_computed_attribute() {
+        let attrs = vec![parse_quote!(#[builder(computed = |b| b.name.len())])];
+        let computed = parse_field_attributes(&attrs).unwrap().computed;
+        assert!(matches!(computed, Some(syn::Expr::Closure(_))));
+
+        let duplicate = vec![parse_quote!(#[builder(computed = |b| 1, computed = |b| 2)])];
+        assert!(parse_field_attributes(&duplicate)
+            .unwrap_err()
+            .to_string()
+            .contains("Duplicate computed attribute"));
+
+        for (attrs, conflict) in [
+            (
+                vec![parse_quote!(#[builder(required, computed = |b| 1)])],
+                "required",
+            ),
+            (
+                vec![parse_quote!(#[builder(skip_setter, computed = |b| 1)])],
+                "skip_setter",
+            ),
+            (
+                vec![parse_quote!(#[builder(setter_name = "set", computed = |b| 1)])],
+                "setter_name",
+            ),
+            (
+                vec![parse_quote!(#[builder(default_from = |b| 1, computed = |b| 1)])],
+                "default_from",
+            ),
+        ] {
+            assert!(parse_field_attributes(&attrs)
+                .unwrap_err()
+                .to_string()
+                .contains(&format!(
+                    "Field-level computed is incompatible with {conflict}"
+                )));
+        }
+    }
+
+    #[test]
+    fn test_parse_lazy_setter_attribute() {
+        let attrs = vec![parse_quote!(#[builder(lazy_setter)])];
+        assert!(parse_field_attributes(&attrs).unwrap().lazy_setter);
+        assert!(!FieldAttributes::default().lazy_setter);
+
+        let duplicate = vec![parse_quote!(#[builder(lazy_setter, lazy_setter)])];
+        assert!(parse_field_attributes(&duplicate)
+            .unwrap_err()
+            .to_string()
+            .contains("Duplicate lazy_setter attribute"));
+
+        for (attrs, conflict) in [
+            (
+                vec![parse_quote!(#[builder(lazy_setter, skip_setter, default = 1)])],
+                "skip_setter",
+            ),
+            (
+                vec![parse_quote!(#[builder(lazy_setter, nested)])],
+                "nested",
+            ),
+            (
+                vec![parse_quote!(#[builder(lazy_setter, resolve = |v| v)])],
+                "resolve",
+            ),
+        ] {
+            assert!(parse_field_attributes(&attrs)
+                .unwrap_err()
+                .to_string()
+                .contains(&format!(
+                    "Field-level lazy_setter is incompatible with {conflict}"
+                )));
+        }
+    }
+
+    #[test]
+    fn test_parse_std_converter() {
+        let attrs = vec![parse_quote!(#[builder(converter = std_converters::csv_list)])];
+        let result = parse_field_attributes(&attrs);
+
+        if cfg!(feature = "std-converters") {
+            assert!(result.unwrap().converter.is_some());
+
+            let unknown = vec![parse_quote!(#[builder(converter = std_converters::csv)])];
+            assert!(parse_field_attributes(&unknown)
+                .unwrap_err()
+                .to_string()
+                .contains("Unknown built-in converter `csv`"));
+        } else {
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .contains("require the `std-converters` feature"));
+        }
+    }
+
+    #[test]
+    fn test_parse_try_setter_attribute() {
+        let attrs = vec![parse_quote!(#[builder(required, try_setter)])];
+        assert!(parse_field_attributes(&attrs).unwrap().try_setter);
+        assert!(!FieldAttributes::default().try_setter);
+
+        let duplicate = vec![parse_quote!(#[builder(try_setter, try_setter)])];
+        assert!(parse_field_attributes(&duplicate)
+            .unwrap_err()
+            .to_string()
+            .contains("Duplicate try_setter attribute"));
+
+        for (attrs, conflict) in [
+            (
+                vec![parse_quote!(#[builder(try_setter, skip_setter, default = [0; 2])])],
+                "skip_setter",
+            ),
+            (
+                vec![parse_quote!(#[builder(try_setter, converter = |v: u8| [v; 2])])],
+                "converter",
+            ),
+            (
+                vec![parse_quote!(#[builder(try_setter, computed = |b| [0; 2])])],
+                "try_setter",
+            ),
+        ] {
+            let error = parse_field_attributes(&attrs).unwrap_err().to_string();
+            assert!(error.contains(conflict), "{error}");
+        }
+    }
+
+    #[test]
+    fn test_parse_setter_also_ref_attribute() {
+        let attrs = vec![parse_quote!(#[builder(required, setter(also_ref))])];
+        assert!(parse_field_attributes(&attrs).unwrap().also_ref);
+        assert!(!FieldAttributes::default().also_ref);
+
+        for (attrs, error) in [
+            (
+                vec![parse_quote!(#[builder(setter(also_ref, also_ref))])],
+                "Duplicate also_ref option",
+            ),
+            (
+                vec![parse_quote!(#[builder(setter(by_ref))])],
+                "Unknown setter option. Supported options: also_ref",
+            ),
+            (
+                vec![parse_quote!(#[builder(setter(also_ref), converter = |v: &str| v.len())])],
+                "Field-level setter(also_ref) is incompatible with converter",
+            ),
+            (
+                vec![parse_quote!(#[builder(setter(also_ref), skip_setter, default = 0)])],
+                "Field-level setter(also_ref) is incompatible with skip_setter",
+            ),
+            (
+                vec![parse_quote!(#[builder(setter(also_ref), non_empty, validate_on_set)])],
+                "Field-level setter(also_ref) is incompatible with validate_on_set",
+            ),
+        ] {
+            let message = parse_field_attributes(&attrs).unwrap_err().to_string();
+            assert!(message.contains(error), "{message}");
+        }
+    }
+
+    #[test]
+    fn test_parse_clone_from_ref_attribute() {
+        let attrs = vec![parse_quote!(#[builder(required, clone_from_ref)])];
+        assert!(parse_field_attributes(&attrs).unwrap().clone_from_ref);
+        assert!(!FieldAttributes::default().clone_from_ref);
+
+        for (attrs, error) in [
+            (
+                vec![parse_quote!(#[builder(clone_from_ref, clone_from_ref)])],
+                "Duplicate clone_from_ref attribute",
+            ),
+            (
+                vec![parse_quote!(#[builder(clone_from_ref, impl_into)])],
+                "Field-level clone_from_ref is incompatible with impl_into",
+            ),
+            (
+                vec![parse_quote!(#[builder(clone_from_ref, converter = |v: &str| v.len())])],
+                "Field-level clone_from_ref is incompatible with converter",
+            ),
+            (
+                vec![parse_quote!(#[builder(clone_from_ref, setter(also_ref))])],
+                "Field-level clone_from_ref is incompatible with setter(also_ref)",
+            ),
+            (
+                vec![parse_quote!(#[builder(clone_from_ref, skip_setter, default = 0)])],
+                "Field-level clone_from_ref is incompatible with skip_setter",
+            ),
+        ] {
+            let message = parse_field_attributes(&attrs).unwrap_err().to_string();
+            assert!(message.contains(error), "{message}");
+        }
+
+        // Turning impl_into off at the field level is not a conflict
+        let attrs = vec![parse_quote!(#[builder(clone_from_ref, impl_into = false)])];
+        assert!(parse_field_attributes(&attrs).is_ok());
+    }
+
+    #[test]
+    fn test_parse_wrap_attribute() {
+        let attrs = vec![parse_quote!(#[builder(required, wrap = u64)])];
+        let wrap = parse_field_attributes(&attrs).unwrap().wrap.unwrap();
+        assert_eq!(wrap.inner, parse_quote!(u64));
+        assert!(wrap.constructor.is_none());
+        assert!(FieldAttributes::default().wrap.is_none());
+
+        let attrs = vec![parse_quote!(#[builder(wrap(&str, with = Email::parse))])];
+        let wrap = parse_field_attributes(&attrs).unwrap().wrap.unwrap();
+        assert_eq!(wrap.inner, parse_quote!(&str));
+        assert_eq!(wrap.constructor, Some(parse_quote!(Email::parse)));
+
+        for (attrs, error) in [
+            (
+                vec![parse_quote!(#[builder(wrap = u64, wrap = u32)])],
+                "Duplicate wrap attribute",
+            ),
+            (
+                vec![parse_quote!(#[builder(wrap(u64, via = UserId::new))])],
+                "Unknown wrap option. Supported options: with",
+            ),
+            (
+                vec![parse_quote!(#[builder(wrap(u64, with = UserId::new, extra))])],
+                "Expected `wrap(InnerType)` or `wrap(InnerType, with = path)`",
+            ),
+            (
+                vec![parse_quote!(#[builder(wrap = u64, converter = |v: u64| v)])],
+                "Field-level wrap is incompatible with converter",
+            ),
+            (
+                vec![parse_quote!(#[builder(wrap = u64, try_setter)])],
+                "Field-level wrap is incompatible with try_setter",
+            ),
+        ] {
+            let message = parse_field_attributes(&attrs).unwrap_err().to_string();
+            assert!(message.contains(error), "{message}");
+        }
+    }
+
+    #[test]
+    fn test_parse_test_default_attribute() {
+        let attrs = vec![parse_quote!(#[builder(required, test_default = Email::new("a@b.c"))])];
+        let field_attrs = parse_field_attributes(&attrs).unwrap();
+        assert_eq!(
+            field_attrs.test_default,
+            Some(parse_quote!(Email::new("a@b.c")))
+        );
+        assert!(FieldAttributes::default().test_default.is_none());
+
+        let attrs = vec![parse_quote!(#[builder(test_default = 1, test_default = 2)])];
+        let error = parse_field_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("Duplicate test_default attribute"));
+
+        let attrs = vec![parse_quote!(#[builder(required(cfg(test)), test_default = 1)])];
+        let error = parse_field_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("Field-level required(cfg(...)) is incompatible with test_default"));
+    }
+
+    #[test]
+    fn test_parse_default_through_converter_attribute() {
+        let attrs = vec![parse_quote!(
+            #[builder(default = 8, converter = |kib: u32| kib * 1024, default_through_converter)]
+        )];
+        assert!(
+            parse_field_attributes(&attrs)
+                .unwrap()
+                .default_through_converter
+        );
+        assert!(!FieldAttributes::default().default_through_converter);
+
+        let attrs =
+            vec![parse_quote!(#[builder(default = 8, wrap = u32, default_through_converter)])];
+        assert!(parse_field_attributes(&attrs).is_ok());
+
+        let attrs = vec![parse_quote!(
+            #[builder(default = 8, default_through_converter, default_through_converter)]
+        )];
+        let error = parse_field_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("Duplicate default_through_converter attribute"));
+
+        for attrs in [
+            vec![parse_quote!(#[builder(default = 8, default_through_converter)])],
+            vec![
+                parse_quote!(#[builder(converter = |kib: u32| kib * 1024, default_through_converter)]),
+            ],
+        ] {
+            let error = parse_field_attributes(&attrs).unwrap_err().to_string();
+            assert!(error
+                .contains("Field-level default_through_converter requires default and converter"));
+        }
+    }
+
+    #[test]
+    fn test_parse_setter_input_attribute() {
+        let attrs = vec![parse_quote!(#[builder(setter_input = "u8")])];
+        let parsed = parse_field_attributes(&attrs).unwrap();
+        assert!(parsed.setter_input.is_some());
+        assert_eq!(parsed.via, None);
+        assert!(FieldAttributes::default().setter_input.is_none());
+
+        let attrs = vec![parse_quote!(#[builder(setter_input = u32, via = TryFrom)])];
+        let parsed = parse_field_attributes(&attrs).unwrap();
+        assert_eq!(parsed.via, Some(SetterInputVia::TryFrom));
+
+        let attrs = vec![parse_quote!(#[builder(setter_input = u8, setter_input = u16)])];
+        let error = parse_field_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("Duplicate setter_input attribute"));
+
+        let attrs = vec![parse_quote!(#[builder(setter_input = u8, via = Into)])];
+        let error = parse_field_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("Unknown via trait. Supported traits: From, TryFrom"));
+
+        let attrs = vec![parse_quote!(#[builder(via = From)])];
+        let error = parse_field_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("Field-level via requires setter_input"));
+
+        for (attrs, conflict) in [
+            (
+                vec![parse_quote!(#[builder(setter_input = u8, converter = |v: u8| v as u16)])],
+                "converter",
+            ),
+            (
+                vec![parse_quote!(#[builder(setter_input = u32, via = TryFrom, impl_into)])],
+                "impl_into",
+            ),
+        ] {
+            let error = parse_field_attributes(&attrs).unwrap_err().to_string();
+            assert!(
+                error.contains(&format!(
+                    "Field-level setter_input is incompatible with {conflict}"
+                )),
+                "{error}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_boxed_field_attribute() {
+        let attrs = vec![parse_quote!(#[builder(required, boxed_field)])];
+        assert!(parse_field_attributes(&attrs).unwrap().boxed_field);
+        assert!(!FieldAttributes::default().boxed_field);
+
+        let attrs = vec![parse_quote!(#[builder(boxed_field, boxed_field)])];
+        let error = parse_field_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("Duplicate boxed_field attribute"));
+
+        let attrs = vec![parse_quote!(#[builder(computed = |b| 1, boxed_field)])];
+        let error = parse_field_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("Field-level computed is incompatible with boxed_field"));
+    }
+
+    #[test]
+    fn test_parse_dyn_into_attribute() {
+        let attrs = vec![parse_quote!(#[builder(required, dyn_into)])];
+        assert!(parse_field_attributes(&attrs).unwrap().dyn_into);
+        assert!(!FieldAttributes::default().dyn_into);
+
+        for (attrs, error) in [
+            (
+                vec![parse_quote!(#[builder(dyn_into, dyn_into)])],
+                "Duplicate dyn_into attribute",
+            ),
+            (
+                vec![parse_quote!(#[builder(dyn_into, impl_into)])],
+                "Field-level dyn_into is incompatible with impl_into",
+            ),
+            (
+                vec![parse_quote!(#[builder(dyn_into, wrap = u8)])],
+                "Field-level dyn_into is incompatible with wrap",
+            ),
+            (
+                vec![parse_quote!(#[builder(dyn_into, try_setter)])],
+                "Field-level dyn_into is incompatible with try_setter",
+            ),
+        ] {
+            let message = parse_field_attributes(&attrs).unwrap_err().to_string();
+            assert!(message.contains(error), "{message}");
+        }
+    }
+
+    #[test]
+    fn test_parse_builder_only_attribute() {
+        let attrs = vec![parse_quote!(#[builder(required, builder_only)])];
+        assert!(parse_field_attributes(&attrs).unwrap().builder_only);
+        assert!(!FieldAttributes::default().builder_only);
+
+        let duplicate = vec![parse_quote!(#[builder(builder_only, builder_only)])];
+        assert!(parse_field_attributes(&duplicate)
+            .unwrap_err()
+            .to_string()
+            .contains("Duplicate builder_only attribute"));
+
+        for (attrs, conflict) in [
+            (
+                vec![parse_quote!(#[builder(builder_only, skip_setter, default = 0)])],
+                "skip_setter",
+            ),
+            (
+                vec![parse_quote!(#[builder(builder_only, computed = |b| 0)])],
+                "computed",
+            ),
+        ] {
+            let error = parse_field_attributes(&attrs).unwrap_err().to_string();
+            assert!(error.contains(conflict), "{error}");
+        }
+    }
+
+    #[test]
+    fn test_parse_required_cfg_attribute() {
+        let attrs =
+            vec![parse_quote!(#[builder(required(cfg(feature = "strict")), default = 8080)])];
+        let mut attributes = parse_field_attributes(&attrs).unwrap();
+        assert!(!attributes.required);
+        let predicate = attributes.required_cfg.as_ref().unwrap();
+        assert_eq!(
+            quote::quote!(#predicate).to_string(),
+            "feature = \"strict\""
+        );
+
+        let mut optional = attributes.clone();
+        optional.apply_required_cfg(false);
+        assert!(!optional.required && optional.required_cfg.is_none());
+        assert!(optional.default_value.is_some());
+        attributes.apply_required_cfg(true);
+        assert!(attributes.required && attributes.required_cfg.is_none());
+        assert!(attributes.default_value.is_none());
+
+        let condition = vec![parse_quote!(#[builder(required(if_feature = "strict"))])];
+        assert!(parse_field_attributes(&condition)
+            .unwrap_err()
+            .to_string()
+            .contains("Unsupported required condition"));
+
+        for (attrs, conflict) in [
+            (
+                vec![parse_quote!(#[builder(required, required(cfg(test)))])],
+                "Duplicate required attribute",
+            ),
+            (
+                vec![parse_quote!(#[builder(required(cfg(test)), optional)])],
+                "optional",
+            ),
+            (
+                vec![parse_quote!(#[builder(required(cfg(test)), default_from = |b| 0)])],
+                "default_from",
+            ),
+        ] {
+            let error = parse_field_attributes(&attrs).unwrap_err().to_string();
+            assert!(error.contains(conflict), "{error}");
+        }
+    }
+
+    #[test]
+    fn test_parse_validate_attribute() {
+        let attrs = vec![
+            parse_quote!(#[builder(validate = |port: &u16| *port != 0, validate_message = "port must not be 0")]),
+        ];
+        let attributes = parse_field_attributes(&attrs).unwrap();
+        assert!(attributes.validate.is_some());
+        assert_eq!(
+            attributes.validate_message.unwrap().value(),
+            "port must not be 0"
+        );
+        assert!(FieldAttributes::default().validate.is_none());
+
+        let duplicate = vec![parse_quote!(#[builder(validate = is_valid, validate = is_valid)])];
+        assert!(parse_field_attributes(&duplicate)
+            .unwrap_err()
+            .to_string()
+            .contains("Duplicate validate attribute"));
+
+        for (attrs, error) in [
+            (
+                vec![parse_quote!(#[builder(validate_message = "invalid")])],
+                "validate_message requires validate",
+            ),
+            (
+                vec![parse_quote!(#[builder(builder_only, validate = is_valid)])],
+                "validate is incompatible with builder_only",
+            ),
+        ] {
+            let message = parse_field_attributes(&attrs).unwrap_err().to_string();
+            assert!(message.contains(error), "{message}");
+        }
+    }
+
+    #[test]
+    fn test_parse_validate_on_set_attribute() {
+        let attrs = vec![parse_quote!(#[builder(required, non_empty, validate_on_set)])];
+        assert!(parse_field_attributes(&attrs).unwrap().validate_on_set);
+        assert!(!FieldAttributes::default().validate_on_set);
+
+        for (attrs, error) in [
+            (
+                vec![parse_quote!(#[builder(validate_on_set)])],
+                "validate_on_set requires validate, non_empty or range",
+            ),
+            (
+                vec![parse_quote!(#[builder(range = 1..10, validate_on_set, try_setter)])],
+                "validate_on_set is incompatible with try_setter",
+            ),
+            (
+                vec![
+                    parse_quote!(#[builder(non_empty, validate_on_set, skip_setter, default = vec![1])]),
+                ],
+                "validate_on_set is incompatible with skip_setter",
+            ),
+        ] {
+            let message = parse_field_attributes(&attrs).unwrap_err().to_string();
+            assert!(message.contains(error), "{message}");
+        }
+    }
+
+    #[test]
+    fn test_parse_validation_shorthands() {
+        let attrs =
+            vec![parse_quote!(#[builder(non_empty, range = 1.., validate_message = "invalid")])];
+        let attributes = parse_field_attributes(&attrs).unwrap();
+        assert!(attributes.non_empty);
+        assert!(attributes.range.is_some());
+        assert_eq!(attributes.validation_attribute(), Some("non_empty"));
+        assert_eq!(FieldAttributes::default().validation_attribute(), None);
+
+        for (attrs, error) in [
+            (
+                vec![parse_quote!(#[builder(range = 100)])],
+                "range must be a range expression",
+            ),
+            (
+                vec![parse_quote!(#[builder(range = ..)])],
+                "range must be a range expression",
+            ),
+            (
+                vec![parse_quote!(#[builder(non_empty, non_empty)])],
+                "Duplicate non_empty attribute",
+            ),
+            (
+                vec![parse_quote!(#[builder(builder_only, range = 0..10)])],
+                "range is incompatible with builder_only",
+            ),
+        ] {
+            let message = parse_field_attributes(&attrs).unwrap_err().to_string();
+            assert!(message.contains(error), "{message}");
+        }
+    }
+
+    #[test]
+    fn test_parse_accumulate_attribute() {
+        let attrs = vec![
+            parse_quote!(#[builder(accumulate = |tags, tag: &str| tags.push(tag.to_string()))]),
+        ];
+        assert!(parse_field_attributes(&attrs).unwrap().accumulate.is_some());
+        assert!(FieldAttributes::default().accumulate.is_none());
+
+        let duplicate = vec![
+            parse_quote!(#[builder(accumulate = |a, b: u8| *a += b, accumulate = |a, b: u8| *a -= b)]),
+        ];
+        assert!(parse_field_attributes(&duplicate)
+            .unwrap_err()
+            .to_string()
+            .contains("Duplicate accumulate attribute"));
+
+        let untyped = vec![parse_quote!(#[builder(accumulate = |tags, tag| tags.push(tag))])];
+        assert!(parse_field_attributes(&untyped)
+            .unwrap_err()
+            .to_string()
+            .contains("the second of them typed"));
+
+        for (attrs, conflict) in [
+            (
+                vec![parse_quote!(#[builder(required, accumulate = |a, b: u8| *a += b)])],
+                "required",
+            ),
+            (
+                vec![
+                    parse_quote!(#[builder(converter = |v: u8| v, accumulate = |a, b: u8| *a += b)]),
+                ],
+                "converter",
+            ),
+            (
+                vec![parse_quote!(#[builder(lazy_setter, accumulate = |a, b: u8| *a += b)])],
+                "lazy_setter",
+            ),
+            (
+                vec![
+                    parse_quote!(#[builder(default_from = |b| 0, accumulate = |a, b: u8| *a += b)]),
+                ],
+                "default_from",
+            ),
+        ] {
+            let error = parse_field_attributes(&attrs).unwrap_err().to_string();
+            assert!(error.contains(conflict), "{error}");
+        }
+    }
 }
@@ -14,7 +14,7 @@
 //!
 //! - `#[builder(required)]` - Mark field as required
 //! - `#[builder(setter_name = "name")]` - Custom setter method name
-//! - `#[builder(default = "expr")]` - Custom default value expression
+//! - `#[builder(default = expr)]` - Custom default value expression
 //! - `#[builder(skip_setter)]` - Don't generate a setter method
 //!
 //! # Usage
@@ -28,5 +28,5 @@ pub mod field_attrs;
 pub mod struct_attrs;
 
 // Re-export main types for convenience
-pub use field_attrs::{parse_field_attributes, FieldAttributes};
+pub use field_attrs::{parse_field_attributes_in_struct, FieldAttributes, SetterInputVia};
 pub use struct_attrs::{parse_struct_attributes, StructAttributes};
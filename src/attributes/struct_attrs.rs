@@ -7,8 +7,42 @@
 //!
 //! - `build_method = "name"` - Specifies a custom name for the build method
 //! - `setter_prefix = "prefix_"` - Specifies a prefix for all setter method names
+//! - `prefix_scope = "all" | "optional"` - Which setters the struct-level prefix applies to (default `"all"`)
 //! - `impl_into` - Use `impl Into<FieldType>` for setter parameters instead of `FieldType`
 //! - `const` - Generate const-compatible builder methods for compile-time construction
+//! - `state_traits` - Generate traits implemented by every builder state for extension traits
+//! - `setter_trait` - Generate a `{Struct}BuilderSetters` trait with the optional setters, implemented by every state
+//! - `into_iter` - `Vec<T>` setters accept `impl IntoIterator<Item = impl Into<T>>`
+//! - `all_required` - Every field is required unless it is `optional` or has a default
+//! - `assert_send` / `assert_send_sync` - Statically assert that every builder state is `Send` (and `Sync`)
+//! - `static_assert(Send, Sync, Unpin)` - Statically assert that every builder state implements the listed auto traits
+//! - `module = "name"` - Generate the builder types into a child module with the given name
+//! - `finishers(name, name(validate = path, error = Type), name(into = Type), name(garde))` - Additional build methods
+//! - `no_docs` - Emit no generated doc comments on builder items
+//! - `hide_states` - Keep the intermediate builder states out of the enclosing namespace
+//! - `stable_names` - Make the state aliases the only public names of the builder states
+//! - `build_by = "clone"` - The build method takes `&self` and builds from clones of the fields
+//! - `option_into` - Optional `Option<T>` setters accept `impl Into<Option<T>>`
+//! - `lite` - Generate `new(required...)` and `with_*` methods on the struct instead of builder types
+//! - `lints = "allow" | "warn" | "deny"` - How the builder lints are reported (default `"warn"`)
+//! - `debug_expand` - Show the generated code in a compiler warning
+//! - `arbitrary` - Implement `arbitrary::Arbitrary` by driving the builder (`arbitrary` feature)
+//! - `non_exhaustive` / `non_exhaustive = false` - Whether the builder types are `#[non_exhaustive]`
+//! - `build_vis = "pub(crate)"` - Visibility of the methods that build the struct without validation
+//! - `entry_vis = "pub(crate)"` - Visibility of the methods that start the builder
+//! - `no_entry` - Generate no `builder()` method, only the builder types
+//! - `default_struct = "Config::default()"` - Take unset optional fields from a template instance
+//! - `base = "Config::default()"` - Take unset optional fields from an instance created by the build method
+//! - `fill_from` - Generate `fill_from(&Struct)`, which copies the optional fields that are still unset from an instance
+//! - `apply_env` - Generate `apply_env(prefix)`, which sets optional fields from environment variables (`env` feature)
+//! - `test_default` / `test_default(cfg(predicate))` - Generate `test_builder()`, a complete builder for tests
+//! - `derive(Clone)` / `derive(Copy, Clone)` - Implement `Clone` (and `Copy`) for the builder types
+//! - `derive(Serialize, Deserialize)` - Derive serde's traits for the builder types
+//! - `impl_trait = "path::Trait"` - Implement a trait whose items all have defaults for every builder state
+//! - `buildable = "path::Buildable"` - Implement a `{ type Builder; fn builder() }` trait for the struct
+//! - `field_descriptors = "path::FieldDescriptor"` - Generate a `FIELDS` constant describing the fields (`metadata` feature)
+//! - `also_dynamic` - Also generate `{Struct}DynBuilder`, which checks for unset required fields at runtime
+//! - `nestable` - Generate the items that `#[builder(nested)]` fields of other structs use to nest this builder
 //!
 
 /// Configuration derived from struct-level builder attributes.
@@ -20,8 +54,45 @@
 ///
 /// * `build_method_name` - Custom name for the final build method (None = "build")
 /// * `setter_prefix` - Common prefix for all setter method names (None = no prefix)
+/// * `setter_prefix_except` - Fields whose setters the struct-level prefix skips
+/// * `prefix_scope` - Which setters the struct-level prefix applies to
 /// * `impl_into` - Whether setters should accept `impl Into<FieldType>` (false = use `FieldType`)
 /// * `const` - Whether to generate const-compatible builder methods
+/// * `state_traits` - Whether to generate traits implemented by the builder states
+/// * `setter_trait` - Whether to generate a trait with the optional setters of every builder state
+/// * `into_iter` - Whether `Vec<T>` setters accept any iterable of convertible items
+/// * `all_required` - Whether fields are required unless opted out
+/// * `assert_send` - Whether to assert that every builder state is `Send`
+/// * `assert_sync` - Whether to assert that every builder state is `Sync`
+/// * `module` - Child module the builder types are generated into (None = beside the struct)
+/// * `finishers` - Additional build methods declared with `finishers(...)`
+/// * `no_docs` - Whether generated doc comments are left out
+/// * `hide_states` - Whether the intermediate builder states are hidden in an anonymous const
+/// * `stable_names` - Whether only the documented aliases name the builder states
+/// * `build_by_clone` - Whether the build method borrows the builder and clones its fields
+/// * `option_into` - Whether optional `Option<T>` setters accept a bare `T` or an `Option<T>`
+/// * `lite` - Whether to generate methods on the struct itself instead of builder types
+/// * `lints` - How the builder lints (e.g. `impl_into` on primitive fields) are reported
+/// * `debug_expand` - Whether the generated code is shown in a compiler warning
+/// * `arbitrary` - Whether `arbitrary::Arbitrary` is implemented through the builder
+/// * `non_exhaustive` - Whether the builder types are `#[non_exhaustive]` (None = like the struct)
+/// * `build_vis` - Visibility of the build method (None = like the builder)
+/// * `entry_vis` - Visibility of `builder()` and the builder constructors (None = like the builder)
+/// * `no_entry` - Whether `builder()` is left out, so builders start from the state types
+/// * `default_struct` - Template instance the optional fields without a default start from
+/// * `base` - Instance the build method takes the unset optional fields from
+/// * `fill_from` - Whether the builder states get a `fill_from` method taking unset fields from an instance
+/// * `apply_env` - Whether the builder states get an `apply_env` method reading environment variables
+/// * `derive_clone`, `derive_copy` - Whether the builder types implement `Clone` and `Copy`
+/// * `derive_serialize`, `derive_deserialize` - Whether the builder types derive serde's traits
+/// * `impl_traits` - User traits implemented for every builder state
+/// * `buildable` - User trait giving generic code the builder of the struct (None = not implemented)
+/// * `field_descriptors` - User struct the `FIELDS` constant describes the fields with (None = no constant)
+/// * `also_dynamic` - Whether a runtime-checked `{Struct}DynBuilder` is generated next to the builder
+/// * `nestable` - Whether other builders can nest this one with `#[builder(nested)]`
+/// * `state_aliases` - Public aliases for the initial and complete builder states (None = no aliases)
+/// * `boxed` - Whether the builder keeps its values on the heap and builds a `Box<Struct>`
+/// * `test_default` - Configuration predicate under which `test_builder()` exists (None = no method)
 ///
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StructAttributes {
@@ -54,6 +125,18 @@ pub struct StructAttributes {
     /// # Examples
     pub setter_prefix: Option<String>,
 
+    /// Fields whose setters keep their natural names despite `setter_prefix`.
+    ///
+    /// Set by `#[builder(setter_prefix(except(id, name)))]`. A field-level
+    /// `setter_prefix` still applies to a listed field.
+    pub setter_prefix_except: Vec<syn::Ident>,
+
+    /// Which setters the struct-level `setter_prefix` applies to.
+    ///
+    /// Set by `#[builder(prefix_scope = "optional")]` so the required setters
+    /// keep their bare names while the optional ones get the prefix.
+    pub prefix_scope: PrefixScope,
+
     /// Whether setter methods should use `impl Into<FieldType>` parameters.
     ///
     /// If false, setter methods use the field type directly: `fn field(value: FieldType)`
@@ -83,6 +166,503 @@ pub struct StructAttributes {
     /// - All optional fields must have explicit `#[builder(default = expr)]`
     /// - Closure converters are transformed into generated const fns
     pub const_builder: bool,
+
+    /// Whether to generate public traits implemented by the builder states.
+    ///
+    /// When enabled, a `{Struct}BuilderState` trait is implemented by every
+    /// builder state and a `{Struct}BuilderFinish` trait by the state(s) that
+    /// can build the struct. Downstream crates can write extension traits
+    /// against these instead of naming concrete state types.
+    pub state_traits: bool,
+
+    /// Whether to generate a trait with the optional field setters.
+    ///
+    /// When enabled, a `{Struct}BuilderSetters` trait declares the setters of
+    /// the optional fields and every builder state implements it, so helpers
+    /// can configure a builder without fixing which required fields are set.
+    pub setter_trait: bool,
+
+    /// Whether `Vec<T>` setters accept `impl IntoIterator<Item = impl Into<T>>`.
+    ///
+    /// Only fields whose type is `Vec<T>` are affected; other fields keep their
+    /// regular or `impl_into` setters. Takes precedence over `impl_into` for the
+    /// affected fields.
+    ///
+    /// # Field-Level Override
+    ///
+    /// Individual fields can override this struct-level setting using
+    /// `#[builder(into_iter = true/false)]` on the field itself.
+    pub into_iter: bool,
+
+    /// Whether every field is required by default.
+    ///
+    /// When enabled, fields are required unless they are marked
+    /// `#[builder(optional)]`, have a `#[builder(default = ...)]`, use
+    /// `#[builder(skip_setter)]`, or accumulate with `#[builder(accumulate)]`.
+    /// Useful for domain types where almost every field is mandatory.
+    pub all_required: bool,
+
+    /// Whether to statically assert that every builder state is `Send`.
+    ///
+    /// Set by `#[builder(assert_send)]`, `#[builder(assert_send_sync)]` and
+    /// `#[builder(static_assert(Send))]`. For generic structs the assertion
+    /// assumes every type parameter implements the asserted traits, so it
+    /// catches builders that lose the auto trait through their own fields
+    /// rather than through the parameters.
+    pub assert_send: bool,
+
+    /// Whether to statically assert that every builder state is `Sync`.
+    ///
+    /// Set by `#[builder(assert_send_sync)]` and `#[builder(static_assert(Sync))]`.
+    pub assert_sync: bool,
+
+    /// Whether to statically assert that every builder state is `Unpin`.
+    ///
+    /// Set by `#[builder(static_assert(Unpin))]`.
+    pub assert_unpin: bool,
+
+    /// Child module that the generated builder items are placed in.
+    ///
+    /// A derive macro can only emit items next to the struct, so
+    /// `#[builder(module = "user_builder")]` wraps them in a sibling module
+    /// instead. Codebases that keep builders apart from their domain types can
+    /// then re-export the module's contents from wherever builders live.
+    pub module: Option<syn::Ident>,
+
+    /// Additional build methods available next to the build method.
+    ///
+    /// Each finisher builds the struct with the regular build method and then
+    /// optionally validates and/or converts the result.
+    pub finishers: Vec<Finisher>,
+
+    /// Whether to leave generated doc comments off the builder items.
+    ///
+    /// Set by `#[builder(no_docs)]` for crates whose doc lints trip over the
+    /// generated prose. Doc comments on the struct's fields are still copied
+    /// to their setters.
+    pub no_docs: bool,
+
+    /// Whether to generate the type-state builder states inside an anonymous const.
+    ///
+    /// Set by `#[builder(hide_states)]` so the `{Struct}Builder_Has..._Missing...`
+    /// types stay out of glob imports and autocomplete. Only the initial state is
+    /// nameable, through a `{Struct}Builder` alias.
+    pub hide_states: bool,
+
+    /// Whether the builder states are only public through stable aliases.
+    ///
+    /// Set by `#[builder(stable_names)]` for crates whose public API exposes
+    /// builder states. It implies `hide_states` and `state_aliases`, so the
+    /// generated state names never appear in the API and only the
+    /// `{Struct}Builder` alias and the state aliases can be named.
+    pub stable_names: bool,
+
+    /// Whether the build method takes `&self` and builds from clones of the fields.
+    ///
+    /// Set by `#[builder(build_by = "clone")]`, so one configured builder can
+    /// build many instances. Every field type must implement `Clone`.
+    pub build_by_clone: bool,
+
+    /// Whether optional `Option<T>` setters accept `impl Into<Option<T>>`.
+    ///
+    /// Callers can then pass either `value` or `Some(value)`/`None`. This is
+    /// `impl_into` restricted to optional `Option<T>` fields, so a field-level
+    /// `#[builder(impl_into = false)]` opts a field back out.
+    pub option_into: bool,
+
+    /// Whether to generate chainable methods on the struct instead of a builder.
+    ///
+    /// Set by `#[builder(lite)]`. The struct gets a `new` constructor taking the
+    /// required fields and a `with_{field}` method for each optional field, with
+    /// no builder types at all. Suited to small structs where the type-state
+    /// builder would be overkill.
+    pub lite: bool,
+
+    /// How the builder lints are reported.
+    ///
+    /// Set by `#[builder(lints = "...")]`. Lints flag configurations that
+    /// compile but are likely mistakes, such as `impl_into` on a `u16` field.
+    pub lints: LintLevel,
+
+    /// Whether to show the generated code in a compiler warning.
+    ///
+    /// Set by `#[builder(debug_expand)]` to inspect the builder states and
+    /// methods without `cargo expand`. Meant to be removed again after debugging.
+    pub debug_expand: bool,
+
+    /// Whether to implement `arbitrary::Arbitrary` for the struct.
+    ///
+    /// Set by `#[builder(arbitrary)]`, which requires the `arbitrary` feature.
+    /// The implementation calls the setters with arbitrary values, so fuzzed
+    /// values pass through the same converters and defaults as production code.
+    pub arbitrary: bool,
+
+    /// Whether the builder types are marked `#[non_exhaustive]`.
+    ///
+    /// Set by `#[builder(non_exhaustive)]` or `#[builder(non_exhaustive = false)]`.
+    /// If None, the builder types follow the struct's own `#[non_exhaustive]`.
+    pub non_exhaustive: Option<bool>,
+
+    /// Visibility of the methods that build the struct without validation.
+    ///
+    /// Set by `#[builder(build_vis = "pub(crate)")]`. The build method,
+    /// `{build}_into`, `{build}_clone` and the nesting support get this
+    /// visibility and the `From<Builder>` impl is left out, while the
+    /// finishers declared with `finishers(...)` stay as visible as the
+    /// builder. If None, the build method is as visible as the builder.
+    pub build_vis: Option<syn::Visibility>,
+
+    /// Visibility of the methods that start the builder.
+    ///
+    /// Set by `#[builder(entry_vis = "pub(crate)")]`. `builder()` (or the
+    /// `builder_method` entry point, or `new` in lite mode) and the `new`
+    /// constructors of the builder types get this visibility, and the regular
+    /// builder's `Default` impl is left out. If None, they are as visible as
+    /// the builder.
+    pub entry_vis: Option<syn::Visibility>,
+
+    /// Whether the entry method on the struct is left out.
+    ///
+    /// Set by `#[builder(no_entry)]`. The builder types, setters and build
+    /// method are generated as usual, but not `builder()` or the
+    /// `builder_method` entry point, so an entry function of your own can
+    /// start the builder with `new()` and pre-populate fields.
+    pub no_entry: bool,
+
+    /// Template instance that optional fields start from.
+    ///
+    /// Set by `#[builder(default_struct = "Config::default()")]`. The
+    /// expression is evaluated once when the builder is created, with `Self`
+    /// naming the struct, and every optional field without a `default`,
+    /// `default_from` or `computed` of its own starts with the template's value.
+    pub default_struct: Option<syn::Expr>,
+
+    /// Base instance that the build method takes unset fields from.
+    ///
+    /// Set by `#[builder(base = "Config::default()")]`. The expression is
+    /// evaluated when the struct is built, with `Self` naming the struct, and
+    /// every optional field that was not set and has no `default`,
+    /// `default_from` or `computed` of its own takes the base's value.
+    pub base: Option<syn::Expr>,
+
+    /// Whether every builder state gets a `fill_from(&Struct)` method.
+    ///
+    /// Set by `#[builder(fill_from)]`. The builder tracks which optional
+    /// fields were set, and `fill_from` clones the values of the others from
+    /// an existing instance, so layers of configuration can be applied from
+    /// the most to the least specific one.
+    pub fill_from: bool,
+
+    /// Whether every builder state gets an `apply_env(prefix)` method.
+    ///
+    /// Set by `#[builder(apply_env)]`, which requires the `env` feature. The
+    /// method reads `{PREFIX}_{FIELD}` for every optional field with a setter,
+    /// parses it with `FromStr` and passes it to the setter, skipping the
+    /// variables that are not set.
+    pub apply_env: bool,
+
+    /// Whether the builder types implement `Clone`.
+    ///
+    /// Set by `#[builder(derive(Clone))]`. Each builder state is `Clone` when
+    /// the values it stores are, so a partially configured builder can be
+    /// duplicated and finished in different ways.
+    pub derive_clone: bool,
+
+    /// Whether the builder types implement `Copy`.
+    ///
+    /// Set by `#[builder(derive(Copy, Clone))]`. Each builder state is `Copy`
+    /// when the values it stores are, which suits small structs built in
+    /// const or table-driven code.
+    pub derive_copy: bool,
+
+    /// Whether the builder types derive `serde::Serialize`.
+    ///
+    /// Set by `#[builder(derive(Serialize))]`. Each state writes the values
+    /// it holds under the field names, skipping required fields it has not
+    /// set, so a partially filled builder can be persisted as a draft.
+    pub derive_serialize: bool,
+
+    /// Whether the builder types derive `serde::Deserialize`.
+    ///
+    /// Set by `#[builder(derive(Deserialize))]`. A draft restores into any
+    /// state whose set fields it contains.
+    pub derive_deserialize: bool,
+
+    /// User traits implemented for every builder state.
+    ///
+    /// Set by `#[builder(impl_trait = "crate::Described")]`, which may be
+    /// repeated. The impls are empty, so every item of the trait needs a
+    /// default; this lets registries and plugin systems handle the builders of
+    /// different structs uniformly through a trait they define.
+    pub impl_traits: Vec<syn::Path>,
+
+    /// User trait that names the builder of the struct.
+    ///
+    /// Set by `#[builder(buildable = "crate::Buildable")]`. The trait must
+    /// declare `type Builder;` and `fn builder() -> Self::Builder;`, which the
+    /// struct implements with its initial builder state and `builder()`, so
+    /// frameworks can start the builders of different structs generically.
+    pub buildable: Option<syn::Path>,
+
+    /// User struct describing a field at runtime.
+    ///
+    /// Set by `#[builder(field_descriptors = "crate::FieldDescriptor")]`,
+    /// which requires the `metadata` feature. The struct gets a `FIELDS`
+    /// constant with one descriptor per field that has a setter, giving its
+    /// name, whether it is required, its type, whether it has a default and
+    /// its documentation.
+    pub field_descriptors: Option<syn::Path>,
+
+    /// Whether a runtime-checked builder is generated next to the builder.
+    ///
+    /// Set by `#[builder(also_dynamic)]`. `{Struct}DynBuilder` keeps every
+    /// value in an `Option` of one type, so code that sets fields from data,
+    /// such as a plugin manifest, can hold it in a variable and set fields in
+    /// any order. Its build method reports unset required fields as an error
+    /// and builds through the regular builder.
+    pub also_dynamic: bool,
+
+    /// Whether other builders can nest this one.
+    ///
+    /// Set by `#[builder(nestable)]`. The struct gets hidden
+    /// `{Struct}BuilderStart` and `{Struct}BuilderReady` aliases and a hidden
+    /// function building it from a configuration closure, which the
+    /// `#[builder(nested)]` and `nested_each` methods of other structs call.
+    pub nestable: bool,
+
+    /// Public type aliases for the initial and complete builder states.
+    ///
+    /// Set by `#[builder(state_aliases)]` or
+    /// `#[builder(state_aliases(start = "NewUser", complete = "ReadyUser"))]`.
+    /// The aliases give function signatures and struct fields that store
+    /// builders a readable name instead of `UserBuilder_HasName_HasEmail`.
+    pub state_aliases: Option<StateAliases>,
+
+    /// Whether the builder keeps its values on the heap.
+    ///
+    /// Set by `#[builder(boxed)]`. Every field is stored in a `Box`, so moving
+    /// the builder between states only moves pointers, and the build method
+    /// returns `Box<Struct>`. This keeps structs with large fixed-size arrays
+    /// from being copied across the stack on every setter call.
+    pub boxed: bool,
+
+    /// Configuration predicate guarding the generated `test_builder()` method.
+    ///
+    /// Set to `test` by `#[builder(test_default)]`, or to the predicate given
+    /// with `#[builder(test_default(cfg(feature = "fixtures")))]`. The method
+    /// returns the complete builder state with every required field set to its
+    /// field-level `test_default` or `Default::default()`, so tests only set
+    /// the fields they are about.
+    pub test_default: Option<syn::Meta>,
+}
+
+/// Names of the builder state aliases requested with `#[builder(state_aliases)]`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StateAliases {
+    /// Name of the initial state alias (None = `{Struct}BuilderStart`)
+    pub start: Option<syn::Ident>,
+
+    /// Name of the complete state alias (None = `{Struct}BuilderComplete`)
+    pub complete: Option<syn::Ident>,
+}
+
+impl StateAliases {
+    /// Gets the name of the initial state alias.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder_base_name` - The name the builder types start with
+    ///
+    /// # Returns
+    ///
+    /// The custom name, or `{Struct}BuilderStart`
+    pub fn start_ident(&self, builder_base_name: &str) -> syn::Ident {
+        self.start
+            .clone()
+            .unwrap_or_else(|| quote::format_ident!("{}BuilderStart", builder_base_name))
+    }
+
+    /// Gets the name of the complete state alias.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder_base_name` - The name the builder types start with
+    ///
+    /// # Returns
+    ///
+    /// The custom name, or `{Struct}BuilderComplete`
+    pub fn complete_ident(&self, builder_base_name: &str) -> syn::Ident {
+        self.complete
+            .clone()
+            .unwrap_or_else(|| quote::format_ident!("{}BuilderComplete", builder_base_name))
+    }
+}
+
+/// An additional build method declared with `#[builder(finishers(...))]`.
+///
+/// The generated method calls the regular build method, passes the value to
+/// `validate` (a function taking `&Struct` and returning `Result<(), E>`), then
+/// converts it with `Into` when `into` is set. With a `context` type the method
+/// takes a `&Context` argument that is passed on to `validate`. `Self` in
+/// `error`, `context` and `into` refers to the struct being built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finisher {
+    /// Name of the generated method
+    pub name: syn::Ident,
+
+    /// Validation function run on the built value
+    pub validate: Option<syn::Path>,
+
+    /// Error type returned when validation fails
+    pub error: Option<syn::Type>,
+
+    /// Type the built value is converted into
+    pub into: Option<syn::Type>,
+
+    /// Type of the context argument passed to `validate`
+    pub context: Option<syn::Type>,
+}
+
+/// How builder lints are reported, set with `#[builder(lints = "...")]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LintLevel {
+    /// Lints are not reported
+    Allow,
+    /// Lints are reported as compiler warnings
+    #[default]
+    Warn,
+    /// Lints are reported as compile errors
+    Deny,
+}
+
+/// Which setters the struct-level prefix applies to, set with `#[builder(prefix_scope = "...")]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrefixScope {
+    /// Every setter gets the prefix
+    #[default]
+    All,
+    /// Only the setters of optional fields get the prefix
+    Optional,
+}
+
+/// Parses a visibility given as a string literal (`build_vis = "pub(crate)"`).
+fn parse_visibility_value(
+    meta: &syn::meta::ParseNestedMeta,
+    name: &str,
+) -> syn::Result<syn::Visibility> {
+    let lit_str: syn::LitStr = meta.value()?.parse()?;
+    lit_str.parse::<syn::Visibility>().map_err(|_| {
+        syn::Error::new(
+            lit_str.span(),
+            format!(
+                "Invalid {name} value '{}'. Use a visibility such as \"pub(crate)\", \
+                 \"pub(super)\" or \"pub(in crate::path)\".",
+                lit_str.value()
+            ),
+        )
+    })
+}
+
+/// Parses a type given either directly or as a string literal (`into = "Arc<Self>"`).
+fn parse_type_value(input: syn::parse::ParseStream) -> syn::Result<syn::Type> {
+    if input.peek(syn::LitStr) {
+        let lit_str: syn::LitStr = input.parse()?;
+        lit_str.parse()
+    } else {
+        input.parse()
+    }
+}
+
+/// Parses a single entry of `finishers(...)`.
+fn parse_finisher(meta: syn::meta::ParseNestedMeta) -> syn::Result<Finisher> {
+    let name = meta
+        .path
+        .get_ident()
+        .cloned()
+        .ok_or_else(|| meta.error("Finisher names must be plain identifiers"))?;
+    let mut finisher = Finisher {
+        name,
+        validate: None,
+        error: None,
+        into: None,
+        context: None,
+    };
+    let mut garde = false;
+
+    if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|option| {
+            if option.path.is_ident("validate") {
+                // build_validated(validate = path)
+                if finisher.validate.is_some() {
+                    return Err(option.error("Duplicate validate option"));
+                }
+                finisher.validate = Some(option.value()?.parse()?);
+                Ok(())
+            } else if option.path.is_ident("error") {
+                // build_validated(error = Type)
+                if finisher.error.is_some() {
+                    return Err(option.error("Duplicate error option"));
+                }
+                finisher.error = Some(parse_type_value(option.value()?)?);
+                Ok(())
+            } else if option.path.is_ident("into") {
+                // build_arc(into = "Arc<Self>")
+                if finisher.into.is_some() {
+                    return Err(option.error("Duplicate into option"));
+                }
+                finisher.into = Some(parse_type_value(option.value()?)?);
+                Ok(())
+            } else if option.path.is_ident("context") {
+                // build_validated(context = Type)
+                if finisher.context.is_some() {
+                    return Err(option.error("Duplicate context option"));
+                }
+                finisher.context = Some(parse_type_value(option.value()?)?);
+                Ok(())
+            } else if option.path.is_ident("garde") {
+                // build_validated(garde)
+                garde = true;
+                Ok(())
+            } else {
+                Err(option.error(
+                    "Unknown finisher option. Supported options: validate, error, into, context, garde",
+                ))
+            }
+        })?;
+    }
+
+    if garde {
+        // Runs `garde::Validate` with a caller-provided context
+        if finisher.validate.is_some() || finisher.error.is_some() || finisher.context.is_some() {
+            return Err(meta.error(format!(
+                "Finisher `{}` uses `garde`, which provides `validate`, `error` and `context`. \
+                 Remove them or use them without `garde`.",
+                finisher.name
+            )));
+        }
+        finisher.validate = Some(syn::parse_quote! { ::garde::Validate::validate_with });
+        finisher.error = Some(syn::parse_quote! { ::garde::Report });
+        finisher.context = Some(syn::parse_quote! { <Self as ::garde::Validate>::Context });
+    }
+    if finisher.context.is_some() && finisher.validate.is_none() {
+        return Err(meta.error(format!(
+            "Finisher `{}` has a `context` type but no `validate` function to pass it to.",
+            finisher.name
+        )));
+    }
+
+    match (&finisher.validate, &finisher.error) {
+        (Some(_), None) => Err(meta.error(format!(
+            "Finisher `{}` has `validate` but no `error` type. \
+             Add `error = YourError` to name the error returned when validation fails.",
+            finisher.name
+        ))),
+        (None, Some(_)) => Err(meta.error(format!(
+            "Finisher `{}` has an `error` type but no `validate` function.",
+            finisher.name
+        ))),
+        _ => Ok(finisher),
+    }
 }
 
 impl Default for StructAttributes {
@@ -91,14 +671,90 @@ impl Default for StructAttributes {
     /// Default configuration:
     /// - `build_method_name: None` - Use "build" as the method name
     /// - `setter_prefix: None` - No prefix for setter methods
+    /// - `setter_prefix_except: []` - The prefix applies to every setter
+    /// - `prefix_scope: PrefixScope::All` - The prefix applies to required and optional setters
     /// - `impl_into: false` - Use direct field types in setters
     /// - `const_builder: false` - Generate regular (non-const) methods
+    /// - `state_traits: false` - Don't generate builder state traits
+    /// - `setter_trait: false` - Don't generate the optional setters trait
+    /// - `into_iter: false` - `Vec<T>` setters take the field type
+    /// - `all_required: false` - Fields are optional unless marked `required`
+    /// - `assert_send: false`, `assert_sync: false`, `assert_unpin: false` - No auto trait assertions
+    /// - `module: None` - Builder types are generated beside the struct
+    /// - `finishers: []` - Only the build method finishes the builder
+    /// - `no_docs: false` - Builder items carry generated documentation
+    /// - `hide_states: false` - Builder states are declared beside the struct
+    /// - `stable_names: false` - The generated state names are public
+    /// - `build_by_clone: false` - The build method consumes the builder
+    /// - `option_into: false` - Optional `Option<T>` setters take the field type
+    /// - `lite: false` - Generate builder types
+    /// - `lints: LintLevel::Warn` - Builder lints are reported as warnings
+    /// - `debug_expand: false` - The generated code is not shown
+    /// - `arbitrary: false` - No `Arbitrary` implementation is generated
+    /// - `non_exhaustive: None` - The builder types follow the struct's `#[non_exhaustive]`
+    /// - `build_vis: None` - The build method is as visible as the builder
+    /// - `entry_vis: None` - `builder()` is as visible as the builder
+    /// - `no_entry: false` - `builder()` is generated
+    /// - `default_struct: None` - Optional fields start from their own defaults
+    /// - `base: None` - Unset optional fields keep their own defaults
+    /// - `fill_from: false` - No `fill_from` method is generated
+    /// - `apply_env: false` - No `apply_env` method is generated
+    /// - `derive_clone: false`, `derive_copy: false` - The builder types implement neither
+    /// - `derive_serialize: false`, `derive_deserialize: false` - The builder types derive no serde traits
+    /// - `impl_traits: []` - The builder types implement no user traits
+    /// - `buildable: None` - The struct implements no trait naming its builder
+    /// - `field_descriptors: None` - No `FIELDS` constant is generated
+    /// - `also_dynamic: false` - No dynamic builder is generated
+    /// - `nestable: false` - No items for nesting the builder are generated
+    /// - `state_aliases: None` - No aliases for the builder states
+    /// - `boxed: false` - The builder stores its values inline and builds the struct by value
+    /// - `test_default: None` - No `test_builder` method is generated
     fn default() -> Self {
         Self {
             build_method_name: None,
             setter_prefix: None,
+            setter_prefix_except: Vec::new(),
+            prefix_scope: PrefixScope::All,
             impl_into: false,
             const_builder: false,
+            state_traits: false,
+            setter_trait: false,
+            into_iter: false,
+            all_required: false,
+            assert_send: false,
+            assert_sync: false,
+            assert_unpin: false,
+            module: None,
+            finishers: Vec::new(),
+            no_docs: false,
+            hide_states: false,
+            stable_names: false,
+            build_by_clone: false,
+            option_into: false,
+            lite: false,
+            lints: LintLevel::Warn,
+            debug_expand: false,
+            arbitrary: false,
+            non_exhaustive: None,
+            build_vis: None,
+            entry_vis: None,
+            no_entry: false,
+            default_struct: None,
+            base: None,
+            fill_from: false,
+            apply_env: false,
+            derive_clone: false,
+            derive_copy: false,
+            derive_serialize: false,
+            derive_deserialize: false,
+            impl_traits: Vec::new(),
+            buildable: None,
+            field_descriptors: None,
+            also_dynamic: false,
+            nestable: false,
+            state_aliases: None,
+            boxed: false,
+            test_default: None,
         }
     }
 }
@@ -133,6 +789,39 @@ impl StructAttributes {
         self.setter_prefix.as_deref()
     }
 
+    /// Gets the fields listed in `setter_prefix(except(...))`.
+    ///
+    /// # Returns
+    ///
+    /// The listed field names, in declaration order
+    pub fn get_setter_prefix_except(&self) -> &[syn::Ident] {
+        &self.setter_prefix_except
+    }
+
+    /// Checks whether the struct-level setter prefix skips a field.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The field name, without any `r#` prefix
+    ///
+    /// # Returns
+    ///
+    /// `true` if the field is listed in `setter_prefix(except(...))`
+    pub fn is_setter_prefix_exempt(&self, field_name: &str) -> bool {
+        self.setter_prefix_except.iter().any(|ident| {
+            crate::utils::identifiers::strip_raw_identifier_prefix(&ident.to_string()) == field_name
+        })
+    }
+
+    /// Gets the setters the struct-level prefix applies to.
+    ///
+    /// # Returns
+    ///
+    /// The `PrefixScope` set with `prefix_scope`
+    pub fn get_prefix_scope(&self) -> PrefixScope {
+        self.prefix_scope
+    }
+
     /// Gets the impl_into setting for the struct.
     ///
     /// This method provides access to the struct-level impl_into setting that
@@ -160,6 +849,358 @@ impl StructAttributes {
         self.const_builder
     }
 
+    /// Gets the state_traits setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether the builder state traits should be generated.
+    pub fn get_state_traits(&self) -> bool {
+        self.state_traits
+    }
+
+    /// Gets the setter_trait setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether the optional setters trait should be generated.
+    pub fn get_setter_trait(&self) -> bool {
+        self.setter_trait
+    }
+
+    /// Gets the into_iter setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether `Vec<T>` setters should accept
+    /// `impl IntoIterator<Item = impl Into<T>>` parameters.
+    pub fn get_into_iter(&self) -> bool {
+        self.into_iter
+    }
+
+    /// Gets the all_required setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether fields are required unless opted out.
+    pub fn get_all_required(&self) -> bool {
+        self.all_required
+    }
+
+    /// Gets the assert_send setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether every builder state must be `Send`.
+    pub fn get_assert_send(&self) -> bool {
+        self.assert_send
+    }
+
+    /// Gets the assert_sync setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether every builder state must be `Sync`.
+    pub fn get_assert_sync(&self) -> bool {
+        self.assert_sync
+    }
+
+    /// Gets the assert_unpin setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether every builder state must be `Unpin`.
+    pub fn get_assert_unpin(&self) -> bool {
+        self.assert_unpin
+    }
+
+    /// Gets the module the builder types are generated into.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<&syn::Ident>` with the module name, or `None` if the builder
+    /// types are generated beside the struct.
+    pub fn get_module(&self) -> Option<&syn::Ident> {
+        self.module.as_ref()
+    }
+
+    /// Gets the additional build methods.
+    ///
+    /// # Returns
+    ///
+    /// A slice of the finishers declared with `finishers(...)`. Entries that
+    /// just name the build method itself are dropped during parsing.
+    pub fn get_finishers(&self) -> &[Finisher] {
+        &self.finishers
+    }
+
+    /// Gets the no_docs setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether generated doc comments are left out.
+    pub fn get_no_docs(&self) -> bool {
+        self.no_docs
+    }
+
+    /// Gets the hide_states setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether the builder states are hidden in an anonymous const.
+    pub fn get_hide_states(&self) -> bool {
+        self.hide_states
+    }
+
+    /// Gets the stable_names setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether only the aliases name the builder states.
+    pub fn get_stable_names(&self) -> bool {
+        self.stable_names
+    }
+
+    /// Gets the build_by setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether the build method clones the fields of a borrowed builder.
+    pub fn get_build_by_clone(&self) -> bool {
+        self.build_by_clone
+    }
+
+    /// Gets the option_into setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether optional `Option<T>` setters should accept
+    /// `impl Into<Option<T>>` parameters.
+    pub fn get_option_into(&self) -> bool {
+        self.option_into
+    }
+
+    /// Gets the lite setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether `new` and `with_*` methods are generated on
+    /// the struct instead of builder types.
+    pub fn get_lite(&self) -> bool {
+        self.lite
+    }
+
+    /// Gets the lint level for the struct.
+    ///
+    /// # Returns
+    ///
+    /// The `LintLevel` builder lints are reported at.
+    pub fn get_lints(&self) -> LintLevel {
+        self.lints
+    }
+
+    /// Gets the debug_expand setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether the generated code is shown in a compiler warning.
+    pub fn get_debug_expand(&self) -> bool {
+        self.debug_expand
+    }
+
+    /// Gets the arbitrary setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether `arbitrary::Arbitrary` is implemented through the builder.
+    pub fn get_arbitrary(&self) -> bool {
+        self.arbitrary
+    }
+
+    /// Gets the non_exhaustive setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<bool>` with the explicit setting, or None if the builder types
+    /// follow the struct's own `#[non_exhaustive]`.
+    pub fn get_non_exhaustive(&self) -> Option<bool> {
+        self.non_exhaustive
+    }
+
+    /// Gets the visibility of the build method.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<&syn::Visibility>` with the visibility as written, or `None`
+    /// if the build method is as visible as the builder.
+    pub fn get_build_vis(&self) -> Option<&syn::Visibility> {
+        self.build_vis.as_ref()
+    }
+
+    /// Gets the visibility of the methods that start the builder.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<&syn::Visibility>` with the visibility as written, or `None`
+    /// if `builder()` is as visible as the builder.
+    pub fn get_entry_vis(&self) -> Option<&syn::Visibility> {
+        self.entry_vis.as_ref()
+    }
+
+    /// Gets the no_entry setting for the struct.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no entry method is generated on the struct.
+    pub fn get_no_entry(&self) -> bool {
+        self.no_entry
+    }
+
+    /// Gets the template instance optional fields start from.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<&syn::Expr>` with the template expression, or `None` if
+    /// every optional field starts from its own default.
+    pub fn get_default_struct(&self) -> Option<&syn::Expr> {
+        self.default_struct.as_ref()
+    }
+
+    /// Gets the base instance unset optional fields are taken from.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<&syn::Expr>` with the base expression, or `None` if unset
+    /// optional fields keep their own defaults.
+    pub fn get_base(&self) -> Option<&syn::Expr> {
+        self.base.as_ref()
+    }
+
+    /// Gets whether the builder states get a `fill_from` method.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `fill_from` copies unset optional fields from an instance
+    pub fn get_fill_from(&self) -> bool {
+        self.fill_from
+    }
+
+    /// Gets whether the builder states get an `apply_env` method.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `apply_env` sets optional fields from environment variables
+    pub fn get_apply_env(&self) -> bool {
+        self.apply_env
+    }
+
+    /// Gets whether the builder types implement `Clone`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `Clone` was requested with `derive(...)`
+    pub fn get_derive_clone(&self) -> bool {
+        self.derive_clone
+    }
+
+    /// Gets whether the builder types implement `Copy`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `Copy` was requested with `derive(...)`
+    pub fn get_derive_copy(&self) -> bool {
+        self.derive_copy
+    }
+
+    /// Gets whether the builder types derive `serde::Serialize`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `Serialize` was requested with `derive(...)`
+    pub fn get_derive_serialize(&self) -> bool {
+        self.derive_serialize
+    }
+
+    /// Gets whether the builder types derive `serde::Deserialize`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `Deserialize` was requested with `derive(...)`
+    pub fn get_derive_deserialize(&self) -> bool {
+        self.derive_deserialize
+    }
+
+    /// Gets the user traits implemented for every builder state.
+    ///
+    /// # Returns
+    ///
+    /// The trait paths given with `impl_trait`, in declaration order
+    pub fn get_impl_traits(&self) -> &[syn::Path] {
+        &self.impl_traits
+    }
+
+    /// Gets the user trait that names the builder of the struct.
+    ///
+    /// # Returns
+    ///
+    /// The trait path given with `buildable`, or `None` if it was not given
+    pub fn get_buildable(&self) -> Option<&syn::Path> {
+        self.buildable.as_ref()
+    }
+
+    /// Gets the user struct that describes the fields at runtime.
+    ///
+    /// # Returns
+    ///
+    /// The struct path given with `field_descriptors`, or `None` if it was not given
+    pub fn get_field_descriptors(&self) -> Option<&syn::Path> {
+        self.field_descriptors.as_ref()
+    }
+
+    /// Gets whether a runtime-checked builder is generated next to the builder.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `{Struct}DynBuilder` is generated
+    pub fn get_also_dynamic(&self) -> bool {
+        self.also_dynamic
+    }
+
+    /// Gets whether other builders can nest this one.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the items used by `#[builder(nested)]` fields are generated
+    pub fn get_nestable(&self) -> bool {
+        self.nestable
+    }
+
+    /// Gets the requested builder state aliases.
+    ///
+    /// # Returns
+    ///
+    /// The alias names, or `None` if `state_aliases` was not given
+    pub fn get_state_aliases(&self) -> Option<&StateAliases> {
+        self.state_aliases.as_ref()
+    }
+
+    /// Gets whether the builder keeps its values on the heap.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the builder stores boxed values and builds a `Box<Struct>`
+    pub fn get_boxed(&self) -> bool {
+        self.boxed
+    }
+
+    /// Gets the predicate under which `test_builder()` is generated.
+    ///
+    /// # Returns
+    ///
+    /// The `cfg` predicate, or `None` if `test_default` was not given
+    pub fn get_test_default(&self) -> Option<&syn::Meta> {
+        self.test_default.as_ref()
+    }
+
     /// Validates that the struct attributes are consistent and valid.
     ///
     /// This method checks that all struct-level attributes have valid values
@@ -212,6 +1253,24 @@ impl StructAttributes {
             }
         }
 
+        // Validate that prefix exceptions have a prefix to skip
+        if self.setter_prefix.is_none() && !self.setter_prefix_except.is_empty() {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "setter_prefix(except(...)) requires a struct-level prefix. \
+                 Add `setter_prefix = \"with_\"` alongside the exceptions.",
+            ));
+        }
+
+        // Validate that a prefix scope has a prefix to scope
+        if self.setter_prefix.is_none() && self.prefix_scope != PrefixScope::All {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "prefix_scope requires a struct-level prefix. \
+                 Add `setter_prefix = \"with_\"` alongside the scope.",
+            ));
+        }
+
         // Validate setter prefix if provided
         if let Some(setter_prefix) = &self.setter_prefix {
             if setter_prefix.is_empty() {
@@ -233,27 +1292,322 @@ impl StructAttributes {
                 ));
             }
 
-            // Check for basic identifier validity of prefix
-            // This is a heuristic check - we'll do full validation when combining with field names
-            if !setter_prefix
-                .chars()
-                .all(|c| c.is_alphanumeric() || c == '_')
-            {
+            // Check for basic identifier validity of prefix
+            // This is a heuristic check - we'll do full validation when combining with field names
+            if !setter_prefix
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_')
+            {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!(
+                        "Invalid setter prefix '{setter_prefix}'. Setter prefixes must contain only alphanumeric characters and underscores."
+                    ),
+                ));
+            }
+        }
+
+        // Validate that finisher names are unique
+        for (index, finisher) in self.finishers.iter().enumerate() {
+            if self.finishers[..index]
+                .iter()
+                .any(|other| other.name == finisher.name)
+            {
+                return Err(syn::Error::new(
+                    finisher.name.span(),
+                    format!("Duplicate finisher `{}`", finisher.name),
+                ));
+            }
+        }
+
+        // Validate that no finisher takes the name of the generated `{build}_into`
+        let build_method_name = self.get_build_method_name();
+        let build_into_name = format!("{}_into", build_method_name.trim_start_matches("r#"));
+        if let Some(finisher) = self
+            .finishers
+            .iter()
+            .find(|finisher| finisher.name == build_into_name)
+        {
+            return Err(syn::Error::new(
+                finisher.name.span(),
+                format!(
+                    "`{build_into_name}` is generated for every builder. \
+                     Call `{build_into_name}::<Target>()` instead, or give this finisher a different name."
+                ),
+            ));
+        }
+
+        // Validate that the build method itself is not given finisher options
+        if let Some(finisher) = self.finishers.iter().find(|finisher| {
+            finisher.name == build_method_name
+                && (finisher.validate.is_some() || finisher.into.is_some())
+        }) {
+            return Err(syn::Error::new(
+                finisher.name.span(),
+                format!(
+                    "The build method `{build_method_name}` cannot take finisher options. \
+                     Give this finisher a different name, e.g. `try_{build_method_name}`."
+                ),
+            ));
+        }
+
+        // Validate const and impl_into are not used together
+        if self.const_builder && self.impl_into {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "`const` and `impl_into` cannot be used together. \
+                 `impl Into<T>` requires trait bounds which are not supported in const fn.",
+            ));
+        }
+
+        // Validate const and into_iter are not used together
+        if self.const_builder && self.into_iter {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "`const` and `into_iter` cannot be used together. \
+                 Collecting an iterator is not supported in const fn.",
+            ));
+        }
+
+        // Validate const and option_into are not used together
+        if self.const_builder && self.option_into {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "`const` and `option_into` cannot be used together. \
+                 `impl Into<Option<T>>` requires trait bounds which are not supported in const fn.",
+            ));
+        }
+
+        // Validate that lite is not combined with options for the builder types
+        if self.lite {
+            let conflict = if self.state_traits {
+                Some("state_traits")
+            } else if self.setter_trait {
+                Some("setter_trait")
+            } else if self.module.is_some() {
+                Some("module")
+            } else if !self.finishers.is_empty() {
+                Some("finishers")
+            } else if self.stable_names {
+                Some("stable_names")
+            } else if self.hide_states {
+                Some("hide_states")
+            } else if self.build_by_clone {
+                Some("build_by")
+            } else if self.build_method_name.is_some() {
+                Some("build_method")
+            } else if self.assert_send || self.assert_sync {
+                Some("assert_send")
+            } else if self.assert_unpin {
+                Some("static_assert")
+            } else if self.non_exhaustive == Some(true) {
+                Some("non_exhaustive")
+            } else if self.build_vis.is_some() {
+                Some("build_vis")
+            } else if self.no_entry {
+                Some("no_entry")
+            } else if self.base.is_some() {
+                Some("base")
+            } else if self.fill_from {
+                Some("fill_from")
+            } else if self.apply_env {
+                Some("apply_env")
+            } else if self.derive_clone || self.derive_serialize || self.derive_deserialize {
+                Some("derive")
+            } else if !self.impl_traits.is_empty() {
+                Some("impl_trait")
+            } else if self.buildable.is_some() {
+                Some("buildable")
+            } else if self.also_dynamic {
+                Some("also_dynamic")
+            } else if self.nestable {
+                Some("nestable")
+            } else if self.state_aliases.is_some() {
+                Some("state_aliases")
+            } else if self.boxed {
+                Some("boxed")
+            } else if self.test_default.is_some() {
+                Some("test_default")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!(
+                        "`lite` and `{conflict}` cannot be used together. \
+                         `lite` generates no builder types, only `new` and `with_*` methods on the struct."
+                    ),
+                ));
+            }
+        }
+
+        // Validate that the build method cannot be reached through the state traits
+        if self.state_traits && self.build_vis.is_some() {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "`state_traits` and `build_vis` cannot be used together. \
+                 The public `{Struct}BuilderFinish` trait would make the build method callable \
+                 wherever the builder is visible.",
+            ));
+        }
+
+        // Validate that a builder without an entry method can still be started
+        if self.no_entry {
+            let conflict = if self.stable_names {
+                Some("stable_names")
+            } else if self.hide_states {
+                Some("hide_states")
+            } else if self.arbitrary {
+                Some("arbitrary")
+            } else if self.buildable.is_some() {
+                Some("buildable")
+            } else if self.also_dynamic {
+                Some("also_dynamic")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!(
+                        "`no_entry` and `{conflict}` cannot be used together. \
+                         `{conflict}` starts the builder with `builder()`, which `no_entry` leaves out."
+                    ),
+                ));
+            }
+        }
+
+        // Validate that the trait does not hand out a restricted entry point
+        if self.buildable.is_some() && self.entry_vis.is_some() {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "`buildable` and `entry_vis` cannot be used together. \
+                 The trait's `builder()` would start the builder wherever the trait is visible.",
+            ));
+        }
+
+        // Validate that the dynamic builder does not hand out restricted methods
+        if self.also_dynamic {
+            let conflict = if self.entry_vis.is_some() {
+                Some("entry_vis")
+            } else if self.build_vis.is_some() {
+                Some("build_vis")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!(
+                        "`also_dynamic` and `{conflict}` cannot be used together. \
+                         The dynamic builder starts and builds the struct wherever it is visible."
+                    ),
+                ));
+            }
+        }
+
+        // Validate that a nested setter receives the struct itself
+        if self.nestable && self.boxed {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "`nestable` and `boxed` cannot be used together. \
+                 A boxed builder builds a `Box` instead of the value a nested setter takes.",
+            ));
+        }
+
+        // Validate that the template can be evaluated in const fn
+        if self.const_builder && self.default_struct.is_some() {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "`const` and `default_struct` cannot be used together. \
+                 The fields of the template that are not used cannot be dropped in const fn.",
+            ));
+        }
+
+        // Validate that unset fields come from a single place
+        if self.base.is_some() {
+            let conflict = if self.default_struct.is_some() {
+                Some((
+                    "default_struct",
+                    "Both take the unset optional fields from an instance of the struct.",
+                ))
+            } else if self.const_builder {
+                Some((
+                    "const",
+                    "The fields of the base that are not used cannot be dropped in const fn.",
+                ))
+            } else {
+                None
+            };
+            if let Some((conflict, reason)) = conflict {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!("`base` and `{conflict}` cannot be used together. {reason}"),
+                ));
+            }
+        }
+
+        // Validate that the builder can tell which fields were set
+        if self.fill_from {
+            let conflict = if self.default_struct.is_some() {
+                Some((
+                    "default_struct",
+                    "Fields starting from the template cannot be told apart from fields that were set.",
+                ))
+            } else if self.const_builder {
+                Some((
+                    "const",
+                    "The values of the instance cannot be cloned in const fn.",
+                ))
+            } else {
+                None
+            };
+            if let Some((conflict, reason)) = conflict {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!("`fill_from` and `{conflict}` cannot be used together. {reason}"),
+                ));
+            }
+        }
+
+        // Validate that Copy builders are also Clone, as `Copy: Clone`
+        if self.derive_copy && !self.derive_clone {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "`derive(Copy)` requires `Clone` as well, since `Copy` is a subtrait of `Clone`. \
+                 Use `#[builder(derive(Copy, Clone))]`.",
+            ));
+        }
+
+        // Validate that boxed builders are only combined with what can hold a Box
+        if self.boxed {
+            let conflict = if self.const_builder {
+                Some(("const", "`Box::new` cannot be called in const fn."))
+            } else if self.derive_copy {
+                Some(("derive(Copy)", "`Box` is not `Copy`."))
+            } else if !self.finishers.is_empty() {
+                Some((
+                    "finishers",
+                    "Finishers take the struct by value, which `boxed` keeps off the stack.",
+                ))
+            } else {
+                None
+            };
+            if let Some((conflict, reason)) = conflict {
                 return Err(syn::Error::new(
                     proc_macro2::Span::call_site(),
-                    format!(
-                        "Invalid setter prefix '{setter_prefix}'. Setter prefixes must contain only alphanumeric characters and underscores."
-                    ),
+                    format!("`boxed` and `{conflict}` cannot be used together. {reason}"),
                 ));
             }
         }
 
-        // Validate const and impl_into are not used together
-        if self.const_builder && self.impl_into {
+        // Validate const and build_by = "clone" are not used together
+        if self.const_builder && self.build_by_clone {
             return Err(syn::Error::new(
                 proc_macro2::Span::call_site(),
-                "`const` and `impl_into` cannot be used together. \
-                 `impl Into<T>` requires trait bounds which are not supported in const fn.",
+                "`const` and `build_by = \"clone\"` cannot be used together. \
+                 `Clone::clone` cannot be called in const fn.",
             ));
         }
 
@@ -325,6 +1679,27 @@ pub fn parse_struct_attributes(attrs: &[syn::Attribute]) -> syn::Result<StructAt
 
                     struct_attributes.build_method_name = Some(build_method_name);
                     Ok(())
+                } else if meta.path.is_ident("setter_prefix")
+                    && meta.input.peek(syn::token::Paren)
+                {
+                    // #[builder(setter_prefix(except(id, name)))]
+                    meta.parse_nested_meta(|option| {
+                        if !option.path.is_ident("except") {
+                            return Err(option.error(
+                                "Unknown setter_prefix option. Supported options: except",
+                            ));
+                        }
+                        option.parse_nested_meta(|field| {
+                            let ident = field.path.require_ident()?;
+                            if struct_attributes.setter_prefix_except.contains(ident) {
+                                return Err(field.error(format!(
+                                    "Field `{ident}` is listed more than once in setter_prefix(except(...))"
+                                )));
+                            }
+                            struct_attributes.setter_prefix_except.push(ident.clone());
+                            Ok(())
+                        })
+                    })
                 } else if meta.path.is_ident("setter_prefix") {
                     // #[builder(setter_prefix = "prefix_")]
                     let value = meta.value()?;
@@ -338,6 +1713,23 @@ pub fn parse_struct_attributes(attrs: &[syn::Attribute]) -> syn::Result<StructAt
 
                     struct_attributes.setter_prefix = Some(setter_prefix);
                     Ok(())
+                } else if meta.path.is_ident("prefix_scope") {
+                    // #[builder(prefix_scope = "optional")]
+                    let value = meta.value()?;
+                    let lit_str: syn::LitStr = value.parse()?;
+                    struct_attributes.prefix_scope = match lit_str.value().as_str() {
+                        "all" => PrefixScope::All,
+                        "optional" => PrefixScope::Optional,
+                        other => {
+                            return Err(syn::Error::new(
+                                lit_str.span(),
+                                format!(
+                                    "Invalid prefix_scope value '{other}'. Use \"all\" or \"optional\"."
+                                ),
+                            ))
+                        }
+                    };
+                    Ok(())
                 } else if meta.path.is_ident("impl_into") {
                     // #[builder(impl_into)]
                     struct_attributes.impl_into = true;
@@ -346,10 +1738,371 @@ pub fn parse_struct_attributes(attrs: &[syn::Attribute]) -> syn::Result<StructAt
                     // #[builder(const)]
                     struct_attributes.const_builder = true;
                     Ok(())
+                } else if meta.path.is_ident("state_traits") {
+                    // #[builder(state_traits)]
+                    struct_attributes.state_traits = true;
+                    Ok(())
+                } else if meta.path.is_ident("setter_trait") {
+                    // #[builder(setter_trait)]
+                    struct_attributes.setter_trait = true;
+                    Ok(())
+                } else if meta.path.is_ident("into_iter") {
+                    // #[builder(into_iter)]
+                    struct_attributes.into_iter = true;
+                    Ok(())
+                } else if meta.path.is_ident("all_required") {
+                    // #[builder(all_required)]
+                    struct_attributes.all_required = true;
+                    Ok(())
+                } else if meta.path.is_ident("assert_send") {
+                    // #[builder(assert_send)]
+                    struct_attributes.assert_send = true;
+                    Ok(())
+                } else if meta.path.is_ident("assert_send_sync") {
+                    // #[builder(assert_send_sync)]
+                    struct_attributes.assert_send = true;
+                    struct_attributes.assert_sync = true;
+                    Ok(())
+                } else if meta.path.is_ident("static_assert") {
+                    // #[builder(static_assert(Send, Sync, Unpin))]
+                    meta.parse_nested_meta(|auto_trait| {
+                        if auto_trait.path.is_ident("Send") {
+                            struct_attributes.assert_send = true;
+                        } else if auto_trait.path.is_ident("Sync") {
+                            struct_attributes.assert_sync = true;
+                        } else if auto_trait.path.is_ident("Unpin") {
+                            struct_attributes.assert_unpin = true;
+                        } else {
+                            return Err(auto_trait.error(
+                                "Unsupported static assertion. Supported auto traits: Send, Sync, Unpin",
+                            ));
+                        }
+                        Ok(())
+                    })
+                } else if meta.path.is_ident("module") {
+                    // #[builder(module = "name")]
+                    let value = meta.value()?;
+                    let lit_str: syn::LitStr = value.parse()?;
+                    let module = lit_str.parse::<syn::Ident>().map_err(|_| {
+                        syn::Error::new(
+                            lit_str.span(),
+                            format!(
+                                "Invalid module name '{}'. The builder types are generated into a \
+                                 child module, so the name must be a plain identifier such as \"user_builder\".",
+                                lit_str.value()
+                            ),
+                        )
+                    })?;
+                    struct_attributes.module = Some(module);
+                    Ok(())
+                } else if meta.path.is_ident("finishers") {
+                    // #[builder(finishers(build, try_build(validate = check, error = Error)))]
+                    meta.parse_nested_meta(|entry| {
+                        struct_attributes.finishers.push(parse_finisher(entry)?);
+                        Ok(())
+                    })
+                } else if meta.path.is_ident("no_docs") {
+                    // #[builder(no_docs)]
+                    struct_attributes.no_docs = true;
+                    Ok(())
+                } else if meta.path.is_ident("hide_states") {
+                    // #[builder(hide_states)]
+                    struct_attributes.hide_states = true;
+                    Ok(())
+                } else if meta.path.is_ident("stable_names") {
+                    // #[builder(stable_names)]
+                    struct_attributes.stable_names = true;
+                    Ok(())
+                } else if meta.path.is_ident("build_by") {
+                    // #[builder(build_by = "clone")]
+                    let value = meta.value()?;
+                    let lit_str: syn::LitStr = value.parse()?;
+                    struct_attributes.build_by_clone = match lit_str.value().as_str() {
+                        "clone" => true,
+                        "move" => false,
+                        other => {
+                            return Err(syn::Error::new(
+                                lit_str.span(),
+                                format!(
+                                    "Invalid build_by value '{other}'. Use \"move\" for a build method \
+                                     that consumes the builder or \"clone\" for one that borrows it."
+                                ),
+                            ))
+                        }
+                    };
+                    Ok(())
+                } else if meta.path.is_ident("option_into") {
+                    // #[builder(option_into)]
+                    struct_attributes.option_into = true;
+                    Ok(())
+                } else if meta.path.is_ident("test_default") {
+                    // #[builder(test_default)] or #[builder(test_default(cfg(predicate)))]
+                    if struct_attributes.test_default.is_some() {
+                        return Err(meta.error("Duplicate test_default attribute"));
+                    }
+                    let predicate = if meta.input.peek(syn::token::Paren) {
+                        let content;
+                        syn::parenthesized!(content in meta.input);
+                        let condition: syn::Meta = content.parse()?;
+                        match &condition {
+                            syn::Meta::List(list) if list.path.is_ident("cfg") => {
+                                list.parse_args::<syn::Meta>()?
+                            }
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    &condition,
+                                    "Expected `test_default(cfg(predicate))`, e.g. \
+                                     `test_default(cfg(feature = \"fixtures\"))`",
+                                ));
+                            }
+                        }
+                    } else {
+                        syn::parse_quote!(test)
+                    };
+                    struct_attributes.test_default = Some(predicate);
+                    Ok(())
+                } else if meta.path.is_ident("boxed") {
+                    // #[builder(boxed)]
+                    struct_attributes.boxed = true;
+                    Ok(())
+                } else if meta.path.is_ident("lite") {
+                    // #[builder(lite)]
+                    struct_attributes.lite = true;
+                    Ok(())
+                } else if meta.path.is_ident("lints") {
+                    // #[builder(lints = "deny")]
+                    let value = meta.value()?;
+                    let lit_str: syn::LitStr = value.parse()?;
+                    struct_attributes.lints = match lit_str.value().as_str() {
+                        "allow" => LintLevel::Allow,
+                        "warn" => LintLevel::Warn,
+                        "deny" => LintLevel::Deny,
+                        other => {
+                            return Err(syn::Error::new(
+                                lit_str.span(),
+                                format!(
+                                    "Invalid lints value '{other}'. Use \"allow\", \"warn\" or \"deny\"."
+                                ),
+                            ))
+                        }
+                    };
+                    Ok(())
+                } else if meta.path.is_ident("debug_expand") {
+                    // #[builder(debug_expand)]
+                    struct_attributes.debug_expand = true;
+                    Ok(())
+                } else if meta.path.is_ident("arbitrary") {
+                    // #[builder(arbitrary)]
+                    if !cfg!(feature = "arbitrary") {
+                        return Err(meta.error(
+                            "`arbitrary` requires the `arbitrary` feature. Enable the `arbitrary` \
+                             feature of type-state-builder and add `arbitrary` to your dependencies.",
+                        ));
+                    }
+                    struct_attributes.arbitrary = true;
+                    Ok(())
+                } else if meta.path.is_ident("non_exhaustive") {
+                    // #[builder(non_exhaustive)] or #[builder(non_exhaustive = true/false)]
+                    if meta.input.peek(syn::Token![=]) {
+                        let value = meta.value()?;
+                        let lit_bool: syn::LitBool = value.parse()?;
+                        struct_attributes.non_exhaustive = Some(lit_bool.value);
+                    } else {
+                        struct_attributes.non_exhaustive = Some(true);
+                    }
+                    Ok(())
+                } else if meta.path.is_ident("build_vis") {
+                    // #[builder(build_vis = "pub(crate)")]
+                    struct_attributes.build_vis = Some(parse_visibility_value(&meta, "build_vis")?);
+                    Ok(())
+                } else if meta.path.is_ident("entry_vis") {
+                    // #[builder(entry_vis = "pub(crate)")]
+                    struct_attributes.entry_vis = Some(parse_visibility_value(&meta, "entry_vis")?);
+                    Ok(())
+                } else if meta.path.is_ident("no_entry") {
+                    // #[builder(no_entry)]
+                    struct_attributes.no_entry = true;
+                    Ok(())
+                } else if meta.path.is_ident("default_struct") {
+                    // #[builder(default_struct = "Config::default()")] or
+                    // #[builder(default_struct = Config::default())]
+                    let value = meta.value()?;
+                    let default_struct = if value.peek(syn::LitStr) {
+                        value.parse::<syn::LitStr>()?.parse()?
+                    } else {
+                        value.parse()?
+                    };
+                    struct_attributes.default_struct = Some(default_struct);
+                    Ok(())
+                } else if meta.path.is_ident("derive") {
+                    // #[builder(derive(Copy, Clone))]
+                    meta.parse_nested_meta(|derive| {
+                        if derive.path.is_ident("Clone") {
+                            struct_attributes.derive_clone = true;
+                            Ok(())
+                        } else if derive.path.is_ident("Copy") {
+                            struct_attributes.derive_copy = true;
+                            Ok(())
+                        } else if derive.path.is_ident("Serialize")
+                            || derive.path.is_ident("Deserialize")
+                        {
+                            if !cfg!(feature = "serde") {
+                                return Err(derive.error(
+                                    "Serde derives require the `serde` feature. Enable the `serde` \
+                                     feature of type-state-builder and add `serde` to your dependencies.",
+                                ));
+                            }
+                            if derive.path.is_ident("Serialize") {
+                                struct_attributes.derive_serialize = true;
+                            } else {
+                                struct_attributes.derive_deserialize = true;
+                            }
+                            Ok(())
+                        } else {
+                            Err(derive.error(
+                                "Unsupported builder derive. Supported traits: Clone, Copy, Serialize, Deserialize",
+                            ))
+                        }
+                    })
+                } else if meta.path.is_ident("base") {
+                    // #[builder(base = "Config::default()")] or
+                    // #[builder(base = Config::default())]
+                    let value = meta.value()?;
+                    let base = if value.peek(syn::LitStr) {
+                        value.parse::<syn::LitStr>()?.parse()?
+                    } else {
+                        value.parse()?
+                    };
+                    struct_attributes.base = Some(base);
+                    Ok(())
+                } else if meta.path.is_ident("fill_from") {
+                    // #[builder(fill_from)]
+                    struct_attributes.fill_from = true;
+                    Ok(())
+                } else if meta.path.is_ident("apply_env") {
+                    // #[builder(apply_env)]
+                    if !cfg!(feature = "env") {
+                        return Err(meta.error(
+                            "`apply_env` requires the `env` feature. Enable the `env` \
+                             feature of type-state-builder.",
+                        ));
+                    }
+                    struct_attributes.apply_env = true;
+                    Ok(())
+                } else if meta.path.is_ident("state_aliases") {
+                    // #[builder(state_aliases)] or
+                    // #[builder(state_aliases(start = "NewUser", complete = "ReadyUser"))]
+                    if struct_attributes.state_aliases.is_some() {
+                        return Err(meta.error("Duplicate state_aliases attribute"));
+                    }
+                    let mut aliases = StateAliases::default();
+                    if meta.input.peek(syn::token::Paren) {
+                        meta.parse_nested_meta(|option| {
+                            let slot = if option.path.is_ident("start") {
+                                &mut aliases.start
+                            } else if option.path.is_ident("complete") {
+                                &mut aliases.complete
+                            } else {
+                                return Err(option.error(
+                                    "Unknown state_aliases option. Supported options: start, complete",
+                                ));
+                            };
+                            if slot.is_some() {
+                                return Err(option.error("Duplicate state_aliases option"));
+                            }
+                            let lit_str: syn::LitStr = option.value()?.parse()?;
+                            let ident = lit_str.parse::<syn::Ident>().map_err(|_| {
+                                syn::Error::new(
+                                    lit_str.span(),
+                                    format!(
+                                        "Invalid alias name '{}'. State aliases must be plain \
+                                         identifiers such as \"ReadyUser\".",
+                                        lit_str.value()
+                                    ),
+                                )
+                            })?;
+                            *slot = Some(ident);
+                            Ok(())
+                        })?;
+                    }
+                    if aliases.start.is_some() && aliases.start == aliases.complete {
+                        return Err(meta.error(
+                            "The start and complete state aliases need different names",
+                        ));
+                    }
+                    struct_attributes.state_aliases = Some(aliases);
+                    Ok(())
+                } else if meta.path.is_ident("impl_trait") {
+                    // #[builder(impl_trait = "crate::Described")] or
+                    // #[builder(impl_trait = crate::Described)]
+                    let value = meta.value()?;
+                    let path: syn::Path = if value.peek(syn::LitStr) {
+                        value.parse::<syn::LitStr>()?.parse()?
+                    } else {
+                        value.parse()?
+                    };
+                    if struct_attributes
+                        .impl_traits
+                        .iter()
+                        .any(|existing| existing == &path)
+                    {
+                        return Err(meta.error(format!(
+                            "Duplicate impl_trait attribute. `{}` is already implemented for the builder states",
+                            quote::quote!(#path).to_string().replace(' ', "")
+                        )));
+                    }
+                    struct_attributes.impl_traits.push(path);
+                    Ok(())
+                } else if meta.path.is_ident("buildable") {
+                    // #[builder(buildable = "crate::Buildable")] or
+                    // #[builder(buildable = crate::Buildable)]
+                    if struct_attributes.buildable.is_some() {
+                        return Err(meta.error(
+                            "Duplicate buildable attribute. Only one trait can name the builder of the struct",
+                        ));
+                    }
+                    let value = meta.value()?;
+                    let path: syn::Path = if value.peek(syn::LitStr) {
+                        value.parse::<syn::LitStr>()?.parse()?
+                    } else {
+                        value.parse()?
+                    };
+                    struct_attributes.buildable = Some(path);
+                    Ok(())
+                } else if meta.path.is_ident("also_dynamic") {
+                    // #[builder(also_dynamic)]
+                    struct_attributes.also_dynamic = true;
+                    Ok(())
+                } else if meta.path.is_ident("nestable") {
+                    // #[builder(nestable)]
+                    struct_attributes.nestable = true;
+                    Ok(())
+                } else if meta.path.is_ident("field_descriptors") {
+                    // #[builder(field_descriptors = "crate::FieldDescriptor")] or
+                    // #[builder(field_descriptors = crate::FieldDescriptor)]
+                    if !cfg!(feature = "metadata") {
+                        return Err(meta.error(
+                            "`field_descriptors` requires the `metadata` feature. Enable the \
+                             `metadata` feature of type-state-builder.",
+                        ));
+                    }
+                    if struct_attributes.field_descriptors.is_some() {
+                        return Err(meta.error(
+                            "Duplicate field_descriptors attribute. The fields can only be described once",
+                        ));
+                    }
+                    let value = meta.value()?;
+                    let path: syn::Path = if value.peek(syn::LitStr) {
+                        value.parse::<syn::LitStr>()?.parse()?
+                    } else {
+                        value.parse()?
+                    };
+                    struct_attributes.field_descriptors = Some(path);
+                    Ok(())
                 } else {
                     // Unknown struct-level attribute
                     Err(meta.error(
-                        "Unknown struct-level builder attribute. Supported attributes: build_method, setter_prefix, impl_into, const"
+                        "Unknown struct-level builder attribute. Supported attributes: build_method, setter_prefix, prefix_scope, impl_into, const, state_traits, setter_trait, into_iter, all_required, assert_send, assert_send_sync, static_assert, module, finishers, no_docs, hide_states, stable_names, build_by, option_into, lite, lints, debug_expand, arbitrary, non_exhaustive, build_vis, entry_vis, no_entry, default_struct, base, fill_from, apply_env, derive, impl_trait, buildable, field_descriptors, also_dynamic, nestable, state_aliases, boxed, test_default"
                     ))
                 }
             })?;
@@ -359,6 +2112,21 @@ pub fn parse_struct_attributes(attrs: &[syn::Attribute]) -> syn::Result<StructAt
     // Validate the final configuration
     struct_attributes.validate()?;
 
+    // With `stable_names` the aliases are the only public names of the states
+    if struct_attributes.stable_names {
+        struct_attributes.hide_states = true;
+        struct_attributes
+            .state_aliases
+            .get_or_insert_with(StateAliases::default);
+    }
+
+    // Naming the build method itself in `finishers(...)` just documents that it
+    // is available; it is generated regardless
+    let build_method_name = struct_attributes.get_build_method_name().to_string();
+    struct_attributes
+        .finishers
+        .retain(|finisher| finisher.name != build_method_name);
+
     Ok(struct_attributes)
 }
 
@@ -392,6 +2160,7 @@ mod tests {
             setter_prefix: None,
             impl_into: false,
             const_builder: false,
+            ..StructAttributes::default()
         };
         assert_eq!(custom_attrs.get_build_method_name(), "create");
     }
@@ -403,6 +2172,7 @@ mod tests {
             setter_prefix: None,
             impl_into: false,
             const_builder: false,
+            ..StructAttributes::default()
         };
         assert_eq!(attrs.get_build_method_name(), "create");
 
@@ -411,6 +2181,7 @@ mod tests {
             setter_prefix: None,
             impl_into: false,
             const_builder: false,
+            ..StructAttributes::default()
         };
         assert_eq!(attrs2.get_build_method_name(), "construct");
     }
@@ -422,6 +2193,7 @@ mod tests {
             setter_prefix: Some("with_".to_string()),
             impl_into: false,
             const_builder: false,
+            ..StructAttributes::default()
         };
         assert!(valid_attrs.validate().is_ok());
 
@@ -436,6 +2208,7 @@ mod tests {
             setter_prefix: None,
             impl_into: false,
             const_builder: false,
+            ..StructAttributes::default()
         };
         let result = invalid_attrs.validate();
         assert!(result.is_err());
@@ -536,6 +2309,7 @@ mod tests {
             setter_prefix: Some("with_".to_string()),
             impl_into: false,
             const_builder: false,
+            ..StructAttributes::default()
         };
         assert_eq!(custom_attrs.get_setter_prefix(), Some("with_"));
     }
@@ -562,6 +2336,66 @@ mod tests {
         assert_eq!(struct_attrs.get_setter_prefix(), Some("with_"));
     }
 
+    #[test]
+    fn test_parse_setter_prefix_except() {
+        let attrs = vec![parse_quote!(
+            #[builder(setter_prefix = "with_", setter_prefix(except(id, r#type)))]
+        )];
+        let struct_attrs = parse_struct_attributes(&attrs).unwrap();
+
+        assert_eq!(struct_attrs.get_setter_prefix(), Some("with_"));
+        let names: Vec<_> = struct_attrs
+            .get_setter_prefix_except()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(names, ["id", "r#type"]);
+        assert!(struct_attrs.is_setter_prefix_exempt("id"));
+        assert!(struct_attrs.is_setter_prefix_exempt("type"));
+        assert!(!struct_attrs.is_setter_prefix_exempt("name"));
+    }
+
+    #[test]
+    fn test_parse_setter_prefix_except_errors() {
+        let error =
+            |attr: syn::Attribute| parse_struct_attributes(&[attr]).unwrap_err().to_string();
+
+        assert!(error(parse_quote!(
+            #[builder(setter_prefix = "with_", setter_prefix(except(id, id)))]
+        ))
+        .contains("Field `id` is listed more than once"));
+        assert!(error(parse_quote!(
+            #[builder(setter_prefix = "with_", setter_prefix(only(id)))]
+        ))
+        .contains("Unknown setter_prefix option"));
+        assert!(error(parse_quote!(#[builder(setter_prefix(except(id)))]))
+            .contains("requires a struct-level prefix"));
+    }
+
+    #[test]
+    fn test_parse_prefix_scope_attribute() {
+        let attrs = vec![parse_quote!(
+            #[builder(setter_prefix = "with_", prefix_scope = "optional")]
+        )];
+        assert_eq!(
+            parse_struct_attributes(&attrs).unwrap().get_prefix_scope(),
+            PrefixScope::Optional
+        );
+        assert_eq!(
+            StructAttributes::default().get_prefix_scope(),
+            PrefixScope::All
+        );
+
+        let error =
+            |attr: syn::Attribute| parse_struct_attributes(&[attr]).unwrap_err().to_string();
+        assert!(error(parse_quote!(
+            #[builder(setter_prefix = "with_", prefix_scope = "required")]
+        ))
+        .contains("Invalid prefix_scope value 'required'"));
+        assert!(error(parse_quote!(#[builder(prefix_scope = "optional")]))
+            .contains("prefix_scope requires a struct-level prefix"));
+    }
+
     #[test]
     fn test_parse_empty_setter_prefix_error() {
         let attrs = vec![parse_quote!(#[builder(setter_prefix = "")])];
@@ -581,6 +2415,7 @@ mod tests {
             setter_prefix: Some("".to_string()),
             impl_into: false,
             const_builder: false,
+            ..StructAttributes::default()
         };
         let result = invalid_attrs.validate();
         assert!(result.is_err());
@@ -597,6 +2432,7 @@ mod tests {
             setter_prefix: Some("1invalid_".to_string()),
             impl_into: false,
             const_builder: false,
+            ..StructAttributes::default()
         };
         let result = invalid_attrs.validate();
         assert!(result.is_err());
@@ -613,6 +2449,7 @@ mod tests {
             setter_prefix: Some("with-".to_string()),
             impl_into: false,
             const_builder: false,
+            ..StructAttributes::default()
         };
         let result = invalid_attrs.validate();
         assert!(result.is_err());
@@ -634,6 +2471,7 @@ mod tests {
                 setter_prefix: Some(prefix.to_string()),
                 impl_into: false,
                 const_builder: false,
+                ..StructAttributes::default()
             };
             assert!(
                 attrs.validate().is_ok(),
@@ -669,6 +2507,7 @@ mod tests {
             setter_prefix: None,
             impl_into: true,
             const_builder: false,
+            ..StructAttributes::default()
         };
         assert!(impl_into_attrs.get_impl_into());
 
@@ -678,6 +2517,7 @@ mod tests {
             setter_prefix: None,
             impl_into: false,
             const_builder: false,
+            ..StructAttributes::default()
         };
         assert!(!no_impl_into_attrs.get_impl_into());
     }
@@ -731,6 +2571,7 @@ mod tests {
             setter_prefix: Some("with_".to_string()),
             impl_into: true,
             const_builder: false,
+            ..StructAttributes::default()
         };
         assert!(valid_attrs.validate().is_ok());
 
@@ -740,6 +2581,7 @@ mod tests {
             setter_prefix: None,
             impl_into: true,
             const_builder: false,
+            ..StructAttributes::default()
         };
         assert!(impl_into_only.validate().is_ok());
 
@@ -749,6 +2591,7 @@ mod tests {
             setter_prefix: None,
             impl_into: false,
             const_builder: false,
+            ..StructAttributes::default()
         };
         assert!(no_impl_into.validate().is_ok());
     }
@@ -767,6 +2610,7 @@ mod tests {
             setter_prefix: None,
             impl_into: false,
             const_builder: true,
+            ..StructAttributes::default()
         };
         assert!(const_attrs.get_const_builder());
     }
@@ -800,6 +2644,7 @@ mod tests {
             setter_prefix: None,
             impl_into: true,
             const_builder: true,
+            ..StructAttributes::default()
         };
         let result = invalid_attrs.validate();
         assert!(result.is_err());
@@ -824,6 +2669,7 @@ mod tests {
             setter_prefix: None,
             impl_into: false,
             const_builder: true,
+            ..StructAttributes::default()
         };
         assert!(const_only.validate().is_ok());
     }
@@ -835,7 +2681,691 @@ mod tests {
             setter_prefix: Some("with_".to_string()),
             impl_into: false,
             const_builder: true,
+            ..StructAttributes::default()
         };
         assert!(attrs.validate().is_ok());
     }
+
+    #[test]
+    fn test_parse_state_traits_attribute() {
+        let attrs = vec![parse_quote!(#[builder(state_traits)])];
+        let struct_attrs = parse_struct_attributes(&attrs).unwrap();
+
+        assert!(struct_attrs.state_traits);
+        assert!(struct_attrs.get_state_traits());
+        assert!(!StructAttributes::default().get_state_traits());
+    }
+
+    #[test]
+    fn test_parse_setter_trait_attribute() {
+        let attrs = vec![parse_quote!(#[builder(setter_trait)])];
+        assert!(parse_struct_attributes(&attrs).unwrap().get_setter_trait());
+        assert!(!StructAttributes::default().get_setter_trait());
+
+        let attrs = vec![parse_quote!(#[builder(lite, setter_trait)])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`lite` and `setter_trait` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_into_iter_attribute() {
+        let attrs = vec![parse_quote!(#[builder(into_iter)])];
+        let struct_attrs = parse_struct_attributes(&attrs).unwrap();
+
+        assert!(struct_attrs.get_into_iter());
+        assert!(!StructAttributes::default().get_into_iter());
+    }
+
+    #[test]
+    fn test_const_with_into_iter_error() {
+        let attrs = vec![parse_quote!(#[builder(const, into_iter)])];
+        let error = parse_struct_attributes(&attrs).unwrap_err();
+
+        assert!(error
+            .to_string()
+            .contains("`const` and `into_iter` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_all_required_attribute() {
+        let attrs = vec![parse_quote!(#[builder(all_required)])];
+        let struct_attrs = parse_struct_attributes(&attrs).unwrap();
+
+        assert!(struct_attrs.get_all_required());
+        assert!(!StructAttributes::default().get_all_required());
+    }
+
+    #[test]
+    fn test_parse_no_docs_attribute() {
+        let attrs = vec![parse_quote!(#[builder(no_docs)])];
+        assert!(parse_struct_attributes(&attrs).unwrap().get_no_docs());
+        assert!(!StructAttributes::default().get_no_docs());
+    }
+
+    #[test]
+    fn test_parse_build_by_attribute() {
+        let attrs = vec![parse_quote!(#[builder(build_by = "clone")])];
+        assert!(parse_struct_attributes(&attrs)
+            .unwrap()
+            .get_build_by_clone());
+
+        let attrs = vec![parse_quote!(#[builder(build_by = "move")])];
+        assert!(!parse_struct_attributes(&attrs)
+            .unwrap()
+            .get_build_by_clone());
+        assert!(!StructAttributes::default().get_build_by_clone());
+
+        let attrs = vec![parse_quote!(#[builder(build_by = "ref")])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("Invalid build_by value 'ref'"), "{error}");
+    }
+
+    #[test]
+    fn test_parse_option_into_attribute() {
+        let attrs = vec![parse_quote!(#[builder(option_into)])];
+        assert!(parse_struct_attributes(&attrs).unwrap().get_option_into());
+        assert!(!StructAttributes::default().get_option_into());
+
+        let attrs = vec![parse_quote!(#[builder(const, option_into)])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`const` and `option_into` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_lints_attribute() {
+        let attrs = vec![parse_quote!(#[builder(lints = "deny")])];
+        assert_eq!(
+            parse_struct_attributes(&attrs).unwrap().get_lints(),
+            LintLevel::Deny
+        );
+        assert_eq!(StructAttributes::default().get_lints(), LintLevel::Warn);
+
+        let attrs = vec![parse_quote!(#[builder(lints = "forbid")])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("Invalid lints value 'forbid'"), "{error}");
+    }
+
+    #[test]
+    fn test_parse_debug_expand_attribute() {
+        let attrs = vec![parse_quote!(#[builder(debug_expand)])];
+        assert!(parse_struct_attributes(&attrs).unwrap().get_debug_expand());
+        assert!(!StructAttributes::default().get_debug_expand());
+    }
+
+    #[test]
+    fn test_parse_arbitrary_attribute() {
+        let attrs = vec![parse_quote!(#[builder(arbitrary)])];
+        let result = parse_struct_attributes(&attrs);
+        if cfg!(feature = "arbitrary") {
+            assert!(result.unwrap().get_arbitrary());
+        } else {
+            let error = result.unwrap_err().to_string();
+            assert!(
+                error.contains("requires the `arbitrary` feature"),
+                "{error}"
+            );
+        }
+        assert!(!StructAttributes::default().get_arbitrary());
+    }
+
+    #[test]
+    fn test_parse_apply_env_attribute() {
+        let attrs = vec![parse_quote!(#[builder(apply_env)])];
+        let result = parse_struct_attributes(&attrs);
+        if cfg!(feature = "env") {
+            assert!(result.unwrap().get_apply_env());
+
+            let attrs = vec![parse_quote!(#[builder(lite, apply_env)])];
+            let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+            assert!(error.contains("`lite` and `apply_env` cannot be used together"));
+        } else {
+            let error = result.unwrap_err().to_string();
+            assert!(error.contains("requires the `env` feature"), "{error}");
+        }
+        assert!(!StructAttributes::default().get_apply_env());
+    }
+
+    #[test]
+    fn test_parse_lite_attribute() {
+        let attrs = vec![parse_quote!(#[builder(lite)])];
+        assert!(parse_struct_attributes(&attrs).unwrap().get_lite());
+        assert!(!StructAttributes::default().get_lite());
+
+        let attrs = vec![parse_quote!(#[builder(lite, state_traits)])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`lite` and `state_traits` cannot be used together"));
+
+        let attrs = vec![parse_quote!(#[builder(lite, build_method = "create")])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`lite` and `build_method` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_non_exhaustive_attribute() {
+        let attrs = vec![parse_quote!(#[builder(non_exhaustive)])];
+        let attributes = parse_struct_attributes(&attrs).unwrap();
+        assert_eq!(attributes.get_non_exhaustive(), Some(true));
+
+        let attrs = vec![parse_quote!(#[builder(non_exhaustive = false)])];
+        let attributes = parse_struct_attributes(&attrs).unwrap();
+        assert_eq!(attributes.get_non_exhaustive(), Some(false));
+        assert_eq!(StructAttributes::default().get_non_exhaustive(), None);
+
+        let attrs = vec![parse_quote!(#[builder(lite, non_exhaustive)])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`lite` and `non_exhaustive` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_build_vis_attribute() {
+        let attrs = vec![parse_quote!(#[builder(build_vis = "pub(crate)")])];
+        let attributes = parse_struct_attributes(&attrs).unwrap();
+        let expected: syn::Visibility = parse_quote!(pub(crate));
+        assert_eq!(attributes.get_build_vis(), Some(&expected));
+        assert_eq!(StructAttributes::default().get_build_vis(), None);
+
+        let attrs = vec![parse_quote!(#[builder(build_vis = "crate")])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("Invalid build_vis value 'crate'"), "{error}");
+
+        let attrs = vec![parse_quote!(#[builder(state_traits, build_vis = "pub(crate)")])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`state_traits` and `build_vis` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_entry_vis_attribute() {
+        let attrs = vec![parse_quote!(#[builder(entry_vis = "pub(super)")])];
+        let attributes = parse_struct_attributes(&attrs).unwrap();
+        let expected: syn::Visibility = parse_quote!(pub(super));
+        assert_eq!(attributes.get_entry_vis(), Some(&expected));
+        assert_eq!(StructAttributes::default().get_entry_vis(), None);
+
+        let attrs = vec![parse_quote!(#[builder(entry_vis = "pub crate")])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(
+            error.contains("Invalid entry_vis value 'pub crate'"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn test_parse_no_entry_attribute() {
+        let attrs = vec![parse_quote!(#[builder(no_entry)])];
+        assert!(parse_struct_attributes(&attrs).unwrap().get_no_entry());
+        assert!(!StructAttributes::default().get_no_entry());
+
+        let attrs = vec![parse_quote!(#[builder(no_entry, hide_states)])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`no_entry` and `hide_states` cannot be used together"));
+
+        let attrs = vec![parse_quote!(#[builder(lite, no_entry)])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`lite` and `no_entry` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_default_struct_attribute() {
+        let expected: syn::Expr = parse_quote!(Config::default());
+        let attrs = vec![parse_quote!(#[builder(default_struct = "Config::default()")])];
+        let attributes = parse_struct_attributes(&attrs).unwrap();
+        assert_eq!(attributes.get_default_struct(), Some(&expected));
+
+        let attrs = vec![parse_quote!(#[builder(default_struct = Config::default())])];
+        let attributes = parse_struct_attributes(&attrs).unwrap();
+        assert_eq!(attributes.get_default_struct(), Some(&expected));
+        assert_eq!(StructAttributes::default().get_default_struct(), None);
+
+        let attrs = vec![parse_quote!(#[builder(const, default_struct = "Config::new()")])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`const` and `default_struct` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_base_attribute() {
+        let expected: syn::Expr = parse_quote!(Config::default());
+        let attrs = vec![parse_quote!(#[builder(base = "Config::default()")])];
+        let attributes = parse_struct_attributes(&attrs).unwrap();
+        assert_eq!(attributes.get_base(), Some(&expected));
+
+        let attrs = vec![parse_quote!(#[builder(base = Config::default())])];
+        let attributes = parse_struct_attributes(&attrs).unwrap();
+        assert_eq!(attributes.get_base(), Some(&expected));
+        assert_eq!(StructAttributes::default().get_base(), None);
+
+        let attrs = vec![
+            parse_quote!(#[builder(base = "Config::default()", default_struct = "Config::default()")]),
+        ];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`base` and `default_struct` cannot be used together"));
+
+        let attrs = vec![parse_quote!(#[builder(lite, base = "Config::default()")])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`lite` and `base` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_fill_from_attribute() {
+        let attrs = vec![parse_quote!(#[builder(fill_from)])];
+        assert!(parse_struct_attributes(&attrs).unwrap().get_fill_from());
+        assert!(!StructAttributes::default().get_fill_from());
+
+        let attrs = vec![parse_quote!(#[builder(fill_from, const)])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`fill_from` and `const` cannot be used together"));
+
+        let attrs = vec![parse_quote!(#[builder(lite, fill_from)])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`lite` and `fill_from` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_derive_attribute() {
+        let attrs = vec![parse_quote!(#[builder(derive(Copy, Clone))])];
+        let attributes = parse_struct_attributes(&attrs).unwrap();
+        assert!(attributes.get_derive_clone());
+        assert!(attributes.get_derive_copy());
+
+        let attrs = vec![parse_quote!(#[builder(derive(Clone))])];
+        let attributes = parse_struct_attributes(&attrs).unwrap();
+        assert!(attributes.get_derive_clone());
+        assert!(!attributes.get_derive_copy());
+        assert!(!StructAttributes::default().get_derive_clone());
+
+        let attrs = vec![parse_quote!(#[builder(derive(Copy))])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`derive(Copy)` requires `Clone` as well"));
+
+        let attrs = vec![parse_quote!(#[builder(derive(Clone, Debug))])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("Unsupported builder derive. Supported traits: Clone, Copy"));
+
+        let attrs = vec![parse_quote!(#[builder(lite, derive(Clone))])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`lite` and `derive` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_serde_derive_attribute() {
+        let attrs = vec![parse_quote!(#[builder(derive(Serialize, Deserialize))])];
+        let result = parse_struct_attributes(&attrs);
+        if cfg!(feature = "serde") {
+            let attributes = result.unwrap();
+            assert!(attributes.get_derive_serialize());
+            assert!(attributes.get_derive_deserialize());
+            assert!(!attributes.get_derive_clone());
+        } else {
+            let error = result.unwrap_err().to_string();
+            assert!(error.contains("require the `serde` feature"), "{error}");
+        }
+        assert!(!StructAttributes::default().get_derive_serialize());
+        assert!(!StructAttributes::default().get_derive_deserialize());
+    }
+
+    #[test]
+    fn test_parse_impl_trait_attribute() {
+        let attrs = vec![
+            parse_quote!(#[builder(impl_trait = "crate::Described", impl_trait = Plugin<u32>)]),
+        ];
+        let attributes = parse_struct_attributes(&attrs).unwrap();
+        let expected: Vec<syn::Path> =
+            vec![parse_quote!(crate::Described), parse_quote!(Plugin<u32>)];
+        assert_eq!(attributes.get_impl_traits(), expected.as_slice());
+        assert!(StructAttributes::default().get_impl_traits().is_empty());
+
+        let attrs =
+            vec![parse_quote!(#[builder(impl_trait = "Described", impl_trait = Described)])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(
+            error.contains("Duplicate impl_trait attribute. `Described` is already implemented")
+        );
+
+        let attrs = vec![parse_quote!(#[builder(lite, impl_trait = "Described")])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`lite` and `impl_trait` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_buildable_attribute() {
+        let attrs = vec![parse_quote!(#[builder(buildable = "crate::Buildable")])];
+        let attributes = parse_struct_attributes(&attrs).unwrap();
+        let expected: syn::Path = parse_quote!(crate::Buildable);
+        assert_eq!(attributes.get_buildable(), Some(&expected));
+        assert_eq!(StructAttributes::default().get_buildable(), None);
+
+        let attrs = vec![parse_quote!(#[builder(buildable = Buildable, buildable = Buildable)])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("Duplicate buildable attribute"));
+
+        let conflicts: [syn::Attribute; 3] = [
+            parse_quote!(#[builder(lite, buildable = Buildable)]),
+            parse_quote!(#[builder(no_entry, buildable = Buildable)]),
+            parse_quote!(#[builder(entry_vis = "pub(crate)", buildable = Buildable)]),
+        ];
+        for attr in conflicts {
+            let error = parse_struct_attributes(&[attr]).unwrap_err().to_string();
+            assert!(error.contains("and `buildable` cannot") || error.contains("`buildable` and"));
+        }
+    }
+
+    #[test]
+    fn test_parse_nestable_attribute() {
+        let attrs = vec![parse_quote!(#[builder(nestable)])];
+        assert!(parse_struct_attributes(&attrs).unwrap().get_nestable());
+        assert!(!StructAttributes::default().get_nestable());
+
+        let attrs = vec![parse_quote!(#[builder(lite, nestable)])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`lite` and `nestable` cannot be used together"));
+
+        let attrs = vec![parse_quote!(#[builder(nestable, boxed)])];
+        let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+        assert!(error.contains("`nestable` and `boxed` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_also_dynamic_attribute() {
+        let attrs = vec![parse_quote!(#[builder(also_dynamic)])];
+        assert!(parse_struct_attributes(&attrs).unwrap().get_also_dynamic());
+        assert!(!StructAttributes::default().get_also_dynamic());
+
+        let conflicts: [syn::Attribute; 4] = [
+            parse_quote!(#[builder(lite, also_dynamic)]),
+            parse_quote!(#[builder(no_entry, also_dynamic)]),
+            parse_quote!(#[builder(entry_vis = "pub(crate)", also_dynamic)]),
+            parse_quote!(#[builder(build_vis = "pub(crate)", also_dynamic)]),
+        ];
+        for attr in conflicts {
+            let error = parse_struct_attributes(&[attr]).unwrap_err().to_string();
+            assert!(
+                error.contains("and `also_dynamic` cannot") || error.contains("`also_dynamic` and"),
+                "{error}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_field_descriptors_attribute() {
+        let attrs = vec![parse_quote!(#[builder(field_descriptors = "crate::FieldDescriptor")])];
+        let result = parse_struct_attributes(&attrs);
+        if cfg!(feature = "metadata") {
+            let expected: syn::Path = parse_quote!(crate::FieldDescriptor);
+            assert_eq!(result.unwrap().get_field_descriptors(), Some(&expected));
+
+            let attrs = vec![parse_quote!(
+                #[builder(field_descriptors = Descriptor, field_descriptors = Descriptor)]
+            )];
+            let error = parse_struct_attributes(&attrs).unwrap_err().to_string();
+            assert!(error.contains("Duplicate field_descriptors attribute"));
+        } else {
+            let error = result.unwrap_err().to_string();
+            assert!(error.contains("requires the `metadata` feature"), "{error}");
+        }
+        assert_eq!(StructAttributes::default().get_field_descriptors(), None);
+    }
+
+    #[test]
+    fn test_parse_state_aliases_attribute() {
+        let attrs = vec![parse_quote!(#[builder(state_aliases)])];
+        let aliases = parse_struct_attributes(&attrs)
+            .unwrap()
+            .state_aliases
+            .unwrap();
+        assert_eq!(aliases.start_ident("User"), "UserBuilderStart");
+        assert_eq!(aliases.complete_ident("User"), "UserBuilderComplete");
+        assert!(StructAttributes::default().get_state_aliases().is_none());
+
+        let attrs = vec![parse_quote!(#[builder(state_aliases(complete = "ReadyUser"))])];
+        let aliases = parse_struct_attributes(&attrs)
+            .unwrap()
+            .state_aliases
+            .unwrap();
+        assert_eq!(aliases.start_ident("User"), "UserBuilderStart");
+        assert_eq!(aliases.complete_ident("User"), "ReadyUser");
+    }
+
+    #[test]
+    fn test_parse_state_aliases_errors() {
+        let error =
+            |attr: syn::Attribute| parse_struct_attributes(&[attr]).unwrap_err().to_string();
+
+        assert!(
+            error(parse_quote!(#[builder(state_aliases(start = "New User"))]))
+                .contains("Invalid alias name 'New User'")
+        );
+        assert!(
+            error(parse_quote!(#[builder(state_aliases(ready = "ReadyUser"))]))
+                .contains("Unknown state_aliases option")
+        );
+        assert!(
+            error(parse_quote!(#[builder(state_aliases(start = "A", start = "B"))]))
+                .contains("Duplicate state_aliases option")
+        );
+        assert!(
+            error(parse_quote!(#[builder(state_aliases(start = "A", complete = "A"))]))
+                .contains("need different names")
+        );
+        assert!(
+            error(parse_quote!(#[builder(state_aliases, state_aliases)]))
+                .contains("Duplicate state_aliases attribute")
+        );
+        assert!(error(parse_quote!(#[builder(lite, state_aliases)]))
+            .contains("`lite` and `state_aliases` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_boxed_attribute() {
+        let attrs = vec![parse_quote!(#[builder(boxed)])];
+        assert!(parse_struct_attributes(&attrs).unwrap().get_boxed());
+        assert!(!StructAttributes::default().get_boxed());
+
+        let error =
+            |attr: syn::Attribute| parse_struct_attributes(&[attr]).unwrap_err().to_string();
+        assert!(error(parse_quote!(#[builder(boxed, const)]))
+            .contains("`boxed` and `const` cannot be used together"));
+        assert!(error(parse_quote!(#[builder(boxed, derive(Copy, Clone))]))
+            .contains("`boxed` and `derive(Copy)` cannot be used together"));
+        assert!(error(parse_quote!(#[builder(boxed, finishers(try_build))]))
+            .contains("`boxed` and `finishers` cannot be used together"));
+        assert!(error(parse_quote!(#[builder(lite, boxed)]))
+            .contains("`lite` and `boxed` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_test_default_attribute() {
+        let attrs = vec![parse_quote!(#[builder(test_default)])];
+        let attributes = parse_struct_attributes(&attrs).unwrap();
+        assert_eq!(attributes.get_test_default(), Some(&parse_quote!(test)));
+        assert!(StructAttributes::default().get_test_default().is_none());
+
+        let attrs = vec![parse_quote!(#[builder(test_default(cfg(feature = "fixtures")))])];
+        let attributes = parse_struct_attributes(&attrs).unwrap();
+        assert_eq!(
+            attributes.get_test_default(),
+            Some(&parse_quote!(feature = "fixtures"))
+        );
+
+        let error =
+            |attr: syn::Attribute| parse_struct_attributes(&[attr]).unwrap_err().to_string();
+        assert!(
+            error(parse_quote!(#[builder(test_default(feature = "fixtures"))]))
+                .contains("Expected `test_default(cfg(predicate))`")
+        );
+        assert!(error(parse_quote!(#[builder(test_default, test_default)]))
+            .contains("Duplicate test_default attribute"));
+        assert!(error(parse_quote!(#[builder(lite, test_default)]))
+            .contains("`lite` and `test_default` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_stable_names_attribute() {
+        let attributes =
+            parse_struct_attributes(&[parse_quote!(#[builder(stable_names)])]).unwrap();
+        assert!(attributes.get_stable_names());
+        assert!(attributes.get_hide_states());
+        assert_eq!(
+            attributes.get_state_aliases(),
+            Some(&StateAliases::default())
+        );
+        assert!(!StructAttributes::default().get_stable_names());
+
+        // Custom alias names are kept
+        let attributes = parse_struct_attributes(&[parse_quote!(
+            #[builder(stable_names, state_aliases(complete = "ReadyUser"))]
+        )])
+        .unwrap();
+        assert_eq!(
+            attributes
+                .get_state_aliases()
+                .unwrap()
+                .complete_ident("User"),
+            "ReadyUser"
+        );
+
+        let error = parse_struct_attributes(&[parse_quote!(#[builder(no_entry, stable_names)])])
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("`no_entry` and `stable_names` cannot be used together"));
+    }
+
+    #[test]
+    fn test_parse_hide_states_attribute() {
+        let attrs = vec![parse_quote!(#[builder(hide_states)])];
+        assert!(parse_struct_attributes(&attrs).unwrap().get_hide_states());
+        assert!(!StructAttributes::default().get_hide_states());
+    }
+
+    #[test]
+    fn test_parse_auto_trait_assertions() {
+        let send = parse_struct_attributes(&[parse_quote!(#[builder(assert_send)])]).unwrap();
+        assert!(send.get_assert_send());
+        assert!(!send.get_assert_sync());
+
+        let both = parse_struct_attributes(&[parse_quote!(#[builder(assert_send_sync)])]).unwrap();
+        assert!(both.get_assert_send());
+        assert!(both.get_assert_sync());
+
+        let default = StructAttributes::default();
+        assert!(!default.get_assert_send() && !default.get_assert_sync());
+        assert!(!default.get_assert_unpin());
+
+        let listed =
+            parse_struct_attributes(&[parse_quote!(#[builder(static_assert(Sync, Unpin))])])
+                .unwrap();
+        assert!(!listed.get_assert_send());
+        assert!(listed.get_assert_sync());
+        assert!(listed.get_assert_unpin());
+
+        let error = parse_struct_attributes(&[parse_quote!(#[builder(static_assert(Copy))])])
+            .unwrap_err()
+            .to_string();
+        assert!(error
+            .contains("Unsupported static assertion. Supported auto traits: Send, Sync, Unpin"));
+    }
+
+    #[test]
+    fn test_parse_module_attribute() {
+        let attrs =
+            parse_struct_attributes(&[parse_quote!(#[builder(module = "user_builder")])]).unwrap();
+        assert_eq!(attrs.get_module().unwrap().to_string(), "user_builder");
+        assert!(StructAttributes::default().get_module().is_none());
+
+        let error =
+            parse_struct_attributes(&[parse_quote!(#[builder(module = "crate::builders")])])
+                .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Invalid module name 'crate::builders'"));
+    }
+
+    #[test]
+    fn test_parse_finishers_attribute() {
+        let attrs = parse_struct_attributes(&[parse_quote!(
+            #[builder(finishers(
+                build,
+                try_build(validate = check, error = Error),
+                build_arc(into = "Arc<Self>")
+            ))]
+        )])
+        .unwrap();
+
+        let finishers = attrs.get_finishers();
+        assert_eq!(finishers.len(), 2);
+        assert_eq!(finishers[0].name.to_string(), "try_build");
+        assert!(finishers[0].validate.is_some() && finishers[0].error.is_some());
+        assert_eq!(finishers[1].name.to_string(), "build_arc");
+        assert!(finishers[1].into.is_some() && finishers[1].validate.is_none());
+    }
+
+    #[test]
+    fn test_parse_finisher_context_and_garde() {
+        let attrs = parse_struct_attributes(&[parse_quote!(
+            #[builder(finishers(
+                build_checked(validate = check, error = Error, context = Limits),
+                build_garde(garde)
+            ))]
+        )])
+        .unwrap();
+
+        let finishers = attrs.get_finishers();
+        assert!(finishers[0].context.is_some());
+        let garde = &finishers[1];
+        let validate = garde.validate.as_ref().unwrap();
+        let context = garde.context.as_ref().unwrap();
+        assert_eq!(
+            quote::quote!(#validate).to_string(),
+            ":: garde :: Validate :: validate_with"
+        );
+        assert_eq!(
+            quote::quote!(#context).to_string(),
+            "< Self as :: garde :: Validate > :: Context"
+        );
+
+        let error = parse_struct_attributes(&[parse_quote!(
+            #[builder(finishers(build_garde(garde, error = Error)))]
+        )])
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains("uses `garde`"), "{error}");
+
+        let error = parse_struct_attributes(&[parse_quote!(
+            #[builder(finishers(build_checked(context = Limits)))]
+        )])
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains("no `validate` function"), "{error}");
+    }
+
+    #[test]
+    fn test_invalid_finishers_attribute() {
+        let missing_error = parse_struct_attributes(&[
+            parse_quote!(#[builder(finishers(try_build(validate = check)))]),
+        ]);
+        assert!(missing_error
+            .unwrap_err()
+            .to_string()
+            .contains("no `error` type"));
+
+        let build_with_options = parse_struct_attributes(&[
+            parse_quote!(#[builder(finishers(build(into = Box<Self>)))]),
+        ]);
+        assert!(build_with_options
+            .unwrap_err()
+            .to_string()
+            .contains("cannot take finisher options"));
+
+        let duplicate = parse_struct_attributes(&[parse_quote!(#[builder(finishers(done, done))])]);
+        assert!(duplicate
+            .unwrap_err()
+            .to_string()
+            .contains("Duplicate finisher"));
+
+        let build_into = parse_struct_attributes(&[parse_quote!(
+            #[builder(finishers(build_into(into = Box<Self>)))]
+        )]);
+        assert!(build_into
+            .unwrap_err()
+            .to_string()
+            .contains("is generated for every builder"));
+    }
 }
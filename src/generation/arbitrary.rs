@@ -0,0 +1,237 @@
+//! `arbitrary::Arbitrary` Through the Builder
+//!
+//! `#[builder(arbitrary)]` (with the `arbitrary` feature) implements
+//! `arbitrary::Arbitrary` for the struct by driving its own builder: every
+//! required field's setter is called with an arbitrary value, and each optional
+//! field is either set the same way or left at its default. Fuzzed values
+//! therefore go through the same converters, defaults and build-time resolution
+//! as values constructed by hand.
+//!
+//! # Generated Code Structure
+//!
+//! ```rust,ignore
+//! impl<'__arbitrary> ::arbitrary::Arbitrary<'__arbitrary> for Config
+//! where
+//!     String: ::arbitrary::Arbitrary<'__arbitrary>,
+//!     u16: ::arbitrary::Arbitrary<'__arbitrary>,
+//! {
+//!     fn arbitrary(u: &mut ::arbitrary::Unstructured<'__arbitrary>) -> ::arbitrary::Result<Self> {
+//!         let mut builder = Self::builder();
+//!         if u.arbitrary::<bool>()? {
+//!             builder = builder.port(u.arbitrary::<u16>()?);
+//!         }
+//!         let builder = builder.host(u.arbitrary::<String>()?);
+//!         ::core::result::Result::Ok(builder.build())
+//!     }
+//! }
+//! ```
+//!
+//! Each setter receives a value of its natural input type: the converter's
+//! parameter type for fields with a `converter`, the field type otherwise.
+
+use crate::analysis::{FieldInfo, StructAnalysis};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The lifetime of the `Unstructured` data in the generated implementation.
+const ARBITRARY_LIFETIME: &str = "'__arbitrary";
+
+/// The setter prefix used in lite mode when none is configured.
+const LITE_SETTER_PREFIX: &str = "with_";
+
+/// Generates the `arbitrary::Arbitrary` implementation for a struct.
+///
+/// # Arguments
+///
+/// * `analysis` - Complete struct analysis
+///
+/// # Returns
+///
+/// A `syn::Result<TokenStream>` containing the impl block.
+pub fn generate_arbitrary_impl(analysis: &StructAnalysis) -> syn::Result<TokenStream> {
+    let struct_name = analysis.struct_name();
+    let struct_attributes = analysis.struct_attributes();
+    let lifetime = syn::Lifetime::new(ARBITRARY_LIFETIME, proc_macro2::Span::call_site());
+    let is_lite = struct_attributes.get_lite();
+    let setter_prefix = if is_lite {
+        struct_attributes
+            .get_setter_prefix()
+            .or(Some(LITE_SETTER_PREFIX))
+    } else {
+        struct_attributes.get_setter_prefix()
+    };
+
+    // Every value drawn from the input needs an `Arbitrary` implementation
+    let mut generics = analysis.struct_generics().clone();
+    generics.params.insert(
+        0,
+        syn::GenericParam::Lifetime(syn::LifetimeParam::new(lifetime.clone())),
+    );
+    let settable_fields = analysis
+        .all_fields()
+        .filter(|field| field.should_generate_setter());
+    for field in settable_fields {
+        let input_type = field.setter_input_type();
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote! { #input_type: ::arbitrary::Arbitrary<#lifetime> });
+    }
+    // Starting the builder needs the `Default` bounds of the optional fields
+    let default_bounds = crate::generation::TokenGenerator::new(analysis).default_field_bounds();
+    for bound in default_bounds {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote! { #bound });
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, type_generics, _) = analysis.struct_generics().split_for_impl();
+
+    let draw = |field: &FieldInfo| {
+        let input_type = field.setter_input_type();
+        quote! { u.arbitrary::<#input_type>()? }
+    };
+    let setter_ident = |field: &FieldInfo| -> syn::Result<syn::Ident> {
+        syn::parse_str(&field.final_setter_name(setter_prefix))
+    };
+    // Setters that validate or use TryFrom reject input the same way a validating build does
+    let checked = |field: &FieldInfo| {
+        if field.has_fallible_setter() {
+            quote! { .map_err(|_| ::arbitrary::Error::IncorrectFormat)? }
+        } else {
+            quote! {}
+        }
+    };
+
+    // Lite mode takes the required fields as constructor arguments
+    let (start, required_setters) = if is_lite {
+        let arguments = analysis.required_fields().iter().map(draw);
+        (quote! { Self::new(#(#arguments),*) }, Vec::new())
+    } else {
+        let start = match analysis.builder_method_field() {
+            Some(field) => {
+                let entry_ident = setter_ident(field)?;
+                let value = draw(field);
+                quote! { Self::#entry_ident(#value) }
+            }
+            None => quote! { Self::builder() },
+        };
+        let mut required_setters = Vec::new();
+        for field in analysis.required_fields() {
+            if field.attributes().builder_method {
+                continue;
+            }
+            let setter = setter_ident(field)?;
+            let value = draw(field);
+            let checked = checked(field);
+            required_setters.push(quote! { let builder = builder.#setter(#value)#checked; });
+        }
+        (start, required_setters)
+    };
+
+    // Optional fields keep their defaults unless the input decides to set them
+    let mut optional_setters = Vec::new();
+    for field in analysis.optional_fields() {
+        if !field.should_generate_setter() {
+            continue;
+        }
+        let setter = setter_ident(field)?;
+        let value = draw(field);
+        let cfg = field.setter_cfg_tokens();
+        let checked = checked(field);
+        optional_setters.push(quote! {
+            #cfg
+            if u.arbitrary::<bool>()? {
+                builder = builder.#setter(#value)#checked;
+            }
+        });
+    }
+
+    let finish = if is_lite {
+        quote! { builder }
+    } else if analysis.has_validated_fields() {
+        // Input that fails a field's validation does not describe a value
+        let build_ident: syn::Ident = syn::parse_str(struct_attributes.get_build_method_name())?;
+        quote! {
+            builder
+                .#build_ident()
+                .map_err(|_| ::arbitrary::Error::IncorrectFormat)?
+        }
+    } else {
+        let build_ident: syn::Ident = syn::parse_str(struct_attributes.get_build_method_name())?;
+        quote! { builder.#build_ident() }
+    };
+    // `Arbitrary` produces the struct itself, not the box a boxed builder builds
+    let finish = if struct_attributes.get_boxed() {
+        quote! { *#finish }
+    } else {
+        finish
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::arbitrary::Arbitrary<#lifetime> for #struct_name #type_generics #where_clause {
+            fn arbitrary(u: &mut ::arbitrary::Unstructured<#lifetime>) -> ::arbitrary::Result<Self> {
+                #[allow(unused_mut)]
+                let mut builder = #start;
+                #(#optional_setters)*
+                #(#required_setters)*
+                ::core::result::Result::Ok(#finish)
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::analyze_struct;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_arbitrary_impl() {
+        let input = parse_quote! {
+            struct Config {
+                #[builder(required)]
+                host: String,
+                #[builder(default = 80)]
+                port: u16,
+                #[builder(converter = |tags: Vec<String>| tags.join(","))]
+                tags: String,
+                #[builder(skip_setter, default = 1)]
+                version: u8,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let code = generate_arbitrary_impl(&analysis).unwrap().to_string();
+
+        assert!(code.contains(
+            "impl < '__arbitrary > :: arbitrary :: Arbitrary < '__arbitrary > for Config"
+        ));
+        assert!(code.contains("Vec < String > : :: arbitrary :: Arbitrary < '__arbitrary >"));
+        assert!(code.contains("let mut builder = Self :: builder ()"));
+        assert!(code.contains("builder = builder . port (u . arbitrary :: < u16 > () ?)"));
+        assert!(code.contains("let builder = builder . host (u . arbitrary :: < String > () ?)"));
+        assert!(!code.contains("version"));
+        assert!(code.contains("Ok (builder . build ())"));
+    }
+
+    #[test]
+    fn test_generate_arbitrary_impl_for_lite_mode() {
+        let input = parse_quote! {
+            #[builder(lite)]
+            struct Point<T> {
+                #[builder(required)]
+                x: T,
+                label: Option<String>,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let code = generate_arbitrary_impl(&analysis).unwrap().to_string();
+
+        assert!(code.contains("for Point < T >"));
+        assert!(code.contains("let mut builder = Self :: new (u . arbitrary :: < T > () ?)"));
+        assert!(code.contains("builder = builder . with_label"));
+        assert!(code.contains("Ok (builder)"));
+    }
+}
@@ -0,0 +1,94 @@
+//! Expansion of the `build!` Macro
+//!
+//! This module turns a struct literal such as `User { name: "a", age: 3 }` into
+//! the equivalent builder chain `User::builder().name("a").age(3).build()`.
+//! The derived builder still checks the required fields, so a missing field
+//! fails to compile just like an incomplete hand-written chain.
+//!
+//! The macro only sees the literal, not the struct definition. Each field name
+//! is therefore used as the setter name, and the chain always ends in `build()`.
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+
+/// Generates the builder chain for a struct literal passed to `build!`.
+///
+/// # Arguments
+///
+/// * `input` - The struct literal, e.g. `User { name: "a".into() }`
+///
+/// # Returns
+///
+/// A `syn::Result<TokenStream>` containing the builder chain expression.
+///
+/// # Errors
+///
+/// Returns an error for struct update syntax (`..base`), qualified paths and
+/// tuple struct fields, none of which map onto setter calls.
+pub fn generate_build_expression(input: &syn::ExprStruct) -> syn::Result<TokenStream> {
+    if let Some(rest) = &input.rest {
+        return Err(syn::Error::new(
+            rest.span(),
+            "build! does not support struct update syntax. \
+             Fields left out of the literal keep their builder defaults.",
+        ));
+    }
+    if input.qself.is_some() {
+        return Err(syn::Error::new(
+            input.path.span(),
+            "build! expects a plain struct path such as `User` or `User::<T>`",
+        ));
+    }
+
+    let path = &input.path;
+    let mut setters = Vec::new();
+    for field in &input.fields {
+        let setter_ident = match &field.member {
+            syn::Member::Named(ident) => ident,
+            syn::Member::Unnamed(index) => {
+                return Err(syn::Error::new(
+                    index.span(),
+                    "build! only supports structs with named fields",
+                ))
+            }
+        };
+        let value = &field.expr;
+        // Errors about an unknown setter or a missing field point at the field
+        setters.push(quote_spanned! {setter_ident.span()=> .#setter_ident(#value) });
+    }
+
+    Ok(quote! {
+        #path::builder() #(#setters)* .build()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_build_expression() {
+        let input: syn::ExprStruct = parse_quote! {
+            User { name: "a".to_string(), age }
+        };
+        let tokens = generate_build_expression(&input).unwrap();
+
+        assert_eq!(
+            tokens.to_string(),
+            quote! { User::builder().name("a".to_string()).age(age).build() }.to_string()
+        );
+    }
+
+    #[test]
+    fn test_generate_build_expression_rejects_unsupported_literals() {
+        let input: syn::ExprStruct = parse_quote! { User { name: "a", ..base } };
+        let error = generate_build_expression(&input).unwrap_err().to_string();
+        assert!(error.contains("does not support struct update syntax"));
+
+        let input: syn::ExprStruct = parse_quote! { Pair { 0: 1 } };
+        let error = generate_build_expression(&input).unwrap_err().to_string();
+        assert!(error.contains("only supports structs with named fields"));
+    }
+}
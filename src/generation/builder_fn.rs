@@ -0,0 +1,482 @@
+//! Expansion of the `#[builder_fn]` Attribute Macro
+//!
+//! This module turns a free function into a builder for its arguments, so
+//!
+//! ```text
+//! fn connect(host: &str, port: u16, timeout: Option<Duration>) -> Client { ... }
+//! ```
+//!
+//! is called as `connect().host("db").port(5432).call()`. The parameters become
+//! the fields of a `{Function}Args` struct (`ConnectArgs`), which gets the
+//! regular type-state builder: parameters are required unless their type is
+//! `Option<T>` or they opt out with the usual field attributes. `connect()`
+//! starts the builder, and `call()` on the complete state builds the arguments,
+//! binds them to the original parameter patterns and runs the function body.
+//!
+//! Elided lifetimes in parameter types are given names, as the arguments are
+//! stored in a struct, and a return type that borrows from the single input
+//! lifetime keeps doing so. Methods cannot be expanded: an attribute on an
+//! associated function cannot emit the arguments struct into the `impl` block.
+
+use crate::analysis::StructAnalysis;
+use crate::attributes::{parse_field_attributes_in_struct, StructAttributes};
+use crate::utils::field_utils::extract_option_inner_type;
+use crate::utils::identifiers::{snake_case_to_pascal_case, strip_raw_identifier_prefix};
+use crate::validation::ErrorMessages;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::spanned::Spanned;
+
+/// A function with `#[builder_fn]`, split into its arguments struct and body.
+pub struct BuilderFn {
+    /// The original function, whose parameters have been moved to `args_struct`
+    function: syn::ItemFn,
+    /// The generated `{Function}Args` struct, with the builder attributes
+    args_struct: syn::DeriveInput,
+    /// The pattern each parameter is bound to in the function body, e.g. `mut port`
+    bindings: Vec<syn::Pat>,
+    /// The return type, with elided lifetimes named after the input lifetime
+    output: syn::ReturnType,
+}
+
+impl BuilderFn {
+    /// Splits a function into the arguments struct and the function body.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The macro arguments, used as struct-level builder attributes
+    /// * `function` - The function the attribute is applied to
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result` containing the split function.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for methods, `const` and variadic functions,
+    /// parameters that are not bound to a single identifier and `impl Trait`
+    /// parameter types.
+    pub fn parse(args: TokenStream, mut function: syn::ItemFn) -> syn::Result<Self> {
+        let sig = &function.sig;
+        if let Some(constness) = &sig.constness {
+            return Err(syn::Error::new(
+                constness.span(),
+                "#[builder_fn] does not support const functions",
+            ));
+        }
+        if let Some(variadic) = &sig.variadic {
+            return Err(syn::Error::new(
+                variadic.span(),
+                "#[builder_fn] does not support variadic functions",
+            ));
+        }
+
+        let mut generics = sig.generics.clone();
+        let mut lifetimes = Vec::new();
+        let mut fields = Vec::new();
+        let mut bindings = Vec::new();
+        for input in &sig.inputs {
+            let param = match input {
+                syn::FnArg::Typed(param) => param,
+                syn::FnArg::Receiver(receiver) => {
+                    return Err(ErrorMessages::structured_error(
+                        receiver,
+                        "#[builder_fn] does not support methods",
+                        Some("the arguments struct cannot be generated inside an `impl` block"),
+                        Some("apply #[builder_fn] to a free function and call it from the method"),
+                    ));
+                }
+            };
+            let field_ident = match param.pat.as_ref() {
+                syn::Pat::Ident(pat_ident) if pat_ident.subpat.is_none() => pat_ident.ident.clone(),
+                pat => {
+                    return Err(ErrorMessages::structured_error(
+                        pat,
+                        "#[builder_fn] parameters must be bound to a name",
+                        Some("each parameter becomes a setter named after it"),
+                        Some("bind the parameter to a name and destructure it in the body"),
+                    ));
+                }
+            };
+            if let syn::Type::ImplTrait(impl_trait) = param.ty.as_ref() {
+                return Err(ErrorMessages::structured_error(
+                    impl_trait,
+                    "#[builder_fn] does not support `impl Trait` parameters",
+                    Some("the builder stores each argument, which needs a nameable type"),
+                    Some("declare a type parameter, e.g. `fn f<T: Trait>(value: T)`"),
+                ));
+            }
+
+            let mut ty = param.ty.as_ref().clone();
+            name_elided_lifetimes(&mut ty, &mut lifetimes);
+
+            let mut attrs = param.attrs.clone();
+            // Option parameters can be left out, like optional struct fields
+            let marked = parse_field_attributes_in_struct(&attrs, &StructAttributes::default())
+                .map_or(true, |attributes| {
+                    attributes.required || attributes.optional || attributes.required_cfg.is_some()
+                });
+            if !marked && extract_option_inner_type(&ty).is_some() {
+                attrs.push(syn::parse_quote!(#[builder(optional)]));
+            }
+
+            fields.push(quote! {
+                #(#attrs)*
+                #field_ident: #ty
+            });
+            bindings.push(param.pat.as_ref().clone());
+        }
+
+        // Type parameters and lifetimes only used by the return type still
+        // need a use in the struct
+        let declared: Vec<TokenStream> = generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                syn::GenericParam::Lifetime(lifetime_param) => {
+                    let lifetime = &lifetime_param.lifetime;
+                    Some(quote! { &#lifetime () })
+                }
+                syn::GenericParam::Type(type_param) => {
+                    let ident = &type_param.ident;
+                    Some(quote! { #ident })
+                }
+                syn::GenericParam::Const(_) => None,
+            })
+            .collect();
+        if !declared.is_empty() {
+            fields.push(quote! {
+                #[builder(skip_setter, default = ::core::marker::PhantomData)]
+                __builder_fn_marker: ::core::marker::PhantomData<fn() -> (#(#declared,)*)>
+            });
+        }
+
+        let mut output = sig.output.clone();
+        if let ([lifetime], syn::ReturnType::Type(_, ty)) = (lifetimes.as_slice(), &mut output) {
+            if !generics
+                .params
+                .iter()
+                .any(|param| matches!(param, syn::GenericParam::Lifetime(_)))
+            {
+                fill_elided_lifetimes(ty, lifetime);
+            }
+        }
+        for lifetime in lifetimes.into_iter().rev() {
+            generics.params.insert(
+                0,
+                syn::GenericParam::Lifetime(syn::LifetimeParam::new(lifetime)),
+            );
+        }
+
+        let fn_name = strip_raw_identifier_prefix(&sig.ident.to_string()).into_owned();
+        let args_ident = syn::Ident::new(
+            &format!("{}Args", snake_case_to_pascal_case(&fn_name)),
+            sig.ident.span(),
+        );
+        let doc = format!("The arguments of [`{fn_name}`], collected by its builder.");
+        let vis = &function.vis;
+        let where_clause = &generics.where_clause;
+        let mut args_struct: syn::DeriveInput = syn::parse2(quote! {
+            #[doc = #doc]
+            #[builder(all_required)]
+            #vis struct #args_ident #generics #where_clause {
+                #(#fields,)*
+            }
+        })?;
+        if !args.is_empty() {
+            args_struct.attrs.push(syn::parse_quote!(#[builder(#args)]));
+        }
+
+        function.sig.inputs.clear();
+        Ok(Self {
+            function,
+            args_struct,
+            bindings,
+            output,
+        })
+    }
+
+    /// Returns the arguments struct, with the builder attributes still on it.
+    pub fn args_struct(&self) -> &syn::DeriveInput {
+        &self.args_struct
+    }
+
+    /// Generates the arguments struct, the entry function and `call()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `analysis` - The analysis of the arguments struct
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<TokenStream>` containing the struct without builder
+    /// attributes, the function returning the initial builder state and the
+    /// `call` method of the complete state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for builder options that leave no single complete
+    /// state to add `call()` to, or no `builder()` to start from.
+    pub fn generate(&self, analysis: &StructAnalysis) -> syn::Result<TokenStream> {
+        let struct_attributes = analysis.struct_attributes();
+        let unsupported = if struct_attributes.get_lite() {
+            Some("lite")
+        } else if struct_attributes.get_const_builder() {
+            Some("const")
+        } else if struct_attributes.get_hide_states() {
+            Some("hide_states")
+        } else if struct_attributes.get_no_entry() {
+            Some("no_entry")
+        } else if struct_attributes.get_boxed() {
+            Some("boxed")
+        } else if analysis.builder_method_field().is_some() {
+            Some("builder_method")
+        } else if !analysis.required_cfg_predicates().is_empty() {
+            Some("required(cfg(...))")
+        } else if analysis.has_validated_fields() {
+            Some("validate")
+        } else {
+            None
+        };
+        if let Some(unsupported) = unsupported {
+            return Err(ErrorMessages::structured_error(
+                &self.function.sig.ident,
+                &format!("#[builder_fn] cannot be combined with `{unsupported}`"),
+                Some("`call()` is added to the complete builder state, which `builder()` must reach with a plain build method"),
+                Some(&format!("remove `{unsupported}`")),
+            ));
+        }
+
+        let mut args_struct = self.args_struct.clone();
+        args_struct
+            .attrs
+            .retain(|attr| !attr.path().is_ident("builder"));
+        if let syn::Data::Struct(data) = &mut args_struct.data {
+            for field in data.fields.iter_mut() {
+                field.attrs.retain(|attr| !attr.path().is_ident("builder"));
+            }
+        }
+
+        let args_ident = &self.args_struct.ident;
+        let start_alias = syn::Ident::new(&format!("{args_ident}BuilderStart"), Span::call_site());
+        let ready_alias = syn::Ident::new(&format!("{args_ident}BuilderReady"), Span::call_site());
+        let (impl_generics, type_generics, where_clause) =
+            self.args_struct.generics.split_for_impl();
+
+        let syn::ItemFn {
+            attrs,
+            vis,
+            sig,
+            block,
+        } = &self.function;
+        let fn_ident = &sig.ident;
+        let asyncness = &sig.asyncness;
+        let unsafety = &sig.unsafety;
+        let abi = &sig.abi;
+        let output = &self.output;
+        let build_method: syn::Ident = syn::parse_str(struct_attributes.get_build_method_name())?;
+        let bindings = &self.bindings;
+        // The body's statements are spliced after the destructuring rather
+        // than nested as a block, so a single-expression body does not end up
+        // in redundant braces that trip `unused_braces` in the caller's crate.
+        let stmts = &block.stmts;
+
+        let call_doc = format!(
+            "Calls `{}` with the arguments set on the builder.",
+            strip_raw_identifier_prefix(&fn_ident.to_string())
+        );
+
+        Ok(quote! {
+            #args_struct
+
+            #(#attrs)*
+            #vis fn #fn_ident #impl_generics () -> #start_alias #type_generics #where_clause {
+                #args_ident::builder()
+            }
+
+            impl #impl_generics #ready_alias #type_generics #where_clause {
+                #[doc = #call_doc]
+                #vis #asyncness #unsafety #abi fn call(self) #output {
+                    let #args_ident { #(#bindings,)* .. } = self.#build_method();
+                    #(#stmts)*
+                }
+            }
+        })
+    }
+}
+
+/// Names the elided lifetimes in a parameter type.
+///
+/// Each `&T` without a lifetime and each `'_` gets a new lifetime
+/// `'__builder_fn_N`, which is recorded in `lifetimes`.
+///
+/// # Arguments
+///
+/// * `ty` - The parameter type to rewrite in place
+/// * `lifetimes` - The lifetimes created so far
+fn name_elided_lifetimes(ty: &mut syn::Type, lifetimes: &mut Vec<syn::Lifetime>) {
+    rewrite_lifetimes(ty, &mut |lifetime| {
+        let named = syn::Lifetime::new(
+            &format!("'__builder_fn_{}", lifetimes.len()),
+            Span::call_site(),
+        );
+        lifetimes.push(named.clone());
+        *lifetime = Some(named);
+    });
+}
+
+/// Replaces the elided lifetimes in a return type with the input lifetime.
+///
+/// # Arguments
+///
+/// * `ty` - The return type to rewrite in place
+/// * `input` - The only lifetime of the function's parameters
+fn fill_elided_lifetimes(ty: &mut syn::Type, input: &syn::Lifetime) {
+    rewrite_lifetimes(ty, &mut |lifetime| *lifetime = Some(input.clone()));
+}
+
+/// Calls `rewrite` for each elided or `'_` lifetime in a type.
+///
+/// Lifetimes left out of paths, such as `Formatter` for `Formatter<'_>`, are
+/// not visible in the tokens and stay elided.
+fn rewrite_lifetimes(ty: &mut syn::Type, rewrite: &mut dyn FnMut(&mut Option<syn::Lifetime>)) {
+    match ty {
+        syn::Type::Reference(reference) => {
+            if reference
+                .lifetime
+                .as_ref()
+                .map_or(true, |lifetime| lifetime.ident == "_")
+            {
+                rewrite(&mut reference.lifetime);
+            }
+            rewrite_lifetimes(&mut reference.elem, rewrite);
+        }
+        syn::Type::Path(type_path) => {
+            if let Some(qself) = &mut type_path.qself {
+                rewrite_lifetimes(&mut qself.ty, rewrite);
+            }
+            for segment in type_path.path.segments.iter_mut() {
+                if let syn::PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+                    for argument in arguments.args.iter_mut() {
+                        match argument {
+                            syn::GenericArgument::Lifetime(lifetime) if lifetime.ident == "_" => {
+                                let mut named = None;
+                                rewrite(&mut named);
+                                if let Some(named) = named {
+                                    *lifetime = named;
+                                }
+                            }
+                            syn::GenericArgument::Type(ty) => rewrite_lifetimes(ty, rewrite),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+        syn::Type::Array(array) => rewrite_lifetimes(&mut array.elem, rewrite),
+        syn::Type::Slice(slice) => rewrite_lifetimes(&mut slice.elem, rewrite),
+        syn::Type::Ptr(pointer) => rewrite_lifetimes(&mut pointer.elem, rewrite),
+        syn::Type::Paren(paren) => rewrite_lifetimes(&mut paren.elem, rewrite),
+        syn::Type::Group(group) => rewrite_lifetimes(&mut group.elem, rewrite),
+        syn::Type::Tuple(tuple) => {
+            for elem in tuple.elems.iter_mut() {
+                rewrite_lifetimes(elem, rewrite);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn parse(args: TokenStream, function: syn::ItemFn) -> syn::Result<BuilderFn> {
+        BuilderFn::parse(args, function)
+    }
+
+    #[test]
+    fn test_parameters_become_fields() {
+        let builder_fn = parse(
+            quote! {},
+            parse_quote! {
+                pub fn connect(host: &str, mut port: u16, timeout: Option<u64>) -> Client {
+                    Client::new(host, port, timeout)
+                }
+            },
+        )
+        .unwrap();
+
+        let args_struct = builder_fn.args_struct();
+        assert_eq!(args_struct.ident, "ConnectArgs");
+        assert_eq!(
+            quote! { #args_struct }.to_string(),
+            quote! {
+                #[doc = "The arguments of [`connect`], collected by its builder."]
+                #[builder(all_required)]
+                pub struct ConnectArgs<'__builder_fn_0> {
+                    host: &'__builder_fn_0 str,
+                    port: u16,
+                    #[builder(optional)]
+                    timeout: Option<u64>,
+                }
+            }
+            .to_string()
+        );
+        let port = &builder_fn.bindings[1];
+        assert_eq!(quote! { #port }.to_string(), "mut port");
+    }
+
+    #[test]
+    fn test_lifetimes_and_generics() {
+        let builder_fn = parse(
+            quote! { setter_prefix = "with_" },
+            parse_quote! {
+                fn first<T: Clone>(items: &[T], #[builder(required)] fallback: Option<T>) -> &T {
+                    &items[0]
+                }
+            },
+        )
+        .unwrap();
+
+        let args_struct = builder_fn.args_struct();
+        let code = quote! { #args_struct }.to_string();
+        assert!(code.contains("struct FirstArgs < '__builder_fn_0 , T : Clone >"));
+        assert!(code.contains("items : & '__builder_fn_0 [T]"));
+        assert!(!code.contains("# [builder (optional)]"));
+        assert!(code.contains("PhantomData < fn () -> (T ,) >"));
+        assert!(code.contains("# [builder (setter_prefix = \"with_\")]"));
+
+        let output = &builder_fn.output;
+        assert_eq!(
+            quote! { #output }.to_string(),
+            quote! { -> &'__builder_fn_0 T }.to_string()
+        );
+    }
+
+    #[test]
+    fn test_unsupported_functions() {
+        for (function, error) in [
+            (
+                parse_quote! { fn f(&self) {} },
+                "#[builder_fn] does not support methods",
+            ),
+            (
+                parse_quote! { const fn f(a: u8) {} },
+                "#[builder_fn] does not support const functions",
+            ),
+            (
+                parse_quote! { fn f((a, b): (u8, u8)) {} },
+                "#[builder_fn] parameters must be bound to a name",
+            ),
+            (
+                parse_quote! { fn f(a: impl Into<String>) {} },
+                "#[builder_fn] does not support `impl Trait` parameters",
+            ),
+        ] {
+            let message = parse(quote! {}, function).err().unwrap().to_string();
+            assert!(message.contains(error), "{message}");
+        }
+    }
+}
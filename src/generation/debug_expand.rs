@@ -0,0 +1,270 @@
+//! Showing the Generated Code with `debug_expand`
+//!
+//! `#[builder(debug_expand)]` reports everything the derive generates for a
+//! struct in a compiler warning, so the builder states and their methods can be
+//! inspected without installing `cargo expand`.
+//!
+//! The warning uses the same deprecated-constant mechanism as the builder
+//! lints, and is shown regardless of the `lints` setting. The code is laid out
+//! one item and statement per line; it is meant for reading, not for pasting
+//! back into a source file.
+
+use crate::analysis::StructAnalysis;
+use crate::generation::lints::{generate_warning, Lint};
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+
+/// Keywords that keep a space before a following parenthesis or path, as in
+/// `let (a, b)` or `impl ::core::fmt::Debug`.
+const KEYWORDS: &[&str] = &[
+    "as", "dyn", "else", "for", "if", "impl", "in", "let", "match", "move", "mut", "return",
+    "where",
+];
+
+/// Generates the warning that shows the generated code for a struct.
+///
+/// # Arguments
+///
+/// * `analysis` - Complete struct analysis
+/// * `tokens` - Everything generated for the struct
+///
+/// # Returns
+///
+/// A `TokenStream` containing the warning item.
+pub fn generate_expansion_warning(analysis: &StructAnalysis, tokens: &TokenStream) -> TokenStream {
+    let struct_name = analysis.struct_name();
+    generate_warning(&Lint {
+        name: "debug_expand",
+        span: struct_name.span(),
+        message: format!(
+            "generated code for `{struct_name}`:\n\n{}\n",
+            format_tokens(tokens)
+        ),
+        help: "remove `debug_expand` from the `#[builder(...)]` attribute when done".to_string(),
+    })
+}
+
+/// Lays out a token stream with one item or statement per line.
+///
+/// # Arguments
+///
+/// * `tokens` - The tokens to lay out
+///
+/// # Returns
+///
+/// The tokens as indented source text.
+pub fn format_tokens(tokens: &TokenStream) -> String {
+    let mut formatter = Formatter::default();
+    formatter.write_stream(tokens.clone(), 0, true);
+    formatter.output.trim_end().to_string()
+}
+
+/// State of the token layout.
+#[derive(Default)]
+struct Formatter {
+    /// The text written so far
+    output: String,
+    /// Whether the next token starts a new line
+    at_line_start: bool,
+    /// Whether the next token is separated from the previous one by a space
+    space_before_next: bool,
+    /// Whether a closing brace was just written, which ends the line unless a
+    /// separator or method call follows
+    after_brace: bool,
+    /// The previous token, if it was an identifier
+    previous_ident: Option<String>,
+    /// Whether the previous token was the `#` of an attribute
+    previous_hash: bool,
+    /// Whether the previous token was the first `:` of a `::` path separator
+    previous_path_colon: bool,
+    /// The previous token, if it was a punctuation character
+    previous_punct: Option<(char, Spacing)>,
+    /// Whether the previous token closed a generic argument list
+    previous_closing_angle: bool,
+    /// The generic argument lists open in the current group, `true` for the
+    /// parameters of an `impl`, which are followed by a space
+    open_angles: Vec<bool>,
+}
+
+impl Formatter {
+    fn write_stream(&mut self, stream: TokenStream, indent: usize, block: bool) {
+        for token in stream {
+            self.write_token(token, indent, block);
+        }
+    }
+
+    fn write_token(&mut self, token: TokenTree, indent: usize, block: bool) {
+        if self.after_brace {
+            self.after_brace = false;
+            let continues_line = match &token {
+                TokenTree::Punct(punct) => matches!(punct.as_char(), ',' | ';' | '.' | '?'),
+                TokenTree::Ident(ident) => ident == "else",
+                _ => false,
+            };
+            if !continues_line {
+                self.newline();
+            }
+        }
+
+        let previous_ident = self.previous_ident.take();
+        let previous_hash = std::mem::take(&mut self.previous_hash);
+        let previous_punct = self.previous_punct.take();
+        let previous_closing_angle = std::mem::take(&mut self.previous_closing_angle);
+        // Paths and groups directly follow identifiers and generic arguments
+        let attached = match &previous_ident {
+            Some(ident) => !KEYWORDS.contains(&ident.as_str()),
+            None => previous_closing_angle,
+        };
+        match token {
+            TokenTree::Ident(ident) => {
+                let text = ident.to_string();
+                self.write(&text, indent, true);
+                self.previous_ident = Some(text);
+            }
+            TokenTree::Literal(literal) => {
+                self.write(&literal.to_string(), indent, true);
+            }
+            TokenTree::Punct(punct) => {
+                let ch = punct.as_char();
+                let joint = punct.spacing() == Spacing::Joint;
+                let after_path_colon = std::mem::take(&mut self.previous_path_colon);
+                // `->` and `=>` are arrows, not generic argument lists
+                let is_arrow =
+                    ch == '>' && matches!(previous_punct, Some(('-' | '=', Spacing::Joint)));
+                let space_before = match ch {
+                    // `a::b` and `T: Clone`, but `= ::core::option::Option`
+                    ':' => joint && !after_path_colon && !attached,
+                    '!' => !attached,
+                    ',' | ';' | '.' | '?' | '<' => false,
+                    '>' => is_arrow,
+                    _ => true,
+                };
+                self.write(&ch.to_string(), indent, space_before);
+                self.space_before_next =
+                    !joint && !after_path_colon && !matches!(ch, '.' | '&' | '<' | '#' | '!');
+                match ch {
+                    ':' if joint => self.previous_path_colon = true,
+                    '<' => self
+                        .open_angles
+                        .push(previous_ident.as_deref() == Some("impl")),
+                    '>' if !is_arrow => {
+                        let impl_parameters = self.open_angles.pop().unwrap_or(false);
+                        self.previous_closing_angle = !impl_parameters;
+                    }
+                    ';' | ',' if block && self.open_angles.is_empty() => self.newline(),
+                    '#' => self.previous_hash = true,
+                    _ => {}
+                }
+                self.previous_punct = Some((ch, punct.spacing()));
+            }
+            TokenTree::Group(group) => match group.delimiter() {
+                Delimiter::Brace => {
+                    self.write("{", indent, true);
+                    if !group.stream().is_empty() {
+                        self.newline();
+                        self.write_group(group.stream(), indent + 1, true);
+                        self.newline();
+                    }
+                    self.write("}", indent, false);
+                    self.space_before_next = true;
+                    self.after_brace = true;
+                }
+                Delimiter::Parenthesis | Delimiter::Bracket => {
+                    let (open, close) = if group.delimiter() == Delimiter::Parenthesis {
+                        ("(", ")")
+                    } else {
+                        ("[", "]")
+                    };
+                    self.write(open, indent, !attached && !previous_hash);
+                    self.space_before_next = false;
+                    self.write_group(group.stream(), indent, false);
+                    self.write(close, indent, false);
+                    self.space_before_next = true;
+                    if previous_hash && group.delimiter() == Delimiter::Bracket {
+                        // Attributes go on their own line
+                        self.newline();
+                    }
+                }
+                Delimiter::None => self.write_stream(group.stream(), indent, block),
+            },
+        }
+    }
+
+    fn write_group(&mut self, stream: TokenStream, indent: usize, block: bool) {
+        let open_angles = std::mem::take(&mut self.open_angles);
+        self.write_stream(stream, indent, block);
+        self.open_angles = open_angles;
+    }
+
+    fn write(&mut self, text: &str, indent: usize, space_before: bool) {
+        if self.at_line_start {
+            self.output.push_str(&"    ".repeat(indent));
+            self.at_line_start = false;
+        } else if space_before && self.space_before_next && !self.output.is_empty() {
+            self.output.push(' ');
+        }
+        self.output.push_str(text);
+        self.space_before_next = true;
+    }
+
+    fn newline(&mut self) {
+        if !self.at_line_start {
+            self.output.push('\n');
+            self.at_line_start = true;
+        }
+        self.space_before_next = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    #[test]
+    fn test_generate_expansion_warning() {
+        let input = syn::parse_quote! {
+            #[builder(debug_expand)]
+            struct Config {
+                #[builder(required)]
+                name: String,
+            }
+        };
+        let analysis = crate::analysis::analyze_struct(&input).unwrap();
+        let generated = quote! { pub struct ConfigBuilder_MissingName {} };
+        let warning = generate_expansion_warning(&analysis, &generated).to_string();
+
+        assert!(warning.contains("deprecated"));
+        assert!(warning.contains("const debug_expand"));
+        assert!(warning.contains("generated code for `Config`"));
+        assert!(warning.contains("pub struct ConfigBuilder_MissingName {}"));
+    }
+
+    #[test]
+    fn test_format_tokens() {
+        let tokens = quote! {
+            #[doc = "Builder"]
+            pub struct UserBuilder<T: Clone> { name: Option<String>, value: T, }
+            impl<T: Clone> UserBuilder<T> {
+                pub fn name(mut self, value: String) -> Self {
+                    self.name = ::core::option::Option::Some(value);
+                    self
+                }
+            }
+        };
+
+        assert_eq!(
+            format_tokens(&tokens),
+            "#[doc = \"Builder\"]\n\
+             pub struct UserBuilder<T: Clone> {\n    \
+                 name: Option<String>,\n    \
+                 value: T,\n\
+             }\n\
+             impl<T: Clone> UserBuilder<T> {\n    \
+                 pub fn name(mut self, value: String) -> Self {\n        \
+                     self.name = ::core::option::Option::Some(value);\n        \
+                     self\n    \
+                 }\n\
+             }"
+        );
+    }
+}
@@ -0,0 +1,593 @@
+//! Runtime-Checked Builders Next to the Type-State Builder
+//!
+//! `#[builder(also_dynamic)]` generates `{Struct}DynBuilder` in addition to the
+//! type-state builder. It keeps every settable field in an `Option` of the
+//! setter's input type, so it has one type whatever is set, and code driven by
+//! data, such as a plugin reading field values from a manifest, can keep it in
+//! a variable and set fields in any order.
+//!
+//! Its build method reports an unset required field as an error, then replays
+//! the stored values through the regular builder, so converters, defaults and
+//! checks behave exactly as they do for statically built values. It is
+//! `#[track_caller]`, and the error records the location it was called from,
+//! so a log points at the code that forgot a field.
+//!
+//! `into_builder` replays the stored values the same way and returns the
+//! regular builder in the state with every required field set. With serde
+//! derives, the dynamic builder derives them too and leaves out unset fields,
+//! so a draft restores into one type whatever it has set.
+//!
+//! `reset` unsets every field in place. Setters that copy their argument into
+//! a `String` or `Vec` keep the emptied buffer, so a builder reused for many
+//! values does not reallocate them.
+//!
+//! # Generated Code Structure
+//!
+//! ```rust,ignore
+//! pub struct ConfigDynBuilder {
+//!     host: Option<String>,
+//!     port: Option<u16>,
+//! }
+//!
+//! impl ConfigDynBuilder {
+//!     pub fn new() -> Self { /* every field unset */ }
+//!     pub fn reset(&mut self) { /* every field unset, buffers kept */ }
+//!     pub fn host(mut self, value: String) -> Self { /* stores Some(value) */ }
+//!     pub fn port(mut self, value: u16) -> Self { /* stores Some(value) */ }
+//!     #[track_caller]
+//!     pub fn build(self) -> Result<Config, ConfigDynBuildError> {
+//!         let __dyn_build_location = ::core::panic::Location::caller();
+//!         let __host = self.host.ok_or(/* missing `host` */)?;
+//!         let mut builder = <Config>::builder();
+//!         if let Some(value) = self.port {
+//!             builder = builder.port(value);
+//!         }
+//!         let builder = builder.host(__host);
+//!         Ok(builder.build())
+//!     }
+//!     #[track_caller]
+//!     pub fn into_builder(self) -> Result<ConfigBuilder_HasHost, ConfigDynBuildError> {
+//!         /* the same replay, returning Ok(builder) */
+//!     }
+//! }
+//!
+//! impl Config {
+//!     pub fn dyn_builder() -> ConfigDynBuilder { ConfigDynBuilder::new() }
+//! }
+//! ```
+
+use crate::analysis::{FieldInfo, StructAnalysis};
+use crate::generation::type_state_builder::complete_state_name;
+use crate::generation::TokenGenerator;
+use crate::utils::field_utils::{resolve_effective_impl_into, SetterParameterConfig};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// The error message of a required field that was never set.
+const MISSING_MESSAGE: &str = "required field is not set";
+
+/// The error message of a `TryFrom` setter rejecting its value.
+const CONVERSION_MESSAGE: &str = "value cannot be converted";
+
+/// The local holding the build method's caller location.
+const LOCATION_LOCAL: &str = "__dyn_build_location";
+
+/// Generates the dynamic builder, its error type and the `dyn_builder` method.
+///
+/// # Arguments
+///
+/// * `analysis` - Complete struct analysis
+///
+/// # Returns
+///
+/// A `syn::Result<TokenStream>` containing the generated items.
+pub fn generate_dynamic_builder(analysis: &StructAnalysis) -> syn::Result<TokenStream> {
+    let token_generator = TokenGenerator::new(analysis);
+    let struct_name = analysis.struct_name();
+    let struct_attributes = analysis.struct_attributes();
+    let visibility = analysis.struct_visibility();
+    let impl_generics = token_generator.impl_generics_tokens();
+    let type_generics = token_generator.type_generics_tokens();
+    let where_clause = token_generator.where_clause_tokens();
+    let setter_prefix = struct_attributes.get_setter_prefix();
+    let builder_ident = format_ident!("{}DynBuilder", struct_name);
+    let error_ident = format_ident!("{}DynBuildError", struct_name);
+    let documented = token_generator.config().include_documentation;
+    let doc = |text: String| {
+        if documented {
+            quote! { #[doc = #text] }
+        } else {
+            token_generator.undocumented_item_tokens()
+        }
+    };
+
+    let settable_fields: Vec<&FieldInfo> = analysis
+        .all_fields()
+        .filter(|field| field.should_generate_setter())
+        .collect();
+
+    // Each field stores its setter's input until the build method replays it.
+    // The setters take what the type-state setters take, converted on the way in
+    let struct_impl_into = struct_attributes.get_impl_into();
+    let struct_into_iter = struct_attributes.get_into_iter();
+    let struct_option_into = struct_attributes.get_option_into();
+    // With serde derives, unset fields are left out and the buffers, which
+    // hold no value, are not stored
+    let serde_skip = token_generator.generate_serde_skip_attribute();
+    let serde_unset = if serde_skip.is_empty() {
+        quote! {}
+    } else {
+        quote! { #[serde(default, skip_serializing_if = "::core::option::Option::is_none")] }
+    };
+    let mut storage = Vec::new();
+    let mut initializers = Vec::new();
+    let mut setters = Vec::new();
+    let mut resets = Vec::new();
+    for field in &settable_fields {
+        let field_name = field.name();
+        let input_type = field.setter_input_type();
+        let cfg = field.setter_cfg_tokens();
+        let setter_ident: syn::Ident = syn::parse_str(&field.final_setter_name(setter_prefix))?;
+        let use_impl_into = if field.is_required() {
+            !field.attributes().transparent
+                && resolve_effective_impl_into(field.attributes().impl_into, struct_impl_into)
+        } else {
+            resolve_effective_impl_into(
+                field.attributes().impl_into,
+                struct_impl_into || field.uses_option_into(struct_option_into),
+            )
+        };
+        let SetterParameterConfig {
+            param_type,
+            field_assignment_expr,
+        } = field.stored_input_parameter_config(use_impl_into, struct_into_iter);
+        storage.push(quote! {
+            #cfg
+            #serde_unset
+            #field_name: ::core::option::Option<#input_type>,
+        });
+        initializers.push(quote! {
+            #cfg
+            #field_name: ::core::option::Option::None,
+        });
+        let setter_doc = doc(format!(
+            "Sets the `{}` field, replacing any value set before.",
+            field.clean_name()
+        ));
+
+        // Setters copying into a `String` or `Vec` fill a buffer, which `reset`
+        // keeps along with its capacity for the next value
+        let Some(fill) = buffer_fill(field, struct_into_iter) else {
+            setters.push(quote! {
+                #setter_doc
+                #cfg
+                pub fn #setter_ident(mut self, value: #param_type) -> Self {
+                    self.#field_name = ::core::option::Option::Some(#field_assignment_expr);
+                    self
+                }
+            });
+            resets.push(quote! {
+                #cfg
+                {
+                    self.#field_name = ::core::option::Option::None;
+                }
+            });
+            continue;
+        };
+        let buffer_ident = format_ident!("__{}_buffer", field.clean_name());
+        storage.push(quote! {
+            #cfg
+            #serde_skip
+            #buffer_ident: #input_type,
+        });
+        initializers.push(quote! {
+            #cfg
+            #buffer_ident: ::core::default::Default::default(),
+        });
+        setters.push(quote! {
+            #setter_doc
+            #cfg
+            pub fn #setter_ident(mut self, value: #param_type) -> Self {
+                let mut buffer = match self.#field_name.take() {
+                    ::core::option::Option::Some(buffer) => buffer,
+                    ::core::option::Option::None => ::core::mem::take(&mut self.#buffer_ident),
+                };
+                buffer.clear();
+                #fill
+                self.#field_name = ::core::option::Option::Some(buffer);
+                self
+            }
+        });
+        resets.push(quote! {
+            #cfg
+            if let ::core::option::Option::Some(mut buffer) = self.#field_name.take() {
+                buffer.clear();
+                self.#buffer_ident = buffer;
+            }
+        });
+    }
+
+    // Generic structs are tracked through the struct type itself, since not
+    // every field has a setter to store
+    let (marker_field, marker_init) = if analysis.struct_generics().params.is_empty() {
+        (quote! {}, quote! {})
+    } else {
+        let marker_ident = format_ident!("{}", token_generator.get_phantom_data_field_name());
+        (
+            quote! {
+                #serde_skip
+                #marker_ident: ::core::marker::PhantomData<fn() -> #struct_name #type_generics>,
+            },
+            quote! { #marker_ident: ::core::marker::PhantomData, },
+        )
+    };
+
+    let (build_body, into_builder_body) =
+        generate_build_bodies(analysis, &settable_fields, &error_ident)?;
+    let built_type = token_generator.built_type();
+    let complete_state = complete_state_type(analysis, &token_generator)?;
+    let default_bounds = token_generator.default_field_bounds();
+    let build_where_clause = if default_bounds.is_empty() {
+        quote! {}
+    } else {
+        quote! { where #(#default_bounds),* }
+    };
+
+    let builder_doc = doc(format!(
+        "A builder for [`{struct_name}`] that checks for unset required fields when \
+         building instead of at compile time.\n\n\
+         It has one type whatever is set, so fields can be set in any order from \
+         runtime data. Create it with [`{struct_name}::dyn_builder`]."
+    ));
+    let new_doc = doc("Creates a builder with every field unset.".to_string());
+    let reset_doc = doc(
+        "Unsets every field, returning the builder to the state `new()` creates.\n\n\
+         Fields whose setter copies its argument, `into_iter` setters of `Vec` \
+         fields and `clone_from_ref` setters of `String` and `Vec` fields, keep \
+         their emptied buffer, so setting them again reuses its capacity."
+            .to_string(),
+    );
+    let build_doc = doc(format!(
+        "Builds the `{struct_name}` through its regular builder.\n\n\
+         # Errors\n\n\
+         Returns an error naming the field if a required field is not set or \
+         a value is rejected, along with the location this method was called from."
+    ));
+    let into_builder_doc = doc(
+        "Passes the set fields to the regular builder and returns it with every \
+         required field set, so the remaining fields can be set and the struct \
+         built as usual.\n\n\
+         # Errors\n\n\
+         Returns an error naming the field if a required field is not set or a \
+         setter rejects its value, along with the location this method was called from."
+            .to_string(),
+    );
+    let entry_doc = doc(format!(
+        "Creates a [`{builder_ident}`], which checks for unset required fields when building."
+    ));
+    let error_doc = doc(format!(
+        "The error returned when a [`{builder_ident}`] cannot build a `{struct_name}`."
+    ));
+    let error_impl = token_generator.generate_error_trait_impl(&error_ident);
+    let serde_derive = token_generator.generate_serde_derive_attribute();
+    let (error_field_doc, error_message_doc, error_location_doc) = if documented {
+        (
+            quote! { #[doc = "The name of the field that is unset or whose value was rejected."] },
+            quote! { #[doc = "Why the struct could not be built."] },
+            quote! { #[doc = "Where the build method was called."] },
+        )
+    } else {
+        let undocumented = token_generator.undocumented_item_tokens();
+        (undocumented.clone(), undocumented.clone(), undocumented)
+    };
+
+    Ok(quote! {
+        #error_doc
+        #[derive(
+            ::core::fmt::Debug,
+            ::core::clone::Clone,
+            ::core::marker::Copy,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq
+        )]
+        #visibility struct #error_ident {
+            #error_field_doc
+            pub field: &'static str,
+            #error_message_doc
+            pub message: &'static str,
+            #error_location_doc
+            pub location: &'static ::core::panic::Location<'static>,
+        }
+
+        #[automatically_derived]
+        impl ::core::fmt::Display for #error_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::write!(f, "field `{}`: {} (at {})", self.field, self.message, self.location)
+            }
+        }
+
+        #error_impl
+
+        #builder_doc
+        #serde_derive
+        #visibility struct #builder_ident #impl_generics #where_clause {
+            #(#storage)*
+            #marker_field
+        }
+
+        impl #impl_generics #builder_ident #type_generics #where_clause {
+            #new_doc
+            pub fn new() -> Self {
+                Self {
+                    #(#initializers)*
+                    #marker_init
+                }
+            }
+
+            #reset_doc
+            pub fn reset(&mut self) {
+                #(#resets)*
+            }
+
+            #(#setters)*
+
+            #build_doc
+            #[track_caller]
+            pub fn build(self) -> ::core::result::Result<#built_type, #error_ident>
+            #build_where_clause
+            {
+                #build_body
+            }
+
+            #into_builder_doc
+            #[track_caller]
+            pub fn into_builder(self) -> ::core::result::Result<#complete_state, #error_ident>
+            #build_where_clause
+            {
+                #into_builder_body
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::default::Default for #builder_ident #type_generics #where_clause {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl #impl_generics #struct_name #type_generics #where_clause {
+            #entry_doc
+            #visibility fn dyn_builder() -> #builder_ident #type_generics {
+                #builder_ident::new()
+            }
+        }
+    })
+}
+
+/// Generates the statement copying a setter's argument into a reused buffer.
+///
+/// # Arguments
+///
+/// * `field` - The field whose setter is generated
+/// * `struct_into_iter` - Whether struct-level into_iter is enabled
+///
+/// # Returns
+///
+/// The statement filling the emptied `buffer` from `value`, or `None` if the
+/// setter takes ownership of its argument, so there is no buffer to reuse.
+fn buffer_fill(field: &FieldInfo, struct_into_iter: bool) -> Option<TokenStream> {
+    use crate::utils::field_utils::{extract_vec_inner_type, is_string_type};
+
+    if field.try_from_input().is_some() || field.attributes().converter.is_some() {
+        return None;
+    }
+    let field_type = field.field_type();
+    if field.attributes().clone_from_ref {
+        if is_string_type(field_type) {
+            return Some(quote! { buffer.push_str(value); });
+        }
+        return extract_vec_inner_type(field_type)
+            .map(|_| quote! { buffer.extend_from_slice(value); });
+    }
+    field.uses_into_iter(struct_into_iter).then(|| {
+        quote! {
+            ::core::iter::Extend::extend(
+                &mut buffer,
+                ::core::iter::Iterator::map(
+                    ::core::iter::IntoIterator::into_iter(value),
+                    ::core::convert::Into::into,
+                ),
+            );
+        }
+    })
+}
+
+/// Generates the bodies of the dynamic builder's build and `into_builder` methods.
+///
+/// Unset required fields are reported first, in declaration order. The stored
+/// values are then passed to the regular builder's setters: optional fields
+/// only when set, required fields in order, as [`super::arbitrary`] does.
+/// The build method then builds the struct, and `into_builder` returns the
+/// regular builder.
+fn generate_build_bodies(
+    analysis: &StructAnalysis,
+    settable_fields: &[&FieldInfo],
+    error_ident: &syn::Ident,
+) -> syn::Result<(TokenStream, TokenStream)> {
+    let struct_name = analysis.struct_name();
+    let type_generics = TokenGenerator::new(analysis).type_generics_tokens();
+    let struct_attributes = analysis.struct_attributes();
+    let setter_prefix = struct_attributes.get_setter_prefix();
+    let setter_ident = |field: &FieldInfo| -> syn::Result<syn::Ident> {
+        syn::parse_str(&field.final_setter_name(setter_prefix))
+    };
+    let local = |field: &FieldInfo| {
+        let local_ident = format_ident!("__{}", field.clean_name());
+        quote! { #local_ident }
+    };
+    // Setters taking a reference receive one to the stored value
+    let argument = |field: &FieldInfo, value: TokenStream| {
+        if field.attributes().clone_from_ref {
+            quote! { &#value }
+        } else {
+            value
+        }
+    };
+    // Every error carries the caller location taken when the build starts
+    let location = format_ident!("{}", LOCATION_LOCAL);
+    let error = |field: TokenStream, message: TokenStream| {
+        quote! { #error_ident { field: #field, message: #message, location: #location } }
+    };
+    // Fallible setters report the field with the check's message
+    let checked = |field: &FieldInfo| {
+        let name = field.clean_name();
+        if field.try_from_input().is_some() {
+            let error = error(quote! { #name }, quote! { #CONVERSION_MESSAGE });
+            quote! { .map_err(|_| #error)? }
+        } else if field.attributes().validate_on_set {
+            let error = error(quote! { error.field }, quote! { error.message });
+            quote! { .map_err(|error| #error)? }
+        } else {
+            quote! {}
+        }
+    };
+
+    let mut unwraps = Vec::new();
+    for field in analysis.required_fields() {
+        let field_name = field.name();
+        let local = local(field);
+        let name = field.clean_name();
+        let error = error(quote! { #name }, quote! { #MISSING_MESSAGE });
+        unwraps.push(quote! {
+            let #local = self.#field_name.ok_or(#error)?;
+        });
+    }
+
+    let start = match analysis.builder_method_field() {
+        Some(field) => {
+            let entry_ident = setter_ident(field)?;
+            let value = argument(field, local(field));
+            let checked = checked(field);
+            quote! { <#struct_name #type_generics>::#entry_ident(#value)#checked }
+        }
+        None => quote! { <#struct_name #type_generics>::builder() },
+    };
+
+    let mut optional_setters = Vec::new();
+    for field in settable_fields.iter().filter(|field| !field.is_required()) {
+        let field_name = field.name();
+        let setter = setter_ident(field)?;
+        let value = argument(field, quote! { value });
+        let cfg = field.setter_cfg_tokens();
+        let checked = checked(field);
+        optional_setters.push(quote! {
+            #cfg
+            if let ::core::option::Option::Some(value) = self.#field_name {
+                builder = builder.#setter(#value)#checked;
+            }
+        });
+    }
+
+    let mut required_setters = Vec::new();
+    for field in analysis.required_fields() {
+        if field.attributes().builder_method {
+            continue;
+        }
+        let setter = setter_ident(field)?;
+        let value = argument(field, local(field));
+        let checked = checked(field);
+        required_setters.push(quote! { let builder = builder.#setter(#value)#checked; });
+    }
+
+    let build_ident: syn::Ident = syn::parse_str(struct_attributes.get_build_method_name())?;
+    let finish = if analysis.has_validated_fields() {
+        let error = error(quote! { error.field }, quote! { error.message });
+        quote! { builder.#build_ident().map_err(|error| #error) }
+    } else {
+        quote! { ::core::result::Result::Ok(builder.#build_ident()) }
+    };
+
+    let replay = quote! {
+        #[allow(unused_variables)]
+        let #location = ::core::panic::Location::caller();
+        #(#unwraps)*
+        #[allow(unused_mut)]
+        let mut builder = #start;
+        #(#optional_setters)*
+        #(#required_setters)*
+    };
+    Ok((
+        quote! { #replay #finish },
+        quote! { #replay ::core::result::Result::Ok(builder) },
+    ))
+}
+
+/// Gets the type of the regular builder's state with every required field set.
+///
+/// The state is named as the state aliases name it, through the module given
+/// with `#[builder(module = "...")]`, which the dynamic builder sits outside of.
+fn complete_state_type(
+    analysis: &StructAnalysis,
+    token_generator: &TokenGenerator,
+) -> syn::Result<TokenStream> {
+    let struct_name = analysis.struct_name();
+    let type_generics = token_generator.type_generics_tokens();
+    let module = analysis
+        .struct_attributes()
+        .get_module()
+        .map(|module| quote! { #module:: });
+    // A regular builder has a single state, which `hide_states` leaves in place
+    if analysis.struct_attributes().get_hide_states() && !analysis.has_only_optional_fields() {
+        let states_trait = token_generator.hidden_states_trait_ident()?;
+        Ok(quote! { <#struct_name #type_generics as #module #states_trait>::Ready })
+    } else {
+        let ready_ident: syn::Ident = syn::parse_str(&complete_state_name(analysis))?;
+        Ok(quote! { #module #ready_ident #type_generics })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::analyze_struct;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_dynamic_builder() {
+        let input = parse_quote! {
+            pub struct Config {
+                #[builder(required)]
+                host: String,
+                #[builder(default = 80)]
+                port: u16,
+                #[builder(skip_setter, default = 1)]
+                version: u8,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let code = generate_dynamic_builder(&analysis).unwrap().to_string();
+
+        assert!(code.contains("pub struct ConfigDynBuilder"));
+        assert!(code.contains("host : :: core :: option :: Option < String > ,"));
+        assert!(code.contains("pub fn port (mut self , value : u16) -> Self"));
+        assert!(code.contains("pub fn reset (& mut self)"));
+        assert!(code.contains("self . port = :: core :: option :: Option :: None ;"));
+        assert!(code.contains("# [track_caller] pub fn build (self)"));
+        assert!(
+            code.contains("let __dyn_build_location = :: core :: panic :: Location :: caller ()")
+        );
+        assert!(code.contains(
+            "let __host = self . host . ok_or (ConfigDynBuildError { field : \"host\" , \
+             message : \"required field is not set\" , location : __dyn_build_location }) ?"
+        ));
+        assert!(code.contains("let mut builder = < Config > :: builder ()"));
+        assert!(code.contains("builder = builder . port (value)"));
+        assert!(code.contains("let builder = builder . host (__host)"));
+        assert!(code.contains(
+            "pub fn into_builder (self) -> :: core :: result :: Result < ConfigBuilder_HasHost , ConfigDynBuildError >"
+        ));
+        assert!(code.contains("pub fn dyn_builder () -> ConfigDynBuilder"));
+        assert!(!code.contains("version"));
+        assert!(!code.contains("serde"));
+    }
+}
@@ -0,0 +1,208 @@
+//! Enum Builder Generation
+//!
+//! This module implements builders for enums, which are built in two phases:
+//! first the variant is selected, then that variant's fields are set as for
+//! a struct:
+//!
+//! ```rust,ignore
+//! let message = Message::builder().data().payload(bytes).build();
+//! ```
+//!
+//! # Generated Code Structure
+//!
+//! For an enum `Message`, this generates:
+//! - `MessageBuilder` - The variant selection builder returned by `Message::builder()`,
+//!   with one method per variant named after it in snake case
+//! - One builder per variant, named after the enum and the variant (e.g.
+//!   `MessageDataBuilder_MissingPayload`), generated like the builder of a
+//!   struct with the variant's fields: a type-state builder if the variant has
+//!   required fields, otherwise a regular builder
+//!
+//! Every variant builder's build method returns the enum, so the variant
+//! must be selected before any field can be set, and its required fields must
+//! be set before it can be built.
+
+use crate::analysis::StructAnalysis;
+use crate::generation::{
+    debug_expand, generate_regular_builder, generate_type_state_builder, lints, TokenGenerator,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::GenericParam;
+
+/// Generates the variant selection builder and the builders of all variants.
+///
+/// # Arguments
+///
+/// * `variants` - The analysis of each variant, as returned by `analyze_enum`
+///
+/// # Returns
+///
+/// A `syn::Result<TokenStream>` containing all generated items.
+///
+/// # Errors
+///
+/// Returns errors for variants whose configuration fails validation.
+pub fn generate_enum_builder(variants: &[StructAnalysis]) -> syn::Result<TokenStream> {
+    let Some(first) = variants.first() else {
+        return Ok(TokenStream::new());
+    };
+
+    let mut tokens = generate_variant_selection_builder(first)?;
+    for variant in variants {
+        variant.validate_for_generation()?;
+        tokens.extend(lints::generate_lints(variant)?);
+        tokens.extend(if variant.has_only_optional_fields() {
+            generate_regular_builder(variant)?
+        } else {
+            generate_type_state_builder(variant)?
+        });
+    }
+
+    // The variants share the enum's generics, and with them the helper traits
+    let bound_helpers = TokenGenerator::new(first).generate_bound_helper_traits();
+    let tokens = quote! { #bound_helpers #tokens };
+
+    if first.struct_attributes().get_debug_expand() {
+        let expansion = debug_expand::generate_expansion_warning(first, &tokens);
+        return Ok(quote! { #tokens #expansion });
+    }
+    Ok(tokens)
+}
+
+/// Generates the `{Enum}Builder` type and the enum's `builder()` method.
+///
+/// The selection builder holds no values, only the enum's generics; its
+/// variant methods are generated with each variant's builder.
+///
+/// # Arguments
+///
+/// * `analysis` - The analysis of any variant of the enum
+///
+/// # Returns
+///
+/// A `syn::Result<TokenStream>` containing the type, its `Debug` impl, the
+/// impls requested with `derive(...)` and the impl block with `builder()`.
+fn generate_variant_selection_builder(analysis: &StructAnalysis) -> syn::Result<TokenStream> {
+    let token_generator = TokenGenerator::new(analysis);
+    let enum_name = analysis.struct_name();
+    let selection_ident = syn::Ident::new(
+        &format!("{enum_name}Builder"),
+        proc_macro2::Span::call_site(),
+    );
+    let impl_generics = token_generator.impl_generics_tokens();
+    let type_generics = token_generator.type_generics_tokens();
+    let where_clause = token_generator.where_clause_tokens();
+    let const_kw = token_generator.const_keyword();
+    let visibility = analysis.builder_visibility();
+    let entry_visibility = analysis.entry_visibility();
+    let non_exhaustive = token_generator.generate_non_exhaustive_attribute();
+    let debug_name = selection_ident.to_string();
+    let struct_attributes = analysis.struct_attributes();
+
+    // Holding nothing but the marker, the selection builder can always be duplicated
+    let mut derive_impls = TokenStream::new();
+    if struct_attributes.get_derive_clone() {
+        derive_impls.extend(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::clone::Clone for #selection_ident #type_generics #where_clause {
+                fn clone(&self) -> Self {
+                    #selection_ident {
+                        _marker: ::core::marker::PhantomData,
+                    }
+                }
+            }
+        });
+    }
+    if struct_attributes.get_derive_copy() {
+        derive_impls.extend(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::marker::Copy for #selection_ident #type_generics #where_clause {}
+        });
+    }
+
+    // Nothing is stored, so every parameter is only tracked by the marker
+    let phantom_types: Vec<TokenStream> = analysis
+        .struct_generics()
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(type_param) => {
+                let ident = &type_param.ident;
+                Some(quote! { #ident })
+            }
+            GenericParam::Lifetime(lifetime_param) => {
+                let lifetime = &lifetime_param.lifetime;
+                Some(quote! { &#lifetime () })
+            }
+            GenericParam::Const(_) => None,
+        })
+        .collect();
+
+    let type_doc = token_generator.generate_method_documentation(
+        &selection_ident.to_string(),
+        &format!("Selects the variant of `{enum_name}` to build"),
+        Some(&format!(
+            "Created with `{enum_name}::builder()`. Each method starts the builder of one variant."
+        )),
+    );
+    let builder_doc = token_generator.generate_method_documentation(
+        "builder",
+        "Creates a new type-safe builder, starting with the choice of the variant",
+        Some("Select the variant first, then set its required fields before building."),
+    );
+
+    Ok(quote! {
+        #type_doc
+        #non_exhaustive
+        #visibility struct #selection_ident #impl_generics #where_clause {
+            _marker: ::core::marker::PhantomData<(#(#phantom_types,)*)>,
+        }
+
+        impl #impl_generics ::core::fmt::Debug for #selection_ident #type_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(#debug_name)
+            }
+        }
+
+        #derive_impls
+
+        impl #impl_generics #enum_name #type_generics #where_clause {
+            #builder_doc
+            #entry_visibility #const_kw fn builder() -> #selection_ident #type_generics {
+                #selection_ident {
+                    _marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::analyze_enum;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_enum_builder() {
+        let input = parse_quote! {
+            enum Message {
+                Ping,
+                DataFrame {
+                    #[builder(required)]
+                    payload: Vec<u8>,
+                    flags: u8,
+                },
+            }
+        };
+        let variants = analyze_enum(&input).unwrap();
+        let code = generate_enum_builder(&variants).unwrap().to_string();
+
+        assert!(code.contains("struct MessageBuilder"));
+        assert!(code.contains("pub fn builder () -> MessageBuilder"));
+        assert!(code.contains("pub fn ping (self) -> MessagePingBuilder"));
+        assert!(code.contains("pub fn data_frame (self) -> MessageDataFrameBuilder_MissingPayload"));
+        assert!(code.contains("Message :: DataFrame {"));
+    }
+}
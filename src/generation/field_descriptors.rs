@@ -0,0 +1,185 @@
+//! Runtime Field Descriptors
+//!
+//! `#[builder(field_descriptors = "path::FieldDescriptor")]` (with the
+//! `metadata` feature) generates a `FIELDS` constant on the struct describing
+//! every field that has a setter, so admin UIs and schema exports can be
+//! generated from the struct instead of maintained by hand next to it.
+//!
+//! Proc-macro crates cannot export types, so the descriptor struct is declared
+//! by the user. The constant fills its fields by name and expects:
+//!
+//! ```rust,ignore
+//! pub struct FieldDescriptor {
+//!     pub name: &'static str,
+//!     pub required: bool,
+//!     pub type_name: &'static str,
+//!     pub has_default: bool,
+//!     pub doc: &'static str,
+//! }
+//! ```
+//!
+//! # Generated Code Structure
+//!
+//! ```rust,ignore
+//! impl Config {
+//!     /// The fields of `Config` that have a setter, required fields first.
+//!     pub const FIELDS: &'static [crate::FieldDescriptor] = &[
+//!         crate::FieldDescriptor {
+//!             name: "host",
+//!             required: true,
+//!             type_name: "String",
+//!             has_default: false,
+//!             doc: "The host to connect to.",
+//!         },
+//!         // ...
+//!     ];
+//! }
+//! ```
+
+use crate::analysis::{FieldInfo, StructAnalysis};
+use crate::generation::TokenGenerator;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+
+/// Generates the `FIELDS` constant describing the fields of a struct.
+///
+/// Fields without a setter (`skip_setter` and `computed`) are left out, since
+/// nothing outside the struct can set them. Required fields come first, then
+/// optional fields, each in declaration order.
+///
+/// # Arguments
+///
+/// * `analysis` - Complete struct analysis
+/// * `descriptor` - The user's descriptor struct given with `field_descriptors`
+///
+/// # Returns
+///
+/// A `TokenStream` containing the impl block with the constant.
+pub fn generate_field_descriptors(
+    analysis: &StructAnalysis,
+    descriptor: &syn::Path,
+) -> TokenStream {
+    let token_generator = TokenGenerator::new(analysis);
+    let struct_name = analysis.struct_name();
+    let visibility = analysis.struct_visibility();
+    let impl_generics = token_generator.impl_generics_tokens();
+    let type_generics = token_generator.type_generics_tokens();
+    let where_clause = token_generator.where_clause_tokens();
+
+    let descriptors = analysis
+        .all_fields()
+        .filter(|field| field.should_generate_setter())
+        .map(|field| {
+            let name = field.clean_name();
+            let required = field.is_required();
+            let type_name = type_display_name(field.field_type());
+            let has_default =
+                field.has_custom_default() || field.attributes().default_from.is_some();
+            let doc = field_doc(field);
+            // Missing or mistyped descriptor fields are reported at the path
+            quote_spanned! {descriptor.span()=>
+                #descriptor {
+                    name: #name,
+                    required: #required,
+                    type_name: #type_name,
+                    has_default: #has_default,
+                    doc: #doc,
+                }
+            }
+        });
+
+    let doc = if token_generator.config().include_documentation {
+        let doc =
+            format!("The fields of `{struct_name}` that have a setter, required fields first.");
+        quote! { #[doc = #doc] }
+    } else {
+        token_generator.undocumented_item_tokens()
+    };
+
+    quote! {
+        impl #impl_generics #struct_name #type_generics #where_clause {
+            #doc
+            #visibility const FIELDS: &'static [#descriptor] = &[#(#descriptors),*];
+        }
+    }
+}
+
+/// Renders a type the way it is usually written, e.g. `Option<Vec<String>>`.
+///
+/// Token streams print with a space between every token, which is removed
+/// around punctuation that does not take spaces in source code.
+fn type_display_name(ty: &syn::Type) -> String {
+    let mut name = quote! { #ty }.to_string();
+    for (spaced, tight) in [
+        (" <", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        (" ,", ","),
+        (" ::", "::"),
+        (":: ", "::"),
+        ("& ", "&"),
+        ("( ", "("),
+        (" )", ")"),
+        ("[ ", "["),
+        (" ]", "]"),
+        (" ;", ";"),
+    ] {
+        name = name.replace(spaced, tight);
+    }
+    name
+}
+
+/// Joins a field's doc comment lines, without the space after `///`.
+fn field_doc(field: &FieldInfo) -> String {
+    field
+        .docs()
+        .iter()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::analyze_struct;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_field_descriptors() {
+        let input = parse_quote! {
+            pub struct Config<T> {
+                /// The host to connect to.
+                ///
+                /// Either a name or an address.
+                #[builder(required)]
+                host: String,
+                #[builder(default = 80)]
+                port: u16,
+                tags: Option<Vec<&'static str>>,
+                value: T,
+                #[builder(skip_setter, default = 1)]
+                version: u8,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let code = generate_field_descriptors(&analysis, &parse_quote!(crate::FieldDescriptor))
+            .to_string();
+
+        assert!(code.contains("impl < T > Config < T >"));
+        assert!(code.contains("pub const FIELDS : & 'static [crate :: FieldDescriptor]"));
+        assert!(code.contains(
+            "name : \"host\" , required : true , type_name : \"String\" , has_default : false , \
+             doc : \"The host to connect to.\\n\\nEither a name or an address.\""
+        ));
+        assert!(code.contains(
+            "name : \"port\" , required : false , type_name : \"u16\" , has_default : true"
+        ));
+        assert!(code.contains("type_name : \"Option<Vec<&'static str>>\""));
+        assert!(code.contains("type_name : \"T\""));
+        assert!(!code.contains("version"));
+    }
+}
@@ -0,0 +1,290 @@
+//! Builder Lints
+//!
+//! Lints flag builder configurations that compile but are likely mistakes,
+//! such as `impl_into` on a `u16` field. How they are reported is set with
+//! `#[builder(lints = "allow" | "warn" | "deny")]`, defaulting to `"warn"`.
+//!
+//! # Reporting Warnings
+//!
+//! Proc macros cannot emit warnings on stable Rust. Each warning is therefore
+//! a use of a `#[deprecated]` constant named after the lint, spanned at the
+//! offending field, so the compiler reports it at the right place:
+//!
+//! ```text
+//! warning: use of deprecated constant `impl_into_on_numeric`: field `port`: ...
+//! ```
+//!
+//! With `lints = "deny"` the same findings are compile errors instead.
+
+use crate::analysis::{FieldInfo, StructAnalysis};
+use crate::attributes::struct_attrs::LintLevel;
+use crate::utils::field_utils::{needs_typed_literal, resolve_effective_impl_into};
+use crate::validation::ErrorMessages;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+/// A finding reported by a builder lint.
+#[derive(Debug, Clone)]
+pub struct Lint {
+    /// Name of the lint, shown as the name of the deprecated constant
+    pub name: &'static str,
+    /// Where the finding is reported
+    pub span: Span,
+    /// What is wrong
+    pub message: String,
+    /// How to fix it
+    pub help: String,
+}
+
+/// Runs every builder lint over the analyzed struct.
+///
+/// # Arguments
+///
+/// * `analysis` - Complete struct analysis
+///
+/// # Returns
+///
+/// The findings in field declaration order.
+pub fn collect_lints(analysis: &StructAnalysis) -> Vec<Lint> {
+    analysis
+        .all_fields()
+        .flat_map(|field| {
+            [
+                impl_into_on_numeric(analysis, field),
+                redundant_converter(field),
+            ]
+        })
+        .flatten()
+        .collect()
+}
+
+/// Generates the tokens reporting the lint findings for a struct.
+///
+/// # Arguments
+///
+/// * `analysis` - Complete struct analysis
+///
+/// # Returns
+///
+/// A `syn::Result<TokenStream>` with one warning item per finding, nothing when
+/// lints are allowed, or an error combining every finding when they are denied.
+pub fn generate_lints(analysis: &StructAnalysis) -> syn::Result<TokenStream> {
+    let level = analysis.struct_attributes().get_lints();
+    if level == LintLevel::Allow {
+        return Ok(TokenStream::new());
+    }
+
+    let lints = collect_lints(analysis);
+    if level == LintLevel::Deny {
+        let mut errors = lints.iter().map(|lint| {
+            ErrorMessages::structured_error_span(
+                lint.span,
+                &lint.message,
+                Some(&format!(
+                    "denied by `#[builder(lints = \"deny\")]` ({})",
+                    lint.name
+                )),
+                Some(&lint.help),
+            )
+        });
+        return match errors.next() {
+            Some(mut error) => {
+                errors.for_each(|next| error.combine(next));
+                Err(error)
+            }
+            None => Ok(TokenStream::new()),
+        };
+    }
+
+    Ok(lints.iter().map(generate_warning).collect())
+}
+
+/// Generates a use of a deprecated constant that makes rustc warn at the lint's span.
+pub fn generate_warning(lint: &Lint) -> TokenStream {
+    // Spanning the constant's use at the field puts the warning there
+    let name = syn::Ident::new(lint.name, Span::call_site());
+    let usage = syn::Ident::new(lint.name, lint.span);
+    let note = format!("{}\nhelp: {}", lint.message, lint.help);
+
+    quote! {
+        const _: () = {
+            #[deprecated(note = #note)]
+            #[allow(non_upper_case_globals)]
+            const #name: () = ();
+            #usage
+        };
+    }
+}
+
+/// Flags converters that take the field type and return their argument.
+///
+/// A converter such as `|value: String| value` does what the plain setter
+/// already does, but adds a closure call to the generated code.
+fn redundant_converter(field: &FieldInfo) -> Option<Lint> {
+    let syn::Expr::Closure(closure) = field.attributes().converter.as_ref()? else {
+        return None;
+    };
+    let [syn::Pat::Type(parameter)] = closure.inputs.iter().collect::<Vec<_>>()[..] else {
+        return None;
+    };
+    let syn::Pat::Ident(binding) = parameter.pat.as_ref() else {
+        return None;
+    };
+    if binding.by_ref.is_some() || binding.subpat.is_some() {
+        return None;
+    }
+
+    let field_type = field.field_type();
+    let parameter_type = &parameter.ty;
+    let same_type = quote! { #parameter_type }.to_string() == quote! { #field_type }.to_string();
+    if !same_type || !returns_binding(&closure.body, &binding.ident) {
+        return None;
+    }
+
+    let field_name = field.clean_name();
+    Some(Lint {
+        name: "redundant_converter",
+        span: field.name().span(),
+        message: format!(
+            "field `{field_name}`: the converter takes `{}` and returns it unchanged",
+            quote! { #field_type }
+        ),
+        help: format!(
+            "remove the `converter` from field `{field_name}`, its setter takes the field type already"
+        ),
+    })
+}
+
+/// Returns `true` if a closure body is just the given binding, e.g. `value` or `{ value }`.
+fn returns_binding(body: &syn::Expr, binding: &syn::Ident) -> bool {
+    match body {
+        syn::Expr::Path(path) => path.qself.is_none() && path.path.is_ident(binding),
+        syn::Expr::Paren(paren) => returns_binding(&paren.expr, binding),
+        syn::Expr::Block(block) if block.label.is_none() => match &block.block.stmts[..] {
+            [syn::Stmt::Expr(expr, None)] => returns_binding(expr, binding),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Flags `impl_into` setters for numeric fields other than `i32` and `f64`.
+///
+/// An `impl Into<u16>` parameter has several candidate source types, so an
+/// integer literal such as `.port(8080)` falls back to `i32` and fails to compile.
+fn impl_into_on_numeric(analysis: &StructAnalysis, field: &FieldInfo) -> Option<Lint> {
+    let struct_attributes = analysis.struct_attributes();
+    let attributes = field.attributes();
+    let uses_impl_into = !struct_attributes.get_const_builder()
+        && field.should_generate_setter()
+        && attributes.converter.is_none()
+        && !attributes.transparent
+        && resolve_effective_impl_into(attributes.impl_into, struct_attributes.get_impl_into());
+    if !uses_impl_into || !needs_typed_literal(field.field_type()) {
+        return None;
+    }
+
+    let field_name = field.clean_name();
+    let field_type = field.field_type();
+    let help = if attributes.impl_into.is_some() {
+        format!("remove `impl_into` from field `{field_name}`")
+    } else {
+        format!("add `#[builder(impl_into = false)]` to field `{field_name}`")
+    };
+    Some(Lint {
+        name: "impl_into_on_numeric",
+        span: field.name().span(),
+        message: format!(
+            "field `{field_name}`: `impl_into` on `{}` breaks literal inference, \
+             so a call like `.{field_name}(1)` does not compile",
+            quote! { #field_type }
+        ),
+        help,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::analyze_struct;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_impl_into_on_numeric_lint() {
+        let input = parse_quote! {
+            #[builder(impl_into)]
+            struct Server {
+                #[builder(required)]
+                host: String,
+                port: u16,
+                #[builder(impl_into = false)]
+                workers: usize,
+                #[builder(converter = |value: u8| value as u32)]
+                retries: u32,
+                count: i32,
+                ratio: f32,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let lints = collect_lints(&analysis);
+
+        let messages: Vec<_> = lints.iter().map(|lint| lint.message.as_str()).collect();
+        assert_eq!(messages.len(), 2, "{messages:?}");
+        assert!(messages[0].starts_with("field `port`: `impl_into` on `u16`"));
+        assert!(messages[1].starts_with("field `ratio`: `impl_into` on `f32`"));
+        assert!(lints[0].help.contains("impl_into = false"));
+    }
+
+    #[test]
+    fn test_redundant_converter_lint() {
+        let input = parse_quote! {
+            struct Document {
+                #[builder(required, converter = |title: String| title)]
+                title: String,
+                #[builder(converter = |tags: Vec<String>| { tags })]
+                tags: Vec<String>,
+                #[builder(converter = |body: String| body.trim().to_string())]
+                body: String,
+                #[builder(converter = |len: u8| len)]
+                len: u32,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let lints = collect_lints(&analysis);
+
+        let messages: Vec<_> = lints.iter().map(|lint| lint.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "field `title`: the converter takes `String` and returns it unchanged",
+                "field `tags`: the converter takes `Vec < String >` and returns it unchanged",
+            ]
+        );
+        assert!(lints[0].help.contains("remove the `converter`"));
+    }
+
+    #[test]
+    fn test_lint_levels() {
+        let input = parse_quote! {
+            #[builder(lints = "deny")]
+            struct Server {
+                #[builder(impl_into)]
+                port: u16,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let error = generate_lints(&analysis).unwrap_err().to_string();
+        assert!(error.contains("field `port`"), "{error}");
+        assert!(error.contains("remove `impl_into`"), "{error}");
+
+        let input = parse_quote! {
+            #[builder(lints = "allow")]
+            struct Server {
+                #[builder(impl_into)]
+                port: u16,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        assert!(generate_lints(&analysis).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,148 @@
+//! Lite Builder Generation
+//!
+//! This module implements `#[builder(lite)]`, which generates no builder types
+//! at all. Instead, the struct itself gets a constructor taking the required
+//! fields and chainable methods for the optional ones:
+//!
+//! ```rust,ignore
+//! let config = Config::new("localhost".to_string()).with_port(8080);
+//! ```
+//!
+//! # Generated Code Structure
+//!
+//! For a struct with the `lite` attribute, this generates one impl block with:
+//! - `new(required...)` - Takes the required fields in declaration order and
+//!   initializes the optional fields with their defaults
+//! - `with_{field}(self, value) -> Self` - A setter for each optional field,
+//!   using `with_` unless a setter prefix is configured
+//!
+//! Required fields are only checked by the arguments of `new`, so there are no
+//! builder states and nothing to build.
+
+use crate::analysis::StructAnalysis;
+use crate::generation::TokenGenerator;
+use crate::utils::field_utils::resolve_effective_impl_into;
+use quote::quote;
+
+/// The setter prefix used in lite mode when none is configured.
+const LITE_SETTER_PREFIX: &str = "with_";
+
+/// Generates the lite-mode methods for a struct.
+///
+/// # Arguments
+///
+/// * `analysis` - Complete struct analysis containing all necessary information
+///
+/// # Returns
+///
+/// A `syn::Result<proc_macro2::TokenStream>` containing the impl block with the
+/// `new` constructor and the `with_*` setters.
+pub fn generate_lite_builder(analysis: &StructAnalysis) -> syn::Result<proc_macro2::TokenStream> {
+    let token_generator = TokenGenerator::new(analysis);
+    let struct_name = analysis.struct_name();
+    let impl_generics = token_generator.impl_generics_tokens();
+    let type_generics = token_generator.type_generics_tokens();
+    let where_clause = token_generator.where_clause_tokens();
+    let const_kw = token_generator.const_keyword();
+    let is_const = token_generator.is_const_builder();
+    let struct_attributes = analysis.struct_attributes();
+    let default_bounds = token_generator.default_bounds_where_clause();
+    let non_exhaustive_doc = token_generator.generate_non_exhaustive_documentation();
+    let visibility = analysis.entry_visibility();
+    let default_struct = token_generator.generate_default_struct_binding();
+
+    // Required fields become the parameters of `new`
+    let mut parameters = Vec::new();
+    let mut field_init = proc_macro2::TokenStream::new();
+    for field in analysis.required_fields() {
+        let field_name = field.name();
+        let field_type = field.field_type();
+        let use_impl_into = !is_const
+            && resolve_effective_impl_into(
+                field.attributes().impl_into,
+                struct_attributes.get_impl_into(),
+            );
+        if use_impl_into {
+            parameters.push(quote! { #field_name: impl ::core::convert::Into<#field_type> });
+            field_init.extend(quote! { #field_name: ::core::convert::Into::into(#field_name), });
+        } else {
+            parameters.push(quote! { #field_name: #field_type });
+            field_init.extend(quote! { #field_name, });
+        }
+    }
+
+    // Optional fields start at their defaults and get `with_*` setters
+    let struct_setter_prefix = struct_attributes
+        .get_setter_prefix()
+        .or(Some(LITE_SETTER_PREFIX));
+    let struct_impl_into = !is_const && struct_attributes.get_impl_into();
+    let struct_into_iter = struct_attributes.get_into_iter();
+    let struct_option_into = struct_attributes.get_option_into();
+    let mut setter_methods = proc_macro2::TokenStream::new();
+    for field in analysis.optional_fields() {
+        field_init.extend(token_generator.generate_optional_field_initialization(field)?);
+        if field.should_generate_setter() {
+            setter_methods.extend(field.generate_setter_method(
+                &syn::parse_quote!(Self),
+                struct_setter_prefix,
+                struct_impl_into || field.uses_option_into(struct_option_into),
+                struct_into_iter,
+                is_const,
+                &token_generator.validation_error_type_ident(),
+            )?);
+        }
+    }
+
+    let doc = token_generator.generate_method_documentation(
+        "new",
+        &format!("Creates a new `{struct_name}` from its required fields"),
+        Some(
+            "Optional fields start at their defaults and can be changed with the `with_*` methods.",
+        ),
+    );
+
+    Ok(quote! {
+        impl #impl_generics #struct_name #type_generics #where_clause {
+            #doc
+            #non_exhaustive_doc
+            #[allow(clippy::too_many_arguments)]
+            #visibility #const_kw fn new(#(#parameters),*) -> Self #default_bounds {
+                #default_struct
+                Self {
+                    #field_init
+                }
+            }
+
+            #setter_methods
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::analyze_struct;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_lite_builder() {
+        let input = parse_quote! {
+            #[builder(lite)]
+            struct Config {
+                #[builder(required)]
+                host: String,
+                #[builder(default = 80)]
+                port: u16,
+                #[builder(setter_name = "timeout_secs")]
+                timeout: Option<u64>,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let code = generate_lite_builder(&analysis).unwrap().to_string();
+
+        assert!(code.contains("pub fn new (host : String) -> Self"));
+        assert!(code.contains("pub fn with_port (mut self , value : u16) -> Self"));
+        assert!(code.contains("pub fn with_timeout_secs"));
+        assert!(!code.contains("ConfigBuilder"));
+    }
+}
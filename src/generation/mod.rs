@@ -17,6 +17,16 @@
 //! - [`tokens`]: Core token generation utilities and the `TokenGenerator`
 //! - [`type_state_builder`]: Type-state builder pattern implementation
 //! - [`regular_builder`]: Simple builder pattern for optional-only structs
+//! - [`enum_builder`]: Variant selection followed by a builder per variant for enums
+//! - [`build_macro`]: Expansion of the `build!` struct-literal macro
+//! - [`builder_fn`]: A builder for the arguments of a function with `#[builder_fn]`
+//! - [`lite_builder`]: `new` and `with_*` methods on the struct for `#[builder(lite)]`
+//! - [`lints`]: Warnings for configurations that compile but are likely mistakes
+//! - [`debug_expand`]: The generated code shown in a warning for `#[builder(debug_expand)]`
+//! - [`arbitrary`]: `arbitrary::Arbitrary` implemented through the builder for `#[builder(arbitrary)]`
+//! - [`field_descriptors`]: The `FIELDS` constant describing the fields for `#[builder(field_descriptors)]`
+//! - [`dynamic_builder`]: The runtime-checked `{Struct}DynBuilder` for `#[builder(also_dynamic)]`
+//! - [`required_cfg`]: A `cfg`-gated builder per combination of `required(cfg(...))` predicates
 //!
 //! # Builder Pattern Selection
 //!
@@ -33,13 +43,25 @@
 //!   - More user-friendly for simple cases
 //!
 
+pub mod arbitrary;
+pub mod build_macro;
+pub mod builder_fn;
+pub mod debug_expand;
+pub mod dynamic_builder;
+pub mod enum_builder;
+pub mod field_descriptors;
+pub mod lints;
+pub mod lite_builder;
 pub mod regular_builder;
+pub mod required_cfg;
 pub mod tokens;
 pub mod type_state_builder;
 
 // Re-export main types and functions for convenience
 pub use self::type_state_builder::generate_type_state_builder;
+pub use enum_builder::generate_enum_builder;
 pub use regular_builder::generate_regular_builder;
+pub use required_cfg::generate_required_cfg_builders;
 pub use tokens::TokenGenerator;
 
 use crate::analysis::StructAnalysis;
@@ -88,13 +110,112 @@ pub fn generate_builder(analysis: &StructAnalysis) -> syn::Result<proc_macro2::T
     // Validate the analysis before generation
     analysis.validate_for_generation()?;
 
-    // Select the appropriate builder pattern based on field requirements
-    if analysis.has_only_optional_fields() {
+    // Lint warnings sit beside the generated items, outside any builder module
+    let lints = lints::generate_lints(analysis)?;
+
+    // The errors of a validating build method and of `apply_env` sit beside the builder types
+    let validation_error = TokenGenerator::new(analysis).generate_validation_error_type();
+    let env_error = TokenGenerator::new(analysis).generate_env_error_type();
+    let tokens = if analysis.struct_attributes().get_lite() {
+        // Lite mode adds methods to the struct itself and has no builder types
+        lite_builder::generate_lite_builder(analysis)?
+    } else if analysis.has_only_optional_fields() {
         // All fields are optional - use the simpler regular builder pattern
-        generate_regular_builder(analysis)
+        let builder = generate_regular_builder(analysis)?;
+        wrap_in_builder_module(
+            analysis,
+            quote::quote! { #validation_error #env_error #builder },
+        )
     } else {
         // Has required fields - use the type-state builder pattern for compile-time safety
-        generate_type_state_builder(analysis)
+        let builder = generate_type_state_builder(analysis)?;
+        wrap_in_builder_module(
+            analysis,
+            quote::quote! { #validation_error #env_error #builder },
+        )
+    };
+    let arbitrary = if analysis.struct_attributes().get_arbitrary() {
+        arbitrary::generate_arbitrary_impl(analysis)?
+    } else {
+        proc_macro2::TokenStream::new()
+    };
+    let field_descriptors = analysis
+        .struct_attributes()
+        .get_field_descriptors()
+        .map(|descriptor| field_descriptors::generate_field_descriptors(analysis, descriptor))
+        .unwrap_or_default();
+    let dynamic_builder = if analysis.struct_attributes().get_also_dynamic() {
+        dynamic_builder::generate_dynamic_builder(analysis)?
+    } else {
+        proc_macro2::TokenStream::new()
+    };
+    // Helper traits are shared by the builder and the lite methods alike
+    let bound_helpers = TokenGenerator::new(analysis).generate_bound_helper_traits();
+    let tokens = quote::quote! {
+        #bound_helpers #tokens #arbitrary #field_descriptors #dynamic_builder #lints
+    };
+
+    if analysis.struct_attributes().get_debug_expand() {
+        let expansion = debug_expand::generate_expansion_warning(analysis, &tokens);
+        return Ok(quote::quote! { #tokens #expansion });
+    }
+    Ok(tokens)
+}
+
+/// Places the generated builder items in the module requested with
+/// `#[builder(module = "...")]`.
+///
+/// The module imports everything from the struct's module so field types,
+/// defaults and converters resolve as before. The nested-builder aliases are
+/// re-exported beside the struct, so `#[builder(nested)]` fields in other
+/// structs keep finding them.
+///
+/// # Arguments
+///
+/// * `analysis` - The struct analysis
+/// * `tokens` - The generated builder items
+///
+/// # Returns
+///
+/// The items wrapped in the module, or unchanged if no module was requested.
+fn wrap_in_builder_module(
+    analysis: &StructAnalysis,
+    tokens: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let Some(module) = analysis.struct_attributes().get_module() else {
+        return tokens;
+    };
+
+    let struct_name = analysis.struct_name();
+    let visibility = analysis.struct_visibility();
+    let doc = if analysis.struct_attributes().get_no_docs() {
+        quote::quote! { #[allow(missing_docs)] }
+    } else {
+        let text = format!("Builder types for [`{struct_name}`].");
+        quote::quote! { #[doc = #text] }
+    };
+
+    let nested_reexport = if analysis.struct_attributes().get_nestable() {
+        let start_alias = quote::format_ident!("{}BuilderStart", struct_name);
+        let ready_alias = quote::format_ident!("{}BuilderReady", struct_name);
+        quote::quote! {
+            #[doc(hidden)]
+            #[allow(unused_imports)]
+            #visibility use self::#module::{#start_alias, #ready_alias};
+        }
+    } else {
+        quote::quote! {}
+    };
+
+    quote::quote! {
+        #doc
+        #visibility mod #module {
+            use super::*;
+
+            #tokens
+        }
+
+        #nested_reexport
     }
 }
 
@@ -216,6 +337,42 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_generate_builder_without_docs() {
+        let input = parse_quote! {
+            #[builder(no_docs, state_traits, module = "example_builder")]
+            pub struct Example {
+                #[builder(required, lazy_setter)]
+                name: String,
+                /// Kept: field docs are copied to the setter.
+                #[builder(nested)]
+                inner: Inner,
+            }
+        };
+
+        let analysis = analyze_struct(&input).unwrap();
+        let code = generate_builder(&analysis).unwrap().to_string();
+
+        // Each copy of the field docs is an empty line followed by the text
+        let copied_docs = code
+            .matches("Kept: field docs are copied to the setter.")
+            .count();
+        assert!(copied_docs > 0);
+        assert_eq!(code.matches("doc =").count(), 2 * copied_docs, "{code}");
+        assert!(code.contains("allow (missing_docs)"));
+
+        let documented = analyze_struct(&parse_quote! {
+            pub struct Example {
+                #[builder(required)]
+                name: String,
+            }
+        })
+        .unwrap();
+        let code = generate_builder(&documented).unwrap().to_string();
+        assert!(code.contains("doc ="));
+        assert!(!code.contains("missing_docs"));
+    }
+
     #[test]
     fn test_builder_selection_logic() {
         // Test type-state builder selection
@@ -99,8 +99,7 @@ impl<'a> RegularBuilderCoordinator<'a> {
     ///
     /// A new `RegularBuilderCoordinator` ready for generation.
     fn new(token_generator: &'a TokenGenerator<'a>) -> Self {
-        let struct_name = token_generator.analysis().struct_name();
-        let builder_name = format!("{struct_name}Builder");
+        let builder_name = format!("{}Builder", token_generator.analysis().builder_base_name());
 
         Self {
             token_generator,
@@ -125,12 +124,65 @@ impl<'a> RegularBuilderCoordinator<'a> {
         // Generate the constructor method on the original struct
         tokens.extend(self.generate_struct_constructor_method()?);
 
+        // Without required fields, the builder for tests is a new builder
+        let builder_ident = syn::parse_str::<Ident>(&self.builder_name)?;
+        tokens.extend(
+            self.token_generator
+                .generate_test_builder_method(&builder_ident, &quote! { #builder_ident::new() }),
+        );
+
         // Generate the Default implementation for the builder
         tokens.extend(self.generate_default_implementation()?);
 
         // Generate the main implementation block with all methods
         tokens.extend(self.generate_builder_implementation()?);
 
+        // Let the builder convert into the struct
+        tokens.extend(
+            self.token_generator
+                .generate_from_builder_impl(&builder_ident)?,
+        );
+
+        // Generate the builder state traits for downstream extension traits
+        tokens.extend(self.token_generator.generate_state_traits()?);
+        tokens.extend(
+            self.token_generator
+                .generate_state_trait_impls(&builder_ident, true)?,
+        );
+        let setter_methods = self.generate_setter_methods()?;
+        tokens.extend(
+            self.token_generator
+                .generate_setter_trait(&setter_methods)?,
+        );
+        tokens.extend(
+            self.token_generator
+                .generate_setter_trait_impl(&builder_ident, &setter_methods)?,
+        );
+
+        // Generate the support items for nesting this builder in other builders
+        tokens.extend(
+            self.token_generator
+                .generate_nested_support(&builder_ident, &builder_ident)?,
+        );
+
+        // Generate the requested state aliases, which name the single builder type
+        tokens.extend(
+            self.token_generator
+                .generate_state_aliases(&builder_ident, &builder_ident)?,
+        );
+
+        // Implement the user trait that names the builder if requested
+        tokens.extend(
+            self.token_generator
+                .generate_buildable_impl(&builder_ident)?,
+        );
+
+        // Assert the builder's auto traits if requested
+        tokens.extend(
+            self.token_generator
+                .generate_auto_trait_assertions(std::slice::from_ref(&builder_ident)),
+        );
+
         Ok(tokens)
     }
 
@@ -166,20 +218,37 @@ impl<'a> RegularBuilderCoordinator<'a> {
             })
         );
 
-        // Generate Debug implementation if configured
-        let debug_impl = self
+        // Generate Debug implementation if configured, and the ZeroizeOnDrop
+        // marker for builders whose fields are all sensitive
+        let debug_impl = self.token_generator.generate_debug_impl(
+            &quote! { #builder_ident },
+            &type_generics,
+            &[],
+        );
+        let zeroize_impl = self
             .token_generator
-            .generate_debug_impl(&quote! { #builder_ident }, &type_generics);
+            .generate_zeroize_on_drop_impl(&quote! { #builder_ident }, &type_generics);
+        let derive_impls = self.token_generator.generate_derive_impls(
+            &quote! { #builder_ident },
+            &type_generics,
+            &[],
+        );
 
-        let struct_visibility = self.token_generator.analysis().struct_visibility();
+        let struct_visibility = self.token_generator.analysis().builder_visibility();
+        let non_exhaustive = self.token_generator.generate_non_exhaustive_attribute();
+        let serde_derive = self.token_generator.generate_serde_derive_attribute();
 
         Ok(quote! {
             #doc
+            #serde_derive
+            #non_exhaustive
             #struct_visibility struct #builder_ident #impl_generics #where_clause {
                 #field_declarations
             }
 
             #debug_impl
+            #zeroize_impl
+            #derive_impls
         })
     }
 
@@ -199,6 +268,7 @@ impl<'a> RegularBuilderCoordinator<'a> {
         for optional_field in analysis.optional_fields() {
             let field_name = optional_field.name();
             let field_type = optional_field.field_type();
+            let storage_type = optional_field.builder_storage_type();
 
             let doc = self.token_generator.generate_field_documentation(
                 &optional_field.clean_name(),
@@ -206,10 +276,16 @@ impl<'a> RegularBuilderCoordinator<'a> {
                 false,
                 "Optional field",
             );
+            let serde_skip = if optional_field.attributes().computed.is_some() {
+                self.token_generator.generate_serde_skip_attribute()
+            } else {
+                quote! {}
+            };
 
             field_declarations.extend(quote! {
                 #doc
-                #field_name: #field_type,
+                #serde_skip
+                #field_name: #storage_type,
             });
         }
 
@@ -229,26 +305,29 @@ impl<'a> RegularBuilderCoordinator<'a> {
     /// A `syn::Result<proc_macro2::TokenStream>` containing the constructor method.
     fn generate_struct_constructor_method(&self) -> syn::Result<proc_macro2::TokenStream> {
         let analysis = self.token_generator.analysis();
-        let struct_name = analysis.struct_name();
-        let builder_ident = syn::parse_str::<Ident>(&self.builder_name)?;
+        // With no_entry the builder is started with its own `new()`
+        if analysis.struct_attributes().get_no_entry() {
+            return Ok(quote! {});
+        }
 
-        let impl_generics = self.token_generator.impl_generics_tokens();
-        let type_generics = self.token_generator.type_generics_tokens();
-        let where_clause = self.token_generator.where_clause_tokens();
+        let builder_ident = syn::parse_str::<Ident>(&self.builder_name)?;
 
         let doc = self.token_generator.generate_method_documentation(
             "builder",
             "Creates a new builder for constructing an instance with optional field customization",
             Some("All fields start with their default values and can be customized using setter methods.")
         );
+        let setters_doc = self
+            .token_generator
+            .generate_setters_summary_documentation();
+        let non_exhaustive_doc = self.token_generator.generate_non_exhaustive_documentation();
 
-        let const_kw = self.token_generator.const_keyword();
-        let is_const = self.token_generator.is_const_builder();
-
-        // For const builders, inline initialization instead of calling default()
-        let builder_init = if is_const {
+        // Without a Default impl, inline initialization instead of calling default()
+        let builder_init = if !self.has_default_impl() {
             let default_field_init = self.generate_default_field_initializations()?;
+            let default_struct = self.token_generator.generate_default_struct_binding();
             quote! {
+                #default_struct
                 #builder_ident {
                     #default_field_init
                 }
@@ -257,14 +336,25 @@ impl<'a> RegularBuilderCoordinator<'a> {
             quote! { #builder_ident::default() }
         };
 
-        Ok(quote! {
-            impl #impl_generics #struct_name #type_generics #where_clause {
-                #doc
-                pub #const_kw fn builder() -> #builder_ident #type_generics {
-                    #builder_init
-                }
-            }
-        })
+        self.token_generator.generate_entry_method(
+            &doc,
+            &quote! {
+                #setters_doc
+                #non_exhaustive_doc
+            },
+            &builder_ident,
+            &builder_init,
+        )
+    }
+
+    /// Returns whether the builder implements `Default`.
+    ///
+    /// `Default::default()` cannot be called in const fn, and a trait impl
+    /// would let anyone start the builder despite `entry_vis`.
+    fn has_default_impl(&self) -> bool {
+        let analysis = self.token_generator.analysis();
+        !self.token_generator.is_const_builder()
+            && analysis.struct_attributes().get_entry_vis().is_none()
     }
 
     /// Generates the Default implementation for the builder.
@@ -276,8 +366,7 @@ impl<'a> RegularBuilderCoordinator<'a> {
     ///
     /// A `syn::Result<proc_macro2::TokenStream>` containing the Default implementation.
     fn generate_default_implementation(&self) -> syn::Result<proc_macro2::TokenStream> {
-        // Skip Default impl for const builders - Default::default() is not const
-        if self.token_generator.is_const_builder() {
+        if !self.has_default_impl() {
             return Ok(quote! {});
         }
 
@@ -285,10 +374,14 @@ impl<'a> RegularBuilderCoordinator<'a> {
 
         let impl_generics = self.token_generator.impl_generics_tokens();
         let type_generics = self.token_generator.type_generics_tokens();
-        let where_clause = self.token_generator.where_clause_tokens();
+        // The builder is only `Default` when its optional fields without a `default` are
+        let where_clause = self
+            .token_generator
+            .where_clause_with(&self.token_generator.default_field_bounds());
 
         // Generate default field initializations
         let default_field_init = self.generate_default_field_initializations()?;
+        let default_struct = self.token_generator.generate_default_struct_binding();
 
         let doc = if self.token_generator.config().include_documentation {
             quote! {
@@ -300,8 +393,9 @@ impl<'a> RegularBuilderCoordinator<'a> {
 
         Ok(quote! {
             #doc
-            impl #impl_generics Default for #builder_ident #type_generics #where_clause {
+            impl #impl_generics ::core::default::Default for #builder_ident #type_generics #where_clause {
                 fn default() -> Self {
+                    #default_struct
                     Self {
                         #default_field_init
                     }
@@ -324,7 +418,9 @@ impl<'a> RegularBuilderCoordinator<'a> {
 
         // Initialize all optional fields with their defaults
         for optional_field in analysis.optional_fields() {
-            let field_init_code = optional_field.generate_initialization(false)?;
+            let field_init_code = self
+                .token_generator
+                .generate_optional_field_initialization(optional_field)?;
             field_init.extend(field_init_code);
         }
 
@@ -352,12 +448,16 @@ impl<'a> RegularBuilderCoordinator<'a> {
         // Generate all method implementations
         let constructor_method = self.generate_constructor_method()?;
         let setter_methods = self.generate_setter_methods()?;
+        let fill_from_method = self.token_generator.generate_fill_from_method();
+        let apply_env_method = self.token_generator.generate_apply_env_method()?;
         let build_method = self.generate_build_method()?;
 
         Ok(quote! {
             impl #impl_generics #builder_ident #type_generics #where_clause {
                 #constructor_method
                 #setter_methods
+                #fill_from_method
+                #apply_env_method
                 #build_method
             }
         })
@@ -381,12 +481,15 @@ impl<'a> RegularBuilderCoordinator<'a> {
         );
 
         let const_kw = self.token_generator.const_keyword();
-        let is_const = self.token_generator.is_const_builder();
+        let default_bounds = self.token_generator.default_bounds_where_clause();
+        let visibility = self.token_generator.analysis().entry_visibility();
 
-        // For const builders, inline initialization instead of calling default()
-        let builder_init = if is_const {
+        // Without a Default impl, inline initialization instead of calling default()
+        let builder_init = if !self.has_default_impl() {
             let default_field_init = self.generate_default_field_initializations()?;
+            let default_struct = self.token_generator.generate_default_struct_binding();
             quote! {
+                #default_struct
                 #builder_ident {
                     #default_field_init
                 }
@@ -397,7 +500,7 @@ impl<'a> RegularBuilderCoordinator<'a> {
 
         Ok(quote! {
             #doc
-            pub #const_kw fn new() -> #builder_ident #type_generics {
+            #visibility #const_kw fn new() -> #builder_ident #type_generics #default_bounds {
                 #builder_init
             }
         })
@@ -415,6 +518,7 @@ impl<'a> RegularBuilderCoordinator<'a> {
         let mut setter_methods = proc_macro2::TokenStream::new();
         let analysis = self.token_generator.analysis();
         let is_const = self.token_generator.is_const_builder();
+        let error_ident = self.token_generator.validation_error_type_ident();
 
         // Generate setter for each optional field that should have one
         let struct_setter_prefix = analysis.struct_attributes().get_setter_prefix();
@@ -424,13 +528,17 @@ impl<'a> RegularBuilderCoordinator<'a> {
         } else {
             analysis.struct_attributes().get_impl_into()
         };
+        let struct_into_iter = analysis.struct_attributes().get_into_iter();
+        let struct_option_into = analysis.struct_attributes().get_option_into();
         for optional_field in analysis.optional_fields() {
             if optional_field.should_generate_setter() {
                 let setter_method = optional_field.generate_setter_method(
                     &syn::parse_quote!(Self),
                     struct_setter_prefix,
-                    struct_impl_into,
+                    struct_impl_into || optional_field.uses_option_into(struct_option_into),
+                    struct_into_iter,
                     is_const,
+                    &error_ident,
                 )?;
                 setter_methods.extend(setter_method);
             }
@@ -449,37 +557,53 @@ impl<'a> RegularBuilderCoordinator<'a> {
     /// A `syn::Result<proc_macro2::TokenStream>` containing the build method.
     fn generate_build_method(&self) -> syn::Result<proc_macro2::TokenStream> {
         let analysis = self.token_generator.analysis();
-        let struct_name = analysis.struct_name();
-        let type_generics = self.token_generator.type_generics_tokens();
 
-        // Generate field assignments for the target struct
+        // Generate field assignments for the target struct, after resolving
+        // the fields whose values depend on other fields
+        let computed_statements = analysis
+            .all_fields()
+            .map(|field| field.generate_computed_statement());
+        let resolve_statements = analysis
+            .all_fields()
+            .map(|field| field.generate_resolve_statement());
         let struct_field_assignments = self.generate_struct_field_assignments()?;
+        let constructor = analysis.constructor_path();
+        let base = self.token_generator.generate_base_binding();
 
         // Get build method name from configuration
         let build_method_name = analysis.struct_attributes().get_build_method_name();
-        let build_method_ident = syn::parse_str::<Ident>(build_method_name)?;
 
         let doc = self.token_generator.generate_method_documentation(
             build_method_name,
             "Builds the final instance",
             Some("This method is immediately available since all fields are optional."),
         );
-
-        let const_kw = self.token_generator.const_keyword();
-
-        Ok(quote! {
-            #doc
-            pub #const_kw fn #build_method_ident(self) -> #struct_name #type_generics {
-                #struct_name {
+        let fields_doc = self.token_generator.generate_fields_summary_documentation();
+
+        let build_method = self.token_generator.generate_build_method(
+            &quote! { #doc #fields_doc },
+            &quote! {
+                #(#computed_statements)*
+                #(#resolve_statements)*
+                #base
+                #constructor {
                     #struct_field_assignments
                 }
-            }
+            },
+        )?;
+        let finisher_methods = self.token_generator.generate_finisher_methods()?;
+
+        Ok(quote! {
+            #build_method
+
+            #finisher_methods
         })
     }
 
     /// Generates field assignments for the target struct construction.
     ///
-    /// Simply copies all field values from the builder to the target struct.
+    /// Copies all field values from the builder to the target struct, using the
+    /// resolved value for fields with a `resolve` closure.
     ///
     /// # Returns
     ///
@@ -488,11 +612,16 @@ impl<'a> RegularBuilderCoordinator<'a> {
         let mut assignments = proc_macro2::TokenStream::new();
         let analysis = self.token_generator.analysis();
 
-        // Assign all optional fields by copying from builder
+        // Assign all optional fields by copying from builder, leaving out
+        // builder-only fields, which are not part of the struct
         for optional_field in analysis.optional_fields() {
+            if optional_field.attributes().builder_only {
+                continue;
+            }
             let field_name = optional_field.name();
+            let value = optional_field.build_value_tokens();
             assignments.extend(quote! {
-                #field_name: self.#field_name,
+                #field_name: #value,
             });
         }
 
@@ -545,7 +674,7 @@ mod tests {
     fn test_regular_builder_with_custom_defaults() {
         let input = parse_quote! {
             struct Example {
-                #[builder(default = "42")]
+                #[builder(default = 42)]
                 count: i32,
                 #[builder(default = "String::from(\"test\")")]
                 name: String,
@@ -606,8 +735,8 @@ mod tests {
         assert!(code.contains("<"));
         assert!(code.contains("T"));
 
-        // Should contain PhantomData for generic tracking
-        assert!(code.contains("PhantomData"));
+        // T is stored directly in a field, so no PhantomData is needed
+        assert!(!code.contains("PhantomData"));
     }
 
     #[test]
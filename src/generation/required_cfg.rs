@@ -0,0 +1,156 @@
+//! Builders for Conditionally Required Fields
+//!
+//! A `#[builder(required(cfg(predicate)))]` field is required when the
+//! predicate holds in the crate using the builder. The macro cannot evaluate
+//! the predicate, so it generates the builder once for every combination of
+//! the struct's predicates and gates each copy on its combination:
+//!
+//! ```rust,ignore
+//! #[cfg(all(feature = "strict"))]
+//! // ... builder with the field required ...
+//!
+//! #[cfg(all(not(feature = "strict")))]
+//! // ... builder with the field optional ...
+//! ```
+//!
+//! Exactly one copy is compiled, so the builder types keep their usual names.
+//! Each predicate doubles the generated code.
+
+use crate::analysis::analyze_struct_with_required_cfgs;
+use crate::generation::generate_builder;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Item};
+
+/// Generates a builder for each combination of `required(cfg(...))` predicates.
+///
+/// # Arguments
+///
+/// * `input` - The parsed derive input from the struct definition
+/// * `predicates` - The predicates of the struct's `required(cfg(...))` fields,
+///   in declaration order
+///
+/// # Returns
+///
+/// A `syn::Result<TokenStream>` containing every builder, with each item gated
+/// on the combination of predicates it was generated for.
+///
+/// # Errors
+///
+/// Returns the first analysis, validation or generation error of any
+/// combination.
+pub fn generate_required_cfg_builders(
+    input: &DeriveInput,
+    predicates: &[&syn::Meta],
+) -> syn::Result<TokenStream> {
+    let mut output = TokenStream::new();
+    for combination in 0..1_usize << predicates.len() {
+        let required_cfgs: Vec<bool> = (0..predicates.len())
+            .map(|index| combination & (1 << index) != 0)
+            .collect();
+        let conditions = predicates
+            .iter()
+            .zip(&required_cfgs)
+            .map(|(predicate, &enabled)| {
+                if enabled {
+                    quote! { #predicate }
+                } else {
+                    quote! { not(#predicate) }
+                }
+            });
+        let condition = quote! { all(#(#conditions),*) };
+
+        let analysis = analyze_struct_with_required_cfgs(input, &required_cfgs)?;
+        let builder = generate_builder(&analysis)?;
+        output.extend(gate_items(builder, &condition)?);
+    }
+    Ok(output)
+}
+
+/// Adds `#[cfg(condition)]` to every item in a token stream.
+///
+/// # Arguments
+///
+/// * `tokens` - Generated items
+/// * `condition` - The configuration predicate to gate them on
+///
+/// # Returns
+///
+/// The gated items, or an error if the tokens are not a sequence of items.
+fn gate_items(tokens: TokenStream, condition: &TokenStream) -> syn::Result<TokenStream> {
+    let file: syn::File = syn::parse2(tokens)?;
+    let mut output = TokenStream::new();
+    for mut item in file.items {
+        let cfg: syn::Attribute = syn::parse_quote! { #[cfg(#condition)] };
+        if let Some(attrs) = item_attrs_mut(&mut item) {
+            attrs.insert(0, cfg);
+            output.extend(quote! { #item });
+        } else {
+            output.extend(quote! { #cfg #item });
+        }
+    }
+    Ok(output)
+}
+
+/// Returns the attributes of an item, or `None` for verbatim tokens.
+fn item_attrs_mut(item: &mut Item) -> Option<&mut Vec<syn::Attribute>> {
+    match item {
+        Item::Const(item) => Some(&mut item.attrs),
+        Item::Enum(item) => Some(&mut item.attrs),
+        Item::ExternCrate(item) => Some(&mut item.attrs),
+        Item::Fn(item) => Some(&mut item.attrs),
+        Item::ForeignMod(item) => Some(&mut item.attrs),
+        Item::Impl(item) => Some(&mut item.attrs),
+        Item::Macro(item) => Some(&mut item.attrs),
+        Item::Mod(item) => Some(&mut item.attrs),
+        Item::Static(item) => Some(&mut item.attrs),
+        Item::Struct(item) => Some(&mut item.attrs),
+        Item::Trait(item) => Some(&mut item.attrs),
+        Item::TraitAlias(item) => Some(&mut item.attrs),
+        Item::Type(item) => Some(&mut item.attrs),
+        Item::Union(item) => Some(&mut item.attrs),
+        Item::Use(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::analyze_struct;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_required_cfg_builders() {
+        let input: DeriveInput = parse_quote! {
+            struct Server {
+                #[builder(required(cfg(feature = "strict")), default = 8080)]
+                port: u16,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let predicates = analysis.required_cfg_predicates();
+        assert_eq!(predicates.len(), 1);
+
+        let code = generate_required_cfg_builders(&input, &predicates)
+            .unwrap()
+            .to_string();
+        assert!(code.contains("# [cfg (all (feature = \"strict\"))]"));
+        assert!(code.contains("# [cfg (all (not (feature = \"strict\")))]"));
+        assert!(code.contains("ServerBuilder_MissingPort"));
+        assert!(code.contains("8080"));
+    }
+
+    #[test]
+    fn test_gate_items() {
+        let items = quote! {
+            struct A;
+            impl A {}
+            #[doc = "b"]
+            fn b() {}
+        };
+        let code = gate_items(items, &quote! { test }).unwrap().to_string();
+        assert_eq!(code.matches("# [cfg (test)]").count(), 3);
+        assert!(code.contains("# [cfg (test)] # [doc = \"b\"] fn b"));
+    }
+}
@@ -13,11 +13,14 @@
 //! - **Comprehensive documentation** - Self-documenting generated code
 //!
 
-use crate::analysis::StructAnalysis;
+use crate::analysis::{FieldInfo, StructAnalysis};
 use crate::generation::GenerationConfig;
-use crate::utils::identifiers::generate_unique_identifier;
+use crate::utils::identifiers::{
+    generate_unique_identifier, pascal_case_to_snake_case, strip_raw_identifier_prefix,
+};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
 
 /// Central utility for generating token streams with configurable behavior.
 ///
@@ -50,6 +53,12 @@ pub struct TokenGenerator<'a> {
 
     /// Cached PhantomData field name for consistency across generation
     phantom_data_field_name: String,
+    /// Impl generics, computed once and shared by every builder state's impl blocks
+    impl_generics: TokenStream,
+    /// Type generics, computed once and shared by every builder state's impl blocks
+    type_generics: TokenStream,
+    /// Where clause, computed once and shared by every builder state's impl blocks
+    where_clause: TokenStream,
 }
 
 impl<'a> TokenGenerator<'a> {
@@ -65,6 +74,7 @@ impl<'a> TokenGenerator<'a> {
     ///
     pub fn new(analysis: &'a StructAnalysis) -> Self {
         let config = GenerationConfig {
+            include_documentation: !analysis.struct_attributes().get_no_docs(),
             const_builder: analysis.struct_attributes().get_const_builder(),
             ..Default::default()
         };
@@ -73,6 +83,9 @@ impl<'a> TokenGenerator<'a> {
             analysis,
             config,
             phantom_data_field_name: generate_unique_identifier("_marker"),
+            impl_generics: analysis.impl_generics_tokens(),
+            type_generics: analysis.type_generics_tokens(),
+            where_clause: analysis.where_clause_tokens(),
         }
     }
 
@@ -145,7 +158,7 @@ impl<'a> TokenGenerator<'a> {
     ///
     /// For `struct Example<T: Clone>`, generates: `< T : Clone >`
     pub fn impl_generics_tokens(&self) -> TokenStream {
-        self.analysis.impl_generics_tokens()
+        self.impl_generics.clone()
     }
 
     /// Generates type generics tokens for type references.
@@ -161,7 +174,7 @@ impl<'a> TokenGenerator<'a> {
     ///
     /// For `struct Example<T, U>`, generates: `< T , U >`
     pub fn type_generics_tokens(&self) -> TokenStream {
-        self.analysis.type_generics_tokens()
+        self.type_generics.clone()
     }
 
     /// Generates where clause tokens for type definitions.
@@ -174,7 +187,429 @@ impl<'a> TokenGenerator<'a> {
     ///
     /// For `struct Example<T> where T: Send`, generates: `where T : Send`
     pub fn where_clause_tokens(&self) -> TokenStream {
-        self.analysis.where_clause_tokens()
+        self.where_clause.clone()
+    }
+
+    /// Generates the where clause for type definitions extended with extra bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `bounds` - Additional predicates, e.g. from [`Self::default_field_bounds`]
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the combined where clause, or empty if there
+    /// are no predicates at all.
+    pub fn where_clause_with(&self, bounds: &[TokenStream]) -> TokenStream {
+        if bounds.is_empty() {
+            return self.where_clause_tokens();
+        }
+        let predicates = self.analysis.builder_where_predicates();
+        quote! { where #(#predicates,)* #(#bounds),* }
+    }
+
+    /// Generates the `Default` bounds needed to initialize the optional fields.
+    ///
+    /// Optional fields without a `default` start at `Default::default()`. When
+    /// the field type mentions a generic parameter, e.g. `value: T`, this needs
+    /// `T: Default`. Defaults calling `Default::default` on a generic type, as
+    /// in `default = T::default()`, need the same bound. Only the constructors
+    /// of the initial state evaluate the defaults, so the bounds are put on
+    /// them rather than on every builder state.
+    ///
+    /// Fields taken from the `default_struct` template need no bound of their
+    /// own, but the template expression does, e.g. `Self: Default` for
+    /// `default_struct = "Self::default()"` on a generic struct.
+    ///
+    /// # Returns
+    ///
+    /// One `Type: ::core::default::Default` predicate per distinct type.
+    pub fn default_field_bounds(&self) -> Vec<TokenStream> {
+        let mut default_types = Vec::new();
+        if let Some(default_struct) = self.analysis.struct_attributes().get_default_struct() {
+            // `Self` in the template names the struct
+            let struct_name = self.analysis.struct_name();
+            let type_generics = self.type_generics_tokens();
+            let struct_type: syn::Type = syn::parse_quote! { #struct_name #type_generics };
+            let self_type: syn::Type = syn::parse_quote! { Self };
+            for default_type in
+                crate::utils::generics::default_call_types(default_struct, &struct_type)
+            {
+                if default_type == self_type {
+                    default_types.push(struct_type.clone());
+                } else {
+                    default_types.push(default_type);
+                }
+            }
+        }
+        for field in self.analysis.optional_fields() {
+            let attributes = field.attributes();
+            if attributes.default_from.is_some()
+                || attributes.computed.is_some()
+                || attributes.from_base
+                || self.takes_default_from_struct(field)
+            {
+                continue;
+            }
+            default_types.extend(Self::field_default_types(field));
+        }
+
+        self.generic_default_bounds(default_types)
+    }
+
+    /// Generates the `Default` bounds the build method needs.
+    ///
+    /// A field tracked for `fill_from` that neither its setter nor `fill_from`
+    /// gave a value takes its default when the struct is built, so e.g.
+    /// `value: T` needs `T: Default` on the build method and the methods
+    /// calling it, not just on the constructors.
+    ///
+    /// # Returns
+    ///
+    /// One `Type: ::core::default::Default` predicate per distinct type.
+    pub fn build_default_bounds(&self) -> Vec<TokenStream> {
+        let default_types = self
+            .analysis
+            .optional_fields()
+            .iter()
+            .filter(|field| field.attributes().fill_from)
+            .flat_map(Self::field_default_types)
+            .collect();
+        self.generic_default_bounds(default_types)
+    }
+
+    /// Generates the `Clone` bounds of the `fill_from` method.
+    ///
+    /// The method clones each tracked field out of the fallback instance, so
+    /// e.g. `value: T` needs `T: Clone`. The bounds are on the method only, so
+    /// the builder works with types that cannot be cloned as long as
+    /// `fill_from` is not called.
+    ///
+    /// # Returns
+    ///
+    /// One `Type: ::core::clone::Clone` predicate per distinct type involving
+    /// the struct's generics.
+    pub fn fill_from_clone_bounds(&self) -> Vec<TokenStream> {
+        use crate::utils::extensions::TypeExt;
+
+        let declared =
+            crate::utils::generics::collect_declared_generic_names(self.analysis.struct_generics());
+        let mut bounds: Vec<TokenStream> = Vec::new();
+        for field in self.analysis.optional_fields() {
+            let attributes = field.attributes();
+            if !attributes.fill_from || attributes.builder_only {
+                continue;
+            }
+            let field_type = field.field_type();
+            let bound = quote! { #field_type: ::core::clone::Clone };
+            let is_new = !bounds
+                .iter()
+                .any(|existing| existing.to_string() == bound.to_string());
+            if field_type.references_generics(&declared) && is_new {
+                bounds.push(bound);
+            }
+        }
+        bounds
+    }
+
+    /// Gets the types an optional field's default calls `Default::default` on.
+    ///
+    /// This is the field type itself without a `default`, otherwise the types
+    /// the `default` expression calls `Default::default` on.
+    fn field_default_types(field: &FieldInfo) -> Vec<syn::Type> {
+        match &field.attributes().default_value {
+            Some(default_value) => {
+                crate::utils::generics::default_call_types(default_value, field.field_type())
+            }
+            None => vec![field.field_type().clone()],
+        }
+    }
+
+    /// Turns types needing `Default` into bounds, keeping those that involve
+    /// the struct's generics once each.
+    fn generic_default_bounds(&self, default_types: Vec<syn::Type>) -> Vec<TokenStream> {
+        use crate::utils::extensions::TypeExt;
+
+        let declared =
+            crate::utils::generics::collect_declared_generic_names(self.analysis.struct_generics());
+        let mut bounds: Vec<TokenStream> = Vec::new();
+        for default_type in default_types {
+            let bound = quote! { #default_type: ::core::default::Default };
+            let is_new = !bounds
+                .iter()
+                .any(|existing| existing.to_string() == bound.to_string());
+            if default_type.references_generics(&declared) && is_new {
+                bounds.push(bound);
+            }
+        }
+        bounds
+    }
+
+    /// Gets the value a required field takes in `test_builder()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The required field
+    ///
+    /// # Returns
+    ///
+    /// The field-level `test_default` expression, or `Default::default()`.
+    pub fn test_value_tokens(&self, field: &FieldInfo) -> TokenStream {
+        match &field.attributes().test_default {
+            Some(value) => quote! { #value },
+            None => quote! { ::core::default::Default::default() },
+        }
+    }
+
+    /// Generates `test_builder()` on the struct for `#[builder(test_default)]`.
+    ///
+    /// The method exists under the configured `cfg` predicate only, `test` by
+    /// default, and needs the `Default` bounds of the optional fields like
+    /// `builder()` does, plus those of the required fields it fills with
+    /// `Default::default()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder_ident` - The builder state the method returns
+    /// * `body` - The statements creating the builder
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the impl block, or empty without the
+    /// struct-level `test_default`.
+    pub fn generate_test_builder_method(
+        &self,
+        builder_ident: &syn::Ident,
+        body: &TokenStream,
+    ) -> TokenStream {
+        let Some(predicate) = self.analysis.struct_attributes().get_test_default() else {
+            return quote! {};
+        };
+        let struct_name = self.analysis.struct_name();
+        let impl_generics = self.impl_generics_tokens();
+        let type_generics = self.type_generics_tokens();
+        let where_clause = self.where_clause_tokens();
+        let visibility = self.analysis.entry_visibility();
+
+        let mut default_types = Vec::new();
+        for field in self.analysis.required_fields() {
+            match &field.attributes().test_default {
+                Some(value) => default_types.extend(crate::utils::generics::default_call_types(
+                    value,
+                    field.field_type(),
+                )),
+                None => default_types.push(field.field_type().clone()),
+            }
+        }
+        let mut bounds = self.default_field_bounds();
+        for bound in self.generic_default_bounds(default_types) {
+            if !bounds
+                .iter()
+                .any(|existing| existing.to_string() == bound.to_string())
+            {
+                bounds.push(bound);
+            }
+        }
+        let bounds = if bounds.is_empty() {
+            quote! {}
+        } else {
+            quote! { where #(#bounds),* }
+        };
+
+        let doc = self.generate_method_documentation(
+            "test_builder",
+            "Creates a builder with every required field set to a test value",
+            Some(
+                "Required fields take their `test_default`, or `Default::default()`, so tests \
+                 only set the fields they are about before building.",
+            ),
+        );
+        quote! {
+            #[cfg(#predicate)]
+            impl #impl_generics #struct_name #type_generics #where_clause {
+                #doc
+                #visibility fn test_builder() -> #builder_ident #type_generics #bounds {
+                    #body
+                }
+            }
+        }
+    }
+
+    /// Generates a method-level where clause with the `Default` bounds of
+    /// [`Self::default_field_bounds`].
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the where clause, or empty if no bounds are needed.
+    pub fn default_bounds_where_clause(&self) -> TokenStream {
+        let bounds = self.default_field_bounds();
+        if bounds.is_empty() {
+            return quote! {};
+        }
+        quote! { where #(#bounds),* }
+    }
+
+    /// Returns whether an optional field starts with the `default_struct` template's value.
+    ///
+    /// This applies to every optional field of the struct without a `default`,
+    /// `default_from` or `computed` of its own. Builder-only fields are not
+    /// part of the template and keep their defaults.
+    pub fn takes_default_from_struct(&self, field: &FieldInfo) -> bool {
+        let attributes = field.attributes();
+        self.analysis
+            .struct_attributes()
+            .get_default_struct()
+            .is_some()
+            && !field.is_required()
+            && attributes.default_value.is_none()
+            && attributes.default_from.is_none()
+            && attributes.computed.is_none()
+            && !attributes.builder_only
+    }
+
+    /// Generates the initialization of an optional field in a new builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The optional field
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<TokenStream>` with the field initializer: the
+    /// `default_struct` template's value, or the field's own default.
+    pub fn generate_optional_field_initialization(
+        &self,
+        field: &FieldInfo,
+    ) -> syn::Result<TokenStream> {
+        if !self.takes_default_from_struct(field) {
+            return field.generate_initialization(false);
+        }
+
+        let field_name = field.name();
+        let value = field.wrap_builder_value(&quote! { __default_struct.#field_name });
+        Ok(quote! { #field_name: #value, })
+    }
+
+    /// Generates the statement evaluating the `default_struct` template.
+    ///
+    /// Placed before the initialization of a new builder, so the fields
+    /// initialized by [`Self::generate_optional_field_initialization`] can move
+    /// their values out of it.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the `let` statement, or empty if no field
+    /// is taken from a template.
+    pub fn generate_default_struct_binding(&self) -> TokenStream {
+        let Some(default_struct) = self.analysis.struct_attributes().get_default_struct() else {
+            return quote! {};
+        };
+        if !self
+            .analysis
+            .optional_fields()
+            .iter()
+            .any(|field| self.takes_default_from_struct(field))
+        {
+            return quote! {};
+        }
+
+        // `Self` names the struct inside the template, not the builder; its
+        // generics are inferred from the binding's type
+        let struct_name = self.analysis.struct_name();
+        let type_generics = self.type_generics_tokens();
+        let default_struct =
+            replace_self_type(quote! { #default_struct }, &quote! { #struct_name });
+        quote! {
+            let __default_struct: #struct_name #type_generics = #default_struct;
+        }
+    }
+
+    /// Generates the statement evaluating the struct-level `base` in the build method.
+    ///
+    /// Placed before the struct is constructed, so the fields that were never
+    /// set can move their values out of it.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the `let` statement, or empty if no field
+    /// is taken from a base.
+    pub fn generate_base_binding(&self) -> TokenStream {
+        let Some(base) = self.analysis.struct_attributes().get_base() else {
+            return quote! {};
+        };
+        if !self
+            .analysis
+            .all_fields()
+            .any(|field| field.attributes().from_base)
+        {
+            return quote! {};
+        }
+
+        // As in the `default_struct` template, `Self` names the struct
+        let struct_name = self.analysis.struct_name();
+        let type_generics = self.type_generics_tokens();
+        let base = replace_self_type(quote! { #base }, &quote! { #struct_name });
+        quote! {
+            let __base: #struct_name #type_generics = #base;
+        }
+    }
+
+    /// Generates the `fill_from` method shared by every builder state.
+    ///
+    /// The method clones the optional fields that were not set from an
+    /// existing instance, leaving the fields that were set untouched.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the method, or empty without the
+    /// struct-level `fill_from`.
+    pub fn generate_fill_from_method(&self) -> TokenStream {
+        if !self.analysis.struct_attributes().get_fill_from() {
+            return quote! {};
+        }
+
+        let struct_name = self.analysis.struct_name();
+        let type_generics = self.type_generics_tokens();
+        let fallback = syn::Ident::new("fallback", proc_macro2::Span::call_site());
+        let statements: Vec<TokenStream> = self
+            .analysis
+            .optional_fields()
+            .iter()
+            .map(|field| field.generate_fill_from_statement(&fallback))
+            .filter(|statement| !statement.is_empty())
+            .collect();
+        let doc = self.generate_method_documentation(
+            "fill_from",
+            &format!(
+                "Takes the optional fields that were not set from an existing `{struct_name}`"
+            ),
+            Some(
+                "Fields that were set, or filled in by an earlier call, keep their values, \
+                 so layers of configuration can be applied from the most specific one down.",
+            ),
+        );
+
+        if statements.is_empty() {
+            return quote! {
+                #doc
+                pub fn fill_from(self, #fallback: &#struct_name #type_generics) -> Self {
+                    let _ = #fallback;
+                    self
+                }
+            };
+        }
+
+        let clone_bounds = self.fill_from_clone_bounds();
+        quote! {
+            #doc
+            pub fn fill_from(mut self, #fallback: &#struct_name #type_generics) -> Self
+            where
+                #(#clone_bounds,)*
+            {
+                #(#statements)*
+                self
+            }
+        }
     }
 
     // Documentation generation methods
@@ -202,7 +637,7 @@ impl<'a> TokenGenerator<'a> {
         additional_info: Option<&str>,
     ) -> TokenStream {
         if !self.config.include_documentation {
-            return quote! {};
+            return self.undocumented_item_tokens();
         }
 
         let mut doc_lines = vec![format!("{description}.")];
@@ -273,6 +708,206 @@ impl<'a> TokenGenerator<'a> {
         quote! { #[doc = #doc_text] }
     }
 
+    /// Generates the attribute placed on public items instead of their docs under `no_docs`.
+    ///
+    /// Builder items are public whenever the struct is, so crates running
+    /// `#![deny(missing_docs)]` would otherwise reject the undocumented items.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing `#[allow(missing_docs)]`.
+    pub fn undocumented_item_tokens(&self) -> TokenStream {
+        quote! { #[allow(missing_docs)] }
+    }
+
+    /// Generates a `# Fields` section summarizing the documented fields.
+    ///
+    /// Each field with doc comments is listed with the first line of its
+    /// documentation, so the build method mentions what it assembles.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the section, or empty if documentation is
+    /// disabled or no field is documented.
+    pub fn generate_fields_summary_documentation(&self) -> TokenStream {
+        if !self.config.include_documentation {
+            return quote! {};
+        }
+
+        let entries: Vec<String> = self
+            .analysis
+            .all_fields()
+            .filter_map(|field| {
+                let summary = field.doc_summary()?;
+                let name = field.clean_name();
+                let requirement = if field.is_required() {
+                    " (required)"
+                } else {
+                    ""
+                };
+                let deprecation = if field.is_deprecated() {
+                    " (deprecated)"
+                } else {
+                    ""
+                };
+                Some(format!("- `{name}`{requirement}{deprecation}: {summary}"))
+            })
+            .collect();
+
+        if entries.is_empty() {
+            return quote! {};
+        }
+
+        quote! {
+            #[doc = ""]
+            #[doc = "# Fields"]
+            #[doc = ""]
+            #(#[doc = #entries])*
+        }
+    }
+
+    /// Generates `# Required` and `# Optional` sections listing the setters.
+    ///
+    /// Placed on `builder()`, so the entry point shows at a glance which
+    /// setters must be called before the build method becomes available.
+    /// Each setter is followed by the first line of its field's documentation.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the sections, or empty if documentation is
+    /// disabled or no setter is generated.
+    pub fn generate_setters_summary_documentation(&self) -> TokenStream {
+        if !self.config.include_documentation {
+            return quote! {};
+        }
+
+        let setter_prefix = self.analysis.struct_attributes().get_setter_prefix();
+        let mut required = Vec::new();
+        let mut optional = Vec::new();
+        for field in self.analysis.all_fields() {
+            // The builder_method field is set by the entry point itself
+            if !field.should_generate_setter() || field.attributes().builder_method {
+                continue;
+            }
+
+            let setter_name = field.final_setter_name(setter_prefix);
+            let mut variants = vec![format!("`{setter_name}`")];
+            // Derived names are formed from the name without its `r#`
+            let setter_name = strip_raw_identifier_prefix(&setter_name);
+            if field.attributes().nested || field.attributes().lazy_setter {
+                variants.push(format!("`{setter_name}_with`"));
+            }
+            if field.attributes().try_setter {
+                variants.push(format!("`try_{setter_name}`"));
+            }
+            if field.attributes().also_ref {
+                variants.push(format!("`{setter_name}_ref`"));
+            }
+            let mut entry = format!("- {}", variants.join(" / "));
+            if let Some(summary) = field.doc_summary() {
+                entry.push_str(&format!(": {summary}"));
+            }
+
+            if field.is_required() {
+                required.push(entry);
+            } else {
+                optional.push(entry);
+            }
+        }
+
+        let mut doc_lines = Vec::new();
+        for (heading, entries) in [("# Required", required), ("# Optional", optional)] {
+            if entries.is_empty() {
+                continue;
+            }
+            doc_lines.push(String::new());
+            doc_lines.push(heading.to_string());
+            doc_lines.push(String::new());
+            doc_lines.extend(entries);
+        }
+
+        quote! { #(#[doc = #doc_lines])* }
+    }
+
+    /// Generates the documentation section for the constructors of a
+    /// `#[non_exhaustive]` struct.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the section, or empty if documentation is
+    /// disabled or the struct is exhaustive.
+    pub fn generate_non_exhaustive_documentation(&self) -> TokenStream {
+        if !self.config.include_documentation || !self.analysis.is_non_exhaustive() {
+            return quote! {};
+        }
+
+        let struct_name = self.analysis.struct_name();
+        let doc_lines = [
+            String::new(),
+            "# Non-exhaustive".to_string(),
+            String::new(),
+            format!(
+                "`{struct_name}` is `#[non_exhaustive]`, so other crates construct it through \
+                 this method. Optional fields added later get their own setters and leave \
+                 existing calls unchanged."
+            ),
+        ];
+        quote! { #(#[doc = #doc_lines])* }
+    }
+
+    /// Generates the `#[non_exhaustive]` attribute for the builder types.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the attribute, or empty if the builder types
+    /// are exhaustive.
+    pub fn generate_non_exhaustive_attribute(&self) -> TokenStream {
+        if self.analysis.builder_non_exhaustive() {
+            quote! { #[non_exhaustive] }
+        } else {
+            quote! {}
+        }
+    }
+
+    /// Generates the serde derives requested with `derive(Serialize, Deserialize)`.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the derive attributes, or empty if no serde
+    /// trait was requested.
+    pub fn generate_serde_derive_attribute(&self) -> TokenStream {
+        let struct_attributes = self.analysis.struct_attributes();
+        let serialize = if struct_attributes.get_derive_serialize() {
+            quote! { #[derive(::serde::Serialize)] }
+        } else {
+            quote! {}
+        };
+        let deserialize = if struct_attributes.get_derive_deserialize() {
+            quote! { #[derive(::serde::Deserialize)] }
+        } else {
+            quote! {}
+        };
+        quote! { #serialize #deserialize }
+    }
+
+    /// Generates `#[serde(skip)]` for builder fields that hold no value.
+    ///
+    /// Unset required fields, computed fields and the PhantomData marker are
+    /// left out, so every state writes the same names for the values it holds.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the attribute, or empty if the builder types
+    /// derive no serde trait.
+    pub fn generate_serde_skip_attribute(&self) -> TokenStream {
+        let struct_attributes = self.analysis.struct_attributes();
+        if struct_attributes.get_derive_serialize() || struct_attributes.get_derive_deserialize() {
+            quote! { #[serde(skip)] }
+        } else {
+            quote! {}
+        }
+    }
+
     // Code generation utility methods
 
     /// Generates appropriate type paths based on configuration.
@@ -314,102 +949,2167 @@ impl<'a> TokenGenerator<'a> {
         }
     }
 
-    /// Generates Debug implementation if configured.
+    /// Generates Debug implementation if configured.
+    ///
+    /// Every field except those marked `#[builder(skip_debug)]` is printed.
+    /// Field types are not required to implement `Debug`: a field whose type
+    /// is not known to implement it (including unbounded generic parameters)
+    /// prints as `<non-Debug>`, so callback and trait-object fields never stop
+    /// the builder from being `Debug`. Skipped fields are elided with `..`,
+    /// and `#[builder(redact)]` and `#[builder(sensitive)]` fields print as `"***"`.
+    /// A `#[builder(lazy_setter)]` field holding a closure prints as `"<deferred>"`,
+    /// and `#[builder(computed)]` fields print as `"<computed>"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_name` - The type to generate Debug impl for
+    /// * `type_generics` - Generic parameters for the type
+    /// * `unset_fields` - Required fields still stored as `Option<T>` in this builder
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the Debug implementation or empty if disabled.
+    pub fn generate_debug_impl(
+        &self,
+        type_name: &TokenStream,
+        type_generics: &TokenStream,
+        unset_fields: &[&syn::Ident],
+    ) -> TokenStream {
+        if !self.config.generate_debug_impls {
+            return quote! {};
+        }
+
+        let impl_generics = self.impl_generics_tokens();
+        let where_clause = self.where_clause_tokens();
+
+        let mut has_skipped_fields = false;
+        let mut debug_fields = Vec::new();
+        for field in self.analysis.all_fields() {
+            if field.attributes().skip_debug {
+                has_skipped_fields = true;
+                continue;
+            }
+            let field_name = field.name();
+            let label = field.clean_name();
+            if field.attributes().redact || field.attributes().sensitive {
+                debug_fields.push(quote! {
+                    .field(#label, &"***")
+                });
+            } else if field.attributes().computed.is_some() {
+                debug_fields.push(quote! {
+                    .field(#label, &"<computed>")
+                });
+            } else if field.attributes().lazy_setter && !unset_fields.contains(&field_name) {
+                let deferred = if field.is_boxed() {
+                    quote! { &*self.#field_name }
+                } else {
+                    quote! { &self.#field_name }
+                };
+                debug_fields.push(quote! {
+                    .field(#label, match #deferred {
+                        ::core::result::Result::Ok(value) => (&&__DebugField(value)).__as_debug(),
+                        ::core::result::Result::Err(_) => &"<deferred>",
+                    })
+                });
+            } else {
+                debug_fields.push(quote! {
+                    .field(#label, (&&__DebugField(&self.#field_name)).__as_debug())
+                });
+            }
+        }
+
+        let finish = if has_skipped_fields {
+            quote! { finish_non_exhaustive }
+        } else {
+            quote! { finish }
+        };
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::fmt::Debug for #type_name #type_generics #where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    // Autoref specialization: fields print with their own Debug impl
+                    // when it is known to exist, and as a placeholder otherwise.
+                    struct __DebugField<'a, T>(&'a T);
+                    struct __NonDebug;
+                    impl ::core::fmt::Debug for __NonDebug {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                            f.write_str("<non-Debug>")
+                        }
+                    }
+                    trait __ViaDebug<'a> {
+                        fn __as_debug(&self) -> &'a dyn ::core::fmt::Debug;
+                    }
+                    impl<'a, T: ::core::fmt::Debug> __ViaDebug<'a> for &__DebugField<'a, T> {
+                        fn __as_debug(&self) -> &'a dyn ::core::fmt::Debug {
+                            self.0
+                        }
+                    }
+                    trait __ViaPlaceholder {
+                        fn __as_debug(&self) -> &'static dyn ::core::fmt::Debug;
+                    }
+                    impl<T> __ViaPlaceholder for __DebugField<'_, T> {
+                        fn __as_debug(&self) -> &'static dyn ::core::fmt::Debug {
+                            &__NonDebug
+                        }
+                    }
+
+                    f.debug_struct(stringify!(#type_name))
+                        #(#debug_fields)*
+                        .#finish()
+                }
+            }
+        }
+    }
+
+    /// Generates a `zeroize::ZeroizeOnDrop` marker impl when every field is sensitive.
+    ///
+    /// Sensitive fields are held in `Zeroizing` and wiped when the builder is
+    /// dropped; unset required fields hold no value. The builder only makes
+    /// the `ZeroizeOnDrop` promise when that covers all of its fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_name` - The builder type to generate the impl for
+    /// * `type_generics` - Generic parameters for the type
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the impl, or empty if any field is not sensitive.
+    pub fn generate_zeroize_on_drop_impl(
+        &self,
+        type_name: &TokenStream,
+        type_generics: &TokenStream,
+    ) -> TokenStream {
+        if !self
+            .analysis
+            .all_fields()
+            .all(|field| field.attributes().sensitive)
+        {
+            return quote! {};
+        }
+
+        let impl_generics = self.impl_generics_tokens();
+        let where_clause = self.where_clause_tokens();
+
+        quote! {
+            impl #impl_generics ::zeroize::ZeroizeOnDrop for #type_name #type_generics #where_clause {}
+        }
+    }
+
+    // Builder-specific generation methods
+
+    /// Generates a PhantomData field declaration if needed.
+    ///
+    /// This analyzes the struct's generic usage and creates an appropriate
+    /// PhantomData field to maintain generic parameter variance.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the PhantomData field declaration or empty
+    /// if no PhantomData is needed.
+    pub fn generate_phantom_data_field(&self) -> TokenStream {
+        if !self.analysis.needs_phantom_data() {
+            return quote! {};
+        }
+
+        let field_types: Vec<&syn::Type> =
+            self.analysis.all_fields().map(|f| f.field_type()).collect();
+
+        let phantom_data_type = crate::utils::generics::generate_phantom_data_type(
+            field_types.into_iter(),
+            self.analysis.struct_generics(),
+        );
+
+        if phantom_data_type.is_empty() {
+            return quote! {};
+        }
+
+        let field_name = self.get_phantom_data_field_name();
+        let field_ident = syn::parse_str::<syn::Ident>(field_name)
+            .unwrap_or_else(|_| syn::Ident::new("_marker", proc_macro2::Span::call_site()));
+
+        let doc = if self.config.include_documentation {
+            quote! { #[doc = "PhantomData to track generics and lifetimes from the original struct."] }
+        } else {
+            quote! {}
+        };
+
+        let serde_skip = self.generate_serde_skip_attribute();
+
+        quote! {
+            #doc
+            #serde_skip
+            #field_ident: #phantom_data_type,
+        }
+    }
+
+    /// Generates initialization code for PhantomData fields.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing PhantomData initialization or empty if not needed.
+    pub fn generate_phantom_data_init(&self) -> TokenStream {
+        if !self.analysis.needs_phantom_data() {
+            return quote! {};
+        }
+
+        let field_name = self.get_phantom_data_field_name();
+        let field_ident = syn::parse_str::<syn::Ident>(field_name)
+            .unwrap_or_else(|_| syn::Ident::new("_marker", proc_macro2::Span::call_site()));
+
+        let phantom_data_path = self.generate_type_path("PhantomData");
+
+        quote! {
+            #field_ident: #phantom_data_path,
+        }
+    }
+
+    // Extension surface generation methods
+
+    /// Gets the identifiers of the builder state traits.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<(Ident, Ident)>` containing the `{Struct}BuilderState` and
+    /// `{Struct}BuilderFinish` trait identifiers.
+    pub fn state_trait_idents(&self) -> syn::Result<(syn::Ident, syn::Ident)> {
+        let struct_name = self.analysis.struct_name();
+        Ok((
+            syn::parse_str(&format!("{struct_name}BuilderState"))?,
+            syn::parse_str(&format!("{struct_name}BuilderFinish"))?,
+        ))
+    }
+
+    /// Generates `impl From<Builder> for Struct` for a builder type that can build.
+    ///
+    /// This lets APIs accept `impl Into<Struct>` and callers pass a complete
+    /// builder without calling the build method. With validated fields the
+    /// impl is `TryFrom` instead. Nothing is generated under `build_vis`, as
+    /// trait impls cannot restrict who calls them.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder_ident` - The complete builder type
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<TokenStream>` containing the `From` implementation.
+    pub fn generate_from_builder_impl(
+        &self,
+        builder_ident: &syn::Ident,
+    ) -> syn::Result<TokenStream> {
+        if self.analysis.struct_attributes().get_build_vis().is_some() {
+            return Ok(quote! {});
+        }
+
+        let built_type = self.built_type();
+        let build_method_ident = self.owned_build_method_ident()?;
+        let impl_generics = self.impl_generics_tokens();
+        let type_generics = self.type_generics_tokens();
+        let where_clause = self.where_clause_with(&self.build_default_bounds());
+
+        // A validating build can fail, so the conversion is fallible too
+        if let Some(error_ident) = self.validation_error_ident() {
+            return Ok(quote! {
+                #[automatically_derived]
+                impl #impl_generics ::core::convert::TryFrom<#builder_ident #type_generics>
+                    for #built_type #where_clause
+                {
+                    type Error = #error_ident;
+
+                    #[track_caller]
+                    fn try_from(builder: #builder_ident #type_generics) -> ::core::result::Result<Self, #error_ident> {
+                        builder.#build_method_ident()
+                    }
+                }
+            });
+        }
+
+        Ok(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::From<#builder_ident #type_generics>
+                for #built_type #where_clause
+            {
+                fn from(builder: #builder_ident #type_generics) -> Self {
+                    builder.#build_method_ident()
+                }
+            }
+        })
+    }
+
+    /// Generates the method that starts the builder.
+    ///
+    /// This is `builder()` on the struct. For an enum variant it is a method
+    /// named after the variant on the enum's variant selection builder, e.g.
+    /// `MessageBuilder::ping` for `Message::Ping`, documented as selecting the
+    /// variant instead of with `doc`.
+    ///
+    /// # Arguments
+    ///
+    /// * `doc` - The summary documentation of `builder()`
+    /// * `details_doc` - Further documentation of the method, e.g. the setters
+    /// * `builder_ident` - The builder type the method returns
+    /// * `body` - The statements creating the builder
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<TokenStream>` containing the impl block with the method.
+    pub fn generate_entry_method(
+        &self,
+        doc: &TokenStream,
+        details_doc: &TokenStream,
+        builder_ident: &syn::Ident,
+        body: &TokenStream,
+    ) -> syn::Result<TokenStream> {
+        let struct_name = self.analysis.struct_name();
+        let impl_generics = self.impl_generics_tokens();
+        let type_generics = self.type_generics_tokens();
+        let where_clause = self.where_clause_tokens();
+        let const_kw = self.const_keyword();
+        let default_bounds = self.default_bounds_where_clause();
+        let visibility = self.analysis.entry_visibility();
+
+        let Some(variant) = self.analysis.variant_name() else {
+            return Ok(quote! {
+                impl #impl_generics #struct_name #type_generics #where_clause {
+                    #doc
+                    #details_doc
+                    #visibility #const_kw fn builder() -> #builder_ident #type_generics #default_bounds {
+                        #body
+                    }
+                }
+            });
+        };
+
+        let selection_ident = syn::Ident::new(
+            &format!("{struct_name}Builder"),
+            proc_macro2::Span::call_site(),
+        );
+        let method_ident = variant_method_ident(variant);
+        let doc = self.generate_method_documentation(
+            &method_ident.to_string(),
+            &format!("Selects the `{struct_name}::{variant}` variant"),
+            Some("The returned builder sets the variant's fields and builds the enum."),
+        );
+        Ok(quote! {
+            impl #impl_generics #selection_ident #type_generics #where_clause {
+                #doc
+                #details_doc
+                #visibility #const_kw fn #method_ident(self) -> #builder_ident #type_generics #default_bounds {
+                    #body
+                }
+            }
+        })
+    }
+
+    /// Generates the build method of a builder state that can build.
+    ///
+    /// The method consumes the builder and runs `body`. Under
+    /// `build_by = "clone"` the body moves into a private `__build_owned`
+    /// method instead, and the build method takes `&self` and runs it on a
+    /// clone of the builder's fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `doc` - The documentation attributes of the build method
+    /// * `body` - The statements building the struct from `self`
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<TokenStream>` containing the build method.
+    pub fn generate_build_method(
+        &self,
+        doc: &TokenStream,
+        body: &TokenStream,
+    ) -> syn::Result<TokenStream> {
+        let build_method_ident: syn::Ident =
+            syn::parse_str(self.analysis.struct_attributes().get_build_method_name())?;
+        let const_kw = self.const_keyword();
+        let visibility = self.analysis.build_visibility();
+        let return_type = self.build_return_type();
+
+        // A boxed builder moves the struct into its allocation as it is built
+        let body = if self.analysis.struct_attributes().get_boxed() {
+            quote! { Box::new({ #body }) }
+        } else {
+            body.clone()
+        };
+
+        // Validated fields are checked on the built value before it is returned
+        let (doc, body) = match self.validation_error_ident() {
+            Some(error_ident) => {
+                let value = syn::Ident::new("__value", proc_macro2::Span::call_site());
+                let checks = self
+                    .analysis
+                    .all_fields()
+                    .map(|field| field.generate_validation_statement(&value, &error_ident));
+                let errors_doc = if self.config.include_documentation {
+                    let text = format!(
+                        "# Errors\n\nReturns a [`{error_ident}`] naming the first field \
+                         whose `validate` check fails."
+                    );
+                    quote! { #[doc = ""] #[doc = #text] }
+                } else {
+                    quote! {}
+                };
+                (
+                    quote! { #doc #errors_doc },
+                    quote! {
+                        let #value = { #body };
+                        #(#checks)*
+                        ::core::result::Result::Ok(#value)
+                    },
+                )
+            }
+            None => (doc.clone(), body),
+        };
+
+        let build_bounds = self.build_default_bounds();
+        let track_caller = self.track_caller_tokens();
+        if !self.analysis.struct_attributes().get_build_by_clone() {
+            return Ok(quote! {
+                #doc
+                #track_caller
+                #visibility #const_kw fn #build_method_ident(self) -> #return_type
+                where
+                    #(#build_bounds,)*
+                {
+                    #body
+                }
+            });
+        }
+
+        let (builder_clone, clone_bounds) = self.generate_builder_clone_tokens(false);
+        Ok(quote! {
+            #doc
+            #track_caller
+            #visibility fn #build_method_ident(&self) -> #return_type
+            where
+                #(#clone_bounds,)*
+                #(#build_bounds,)*
+            {
+                #builder_clone.__build_owned()
+            }
+
+            #track_caller
+            fn __build_owned(self) -> #return_type
+            where
+                #(#build_bounds,)*
+            {
+                #body
+            }
+        })
+    }
+
+    /// Gets the identifier of the error returned by a validating build method.
+    ///
+    /// # Returns
+    ///
+    /// `Some({Struct}ValidationError)` if any field is checked in the build
+    /// method, otherwise `None`.
+    pub fn validation_error_ident(&self) -> Option<syn::Ident> {
+        self.analysis
+            .has_validated_fields()
+            .then(|| self.validation_error_type_ident())
+    }
+
+    /// Gets the identifier of the error returned when a field check fails.
+    ///
+    /// Unlike [`Self::validation_error_ident`], this is also the error of the
+    /// setters that validate under `validate_on_set`.
+    ///
+    /// # Returns
+    ///
+    /// The `{Struct}ValidationError` identifier.
+    pub fn validation_error_type_ident(&self) -> syn::Ident {
+        quote::format_ident!("{}ValidationError", self.analysis.struct_name())
+    }
+
+    /// Generates the type the build method returns.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the built type, wrapped in a `Result` with
+    /// the validation error when fields are validated.
+    pub fn build_return_type(&self) -> TokenStream {
+        let built_type = self.built_type();
+        match self.validation_error_ident() {
+            Some(error_ident) => {
+                quote! { ::core::result::Result<#built_type, #error_ident> }
+            }
+            None => built_type,
+        }
+    }
+
+    /// Generates the `#[track_caller]` attribute of the methods that build.
+    ///
+    /// A validating build method returns an error recording where it was
+    /// called, so it and every method calling it are `#[track_caller]` to pass
+    /// the caller's location down to the checks.
+    ///
+    /// # Returns
+    ///
+    /// `#[track_caller]` when fields are validated, otherwise empty.
+    pub fn track_caller_tokens(&self) -> TokenStream {
+        if self.validation_error_ident().is_some() {
+            quote! { #[track_caller] }
+        } else {
+            quote! {}
+        }
+    }
+
+    /// Generates the type of the value the build method produces.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing `Box<Struct>` under `boxed`, otherwise the
+    /// struct type.
+    pub fn built_type(&self) -> TokenStream {
+        let struct_name = self.analysis.struct_name();
+        let type_generics = self.type_generics_tokens();
+        if self.analysis.struct_attributes().get_boxed() {
+            quote! { Box<#struct_name #type_generics> }
+        } else {
+            quote! { #struct_name #type_generics }
+        }
+    }
+
+    /// Generates the `std::error::Error` implementation of a generated error type.
+    ///
+    /// Every error type the macro generates follows the same policy: the
+    /// implementation is emitted with the crate's `std` feature only, which is
+    /// off by default so the generated code keeps working in `no_std` crates.
+    /// Before Rust 1.81 the trait is not in `core`, so no single implementation
+    /// works in both.
+    ///
+    /// # Arguments
+    ///
+    /// * `error_ident` - The generated error type
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the implementation, or empty without `std`.
+    pub fn generate_error_trait_impl(&self, error_ident: &syn::Ident) -> TokenStream {
+        if cfg!(feature = "std") {
+            quote! {
+                #[automatically_derived]
+                impl ::std::error::Error for #error_ident {}
+            }
+        } else {
+            quote! {}
+        }
+    }
+
+    /// Generates the error type returned when a field check fails.
+    ///
+    /// The error names the rejected field, carries its `validate_message` and
+    /// records where the build method or validating setter was called, which
+    /// are `#[track_caller]`. It implements `Display`, and `std::error::Error`
+    /// with the `std` feature, see [`Self::generate_error_trait_impl`].
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the error type, or empty if no field is validated.
+    pub fn generate_validation_error_type(&self) -> TokenStream {
+        if !self.analysis.has_validated_fields() && !self.analysis.has_setter_validated_fields() {
+            return quote! {};
+        }
+        let error_ident = self.validation_error_type_ident();
+
+        let struct_name = self.analysis.struct_name();
+        let visibility = self.analysis.builder_visibility();
+        let (doc, field_doc, message_doc, location_doc) = if self.config.include_documentation {
+            let doc = format!(
+                "The error returned when building a `{struct_name}` with an invalid field."
+            );
+            (
+                quote! { #[doc = #doc] },
+                quote! { #[doc = "The name of the field whose value was rejected."] },
+                quote! { #[doc = "Why the value was rejected."] },
+                quote! { #[doc = "Where the method that rejected the value was called."] },
+            )
+        } else {
+            let undocumented = self.undocumented_item_tokens();
+            (
+                undocumented.clone(),
+                undocumented.clone(),
+                undocumented.clone(),
+                undocumented,
+            )
+        };
+        let error_trait_impl = self.generate_error_trait_impl(&error_ident);
+
+        quote! {
+            #doc
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq
+            )]
+            #visibility struct #error_ident {
+                #field_doc
+                pub field: &'static str,
+                #message_doc
+                pub message: &'static str,
+                #location_doc
+                pub location: &'static ::core::panic::Location<'static>,
+            }
+
+            #[automatically_derived]
+            impl ::core::fmt::Display for #error_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::write!(f, "invalid value for field `{}`: {}", self.field, self.message)
+                }
+            }
+
+            #error_trait_impl
+        }
+    }
+
+    /// Gets the identifier of the error returned by `apply_env`.
+    ///
+    /// # Returns
+    ///
+    /// The `{Struct}EnvError` identifier.
+    pub fn env_error_type_ident(&self) -> syn::Ident {
+        quote::format_ident!("{}EnvError", self.analysis.struct_name())
+    }
+
+    /// Generates the error type returned when `apply_env` cannot use a variable.
+    ///
+    /// The error names the environment variable and says why its value was
+    /// rejected. Reading and parsing the variables goes through hidden helpers
+    /// on the error type, so the methods of every builder state stay short.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the error type, or empty without the
+    /// struct-level `apply_env`.
+    pub fn generate_env_error_type(&self) -> TokenStream {
+        if !self.analysis.struct_attributes().get_apply_env() {
+            return quote! {};
+        }
+        let error_ident = self.env_error_type_ident();
+
+        let struct_name = self.analysis.struct_name();
+        let visibility = self.analysis.builder_visibility();
+        let (doc, variable_doc, message_doc) = if self.config.include_documentation {
+            let doc = format!(
+                "The error returned when an environment variable read for a `{struct_name}` is invalid."
+            );
+            (
+                quote! { #[doc = #doc] },
+                quote! { #[doc = "The name of the environment variable."] },
+                quote! { #[doc = "Why its value was rejected."] },
+            )
+        } else {
+            let undocumented = self.undocumented_item_tokens();
+            (undocumented.clone(), undocumented.clone(), undocumented)
+        };
+        let error_trait_impl = self.generate_error_trait_impl(&error_ident);
+
+        quote! {
+            #doc
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::clone::Clone,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq
+            )]
+            #visibility struct #error_ident {
+                #variable_doc
+                pub variable: ::std::string::String,
+                #message_doc
+                pub message: ::std::string::String,
+            }
+
+            impl #error_ident {
+                #[doc(hidden)]
+                pub fn __read(
+                    prefix: &str,
+                    name: &str,
+                ) -> ::core::result::Result<
+                    ::core::option::Option<(::std::string::String, ::std::string::String)>,
+                    Self,
+                > {
+                    let variable = if prefix.is_empty() {
+                        ::std::string::ToString::to_string(name)
+                    } else {
+                        ::std::format!("{prefix}_{name}")
+                    };
+                    match ::std::env::var(&variable) {
+                        ::core::result::Result::Ok(value) => {
+                            ::core::result::Result::Ok(::core::option::Option::Some((variable, value)))
+                        }
+                        ::core::result::Result::Err(::std::env::VarError::NotPresent) => {
+                            ::core::result::Result::Ok(::core::option::Option::None)
+                        }
+                        ::core::result::Result::Err(error) => ::core::result::Result::Err(Self {
+                            variable,
+                            message: ::std::string::ToString::to_string(&error),
+                        }),
+                    }
+                }
+
+                #[doc(hidden)]
+                pub fn __parse<__T>(variable: &str, value: &str) -> ::core::result::Result<__T, Self>
+                where
+                    __T: ::core::str::FromStr,
+                    <__T as ::core::str::FromStr>::Err: ::core::fmt::Display,
+                {
+                    <__T as ::core::str::FromStr>::from_str(value).map_err(|error| Self {
+                        variable: ::std::string::ToString::to_string(variable),
+                        message: ::std::string::ToString::to_string(&error),
+                    })
+                }
+            }
+
+            #[automatically_derived]
+            impl ::core::fmt::Display for #error_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::write!(
+                        f,
+                        "invalid value in environment variable `{}`: {}",
+                        self.variable,
+                        self.message
+                    )
+                }
+            }
+
+            #error_trait_impl
+        }
+    }
+
+    /// Generates the `apply_env` method shared by every builder state.
+    ///
+    /// The method reads `{PREFIX}_{FIELD}` for every optional field with a
+    /// setter and without `skip_env` or `dyn_into`, parses the value into the
+    /// setter's input type and calls the setter. `Option<T>` fields parse `T`,
+    /// setters taking a reference receive one, and variables that are not set
+    /// are skipped.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<TokenStream>` containing the method, or empty without
+    /// the struct-level `apply_env`.
+    pub fn generate_apply_env_method(&self) -> syn::Result<TokenStream> {
+        use crate::utils::field_utils::extract_option_inner_type;
+
+        if !self.analysis.struct_attributes().get_apply_env() {
+            return Ok(quote! {});
+        }
+        let error_ident = self.env_error_type_ident();
+        let struct_setter_prefix = self.analysis.struct_attributes().get_setter_prefix();
+
+        let mut statements = Vec::new();
+        let mut bounds = Vec::new();
+        for field in self.analysis.optional_fields() {
+            // Trait objects cannot be parsed from a string
+            if !field.should_generate_setter()
+                || field.attributes().skip_env
+                || field.attributes().dyn_into
+            {
+                continue;
+            }
+            let setter_ident: syn::Ident =
+                syn::parse_str(&field.final_setter_name(struct_setter_prefix))?;
+            let variable_name = field.clean_name().to_uppercase();
+            let cfg = field.setter_cfg_tokens();
+
+            // The setter's input decides what the value is parsed into
+            let input_type: syn::Type = syn::parse2(field.setter_input_type())?;
+            let (parsed_type, argument) = match &input_type {
+                syn::Type::Reference(reference) => {
+                    let target = &*reference.elem;
+                    if quote!(#target).to_string() == "str" {
+                        (None, quote! { &value })
+                    } else {
+                        (Some(target.clone()), quote! { &parsed })
+                    }
+                }
+                _ if field.attributes().clone_from_ref => {
+                    (Some(input_type.clone()), quote! { &parsed })
+                }
+                _ if field.attributes().converter.is_none()
+                    && field.attributes().accumulate.is_none()
+                    && field.try_from_input().is_none() =>
+                {
+                    match extract_option_inner_type(&input_type) {
+                        Some(inner) => (
+                            Some(inner.clone()),
+                            quote! { ::core::option::Option::Some(parsed) },
+                        ),
+                        None => (Some(input_type.clone()), quote! { parsed }),
+                    }
+                }
+                _ => (Some(input_type.clone()), quote! { parsed }),
+            };
+            let parse = parsed_type.as_ref().map(|parsed_type| {
+                bounds.push(quote! { #parsed_type: ::core::str::FromStr });
+                bounds.push(quote! {
+                    <#parsed_type as ::core::str::FromStr>::Err: ::core::fmt::Display
+                });
+                quote! {
+                    let parsed = #error_ident::__parse::<#parsed_type>(&variable, &value)?;
+                }
+            });
+            if let Some(error_type) = field.try_from_error_type() {
+                bounds.push(quote! { #error_type: ::core::fmt::Display });
+            }
+            let checked = if field.has_fallible_setter() {
+                quote! {
+                    .map_err(|error| #error_ident {
+                        variable: ::core::clone::Clone::clone(&variable),
+                        message: ::std::string::ToString::to_string(&error),
+                    })?
+                }
+            } else {
+                quote! {}
+            };
+            let variable = if parse.is_some() || field.has_fallible_setter() {
+                quote! { variable }
+            } else {
+                quote! { _ }
+            };
+
+            statements.push(quote! {
+                #cfg
+                if let ::core::option::Option::Some((#variable, value)) =
+                    #error_ident::__read(prefix, #variable_name)?
+                {
+                    #parse
+                    builder = builder.#setter_ident(#argument)#checked;
+                }
+            });
+        }
+
+        let doc = self.generate_method_documentation(
+            "apply_env",
+            "Sets the optional fields from the environment variables `{PREFIX}_{FIELD}`",
+            Some(
+                "Each value is parsed with `FromStr` and passed to the field's setter. \
+                 Variables that are not set are skipped, and an empty prefix reads `{FIELD}`.\n\n\
+                 # Errors\n\n\
+                 Returns an error naming the variable if its value is not unicode or cannot be parsed.",
+            ),
+        );
+        let where_clause = if bounds.is_empty() {
+            quote! {}
+        } else {
+            quote! { where #(#bounds),* }
+        };
+        Ok(quote! {
+            #doc
+            pub fn apply_env(self, prefix: &str) -> ::core::result::Result<Self, #error_ident>
+            #where_clause
+            {
+                #[allow(unused_mut)]
+                let mut builder = self;
+                #(#statements)*
+                ::core::result::Result::Ok(builder)
+            }
+        })
+    }
+
+    /// Generates the receiver of the build method.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing `&self` under `build_by = "clone"`, or `self`.
+    pub fn build_receiver_tokens(&self) -> TokenStream {
+        if self.analysis.struct_attributes().get_build_by_clone() {
+            quote! { &self }
+        } else {
+            quote! { self }
+        }
+    }
+
+    /// Gets the method that consumes a complete builder and builds the struct.
+    ///
+    /// This is the build method itself, unless `build_by = "clone"` makes it
+    /// borrow the builder, in which case the private `__build_owned` method
+    /// consumes it without requiring `Clone`. Generated code that owns the
+    /// builder, such as finishers and the `From` impl, calls this method.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<syn::Ident>` containing the method name.
+    pub fn owned_build_method_ident(&self) -> syn::Result<syn::Ident> {
+        if self.analysis.struct_attributes().get_build_by_clone() {
+            Ok(syn::Ident::new(
+                "__build_owned",
+                proc_macro2::Span::call_site(),
+            ))
+        } else {
+            syn::parse_str(self.analysis.struct_attributes().get_build_method_name())
+        }
+    }
+
+    /// Generates an expression cloning the builder's fields into a new builder.
+    ///
+    /// Only valid on the states that can build, where every field holds its
+    /// storage type.
+    ///
+    /// # Arguments
+    ///
+    /// * `deferred` - Whether to wrap the bounds in `for<'__a>`, which defers
+    ///   them to the call site so a method with a non-`Clone` field still
+    ///   compiles, it just cannot be called
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the `Self { .. }` expression and the `Clone` bounds on the
+    /// storage types it needs.
+    fn generate_builder_clone_tokens(&self, deferred: bool) -> (TokenStream, Vec<TokenStream>) {
+        let storage_types: Vec<(&syn::Ident, TokenStream)> = self
+            .analysis
+            .all_fields()
+            .map(|field| {
+                let storage_type = field.builder_storage_type();
+                (field.name(), quote! { #storage_type })
+            })
+            .collect();
+        let bounds = self.storage_trait_bounds(
+            storage_types.iter().map(|(_, storage_type)| storage_type),
+            &quote! { ::core::clone::Clone },
+            deferred,
+        );
+        (self.generate_field_clones(&storage_types), bounds)
+    }
+
+    /// Generates a `Self { .. }` expression cloning each of the given fields.
+    ///
+    /// The PhantomData marker, if any, is recreated rather than cloned.
+    fn generate_field_clones(&self, fields: &[(&syn::Ident, TokenStream)]) -> TokenStream {
+        let mut field_clones: Vec<TokenStream> = fields
+            .iter()
+            .map(|(field_name, _)| {
+                quote! { #field_name: ::core::clone::Clone::clone(&self.#field_name) }
+            })
+            .collect();
+
+        if self.analysis.needs_phantom_data() {
+            let marker_ident = syn::Ident::new(
+                self.get_phantom_data_field_name(),
+                proc_macro2::Span::call_site(),
+            );
+            field_clones.push(quote! { #marker_ident: ::core::marker::PhantomData });
+        }
+
+        quote! { Self { #(#field_clones),* } }
+    }
+
+    /// Generates one `Type: Trait` predicate per distinct storage type.
+    ///
+    /// # Arguments
+    ///
+    /// * `storage_types` - The types the builder stores its fields as
+    /// * `trait_path` - The trait each type must implement
+    /// * `deferred` - Whether to wrap the bounds in `for<'__a>`, which defers
+    ///   them to the use site, so a predicate on a concrete type that does not
+    ///   hold disables the item instead of failing to compile
+    fn storage_trait_bounds<'t>(
+        &self,
+        storage_types: impl Iterator<Item = &'t TokenStream>,
+        trait_path: &TokenStream,
+        deferred: bool,
+    ) -> Vec<TokenStream> {
+        let binder = if deferred {
+            quote! { for<'__a> }
+        } else {
+            quote! {}
+        };
+        let lifetimes: Vec<String> = self
+            .analysis
+            .struct_generics()
+            .lifetimes()
+            .map(|param| param.lifetime.ident.to_string())
+            .chain(std::iter::once("static".to_string()))
+            .collect();
+
+        let mut bounds: Vec<TokenStream> = Vec::new();
+        for storage_type in storage_types {
+            // Bounds differing only in lifetimes, such as `&'a str: Clone` and
+            // `&'static str: Clone`, make the compiler's choice between them
+            // ambiguous. Quantifying over the lifetimes lets them collapse into one.
+            let mut renamed = Vec::new();
+            let storage_type =
+                canonicalize_lifetimes(storage_type.clone(), &lifetimes, &mut renamed);
+            let bound = if !renamed.is_empty() {
+                let binders = (0..renamed.len()).map(|index| {
+                    syn::Lifetime::new(&format!("'__l{index}"), proc_macro2::Span::call_site())
+                });
+                quote! { for<#(#binders),*> #storage_type: #trait_path }
+            } else {
+                quote! { #binder #storage_type: #trait_path }
+            };
+            if !bounds
+                .iter()
+                .any(|existing| existing.to_string() == bound.to_string())
+            {
+                bounds.push(bound);
+            }
+        }
+        bounds
+    }
+
+    /// Generates the `Clone` and `Copy` impls requested with `derive(...)`.
+    ///
+    /// Unlike `#[derive]`, the impls are bounded on the stored field types
+    /// rather than on every type parameter, so parameters that only appear in
+    /// the PhantomData marker need no bound. The bounds are deferred: a builder
+    /// state holding a field that is not `Copy` is simply not `Copy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_name` - The builder type to generate the impls for
+    /// * `type_generics` - Generic parameters for the type
+    /// * `unset_fields` - Required fields still stored as `Option<T>` in this builder
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the impls, or empty if none were requested.
+    pub fn generate_derive_impls(
+        &self,
+        type_name: &TokenStream,
+        type_generics: &TokenStream,
+        unset_fields: &[&syn::Ident],
+    ) -> TokenStream {
+        let struct_attributes = self.analysis.struct_attributes();
+        if !struct_attributes.get_derive_clone() {
+            return quote! {};
+        }
+
+        let impl_generics = self.impl_generics_tokens();
+        let storage_types: Vec<(&syn::Ident, TokenStream)> = self
+            .analysis
+            .all_fields()
+            .map(|field| {
+                let storage_type = if unset_fields.contains(&field.name()) {
+                    field.unset_storage_type()
+                } else {
+                    let storage_type = field.builder_storage_type();
+                    quote! { #storage_type }
+                };
+                (field.name(), storage_type)
+            })
+            .collect();
+
+        let clone_bounds = self.storage_trait_bounds(
+            storage_types.iter().map(|(_, storage_type)| storage_type),
+            &quote! { ::core::clone::Clone },
+            true,
+        );
+        let clone_where_clause = self.where_clause_with(&clone_bounds);
+        let field_clones = self.generate_field_clones(&storage_types);
+        let mut tokens = quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::clone::Clone for #type_name #type_generics #clone_where_clause {
+                fn clone(&self) -> Self {
+                    #field_clones
+                }
+            }
+        };
+
+        if struct_attributes.get_derive_copy() {
+            let copy_bounds = self.storage_trait_bounds(
+                storage_types.iter().map(|(_, storage_type)| storage_type),
+                &quote! { ::core::marker::Copy },
+                true,
+            );
+            let copy_where_clause = self.where_clause_with(&copy_bounds);
+            tokens.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics ::core::marker::Copy for #type_name #type_generics #copy_where_clause {}
+            });
+        }
+        tokens
+    }
+
+    /// Generates the build methods that finish the builder besides the build method.
+    ///
+    /// `{build}_into::<U>()` builds the struct and converts it with `U: From<Struct>`,
+    /// so the value can flow into wrapper, newtype or enum targets directly.
+    /// `{build}_clone()` builds from clones of the fields and leaves the
+    /// builder intact.
+    ///
+    /// Each finisher declared with `finishers(...)` calls the build method, then runs its `validate` function
+    /// (propagating the error with `?`) and converts the value with `Into` if
+    /// `into` is set. With validated fields every method returns a `Result`,
+    /// and finishers with an `error` type convert the field validation error
+    /// into it with `?`. The methods are placed next to the build method, so they
+    /// are only available on the builder states that can build.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<TokenStream>` containing the finisher methods.
+    pub fn generate_finisher_methods(&self) -> syn::Result<TokenStream> {
+        let struct_name = self.analysis.struct_name();
+        let type_generics = self.type_generics_tokens();
+        let struct_type = quote! { #struct_name #type_generics };
+        let build_method_name = self.analysis.struct_attributes().get_build_method_name();
+        let build_method_ident: syn::Ident = syn::parse_str(build_method_name)?;
+        let build_into_ident = syn::Ident::new(
+            &format!("{}_into", build_method_name.trim_start_matches("r#")),
+            build_method_ident.span(),
+        );
+        let build_clone_ident = syn::Ident::new(
+            &format!("{}_clone", build_method_name.trim_start_matches("r#")),
+            build_method_ident.span(),
+        );
+        let owned_build_ident = self.owned_build_method_ident()?;
+        let build_visibility = self.analysis.build_visibility();
+
+        let built_type = self.built_type();
+        let build_into_doc = if self.config.include_documentation {
+            let built_name = if self.analysis.struct_attributes().get_boxed() {
+                format!("Box<{struct_name}>")
+            } else {
+                struct_name.to_string()
+            };
+            let text = format!(
+                "Builds the final `{struct_name}` instance and converts it into any type \
+                 implementing `From<{built_name}>`."
+            );
+            quote! { #[doc = #text] }
+        } else {
+            self.undocumented_item_tokens()
+        };
+
+        let validation_error = self.validation_error_ident();
+        let build_return_type = self.build_return_type();
+        let build_bounds = self.build_default_bounds();
+        let track_caller = self.track_caller_tokens();
+
+        let mut methods = match &validation_error {
+            Some(error_ident) => quote! {
+                #build_into_doc
+                #track_caller
+                #build_visibility fn #build_into_ident<__U>(self) -> ::core::result::Result<__U, #error_ident>
+                where
+                    __U: ::core::convert::From<#built_type>,
+                    #(#build_bounds,)*
+                {
+                    self.#owned_build_ident().map(::core::convert::From::from)
+                }
+            },
+            None => quote! {
+                #build_into_doc
+                #build_visibility fn #build_into_ident<__U>(self) -> __U
+                where
+                    __U: ::core::convert::From<#built_type>,
+                    #(#build_bounds,)*
+                {
+                    ::core::convert::From::from(self.#owned_build_ident())
+                }
+            },
+        };
+
+        let build_clone_doc = if self.config.include_documentation {
+            let text = format!(
+                "Builds a `{struct_name}` instance from clones of the fields, leaving \
+                 the builder intact so it can build again.\n\n\
+                 Only callable when every field type implements `Clone`."
+            );
+            quote! { #[doc = #text] }
+        } else {
+            self.undocumented_item_tokens()
+        };
+        let (builder_clone, clone_bounds) = self.generate_builder_clone_tokens(true);
+        methods.extend(quote! {
+            #build_clone_doc
+            #track_caller
+            #build_visibility fn #build_clone_ident(&self) -> #build_return_type
+            where
+                #(#clone_bounds,)*
+                #(#build_bounds,)*
+            {
+                #builder_clone.#owned_build_ident()
+            }
+        });
+        for finisher in self.analysis.struct_attributes().get_finishers() {
+            let name = &finisher.name;
+
+            let (target_type, convert) = match &finisher.into {
+                Some(into) => (
+                    replace_self_type(quote! { #into }, &struct_type),
+                    quote! { ::core::convert::Into::into(value) },
+                ),
+                None => (struct_type.clone(), quote! { value }),
+            };
+
+            // The context is taken by reference and passed on to `validate`
+            let (context_param, context_arg) = match &finisher.context {
+                Some(context) => {
+                    let context = replace_self_type(quote! { #context }, &struct_type);
+                    (quote! { , context: &#context }, quote! { , context })
+                }
+                None => (quote! {}, quote! {}),
+            };
+
+            let (return_type, body, track_caller) = match (&finisher.validate, &finisher.error) {
+                (Some(validate), Some(error)) => {
+                    let error = replace_self_type(quote! { #error }, &struct_type);
+                    (
+                        quote! { ::core::result::Result<#target_type, #error> },
+                        quote! {
+                            #validate(&value #context_arg)?;
+                            ::core::result::Result::Ok(#convert)
+                        },
+                        quote! { #[track_caller] },
+                    )
+                }
+                // Field validation errors are returned as they are
+                _ => match &validation_error {
+                    Some(error_ident) => (
+                        quote! { ::core::result::Result<#target_type, #error_ident> },
+                        quote! { ::core::result::Result::Ok(#convert) },
+                        quote! { #[track_caller] },
+                    ),
+                    None => (target_type, convert, quote! {}),
+                },
+            };
+            // A failed field validation is converted into the finisher's error
+            let build = if validation_error.is_some() {
+                quote! { self.#owned_build_ident()? }
+            } else {
+                quote! { self.#owned_build_ident() }
+            };
+
+            let doc = if self.config.include_documentation {
+                let mut text = format!("Builds the final `{struct_name}` instance");
+                if let Some(validate) = &finisher.validate {
+                    let validate = quote! { #validate }.to_string().replace(' ', "");
+                    text.push_str(&format!(" and validates it with `{validate}`"));
+                    if finisher.context.is_some() {
+                        text.push_str(" using the given context");
+                    }
+                }
+                if finisher.into.is_some() {
+                    text.push_str(", converting it with `Into`");
+                }
+                text.push('.');
+                quote! { #[doc = #text] }
+            } else {
+                self.undocumented_item_tokens()
+            };
+
+            methods.extend(quote! {
+                #doc
+                #track_caller
+                pub fn #name(self #context_param) -> #return_type
+                where
+                    #(#build_bounds,)*
+                {
+                    let value = #build;
+                    #body
+                }
+            });
+        }
+
+        Ok(methods)
+    }
+
+    /// Generates the helper traits that state the bounds of bound-heavy type
+    /// parameters once.
+    ///
+    /// Every builder impl block repeats the struct's bounds, so a struct with N
+    /// required fields would otherwise carry the full bound list in all of its
+    /// 2^N states. Each helper has the bounds as supertraits and a blanket
+    /// implementation, so a single `T: Helper` bound implies all of them.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the helper traits, or empty if there are none.
+    pub fn generate_bound_helper_traits(&self) -> TokenStream {
+        // The helpers sit beside the struct, outside any builder module, and
+        // are exactly as visible as the struct and the builder states
+        let visibility = self.analysis.struct_visibility();
+        self.analysis
+            .bound_helpers()
+            .iter()
+            .map(|helper| {
+                let trait_ident = &helper.trait_ident;
+                let bounds = &helper.bounds;
+                quote! {
+                    #[doc(hidden)]
+                    #visibility trait #trait_ident: #(#bounds)+* {}
+
+                    impl<__Bounded: ?::core::marker::Sized + #(#bounds)+*> #trait_ident for __Bounded {}
+                }
+            })
+            .collect()
+    }
+
+    /// Generates the generic parameter list for type aliases of the builder types.
+    ///
+    /// Type aliases do not enforce bounds, so only the parameter names (and the
+    /// types of const parameters) are emitted.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the alias generics or empty if no generics.
+    ///
+    /// # Examples
+    ///
+    /// For `struct Example<'a, T: Clone, const N: usize>`, generates:
+    /// `< 'a , T , const N : usize >`
+    pub fn alias_generics_tokens(&self) -> TokenStream {
+        let generics = self.analysis.struct_generics();
+        if generics.params.is_empty() {
+            return quote! {};
+        }
+
+        let params = generics.params.iter().map(|param| match param {
+            syn::GenericParam::Lifetime(lifetime_param) => {
+                let lifetime = &lifetime_param.lifetime;
+                quote! { #lifetime }
+            }
+            syn::GenericParam::Type(type_param) => {
+                let ident = &type_param.ident;
+                quote! { #ident }
+            }
+            syn::GenericParam::Const(const_param) => {
+                let ident = &const_param.ident;
+                let ty = &const_param.ty;
+                quote! { const #ident: #ty }
+            }
+        });
+
+        quote! { <#(#params),*> }
+    }
+
+    /// Generates the support items that let other builders nest this one.
+    ///
+    /// Emits hidden `{Struct}BuilderStart` and `{Struct}BuilderReady` aliases for
+    /// the initial and complete builder states, plus a hidden associated function
+    /// that runs a configuration closure against a fresh builder and builds the
+    /// result. Fields marked `#[builder(nested)]` in other structs use these to
+    /// generate their `{setter}_with` methods. Nothing is generated unless the
+    /// struct is `#[builder(nestable)]`, which validation rejects together with
+    /// `builder_method`, `boxed` and fields checked when building, or for an
+    /// enum variant, whose aliases would clash with the other variants'.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_ident` - The builder type returned by `builder()`
+    /// * `ready_ident` - The builder type that has the build method
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<TokenStream>` containing the aliases and the function.
+    pub fn generate_nested_support(
+        &self,
+        start_ident: &syn::Ident,
+        ready_ident: &syn::Ident,
+    ) -> syn::Result<TokenStream> {
+        // Other builders only nest structs that opted in
+        if !self.analysis.struct_attributes().get_nestable()
+            || self.analysis.variant_name().is_some()
+        {
+            return Ok(quote! {});
+        }
+
+        let struct_name = self.analysis.struct_name();
+        let visibility = self.analysis.builder_visibility();
+        let start_alias: syn::Ident = syn::parse_str(&format!("{struct_name}BuilderStart"))?;
+        let ready_alias: syn::Ident = syn::parse_str(&format!("{struct_name}BuilderReady"))?;
+        let build_method_ident = self.owned_build_method_ident()?;
+        let build_visibility = self.analysis.nested_build_visibility();
+        let alias_generics = self.alias_generics_tokens();
+        let impl_generics = self.impl_generics_tokens();
+        let type_generics = self.type_generics_tokens();
+        let where_clause = self.where_clause_tokens();
+        let default_bounds = self.default_field_bounds();
+        let (start_type, ready_type) = self.state_alias_targets(start_ident, ready_ident)?;
+
+        let (start_doc, ready_doc) = if self.config.include_documentation {
+            let start_doc = if self.analysis.struct_attributes().get_no_entry() {
+                format!("The initial builder state of `{struct_name}`, created with `new()`.")
+            } else {
+                format!("The builder state returned by `{struct_name}::builder()`.")
+            };
+            let ready_doc = format!("The builder state that can build `{struct_name}`.");
+            (
+                quote! { #[doc = #start_doc] },
+                quote! { #[doc = #ready_doc] },
+            )
+        } else {
+            let undocumented = self.undocumented_item_tokens();
+            (undocumented.clone(), undocumented)
+        };
+
+        // A public state alias of the same name already names the state
+        let start_alias = if self.is_public_state_alias(&start_alias) {
+            quote! {}
+        } else {
+            quote! {
+                #start_doc
+                #[doc(hidden)]
+                #visibility type #start_alias #alias_generics = #start_type;
+            }
+        };
+        let ready_alias = if self.is_public_state_alias(&ready_alias) {
+            quote! {}
+        } else {
+            quote! {
+                #ready_doc
+                #[doc(hidden)]
+                #visibility type #ready_alias #alias_generics = #ready_type;
+            }
+        };
+
+        Ok(quote! {
+            #start_alias
+            #ready_alias
+
+            impl #impl_generics #struct_name #type_generics #where_clause {
+                #[doc(hidden)]
+                #build_visibility fn __build_nested<__F>(configure: __F) -> Self
+                where
+                    __F: ::core::ops::FnOnce(#start_type) -> #ready_type,
+                    #(#default_bounds,)*
+                {
+                    configure(<#start_type>::new()).#build_method_ident()
+                }
+            }
+        })
+    }
+
+    /// Gets the types that aliases of the initial and complete builder states name.
+    ///
+    /// Hidden states are only reachable through the projection trait.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_ident` - The builder type returned by `builder()`
+    /// * `ready_ident` - The builder type that has the build method
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result` containing the initial and the complete state types.
+    fn state_alias_targets(
+        &self,
+        start_ident: &syn::Ident,
+        ready_ident: &syn::Ident,
+    ) -> syn::Result<(TokenStream, TokenStream)> {
+        let struct_name = self.analysis.struct_name();
+        let type_generics = self.type_generics_tokens();
+        // A regular builder has a single state, which `hide_states` leaves in place
+        if self.analysis.struct_attributes().get_hide_states()
+            && !self.analysis.has_only_optional_fields()
+        {
+            let states_trait = self.hidden_states_trait_ident()?;
+            Ok((
+                quote! { <#struct_name #type_generics as #states_trait>::Start },
+                quote! { <#struct_name #type_generics as #states_trait>::Ready },
+            ))
+        } else {
+            Ok((
+                quote! { #start_ident #type_generics },
+                quote! { #ready_ident #type_generics },
+            ))
+        }
+    }
+
+    /// Checks whether `state_aliases` generates an alias with the given name.
+    fn is_public_state_alias(&self, ident: &syn::Ident) -> bool {
+        let base_name = self.analysis.builder_base_name();
+        self.analysis
+            .struct_attributes()
+            .get_state_aliases()
+            .is_some_and(|aliases| {
+                aliases.start_ident(&base_name) == *ident
+                    || aliases.complete_ident(&base_name) == *ident
+            })
+    }
+
+    /// Generates the public aliases requested with `#[builder(state_aliases)]`.
+    ///
+    /// The aliases name the state the builder starts in and the state with
+    /// every required field set, so code storing or passing builders does not
+    /// spell out the generated state names.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_ident` - The builder state the builder starts in
+    /// * `ready_ident` - The builder state that has the build method
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<TokenStream>` containing the aliases, or nothing if
+    /// `state_aliases` was not given.
+    pub fn generate_state_aliases(
+        &self,
+        start_ident: &syn::Ident,
+        ready_ident: &syn::Ident,
+    ) -> syn::Result<TokenStream> {
+        let Some(aliases) = self.analysis.struct_attributes().get_state_aliases() else {
+            return Ok(quote! {});
+        };
+
+        let struct_name = self.analysis.struct_name();
+        let base_name = self.analysis.builder_base_name();
+        let start_alias = aliases.start_ident(&base_name);
+        let complete_alias = aliases.complete_ident(&base_name);
+        let visibility = self.analysis.builder_visibility();
+        let alias_generics = self.alias_generics_tokens();
+        let (start_type, ready_type) = self.state_alias_targets(start_ident, ready_ident)?;
+
+        let (start_doc, complete_doc) = if self.config.include_documentation {
+            let entry = if let Some(field) = self.analysis.builder_method_field() {
+                format!(
+                    "`{struct_name}::{}()`",
+                    field.final_setter_name(self.analysis.struct_attributes().get_setter_prefix())
+                )
+            } else if self.analysis.struct_attributes().get_no_entry() {
+                format!("`{start_alias}::new()`")
+            } else {
+                format!("`{struct_name}::builder()`")
+            };
+            let start_doc = format!("The `{struct_name}` builder as returned by {entry}.");
+            let complete_doc = format!(
+                "The `{struct_name}` builder with every required field set, which can build `{struct_name}`."
+            );
+            (
+                quote! { #[doc = #start_doc] },
+                quote! { #[doc = #complete_doc] },
+            )
+        } else {
+            let undocumented = self.undocumented_item_tokens();
+            (undocumented.clone(), undocumented)
+        };
+
+        Ok(quote! {
+            #start_doc
+            #visibility type #start_alias #alias_generics = #start_type;
+
+            #complete_doc
+            #visibility type #complete_alias #alias_generics = #ready_type;
+        })
+    }
+
+    /// Generates the implementation of the trait given with `#[builder(buildable)]`.
+    ///
+    /// The trait is declared by the user with `type Builder;` and
+    /// `fn builder() -> Self::Builder;`. The struct implements it with its
+    /// initial builder state, so generic code can start the builder of any
+    /// struct implementing the trait. The `Default` bounds `builder()` needs
+    /// move to the impl, as a trait method cannot add bounds of its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_ident` - The builder type returned by `builder()`
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<TokenStream>` containing the trait implementation, or
+    /// nothing if `buildable` was not given.
+    pub fn generate_buildable_impl(&self, start_ident: &syn::Ident) -> syn::Result<TokenStream> {
+        let Some(buildable) = self.analysis.struct_attributes().get_buildable() else {
+            return Ok(quote! {});
+        };
+
+        let struct_name = self.analysis.struct_name();
+        let impl_generics = self.impl_generics_tokens();
+        let type_generics = self.type_generics_tokens();
+        let where_clause = self.where_clause_with(&self.default_field_bounds());
+        let (start_type, _) = self.state_alias_targets(start_ident, start_ident)?;
+
+        // Missing or mismatched trait items are reported at the trait path
+        Ok(quote_spanned! {buildable.span()=>
+            #[automatically_derived]
+            impl #impl_generics #buildable for #struct_name #type_generics #where_clause {
+                type Builder = #start_type;
+
+                fn builder() -> Self::Builder {
+                    // Inherent methods take precedence over trait methods
+                    Self::builder()
+                }
+            }
+        })
+    }
+
+    /// Gets the identifier of the trait that projects to hidden builder states.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<syn::Ident>` containing `__{Struct}BuilderStates`.
+    pub fn hidden_states_trait_ident(&self) -> syn::Result<syn::Ident> {
+        let struct_name = self.analysis.struct_name();
+        syn::parse_str(&format!("__{struct_name}BuilderStates"))
+    }
+
+    /// Generates the items that name hidden builder states from outside.
+    ///
+    /// With `hide_states` the builder states are declared inside an anonymous
+    /// const, where no path can reach them. A hidden trait implemented for the
+    /// struct projects to the initial and complete states, and the public
+    /// `{Struct}Builder` alias names the initial state through it.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<TokenStream>` containing the trait and the alias.
+    pub fn generate_hidden_state_aliases(&self) -> syn::Result<TokenStream> {
+        let struct_name = self.analysis.struct_name();
+        let visibility = self.analysis.builder_visibility();
+        let states_trait = self.hidden_states_trait_ident()?;
+        let builder_alias: syn::Ident = syn::parse_str(&format!("{struct_name}Builder"))?;
+        let alias_generics = self.alias_generics_tokens();
+        let type_generics = self.type_generics_tokens();
+
+        let alias_doc = if self.config.include_documentation {
+            let text = format!("The initial state of the `{struct_name}` builder.");
+            quote! { #[doc = #text] }
+        } else {
+            self.undocumented_item_tokens()
+        };
+
+        Ok(quote! {
+            #[doc(hidden)]
+            #visibility trait #states_trait {
+                type Start;
+                type Ready;
+            }
+
+            #alias_doc
+            #visibility type #builder_alias #alias_generics =
+                <#struct_name #type_generics as #states_trait>::Start;
+        })
+    }
+
+    /// Generates the projection trait implementation for hidden builder states.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_ident` - The builder state with no required fields set
+    /// * `ready_ident` - The builder state with every required field set
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<TokenStream>` containing the implementation.
+    pub fn generate_hidden_state_impl(
+        &self,
+        start_ident: &syn::Ident,
+        ready_ident: &syn::Ident,
+    ) -> syn::Result<TokenStream> {
+        let struct_name = self.analysis.struct_name();
+        let states_trait = self.hidden_states_trait_ident()?;
+        let impl_generics = self.impl_generics_tokens();
+        let type_generics = self.type_generics_tokens();
+        let where_clause = self.where_clause_tokens();
+
+        Ok(quote! {
+            #[automatically_derived]
+            impl #impl_generics #states_trait for #struct_name #type_generics #where_clause {
+                type Start = #start_ident #type_generics;
+                type Ready = #ready_ident #type_generics;
+            }
+        })
+    }
+
+    /// Generates the builder state trait definitions if `state_traits` is enabled.
+    ///
+    /// `{Struct}BuilderState` is implemented by every builder state and names the
+    /// struct being built. `{Struct}BuilderFinish` is implemented by the states that
+    /// can build the struct and forwards to the build method. Together they give
+    /// downstream crates a stable surface to write extension traits against.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<TokenStream>` containing the trait definitions or empty if disabled.
+    pub fn generate_state_traits(&self) -> syn::Result<TokenStream> {
+        if !self.analysis.struct_attributes().get_state_traits() {
+            return Ok(quote! {});
+        }
+
+        let (state_trait, finish_trait) = self.state_trait_idents()?;
+        let struct_name = self.analysis.struct_name();
+        let build_method_name = self.analysis.struct_attributes().get_build_method_name();
+        let build_method_ident = syn::parse_str::<syn::Ident>(build_method_name)?;
+        let build_receiver = self.build_receiver_tokens();
+        let visibility = self.analysis.builder_visibility();
+        let target = if self.analysis.struct_attributes().get_boxed() {
+            quote! { Box<Self::Target> }
+        } else {
+            quote! { Self::Target }
+        };
+        let build_return_type = match self.validation_error_ident() {
+            Some(error_ident) => quote! { ::core::result::Result<#target, #error_ident> },
+            None => target,
+        };
+
+        let (state_doc, target_doc, finish_doc, build_doc) = if self.config.include_documentation {
+            let state_doc = format!(
+                "Implemented by every builder state of `{struct_name}`.\n\n\
+                 Use this trait as a bound to write extension traits that apply to \
+                 the builder regardless of which required fields have been set."
+            );
+            let target_doc = format!("The type being built, `{struct_name}` with its generics.");
+            let finish_doc = format!(
+                "Implemented by the builder states that can build `{struct_name}`.\n\n\
+                 Use this trait as a bound to write extension traits that consume a \
+                 complete builder."
+            );
+            let build_doc = format!("Builds the final `{struct_name}` instance.");
+            (
+                quote! { #[doc = #state_doc] },
+                quote! { #[doc = #target_doc] },
+                quote! { #[doc = #finish_doc] },
+                quote! { #[doc = #build_doc] },
+            )
+        } else {
+            let undocumented = self.undocumented_item_tokens();
+            (
+                undocumented.clone(),
+                undocumented.clone(),
+                undocumented.clone(),
+                undocumented,
+            )
+        };
+
+        Ok(quote! {
+            #state_doc
+            #visibility trait #state_trait {
+                #target_doc
+                type Target;
+            }
+
+            #finish_doc
+            #visibility trait #finish_trait: #state_trait {
+                #build_doc
+                fn #build_method_ident(#build_receiver) -> #build_return_type;
+            }
+        })
+    }
+
+    /// Generates the builder state trait implementations for a single builder type.
+    ///
+    /// Besides the `state_traits` traits, every builder type implements the
+    /// user traits listed with `impl_trait`. Those impls are empty, so the
+    /// traits' items must all have defaults.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder_ident` - The concrete builder type implementing the traits
+    /// * `can_build` - Whether the builder type has a build method
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<TokenStream>` containing the trait implementations or empty
+    /// if `state_traits` is disabled and no `impl_trait` is given.
+    pub fn generate_state_trait_impls(
+        &self,
+        builder_ident: &syn::Ident,
+        can_build: bool,
+    ) -> syn::Result<TokenStream> {
+        let impl_generics = self.impl_generics_tokens();
+        let type_generics = self.type_generics_tokens();
+        let where_clause = self.where_clause_tokens();
+        let user_trait_impls = self
+            .analysis
+            .struct_attributes()
+            .get_impl_traits()
+            .iter()
+            .map(|user_trait| {
+                // Missing trait items are reported at the trait path
+                quote_spanned! {user_trait.span()=>
+                    #[automatically_derived]
+                    impl #impl_generics #user_trait for #builder_ident #type_generics #where_clause {}
+                }
+            });
+        let user_trait_impls = quote! { #(#user_trait_impls)* };
+
+        if !self.analysis.struct_attributes().get_state_traits() {
+            return Ok(user_trait_impls);
+        }
+
+        let (state_trait, finish_trait) = self.state_trait_idents()?;
+        let struct_name = self.analysis.struct_name();
+
+        let finish_impl = if can_build {
+            let build_method_name = self.analysis.struct_attributes().get_build_method_name();
+            let build_method_ident = syn::parse_str::<syn::Ident>(build_method_name)?;
+            let build_receiver = self.build_receiver_tokens();
+            let build_return_type = self.build_return_type();
+            // A borrowing build method is only available when the fields can be cloned
+            let mut bounds = self.build_default_bounds();
+            if self.analysis.struct_attributes().get_build_by_clone() {
+                let (_, clone_bounds) = self.generate_builder_clone_tokens(false);
+                bounds.extend(clone_bounds);
+            }
+            let where_clause = self.where_clause_with(&bounds);
+            let track_caller = self.track_caller_tokens();
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics #finish_trait for #builder_ident #type_generics #where_clause {
+                    #track_caller
+                    fn #build_method_ident(#build_receiver) -> #build_return_type {
+                        // Inherent methods take precedence over trait methods
+                        Self::#build_method_ident(self)
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        Ok(quote! {
+            #[automatically_derived]
+            impl #impl_generics #state_trait for #builder_ident #type_generics #where_clause {
+                type Target = #struct_name #type_generics;
+            }
+
+            #finish_impl
+            #user_trait_impls
+        })
+    }
+
+    /// Returns the name of the optional setters trait, `{Struct}BuilderSetters`.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<syn::Ident>` containing the trait name.
+    pub fn setter_trait_ident(&self) -> syn::Result<syn::Ident> {
+        let struct_name = self.analysis.struct_name();
+        syn::parse_str(&format!("{struct_name}BuilderSetters"))
+    }
+
+    /// Parses the optional field setters into the methods the setters trait declares.
+    ///
+    /// Only methods taking `self` are kept, which leaves out helper functions
+    /// such as the const converters.
     ///
     /// # Arguments
     ///
-    /// * `type_name` - The type to generate Debug impl for
-    /// * `type_generics` - Generic parameters for the type
+    /// * `setter_methods` - The optional field setters shared by every builder state
     ///
     /// # Returns
     ///
-    /// A `TokenStream` containing the Debug implementation or empty if disabled.
-    pub fn generate_debug_impl(
+    /// A `syn::Result` containing the setter methods.
+    fn setter_trait_methods(
         &self,
-        type_name: &TokenStream,
-        type_generics: &TokenStream,
-    ) -> TokenStream {
-        if !self.config.generate_debug_impls {
-            return quote! {};
+        setter_methods: &TokenStream,
+    ) -> syn::Result<Vec<syn::ImplItemFn>> {
+        let setters: syn::ItemImpl = syn::parse2(quote! { impl Setters { #setter_methods } })?;
+        Ok(setters
+            .items
+            .into_iter()
+            .filter_map(|item| match item {
+                syn::ImplItem::Fn(method) if method.sig.receiver().is_some() => Some(method),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Generates the `{Struct}BuilderSetters` trait declaring the optional field setters.
+    ///
+    /// The trait repeats each setter's signature and documentation. It is
+    /// generic over the struct's parameters, which the setters may mention,
+    /// and the setters of a const builder become regular methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `setter_methods` - The optional field setters shared by every builder state
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<TokenStream>` containing the trait or empty if
+    /// `setter_trait` is disabled.
+    pub fn generate_setter_trait(&self, setter_methods: &TokenStream) -> syn::Result<TokenStream> {
+        if !self.analysis.struct_attributes().get_setter_trait() {
+            return Ok(quote! {});
         }
 
+        let setter_trait = self.setter_trait_ident()?;
         let impl_generics = self.impl_generics_tokens();
         let where_clause = self.where_clause_tokens();
+        let visibility = self.analysis.builder_visibility();
+        let trait_doc = if self.config.include_documentation {
+            let struct_name = self.analysis.struct_name();
+            let doc = format!(
+                "The optional field setters of `{struct_name}`, implemented by every builder state.\n\n\
+                 Use this trait to write helpers that configure the builder regardless of \
+                 which required fields have been set."
+            );
+            quote! { #[doc = #doc] }
+        } else {
+            self.undocumented_item_tokens()
+        };
 
-        quote! {
-            #[automatically_derived]
-            impl #impl_generics ::core::fmt::Debug for #type_name #type_generics #where_clause {
-                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                    f.debug_struct(stringify!(#type_name)).finish()
+        let declarations = self
+            .setter_trait_methods(setter_methods)?
+            .into_iter()
+            .map(|method| {
+                let mut sig = method.sig;
+                sig.constness = None;
+                if let Some(syn::FnArg::Receiver(receiver)) = sig.inputs.first_mut() {
+                    receiver.mutability = None;
                 }
+                let attrs = method.attrs.iter().filter(|attr| {
+                    ["doc", "cfg", "deprecated", "allow"]
+                        .iter()
+                        .any(|name| attr.path().is_ident(name))
+                });
+                quote! {
+                    #(#attrs)*
+                    #sig;
+                }
+            });
+
+        Ok(quote! {
+            #trait_doc
+            #visibility trait #setter_trait #impl_generics: ::core::marker::Sized #where_clause {
+                #(#declarations)*
             }
-        }
+        })
     }
 
-    // Builder-specific generation methods
-
-    /// Generates a PhantomData field declaration if needed.
+    /// Generates the `{Struct}BuilderSetters` implementation for a single builder type.
     ///
-    /// This analyzes the struct's generic usage and creates an appropriate
-    /// PhantomData field to maintain generic parameter variance.
+    /// Each method forwards to the builder's inherent setter of the same name.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder_ident` - The concrete builder type implementing the trait
+    /// * `setter_methods` - The optional field setters shared by every builder state
     ///
     /// # Returns
     ///
-    /// A `TokenStream` containing the PhantomData field declaration or empty
-    /// if no PhantomData is needed.
-    pub fn generate_phantom_data_field(&self) -> TokenStream {
-        if !self.analysis.needs_phantom_data() {
-            return quote! {};
+    /// A `syn::Result<TokenStream>` containing the implementation or empty if
+    /// `setter_trait` is disabled.
+    pub fn generate_setter_trait_impl(
+        &self,
+        builder_ident: &syn::Ident,
+        setter_methods: &TokenStream,
+    ) -> syn::Result<TokenStream> {
+        if !self.analysis.struct_attributes().get_setter_trait() {
+            return Ok(quote! {});
         }
 
-        let field_types: Vec<&syn::Type> =
-            self.analysis.all_fields().map(|f| f.field_type()).collect();
-
-        let phantom_data_type = crate::utils::generics::generate_phantom_data_type(
-            field_types.into_iter(),
-            self.analysis.struct_generics(),
-        );
+        let setter_trait = self.setter_trait_ident()?;
+        let impl_generics = self.impl_generics_tokens();
+        let type_generics = self.type_generics_tokens();
+        let where_clause = self.where_clause_tokens();
 
-        if phantom_data_type.is_empty() {
-            return quote! {};
+        let mut forwards = Vec::new();
+        for method in self.setter_trait_methods(setter_methods)? {
+            let mut sig = method.sig;
+            sig.constness = None;
+            if let Some(syn::FnArg::Receiver(receiver)) = sig.inputs.first_mut() {
+                receiver.mutability = None;
+            }
+            let method_ident = &sig.ident;
+            let arguments = sig
+                .inputs
+                .iter()
+                .skip(1)
+                .map(|input| match input {
+                    syn::FnArg::Typed(syn::PatType { pat, .. }) => match pat.as_ref() {
+                        syn::Pat::Ident(pat_ident) => Ok(&pat_ident.ident),
+                        other => Err(syn::Error::new_spanned(
+                            other,
+                            "setter parameters must be identifiers to be forwarded",
+                        )),
+                    },
+                    syn::FnArg::Receiver(receiver) => Err(syn::Error::new_spanned(
+                        receiver,
+                        "setters take `self` as their first parameter only",
+                    )),
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            let cfg = method
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("cfg"));
+            forwards.push(quote! {
+                #(#cfg)*
+                #[allow(deprecated)]
+                #sig {
+                    // Inherent methods take precedence over trait methods
+                    Self::#method_ident(self, #(#arguments),*)
+                }
+            });
         }
 
-        let field_name = self.get_phantom_data_field_name();
-        let field_ident = syn::parse_str::<syn::Ident>(field_name)
-            .unwrap_or_else(|_| syn::Ident::new("_marker", proc_macro2::Span::call_site()));
-
-        let doc = if self.config.include_documentation {
-            quote! { #[doc = "PhantomData to track generics and lifetimes from the original struct."] }
-        } else {
-            quote! {}
-        };
-
-        quote! {
-            #doc
-            #field_ident: #phantom_data_type,
-        }
+        Ok(quote! {
+            #[automatically_derived]
+            impl #impl_generics #setter_trait #type_generics for #builder_ident #type_generics #where_clause {
+                #(#forwards)*
+            }
+        })
     }
 
-    /// Generates initialization code for PhantomData fields.
+    /// Generates static assertions that every builder state is `Send`, `Sync` and/or `Unpin`.
+    ///
+    /// The assertions live in an unnamed constant, so they cost nothing at runtime
+    /// and a builder that loses an auto trait fails to compile at the struct
+    /// definition rather than in downstream code. Every type parameter is assumed
+    /// to satisfy the asserted traits; the check therefore catches fields such as
+    /// `Rc<T>` or `Cell<T>`, not generic arguments chosen by the caller.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder_idents` - Every concrete builder type generated for the struct
     ///
     /// # Returns
     ///
-    /// A `TokenStream` containing PhantomData initialization or empty if not needed.
-    pub fn generate_phantom_data_init(&self) -> TokenStream {
-        if !self.analysis.needs_phantom_data() {
+    /// A `TokenStream` containing the assertions or empty if no auto trait
+    /// is asserted.
+    pub fn generate_auto_trait_assertions(&self, builder_idents: &[syn::Ident]) -> TokenStream {
+        let struct_attributes = self.analysis.struct_attributes();
+        let mut bounds = Vec::new();
+        if struct_attributes.get_assert_send() {
+            bounds.push(quote! { ::core::marker::Send });
+        }
+        if struct_attributes.get_assert_sync() {
+            bounds.push(quote! { ::core::marker::Sync });
+        }
+        if struct_attributes.get_assert_unpin() {
+            bounds.push(quote! { ::core::marker::Unpin });
+        }
+        if bounds.is_empty() {
             return quote! {};
         }
 
-        let field_name = self.get_phantom_data_field_name();
-        let field_ident = syn::parse_str::<syn::Ident>(field_name)
-            .unwrap_or_else(|_| syn::Ident::new("_marker", proc_macro2::Span::call_site()));
+        let mut generics = self.analysis.struct_generics().clone();
+        // Extend each parameter's bounds where they are already declared, so the
+        // generated function does not trip `clippy::multiple_bound_locations`
+        let where_predicates = generics
+            .where_clause
+            .as_mut()
+            .map(|where_clause| &mut where_clause.predicates);
+        let mut where_bounded: Vec<&mut syn::PredicateType> = where_predicates
+            .into_iter()
+            .flatten()
+            .filter_map(|predicate| match predicate {
+                syn::WherePredicate::Type(predicate_type) => Some(predicate_type),
+                _ => None,
+            })
+            .collect();
+        for param in generics.params.iter_mut() {
+            let syn::GenericParam::Type(param) = param else {
+                continue;
+            };
+            let ident = &param.ident;
+            let target = where_bounded
+                .iter_mut()
+                .find(|predicate| {
+                    matches!(&predicate.bounded_ty, syn::Type::Path(path)
+                        if path.qself.is_none() && path.path.is_ident(ident))
+                })
+                .map(|predicate| &mut predicate.bounds)
+                .unwrap_or(&mut param.bounds);
+            for bound in &bounds {
+                target.push(syn::parse_quote! { #bound });
+            }
+        }
+        let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
 
-        let phantom_data_path = self.generate_type_path("PhantomData");
+        let builder_types: Vec<TokenStream> = builder_idents
+            .iter()
+            .map(|builder_ident| quote! { #builder_ident #type_generics })
+            .collect();
 
+        // Taking the builder types as an argument gives the function the same
+        // implied lifetime bounds (`T: 'a` for `&'a T` fields) as the builders
         quote! {
-            #field_ident: #phantom_data_path,
+            const _: () = {
+                fn __assert_auto_traits<__T: ?::core::marker::Sized #(+ #bounds)*>() {}
+
+                #[allow(dead_code)]
+                fn __assert_builder_auto_traits #impl_generics (
+                    _: ::core::marker::PhantomData<(#(#builder_types,)*)>,
+                ) #where_clause {
+                    #(__assert_auto_traits::<#builder_types>();)*
+                }
+            };
+        }
+    }
+}
+
+/// Gets the name of the method selecting an enum variant.
+///
+/// The variant name in snake case, e.g. `data_frame` for `DataFrame`, as a
+/// raw identifier if it is a keyword. `self`, `super` and `crate` cannot be
+/// raw identifiers and get a trailing underscore instead.
+pub fn variant_method_ident(variant: &syn::Ident) -> syn::Ident {
+    let name = pascal_case_to_snake_case(&strip_raw_identifier_prefix(&variant.to_string()));
+    match name.as_str() {
+        "self" | "super" | "crate" => syn::Ident::new(&format!("{name}_"), variant.span()),
+        _ => syn::parse_str(&name).unwrap_or_else(|_| syn::Ident::new_raw(&name, variant.span())),
+    }
+}
+
+/// Replaces every `Self` in a type or expression with the struct being built.
+///
+/// Inside the generated builder impls `Self` would name the builder, while
+/// `Self` in `finishers(...)` types, the `default_struct` template and the
+/// `base` refers to the struct.
+fn replace_self_type(tokens: TokenStream, struct_type: &TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) if ident == "Self" => struct_type.clone(),
+            proc_macro2::TokenTree::Group(group) => {
+                let mut replaced = proc_macro2::Group::new(
+                    group.delimiter(),
+                    replace_self_type(group.stream(), struct_type),
+                );
+                replaced.set_span(group.span());
+                quote! { #replaced }
+            }
+            other => quote! { #other },
+        })
+        .collect()
+}
+
+/// Renames the given lifetimes in a type to `'__l0`, `'__l1`, ... in order of
+/// first appearance, so types differing only in lifetime names become equal.
+///
+/// # Returns
+///
+/// The rewritten tokens and the renamed lifetimes, to be bound with `for<..>`.
+fn canonicalize_lifetimes(
+    tokens: TokenStream,
+    lifetimes: &[String],
+    renamed: &mut Vec<String>,
+) -> TokenStream {
+    let mut output = TokenStream::new();
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            proc_macro2::TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                output.extend(quote! { #punct });
+                if let Some(proc_macro2::TokenTree::Ident(ident)) = tokens.peek() {
+                    let name = ident.to_string();
+                    if lifetimes.contains(&name) {
+                        let index = match renamed.iter().position(|known| *known == name) {
+                            Some(index) => index,
+                            None => {
+                                renamed.push(name);
+                                renamed.len() - 1
+                            }
+                        };
+                        let ident = syn::Ident::new(&format!("__l{index}"), ident.span());
+                        output.extend(quote! { #ident });
+                        tokens.next();
+                    }
+                }
+            }
+            proc_macro2::TokenTree::Group(group) => {
+                let stream = canonicalize_lifetimes(group.stream(), lifetimes, renamed);
+                let mut replaced = proc_macro2::Group::new(group.delimiter(), stream);
+                replaced.set_span(group.span());
+                output.extend(quote! { #replaced });
+            }
+            other => output.extend(quote! { #other }),
         }
     }
+    output
 }
 
 #[cfg(test)]
@@ -462,6 +3162,34 @@ mod tests {
         assert!(doc_str.contains("Test method description"));
     }
 
+    #[test]
+    fn test_generate_setters_summary_documentation() {
+        let input = parse_quote!(
+            #[builder(setter_prefix = "with_")]
+            struct Example {
+                /// The display name.
+                #[builder(required)]
+                name: String,
+                #[builder(lazy_setter)]
+                cache: usize,
+                #[builder(skip_setter, default = 0)]
+                internal: u8,
+            }
+        );
+        let analysis = analyze_struct(&input).unwrap();
+        let generator = TokenGenerator::new(&analysis);
+
+        let doc_str = generator
+            .generate_setters_summary_documentation()
+            .to_string();
+        assert!(doc_str.contains("# Required"));
+        assert!(doc_str.contains("- `with_name`: The display name."));
+        assert!(doc_str.contains("# Optional"));
+        assert!(doc_str.contains("- `with_cache` / `with_cache_with`"));
+        assert!(!doc_str.contains("internal"));
+        assert!(doc_str.find("# Required") < doc_str.find("# Optional"));
+    }
+
     #[test]
     fn test_generate_method_documentation_minimal() {
         let input = parse_quote!(
@@ -539,6 +3267,129 @@ mod tests {
         assert!(!where_clause.is_empty());
     }
 
+    #[test]
+    fn test_default_field_bounds() {
+        let input = parse_quote! {
+            struct Example<T, U> {
+                #[builder(required)]
+                name: String,
+                value: T,
+                items: Vec<U>,
+                #[builder(default = 0)]
+                count: u32,
+                tags: Vec<String>,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let generator = TokenGenerator::new(&analysis);
+
+        let bounds: Vec<_> = generator
+            .default_field_bounds()
+            .iter()
+            .map(|bound| bound.to_string())
+            .collect();
+        assert_eq!(
+            bounds,
+            [
+                "T : :: core :: default :: Default",
+                "Vec < U > : :: core :: default :: Default",
+            ]
+        );
+        assert!(generator
+            .default_bounds_where_clause()
+            .to_string()
+            .starts_with("where T : :: core :: default :: Default"));
+
+        // Only the bounds in use: the struct's own where clause stays as it was
+        assert!(generator.where_clause_tokens().is_empty());
+    }
+
+    #[test]
+    fn test_fill_from_bounds() {
+        let input = parse_quote! {
+            #[builder(fill_from)]
+            struct Example<T, U> {
+                #[builder(required)]
+                name: String,
+                value: T,
+                extra: Option<T>,
+                #[builder(default = 0)]
+                count: u32,
+                #[builder(builder_only)]
+                scratch: Vec<U>,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let generator = TokenGenerator::new(&analysis);
+
+        let clone_bounds: Vec<_> = generator
+            .fill_from_clone_bounds()
+            .iter()
+            .map(|bound| bound.to_string())
+            .collect();
+        assert_eq!(
+            clone_bounds,
+            [
+                "T : :: core :: clone :: Clone",
+                "Option < T > : :: core :: clone :: Clone",
+            ]
+        );
+
+        let build_bounds: Vec<_> = generator
+            .build_default_bounds()
+            .iter()
+            .map(|bound| bound.to_string())
+            .collect();
+        assert_eq!(
+            build_bounds,
+            [
+                "T : :: core :: default :: Default",
+                "Option < T > : :: core :: default :: Default",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_default_struct_initialization() {
+        let input = parse_quote! {
+            #[builder(default_struct = Self::default())]
+            struct Example<T> {
+                #[builder(required)]
+                name: String,
+                value: T,
+                #[builder(default = 0)]
+                count: u32,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let generator = TokenGenerator::new(&analysis);
+        let [value, count] = analysis.optional_fields() else {
+            panic!("expected two optional fields");
+        };
+
+        assert!(generator.takes_default_from_struct(value));
+        assert!(!generator.takes_default_from_struct(count));
+        assert_eq!(
+            generator
+                .generate_optional_field_initialization(value)
+                .unwrap()
+                .to_string(),
+            "value : __default_struct . value ,"
+        );
+        assert_eq!(
+            generator.generate_default_struct_binding().to_string(),
+            "let __default_struct : Example < T > = Example :: default () ;"
+        );
+
+        // The template replaces the field default, so `T` need not be `Default`
+        let bounds: Vec<_> = generator
+            .default_field_bounds()
+            .iter()
+            .map(|bound| bound.to_string())
+            .collect();
+        assert_eq!(bounds, ["Example < T > : :: core :: default :: Default"]);
+    }
+
     #[test]
     fn test_phantom_data_generation() {
         let input = parse_quote! {
@@ -549,10 +3400,203 @@ mod tests {
         let analysis = analyze_struct(&input).unwrap();
         let generator = TokenGenerator::new(&analysis);
 
+        // T is stored directly in a field
+        assert!(generator.generate_phantom_data_field().is_empty());
+        assert!(generator.generate_phantom_data_init().is_empty());
+
+        // A macro in type position may hide how T is used
+        let input = parse_quote! {
+            struct Example<T> {
+                value: wrapper!(T),
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let generator = TokenGenerator::new(&analysis);
+
         let phantom_field = generator.generate_phantom_data_field();
         let phantom_init = generator.generate_phantom_data_init();
 
         assert!(!phantom_field.is_empty());
         assert!(!phantom_init.is_empty());
     }
+
+    #[test]
+    fn test_state_traits_disabled_by_default() {
+        let input = parse_quote! {
+            struct Example {
+                name: String,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let generator = TokenGenerator::new(&analysis);
+        let builder_ident = syn::parse_str::<syn::Ident>("ExampleBuilder").unwrap();
+
+        assert!(generator.generate_state_traits().unwrap().is_empty());
+        assert!(generator
+            .generate_state_trait_impls(&builder_ident, true)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_state_traits_generation() {
+        let input = parse_quote! {
+            #[builder(state_traits, build_method = "create")]
+            pub struct Example<T> {
+                value: T,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let generator = TokenGenerator::new(&analysis);
+        let builder_ident = syn::parse_str::<syn::Ident>("ExampleBuilder").unwrap();
+
+        let traits = generator.generate_state_traits().unwrap().to_string();
+        assert!(traits.contains("pub trait ExampleBuilderState"));
+        assert!(traits.contains("pub trait ExampleBuilderFinish : ExampleBuilderState"));
+        assert!(traits.contains("fn create (self) -> Self :: Target"));
+
+        let complete = generator
+            .generate_state_trait_impls(&builder_ident, true)
+            .unwrap()
+            .to_string();
+        assert!(complete.contains("type Target = Example < T >"));
+        assert!(complete.contains("ExampleBuilderFinish for ExampleBuilder < T >"));
+
+        let incomplete = generator
+            .generate_state_trait_impls(&builder_ident, false)
+            .unwrap()
+            .to_string();
+        assert!(incomplete.contains("ExampleBuilderState for ExampleBuilder < T >"));
+        assert!(!incomplete.contains("ExampleBuilderFinish"));
+    }
+
+    #[test]
+    fn test_user_trait_impls_generation() {
+        let input = parse_quote! {
+            #[builder(impl_trait = "crate::Described")]
+            pub struct Example<T: Clone> {
+                value: T,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let generator = TokenGenerator::new(&analysis);
+        let builder_ident = syn::parse_str::<syn::Ident>("ExampleBuilder").unwrap();
+
+        let impls = generator
+            .generate_state_trait_impls(&builder_ident, false)
+            .unwrap()
+            .to_string();
+        assert!(
+            impls.contains("impl < T : Clone > crate :: Described for ExampleBuilder < T > { }")
+        );
+        assert!(!impls.contains("ExampleBuilderState"));
+    }
+
+    #[test]
+    fn test_setter_trait_generation() {
+        let input = parse_quote! {
+            #[builder(setter_trait)]
+            pub struct Example<T> {
+                #[builder(required)]
+                value: T,
+                /// The label
+                label: String,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let generator = TokenGenerator::new(&analysis);
+        let builder_ident = syn::parse_str::<syn::Ident>("ExampleBuilder_MissingValue").unwrap();
+        let setters = quote! {
+            pub fn label(mut self, value: String) -> Self {
+                self.label = value;
+                self
+            }
+            const fn convert_label(value: u8) -> u8 {
+                value
+            }
+        };
+
+        let declaration = generator
+            .generate_setter_trait(&setters)
+            .unwrap()
+            .to_string();
+        assert!(declaration
+            .contains("pub trait ExampleBuilderSetters < T > : :: core :: marker :: Sized"));
+        assert!(declaration.contains("fn label (self , value : String) -> Self ;"));
+        assert!(!declaration.contains("convert_label"));
+
+        let implementation = generator
+            .generate_setter_trait_impl(&builder_ident, &setters)
+            .unwrap()
+            .to_string();
+        assert!(implementation
+            .contains("ExampleBuilderSetters < T > for ExampleBuilder_MissingValue < T >"));
+        assert!(implementation.contains("Self :: label (self , value)"));
+
+        let disabled = analyze_struct(&parse_quote! { struct Plain { label: String } }).unwrap();
+        let generator = TokenGenerator::new(&disabled);
+        assert!(generator
+            .generate_setter_trait(&setters)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_auto_trait_assertions_generation() {
+        let input = parse_quote! {
+            struct Example<T> {
+                value: T,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let generator = TokenGenerator::new(&analysis);
+        let builder_ident = syn::parse_str::<syn::Ident>("ExampleBuilder").unwrap();
+        assert!(generator
+            .generate_auto_trait_assertions(std::slice::from_ref(&builder_ident))
+            .is_empty());
+
+        let input = parse_quote! {
+            #[builder(assert_send)]
+            struct Example<T> {
+                value: T,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let generator = TokenGenerator::new(&analysis);
+        let assertions = generator
+            .generate_auto_trait_assertions(std::slice::from_ref(&builder_ident))
+            .to_string();
+        assert!(assertions.contains("< T : :: core :: marker :: Send >"));
+        assert!(!assertions.contains("Sync"));
+        assert!(assertions.contains("__assert_auto_traits :: < ExampleBuilder < T > >"));
+    }
+
+    #[test]
+    fn test_canonicalize_lifetimes() {
+        let lifetimes = vec!["a".to_string(), "b".to_string(), "static".to_string()];
+
+        let mut renamed = Vec::new();
+        let tokens = canonicalize_lifetimes(
+            quote! { Option<(&'b str, &'a T, &'b u8)> },
+            &lifetimes,
+            &mut renamed,
+        );
+        assert_eq!(
+            tokens.to_string(),
+            quote! { Option<(&'__l0 str, &'__l1 T, &'__l0 u8)> }.to_string()
+        );
+        assert_eq!(renamed, vec!["b".to_string(), "a".to_string()]);
+
+        let mut renamed = Vec::new();
+        let tokens = canonicalize_lifetimes(
+            quote! { for<'x> fn(&'x str) -> &'static str },
+            &lifetimes,
+            &mut renamed,
+        );
+        assert_eq!(
+            tokens.to_string(),
+            quote! { for<'x> fn(&'x str) -> &'__l0 str }.to_string()
+        );
+        assert_eq!(renamed, vec!["static".to_string()]);
+    }
 }
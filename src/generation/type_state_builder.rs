@@ -35,9 +35,21 @@
 //! ## Key Guarantees
 //! 1. **Impossibility of Invalid States** - Cannot call `build()` until all required fields are set
 //! 2. **Progress Monotonicity** - Can only move toward completion (no "unsetting")
-//! 3. **Zero Runtime Cost** - All validation is compile-time only
+//! 3. **No Runtime Checks** - All validation is compile-time only
 //! 4. **Perfect Ergonomics** - Method chaining works seamlessly
 //!
+//! ## Transition Cost
+//! A required setter builds the next state by moving every field out of the
+//! current one. The states cannot share a layout, because a set field is stored
+//! as `T` and an unset one as `Option<T>`, so the moves are not no-ops at the
+//! type level. The setters are small functions in the deriving crate, however,
+//! so with optimizations LLVM is expected to inline the chain and write each
+//! value straight into the built struct. That is the optimizer's choice, not a
+//! property of the generated code, so the docs describe it as expected
+//! behavior rather than a guarantee. Unoptimized builds copy every
+//! stored value at each transition, which the struct-level `boxed` avoids by
+//! moving pointers instead.
+//!
 //! # Generated Code Structure
 //!
 //! For a struct with required fields, this generates:
@@ -50,11 +62,18 @@
 
 use crate::analysis::StructAnalysis;
 use crate::generation::TokenGenerator;
-use crate::utils::field_utils::{resolve_effective_impl_into, resolve_setter_parameter_config};
+use crate::utils::field_utils::{
+    resolve_effective_impl_into, resolve_into_iter_parameter_config,
+    resolve_setter_parameter_config,
+};
 use crate::utils::identifiers::{snake_case_to_pascal_case, strip_raw_identifier_prefix};
 use quote::quote;
 use syn::Ident;
 
+/// The most required fields, besides a `builder_method` field, for which the
+/// state diagram in the entry method docs lists every state.
+const MAX_DIAGRAM_FIELDS: usize = 4;
+
 /// Generates a complete type-state builder implementation.
 ///
 /// This is the main entry point for type-state builder generation. It creates
@@ -101,10 +120,29 @@ pub fn generate_type_state_builder(
 pub fn generate_with_token_generator(
     token_generator: &TokenGenerator,
 ) -> syn::Result<proc_macro2::TokenStream> {
-    let builder_coordinator = TypeStateBuilderCoordinator::new(token_generator);
+    let builder_coordinator = TypeStateBuilderCoordinator::new(token_generator)?;
     builder_coordinator.generate_complete_implementation()
 }
 
+/// Gets the name of the builder state with every required field set.
+///
+/// A struct with only optional fields has a single state, `{Struct}Builder`,
+/// which is also the name the regular builder uses.
+///
+/// # Arguments
+///
+/// * `analysis` - The struct analysis
+///
+/// # Returns
+///
+/// The name of the state that has the build method.
+pub fn complete_state_name(analysis: &StructAnalysis) -> String {
+    let base_builder_name = format!("{}Builder", analysis.builder_base_name());
+    StateSpace::new(analysis, &base_builder_name)
+        .complete()
+        .concrete_type_name
+}
+
 /// Coordinator for type-state builder generation.
 ///
 /// This struct encapsulates the logic for generating all components of a
@@ -114,8 +152,11 @@ struct TypeStateBuilderCoordinator<'a> {
     /// Token generator for consistent code generation
     token_generator: &'a TokenGenerator<'a>,
 
-    /// Cached state combinations for efficient generation
-    state_combinations: Vec<StateCombination>,
+    /// The builder states, enumerated on demand
+    state_space: StateSpace,
+
+    /// The optional field setters, shared by every builder state
+    optional_setter_methods: proc_macro2::TokenStream,
 
     /// Base name for builder types
     _base_builder_name: String,
@@ -130,19 +171,20 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
     ///
     /// # Returns
     ///
-    /// A new `TypeStateBuilderCoordinator` ready for generation.
-    fn new(token_generator: &'a TokenGenerator<'a>) -> Self {
-        let struct_name = token_generator.analysis().struct_name();
-        let base_builder_name = format!("{struct_name}Builder");
+    /// A `syn::Result` containing a `TypeStateBuilderCoordinator` ready for generation.
+    fn new(token_generator: &'a TokenGenerator<'a>) -> syn::Result<Self> {
+        let base_builder_name =
+            format!("{}Builder", token_generator.analysis().builder_base_name());
 
-        let state_combinations =
-            Self::generate_state_combinations(token_generator.analysis(), &base_builder_name);
+        let state_space = StateSpace::new(token_generator.analysis(), &base_builder_name);
+        let optional_setter_methods = Self::generate_optional_setter_methods(token_generator)?;
 
-        Self {
+        Ok(Self {
             token_generator,
-            state_combinations,
+            state_space,
+            optional_setter_methods,
             _base_builder_name: base_builder_name,
-        }
+        })
     }
 
     /// Generates the complete type-state builder implementation.
@@ -154,6 +196,15 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
     ///
     /// A `syn::Result<proc_macro2::TokenStream>` containing the complete implementation.
     fn generate_complete_implementation(&self) -> syn::Result<proc_macro2::TokenStream> {
+        if self
+            .token_generator
+            .analysis()
+            .struct_attributes()
+            .get_hide_states()
+        {
+            return self.generate_hidden_implementation();
+        }
+
         let mut tokens = proc_macro2::TokenStream::new();
 
         // Generate all concrete builder types
@@ -162,15 +213,92 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
         // Generate the constructor method on the original struct
         tokens.extend(self.generate_struct_constructor_method()?);
 
+        // Generate the complete builder for tests if requested
+        tokens.extend(self.generate_test_builder_method()?);
+
         // Generate setter methods for all states and fields
         tokens.extend(self.generate_all_setter_methods()?);
 
         // Generate build methods for all states (complete and incomplete)
         tokens.extend(self.generate_all_build_methods()?);
 
+        // Generate the builder state traits for downstream extension traits
+        tokens.extend(self.generate_state_traits()?);
+
+        // Generate the support items for nesting this builder in other builders
+        tokens.extend(self.generate_nested_support()?);
+
+        // Generate the requested aliases of the initial and complete states
+        tokens.extend(self.generate_state_aliases()?);
+
+        // Implement the user trait that names the builder if requested
+        tokens.extend(self.generate_buildable_impl()?);
+
+        // Assert the auto traits of every builder state if requested
+        let builder_idents = self
+            .state_space
+            .states()
+            .map(|state_combination| syn::parse_str::<Ident>(&state_combination.concrete_type_name))
+            .collect::<syn::Result<Vec<_>>>()?;
+        tokens.extend(
+            self.token_generator
+                .generate_auto_trait_assertions(&builder_idents),
+        );
+
         Ok(tokens)
     }
 
+    /// Generates the type-state builder with its states hidden in an anonymous const.
+    ///
+    /// Only the items downstream code names stay beside the struct: the state
+    /// trait definitions, the `{Struct}Builder` alias and the nesting support,
+    /// all of which reach the states through the projection trait.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<proc_macro2::TokenStream>` containing the complete implementation.
+    fn generate_hidden_implementation(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let (start_ident, ready_ident) = self.initial_and_complete_states()?;
+
+        let mut visible = self.token_generator.generate_state_traits()?;
+        visible.extend(
+            self.token_generator
+                .generate_setter_trait(&self.optional_setter_methods)?,
+        );
+        visible.extend(self.token_generator.generate_hidden_state_aliases()?);
+        visible.extend(self.generate_nested_support()?);
+        visible.extend(self.generate_state_aliases()?);
+        visible.extend(self.generate_buildable_impl()?);
+
+        let mut hidden = self.generate_concrete_builder_types()?;
+        hidden.extend(self.generate_struct_constructor_method()?);
+        hidden.extend(self.generate_test_builder_method()?);
+        hidden.extend(self.generate_all_setter_methods()?);
+        hidden.extend(self.generate_all_build_methods()?);
+        hidden.extend(self.generate_state_trait_impls()?);
+        hidden.extend(
+            self.token_generator
+                .generate_hidden_state_impl(&start_ident, &ready_ident)?,
+        );
+        let builder_idents = self
+            .state_space
+            .states()
+            .map(|state_combination| syn::parse_str::<Ident>(&state_combination.concrete_type_name))
+            .collect::<syn::Result<Vec<_>>>()?;
+        hidden.extend(
+            self.token_generator
+                .generate_auto_trait_assertions(&builder_idents),
+        );
+
+        Ok(quote! {
+            #visible
+
+            const _: () = {
+                #hidden
+            };
+        })
+    }
+
     /// Generates all concrete builder type definitions.
     ///
     /// Creates struct definitions for each possible state combination of
@@ -182,8 +310,8 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
     fn generate_concrete_builder_types(&self) -> syn::Result<proc_macro2::TokenStream> {
         let mut tokens = proc_macro2::TokenStream::new();
 
-        for state_combination in &self.state_combinations {
-            tokens.extend(self.generate_single_builder_type(state_combination)?);
+        for state_combination in self.state_space.states() {
+            tokens.extend(self.generate_single_builder_type(&state_combination)?);
         }
 
         Ok(tokens)
@@ -227,20 +355,48 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
             ),
         );
 
-        // Generate Debug implementation if configured
-        let debug_impl = self
+        // Generate Debug implementation if configured, and the ZeroizeOnDrop
+        // marker for builders whose fields are all sensitive
+        let unset_fields: Vec<_> = analysis
+            .required_fields()
+            .iter()
+            .enumerate()
+            .filter(|(field_index, _)| !state_combination.set_fields.contains(field_index))
+            .map(|(_, field)| field.name())
+            .collect();
+        let debug_impl = self.token_generator.generate_debug_impl(
+            &quote! { #builder_ident },
+            &type_generics,
+            &unset_fields,
+        );
+        let zeroize_impl = self
             .token_generator
-            .generate_debug_impl(&quote! { #builder_ident }, &type_generics);
+            .generate_zeroize_on_drop_impl(&quote! { #builder_ident }, &type_generics);
+        let derive_impls = self.token_generator.generate_derive_impls(
+            &quote! { #builder_ident },
+            &type_generics,
+            &unset_fields,
+        );
 
-        let struct_visibility = self.token_generator.analysis().struct_visibility();
+        let struct_visibility = self.token_generator.analysis().builder_visibility();
+        let non_exhaustive = self.token_generator.generate_non_exhaustive_attribute();
+        let serde_derive = self.token_generator.generate_serde_derive_attribute();
 
+        // Unset fields are never read, and neither are the fields of states a
+        // program never reaches. rustc only reports this when the struct is
+        // emitted by a local `macro_rules!` macro, but it is never actionable.
         Ok(quote! {
             #doc
+            #serde_derive
+            #[allow(dead_code)]
+            #non_exhaustive
             #struct_visibility struct #builder_ident #impl_generics #where_clause {
                 #field_declarations
             }
 
             #debug_impl
+            #zeroize_impl
+            #derive_impls
         })
     }
 
@@ -282,16 +438,19 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
 
             if is_set {
                 // Field is set in this state - store actual value
+                let storage_type = required_field.builder_storage_type();
                 field_tokens.extend(quote! {
                     #doc
-                    #field_name: #field_type,
+                    #field_name: #storage_type,
                 });
             } else {
                 // Field is not set yet - store as Option
-                let option_type = self.token_generator.generate_type_path("Option");
+                let unset_type = required_field.unset_storage_type();
+                let serde_skip = self.token_generator.generate_serde_skip_attribute();
                 field_tokens.extend(quote! {
                     #doc
-                    #field_name: #option_type<#field_type>,
+                    #serde_skip
+                    #field_name: #unset_type,
                 });
             }
         }
@@ -300,6 +459,7 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
         for optional_field in analysis.optional_fields() {
             let field_name = optional_field.name();
             let field_type = optional_field.field_type();
+            let storage_type = optional_field.builder_storage_type();
 
             let doc = self.token_generator.generate_field_documentation(
                 &optional_field.clean_name(),
@@ -307,10 +467,16 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
                 false,
                 "Optional",
             );
+            let serde_skip = if optional_field.attributes().computed.is_some() {
+                self.token_generator.generate_serde_skip_attribute()
+            } else {
+                quote! {}
+            };
 
             field_tokens.extend(quote! {
                 #doc
-                #field_name: #field_type,
+                #serde_skip
+                #field_name: #storage_type,
             });
         }
 
@@ -330,12 +496,11 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
     /// A `syn::Result<proc_macro2::TokenStream>` containing the constructor method.
     fn generate_struct_constructor_method(&self) -> syn::Result<proc_macro2::TokenStream> {
         let analysis = self.token_generator.analysis();
-        let struct_name = analysis.struct_name();
 
-        let impl_generics = self.token_generator.impl_generics_tokens();
-        let type_generics = self.token_generator.type_generics_tokens();
-        let where_clause = self.token_generator.where_clause_tokens();
-        let const_kw = self.token_generator.const_keyword();
+        // With no_entry the builder is started from its initial state
+        if analysis.struct_attributes().get_no_entry() {
+            return Ok(quote! {});
+        }
 
         // Check if we have a builder_method field
         if let Some(builder_method_field) = analysis.builder_method_field() {
@@ -344,16 +509,7 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
         }
 
         // Standard case: generate builder() method
-        let initial_state = self
-            .state_combinations
-            .iter()
-            .find(|combo| combo.set_fields.is_empty())
-            .ok_or_else(|| {
-                syn::Error::new(
-                    proc_macro2::Span::call_site(),
-                    "No initial state found in state combinations",
-                )
-            })?;
+        let initial_state = self.state_space.initial();
 
         let initial_builder_ident = syn::parse_str::<Ident>(&initial_state.concrete_type_name)?;
 
@@ -362,17 +518,256 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
             "Creates a new type-safe builder for constructing an instance",
             Some("This builder uses the type-state pattern to ensure all required fields are set before building.")
         );
+        let setters_doc = self
+            .token_generator
+            .generate_setters_summary_documentation();
+        let diagram_doc = self.generate_state_diagram_documentation();
+        let non_exhaustive_doc = self.token_generator.generate_non_exhaustive_documentation();
+
+        self.token_generator.generate_entry_method(
+            &doc,
+            &quote! {
+                #setters_doc
+                #diagram_doc
+                #non_exhaustive_doc
+            },
+            &initial_builder_ident,
+            &quote! { #initial_builder_ident::new() },
+        )
+    }
+
+    /// Generates `test_builder()`, which returns the complete builder state.
+    ///
+    /// The method starts from the initial state, passing the test value of a
+    /// `builder_method` field to its constructor, and moves the optional
+    /// fields into the complete state with every other required field set to
+    /// its test value. Under the same `cfg`, the complete state gets the
+    /// setters of the required fields, so tests can replace a test value.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<proc_macro2::TokenStream>` containing the method, or
+    /// empty without the struct-level `test_default`.
+    fn generate_test_builder_method(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let analysis = self.token_generator.analysis();
+        if analysis.struct_attributes().get_test_default().is_none() {
+            return Ok(quote! {});
+        }
+        let initial_ident =
+            syn::parse_str::<Ident>(&self.state_space.initial().concrete_type_name)?;
+        let complete_ident =
+            syn::parse_str::<Ident>(&self.state_space.complete().concrete_type_name)?;
+
+        let start = match analysis.builder_method_field() {
+            Some(field) => {
+                let value = self.token_generator.test_value_tokens(field);
+                quote! { #initial_ident::new(#value) }
+            }
+            None => quote! { #initial_ident::new() },
+        };
+
+        let mut assignments = proc_macro2::TokenStream::new();
+        for required_field in analysis.required_fields() {
+            let field_name = required_field.name();
+            if required_field.attributes().builder_method {
+                assignments.extend(quote! { #field_name: start.#field_name, });
+            } else {
+                let value = required_field
+                    .wrap_builder_value(&self.token_generator.test_value_tokens(required_field));
+                assignments.extend(quote! { #field_name: #value, });
+            }
+        }
+        for optional_field in analysis.optional_fields() {
+            let field_name = optional_field.name();
+            assignments.extend(quote! { #field_name: start.#field_name, });
+        }
+        if analysis.needs_phantom_data() {
+            let marker_name = self.token_generator.get_phantom_data_field_name();
+            let marker_ident = syn::parse_str::<Ident>(marker_name)?;
+            assignments.extend(quote! { #marker_ident: start.#marker_ident, });
+        }
+
+        let type_generics = self.token_generator.type_generics_tokens();
+        let method = self.token_generator.generate_test_builder_method(
+            &complete_ident,
+            &quote! {
+                let start: #initial_ident #type_generics = #start;
+                #complete_ident {
+                    #assignments
+                }
+            },
+        );
+        let overrides = self.generate_test_value_overrides(&complete_ident)?;
 
         Ok(quote! {
-            impl #impl_generics #struct_name #type_generics #where_clause {
+            #method
+            #overrides
+        })
+    }
+
+    /// Generates the setters of the required fields on the complete state.
+    ///
+    /// Each setter moves the builder back into the state where its field is
+    /// unset and calls the regular setter there, so it takes the same
+    /// argument and runs the same conversion and checks. The `builder_method`
+    /// field has no setter to call and is left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `complete_ident` - The complete builder state
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<proc_macro2::TokenStream>` containing the impl block.
+    fn generate_test_value_overrides(
+        &self,
+        complete_ident: &Ident,
+    ) -> syn::Result<proc_macro2::TokenStream> {
+        let analysis = self.token_generator.analysis();
+        let Some(predicate) = analysis.struct_attributes().get_test_default() else {
+            return Ok(quote! {});
+        };
+        let impl_generics = self.token_generator.impl_generics_tokens();
+        let type_generics = self.token_generator.type_generics_tokens();
+        let where_clause = self.token_generator.where_clause_tokens();
+        let struct_setter_prefix = analysis.struct_attributes().get_setter_prefix();
+        let complete_mask = self.state_space.complete_mask();
+
+        let mut methods = Vec::new();
+        for (field_index, field) in analysis.required_fields().iter().enumerate() {
+            let Some(unset_state) = self.state_space.get(complete_mask & !(1 << field_index))
+            else {
+                continue;
+            };
+            let unset_ident = syn::parse_str::<Ident>(&unset_state.concrete_type_name)?;
+            let setter_ident =
+                syn::parse_str::<Ident>(&field.final_setter_name(struct_setter_prefix))?;
+            let param_type = self.required_setter_parameter_config(field).param_type;
+            let return_type = if let Some(error_type) = field.try_from_error_type() {
+                quote! { ::core::result::Result<Self, #error_type> }
+            } else if field.attributes().validate_on_set {
+                let error_ident = self.token_generator.validation_error_type_ident();
+                quote! { ::core::result::Result<Self, #error_ident> }
+            } else {
+                quote! { Self }
+            };
+
+            let mut assignments = proc_macro2::TokenStream::new();
+            for (other_index, other) in analysis.required_fields().iter().enumerate() {
+                let other_name = other.name();
+                if other_index == field_index {
+                    assignments.extend(quote! { #other_name: ::core::option::Option::None, });
+                } else {
+                    assignments.extend(quote! { #other_name: self.#other_name, });
+                }
+            }
+            for optional_field in analysis.optional_fields() {
+                let field_name = optional_field.name();
+                assignments.extend(quote! { #field_name: self.#field_name, });
+            }
+            if analysis.needs_phantom_data() {
+                let marker_name = self.token_generator.get_phantom_data_field_name();
+                let marker_ident = syn::parse_str::<Ident>(marker_name)?;
+                assignments.extend(quote! { #marker_ident: self.#marker_ident, });
+            }
+
+            let doc = self.token_generator.generate_method_documentation(
+                &setter_ident.to_string(),
+                &format!("Replaces the test value of `{}`", field.clean_name()),
+                Some("Only generated with `test_builder()`, whose builder has every required field set."),
+            );
+            let cfg = field.setter_cfg_tokens();
+            methods.push(quote! {
                 #doc
-                pub #const_kw fn builder() -> #initial_builder_ident #type_generics {
-                    #initial_builder_ident::new()
+                #cfg
+                pub fn #setter_ident(self, value: #param_type) -> #return_type {
+                    #unset_ident {
+                        #assignments
+                    }
+                    .#setter_ident(value)
                 }
+            });
+        }
+
+        if methods.is_empty() {
+            return Ok(quote! {});
+        }
+        Ok(quote! {
+            #[cfg(#predicate)]
+            impl #impl_generics #complete_ident #type_generics #where_clause {
+                #(#methods)*
             }
         })
     }
 
+    /// Generates a `# States` section drawing the builder's state machine.
+    ///
+    /// Each state is listed with the required setters leading out of it and the
+    /// state they lead to; the complete state leads to the build method. With
+    /// more than [`MAX_DIAGRAM_FIELDS`] required fields only the initial and
+    /// the complete state are drawn, as the number of states doubles with
+    /// every field.
+    ///
+    /// # Returns
+    ///
+    /// A `TokenStream` containing the section, or empty if documentation is
+    /// disabled.
+    fn generate_state_diagram_documentation(&self) -> proc_macro2::TokenStream {
+        if !self.token_generator.config().include_documentation {
+            return quote! {};
+        }
+
+        let analysis = self.token_generator.analysis();
+        let setter_prefix = analysis.struct_attributes().get_setter_prefix();
+        let setters: Vec<(usize, String)> = analysis
+            .required_fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| !field.attributes().builder_method)
+            .map(|(field_index, field)| (field_index, field.final_setter_name(setter_prefix)))
+            .collect();
+        let build_method_name = analysis.struct_attributes().get_build_method_name();
+
+        // States come in mask order, so the complete state is drawn last
+        let mut diagram = Vec::new();
+        if setters.len() <= MAX_DIAGRAM_FIELDS {
+            for state in self.state_space.states() {
+                diagram.push(state.concrete_type_name.clone());
+                for (field_index, setter_name) in &setters {
+                    if !state.is_set(*field_index) {
+                        let next = self.state_space.with_field_set(&state, *field_index);
+                        diagram.push(format!(
+                            "    .{setter_name}() -> {}",
+                            next.concrete_type_name
+                        ));
+                    }
+                }
+            }
+        } else {
+            let names: Vec<String> = setters
+                .iter()
+                .map(|(_, setter_name)| format!("{setter_name}()"))
+                .collect();
+            diagram.push(self.state_space.initial().concrete_type_name);
+            diagram.push(format!("    {} in any order", names.join(", ")));
+            diagram.push(self.state_space.complete().concrete_type_name);
+        }
+        diagram.push(format!(
+            "    .{build_method_name}() -> {}",
+            analysis.struct_name()
+        ));
+
+        let mut doc_lines = vec![
+            String::new(),
+            "# States".to_string(),
+            String::new(),
+            "```text".to_string(),
+        ];
+        doc_lines.extend(diagram);
+        doc_lines.push("```".to_string());
+        quote! { #(#[doc = #doc_lines])* }
+    }
+
     /// Generates the entry point method for builder_method attribute.
     ///
     /// When a field has `#[builder(builder_method)]`, this generates the entry point
@@ -391,29 +786,8 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
         let const_kw = self.token_generator.const_keyword();
         let is_const = self.token_generator.is_const_builder();
 
-        // Find the field index
-        let field_index = analysis
-            .required_fields()
-            .iter()
-            .position(|f| f.name() == field.name())
-            .ok_or_else(|| {
-                syn::Error::new(
-                    proc_macro2::Span::call_site(),
-                    "builder_method field not found in required fields",
-                )
-            })?;
-
-        // Find the initial state (with only this field set)
-        let initial_state = self
-            .state_combinations
-            .iter()
-            .find(|combo| combo.set_fields.len() == 1 && combo.set_fields.contains(&field_index))
-            .ok_or_else(|| {
-                syn::Error::new(
-                    proc_macro2::Span::call_site(),
-                    "No initial state found for builder_method field",
-                )
-            })?;
+        // The initial state has only this field set
+        let initial_state = self.state_space.initial();
 
         let initial_builder_ident = syn::parse_str::<Ident>(&initial_state.concrete_type_name)?;
 
@@ -425,8 +799,10 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
         let field_name = field.name();
 
         // Handle impl_into
-        let use_impl_into = if is_const {
-            false // impl_into not supported in const fn
+        // impl_into is not supported in const fn, and transparent setters take
+        // the Option<T> field type as-is
+        let use_impl_into = if is_const || field.attributes().transparent {
+            false
         } else {
             crate::utils::field_utils::resolve_effective_impl_into(
                 field.attributes().impl_into,
@@ -480,6 +856,37 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
                     None,
                 )
             }
+        } else if let Some(dyn_config) = (!is_const)
+            .then(|| field.dyn_into_parameter_config())
+            .flatten()
+        {
+            // Box the concrete value into the trait object
+            (
+                dyn_config.param_type,
+                dyn_config.field_assignment_expr,
+                None,
+            )
+        } else if let Some(clone_config) = (!is_const)
+            .then(|| field.clone_from_ref_parameter_config())
+            .flatten()
+        {
+            // Borrow the argument and store an owned copy
+            (
+                clone_config.param_type,
+                clone_config.field_assignment_expr,
+                None,
+            )
+        } else if let Some(into_iter_config) = (!is_const
+            && field.uses_into_iter(analysis.struct_attributes().get_into_iter()))
+        .then(|| resolve_into_iter_parameter_config(field_type))
+        .flatten()
+        {
+            // Collect any iterable of convertible items
+            (
+                into_iter_config.param_type,
+                into_iter_config.field_assignment_expr,
+                None,
+            )
         } else if use_impl_into {
             // Use impl Into
             (
@@ -500,13 +907,23 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
             ),
             Some("This is the entry point for the type-safe builder pattern."),
         );
+        let field_docs = field.setter_doc_tokens(&setter_name);
+        let deprecation = field.setter_deprecation_tokens();
+        let default_bounds = self.token_generator.default_bounds_where_clause();
+        let diagram_doc = self.generate_state_diagram_documentation();
+        let non_exhaustive_doc = self.token_generator.generate_non_exhaustive_documentation();
+        let visibility = analysis.entry_visibility();
 
         Ok(quote! {
             impl #impl_generics #struct_name #type_generics #where_clause {
                 #const_fn_decl
 
                 #doc
-                pub #const_kw fn #setter_ident(value: #param_type) -> #initial_builder_ident #type_generics {
+                #field_docs
+                #diagram_doc
+                #non_exhaustive_doc
+                #deprecation
+                #visibility #const_kw fn #setter_ident(value: #param_type) -> #initial_builder_ident #type_generics #default_bounds {
                     #initial_builder_ident::new(#field_assignment)
                 }
             }
@@ -547,33 +964,8 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
         // Check if we have a builder_method field
         let builder_method_field = analysis.builder_method_field();
 
-        let initial_state = if builder_method_field.is_some() {
-            // When builder_method is used, the initial state has that field set
-            let field_index = analysis
-                .required_fields()
-                .iter()
-                .position(|f| f.attributes().builder_method)
-                .unwrap();
-            self.state_combinations
-                .iter()
-                .find(|combo| {
-                    combo.set_fields.len() == 1 && combo.set_fields.contains(&field_index)
-                })
-                .ok_or_else(|| {
-                    syn::Error::new(
-                        proc_macro2::Span::call_site(),
-                        "No initial state found for builder_method",
-                    )
-                })?
-        } else {
-            // Standard case: initial state has no fields set
-            self.state_combinations
-                .iter()
-                .find(|combo| combo.set_fields.is_empty())
-                .ok_or_else(|| {
-                    syn::Error::new(proc_macro2::Span::call_site(), "No initial state found")
-                })?
-        };
+        // When builder_method is used, the initial state has that field set
+        let initial_state = self.state_space.initial();
 
         let initial_builder_ident = syn::parse_str::<Ident>(&initial_state.concrete_type_name)?;
 
@@ -584,10 +976,14 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
 
         // Generate field initializations
         let field_init = self.generate_initial_field_initializations()?;
+        let default_struct = self.token_generator.generate_default_struct_binding();
+        let default_bounds = self.token_generator.default_bounds_where_clause();
+        let visibility = analysis.entry_visibility();
 
         if let Some(bm_field) = builder_method_field {
             let field_type = bm_field.field_type();
             let field_name = bm_field.name();
+            let field_value = bm_field.wrap_builder_value(&quote! { #field_name });
 
             let doc = self.token_generator.generate_method_documentation(
                 "new",
@@ -598,9 +994,10 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
             Ok(quote! {
                 impl #impl_generics #initial_builder_ident #type_generics #where_clause {
                     #doc
-                    pub #const_kw fn new(#field_name: #field_type) -> Self {
+                    #visibility #const_kw fn new(#field_name: #field_type) -> Self #default_bounds {
+                        #default_struct
                         Self {
-                            #field_name,
+                            #field_name: #field_value,
                             #field_init
                         }
                     }
@@ -617,7 +1014,8 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
             Ok(quote! {
                 impl #impl_generics #initial_builder_ident #type_generics #where_clause {
                     #doc
-                    pub #const_kw fn new() -> Self {
+                    #visibility #const_kw fn new() -> Self #default_bounds {
+                        #default_struct
                         Self {
                             #field_init
                         }
@@ -651,7 +1049,9 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
 
         // Initialize optional fields with defaults
         for optional_field in analysis.optional_fields() {
-            let field_init_code = optional_field.generate_initialization(false)?;
+            let field_init_code = self
+                .token_generator
+                .generate_optional_field_initialization(optional_field)?;
             field_init.extend(field_init_code);
         }
 
@@ -671,14 +1071,14 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
         let analysis = self.token_generator.analysis();
 
         // For each state combination, generate setters for unset required fields
-        for state_combination in &self.state_combinations {
+        for state_combination in self.state_space.states() {
             for (field_index, required_field) in analysis.required_fields().iter().enumerate() {
                 // Only generate setter if this field is not set in this state
-                if !state_combination.set_fields.contains(&field_index) {
+                if !state_combination.is_set(field_index) {
                     tokens.extend(self.generate_required_field_setter(
                         required_field,
                         field_index,
-                        state_combination,
+                        &state_combination,
                     )?);
                 }
             }
@@ -708,8 +1108,7 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
         let field_type = field.field_type();
 
         // Determine the output state (current state + this field set)
-        let output_state =
-            self.find_state_with_field_set(field_index, &current_state.set_fields)?;
+        let output_state = self.state_space.with_field_set(current_state, field_index);
         let output_builder_ident = syn::parse_str::<Ident>(&output_state.concrete_type_name)?;
 
         let impl_generics = self.token_generator.impl_generics_tokens();
@@ -725,40 +1124,97 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
         let setter_config = field.create_setter_config(struct_setter_prefix);
         let setter_ident = syn::parse_str::<Ident>(&setter_config.setter_name)?;
 
+        let transition_info = if field.try_from_input().is_some() {
+            "This method transitions the builder to a new state where this field is set.\n\n\
+             # Errors\n\n\
+             Returns the conversion error if the value cannot be converted."
+        } else if field.attributes().validate_on_set {
+            "This method transitions the builder to a new state where this field is set.\n\n\
+             # Errors\n\n\
+             Returns the validation error if the field's checks reject the value."
+        } else if field.attributes().transparent {
+            "This method transitions the builder to a new state where this field is set. \
+             Pass `None` to explicitly leave the field empty."
+        } else {
+            "This method transitions the builder to a new state where this field is set."
+        };
         let doc = self.token_generator.generate_method_documentation(
             &setter_config.setter_name,
             &{
                 let field_name = field.clean_name();
                 format!("Sets the required field `{field_name}`")
             },
-            Some("This method transitions the builder to a new state where this field is set."),
+            Some(transition_info),
         );
+        let field_docs = field.setter_doc_tokens(&setter_config.setter_name);
+        let deprecation = field.setter_deprecation_tokens();
+        let nested_setter = field.generate_nested_setter_method(
+            &setter_ident,
+            &quote! { #output_builder_ident #type_generics },
+        )?;
+        let try_setter = field.generate_try_setter_method(
+            &setter_ident,
+            &quote! { #output_builder_ident #type_generics },
+        )?;
+        let ref_setter = field.generate_ref_setter_method(
+            &setter_ident,
+            &quote! { #output_builder_ident #type_generics },
+        )?;
+        let lazy_assignments = self.generate_field_assignments_for_transition_with_value(
+            field_index,
+            &field.lazy_builder_value(&quote! { value }),
+        )?;
+        let lazy_setter = field.generate_lazy_setter_method(
+            &setter_ident,
+            &quote! { #output_builder_ident #type_generics },
+            &quote! {
+                #output_builder_ident {
+                    #lazy_assignments
+                }
+            },
+        )?;
 
         // Determine parameter type and field assignment logic
-        let struct_impl_into = self
-            .token_generator
-            .analysis()
-            .struct_attributes()
-            .get_impl_into();
-        let field_impl_into = field.attributes().impl_into;
         let converter = field.attributes().converter.as_ref();
         let is_const = self.token_generator.is_const_builder();
-        // When const, force impl_into to false (trait bounds not supported in const fn)
-        let use_impl_into = if is_const {
-            false
-        } else {
-            resolve_effective_impl_into(field_impl_into, struct_impl_into)
-        };
-
-        // Use the shared utility to determine parameter configuration
-        let param_config = resolve_setter_parameter_config(field_type, converter, use_impl_into);
+        let param_config = self.required_setter_parameter_config(field);
 
         let param_type = param_config.param_type;
         let const_kw = self.token_generator.const_keyword();
 
         // Generate method signature and body based on setter type
-        let (method_signature, method_body, const_fn_decl) = if let Some(converter_expr) = converter
-        {
+        let (method_signature, method_body, const_fn_decl) = if field.has_fallible_setter() {
+            // Validating or TryFrom setter - check the converted value before the transition
+            let error_ident = self.token_generator.validation_error_type_ident();
+            let (error_type, checks) = match field.try_from_error_type() {
+                Some(error_type) => (error_type, quote! {}),
+                None => (
+                    quote! { #error_ident },
+                    field.generate_value_checks(&quote! { value }, &error_ident),
+                ),
+            };
+            let track_caller = field.setter_track_caller_tokens();
+            let signature = quote! {
+                #track_caller
+                pub fn #setter_ident(self, value: #param_type)
+                    -> ::core::result::Result<#output_builder_ident #type_generics, #error_type>
+            };
+
+            let conversion = &param_config.field_assignment_expr;
+            let field_assignments = self.generate_field_assignments_for_transition_with_expr(
+                field_index,
+                &quote! { value },
+            )?;
+
+            let body = quote! {
+                let value: #field_type = #conversion;
+                #checks
+                ::core::result::Result::Ok(#output_builder_ident {
+                    #field_assignments
+                })
+            };
+            (signature, body, None)
+        } else if let Some(converter_expr) = converter {
             // Custom converter - generate a setter that applies the closure expression
             let signature = quote! {
                 pub #const_kw fn #setter_ident(self, value: #param_type) -> #output_builder_ident #type_generics
@@ -787,7 +1243,7 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
                     (quote! { (#converter_expr)(value) }, None)
                 }
             } else {
-                (quote! { (#converter_expr)(value) }, None)
+                (param_config.field_assignment_expr.clone(), None)
             };
 
             // For custom converters, generate field assignments using the expression
@@ -827,13 +1283,84 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
                 #const_fn_decl
 
                 #doc
+                #field_docs
+                #deprecation
                 #method_signature {
                     #method_body
                 }
+
+                #nested_setter
+                #lazy_setter
+                #try_setter
+                #ref_setter
             }
         })
     }
 
+    /// Determines the parameter type and field assignment of a required setter.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The required field
+    ///
+    /// # Returns
+    ///
+    /// The `SetterParameterConfig` of the field's setter.
+    fn required_setter_parameter_config(
+        &self,
+        field: &crate::analysis::FieldInfo,
+    ) -> crate::utils::field_utils::SetterParameterConfig {
+        let field_type = field.field_type();
+        let struct_impl_into = self
+            .token_generator
+            .analysis()
+            .struct_attributes()
+            .get_impl_into();
+        let field_impl_into = field.attributes().impl_into;
+        let converter = field.attributes().converter.as_ref();
+        let is_const = self.token_generator.is_const_builder();
+        // When const, force impl_into to false (trait bounds not supported in const fn)
+        // Transparent fields always take the Option<T> field type as-is
+        let use_impl_into = if is_const || field.attributes().transparent {
+            false
+        } else {
+            resolve_effective_impl_into(field_impl_into, struct_impl_into)
+        };
+
+        // Use the shared utilities to determine parameter configuration;
+        // into_iter takes precedence over impl_into, and neither is ever const
+        let struct_into_iter = self
+            .token_generator
+            .analysis()
+            .struct_attributes()
+            .get_into_iter();
+        field
+            .try_from_parameter_config()
+            .or_else(|| {
+                (!is_const)
+                    .then(|| field.dyn_into_parameter_config())
+                    .flatten()
+            })
+            .or_else(|| {
+                (!is_const)
+                    .then(|| field.clone_from_ref_parameter_config())
+                    .flatten()
+            })
+            .or_else(|| {
+                (!is_const && field.uses_into_iter(struct_into_iter))
+                    .then(|| resolve_into_iter_parameter_config(field_type))
+                    .flatten()
+            })
+            .or_else(|| {
+                (!is_const)
+                    .then(|| field.converter_into_parameter_config())
+                    .flatten()
+            })
+            .unwrap_or_else(|| {
+                resolve_setter_parameter_config(field_type, converter, use_impl_into)
+            })
+    }
+
     /// Generates field assignments for a state transition with a custom field assignment expression.
     ///
     /// This is a flexible version that allows specifying exactly how the field being set
@@ -852,6 +1379,28 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
         &self,
         setting_field_index: usize,
         field_assignment_expr: &proc_macro2::TokenStream,
+    ) -> syn::Result<proc_macro2::TokenStream> {
+        let analysis = self.token_generator.analysis();
+        let value = analysis.required_fields()[setting_field_index]
+            .wrap_builder_value(field_assignment_expr);
+        self.generate_field_assignments_for_transition_with_value(setting_field_index, &value)
+    }
+
+    /// Generates field assignments for a state transition storing an already
+    /// wrapped builder value in the field being set.
+    ///
+    /// # Arguments
+    ///
+    /// * `setting_field_index` - Index of the field being set
+    /// * `value` - The builder value to store in the field being set
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<proc_macro2::TokenStream>` containing field assignment code.
+    fn generate_field_assignments_for_transition_with_value(
+        &self,
+        setting_field_index: usize,
+        value: &proc_macro2::TokenStream,
     ) -> syn::Result<proc_macro2::TokenStream> {
         let mut assignments = proc_macro2::TokenStream::new();
         let analysis = self.token_generator.analysis();
@@ -861,9 +1410,9 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
             let field_name = required_field.name();
 
             if field_index == setting_field_index {
-                // This is the field being set - use the provided expression
+                // This is the field being set - use the provided value
                 assignments.extend(quote! {
-                    #field_name: #field_assignment_expr,
+                    #field_name: #value,
                 });
             } else {
                 // Copy from existing state
@@ -896,50 +1445,81 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
     /// Generates setter methods for optional fields.
     ///
     /// Optional field setters don't cause state transitions - they work
-    /// the same way in all builder states.
+    /// the same way in all builder states, so they are generated once and
+    /// emitted in an impl block for each state, together with `fill_from`
+    /// and `apply_env`.
     ///
     /// # Returns
     ///
     /// A `syn::Result<proc_macro2::TokenStream>` containing optional field setters.
     fn generate_optional_field_setters(&self) -> syn::Result<proc_macro2::TokenStream> {
         let mut tokens = proc_macro2::TokenStream::new();
-        let analysis = self.token_generator.analysis();
+        let fill_from_method = self.token_generator.generate_fill_from_method();
+        let apply_env_method = self.token_generator.generate_apply_env_method()?;
+        if self.optional_setter_methods.is_empty()
+            && fill_from_method.is_empty()
+            && apply_env_method.is_empty()
+        {
+            return Ok(tokens);
+        }
+
+        let impl_generics = self.token_generator.impl_generics_tokens();
+        let type_generics = self.token_generator.type_generics_tokens();
+        let where_clause = self.token_generator.where_clause_tokens();
+        let setter_methods = &self.optional_setter_methods;
 
         // Generate setters for each builder state
-        for state_combination in &self.state_combinations {
+        for state_combination in self.state_space.states() {
             let builder_ident = syn::parse_str::<Ident>(&state_combination.concrete_type_name)?;
-            let mut setter_methods = proc_macro2::TokenStream::new();
-
-            // Generate setter for each optional field
-            let struct_setter_prefix = analysis.struct_attributes().get_setter_prefix();
-            let struct_impl_into = analysis.struct_attributes().get_impl_into();
-            let is_const = self.token_generator.is_const_builder();
-            for optional_field in analysis.optional_fields() {
-                if optional_field.should_generate_setter() {
-                    let setter_method = optional_field.generate_setter_method(
-                        &syn::parse_quote!(Self),
-                        struct_setter_prefix,
-                        struct_impl_into,
-                        is_const,
-                    )?;
-                    setter_methods.extend(setter_method);
+            tokens.extend(quote! {
+                impl #impl_generics #builder_ident #type_generics #where_clause {
+                    #setter_methods
+                    #fill_from_method
+                    #apply_env_method
                 }
-            }
+            });
+        }
 
-            if !setter_methods.is_empty() {
-                let impl_generics = self.token_generator.impl_generics_tokens();
-                let type_generics = self.token_generator.type_generics_tokens();
-                let where_clause = self.token_generator.where_clause_tokens();
+        Ok(tokens)
+    }
 
-                tokens.extend(quote! {
-                    impl #impl_generics #builder_ident #type_generics #where_clause {
-                        #setter_methods
-                    }
-                });
+    /// Generates the setter methods of the optional fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_generator` - Token generator to use for code generation
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<proc_macro2::TokenStream>` containing the setter methods.
+    fn generate_optional_setter_methods(
+        token_generator: &TokenGenerator,
+    ) -> syn::Result<proc_macro2::TokenStream> {
+        let analysis = token_generator.analysis();
+        let error_ident = token_generator.validation_error_type_ident();
+        let mut setter_methods = proc_macro2::TokenStream::new();
+
+        // Generate setter for each optional field
+        let struct_setter_prefix = analysis.struct_attributes().get_setter_prefix();
+        let struct_impl_into = analysis.struct_attributes().get_impl_into();
+        let struct_into_iter = analysis.struct_attributes().get_into_iter();
+        let struct_option_into = analysis.struct_attributes().get_option_into();
+        let is_const = token_generator.is_const_builder();
+        for optional_field in analysis.optional_fields() {
+            if optional_field.should_generate_setter() {
+                let setter_method = optional_field.generate_setter_method(
+                    &syn::parse_quote!(Self),
+                    struct_setter_prefix,
+                    struct_impl_into || optional_field.uses_option_into(struct_option_into),
+                    struct_into_iter,
+                    is_const,
+                    &error_ident,
+                )?;
+                setter_methods.extend(setter_method);
             }
         }
 
-        Ok(tokens)
+        Ok(setter_methods)
     }
 
     /// Generates the build method for the final builder state.
@@ -958,19 +1538,28 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
         let mut assignments = proc_macro2::TokenStream::new();
         let analysis = self.token_generator.analysis();
 
-        // Assign required fields (stored as actual values in final state)
+        // Assign required fields (stored as actual values in final state),
+        // leaving out builder-only fields, which are not part of the struct
         for required_field in analysis.required_fields() {
+            if required_field.attributes().builder_only {
+                continue;
+            }
             let field_name = required_field.name();
+            let value = required_field.build_value_tokens();
             assignments.extend(quote! {
-                #field_name: self.#field_name,
+                #field_name: #value,
             });
         }
 
         // Assign optional fields
         for optional_field in analysis.optional_fields() {
+            if optional_field.attributes().builder_only {
+                continue;
+            }
             let field_name = optional_field.name();
+            let value = optional_field.build_value_tokens();
             assignments.extend(quote! {
-                #field_name: self.#field_name,
+                #field_name: #value,
             });
         }
 
@@ -980,7 +1569,8 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
     /// Generates build methods for all builder states.
     ///
     /// This method creates build methods for every builder state:
-    /// - Complete states get normal build methods that construct the struct
+    /// - Complete states get normal build methods that construct the struct,
+    ///   plus a `From<Builder>` impl for the struct
     /// - Incomplete states get #[doc(hidden)] build methods with compile_error!
     ///   to provide helpful messages to AI coding assistants
     ///
@@ -988,221 +1578,184 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
     ///
     /// A `syn::Result<proc_macro2::TokenStream>` containing all build method implementations.
     fn generate_all_build_methods(&self) -> syn::Result<proc_macro2::TokenStream> {
-        let mut tokens = proc_macro2::TokenStream::new();
-        let analysis = self.token_generator.analysis();
-        let num_required_fields = analysis.required_fields().len();
-        let final_state_value = (1 << num_required_fields) - 1; // All bits set
+        // Only the complete state has build methods
+        let state_combination = self.state_space.complete();
+        let mut tokens = self.generate_complete_build_method(&state_combination)?;
 
-        for state_combination in &self.state_combinations {
-            // Calculate state value from set_fields
-            let state_value = state_combination
-                .set_fields
-                .iter()
-                .fold(0, |acc, &field_index| acc | (1 << field_index));
+        // Let the complete builder convert into the struct
+        let builder_ident = syn::parse_str::<Ident>(&state_combination.concrete_type_name)?;
+        tokens.extend(
+            self.token_generator
+                .generate_from_builder_impl(&builder_ident)?,
+        );
 
-            // Only generate build methods for complete states
-            if state_value == final_state_value {
-                let build_method = self.generate_complete_build_method(state_combination)?;
-                tokens.extend(build_method);
-            }
+        Ok(tokens)
+    }
+
+    /// Generates the builder state traits and their implementations.
+    ///
+    /// Every state implements `{Struct}BuilderState`; only the complete state
+    /// implements `{Struct}BuilderFinish`. Nothing is generated unless the
+    /// struct opts in with `#[builder(state_traits)]`. Every state also
+    /// implements `{Struct}BuilderSetters` under `#[builder(setter_trait)]`.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<proc_macro2::TokenStream>` containing the traits and impls.
+    fn generate_state_traits(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let mut tokens = self.token_generator.generate_state_traits()?;
+        tokens.extend(
+            self.token_generator
+                .generate_setter_trait(&self.optional_setter_methods)?,
+        );
+        tokens.extend(self.generate_state_trait_impls()?);
+        Ok(tokens)
+    }
+
+    /// Generates the builder state trait implementations for every state.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<proc_macro2::TokenStream>` containing the implementations.
+    fn generate_state_trait_impls(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let mut tokens = proc_macro2::TokenStream::new();
+
+        for state_combination in self.state_space.states() {
+            let builder_ident = syn::parse_str::<Ident>(&state_combination.concrete_type_name)?;
+            let is_complete = self.state_space.is_complete(&state_combination);
+            tokens.extend(
+                self.token_generator
+                    .generate_state_trait_impls(&builder_ident, is_complete)?,
+            );
+            tokens.extend(
+                self.token_generator
+                    .generate_setter_trait_impl(&builder_ident, &self.optional_setter_methods)?,
+            );
         }
 
         Ok(tokens)
     }
 
+    /// Generates the support items for nesting this builder in other builders.
+    ///
+    /// The initial state (no required fields set) and the complete state (all
+    /// required fields set) are exposed through the aliases that
+    /// `#[builder(nested)]` fields refer to.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<proc_macro2::TokenStream>` containing the nesting support items.
+    fn generate_nested_support(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let analysis = self.token_generator.analysis();
+        // builder_method replaces builder(), so there is no initial state to expose
+        if analysis.builder_method_field().is_some() {
+            return Ok(quote! {});
+        }
+
+        let (start_ident, ready_ident) = self.initial_and_complete_states()?;
+        self.token_generator
+            .generate_nested_support(&start_ident, &ready_ident)
+    }
+
+    /// Generates the aliases requested with `#[builder(state_aliases)]`.
+    ///
+    /// Unlike the nesting support, the start alias also exists with
+    /// `builder_method`, where it names the state the entry point returns.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<proc_macro2::TokenStream>` containing the aliases.
+    fn generate_state_aliases(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let start_ident = syn::parse_str::<Ident>(&self.state_space.initial().concrete_type_name)?;
+        let ready_ident = syn::parse_str::<Ident>(&self.state_space.complete().concrete_type_name)?;
+        self.token_generator
+            .generate_state_aliases(&start_ident, &ready_ident)
+    }
+
+    /// Generates the implementation of the trait given with `#[builder(buildable)]`.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<proc_macro2::TokenStream>` containing the trait implementation.
+    fn generate_buildable_impl(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let start_ident = syn::parse_str::<Ident>(&self.state_space.initial().concrete_type_name)?;
+        self.token_generator.generate_buildable_impl(&start_ident)
+    }
+
+    /// Finds the builder states with no required fields and with every required field set.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result` containing the initial and the complete state identifiers.
+    fn initial_and_complete_states(&self) -> syn::Result<(Ident, Ident)> {
+        // With builder_method there is no state without required fields
+        let start_state = self.state_space.get(0).ok_or_else(|| {
+            syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "Missing builder state in state combinations",
+            )
+        })?;
+
+        let start_ident = syn::parse_str::<Ident>(&start_state.concrete_type_name)?;
+        let ready_ident = syn::parse_str::<Ident>(&self.state_space.complete().concrete_type_name)?;
+
+        Ok((start_ident, ready_ident))
+    }
+
     /// Generates a normal build method for a complete builder state.
     fn generate_complete_build_method(
         &self,
         state_combination: &StateCombination,
     ) -> syn::Result<proc_macro2::TokenStream> {
         let analysis = self.token_generator.analysis();
-        let struct_name = analysis.struct_name();
         let builder_ident = syn::parse_str::<Ident>(&state_combination.concrete_type_name)?;
 
         let impl_generics = self.token_generator.impl_generics_tokens();
         let type_generics = self.token_generator.type_generics_tokens();
         let where_clause = self.token_generator.where_clause_tokens();
 
-        // Generate field assignments for the final struct
+        // Generate field assignments for the final struct, after resolving
+        // the fields whose values depend on other fields
+        let computed_statements = analysis
+            .all_fields()
+            .map(|field| field.generate_computed_statement());
+        let resolve_statements = analysis
+            .all_fields()
+            .map(|field| field.generate_resolve_statement());
         let struct_field_assignments = self.generate_final_struct_assignments()?;
+        let constructor = analysis.constructor_path();
+        let base = self.token_generator.generate_base_binding();
 
         // Get build method name
         let build_method_name = analysis.struct_attributes().get_build_method_name();
-        let build_method_ident = syn::parse_str::<Ident>(build_method_name)?;
 
         let doc = self.token_generator.generate_method_documentation(
             build_method_name,
             "Builds the final instance after all required fields have been set",
             Some("This method is only available when all required fields have been provided."),
         );
-
-        let const_kw = self.token_generator.const_keyword();
+        let fields_doc = self.token_generator.generate_fields_summary_documentation();
+
+        let build_method = self.token_generator.generate_build_method(
+            &quote! { #doc #fields_doc },
+            &quote! {
+                #(#computed_statements)*
+                #(#resolve_statements)*
+                #base
+                #constructor {
+                    #struct_field_assignments
+                }
+            },
+        )?;
+        let finisher_methods = self.token_generator.generate_finisher_methods()?;
 
         Ok(quote! {
             impl #impl_generics #builder_ident #type_generics #where_clause {
-                #doc
-                pub #const_kw fn #build_method_ident(self) -> #struct_name #type_generics {
-                    #struct_name {
-                        #struct_field_assignments
-                    }
-                }
-            }
-        })
-    }
-
-    // Helper methods for state management
+                #build_method
 
-    /// Generates the complete state space for the type-state builder's finite automaton.
-    ///
-    /// This is the **core algorithm** that creates the state machine structure for compile-time
-    /// validation. It implements a **systematic enumeration** of all possible combinations of
-    /// required field states, creating the foundation for type-safe builder generation.
-    ///
-    /// # The State Generation Algorithm
-    ///
-    /// The algorithm uses **binary enumeration** to generate all possible states:
-    ///
-    /// ## Phase 1: State Space Calculation
-    /// For N required fields, generate exactly 2^N states using bit manipulation.
-    /// Each bit in the state mask represents a required field (0 = not set, 1 = set).
-    ///
-    /// ## Phase 2: Bit-to-Field Mapping
-    /// Convert each bit position to field indices by checking if each bit is set
-    /// in the current state mask, building a list of which fields are set in each state.
-    ///
-    /// ## Phase 3: Type Name Generation
-    /// Create unique, deterministic type names:
-    /// - **Empty state**: `{BaseBuilder}`
-    /// - **Populated states**: `{BaseBuilder}_Has{Field1}_Has{Field2}...`
-    ///
-    /// # Example State Generation
-    ///
-    /// For a struct with required fields name and email, the algorithm generates 4 states:
-    /// - State 0: no fields set → "UserBuilder"
-    /// - State 1: name field set → "UserBuilder_HasName"
-    /// - State 2: email field set → "UserBuilder_HasEmail"  
-    /// - State 3: both fields set → "UserBuilder_HasName_HasEmail"
-    ///
-    /// # Algorithmic Properties
-    ///
-    /// The algorithm guarantees:
-    /// 1. **Completeness**: Every possible field combination is represented
-    /// 2. **Uniqueness**: Each state has a distinct type name
-    /// 3. **Determinism**: Identical inputs produce identical state spaces
-    /// 4. **Correctness**: State transitions are mathematically sound
-    /// 5. **Efficiency**: O(2^N) time complexity (optimal for complete enumeration)
-    ///
-    /// # Theoretical Soundness
-    ///
-    /// This approach is **theoretically complete** because:
-    /// - The bit enumeration covers all 2^N possible boolean combinations
-    /// - Each state corresponds to exactly one concrete builder type
-    /// - State transitions form a **directed acyclic graph** toward completion
-    /// - The final state (all bits set) is the unique terminal state
-    ///
-    /// # Arguments
-    ///
-    /// * `analysis` - The struct analysis containing required field information
-    /// * `base_builder_name` - Base name for builder types used in naming
-    ///
-    /// # Returns
-    ///
-    /// A vector containing all `StateCombination` instances representing the complete state space.
-    fn generate_state_combinations(
-        analysis: &StructAnalysis,
-        base_builder_name: &str,
-    ) -> Vec<StateCombination> {
-        let mut combinations = Vec::new();
-        let num_required_fields = analysis.required_fields().len();
-
-        // Find the builder_method field index if any
-        let builder_method_index = analysis
-            .required_fields()
-            .iter()
-            .position(|f| f.attributes().builder_method);
-
-        // Generate all possible combinations (2^n states)
-        for state_mask in 0..(1 << num_required_fields) {
-            // Skip states where builder_method field is not set
-            // (those states are unreachable when using builder_method)
-            if let Some(bm_index) = builder_method_index {
-                if (state_mask & (1 << bm_index)) == 0 {
-                    continue;
-                }
-            }
-
-            let mut set_fields = Vec::new();
-            let mut has_parts = Vec::new();
-            let mut missing_parts = Vec::new();
-
-            // Process all required fields to determine their state
-            for field_index in 0..num_required_fields {
-                let field_name = &analysis.required_fields()[field_index].name().to_string();
-                let clean_name = strip_raw_identifier_prefix(field_name);
-                let pascal_case_name = snake_case_to_pascal_case(&clean_name);
-
-                if (state_mask & (1 << field_index)) != 0 {
-                    // Field is set
-                    set_fields.push(field_index);
-                    has_parts.push(format!("Has{pascal_case_name}"));
-                } else {
-                    // Field is missing
-                    missing_parts.push(format!("Missing{pascal_case_name}"));
-                }
+                #finisher_methods
             }
-
-            // Build type name: Has fields first, then Missing fields
-            let mut type_name_parts = Vec::new();
-            type_name_parts.extend(has_parts);
-            type_name_parts.extend(missing_parts);
-
-            let concrete_type_name = if type_name_parts.is_empty() {
-                // This should never happen since we always have required fields
-                base_builder_name.to_string()
-            } else {
-                let joined_parts = type_name_parts.join("_");
-                format!("{base_builder_name}_{joined_parts}")
-            };
-
-            combinations.push(StateCombination {
-                set_fields,
-                concrete_type_name,
-            });
-        }
-
-        combinations
-    }
-
-    /// Finds a state combination with a specific field set.
-    ///
-    /// # Arguments
-    ///
-    /// * `field_index` - Index of the field to set
-    /// * `current_set_fields` - Currently set fields
-    ///
-    /// # Returns
-    ///
-    /// A reference to the state combination or an error if not found.
-    fn find_state_with_field_set(
-        &self,
-        field_index: usize,
-        current_set_fields: &[usize],
-    ) -> syn::Result<&StateCombination> {
-        let mut new_set_fields = current_set_fields.to_vec();
-        if !new_set_fields.contains(&field_index) {
-            new_set_fields.push(field_index);
-            new_set_fields.sort_unstable();
-        }
-
-        self.state_combinations
-            .iter()
-            .find(|combo| combo.set_fields == new_set_fields)
-            .ok_or_else(|| {
-                syn::Error::new(
-                    proc_macro2::Span::call_site(),
-                    format!("No state combination found for set_fields: {new_set_fields:?}"),
-                )
-            })
+        })
     }
 }
 
@@ -1251,6 +1804,12 @@ impl<'a> TypeStateBuilderCoordinator<'a> {
 /// deterministic transitions between states.
 #[derive(Debug, Clone)]
 struct StateCombination {
+    /// Bit mask of the required fields set in this state.
+    ///
+    /// Bit `i` is set when the required field at index `i` is set, so the
+    /// mask identifies the state and a transition is a single bitwise or.
+    mask: usize,
+
     /// Indices of required fields that are set in this state.
     ///
     /// This vector is always:
@@ -1271,6 +1830,190 @@ struct StateCombination {
     concrete_type_name: String,
 }
 
+impl StateCombination {
+    /// Returns whether the required field at `field_index` is set in this state.
+    fn is_set(&self, field_index: usize) -> bool {
+        self.mask & (1 << field_index) != 0
+    }
+}
+
+/// The complete state space of the type-state builder's finite automaton.
+///
+/// This is the **core algorithm** that creates the state machine structure for compile-time
+/// validation. It implements a **systematic enumeration** of all possible combinations of
+/// required field states, creating the foundation for type-safe builder generation.
+///
+/// # The State Generation Algorithm
+///
+/// The algorithm uses **binary enumeration** to generate all possible states:
+///
+/// ## Phase 1: State Space Calculation
+/// For N required fields, generate exactly 2^N states using bit manipulation.
+/// Each bit in the state mask represents a required field (0 = not set, 1 = set).
+///
+/// ## Phase 2: Bit-to-Field Mapping
+/// Convert each bit position to field indices by checking if each bit is set
+/// in the current state mask, building a list of which fields are set in each state.
+///
+/// ## Phase 3: Type Name Generation
+/// Create unique, deterministic type names:
+/// - **Empty state**: `{BaseBuilder}`
+/// - **Populated states**: `{BaseBuilder}_Has{Field1}_Has{Field2}...`
+///
+/// # Example State Generation
+///
+/// For a struct with required fields name and email, the algorithm generates 4 states:
+/// - State 0: no fields set → "UserBuilder"
+/// - State 1: name field set → "UserBuilder_HasName"
+/// - State 2: email field set → "UserBuilder_HasEmail"  
+/// - State 3: both fields set → "UserBuilder_HasName_HasEmail"
+///
+/// # Algorithmic Properties
+///
+/// The algorithm guarantees:
+/// 1. **Completeness**: Every possible field combination is represented
+/// 2. **Uniqueness**: Each state has a distinct type name
+/// 3. **Determinism**: Identical inputs produce identical state spaces
+/// 4. **Correctness**: State transitions are mathematically sound
+/// 5. **Efficiency**: O(2^N) time complexity (optimal for complete enumeration)
+///
+/// # Theoretical Soundness
+///
+/// This approach is **theoretically complete** because:
+/// - The bit enumeration covers all 2^N possible boolean combinations
+/// - Each state corresponds to exactly one concrete builder type
+/// - State transitions form a **directed acyclic graph** toward completion
+/// - The final state (all bits set) is the unique terminal state
+///
+/// # Enumeration on Demand
+///
+/// The states are not collected up front: [`StateSpace::states`] produces them
+/// one at a time from their masks, and the transition of a setter is computed
+/// from the mask rather than searched for. Generating a builder with many
+/// required fields therefore keeps a single state in memory at a time, and the
+/// `Has{Field}`/`Missing{Field}` name parts are computed once per field.
+struct StateSpace {
+    /// Base name for builder types
+    base_builder_name: String,
+
+    /// `Has{Field}` name part of each required field
+    has_parts: Vec<String>,
+
+    /// `Missing{Field}` name part of each required field
+    missing_parts: Vec<String>,
+
+    /// Bits set in every reachable state: the `builder_method` field, if any,
+    /// is set by the entry point, so states without it are unreachable
+    fixed_mask: usize,
+}
+
+impl StateSpace {
+    /// Creates the state space for the required fields of a struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `analysis` - The struct analysis containing required field information
+    /// * `base_builder_name` - Base name for builder types used in naming
+    ///
+    /// # Returns
+    ///
+    /// A `StateSpace` covering every reachable state.
+    fn new(analysis: &StructAnalysis, base_builder_name: &str) -> Self {
+        let mut has_parts = Vec::new();
+        let mut missing_parts = Vec::new();
+        for field in analysis.required_fields() {
+            let field_name = field.name().to_string();
+            let clean_name = strip_raw_identifier_prefix(&field_name);
+            let pascal_case_name = snake_case_to_pascal_case(&clean_name);
+            has_parts.push(format!("Has{pascal_case_name}"));
+            missing_parts.push(format!("Missing{pascal_case_name}"));
+        }
+
+        let fixed_mask = analysis
+            .required_fields()
+            .iter()
+            .position(|field| field.attributes().builder_method)
+            .map_or(0, |field_index| 1 << field_index);
+
+        Self {
+            base_builder_name: base_builder_name.to_string(),
+            has_parts,
+            missing_parts,
+            fixed_mask,
+        }
+    }
+
+    /// Returns the mask with every required field set.
+    fn complete_mask(&self) -> usize {
+        (1 << self.has_parts.len()) - 1
+    }
+
+    /// Enumerates the reachable states in mask order.
+    fn states(&self) -> impl Iterator<Item = StateCombination> + '_ {
+        (0..=self.complete_mask())
+            .filter(move |mask| mask & self.fixed_mask == self.fixed_mask)
+            .map(move |mask| self.state(mask))
+    }
+
+    /// Returns the state with the given mask, or `None` if it is unreachable.
+    fn get(&self, mask: usize) -> Option<StateCombination> {
+        (mask & self.fixed_mask == self.fixed_mask && mask <= self.complete_mask())
+            .then(|| self.state(mask))
+    }
+
+    /// Returns the state the builder starts in.
+    fn initial(&self) -> StateCombination {
+        self.state(self.fixed_mask)
+    }
+
+    /// Returns the state with every required field set.
+    fn complete(&self) -> StateCombination {
+        self.state(self.complete_mask())
+    }
+
+    /// Returns whether every required field is set in a state.
+    fn is_complete(&self, state: &StateCombination) -> bool {
+        state.mask == self.complete_mask()
+    }
+
+    /// Returns the state a setter transitions to from `state`.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The current state
+    /// * `field_index` - Index of the field the setter sets
+    fn with_field_set(&self, state: &StateCombination, field_index: usize) -> StateCombination {
+        self.state(state.mask | (1 << field_index))
+    }
+
+    /// Builds the state for a mask.
+    fn state(&self, mask: usize) -> StateCombination {
+        let num_required_fields = self.has_parts.len();
+        let set_fields: Vec<usize> = (0..num_required_fields)
+            .filter(|field_index| mask & (1 << field_index) != 0)
+            .collect();
+
+        // Build type name: Has fields first, then Missing fields
+        let has_parts = set_fields
+            .iter()
+            .map(|&field_index| &self.has_parts[field_index]);
+        let missing_parts = (0..num_required_fields)
+            .filter(|field_index| mask & (1 << field_index) == 0)
+            .map(|field_index| &self.missing_parts[field_index]);
+        let mut concrete_type_name = self.base_builder_name.clone();
+        for part in has_parts.chain(missing_parts) {
+            concrete_type_name.push('_');
+            concrete_type_name.push_str(part);
+        }
+
+        StateCombination {
+            mask,
+            set_fields,
+            concrete_type_name,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1304,6 +2047,33 @@ mod tests {
         assert!(code.contains("build"));
     }
 
+    #[test]
+    fn test_generate_builder_with_const_expression_field() {
+        let input = parse_quote! {
+            struct Packet<const N: usize>
+            where
+                [(); N * 2]:,
+            {
+                #[builder(required)]
+                payload: [u8; N * 2],
+                tag: u8,
+            }
+        };
+
+        let analysis = analyze_struct(&input).unwrap();
+        let code = generate_type_state_builder(&analysis).unwrap().to_string();
+
+        // Every builder state carries the bound that makes `N * 2` well-formed
+        assert!(code.contains(
+            "struct PacketBuilder_MissingPayload < const N : usize > where [() ; N * 2] : ,"
+        ));
+        assert!(code.contains(
+            "impl < const N : usize > PacketBuilder_HasPayload < N > where [() ; N * 2] : ,"
+        ));
+        assert!(code.contains("payload : :: core :: option :: Option < [u8 ; N * 2] >"));
+        assert!(!code.contains("PhantomData"));
+    }
+
     #[test]
     fn test_state_combinations_generation() {
         let input = parse_quote! {
@@ -1316,8 +2086,7 @@ mod tests {
         };
 
         let analysis = analyze_struct(&input).unwrap();
-        let combinations =
-            TypeStateBuilderCoordinator::generate_state_combinations(&analysis, "TestBuilder");
+        let combinations: Vec<_> = StateSpace::new(&analysis, "TestBuilder").states().collect();
 
         // Should have 2^2 = 4 combinations for 2 required fields
         assert_eq!(combinations.len(), 4);
@@ -1338,6 +2107,49 @@ mod tests {
             .any(|name| name.as_str() == "TestBuilder_HasName_HasEmail")); // Both set
     }
 
+    #[test]
+    fn test_state_space_transitions() {
+        let input = parse_quote! {
+            struct Example {
+                #[builder(required, builder_method)]
+                id: u32,
+                #[builder(required)]
+                name: String,
+                #[builder(required)]
+                r#type: String,
+            }
+        };
+
+        let analysis = analyze_struct(&input).unwrap();
+        let state_space = StateSpace::new(&analysis, "ExampleBuilder");
+
+        // States without the builder_method field are unreachable
+        assert_eq!(state_space.states().count(), 4);
+        assert!(state_space.get(0).is_none());
+
+        let initial = state_space.initial();
+        assert_eq!(
+            initial.concrete_type_name,
+            "ExampleBuilder_HasId_MissingName_MissingType"
+        );
+        assert_eq!(initial.set_fields, [0]);
+
+        let named = state_space.with_field_set(&initial, 1);
+        assert_eq!(
+            named.concrete_type_name,
+            "ExampleBuilder_HasId_HasName_MissingType"
+        );
+        assert!(named.is_set(1) && !named.is_set(2));
+        assert!(!state_space.is_complete(&named));
+
+        let complete = state_space.with_field_set(&named, 2);
+        assert_eq!(
+            complete.concrete_type_name,
+            state_space.complete().concrete_type_name
+        );
+        assert!(state_space.is_complete(&complete));
+    }
+
     #[test]
     fn test_coordinator_creation() {
         let input = parse_quote! {
@@ -1349,9 +2161,207 @@ mod tests {
 
         let analysis = analyze_struct(&input).unwrap();
         let token_generator = TokenGenerator::new(&analysis);
-        let coordinator = TypeStateBuilderCoordinator::new(&token_generator);
+        let coordinator = TypeStateBuilderCoordinator::new(&token_generator).unwrap();
 
-        assert_eq!(coordinator.state_combinations.len(), 2); // 2^1 = 2 states
+        assert_eq!(coordinator.state_space.states().count(), 2); // 2^1 = 2 states
         assert_eq!(coordinator._base_builder_name, "ExampleBuilder");
     }
+
+    #[test]
+    fn test_non_exhaustive_struct() {
+        let input = parse_quote! {
+            #[non_exhaustive]
+            pub struct Config {
+                #[builder(required)]
+                pub name: String,
+                pub port: Option<u16>,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let code = generate_type_state_builder(&analysis).unwrap().to_string();
+
+        // The builder states follow the struct and builder() explains why it exists
+        assert!(code.contains("# [non_exhaustive] pub struct ConfigBuilder_MissingName"));
+        assert!(code.contains("\"# Non-exhaustive\""));
+
+        let input = parse_quote! {
+            #[non_exhaustive]
+            #[builder(non_exhaustive = false)]
+            pub struct Config {
+                #[builder(required)]
+                pub name: String,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let code = generate_type_state_builder(&analysis).unwrap().to_string();
+        assert!(!code.contains("# [non_exhaustive]"));
+        assert!(code.contains("\"# Non-exhaustive\""));
+    }
+
+    #[test]
+    fn test_non_exhaustive_attribute_marks_every_state() {
+        let input = parse_quote! {
+            #[builder(non_exhaustive)]
+            pub struct Config {
+                #[builder(required)]
+                pub name: String,
+                #[builder(required)]
+                pub host: String,
+                pub port: Option<u16>,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let code = generate_type_state_builder(&analysis).unwrap().to_string();
+
+        // All four states of the two required fields, and nothing else
+        for state in [
+            "ConfigBuilder_MissingName_MissingHost",
+            "ConfigBuilder_HasName_MissingHost",
+            "ConfigBuilder_HasHost_MissingName",
+            "ConfigBuilder_HasName_HasHost",
+        ] {
+            assert!(code.contains(&format!("# [non_exhaustive] pub struct {state}")));
+        }
+        assert_eq!(code.matches("# [non_exhaustive]").count(), 4);
+        // The struct itself is exhaustive, so builder() has no note about it
+        assert!(!code.contains("\"# Non-exhaustive\""));
+    }
+
+    #[test]
+    fn test_state_diagram_documentation() {
+        let input = parse_quote! {
+            struct Server {
+                #[builder(required)]
+                host: String,
+                #[builder(required)]
+                port: u16,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let token_generator = TokenGenerator::new(&analysis);
+        let coordinator = TypeStateBuilderCoordinator::new(&token_generator).unwrap();
+        let doc = coordinator
+            .generate_state_diagram_documentation()
+            .to_string();
+        let lines: Vec<&str> = doc
+            .split("# [doc = ")
+            .filter_map(|line| line.trim().strip_suffix(']'))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "\"\"",
+                "\"# States\"",
+                "\"\"",
+                "\"```text\"",
+                "\"ServerBuilder_MissingHost_MissingPort\"",
+                "\"    .host() -> ServerBuilder_HasHost_MissingPort\"",
+                "\"    .port() -> ServerBuilder_HasPort_MissingHost\"",
+                "\"ServerBuilder_HasHost_MissingPort\"",
+                "\"    .port() -> ServerBuilder_HasHost_HasPort\"",
+                "\"ServerBuilder_HasPort_MissingHost\"",
+                "\"    .host() -> ServerBuilder_HasHost_HasPort\"",
+                "\"ServerBuilder_HasHost_HasPort\"",
+                "\"    .build() -> Server\"",
+                "\"```\"",
+            ]
+        );
+
+        // Larger state machines only show where they start and end
+        let input = parse_quote! {
+            #[builder(build_method = "finish")]
+            struct Wide {
+                #[builder(required)] a: u8,
+                #[builder(required)] b: u8,
+                #[builder(required)] c: u8,
+                #[builder(required)] d: u8,
+                #[builder(required)] e: u8,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let token_generator = TokenGenerator::new(&analysis);
+        let coordinator = TypeStateBuilderCoordinator::new(&token_generator).unwrap();
+        let doc = coordinator
+            .generate_state_diagram_documentation()
+            .to_string();
+        assert!(doc.contains("\"    a(), b(), c(), d(), e() in any order\""));
+        assert!(doc.contains("\"WideBuilder_HasA_HasB_HasC_HasD_HasE\""));
+        assert!(doc.contains("\"    .finish() -> Wide\""));
+        assert!(!doc.contains("WideBuilder_HasA_MissingB"));
+    }
+
+    #[test]
+    fn test_no_entry() {
+        let input = parse_quote! {
+            #[builder(no_entry, nestable)]
+            pub struct Client {
+                #[builder(required)]
+                pub handle: u32,
+                #[builder(required)]
+                pub name: String,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let code = generate_type_state_builder(&analysis).unwrap().to_string();
+
+        // The states and their constructor remain, only the entry method is gone,
+        // and nesting starts from the constructor
+        assert!(!code.contains("fn builder"));
+        assert!(code.contains("pub fn new () -> Self"));
+        assert!(code.contains("pub fn build (self) -> Client"));
+        assert!(code.contains("configure (< ClientBuilder_MissingHandle_MissingName > :: new ())"));
+    }
+
+    #[test]
+    fn test_field_docs_propagated_to_setters_and_build() {
+        let input = parse_quote! {
+            struct Example {
+                /// The user's display name.
+                #[builder(required)]
+                name: String,
+                /// Age in years.
+                ///
+                /// Leave unset when unknown.
+                age: Option<u32>,
+                count: u32,
+            }
+        };
+
+        let analysis = analyze_struct(&input).unwrap();
+        let code = generate_type_state_builder(&analysis).unwrap().to_string();
+
+        // Required and optional setters carry the field docs
+        assert!(code.contains("\" The user's display name.\""));
+        assert!(code.contains("\" Leave unset when unknown.\""));
+        // The build method lists documented fields only
+        assert!(code.contains("\"- `name` (required): The user's display name.\""));
+        assert!(code.contains("\"- `age`: Age in years.\""));
+        assert!(!code.contains("- `count`:"));
+        // builder() lists every setter, documented or not
+        assert!(code.contains("\"# Required\""));
+        assert!(code.contains("\"- `count`\""));
+    }
+
+    #[test]
+    fn test_setters_carry_doc_aliases() {
+        let input = parse_quote! {
+            #[builder(setter_prefix = "with_")]
+            struct Pool {
+                #[builder(required, alias = "max_connections")]
+                max_conns: u32,
+                #[builder(setter_name = "timeout")]
+                timeout_secs: Option<u64>,
+                idle: bool,
+            }
+        };
+
+        let analysis = analyze_struct(&input).unwrap();
+        let code = generate_type_state_builder(&analysis).unwrap().to_string();
+
+        assert!(
+            code.contains("# [doc (alias = \"max_conns\")] # [doc (alias = \"max_connections\")]")
+        );
+        assert!(code.contains("# [doc (alias = \"timeout_secs\")]"));
+        assert!(code.contains("# [doc (alias = \"idle\")]"));
+    }
 }
@@ -36,12 +36,25 @@
 //!
 //! This approach generates more structs than type-parameter-based solutions, slightly
 //! increasing compile time. However, the improved error message clarity is worth this
-//! cost. There is no runtime cost due to Rust's zero-cost abstractions.
+//! cost. The states add no runtime checks, and optimized builds are expected to
+//! inline the setters into direct writes, though that is up to the optimizer.
 //!
 //! # Compatibility
 //!
 //! - **no_std**: Fully compatible. Generated code uses only `core` types.
+//! - **Hygiene**: Generated code names `core` items by absolute paths such as
+//!   `::core::option::Option`, so items of the same name in the deriving
+//!   module do not change its meaning. The one exception is `Box`, which
+//!   `lazy_setter` fields take from the prelude or an `alloc` import.
 //! - **MSRV**: Rust 1.70.0 or later.
+//! - **Features**: `zeroize` enables `#[builder(sensitive)]`; the generated code
+//!   then refers to the `zeroize` crate, which must be a dependency of your crate.
+//!   Likewise, `arbitrary` enables `#[builder(arbitrary)]` and refers to the
+//!   `arbitrary` crate, and `serde` enables `#[builder(derive(Serialize, Deserialize))]`
+//!   and refers to the `serde` crate. `std-converters` enables the built-in
+//!   `std_converters::*` converters. `std` implements `std::error::Error` for
+//!   every generated error type; it is off by default so the generated code
+//!   stays `no_std` compatible, and `env` turns it on.
 //!
 //! # Overview
 //!
@@ -52,6 +65,10 @@
 //! - **Regular Builder**: For structs with only optional fields, providing a simple
 //!   builder with immediate `build()` availability
 //!
+//! Doc comments on fields are copied onto their setters, and the build method
+//! lists the documented fields with the first line of their documentation.
+//! A `#[deprecated]` attribute on a field is copied onto its setter as well.
+//!
 //! # Quick Start
 //!
 //! Add the derive macro to your struct and mark required fields:
@@ -96,20 +113,92 @@
 //!
 //! - `#[builder(build_method = "method_name")]` - Custom build method name
 //! - `#[builder(setter_prefix = "prefix_")]` - Prefix for all setter method names
+//! - `#[builder(setter_prefix(except(field, ...)))]` - Fields whose setters keep their names despite the struct-level prefix
+//! - `#[builder(prefix_scope = "optional")]` - Apply the struct-level prefix to the setters of optional fields only
 //! - `#[builder(impl_into)]` - Generate setters with `impl Into<FieldType>` parameters
 //! - `#[builder(const)]` - Generate `const fn` builder methods for compile-time construction
+//! - `#[builder(state_traits)]` - Generate traits implemented by the builder states for extension traits
+//! - `#[builder(setter_trait)]` - Generate a `{Struct}BuilderSetters` trait with the optional setters, implemented by every state
+//! - `#[builder(into_iter)]` - `Vec<T>` setters accept `impl IntoIterator<Item = impl Into<T>>`
+//! - `#[builder(all_required)]` - Make every field required unless it is `optional`, has a `default`, or skips its setter
+//! - `#[builder(assert_send)]` / `#[builder(assert_send_sync)]` - Fail to compile unless every builder state is `Send` (and `Sync`)
+//! - `#[builder(static_assert(Send, Sync, Unpin))]` - Fail to compile unless every builder state implements the listed auto traits
+//! - `#[builder(module = "name")]` - Generate the builder types into a child module
+//! - `#[builder(finishers(...))]` - Additional build methods that validate and/or convert the built value
+//! - `#[builder(no_docs)]` - Leave generated doc comments off the builder items
+//! - `#[builder(hide_states)]` - Keep the intermediate builder states out of the enclosing namespace
+//! - `#[builder(stable_names)]` - Make the state aliases the only public names of the builder states
+//! - `#[builder(build_by = "clone")]` - Build from `&self` by cloning the fields, so a complete builder can be reused
+//! - `#[builder(option_into)]` - Optional `Option<T>` setters accept `impl Into<Option<T>>`, i.e. `value` or `Some(value)`
+//! - `#[builder(lite)]` - Generate `new(required...)` and `with_*` methods on the struct instead of builder types
+//! - `#[builder(lints = "allow" | "warn" | "deny")]` - How builder lints are reported (default `"warn"`)
+//! - `#[builder(debug_expand)]` - Show the generated code in a compiler warning
+//! - `#[builder(arbitrary)]` - Implement `arbitrary::Arbitrary` by driving the builder (`arbitrary` feature)
+//! - `#[builder(apply_env)]` - Add `apply_env(prefix)`, which sets optional fields from environment variables (`env` feature)
+//! - `#[builder(field_descriptors = "path::FieldDescriptor")]` - Describe the fields in a `FIELDS` constant (`metadata` feature)
+//! - `#[builder(test_default)]` / `#[builder(test_default(cfg(predicate)))]` - Add `test_builder()`, a complete builder for tests (under `cfg(test)` by default)
+//! - `#[builder(non_exhaustive)]` / `#[builder(non_exhaustive = false)]` - Whether the builder types are `#[non_exhaustive]` (default: like the struct)
+//! - `#[builder(build_vis = "pub(crate)")]` - Visibility of the build method, leaving the finishers as the public way to build
+//! - `#[builder(entry_vis = "pub(crate)")]` - Visibility of `builder()` and the builder constructors
+//! - `#[builder(no_entry)]` - Generate the builder types but no `builder()` method
+//! - `#[builder(default_struct = "Config::default()")]` - Take unset optional fields from a template instance
+//! - `#[builder(base = "Config::default()")]` - Take unset optional fields from an instance created at build time
+//! - `#[builder(fill_from)]` - Add `fill_from(&Struct)`, which copies the optional fields that are still unset from an instance
+//! - `#[builder(derive(Clone))]` / `#[builder(derive(Copy, Clone))]` - Implement `Clone` (and `Copy`) for the builder states
+//! - `#[builder(derive(Serialize, Deserialize))]` - Derive serde's traits for the builder states (`serde` feature)
+//! - `#[builder(impl_trait = "path::Trait")]` - Implement a trait whose items all have defaults for every builder state
+//! - `#[builder(buildable = "path::Buildable")]` - Implement a trait with `type Builder` and `fn builder()` for the struct
+//! - `#[builder(also_dynamic)]` - Also generate `{Struct}DynBuilder`, which reports unset required fields when building
+//! - `#[builder(nestable)]` - Let `#[builder(nested)]` and `nested_each` fields of other structs nest this builder
+//! - `#[builder(state_aliases)]` / `#[builder(state_aliases(start = "...", complete = "..."))]` - Public aliases for the initial and complete builder states
+//! - `#[builder(boxed)]` - Keep the builder's values on the heap and build a `Box<Struct>`
 //!
 //! ## Field-level Attributes
 //!
 //! - `#[builder(required)]` - Mark field as required
+//! - `#[builder(required(cfg(predicate)))]` - Mark field as required only when the predicate holds
 //! - `#[builder(setter_name = "name")]` - Custom setter method name
+//! - `#[builder(alias = "name")]` - Extra `#[doc(alias)]` on the setter, e.g. an old field name (repeatable)
 //! - `#[builder(setter_prefix = "prefix_")]` - Custom prefix for setter method name
-//! - `#[builder(default = expression)]` - Custom default value
-//! - `#[builder(skip_setter)]` - Don't generate setter (requires default)
+//! - `#[builder(default = expression)]` - Custom default value, type-checked against the field (e.g. `default = 42`, `default = true`)
+//! - `#[builder(default = serde)]` - Reuse the field's `#[serde(default = "path")]` function as the default
+//! - `#[builder(skip_setter)]` - Don't generate setter (the field uses its `default`, or `Default::default()`)
 //! - `#[builder(impl_into)]` - Generate setter with `impl Into<FieldType>` parameter
 //! - `#[builder(impl_into = false)]` - Override struct-level `impl_into` for this field
 //! - `#[builder(converter = |param: InputType| -> FieldType { expression })` - Custom conversion logic for setter input
+//! - `#[builder(converter = std_converters::name)]` - A built-in converter (`std-converters` feature)
 //! - `#[builder(builder_method)]` - Use this field's setter as the builder entry point (replaces `builder()`)
+//! - `#[builder(required, transparent)]` - Required `Option<T>` field whose setter takes `Option<T>`, so `None` must be passed explicitly
+//! - `#[builder(into_iter)]` - `Vec<T>` setter accepts `impl IntoIterator<Item = impl Into<T>>`
+//! - `#[builder(into_iter = false)]` - Override struct-level `into_iter` for this field
+//! - `#[builder(optional)]` - Keep this field optional under struct-level `all_required`
+//! - `#[builder(skip_debug)]` - Leave this field out of the builder's `Debug` output
+//! - `#[builder(redact)]` - Print this field as `"***"` in the builder's `Debug` output
+//! - `#[builder(sensitive)]` - Hold the value in `zeroize::Zeroizing` so abandoned builders wipe it (`zeroize` feature)
+//! - `#[builder(skip_env)]` - Leave the field out of the struct-level `apply_env` method
+//! - `#[builder(nested)]` - Add a `{setter}_with` method that configures the field through its own (`nestable`) builder
+//! - `#[builder(nested_each = "add_child")]` - Add a method that builds one element of a `Vec` field through its own builder and pushes it
+//! - `#[builder(cfg(predicate))]` - Only generate this optional field's setter when the predicate holds
+//! - `#[builder(resolve = |value: T, other: &U| expression)]` - Transform the value at build time using other fields
+//! - `#[builder(lazy_setter)]` - Add a `{setter}_with` method taking a closure that only runs at build time
+//! - `#[builder(default_from = |b| expression)]` - Compute the default at build time from other fields
+//! - `#[builder(computed = |b| expression)]` - Always compute the value at build time (no setter)
+//! - `#[builder(try_setter)]` - Add a `try_{setter}` method that converts its argument with `TryFrom`
+//! - `#[builder(setter(also_ref))]` - Add a `{setter}_ref` method that takes `&T` and clones it
+//! - `#[builder(clone_from_ref)]` - Make the setter take `&T` (`&str` for `String`, `&[T]` for `Vec<T>`) and store an owned copy
+//! - `#[builder(wrap = Inner)]` / `#[builder(wrap(Inner, with = path))]` - Make the setter of a newtype field take the inner type and wrap it
+//! - `#[builder(setter_input = Type)]` / `#[builder(setter_input = Type, via = TryFrom)]` - Make the setter take `Type` and convert it with `From` (default) or `TryFrom`
+//! - `#[builder(dyn_into)]` - Make the setter of a `Box`, `Rc` or `Arc` trait object field take `impl Trait` and allocate the pointer
+//! - `#[builder(test_default = expression)]` - The value of a required field in the struct-level `test_builder()` (default: `Default::default()`)
+//! - `#[builder(boxed_field)]` - Keep this field's value in a `Box` while the builder moves between states
+//! - `#[builder(default_through_converter)]` - Write the `default` in the converter's input type
+//! - `#[builder(builder_only)]` - Set the field on the builder but leave it out of the struct (`#[type_state_builder]` only)
+//! - `#[builder(accumulate = |acc, value: T| statement)]` - Make the setter add its input to the value instead of replacing it
+//! - `#[builder(validate = |value: &T| condition)]` - Check the value in the build method, which then returns a `Result`
+//! - `#[builder(non_empty)]` - Check in the build method that the value is not empty
+//! - `#[builder(range = start..=end)]` - Check in the build method that the value lies in the range
+//! - `#[builder(validate_message = "message")]` - The error message when a `validate`, `non_empty` or `range` check fails
+//! - `#[builder(validate_on_set)]` - Run the field's checks in its setter, which then returns a `Result`
 //!
 //! # Advanced Examples
 //!
@@ -140,6 +229,163 @@
 //!     .create(); // Custom build method name
 //! ```
 //!
+//! ## Sharing Defaults with Serde
+//!
+//! A field that already declares a serde default can reuse it with
+//! `default = serde`, so deserialization and the builder cannot drift apart.
+//! `#[serde(default = "path")]` becomes a call to `path()` and a bare
+//! `#[serde(default)]` becomes `Default::default()`:
+//!
+//! ```
+//! use serde::Deserialize;
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder, Deserialize)]
+//! struct Config {
+//!     #[builder(required)]
+//!     host: String,
+//!
+//!     #[serde(default = "default_port")]
+//!     #[builder(default = serde)]
+//!     port: u16,
+//! }
+//!
+//! fn default_port() -> u16 {
+//!     8080
+//! }
+//!
+//! let config = Config::builder().host("localhost".to_string()).build();
+//! assert_eq!(config.port, 8080);
+//! ```
+//!
+//! Only the attribute is read, so serde is not a dependency of this crate.
+//!
+//! ## Starting From a Template with `default_struct`
+//!
+//! Instead of a `default` on every field, `#[builder(default_struct = ...)]`
+//! names an instance of the struct to take the unset optional fields from.
+//! The expression may be a string or written out, and may use `Self`:
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder, Debug)]
+//! #[builder(default_struct = "Config::production()")]
+//! struct Config {
+//!     #[builder(required)]
+//!     name: String,
+//!     workers: usize,
+//!     log_level: String,
+//!     #[builder(default = false)]
+//!     debug: bool,
+//! }
+//!
+//! impl Config {
+//!     fn production() -> Self {
+//!         Config {
+//!             name: String::new(),
+//!             workers: 16,
+//!             log_level: "warn".to_string(),
+//!             debug: true,
+//!         }
+//!     }
+//! }
+//!
+//! let config = Config::builder().name("api".to_string()).workers(4).build();
+//! assert_eq!(config.workers, 4);
+//! assert_eq!(config.log_level, "warn");
+//! assert!(!config.debug); // A field's own `default` still wins
+//! ```
+//!
+//! The template is evaluated once when the builder is created, and its
+//! required fields are ignored. Because the fields are moved out of it, the
+//! struct cannot implement `Drop`.
+//!
+//! ### Layering Over a Base with `base`
+//!
+//! `#[builder(base = ...)]` works like functional update syntax,
+//! `Config { set_fields.., ..base }`: the build method evaluates the base and
+//! takes every optional field that was never set from it. Fields that skip
+//! their setter always come from the base:
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder, Debug)]
+//! #[builder(base = "Config::from_env()")]
+//! struct Config {
+//!     #[builder(required)]
+//!     name: String,
+//!     workers: usize,
+//!     #[builder(skip_setter)]
+//!     log_level: String,
+//! }
+//!
+//! impl Config {
+//!     fn from_env() -> Self {
+//!         Config {
+//!             name: String::new(),
+//!             workers: 8,
+//!             log_level: "info".to_string(),
+//!         }
+//!     }
+//! }
+//!
+//! let config = Config::builder().name("api".to_string()).build();
+//! assert_eq!(config.workers, 8);
+//! assert_eq!(config.log_level, "info");
+//! ```
+//!
+//! Unlike `default_struct`, the base is evaluated each time the struct is
+//! built, so it sees the latest layer of configuration. Until then, the
+//! builder holds the fields it takes from the base as `Option<T>`, which is
+//! what `default_from` and `computed` closures read. Fields with a `default`,
+//! `default_from`, `computed`, `lazy_setter`, `sensitive` or `resolve` of
+//! their own keep it. `base` cannot be combined with `default_struct`,
+//! `const` or `lite`.
+//!
+//! ### Filling Unset Fields with `fill_from`
+//!
+//! `base` is fixed when the struct is declared. To layer a builder over
+//! instances only known at runtime, `#[builder(fill_from)]` adds a
+//! `fill_from(&Struct)` method to every builder state. It clones each optional
+//! field that was not set yet from the given instance, so calling it for each
+//! layer, from the most specific to the least, keeps the first value found:
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder, Clone)]
+//! #[builder(fill_from)]
+//! struct Config {
+//!     #[builder(required)]
+//!     name: String,
+//!     workers: usize,
+//!     log_level: Option<String>,
+//! }
+//!
+//! let file = Config { name: String::new(), workers: 0, log_level: Some("debug".to_string()) };
+//! let defaults = Config { name: String::new(), workers: 8, log_level: None };
+//!
+//! let config = Config::builder()
+//!     .name("api".to_string())
+//!     .workers(4)
+//!     .fill_from(&file)
+//!     .fill_from(&defaults)
+//!     .build();
+//! assert_eq!(config.workers, 4);
+//! assert_eq!(config.log_level.as_deref(), Some("debug"));
+//! ```
+//!
+//! The filled fields must be `Clone`, and fields that are never set nor filled
+//! keep their defaults. The builder tracks which fields were set by holding
+//! them as `Option<T>` until the build method, where the defaults are
+//! evaluated, so `default_from` and `computed` closures read the `Option`, and
+//! `resolve` cannot read them. Required fields are never filled, and neither
+//! are fields with `computed`, `accumulate`, `nested_each`, `lazy_setter`,
+//! `sensitive` or `resolve`. `fill_from` cannot be combined with
+//! `default_struct`, `const` or `lite`.
+//!
 //! ## Generic Types and Lifetimes
 //!
 //! ```
@@ -166,6 +412,51 @@
 //!     .build();
 //! ```
 //!
+//! No `Container::<i32>::builder()` turbofish is needed: the builder states
+//! carry the struct's generic parameters and the `value` setter binds `T`.
+//! Setters taking `impl Into<T>` or a `converter` argument cannot infer `T`,
+//! so annotate the built value or use the turbofish for those fields.
+//!
+//! Every builder state has its own impl blocks, each repeating the struct's
+//! bounds. When a type parameter has three or more trait bounds, they are
+//! written once in a hidden helper trait (`__{Struct}{Param}Bounds`) with a
+//! blanket implementation, and the impl blocks require just that trait. Bounds
+//! that mention other generic parameters, lifetime bounds and `?Sized` stay
+//! as they are.
+//!
+//! An optional field without a `default`, such as `value: T`, starts at
+//! `Default::default()` and so needs `T: Default`. That bound is only placed
+//! on the methods that start the builder (`builder()`, `new()`, a
+//! `builder_method` entry point), so the builder states and their setters can
+//! be named and used for any `T`.
+//!
+//! Defaults may refer to the struct's generic parameters, as in
+//! `#[builder(default = T::default())]` or `default = Vec::<T>::new()`. The
+//! `Default` bounds of the `Default::default` calls in a default (`T::default()`,
+//! `<T as Default>::default()`) are added to those same methods, so the struct
+//! does not need to declare them. Bounds for other trait calls, such as
+//! `T::new()`, still go on the struct.
+//!
+//! Field types may compute with const generics, as in `[u8; N * 2]` under the
+//! nightly `generic_const_exprs` feature. The `where [(); N * 2]:` bound the
+//! compiler asks for on the struct is repeated on every builder state, so the
+//! builder needs nothing extra:
+//!
+//! ```rust,ignore
+//! #![feature(generic_const_exprs)]
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Packet<const N: usize>
+//! where
+//!     [(); N * 2]:,
+//! {
+//!     #[builder(required)]
+//!     payload: [u8; N * 2],
+//! }
+//!
+//! let packet = Packet::<2>::builder().payload([0; 4]).build();
+//! ```
+//!
 //! ## Setter Prefix Examples
 //!
 //! ```
@@ -215,6 +506,72 @@
 //!     .build();
 //! ```
 //!
+//! `setter_prefix(except(...))` lists fields whose setters keep their natural
+//! names, such as a `builder_method` entry point. A field-level prefix still
+//! applies to a listed field.
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(setter_prefix = "with_", setter_prefix(except(id)))]
+//! struct Order {
+//!     #[builder(required, builder_method)]
+//!     id: u64,
+//!
+//!     #[builder(required)]
+//!     customer: String,
+//! }
+//!
+//! let order = Order::id(7).with_customer("ada".to_string()).build();
+//! ```
+//!
+//! `prefix_scope = "optional"` keeps the bare names for every required
+//! setter, which make up the chain that has to be written out, and prefixes
+//! only the setters of optional fields.
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(setter_prefix = "with_", prefix_scope = "optional")]
+//! struct Request {
+//!     #[builder(required)]
+//!     url: String,
+//!
+//!     #[builder(default = 30)]
+//!     timeout: u32,
+//! }
+//!
+//! let request = Request::builder()
+//!     .url("https://example.com".to_string())
+//!     .with_timeout(5)
+//!     .build();
+//! ```
+//!
+//! A prefix is joined to a raw identifier without its `r#`: the field
+//! `r#type` gets the setter `with_type` under `setter_prefix = "with_"`, and
+//! keeps `r#type` without a prefix.
+//!
+//! A renamed or prefixed setter carries `#[doc(alias = "field")]`, so rustdoc
+//! and rust-analyzer find `with_max_conns` when searching for `max_conns`.
+//! `#[builder(alias = "...")]` adds further search names, such as the name a
+//! field had before it was renamed.
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(setter_prefix = "with_")]
+//! struct Pool {
+//!     // Found as `max_conns` and `max_connections`
+//!     #[builder(required, alias = "max_connections")]
+//!     max_conns: u32,
+//! }
+//!
+//! let pool = Pool::builder().with_max_conns(8).build();
+//! ```
+//!
 //! ## Ergonomic Conversions with `impl_into`
 //!
 //! The `impl_into` attribute generates setter methods that accept `impl Into<FieldType>`
@@ -320,297 +677,2358 @@
 //! assert_eq!(example.tags, vec!["rust"]);
 //! ```
 //!
-//! ## Custom Conversions with `converter`
+//! ## Collection Setters with `into_iter`
 //!
-//! The `converter` attribute allows you to specify custom conversion logic for field setters,
-//! enabling more powerful transformations than `impl_into` can provide. Use closure syntax
-//! to define the conversion function with explicit parameter types.
+//! `#[builder(into_iter)]` makes a `Vec<T>` setter accept any iterable whose
+//! items convert into `T`, so no `vec!` or `.to_string()` is needed. On the
+//! struct it applies to every `Vec<T>` field, and it takes precedence over
+//! `impl_into` for those fields:
 //!
-//! ```
+//! ```rust
 //! use type_state_builder::TypeStateBuilder;
 //!
 //! #[derive(TypeStateBuilder)]
-//! struct User {
-//!     #[builder(required, impl_into)]
-//!     name: String,
+//! #[builder(into_iter, impl_into)]
+//! struct Command {
+//!     #[builder(required)]
+//!     program: String,        // impl Into<String>
+//!     args: Vec<String>,      // impl IntoIterator<Item = impl Into<String>>
+//!     #[builder(into_iter = false)]
+//!     env: Vec<(String, String)>, // impl Into<Vec<(String, String)>>
+//! }
 //!
-//!     // Normalize email to lowercase and trim whitespace
-//!     #[builder(required, converter = |email: &str| email.trim().to_lowercase())]
-//!     email: String,
+//! let command = Command::builder()
+//!     .program("ls")
+//!     .args(["-l", "-a"])
+//!     .build();
 //!
-//!     // Parse comma-separated tags into Vec<String>
-//!     #[builder(converter = |tags: &str| tags.split(',').map(|s| s.trim().to_string()).collect())]
-//!     interests: Vec<String>,
+//! assert_eq!(command.args, vec!["-l", "-a"]);
+//! ```
 //!
-//!     // Parse age from string with fallback
-//!     #[builder(converter = |age: &str| age.parse().unwrap_or(0))]
-//!     age: u32,
+//! `into_iter` cannot be combined with `converter`, `skip_setter` or `const`.
+//!
+//! ## Optional Values with `option_into`
+//!
+//! Setters of optional `Option<T>` fields take an `Option<T>`, so a known
+//! value has to be wrapped in `Some`. `#[builder(option_into)]` makes those
+//! setters accept `impl Into<Option<T>>` instead, which covers both a bare `T`
+//! and an `Option<T>` passed through from elsewhere:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(option_into)]
+//! struct Profile {
+//!     #[builder(required)]
+//!     name: String,
+//!     nickname: Option<String>,
+//!     age: Option<u32>,
 //! }
 //!
-//! let user = User::builder()
-//!     .name("Alice")
-//!     .email("  ALICE@EXAMPLE.COM  ")  // Will be normalized to "alice@example.com"
-//!     .interests("rust, programming, web")  // Parsed to Vec<String>
-//!     .age("25")  // Parsed from string to u32
+//! let known_age: Option<u32> = None;
+//! let profile = Profile::builder()
+//!     .name("Ada".to_string())
+//!     .nickname("ada".to_string())
+//!     .age(known_age)
 //!     .build();
 //!
-//! assert_eq!(user.email, "alice@example.com");
-//! assert_eq!(user.interests, vec!["rust", "programming", "web"]);
-//! assert_eq!(user.age, 25);
+//! assert_eq!(profile.nickname.as_deref(), Some("ada"));
+//! assert_eq!(profile.age, None);
 //! ```
 //!
-//! ### Complex Converter Examples
+//! It is `impl_into` limited to optional `Option<T>` fields, so
+//! `#[builder(impl_into = false)]` opts a field out. Arguments whose type is
+//! inferred, such as `Some(value.into())`, may need an annotation.
 //!
-//! ```
+//! ## Nested Builders
+//!
+//! When a field's type also derives `TypeStateBuilder` with `#[builder(nestable)]`,
+//! `#[builder(nested)]` adds a `{setter}_with` method next to the regular setter.
+//! The closure receives a fresh builder for the field's type and returns it once
+//! complete; the value is built and passed to the regular setter:
+//!
+//! ```rust
 //! use type_state_builder::TypeStateBuilder;
-//! use std::collections::HashMap;
 //!
 //! #[derive(TypeStateBuilder)]
-//! struct Config {
-//!     // Convert environment-style boolean strings
-//!     #[builder(converter = |enabled: &str| {
-//!         matches!(enabled.to_lowercase().as_str(), "true" | "1" | "yes" | "on")
-//!     })]
-//!     debug_enabled: bool,
-//!
-//!     // Parse key=value pairs into HashMap
-//!     #[builder(converter = |pairs: &str| {
-//!         pairs.split(',')
-//!              .filter_map(|pair| {
-//!                  let mut split = pair.split('=');
-//!                  Some((split.next()?.trim().to_string(),
-//!                       split.next()?.trim().to_string()))
-//!              })
-//!              .collect()
-//!     })]
-//!     env_vars: HashMap<String, String>,
+//! #[builder(nestable)]
+//! struct Address {
+//!     #[builder(required)]
+//!     city: String,
+//!     #[builder(required)]
+//!     zip: String,
+//! }
 //!
-//!     // Transform slice to owned Vec
-//!     #[builder(converter = |hosts: &[&str]| {
-//!         hosts.iter().map(|s| s.to_string()).collect()
-//!     })]
-//!     allowed_hosts: Vec<String>,
+//! #[derive(TypeStateBuilder)]
+//! struct Customer {
+//!     #[builder(required)]
+//!     name: String,
+//!     #[builder(required, nested)]
+//!     address: Address,
 //! }
 //!
-//! let config = Config::builder()
-//!     .debug_enabled("true")
-//!     .env_vars("LOG_LEVEL=debug,PORT=8080")
-//!     .allowed_hosts(&["localhost", "127.0.0.1"])
+//! let customer = Customer::builder()
+//!     .name("Alice".to_string())
+//!     .address_with(|b| b.city("Paris".to_string()).zip("75001".to_string()))
 //!     .build();
 //!
-//! assert_eq!(config.debug_enabled, true);
-//! assert_eq!(config.env_vars.get("LOG_LEVEL"), Some(&"debug".to_string()));
-//! assert_eq!(config.allowed_hosts, vec!["localhost", "127.0.0.1"]);
+//! assert_eq!(customer.address.city, "Paris");
 //! ```
 //!
-//! ### Converter with Generics
+//! The closure must set every required field of the nested builder, otherwise it
+//! does not compile. `nestable` adds hidden `{Struct}BuilderStart` and
+//! `{Struct}BuilderReady` aliases and a hidden `__build_nested` function for
+//! this purpose, so the nested type must live where its aliases are visible.
+//! Structs without it add none of these names to their module. `nestable`
+//! cannot be combined with `builder_method`, `boxed`, `lite` or fields checked
+//! when building, since the nested builder is started with `builder()` and
+//! must build infallibly. The `_with` methods are not `const`, even on const
+//! builders.
+//!
+//! ### Collections of Nested Builders with `nested_each`
+//!
+//! For a `Vec<Child>` field whose element type derives `TypeStateBuilder` with
+//! `#[builder(nestable)]`,
+//! `#[builder(nested_each = "add_child")]` adds an `add_child` method that
+//! configures one element in a closure, builds it and pushes it. Each call adds
+//! one element, so trees and DSLs can be written as a single chain:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(nestable)]
+//! struct Node {
+//!     #[builder(required)]
+//!     label: String,
+//!     #[builder(nested_each = "add_child")]
+//!     children: Vec<Node>,
+//! }
+//!
+//! let root = Node::builder()
+//!     .label("root".to_string())
+//!     .add_child(|b| b.label("a".to_string()))
+//!     .add_child(|b| b.label("b".to_string()).add_child(|b| b.label("b1".to_string())))
+//!     .build();
 //!
+//! assert_eq!(root.children.len(), 2);
+//! assert_eq!(root.children[1].children[0].label, "b1");
 //! ```
+//!
+//! Elements are pushed onto the field's default, and the regular setter stays
+//! available to replace the whole vector. The field stays optional, even under
+//! `all_required`, so `nested_each` cannot be combined with `required`,
+//! `skip_setter`, `builder_method`, `sensitive`, `lazy_setter`, `default_from`
+//! or `validate_on_set`.
+//!
+//! ## Feature-Gated Setters with `cfg`
+//!
+//! `#[builder(cfg(...))]` places the setter (and its `_with` variant) under the
+//! given `#[cfg]` predicate. When the predicate does not hold, the setter does not
+//! exist and the field keeps its default, so knobs for optional subsystems stay
+//! out of the public builder API when the subsystem is compiled out:
+//!
+//! ```rust
 //! use type_state_builder::TypeStateBuilder;
 //!
 //! #[derive(TypeStateBuilder)]
-//! struct Container<T: Clone + Default> {
-//!     #[builder(required, impl_into)]
+//! struct Service {
+//!     #[builder(required)]
 //!     name: String,
+//!     #[builder(cfg(feature = "metrics"), default = 9090)]
+//!     metrics_port: u16,
+//! }
 //!
-//!     // Converter works with generic fields too
-//!     #[builder(converter = |items: &[T]| items.into_iter().map(|item| item.clone()).collect())]
-//!     data: Vec<T>,
+//! let service = Service::builder().name("api".to_string()).build();
+//! assert_eq!(service.metrics_port, 9090);
+//! ```
+//!
+//! Only optional fields can be gated: required fields are part of the builder's
+//! state machine, which cannot change with the configuration. Gated fields stay
+//! optional under `all_required`.
+//!
+//! ## Build-Time Resolution with `resolve`
+//!
+//! A `converter` only sees its own argument. When a field's final value depends
+//! on other fields, `#[builder(resolve = ...)]` runs a closure in the build
+//! method instead, once every field is known. The first parameter receives the
+//! field's value; further parameters receive references to the fields they name:
+//!
+//! ```rust
+//! use std::path::PathBuf;
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Workspace {
+//!     #[builder(required)]
+//!     base_dir: PathBuf,
+//!     #[builder(required, resolve = |path: PathBuf, base_dir: &PathBuf| base_dir.join(path))]
+//!     config: PathBuf,
 //! }
 //!
-//! // Usage with concrete type
-//! let container = Container::builder()
-//!     .name("numbers")        // Convert &str to String thanks to `impl_into`
-//!     .data(&[1, 2, 3, 4, 5]) // Convert &[T] to Vec<T> thanks to `converter`
-//!     .build();               // Available only when all required fields are set
+//! let workspace = Workspace::builder()
+//!     .config(PathBuf::from("app.toml"))
+//!     .base_dir(PathBuf::from("/srv/app"))
+//!     .build();
 //!
-//! assert_eq!(container.data, vec![1, 2, 3, 4, 5]);
+//! assert_eq!(workspace.config, PathBuf::from("/srv/app/app.toml"));
 //! ```
 //!
-//! ### Converter vs impl_into Comparison
+//! Setters can be called in any order. Fields read by a `resolve` closure are
+//! passed as held by the builder, so they cannot have a `resolve` of their own.
+//! `resolve` is not available on const builders.
 //!
-//! | Feature | `impl_into` | `converter` |
-//! |---------|-------------|-------------|
-//! | **Type conversions** | Only `Into` trait | Any custom logic |
-//! | **Parsing strings** | Limited | Full support |
-//! | **Data validation** | No | Custom validation |
-//! | **Complex transformations** | No | Full support |
-//! | **Multiple input formats** | Into only | Any input type |
-//! | **Performance** | Zero-cost | Depends on logic |
-//! | **Syntax** | Attribute flag | Closure expression |
+//! ## Computed Defaults with `default_from`
 //!
-//! **When to use `converter`:**
-//! - Ergonomic setter generation for `Option<String>` fields
-//! - Parsing structured data from strings
-//! - Normalizing or validating input data
-//! - Complex data transformations
-//! - Converting between incompatible types
-//! - Custom business logic in setters
+//! When an optional field's default depends on other fields, such as a
+//! capacity derived from a connection limit, `#[builder(default_from = ...)]`
+//! computes it in the build method. The closure receives a reference to the
+//! builder and only runs if the field's setter was never called:
 //!
-//! **Note**: `converter` is incompatible with `skip_setter` and `impl_into` since
-//! they represent different approaches to setter generation.
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
 //!
-//! ## Optional-Only Structs (Regular Builder)
+//! #[derive(TypeStateBuilder)]
+//! struct Pool {
+//!     #[builder(required)]
+//!     max_connections: usize,
+//!     #[builder(default_from = |b| b.max_connections * 2)]
+//!     capacity: usize,
+//! }
+//!
+//! let pool = Pool::builder().max_connections(16).build();
+//! assert_eq!(pool.capacity, 32);
 //!
+//! let pool = Pool::builder().max_connections(16).capacity(8).build();
+//! assert_eq!(pool.capacity, 8);
 //! ```
+//!
+//! Fields are read as held by the builder, before any `resolve` runs: fields
+//! with their own `default_from` read as `Option<T>`, and they cannot be read
+//! by a `resolve` closure. The closure must not capture variables, and
+//! `default_from` is not available on required fields or const builders.
+//!
+//! ## Derived Fields with `computed`
+//!
+//! A field that is always derived from the others, like a hash or a length,
+//! should not have a setter at all. `#[builder(computed = ...)]` implies
+//! `skip_setter` and runs its closure on every build, with the same reference
+//! to the builder as `default_from`:
+//!
+//! ```rust
 //! use type_state_builder::TypeStateBuilder;
 //!
-//! // No required fields = regular builder pattern
 //! #[derive(TypeStateBuilder)]
-//! struct Settings {
-//!     debug: bool,
-//!     max_connections: Option<u32>,
+//! struct Document {
+//!     #[builder(required)]
+//!     title: String,
+//!     #[builder(computed = |b| b.title.to_lowercase().replace(' ', "-"))]
+//!     slug: String,
+//! }
+//!
+//! let document = Document::builder().title("Hello World".to_string()).build();
+//! assert_eq!(document.slug, "hello-world");
+//! ```
+//!
+//! The builder holds no value for computed fields, so they print as
+//! `"<computed>"` in its `Debug` output. They cannot be combined with setter or
+//! default attributes, cannot be read by `resolve`, and are not available on
+//! const builders.
+//!
+//! ## Field Validation with `validate`
+//!
+//! Rules about a single field can sit next to the field instead of in a
+//! finisher. `#[builder(validate = ...)]` takes a closure or function receiving
+//! a reference to the field's final value and returning whether it is valid.
+//! With any validated field the build method returns
+//! `Result<Struct, {Struct}ValidationError>`, where the error names the first
+//! field that failed and carries its `validate_message`:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder, Debug)]
+//! struct Listener {
+//!     #[builder(required, validate = |host: &String| !host.is_empty())]
+//!     host: String,
+//!     #[builder(default = 8080, validate = |port| *port != 0, validate_message = "port must not be 0")]
+//!     port: u16,
+//! }
+//!
+//! let listener = Listener::builder().host("localhost".to_string()).build().unwrap();
+//! assert_eq!(listener.port, 8080);
+//!
+//! let error = Listener::builder()
+//!     .host("localhost".to_string())
+//!     .port(0)
+//!     .build()
+//!     .unwrap_err();
+//! assert_eq!(error.field, "port");
+//! assert_eq!(error.to_string(), "invalid value for field `port`: port must not be 0");
+//! ```
+//!
+//! The checks run in declaration order on the built value, so `computed` and
+//! `resolve` results are validated too. Methods derived from the build method
+//! return a `Result` as well: `build_into`, `build_clone` and finishers without
+//! an `error` type return the validation error, finishers with one convert it
+//! with `?`, and a complete builder converts into the struct with `TryFrom`
+//! rather than `From`. The error implements `Display`, and `std::error::Error`
+//! with the `std` feature. These methods, and setters under `validate_on_set`,
+//! are `#[track_caller]`: the error's `location` is where the method that
+//! rejected the value was called.
+//! Validated structs cannot be nested in other builders, and `validate` is
+//! not available on const builders, in lite mode or on enum variants.
+//!
+//! The most common checks have shorthands that need no closure.
+//! `#[builder(non_empty)]` rejects values whose `is_empty()` is true, such as
+//! empty strings and collections, and `#[builder(range = 1..=100)]` rejects
+//! values the range does not `contain`. Both make the build method fallible
+//! like `validate` does, come with default messages, and run before the
+//! field's `validate` closure. `non_empty` is rejected on `Option<T>` fields,
+//! which have no `is_empty()`; check the inner value with `validate` there:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder, Debug)]
+//! struct Account {
+//!     #[builder(required, non_empty)]
+//!     name: String,
+//!     #[builder(default = 30, range = 1..=100)]
+//!     quota: u8,
+//! }
+//!
+//! let error = Account::builder().name(String::new()).build().unwrap_err();
+//! assert_eq!(error.message, "must not be empty");
+//!
+//! let error = Account::builder().name("ada".to_string()).quota(0).build().unwrap_err();
+//! assert_eq!(error.message, "must be in range 1..=100");
+//! ```
+//!
+//! With `#[builder(validate_on_set)]` the field's checks run in its setter
+//! instead, so a bad value is reported where it is passed. The setter returns
+//! `Result<Builder, {Struct}ValidationError>` and the check is not repeated
+//! at build time, so a struct whose checks all run on set keeps an
+//! infallible build method. Default values are not checked:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder, Debug)]
+//! struct Endpoint {
+//!     #[builder(required, non_empty, validate_on_set)]
+//!     host: String,
+//!     #[builder(default = 443, range = 1..=65535, validate_on_set)]
+//!     port: u32,
+//! }
+//!
+//! let error = Endpoint::builder().port(0).unwrap_err();
+//! assert_eq!(error.field, "port");
+//!
+//! let endpoint = Endpoint::builder().host("example.com".to_string())?.build();
+//! assert_eq!(endpoint.port, 443);
+//! # Ok::<(), EndpointValidationError>(())
+//! ```
+//!
+//! ## Fallible Setters and Arrays
+//!
+//! Array fields take `[T; N]` directly, so a literal of the wrong length is a
+//! compile error. Values whose length is only known at run time, like a
+//! `Vec<T>` or a slice, go through the `try_{setter}` method generated by
+//! `#[builder(try_setter)]`. It accepts any `V` the field type implements
+//! `TryFrom<V>` for and returns the conversion error instead of the builder:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Color {
+//!     #[builder(required, try_setter)]
+//!     rgb: [u8; 3],
+//! }
+//!
+//! let color = Color::builder().try_rgb(vec![255, 128, 0]).unwrap().build();
+//! assert_eq!(color.rgb, [255, 128, 0]);
+//!
+//! // A vector of the wrong length is handed back as the error
+//! assert_eq!(Color::builder().try_rgb(vec![1, 2]).unwrap_err(), vec![1, 2]);
+//! ```
+//!
+//! `try_setter` works on any field type, not only arrays, and keeps the
+//! regular setter. It cannot be combined with `converter` or `skip_setter`,
+//! and is not available on const builders.
+//!
+//! ## By-Reference Setters with `setter(also_ref)`
+//!
+//! `#[builder(setter(also_ref))]` adds a `{setter}_ref` method taking `&T`
+//! next to the by-value setter. It clones the value, so a large payload can be
+//! reused for many builders, e.g. inside a loop, without giving it up:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Request {
+//!     #[builder(required)]
+//!     id: u32,
+//!     #[builder(required, setter(also_ref))]
+//!     body: Vec<u8>,
+//! }
+//!
+//! let payload = vec![0_u8; 4096];
+//! let requests: Vec<Request> = (0..3)
+//!     .map(|id| Request::builder().id(id).body_ref(&payload).build())
+//!     .collect();
+//! assert_eq!(requests[2].body, payload);
+//! ```
+//!
+//! The field type must implement `Clone`. `setter(also_ref)` cannot be
+//! combined with `converter`, `accumulate` or `validate_on_set`, whose setters
+//! do not take the field type, and is not available on const builders.
+//!
+//! When callers only ever hold a reference, `#[builder(clone_from_ref)]`
+//! changes the setter itself instead. `String` fields take `&str`, `Vec<T>`
+//! fields take `&[T]`, and other fields take `&T` and are cloned. `impl_into`
+//! cannot do this, as `&T: Into<T>` generally does not hold:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Profile {
+//!     #[builder(required, clone_from_ref)]
+//!     name: String,
+//!     #[builder(clone_from_ref)]
+//!     tags: Vec<String>,
+//! }
+//!
+//! fn profile(name: &str, tags: &[String]) -> Profile {
+//!     Profile::builder().name(name).tags(tags).build()
+//! }
+//!
+//! assert_eq!(profile("ada", &["admin".to_string()]).tags, ["admin"]);
+//! ```
+//!
+//! `clone_from_ref` takes precedence over struct-level `impl_into` and
+//! `into_iter`. It cannot be combined with `converter`, `setter(also_ref)` or
+//! `accumulate`, nor with field-level `impl_into` or `into_iter`, and is not
+//! available on const builders or for the required fields of lite builders.
+//!
+//! ## Deferred Values with `lazy_setter`
+//!
+//! `#[builder(lazy_setter)]` adds a `{setter}_with` method next to the regular
+//! setter. It takes an `impl FnOnce() -> T + 'static` closure that the build
+//! method calls, so an expensive value is never computed if a later layer of
+//! configuration sets the field again:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! fn detect_worker_count() -> usize {
+//!     // Imagine probing the machine here
+//!     8
+//! }
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Pool {
+//!     #[builder(required)]
+//!     name: String,
+//!     #[builder(lazy_setter)]
+//!     workers: usize,
+//! }
+//!
+//! // Defaults layer
+//! let builder = Pool::builder().workers_with(detect_worker_count);
+//! // Overrides layer: `detect_worker_count` never runs
+//! let pool = builder.workers(2).name("io".to_string()).build();
+//!
+//! assert_eq!(pool.workers, 2);
+//! ```
+//!
+//! Required fields get a lazy setter too; it moves the builder to the next
+//! state like the regular setter. The closure is boxed, so in `no_std` crates `alloc::boxed::Box` must be in
+//! scope. `lazy_setter` cannot be combined with `skip_setter`, `nested`,
+//! `builder_method`, `sensitive` or `resolve`, fields with a lazy setter cannot
+//! be read by another field's `resolve`, and it is not available on const builders.
+//!
+//! ## Accumulating Setters with `accumulate`
+//!
+//! A setter normally replaces the value. With `#[builder(accumulate = ...)]`
+//! it hands the value the builder holds and its own argument to a closure
+//! instead, so repeated calls build the value up, starting from the field's
+//! default. The setter takes the type of the closure's second parameter:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Command {
+//!     #[builder(required)]
+//!     program: String,
+//!     #[builder(setter_name = "arg", accumulate = |args, arg: &str| args.push(arg.to_string()))]
+//!     args: Vec<String>,
+//! }
+//!
+//! let command = Command::builder()
+//!     .program("ls".to_string())
+//!     .arg("-l")
+//!     .arg("/tmp")
+//!     .build();
+//!
+//! assert_eq!(command.args, ["-l", "/tmp"]);
+//! ```
+//!
+//! Combined with `builder_only` on the [`macro@type_state_builder`] attribute
+//! macro, the accumulated value can stay on the builder as scratch state that
+//! only `computed`, `default_from` and `resolve` closures read.
+//!
+//! `accumulate` is limited to optional fields with a plain setter: it cannot be
+//! combined with `required`, `skip_setter`, `impl_into`, `converter`,
+//! `into_iter`, `nested`, `builder_method`, `sensitive`, `lazy_setter`,
+//! `try_setter`, `default_from` or `computed`, and is not available on const
+//! builders. Fields with an accumulating setter are not taken from a `base`.
+//!
+//! ## Custom Conversions with `converter`
+//!
+//! The `converter` attribute allows you to specify custom conversion logic for field setters,
+//! enabling more powerful transformations than `impl_into` can provide. Use closure syntax
+//! to define the conversion function with explicit parameter types.
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct User {
+//!     #[builder(required, impl_into)]
+//!     name: String,
+//!
+//!     // Normalize email to lowercase and trim whitespace
+//!     #[builder(required, converter = |email: &str| email.trim().to_lowercase())]
+//!     email: String,
+//!
+//!     // Parse comma-separated tags into Vec<String>
+//!     #[builder(converter = |tags: &str| tags.split(',').map(|s| s.trim().to_string()).collect())]
+//!     interests: Vec<String>,
+//!
+//!     // Parse age from string with fallback
+//!     #[builder(converter = |age: &str| age.parse().unwrap_or(0))]
+//!     age: u32,
+//! }
+//!
+//! let user = User::builder()
+//!     .name("Alice")
+//!     .email("  ALICE@EXAMPLE.COM  ")  // Will be normalized to "alice@example.com"
+//!     .interests("rust, programming, web")  // Parsed to Vec<String>
+//!     .age("25")  // Parsed from string to u32
+//!     .build();
+//!
+//! assert_eq!(user.email, "alice@example.com");
+//! assert_eq!(user.interests, vec!["rust", "programming", "web"]);
+//! assert_eq!(user.age, 25);
+//! ```
+//!
+//! ### Complex Converter Examples
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//! use std::collections::HashMap;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Config {
+//!     // Convert environment-style boolean strings
+//!     #[builder(converter = |enabled: &str| {
+//!         matches!(enabled.to_lowercase().as_str(), "true" | "1" | "yes" | "on")
+//!     })]
+//!     debug_enabled: bool,
+//!
+//!     // Parse key=value pairs into HashMap
+//!     #[builder(converter = |pairs: &str| {
+//!         pairs.split(',')
+//!              .filter_map(|pair| {
+//!                  let mut split = pair.split('=');
+//!                  Some((split.next()?.trim().to_string(),
+//!                       split.next()?.trim().to_string()))
+//!              })
+//!              .collect()
+//!     })]
+//!     env_vars: HashMap<String, String>,
+//!
+//!     // Transform slice to owned Vec
+//!     #[builder(converter = |hosts: &[&str]| {
+//!         hosts.iter().map(|s| s.to_string()).collect()
+//!     })]
+//!     allowed_hosts: Vec<String>,
+//! }
+//!
+//! let config = Config::builder()
+//!     .debug_enabled("true")
+//!     .env_vars("LOG_LEVEL=debug,PORT=8080")
+//!     .allowed_hosts(&["localhost", "127.0.0.1"])
+//!     .build();
+//!
+//! assert_eq!(config.debug_enabled, true);
+//! assert_eq!(config.env_vars.get("LOG_LEVEL"), Some(&"debug".to_string()));
+//! assert_eq!(config.allowed_hosts, vec!["localhost", "127.0.0.1"]);
+//! ```
+//!
+//! ### Converter with Generics
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Container<T: Clone + Default> {
+//!     #[builder(required, impl_into)]
+//!     name: String,
+//!
+//!     // Converter works with generic fields too
+//!     #[builder(converter = |items: &[T]| items.into_iter().map(|item| item.clone()).collect())]
+//!     data: Vec<T>,
+//! }
+//!
+//! // Usage with concrete type
+//! let container = Container::builder()
+//!     .name("numbers")        // Convert &str to String thanks to `impl_into`
+//!     .data(&[1, 2, 3, 4, 5]) // Convert &[T] to Vec<T> thanks to `converter`
+//!     .build();               // Available only when all required fields are set
+//!
+//! assert_eq!(container.data, vec![1, 2, 3, 4, 5]);
+//! ```
+//!
+//! ### Converter vs impl_into Comparison
+//!
+//! | Feature | `impl_into` | `converter` |
+//! |---------|-------------|-------------|
+//! | **Type conversions** | Only `Into` trait | Any custom logic |
+//! | **Parsing strings** | Limited | Full support |
+//! | **Data validation** | No | Custom validation |
+//! | **Complex transformations** | No | Full support |
+//! | **Multiple input formats** | Into only | Any input type |
+//! | **Performance** | Zero-cost | Depends on logic |
+//! | **Syntax** | Attribute flag | Closure expression |
+//!
+//! **When to use `converter`:**
+//! - Ergonomic setter generation for `Option<String>` fields
+//! - Parsing structured data from strings
+//! - Normalizing or validating input data
+//! - Complex data transformations
+//! - Converting between incompatible types
+//! - Custom business logic in setters
+//!
+//! **Note**: `converter` is incompatible with `skip_setter`, which removes the
+//! setter the converter belongs to.
+//!
+//! ### Chaining `impl_into` with a Converter
+//!
+//! A field-level `impl_into` on a converter field makes the setter accept
+//! `impl Into<I>`, where `I` is the converter's parameter type, and the
+//! converter then runs on the converted value. This accepts anything
+//! `Into<String>` and normalizes it:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Account {
+//!     #[builder(required, impl_into, converter = |name: String| name.trim().to_lowercase())]
+//!     username: String,
+//! }
+//!
+//! let from_str = Account::builder().username("  Ada ").build();
+//! let from_string = Account::builder().username(String::from("ADA")).build();
+//! assert_eq!(from_str.username, from_string.username);
+//! ```
+//!
+//! Struct-level `impl_into` does not change converter setters, and const
+//! builders keep taking `I` itself.
+//!
+//! ### Defaults in the Converter's Input Type
+//!
+//! A `default` is normally written in the field type, while the setter takes
+//! the converter's input, so the two can drift apart. With
+//! `#[builder(default_through_converter)]` the default is written like a
+//! setter argument and passes through the converter (or `wrap`):
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Cache {
+//!     #[builder(converter = |kib: u32| kib as usize * 1024, default = 8, default_through_converter)]
+//!     capacity: usize,
+//! }
+//!
+//! assert_eq!(Cache::builder().build().capacity, 8 * 1024);
+//! assert_eq!(Cache::builder().capacity(64).build().capacity, 64 * 1024);
+//! ```
+//!
+//! The converter's body runs where the default is used, so this also works on
+//! const builders when the body is const-evaluable.
+//!
+//! ### Built-in Converters
+//!
+//! With the `std-converters` feature, common conversions can be named instead
+//! of written out. `#[builder(converter = std_converters::name)]` is expanded
+//! into the equivalent closure, completed with the field's type:
+//!
+//! - `trim_lowercase` takes a `&str` and trims and lowercases it
+//! - `parse` takes a `&str` and parses it with `FromStr` (a `T` for `Option<T>` fields)
+//! - `seconds` takes a `u64` and produces a `Duration`
+//! - `csv_list` takes a comma-separated `&str` and collects the trimmed, parsed
+//!   items, skipping empty ones
+//!
+//! ```rust,ignore
+//! #[derive(TypeStateBuilder)]
+//! struct Server {
+//!     #[builder(required, converter = std_converters::parse)]
+//!     port: u16,
+//!     #[builder(converter = std_converters::csv_list)]
+//!     tags: Vec<String>,
+//! }
+//!
+//! let server = Server::builder().port("8080").tags("edge, eu-west").build();
+//! ```
+//!
+//! `parse` and `csv_list` panic with the field name and the input when parsing
+//! fails.
+//!
+//! ### Newtype Fields with `wrap`
+//!
+//! Newtypes such as `UserId(u64)` make callers write `.id(UserId(5))`.
+//! `#[builder(wrap = u64)]` makes the setter take the inner type and call the
+//! field type's tuple constructor, and `wrap(Inner, with = path)` names another
+//! constructor. `Option<T>` fields wrap the value into `T` and store `Some`:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(Debug, PartialEq)]
+//! #[repr(transparent)]
+//! struct UserId(u64);
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Email(String);
+//!
+//! impl Email {
+//!     fn parse(address: &str) -> Self {
+//!         Email(address.trim().to_lowercase())
+//!     }
+//! }
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Account {
+//!     #[builder(required, wrap = u64)]
+//!     id: UserId,
+//!     #[builder(required, wrap(&str, with = Email::parse))]
+//!     email: Email,
+//!     #[builder(wrap = u64)]
+//!     referrer: Option<UserId>,
+//! }
+//!
+//! let account = Account::builder().id(5).email("Ada@Example.com").referrer(2).build();
+//! assert_eq!(account.id, UserId(5));
+//! assert_eq!(account.referrer, Some(UserId(2)));
+//! ```
+//!
+//! `wrap` is expanded into a converter, so it combines with `impl_into` like
+//! one and works on const builders when the constructor is a `const fn`.
+//!
+//! ### Setter Input Types with `setter_input`
+//!
+//! When the field type already converts from the type callers have,
+//! `#[builder(setter_input = Type)]` makes the setter take that type and call
+//! `From` without writing a closure. `via = TryFrom` calls `TryFrom` instead,
+//! and the setter returns the conversion error:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Packet {
+//!     #[builder(required, setter_input = u32, via = TryFrom)]
+//!     ttl: u8,
+//!     #[builder(setter_input = u8, default = 30)]
+//!     timeout: u64,
+//! }
+//!
+//! let packet = Packet::builder().ttl(64).unwrap().timeout(5).build();
+//! assert_eq!((packet.ttl, packet.timeout), (64, 5));
+//! assert!(Packet::builder().ttl(300).is_err());
+//! ```
+//!
+//! `setter_input` is not available on const builders, and lite builders only
+//! accept it with `From` on optional fields.
+//!
+//! ### Trait Object Fields with `dyn_into`
+//!
+//! Fields holding trait objects make callers allocate the pointer and coerce
+//! it at every call site. `#[builder(dyn_into)]` on a `Box<dyn Trait>`,
+//! `Rc<dyn Trait>` or `Arc<dyn Trait>` field, or an `Option` of one, makes the
+//! setter take `impl Trait` with the trait object's bounds, plus `'static`
+//! unless they name a lifetime, and allocate the pointer itself:
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! trait Greeter {
+//!     fn greet(&self) -> String;
+//! }
+//!
+//! struct English;
+//!
+//! impl Greeter for English {
+//!     fn greet(&self) -> String {
+//!         "Hello".to_string()
+//!     }
+//! }
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Service {
+//!     #[builder(required, dyn_into)]
+//!     greeter: Arc<dyn Greeter + Send + Sync>,
+//!     #[builder(dyn_into)]
+//!     on_error: Option<Box<dyn Fn(&str)>>,
+//! }
+//!
+//! let service = Service::builder()
+//!     .greeter(English)
+//!     .on_error(|message| eprintln!("{message}"))
+//!     .build();
+//! assert_eq!(service.greeter.greet(), "Hello");
+//! ```
+//!
+//! `dyn_into` replaces the setter's argument type, so it does not combine with
+//! `converter`, `wrap`, `impl_into` or the other attributes that change it,
+//! and cannot be used on const builders.
+//!
+//! ## Optional-Only Structs (Regular Builder)
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! // No required fields = regular builder pattern
+//! #[derive(TypeStateBuilder)]
+//! struct Settings {
+//!     debug: bool,
+//!     max_connections: Option<u32>,
+//!
+//!     #[builder(default = "default.log".to_string())]
+//!     log_file: String,
+//!
+//!     #[builder(skip_setter, default = 42)]
+//!     magic_number: i32,
+//! }
+//!
+//! // Can call build() immediately since no required fields
+//! let settings = Settings::builder()
+//!     .debug(true)
+//!     .max_connections(Some(100))
+//!     .build();
+//! ```
+//!
+//! ## Enums
+//!
+//! Enums are built in two phases: `builder()` returns a variant selection
+//! builder with one method per variant, named after it in snake case, and
+//! each of those starts a builder for the variant's fields. The variant has to
+//! be selected before any field can be set, and its required fields before it
+//! can be built:
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder, Debug, PartialEq)]
+//! enum Message {
+//!     Ping,
+//!     Data {
+//!         #[builder(required)]
+//!         stream: u32,
+//!         #[builder(required)]
+//!         payload: Vec<u8>,
+//!         #[builder(default = 0)]
+//!         flags: u8,
+//!     },
+//! }
+//!
+//! let ping = Message::builder().ping().build();
+//! assert_eq!(ping, Message::Ping);
+//!
+//! let data = Message::builder()
+//!     .data()
+//!     .stream(1)
+//!     .payload(vec![0xff])
+//!     .build();
+//! assert_eq!(data, Message::Data { stream: 1, payload: vec![0xff], flags: 0 });
+//! ```
+//!
+//! Each variant gets the builder a struct with its fields would get, named
+//! after the enum and the variant (e.g. `MessageDataBuilder_MissingStream_MissingPayload`),
+//! and the enum's attributes apply to all of them. Tuple variants are not
+//! supported, and neither are the attributes generating items named after the
+//! enum alone: `module`, `hide_states`, `state_traits`, `lite`, `no_entry`,
+//! `arbitrary`, `default_struct`, `base`, `fill_from`, `apply_env`, `test_default`, `state_aliases` and `stable_names`, as well as
+//! `builder_method`.
+//! Enums cannot be `nestable`, so fields of the enum's type cannot be
+//! `#[builder(nested)]` in other structs, as the enum has no single builder to nest.
+//!
+//! ## Const Builders
+//!
+//! The `#[builder(const)]` attribute generates `const fn` builder methods, enabling
+//! compile-time constant construction. This is useful for embedded systems, static
+//! configuration, and other scenarios where values must be known at compile time.
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder, Debug, PartialEq)]
+//! #[builder(const)]
+//! struct Config {
+//!     #[builder(required)]
+//!     name: &'static str,
+//!
+//!     #[builder(required)]
+//!     version: u32,
+//!
+//!     #[builder(default = 8080)]
+//!     port: u16,
+//! }
+//!
+//! // Compile-time constant construction
+//! const APP_CONFIG: Config = Config::builder()
+//!     .name("my-app")
+//!     .version(1)
+//!     .port(3000)
+//!     .build();
+//!
+//! // Also works in static context
+//! static DEFAULT_CONFIG: Config = Config::builder()
+//!     .name("default")
+//!     .version(0)
+//!     .build();
+//!
+//! // And in const fn
+//! const fn make_config(name: &'static str) -> Config {
+//!     Config::builder()
+//!         .name(name)
+//!         .version(1)
+//!         .build()
+//! }
+//!
+//! const CUSTOM: Config = make_config("custom");
+//! ```
+//!
+//! ### Const Builder Requirements
+//!
+//! When using `#[builder(const)]`, there are some restrictions:
+//!
+//! - **Explicit defaults required**: Optional fields must use `#[builder(default = expr)]`
+//!   because `Default::default()` cannot be called in const context
+//! - **No `impl_into`**: The `impl_into` attribute is incompatible with const builders
+//!   because trait bounds are not supported in const fn
+//! - **Const-compatible types**: Field types must support const construction (e.g.,
+//!   `&'static str` instead of `String`, arrays instead of `Vec`)
+//! - **Const expressions**: Default expressions and converter bodies are checked for
+//!   calls that are known not to be const, such as `String::from`, `.to_string()` or
+//!   `vec![]`, and the error points at the call inside the attribute
+//!
+//! ### Const Builders with Converters
+//!
+//! Closure converters work with const builders. The macro automatically generates
+//! a `const fn` from the closure body:
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder, Debug, PartialEq)]
+//! #[builder(const)]
+//! struct Data {
+//!     // Converter closure is transformed into a const fn
+//!     #[builder(required, converter = |s: &'static str| s.len())]
+//!     name_length: usize,
+//!
+//!     #[builder(default = 0, converter = |n: i32| n * 2)]
+//!     doubled: i32,
+//! }
+//!
+//! const DATA: Data = Data::builder()
+//!     .name_length("hello")  // Converted to 5
+//!     .doubled(21)           // Converted to 42
+//!     .build();
+//!
+//! assert_eq!(DATA.name_length, 5);
+//! assert_eq!(DATA.doubled, 42);
+//! ```
+//!
+//! **Note**: The closure body must be const-evaluable. If it contains non-const
+//! operations (like heap allocation or trait method calls), the Rust compiler
+//! will produce an error.
+//!
+//! ## Builder Method Entry Point
+//!
+//! The `#[builder(builder_method)]` attribute makes a required field's setter the
+//! entry point to the builder, replacing the `builder()` method. This provides a
+//! more ergonomic API when one field is the natural starting point.
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder, Debug, PartialEq)]
+//! struct User {
+//!     #[builder(required, builder_method)]
+//!     id: u64,
+//!     #[builder(required, impl_into)]
+//!     name: String,
+//! }
+//!
+//! // Instead of User::builder().id(1).name("Alice").build()
+//! let user = User::id(1).name("Alice").build();
+//!
+//! assert_eq!(user.id, 1);
+//! assert_eq!(user.name, "Alice".to_string());
+//! ```
+//!
+//! ### Requirements
+//!
+//! - Only one field per struct can have `builder_method`
+//! - The field must be required (not optional)
+//! - Cannot be combined with `skip_setter`
+//!
+//! ### With Const Builders
+//!
+//! The `builder_method` attribute works with const builders:
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder, Debug, PartialEq)]
+//! #[builder(const)]
+//! struct Config {
+//!     #[builder(required, builder_method)]
+//!     name: &'static str,
+//!     #[builder(default = 0)]
+//!     version: u32,
+//! }
+//!
+//! const APP: Config = Config::name("myapp").version(1).build();
+//! ```
+//!
+//! ## Passing Builders Where the Struct Is Expected
+//!
+//! The builder state that can build the struct also implements
+//! `From<Builder> for Struct`, so a complete builder can be passed to APIs that
+//! take `impl Into<Struct>` without calling the build method:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Request {
+//!     #[builder(required)]
+//!     url: String,
+//!     retries: u8,
+//! }
+//!
+//! fn send(request: impl Into<Request>) -> String {
+//!     request.into().url
+//! }
+//!
+//! let url = send(Request::builder().url("https://example.com".to_string()));
+//! assert_eq!(url, "https://example.com");
+//! ```
+//!
+//! ## Required by Default with `all_required`
+//!
+//! For domain types where nearly every field is mandatory, annotating each field
+//! with `required` is noisy. `#[builder(all_required)]` flips the default:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(all_required)]
+//! struct Invoice {
+//!     number: u64,
+//!     customer: String,
+//!     #[builder(default = 30)]
+//!     payment_terms_days: u32,   // optional: has a default
+//!     #[builder(optional)]
+//!     notes: Option<String>,     // optional: opted out
+//! }
+//!
+//! let invoice = Invoice::builder()
+//!     .number(42)
+//!     .customer("ACME".to_string())
+//!     .build();
+//!
+//! assert_eq!(invoice.payment_terms_days, 30);
+//! ```
+//!
+//! Fields that never need a setter call stay optional as well: those with
+//! `skip_setter`, `default_from`, `computed` or a `cfg`-gated setter, and
+//! `accumulate` fields, which start from their default.
+//!
+//! ## Conditionally Required Fields
+//!
+//! A library with a "strict" mode can make a field required only when a
+//! feature is enabled. `#[builder(required(cfg(predicate)))]` generates the
+//! builder twice, under `#[cfg(predicate)]` with the field required and under
+//! `#[cfg(not(predicate))]` with the field optional, so its `default` only
+//! applies while the predicate does not hold:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Server {
+//!     #[builder(required)]
+//!     host: String,
+//!     // Without the `strict` feature, the port defaults to 8080
+//!     #[builder(required(cfg(feature = "strict")), default = 8080)]
+//!     port: u16,
+//! }
+//!
+//! let server = Server::builder()
+//!     .host("example.com".to_string())
+//!     .port(443)
+//!     .build();
+//!
+//! assert_eq!(server.port, 443);
+//! ```
+//!
+//! The predicate is evaluated in the crate that defines the struct. Every
+//! conditionally required field doubles the generated code, as one builder is
+//! generated for each combination of predicates. The attribute cannot be
+//! combined with `optional`, `transparent`, `builder_method`, `skip_setter`,
+//! `cfg`, `default_from`, `computed` or `accumulate`, and is not supported on
+//! enum variants.
+//!
+//! ## Required `Option<T>` Fields
+//!
+//! Some settings are tri-state: the caller must make a decision, but "nothing"
+//! is a valid decision. Mark such `Option<T>` fields `#[builder(required, transparent)]`:
+//! the setter must be called before building, and it takes the `Option<T>` as-is
+//! (even with struct-level `impl_into`), so `None` can be passed directly.
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Deployment {
+//!     #[builder(required, transparent)]
+//!     region: Option<String>,
+//! }
+//!
+//! let deployment = Deployment::builder().region(None).build();
+//! assert_eq!(deployment.region, None);
+//! ```
+//!
+//! ## Extension Traits with `state_traits`
+//!
+//! The concrete builder state types are an implementation detail of the
+//! type-state pattern. To let other crates add combinators to a builder without
+//! naming those types, `#[builder(state_traits)]` generates two traits:
+//!
+//! - `{Struct}BuilderState` - implemented by every builder state, with
+//!   `type Target` naming the struct being built
+//! - `{Struct}BuilderFinish` - implemented by the states that can build the
+//!   struct, with the build method as a trait method
+//!
+//! ```
+//! use type_state_builder::TypeStateBuilder;
+//! use std::sync::Arc;
+//!
+//! #[derive(TypeStateBuilder, Debug, PartialEq)]
+//! #[builder(state_traits)]
+//! pub struct Service {
+//!     #[builder(required)]
+//!     name: String,
+//!     #[builder(default = 8080)]
+//!     port: u16,
+//! }
+//!
+//! // An extension trait written against the generated surface
+//! pub trait BuildArc: ServiceBuilderFinish + Sized {
+//!     fn build_arc(self) -> Arc<Self::Target> {
+//!         Arc::new(self.build())
+//!     }
+//! }
+//!
+//! impl<B: ServiceBuilderFinish> BuildArc for B {}
+//!
+//! let service = Service::builder().name("api".to_string()).build_arc();
+//! assert_eq!(service.port, 8080);
+//! ```
+//!
+//! Helpers that set optional fields face the same problem: written against
+//! one state type, they fix the point in the required-setter order where they
+//! can be called. `#[builder(setter_trait)]` generates a
+//! `{Struct}BuilderSetters` trait declaring the setters of the optional fields
+//! (including their `_with` and `try_` variants), implemented by every
+//! builder state. It is generic over the struct's type parameters:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(setter_trait)]
+//! struct Client {
+//!     #[builder(required)]
+//!     url: String,
+//!     #[builder(required)]
+//!     token: String,
+//!     #[builder(default = 30)]
+//!     timeout_secs: u64,
+//!     retries: u32,
+//! }
+//!
+//! fn common_tuning<B: ClientBuilderSetters>(builder: B) -> B {
+//!     builder.timeout_secs(5).retries(3)
+//! }
+//!
+//! // Works before, between and after the required setters
+//! let client = common_tuning(Client::builder().token("secret".to_string()))
+//!     .url("https://example.com".to_string())
+//!     .build();
+//! assert_eq!(client.timeout_secs, 5);
+//! ```
+//!
+//! The opposite direction works too: `#[builder(impl_trait = "path::Trait")]`
+//! implements one of your own traits for every builder state, so registries
+//! and plugin systems can hold the builders of unrelated structs behind one
+//! trait. The generated impls are empty, so every item of the trait needs a
+//! default. The attribute can be repeated, and the path is resolved where the
+//! builder types are generated (from inside the module with `module = "..."`):
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! pub trait Plugin {
+//!     fn type_name(&self) -> &'static str {
+//!         std::any::type_name::<Self>()
+//!     }
+//! }
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(impl_trait = "Plugin")]
+//! struct Logger {
+//!     #[builder(required)]
+//!     level: u8,
+//! }
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(impl_trait = "Plugin")]
+//! struct Cache {
+//!     capacity: usize,
+//! }
+//!
+//! let plugins: Vec<Box<dyn Plugin>> = vec![
+//!     Box::new(Logger::builder()),
+//!     Box::new(Logger::builder().level(3)),
+//!     Box::new(Cache::builder()),
+//! ];
+//! assert!(plugins[1].type_name().ends_with("LoggerBuilder_HasLevel"));
+//! ```
+//!
+//! To start builders from generic code, declare a trait with a `Builder` type
+//! and a `builder()` function and name it with
+//! `#[builder(buildable = "path::Buildable")]`. The struct implements it with
+//! the state `builder()` returns. This crate only provides the derive, so the
+//! trait lives in your crate, and `builder_method`, `no_entry` and
+//! `entry_vis` cannot be combined with it:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! pub trait Buildable {
+//!     type Builder;
+//!
+//!     fn builder() -> Self::Builder;
+//! }
+//!
+//! fn start<T: Buildable>() -> T::Builder {
+//!     T::builder()
+//! }
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(buildable = "Buildable")]
+//! struct Job {
+//!     #[builder(required)]
+//!     name: String,
+//!     retries: u8,
+//! }
+//!
+//! let job = start::<Job>().name("backup".to_string()).build();
+//! assert_eq!(job.retries, 0);
+//! ```
+//!
+//! ## Runtime-Checked Builders with `also_dynamic`
+//!
+//! Each setter of the type-state builder returns a different type, so a loop
+//! that sets fields from runtime data cannot keep the builder in a variable.
+//! `#[builder(also_dynamic)]` adds `{Struct}DynBuilder` next to it, started
+//! with `dyn_builder()`. It has one type whatever is set, and its build method
+//! returns `{Struct}DynBuildError` naming the first unset required field:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder, Debug)]
+//! #[builder(also_dynamic)]
+//! struct Plugin {
+//!     #[builder(required)]
+//!     name: String,
+//!     #[builder(default = 4)]
+//!     threads: usize,
+//! }
+//!
+//! let mut builder = Plugin::dyn_builder();
+//! for (key, value) in [("threads", "8"), ("name", "search")] {
+//!     builder = match key {
+//!         "name" => builder.name(value.to_string()),
+//!         "threads" => builder.threads(value.parse().unwrap()),
+//!         _ => builder,
+//!     };
+//! }
+//! let plugin = builder.build().unwrap();
+//! assert_eq!(plugin.threads, 8);
+//!
+//! let error = Plugin::dyn_builder().threads(2).build().unwrap_err();
+//! assert_eq!(error.field, "name");
+//! assert_eq!(error.message, "required field is not set");
+//! ```
+//!
+//! The build method is `#[track_caller]`: the error's `location` is the
+//! `std::panic::Location` it was called from, and its `Display` output ends
+//! with it, e.g. ``field `name`: required field is not set (at src/main.rs:12:10)``,
+//! so a log points at the code that forgot the field.
+//!
+//! The setters take the same input as those of the type-state builder, and the
+//! build method passes the stored values through it, so converters, defaults
+//! and checks apply alike; a rejected value is reported with its field as
+//! well. Setters that cannot store their input (`dyn_into`, `accumulate`,
+//! converters borrowing without a named lifetime) and `sensitive` fields cannot
+//! be combined with it.
+//!
+//! `into_builder` passes the stored values to the type-state builder and
+//! returns it in the state with every required field set, reporting unset
+//! fields like the build method, so the remaining setters and the build
+//! method are checked at compile time again.
+//!
+//! `reset(&mut self)` unsets every field, so one builder can be reused for many
+//! values. `into_iter` setters of `Vec` fields and `clone_from_ref` setters of
+//! `String` and `Vec` fields copy their argument into a buffer that `reset`
+//! empties but keeps, so setting them again reuses its capacity.
+//!
+//! With the `std` feature, `{Struct}DynBuildError` implements
+//! `std::error::Error` like the other generated error types, so it converts
+//! into `Box<dyn Error>` with `?`. The feature is off by default, so `no_std`
+//! crates get only the `Display` implementation.
+//!
+//! ## Converting on Build with `build_into`
+//!
+//! Every builder that can build also has `build_into::<U>()` (named after a
+//! custom build method, e.g. `create_into`), which builds the struct and
+//! converts it with `U: From<Struct>`. The value flows straight into wrapper,
+//! newtype or enum targets:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Point {
+//!     #[builder(required)]
+//!     x: i32,
+//! }
+//!
+//! enum Shape {
+//!     Dot(Point),
+//! }
+//!
+//! impl From<Point> for Shape {
+//!     fn from(point: Point) -> Self {
+//!         Shape::Dot(point)
+//!     }
+//! }
+//!
+//! let shape: Shape = Point::builder().x(1).build_into();
+//! let boxed = Point::builder().x(2).build_into::<Box<Point>>();
+//! ```
+//!
+//! ## Multiple Finishers
+//!
+//! `#[builder(finishers(...))]` adds build methods next to the regular one.
+//! Each finisher builds the struct, then optionally runs a `validate` function
+//! (`fn(&Struct) -> Result<(), E>`, with its error converted into `error` via
+//! `?`) and converts the result with `Into` when `into` is set. `Self` in
+//! `error` and `into` stands for the struct. Naming the build method itself is
+//! allowed and just documents that it stays available.
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//! use std::sync::Arc;
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct EmptyHost;
+//!
+//! fn check(config: &Config) -> Result<(), EmptyHost> {
+//!     if config.host.is_empty() { Err(EmptyHost) } else { Ok(()) }
+//! }
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(finishers(
+//!     build,
+//!     try_build(validate = check, error = EmptyHost),
+//!     build_arc(into = "Arc<Self>"),
+//! ))]
+//! struct Config {
+//!     #[builder(required)]
+//!     host: String,
+//! }
+//!
+//! // Infallible for tests, validating for production
+//! let config = Config::builder().host(String::new()).build();
+//! assert!(Config::builder().host(String::new()).try_build().is_err());
+//! let shared: Arc<Config> = Config::builder().host("db".to_string()).build_arc();
+//! ```
+//!
+//! A finisher with `context = Type` takes a `&Type` argument and passes it to
+//! `validate` as a second parameter. `garde` is a shorthand for running
+//! [garde](https://docs.rs/garde)'s `Validate` with the caller's context: it
+//! sets `validate`, `error = garde::Report` and the context type of the
+//! struct's `Validate` implementation. The `garde` crate must be a dependency.
+//!
+//! ```rust,ignore
+//! #[derive(TypeStateBuilder, garde::Validate)]
+//! #[garde(context(Policy))]
+//! #[builder(finishers(build, build_validated(garde)))]
+//! struct Account {
+//!     #[builder(required)]
+//!     #[garde(length(min = ctx.min_username))]
+//!     username: String,
+//! }
+//!
+//! let account: Result<Account, garde::Report> =
+//!     Account::builder().username("ada".to_string()).build_validated(&policy);
+//! ```
 //!
-//!     #[builder(default = "default.log".to_string())]
-//!     log_file: String,
+//! ### Restricting the Build Method with `build_vis`
+//!
+//! `#[builder(build_vis = "pub(crate)")]` gives the build method a narrower
+//! visibility than the builder, so code outside that scope has to go through a
+//! validating finisher. `build_into`, `build_clone` and the nesting support get
+//! the same visibility, and the `From<Builder>` impl is not generated. The
+//! visibility is relative to the struct's module, also with `module = "..."`.
+//!
+//! ```rust
+//! mod accounts {
+//!     use type_state_builder::TypeStateBuilder;
+//!
+//!     fn check(account: &Account) -> Result<(), String> {
+//!         if account.owner.is_empty() { Err("no owner".to_string()) } else { Ok(()) }
+//!     }
+//!
+//!     #[derive(TypeStateBuilder)]
+//!     #[builder(build_vis = "pub(self)", finishers(open(validate = check, error = String)))]
+//!     pub struct Account {
+//!         #[builder(required)]
+//!         pub owner: String,
+//!     }
+//! }
+//!
+//! // `build()` is private to `accounts`; everyone else calls `open()`
+//! let account = accounts::Account::builder().owner("Ada".to_string()).open();
+//! assert!(account.is_ok());
+//! ```
+//!
+//! `build_vis` cannot be combined with `state_traits`, whose public finish
+//! trait would call the build method, or with `lite`.
+//!
+//! ### Restricting the Entry Point with `entry_vis`
+//!
+//! `#[builder(entry_vis = "...")]` does the same for starting the builder:
+//! `builder()` (or the `builder_method` entry point, or `new` in lite mode)
+//! and the `new` constructors of the builder types get that visibility, and
+//! the regular builder no longer implements `Default`. The struct can then be
+//! public while only its own crate or module hands out builders.
+//!
+//! ```rust
+//! mod registry {
+//!     use type_state_builder::TypeStateBuilder;
+//!
+//!     #[derive(TypeStateBuilder)]
+//!     #[builder(entry_vis = "pub(self)")]
+//!     pub struct Handle {
+//!         #[builder(required)]
+//!         pub id: u32,
+//!         pub label: Option<String>,
+//!     }
+//!
+//!     pub fn handle(id: u32) -> HandleBuilder_HasId {
+//!         Handle::builder().id(id)
+//!     }
+//! }
+//!
+//! // `Handle::builder()` is private to `registry`, the builder it returns is not
+//! let handle = registry::handle(7).label(Some("main".to_string())).build();
+//! assert_eq!(handle.id, 7);
+//! ```
+//!
+//! Nested builders start and build the struct, so they are limited by both
+//! `entry_vis` and `build_vis`.
+//!
+//! ### Custom Entry Functions with `no_entry`
+//!
+//! `#[builder(no_entry)]` generates the builder types, setters and build
+//! method but no `builder()` (or `builder_method` entry point), so an entry
+//! function of your own can start the builder with the initial state's `new()`
+//! and hand out a builder with some fields already set:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(Clone, Copy)]
+//! pub struct Runtime;
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(no_entry)]
+//! pub struct Client {
+//!     #[builder(required)]
+//!     runtime: Runtime,
+//!     #[builder(required)]
+//!     name: String,
+//! }
+//!
+//! impl Client {
+//!     pub fn on(runtime: Runtime) -> ClientBuilder_HasRuntime_MissingName {
+//!         ClientBuilder_MissingRuntime_MissingName::new().runtime(runtime)
+//!     }
+//! }
+//!
+//! let client = Client::on(Runtime).name("api".to_string()).build();
+//! ```
+//!
+//! Nested builders keep working, as they start from the initial state too.
+//! `no_entry` cannot be combined with `hide_states`, whose states can only be
+//! reached through `builder()`, or with `arbitrary` and `lite`.
+//!
+//! ## Builders in a Separate Module
+//!
+//! A derive macro can only emit items next to the struct, so the builder types
+//! cannot be placed at an arbitrary path. `#[builder(module = "name")]` moves
+//! them into a child module instead, which can then be re-exported from
+//! wherever the codebase keeps its builders:
+//!
+//! ```rust
+//! mod model {
+//!     use type_state_builder::TypeStateBuilder;
+//!
+//!     #[derive(TypeStateBuilder)]
+//!     #[builder(module = "user_builder")]
+//!     pub struct User {
+//!         #[builder(required)]
+//!         pub name: String,
+//!     }
+//! }
+//!
+//! mod builders {
+//!     pub use crate::model::user_builder::*;
+//! }
+//!
+//! # fn main() {
+//! let builder: builders::UserBuilder_MissingName = model::User::builder();
+//! let user = builder.name("Ada".to_string()).build();
+//! assert_eq!(user.name, "Ada");
+//! # }
+//! ```
+//!
+//! The module imports everything from the struct's module, so field types,
+//! defaults and converters resolve as before. Paths starting with `super::`
+//! in those places need one more `super`, and the struct must be declared at
+//! module level rather than inside a function body.
+//!
+//! The builder types are exactly as visible as the struct, with or without a
+//! module: a private struct's builders are `pub(super)` inside the module,
+//! `pub(super)` and `pub(in super::...)` gain one more `super`, and
+//! restrictions such as `pub(crate)` or `pub(in crate::config)` are kept.
+//!
+//! ## Hiding Builder States
+//!
+//! A struct with N required fields gets 2^N builder state types, all declared
+//! beside the struct where they show up in glob imports and autocomplete.
+//! `#[builder(hide_states)]` declares them inside an anonymous `const _` block
+//! instead. The initial state stays nameable as `{Struct}Builder`, and the
+//! state traits and nested builders keep working:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(hide_states)]
+//! struct User {
+//!     #[builder(required)]
+//!     name: String,
+//!     #[builder(required)]
+//!     email: String,
+//! }
+//!
+//! fn with_admin_name(builder: UserBuilder) -> User {
+//!     builder.name("admin".to_string()).email("admin@example.com".to_string()).build()
+//! }
+//!
+//! assert_eq!(with_admin_name(User::builder()).name, "admin");
+//! ```
+//!
+//! Structs without required fields already have a single `{Struct}Builder`
+//! type, so the attribute changes nothing for them.
+//!
+//! ## Naming Builder States with `state_aliases`
+//!
+//! Storing a builder in a struct field or passing it between functions means
+//! writing its state type, such as `UserBuilder_HasName_HasEmail`.
+//! `#[builder(state_aliases)]` adds `{Struct}BuilderStart` for the state the
+//! builder starts in and `{Struct}BuilderComplete` for the state with every
+//! required field set. `state_aliases(start = "NewUser", complete = "ReadyUser")`
+//! picks other names, and either name may be left out:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(state_aliases(complete = "ReadyUser"))]
+//! struct User {
+//!     #[builder(required)]
+//!     name: String,
+//!     #[builder(required)]
+//!     email: String,
+//! }
+//!
+//! struct Signup {
+//!     pending: ReadyUser,
+//! }
+//!
+//! fn start() -> UserBuilderStart {
+//!     User::builder()
+//! }
+//!
+//! let signup = Signup {
+//!     pending: start().name("ada".to_string()).email("ada@example.com".to_string()),
+//! };
+//! assert_eq!(signup.pending.build().name, "ada");
+//! ```
+//!
+//! With `builder_method` the start alias names the state the entry point
+//! returns. The aliases also work with `hide_states` and `module`.
+//!
+//! ## Stable Public Names with `stable_names`
+//!
+//! The generated state names follow the fields, and the scheme may change
+//! between versions of this crate. A crate whose public API returns or stores
+//! builders can opt out of them with `#[builder(stable_names)]`, which implies
+//! `hide_states` and `state_aliases`. The only public names are then
+//! `{Struct}Builder` and `{Struct}BuilderStart` for the initial state,
+//! `{Struct}BuilderComplete` for the complete state (or the names given with
+//! `state_aliases(...)`), and with `state_traits` the `{Struct}BuilderState`
+//! and `{Struct}BuilderFinish` traits. These names are part of the crate's
+//! compatibility guarantee:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(stable_names)]
+//! pub struct Account {
+//!     #[builder(required)]
+//!     owner: String,
+//!     #[builder(required)]
+//!     email: String,
+//! }
+//!
+//! pub fn with_contact(owner: &str, email: &str) -> AccountBuilderComplete {
+//!     Account::builder().owner(owner.to_string()).email(email.to_string())
+//! }
+//!
+//! assert_eq!(with_contact("ada", "ada@example.com").build().owner, "ada");
+//! ```
+//!
+//! Intermediate states have no public name; generic code reaches them through
+//! `state_traits`. `stable_names` cannot be combined with `lite` or `no_entry`.
+//!
+//! ## Non-exhaustive Structs
+//!
+//! Other crates cannot construct a `#[non_exhaustive]` struct with a literal,
+//! so its builder is how they create it. The generated `build()` lives in the
+//! defining crate and may initialize every field, and an optional field added
+//! later only adds a setter: existing builder chains keep compiling. The
+//! `builder()` documentation says so for readers of the generated docs.
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[non_exhaustive]
+//! pub struct Endpoint {
+//!     #[builder(required)]
+//!     pub host: String,
+//!     pub port: Option<u16>,
+//! }
+//!
+//! let endpoint = Endpoint::builder().host("example.com".to_string()).build();
+//! assert_eq!(endpoint.port, None);
+//! ```
+//!
+//! The builder types are marked `#[non_exhaustive]` like the struct. Use
+//! `#[builder(non_exhaustive = false)]` to leave them unmarked, or
+//! `#[builder(non_exhaustive)]` to mark the builders of an exhaustive struct.
+//!
+//! ## Reusable Builders with `build_by = "clone"`
+//!
+//! The build method consumes the builder by default. With
+//! `#[builder(build_by = "clone")]` it takes `&self` and clones the stored
+//! fields instead, so one complete builder can stamp out many values. The
+//! method is only available when every field type is `Clone`:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(build_by = "clone")]
+//! struct Request {
+//!     #[builder(required)]
+//!     url: String,
+//!     retries: u8,
+//! }
+//!
+//! let template = Request::builder().url("https://example.com".to_string());
+//! let first = template.build();
+//! let second = template.retries(3).build();
+//!
+//! assert_eq!(first.url, second.url);
+//! assert_eq!(second.retries, 3);
+//! ```
+//!
+//! `build_by = "move"` is the default. Clone mode cannot be combined with
+//! `const` builders or `lazy_setter` fields, whose closures are not `Clone`.
+//!
+//! The consuming build method stays the default because it moves the fields
+//! without copying them. When only some call sites need a reusable builder,
+//! every complete builder also has `build_clone()`, which takes `&self` and
+//! can be called whenever the field types are `Clone`:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Job {
+//!     #[builder(required)]
+//!     command: String,
+//! }
+//!
+//! let template = Job::builder().command("cargo".to_string());
+//! let first = template.build_clone();
+//! let last = template.build();
+//!
+//! assert_eq!(first.command, last.command);
+//! ```
+//!
+//! It follows a custom build method name, so `build_method = "create"` gives
+//! `create_clone()`.
+//!
+//! ## Clone and Copy Builders with `derive`
+//!
+//! `#[builder(derive(Clone))]` implements `Clone` for every builder state, and
+//! `#[builder(derive(Copy, Clone))]` adds `Copy`. A `Copy` builder state can be
+//! kept in a constant and completed in several ways, which suits const and
+//! table-driven code:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder, Debug, PartialEq)]
+//! #[builder(const, derive(Copy, Clone))]
+//! struct Rgb {
+//!     #[builder(required)]
+//!     red: u8,
+//!     #[builder(required)]
+//!     green: u8,
+//! }
+//!
+//! const REDDISH: RgbBuilder_HasRed_MissingGreen = Rgb::builder().red(200);
+//!
+//! assert_eq!(REDDISH.green(10).build(), Rgb { red: 200, green: 10 });
+//! assert_eq!(REDDISH.green(180).build(), Rgb { red: 200, green: 180 });
+//! ```
+//!
+//! The impls are bounded on the values each state stores rather than on the
+//! type parameters, so a parameter that only appears in `PhantomData` needs no
+//! bound, and a state holding a non-`Copy` value is `Clone` but not `Copy`.
+//! `derive` cannot be combined with `lite` or `lazy_setter` fields.
+//!
+//! ## Persisting Drafts with Serde Derives
+//!
+//! With the `serde` feature, `#[builder(derive(Serialize, Deserialize))]`
+//! derives serde's traits for every builder state, so a partially filled
+//! builder, such as an unfinished form, can be stored and picked up later.
+//! Each state writes the values it holds under the field names and leaves out
+//! required fields it has not set, so the representation does not depend on
+//! the state. A draft is restored into a named state, which rejects drafts
+//! missing one of the fields the state has set:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")]
+//! # fn main() -> Result<(), serde_json::Error> {
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder, Debug)]
+//! #[builder(derive(Serialize, Deserialize), also_dynamic)]
+//! struct Signup {
+//!     #[builder(required)]
+//!     name: String,
+//!     #[builder(required)]
+//!     email: String,
+//!     #[builder(default = 18)]
+//!     age: u32,
+//! }
+//!
+//! let json = serde_json::to_string(&Signup::builder().name("ada".to_string()))?;
+//! assert_eq!(json, r#"{"name":"ada","age":18}"#);
+//!
+//! let draft: SignupBuilder_HasName_MissingEmail = serde_json::from_str(&json)?;
+//! let signup = draft.email("ada@example.com".to_string()).build();
+//! assert_eq!(signup.age, 18);
+//!
+//! // The dynamic builder restores a draft whatever it has set
+//! let draft: SignupDynBuilder = serde_json::from_str(&json)?;
+//! let builder = draft.email("ada@example.com".to_string()).into_builder().unwrap();
+//! assert_eq!(builder.age(36).build().name, "ada");
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
+//! ```
+//!
+//! With `also_dynamic`, the derives also apply to `{Struct}DynBuilder`, which
+//! has one type for every draft. It leaves out the fields that are not set and
+//! writes the others under their names, so it reads drafts saved from any
+//! state whose setters take the field types. Its `into_builder` method
+//! returns to the type-state builder in the state with every required field set.
+//!
+//! Computed fields are not stored. The derives are not available in `lite`
+//! mode or with `lazy_setter` and `sensitive` fields.
+//!
+//! ## Large Structs with `boxed`
+//!
+//! Every setter moves the builder into its next state. Optimized builds usually
+//! inline the setters and write each value straight into the built struct, but debug
+//! builds copy the stored values at each transition, which for structs with
+//! large fixed-size arrays can overflow the stack. `#[builder(boxed)]` keeps
+//! each value in a `Box`, so a state transition only moves pointers, and the
+//! build method returns `Box<Struct>`:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(boxed)]
+//! struct Frame {
+//!     #[builder(required)]
+//!     id: u32,
+//!     #[builder(required)]
+//!     pixels: [u8; 512 * 1024],
+//! }
+//!
+//! let frame: Box<Frame> = Frame::builder().id(1).pixels([0; 512 * 1024]).build();
+//! assert_eq!(frame.id, 1);
+//! ```
+//!
+//! `From` is implemented for `Box<Struct>` and `build_into` accepts any type
+//! implementing `From<Box<Struct>>`. A boxed builder cannot be `nestable`, and
+//! `boxed` cannot be combined with `const`, `lite`, `derive(Copy)` or `finishers`.
+//!
+//! When only a few fields are large, `#[builder(boxed_field)]` boxes just those
+//! fields. The builder states stay small, and the build method moves the values
+//! out of their boxes, so it still returns the struct itself:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Frame {
+//!     #[builder(required)]
+//!     id: u32,
+//!     #[builder(required, boxed_field)]
+//!     pixels: [u8; 512 * 1024],
+//! }
+//!
+//! let frame: Frame = Frame::builder().id(1).pixels([0; 512 * 1024]).build();
+//! assert_eq!(frame.id, 1);
+//! ```
+//!
+//! `boxed_field` cannot be used with `const`, `lite` or `derive(Copy)`.
+//!
+//! ## The `#[type_state_builder]` Attribute Macro
+//!
+//! A derive can only add items next to a struct, never change it. The
+//! [`macro@type_state_builder`] attribute macro generates the same builder but
+//! re-emits the struct, which makes builder-only fields possible: fields marked
+//! `#[builder(builder_only)]` get a setter and can be read by the `computed`,
+//! `default_from` and `resolve` closures of other fields, but are removed from
+//! the struct:
+//!
+//! ```rust
+//! use type_state_builder::type_state_builder;
+//!
+//! #[type_state_builder(setter_prefix = "with_")]
+//! struct Url {
+//!     #[builder(required, builder_only)]
+//!     host: String,
+//!     #[builder(builder_only, default = 443)]
+//!     port: u16,
+//!     #[builder(computed = |b| format!("https://{}:{}", b.host, b.port))]
+//!     href: String,
+//! }
+//!
+//! let url = Url::builder().with_host("example.com".to_string()).build();
+//! assert_eq!(url.href, "https://example.com:443");
+//! ```
+//!
+//! The macro arguments are struct-level builder attributes, and field
+//! attributes are written as `#[builder(...)]` as with the derive.
+//!
+//! ## Named Function Arguments with `#[builder_fn]`
+//!
+//! [`macro@builder_fn`] gives a free function a builder for its arguments.
+//! Calling the function starts the builder, each parameter gets a setter, and
+//! `call()` on the complete builder runs the function. Parameters are required
+//! unless their type is `Option<T>`, and take the usual field attributes:
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use type_state_builder::builder_fn;
+//!
+//! #[builder_fn]
+//! fn connect(
+//!     host: &str,
+//!     port: u16,
+//!     timeout: Option<Duration>,
+//!     #[builder(default = 3)] retries: u32,
+//! ) -> String {
+//!     format!("{host}:{port}, {retries} retries, timeout {timeout:?}")
+//! }
+//!
+//! let summary = connect().port(5432).host("db").call();
+//! assert_eq!(summary, "db:5432, 3 retries, timeout None");
+//! ```
+//!
+//! The arguments are stored in a generated `{Function}Args` struct, here
+//! `ConnectArgs`, whose builder types appear in error messages and can be
+//! named like any other builder states. Elided lifetimes in parameter types
+//! are named for the struct, `async` and `unsafe` carry over to `call()`, and
+//! the macro arguments are struct-level builder attributes. Methods and
+//! `impl Trait` parameters are not supported: an attribute on an associated
+//! function cannot add the arguments struct to the `impl` block, so a
+//! constructor can forward to a free function instead.
+//!
+//! ## Lite Mode
+//!
+//! For small structs the type-state builder can be more machinery than needed.
+//! `#[builder(lite)]` generates no builder types: the struct gets a `new`
+//! constructor taking the required fields in declaration order, and a chainable
+//! `with_{field}` method for each optional field:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(lite)]
+//! struct Endpoint {
+//!     #[builder(required)]
+//!     host: String,
+//!     #[builder(default = 80)]
+//!     port: u16,
+//!     path: Option<String>,
+//! }
+//!
+//! let endpoint = Endpoint::new("example.com".to_string()).with_port(8080);
+//!
+//! assert_eq!(endpoint.port, 8080);
+//! assert_eq!(endpoint.path, None);
+//! ```
+//!
+//! Setter options such as `setter_prefix`, `impl_into`, `option_into` and
+//! `default` apply as usual. Attributes that need a builder or a build method,
+//! like `state_traits`, `finishers` or `computed`, are rejected.
+//!
+//! ## Struct-Literal Construction with `build!`
+//!
+//! For one-off constructions, [`build!`] takes a struct literal and expands to
+//! the builder chain, so required fields are still checked at compile time:
+//!
+//! ```rust
+//! use type_state_builder::{build, TypeStateBuilder};
+//!
+//! #[derive(TypeStateBuilder)]
+//! struct Point {
+//!     #[builder(required)]
+//!     x: i32,
+//!     #[builder(required)]
+//!     y: i32,
+//!     label: Option<String>,
+//! }
+//!
+//! let point = build!(Point { y: 2, x: 1 }); // Point::builder().y(2).x(1).build()
+//! assert_eq!((point.x, point.y, point.label), (1, 2, None));
+//! ```
+//!
+//! Each field name is used as the setter name, so structs with custom setter
+//! names or a custom build method need the builder chain.
+//!
+//! ## Builder Lints
+//!
+//! Some configurations compile but are likely mistakes. The derive reports
+//! them as warnings at the offending field:
+//!
+//! - `impl_into` on a numeric field other than `i32` or `f64`. An unsuffixed
+//!   literal behind `impl Into<u16>` falls back to `i32`, so `.port(8080)`
+//!   does not compile.
+//! - A `converter` that takes the field type and returns its argument, such as
+//!   `|name: String| name`. The plain setter does the same without the closure.
+//!
+//! Proc macros cannot emit warnings directly on stable Rust, so a lint shows
+//! up as the use of a deprecated constant named after it, e.g.
+//! `impl_into_on_numeric`. `#[builder(lints = "deny")]` turns the findings
+//! into errors and `#[builder(lints = "allow")]` silences them.
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! #[derive(TypeStateBuilder)]
+//! #[builder(impl_into)]
+//! struct Server {
+//!     #[builder(required)]
+//!     host: String,
+//!     #[builder(impl_into = false)] // Without this, `port` would be linted
+//!     port: u16,
+//! }
+//!
+//! let server = Server::builder().host("localhost").port(8080).build();
+//! assert_eq!(server.port, 8080);
+//! ```
+//!
+//! ## Inspecting the Generated Code
+//!
+//! `#[builder(debug_expand)]` shows everything the derive generates for a
+//! struct in a compiler warning, one item and statement per line, without
+//! installing `cargo expand`:
+//!
+//! ```text
+//! warning: use of deprecated constant `_::debug_expand`: generated code for `Config`:
+//!
+//!          #[doc = "Builder for Config with 0 of 1 required fields set."]
+//!          ...
+//!          pub struct ConfigBuilder_MissingName {
+//!              name: ::core::option::Option<String>,
+//!          ...
+//! ```
+//!
+//! The warning is shown regardless of the `lints` setting. Remove the
+//! attribute again once done.
+//!
+//! ## Generated Documentation
+//!
+//! Builder types and methods carry generated doc comments. The docs on
+//! `builder()` list the setters under `# Required` and `# Optional` headings,
+//! each with the first line of its field's documentation, and draw the
+//! builder's states with the setters leading from one to the next under
+//! `# States` (only the first and last state beyond four required fields).
+//! Crates with strict documentation lints can turn them off with
+//! `#[builder(no_docs)]`; the undocumented public items are marked
+//! `#[allow(missing_docs)]` so that `#![deny(missing_docs)]` keeps passing.
+//! Doc comments written on the struct's fields are still copied to their
+//! setters:
+//!
+//! ```rust
+//! #![deny(missing_docs)]
+//! //! Configuration types.
+//! use type_state_builder::TypeStateBuilder;
+//!
+//! /// Server settings.
+//! #[derive(TypeStateBuilder)]
+//! #[builder(no_docs)]
+//! pub struct Server {
+//!     /// Hostname to bind to.
+//!     #[builder(required)]
+//!     pub host: String,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ## Debug Output
+//!
+//! Every builder implements `Debug` and prints the fields collected so far.
+//! Field types do not need to implement `Debug`: callbacks, trait objects and
+//! unbounded generic parameters print as `<non-Debug>`. Mark fields that should
+//! never be printed with `#[builder(skip_debug)]`:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
 //!
-//!     #[builder(skip_setter, default = 42)]
-//!     magic_number: i32,
+//! #[derive(TypeStateBuilder)]
+//! struct Job {
+//!     #[builder(required)]
+//!     name: String,
+//!     #[builder(default = Box::new(|| {}))]
+//!     on_done: Box<dyn Fn()>,
+//!     #[builder(skip_debug)]
+//!     token: String,
 //! }
 //!
-//! // Can call build() immediately since no required fields
-//! let settings = Settings::builder()
-//!     .debug(true)
-//!     .max_connections(Some(100))
-//!     .build();
+//! let builder = Job::builder().name("backup".to_string());
+//! assert_eq!(
+//!     format!("{builder:?}"),
+//!     r#"JobBuilder_HasName { name: "backup", on_done: <non-Debug>, .. }"#
+//! );
 //! ```
 //!
-//! ## Const Builders
-//!
-//! The `#[builder(const)]` attribute generates `const fn` builder methods, enabling
-//! compile-time constant construction. This is useful for embedded systems, static
-//! configuration, and other scenarios where values must be known at compile time.
+//! Secrets that should stay visible as present but never be printed, such as
+//! passwords and tokens, can use `#[builder(redact)]` instead; the field then
+//! prints as `"***"`:
 //!
-//! ```
+//! ```rust
 //! use type_state_builder::TypeStateBuilder;
 //!
-//! #[derive(TypeStateBuilder, Debug, PartialEq)]
-//! #[builder(const)]
-//! struct Config {
+//! #[derive(TypeStateBuilder)]
+//! struct Login {
 //!     #[builder(required)]
-//!     name: &'static str,
+//!     user: String,
+//!     #[builder(required, redact)]
+//!     password: String,
+//! }
 //!
-//!     #[builder(required)]
-//!     version: u32,
+//! let builder = Login::builder().user("admin".to_string()).password("hunter2".to_string());
+//! assert_eq!(
+//!     format!("{builder:?}"),
+//!     r#"LoginBuilder_HasUser_HasPassword { user: "admin", password: "***" }"#
+//! );
+//! ```
 //!
-//!     #[builder(default = 8080)]
-//!     port: u16,
+//! ## Zeroizing Sensitive Fields
+//!
+//! With the `zeroize` feature, `#[builder(sensitive)]` makes every builder state
+//! hold the field in [`zeroize::Zeroizing`](https://docs.rs/zeroize), so a
+//! builder that is dropped without being built wipes the value. `build()` moves
+//! the value into the struct, leaving an emptied wrapper behind; deriving
+//! `ZeroizeOnDrop` on the struct itself is up to you. Sensitive fields are
+//! redacted in `Debug` output, and builders whose fields are all sensitive
+//! implement `zeroize::ZeroizeOnDrop`.
+//!
+//! ```rust,ignore
+//! #[derive(TypeStateBuilder)]
+//! struct Credentials {
+//!     #[builder(required)]
+//!     user: String,
+//!     #[builder(required, sensitive)]
+//!     password: String,
 //! }
+//! ```
 //!
-//! // Compile-time constant construction
-//! const APP_CONFIG: Config = Config::builder()
-//!     .name("my-app")
-//!     .version(1)
-//!     .port(3000)
-//!     .build();
+//! The field type must implement `Zeroize` and `Default`. `sensitive` cannot be
+//! combined with `resolve` or `#[builder(const)]`.
 //!
-//! // Also works in static context
-//! static DEFAULT_CONFIG: Config = Config::builder()
-//!     .name("default")
-//!     .version(0)
-//!     .build();
+//! ## Fuzzing Through the Builder
 //!
-//! // And in const fn
-//! const fn make_config(name: &'static str) -> Config {
-//!     Config::builder()
-//!         .name(name)
-//!         .version(1)
-//!         .build()
+//! With the `arbitrary` feature, `#[builder(arbitrary)]` implements
+//! [`arbitrary::Arbitrary`](https://docs.rs/arbitrary) for the struct by calling
+//! its setters with arbitrary values. Each optional field is either set or left
+//! at its default, and fields with a `converter` receive an arbitrary value of
+//! the converter's input type. Fuzzers and property tests then construct the
+//! struct the same way production code does, so invariants enforced by
+//! converters, defaults and `resolve` hold for every generated value.
+//!
+//! ```rust,ignore
+//! #[derive(TypeStateBuilder, Debug)]
+//! #[builder(arbitrary)]
+//! struct Config {
+//!     #[builder(required)]
+//!     host: String,
+//!     #[builder(converter = |level: u8| level.min(9) as u32)]
+//!     compression: u32,
 //! }
 //!
-//! const CUSTOM: Config = make_config("custom");
+//! let config = Config::arbitrary(&mut arbitrary::Unstructured::new(&bytes))?;
+//! assert!(config.compression <= 9);
 //! ```
 //!
-//! ### Const Builder Requirements
-//!
-//! When using `#[builder(const)]`, there are some restrictions:
+//! Setters without a `converter` receive a value of the field type. Fields
+//! without a setter keep their defaults. For proptest, the implementation can be
+//! turned into a strategy with `proptest-arbitrary-interop`.
 //!
-//! - **Explicit defaults required**: Optional fields must use `#[builder(default = expr)]`
-//!   because `Default::default()` cannot be called in const context
-//! - **No `impl_into`**: The `impl_into` attribute is incompatible with const builders
-//!   because trait bounds are not supported in const fn
-//! - **Const-compatible types**: Field types must support const construction (e.g.,
-//!   `&'static str` instead of `String`, arrays instead of `Vec`)
+//! ## Configuration from the Environment
 //!
-//! ### Const Builders with Converters
+//! With the `env` feature, `#[builder(apply_env)]` adds an
+//! `apply_env(prefix: &str) -> Result<Self, {Struct}EnvError>` method to every
+//! builder state. It reads the variable `{PREFIX}_{FIELD}` (`{FIELD}` for an
+//! empty prefix) of each optional field, parses it with `FromStr` into the
+//! setter's input type and calls the setter. Variables that are not set are
+//! skipped, so together with defaults the struct becomes a 12-factor config
+//! loader:
 //!
-//! Closure converters work with const builders. The macro automatically generates
-//! a `const fn` from the closure body:
+//! ```rust,ignore
+//! #[derive(TypeStateBuilder)]
+//! #[builder(apply_env)]
+//! struct ServerConfig {
+//!     #[builder(required)]
+//!     name: String,
+//!     #[builder(default = 8080)]
+//!     port: u16,
+//!     host: Option<String>,
+//!     #[builder(skip_env)]
+//!     tags: Vec<String>,
+//! }
 //!
+//! std::env::set_var("APP_PORT", "9000");
+//! let config = ServerConfig::builder()
+//!     .name("api".to_string())
+//!     .apply_env("APP")?
+//!     .build();
+//! assert_eq!(config.port, 9000);
 //! ```
-//! use type_state_builder::TypeStateBuilder;
-//!
-//! #[derive(TypeStateBuilder, Debug, PartialEq)]
-//! #[builder(const)]
-//! struct Data {
-//!     // Converter closure is transformed into a const fn
-//!     #[builder(required, converter = |s: &'static str| s.len())]
-//!     name_length: usize,
 //!
-//!     #[builder(default = 0, converter = |n: i32| n * 2)]
-//!     doubled: i32,
+//! `Option<T>` fields parse `T`, fields with a `converter` parse the closure's
+//! input type, and setters taking `&str` receive the raw value. Fields whose
+//! setter input does not implement `FromStr` need `#[builder(skip_env)]`.
+//! `{Struct}EnvError` names the variable whose value was not unicode, could not
+//! be parsed or was rejected by a `validate_on_set` check. Required fields are
+//! not read, as setting them changes the builder's state; set them before or
+//! after `apply_env`. `apply_env` is not available in lite mode or on enums.
+//!
+//! ## Field Metadata with `field_descriptors`
+//!
+//! With the `metadata` feature,
+//! `#[builder(field_descriptors = "path::FieldDescriptor")]` adds a `FIELDS`
+//! constant to the struct, with one descriptor per field that has a setter:
+//! required fields first, then optional ones. Admin UIs and schema exports
+//! can be generated from it instead of keeping the metadata in sync by hand.
+//! The descriptor struct is yours, since a proc-macro crate cannot export
+//! types, and is filled by field name:
+//!
+//! ```rust,ignore
+//! pub struct FieldDescriptor {
+//!     pub name: &'static str,
+//!     pub required: bool,
+//!     pub type_name: &'static str,
+//!     pub has_default: bool,
+//!     pub doc: &'static str,
 //! }
 //!
-//! const DATA: Data = Data::builder()
-//!     .name_length("hello")  // Converted to 5
-//!     .doubled(21)           // Converted to 42
-//!     .build();
+//! #[derive(TypeStateBuilder)]
+//! #[builder(field_descriptors = "FieldDescriptor")]
+//! struct ServerConfig {
+//!     /// Address to listen on.
+//!     #[builder(required)]
+//!     host: String,
+//!     #[builder(default = 8080)]
+//!     port: u16,
+//! }
 //!
-//! assert_eq!(DATA.name_length, 5);
-//! assert_eq!(DATA.doubled, 42);
+//! assert_eq!(ServerConfig::FIELDS[0].doc, "Address to listen on.");
+//! assert_eq!(ServerConfig::FIELDS[1].type_name, "u16");
+//! assert!(ServerConfig::FIELDS[1].has_default);
 //! ```
 //!
-//! **Note**: The closure body must be const-evaluable. If it contains non-const
-//! operations (like heap allocation or trait method calls), the Rust compiler
-//! will produce an error.
-//!
-//! ## Builder Method Entry Point
+//! `has_default` is set for fields with a `default` or `default_from`; other
+//! optional fields start at `Default::default()`. `type_name` is the type as
+//! written on the field, and `doc` its doc comment without the `///` markers.
 //!
-//! The `#[builder(builder_method)]` attribute makes a required field's setter the
-//! entry point to the builder, replacing the `builder()` method. This provides a
-//! more ergonomic API when one field is the natural starting point.
+//! ## Test Fixtures with `test_default`
 //!
-//! ```
-//! use type_state_builder::TypeStateBuilder;
+//! Tests of code taking a struct with many required fields repeat every field
+//! in each test. `#[builder(test_default)]` adds `test_builder()`, which
+//! returns the complete builder state with every required field set to its
+//! field-level `test_default` or `Default::default()`. The builder's
+//! required setters are also available on that state, so a test only sets
+//! the fields it is about:
 //!
-//! #[derive(TypeStateBuilder, Debug, PartialEq)]
+//! ```rust,ignore
+//! #[derive(TypeStateBuilder)]
+//! #[builder(test_default)]
 //! struct User {
-//!     #[builder(required, builder_method)]
-//!     id: u64,
-//!     #[builder(required, impl_into)]
+//!     #[builder(required)]
 //!     name: String,
+//!     #[builder(required, test_default = Email::new("test@example.com"))]
+//!     email: Email,
+//!     #[builder(required)]
+//!     age: u8,
 //! }
 //!
-//! // Instead of User::builder().id(1).name("Alice").build()
-//! let user = User::id(1).name("Alice").build();
-//!
-//! assert_eq!(user.id, 1);
-//! assert_eq!(user.name, "Alice".to_string());
+//! #[test]
+//! fn minors_cannot_sign_up() {
+//!     let user = User::test_builder().age(17).build();
+//!     assert!(sign_up(user).is_err());
+//! }
 //! ```
 //!
-//! ### Requirements
+//! The method and the setters on the complete state only exist under
+//! `cfg(test)`, so production code cannot skip a required field by accident.
+//! `test_default(cfg(feature = "fixtures"))` picks another predicate, e.g. to
+//! share the fixtures with integration tests and other crates. The field-level
+//! `test_default` is only allowed on required fields; optional fields start
+//! from their defaults as in `builder()`. `test_default` is not available in
+//! lite mode or on enums.
+//!
+//! ## Thread Safety of Builders
+//!
+//! Builder states are `Send` and `Sync` through the usual auto trait rules: they
+//! store every field of the struct, so they follow the field types. A builder
+//! for `struct View<'a, T> { item: &'a T }` is `Send` whenever `T: Sync`, just
+//! like `View` itself. Only when a type parameter or lifetime is not mentioned
+//! by any field type (for example because it sits behind a macro) does the
+//! builder carry a `PhantomData` over the field types and every type parameter,
+//! which then also requires `T: Send` for the builder to be `Send`.
+//!
+//! To keep builders movable across threads as the struct evolves, opt into a
+//! compile-time check. `#[builder(assert_send)]` asserts `Send`, and
+//! `#[builder(assert_send_sync)]` asserts both, for every builder state:
+//!
+//! ```rust
+//! use type_state_builder::TypeStateBuilder;
+//! use std::sync::Arc;
 //!
-//! - Only one field per struct can have `builder_method`
-//! - The field must be required (not optional)
-//! - Cannot be combined with `skip_setter`
+//! #[derive(TypeStateBuilder)]
+//! #[builder(assert_send_sync)]
+//! struct Job {
+//!     #[builder(required)]
+//!     name: String,
+//!     payload: Option<Arc<[u8]>>,
+//! }
 //!
-//! ### With Const Builders
+//! let builder = Job::builder();
+//! let job = std::thread::spawn(move || builder.name("sync".to_string()).build())
+//!     .join()
+//!     .unwrap();
+//! assert_eq!(job.name, "sync");
+//! ```
 //!
-//! The `builder_method` attribute works with const builders:
+//! Replacing `Arc` with `Rc` turns this into a compile error at the struct. For
+//! generic structs the check assumes every type parameter is `Send` and `Sync`,
+//! so it flags fields like `Rc<T>` or `Cell<T>` without constraining callers.
 //!
-//! ```
+//! `#[builder(static_assert(Send, Sync, Unpin))]` asserts exactly the listed
+//! auto traits, so a builder can also be required to be `Sync` without `Send`,
+//! or `Unpin` for code that polls it in place:
+//!
+//! ```rust
 //! use type_state_builder::TypeStateBuilder;
 //!
-//! #[derive(TypeStateBuilder, Debug, PartialEq)]
-//! #[builder(const)]
-//! struct Config {
-//!     #[builder(required, builder_method)]
-//!     name: &'static str,
-//!     #[builder(default = 0)]
-//!     version: u32,
+//! #[derive(TypeStateBuilder)]
+//! #[builder(static_assert(Send, Unpin))]
+//! struct Task {
+//!     #[builder(required)]
+//!     id: u64,
+//!     // A `PhantomPinned` field here would fail to compile
+//!     retries: u8,
 //! }
-//!
-//! const APP: Config = Config::name("myapp").version(1).build();
 //! ```
 //!
 //! # Error Prevention
@@ -629,6 +3047,19 @@
 //! let user = User::builder().build(); // ERROR: required field not set
 //! ```
 //!
+//! The type-state builder has no runtime check to fall back on: a missing
+//! required field is a compile error at the call site, so generated code never
+//! panics over one, and finishers report validation failures as `Err` values.
+//! Where the fields are only known at runtime, `#[builder(also_dynamic)]`
+//! adds `{Struct}DynBuilder`, whose `#[track_caller]` build method returns a
+//! `{Struct}DynBuildError` naming the unset field and the location of the call
+//! instead (see
+//! [Runtime-Checked Builders](#runtime-checked-builders-with-also_dynamic)). Panics
+//! during building can only come from the expressions passed to the attributes,
+//! such as a `converter` or `resolve` closure, and point at that expression.
+//! The built-in `std_converters::parse` and `csv_list` name the field and the
+//! rejected input in their panic message.
+//!
 //! ```compile_fail
 //! use type_state_builder::TypeStateBuilder;
 //!
@@ -702,16 +3133,19 @@
 //!
 //! ## converter + impl_into
 //!
-//! Custom converters and `impl_into` are different approaches to parameter handling:
+//! A converter chained with `impl_into` only accepts values that convert into
+//! the converter's parameter type:
 //!
 //! ```compile_fail
 //! use type_state_builder::TypeStateBuilder;
 //!
 //! #[derive(TypeStateBuilder)]
-//! struct InvalidConverterImplInto {
-//!     #[builder(converter = |x: String| x, impl_into = true)]  // ERROR: Conflicting attributes
+//! struct ConverterImplInto {
+//!     #[builder(converter = |x: String| x, impl_into = true)]
 //!     field: String,
 //! }
+//!
+//! let _ = ConverterImplInto::builder().field(42).build();  // ERROR: String: From<i32> is not satisfied
 //! ```
 //!
 //! ## skip_setter + required
@@ -777,6 +3211,8 @@ mod validation;
 ///
 /// - `#[builder(build_method = "name")]` - Custom build method name (default: "build")
 /// - `#[builder(setter_prefix = "prefix_")]` - Prefix for all setter method names
+/// - `#[builder(setter_prefix(except(field, ...)))]` - Fields the struct-level prefix skips
+/// - `#[builder(prefix_scope = "optional")]` - Prefix only the setters of optional fields
 ///
 /// ## Field Attributes
 ///
@@ -784,7 +3220,7 @@ mod validation;
 /// - `#[builder(setter_name = "name")]` - Custom setter method name
 /// - `#[builder(setter_prefix = "prefix_")]` - Custom prefix for this field's setter (overrides struct-level)
 /// - `#[builder(default = expr)]` - Custom default value (must be valid Rust expression)
-/// - `#[builder(skip_setter)]` - Don't generate setter method (requires default value)
+/// - `#[builder(skip_setter)]` - Don't generate setter method (uses `default`, or `Default::default()`)
 ///
 /// # Generated Methods
 ///
@@ -834,6 +3270,135 @@ pub fn derive_type_state_builder(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Generates a type-safe builder like the derive, and may also rewrite the struct.
+///
+/// `#[type_state_builder]` accepts the same `#[builder(...)]` field attributes
+/// as [`TypeStateBuilder`], and its arguments are struct-level builder
+/// attributes, so `#[type_state_builder(build_method = "create")]` is the
+/// same as `#[builder(build_method = "create")]`.
+///
+/// Unlike a derive, an attribute macro re-emits the struct, which allows
+/// `#[builder(builder_only)]` fields: they are set on the builder and can feed
+/// `computed`, `default_from` and `resolve` closures, but are removed from the
+/// struct itself.
+///
+/// # Examples
+///
+/// ```
+/// use type_state_builder::type_state_builder;
+///
+/// #[type_state_builder]
+/// struct Greeting {
+///     #[builder(required, builder_only)]
+///     name: String,
+///     #[builder(computed = |b| format!("Hello, {}!", b.name))]
+///     text: String,
+/// }
+///
+/// let greeting = Greeting::builder().name("Ada".to_string()).build();
+/// assert_eq!(greeting.text, "Hello, Ada!");
+/// ```
+///
+/// Do not combine it with `#[derive(TypeStateBuilder)]` on the same struct.
+#[proc_macro_attribute]
+pub fn type_state_builder(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand_type_state_builder(args.into(), input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Generates a type-safe builder for the arguments of a function.
+///
+/// The function's parameters become setters, named after the parameters, and
+/// `call()` runs the function once every required argument is set. Parameters
+/// are required unless their type is `Option<T>`, and accept the same
+/// `#[builder(...)]` attributes as struct fields. The macro arguments are
+/// struct-level builder attributes.
+///
+/// The arguments are collected in a `{Function}Args` struct, so the builder of
+/// `connect` is `ConnectArgsBuilder`. Methods are not supported, as the struct
+/// cannot be generated inside an `impl` block.
+///
+/// # Examples
+///
+/// ```
+/// use type_state_builder::builder_fn;
+///
+/// #[builder_fn]
+/// fn connect(host: &str, port: u16, #[builder(default = 30)] timeout: u64) -> String {
+///     format!("{host}:{port} ({timeout}s)")
+/// }
+///
+/// let address = connect().port(5432).host("db").call();
+/// assert_eq!(address, "db:5432 (30s)");
+/// ```
+#[proc_macro_attribute]
+pub fn builder_fn(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::ItemFn);
+
+    match expand_builder_fn(args.into(), input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Builds a struct with record-literal syntax through its derived builder.
+///
+/// `build!(User { name: "Alice".to_string() })` expands to
+/// `User::builder().name("Alice".to_string()).build()`, so required fields are
+/// still checked at compile time while the call site reads like a struct literal.
+/// Fields left out keep their builder defaults.
+///
+/// # Examples
+///
+/// ```
+/// use type_state_builder::{build, TypeStateBuilder};
+///
+/// #[derive(TypeStateBuilder)]
+/// struct User {
+///     #[builder(required)]
+///     name: String,
+///     age: Option<u32>,
+/// }
+///
+/// let age = Some(36);
+/// let user = build!(User { name: "Alice".to_string(), age });
+///
+/// assert_eq!(user.name, "Alice");
+/// assert_eq!(user.age, Some(36));
+/// ```
+///
+/// Leaving out a required field fails to compile:
+///
+/// ```compile_fail
+/// use type_state_builder::{build, TypeStateBuilder};
+///
+/// #[derive(TypeStateBuilder)]
+/// struct User {
+///     #[builder(required)]
+///     name: String,
+///     age: Option<u32>,
+/// }
+///
+/// let user = build!(User { age: Some(36) });
+/// ```
+///
+/// The macro does not see the struct definition, so each field name is called
+/// as the setter and the chain ends in `build()`. Structs with custom setter
+/// names or a custom build method need the builder chain instead.
+#[proc_macro]
+pub fn build(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::ExprStruct);
+
+    match generation::build_macro::generate_build_expression(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
 /// Generates the complete builder implementation for a struct.
 ///
 /// This is the main implementation function that coordinates the analysis,
@@ -862,14 +3427,142 @@ pub fn derive_type_state_builder(input: TokenStream) -> TokenStream {
 /// - How to fix configuration issues
 /// - Examples of correct usage
 fn generate_builder_implementation(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    // Enums get a variant selection builder and a builder per variant
+    if let syn::Data::Enum(_) = &input.data {
+        let variants = analysis::analyze_enum(input)?;
+        reject_builder_only_fields(variants.iter().flat_map(|variant| variant.all_fields()))?;
+        return generation::generate_enum_builder(&variants);
+    }
+
     // Step 1: Analyze the struct definition
     let analysis = analysis::analyze_struct(input)?;
 
+    reject_builder_only_fields(analysis.all_fields())?;
+
     // Step 2: Validate the analysis for builder generation
     analysis.validate_for_generation()?;
 
     // Step 3: Generate the appropriate builder implementation
-    generation::generate_builder(&analysis)
+    generate_struct_builder(input, &analysis)
+}
+
+/// Generates the builder for an analyzed struct.
+///
+/// Structs with `required(cfg(...))` fields get one builder per combination
+/// of the predicates, each gated on its combination.
+fn generate_struct_builder(
+    input: &DeriveInput,
+    analysis: &analysis::StructAnalysis,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let predicates = analysis.required_cfg_predicates();
+    if predicates.is_empty() {
+        generation::generate_builder(analysis)
+    } else {
+        generation::generate_required_cfg_builders(input, &predicates)
+    }
+}
+
+/// Rejects `builder_only` fields, as a derive cannot remove fields from the
+/// struct it is applied to.
+fn reject_builder_only_fields<'a>(
+    mut fields: impl Iterator<Item = &'a analysis::FieldInfo>,
+) -> syn::Result<()> {
+    match fields.find(|field| field.attributes().builder_only) {
+        Some(field) => Err(validation::ErrorMessages::structured_error_span(
+            field.name().span(),
+            &format!(
+                "field `{}`: `builder_only` requires the `#[type_state_builder]` attribute macro",
+                field.name()
+            ),
+            Some("a derive cannot remove the field from the struct"),
+            Some("replace `#[derive(TypeStateBuilder)]` with `#[type_state_builder]`"),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Expands the `#[type_state_builder]` attribute macro.
+///
+/// # Arguments
+///
+/// * `args` - The struct-level builder attributes passed to the macro
+/// * `input` - The struct the macro is applied to
+///
+/// # Returns
+///
+/// A `syn::Result<proc_macro2::TokenStream>` containing the struct, without
+/// its `builder` attributes and builder-only fields, followed by the builder.
+fn expand_type_state_builder(
+    args: proc_macro2::TokenStream,
+    mut input: DeriveInput,
+) -> syn::Result<proc_macro2::TokenStream> {
+    if !args.is_empty() {
+        input.attrs.push(syn::parse_quote!(#[builder(#args)]));
+    }
+
+    let analysis = analysis::analyze_struct(&input)?;
+    analysis.validate_for_generation()?;
+    let builder = generate_struct_builder(&input, &analysis)?;
+    let builder_only: Vec<&syn::Ident> = analysis
+        .all_fields()
+        .filter(|field| field.attributes().builder_only)
+        .map(|field| field.name())
+        .collect();
+
+    // Without the derive, `builder` is not a known helper attribute
+    let mut output = input.clone();
+    output.attrs.retain(|attr| !attr.path().is_ident("builder"));
+    if let syn::Data::Struct(data) = &mut output.data {
+        if let syn::Fields::Named(fields) = &mut data.fields {
+            fields.named = std::mem::take(&mut fields.named)
+                .into_iter()
+                .filter(|field| {
+                    !field
+                        .ident
+                        .as_ref()
+                        .is_some_and(|ident| builder_only.contains(&ident))
+                })
+                .map(|mut field| {
+                    field.attrs.retain(|attr| !attr.path().is_ident("builder"));
+                    field
+                })
+                .collect();
+        }
+    }
+
+    Ok(quote::quote! {
+        #output
+        #builder
+    })
+}
+
+/// Expands `#[builder_fn]`: the arguments struct with its builder, the entry
+/// function and `call()`.
+///
+/// # Arguments
+///
+/// * `args` - The macro arguments, used as struct-level builder attributes
+/// * `function` - The function the attribute is applied to
+///
+/// # Returns
+///
+/// A `syn::Result` containing the generated items.
+fn expand_builder_fn(
+    args: proc_macro2::TokenStream,
+    function: syn::ItemFn,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let builder_fn = generation::builder_fn::BuilderFn::parse(args, function)?;
+    let mut analysis = analysis::analyze_struct(builder_fn.args_struct())?;
+    reject_builder_only_fields(analysis.all_fields())?;
+    analysis.validate_for_generation()?;
+    let function = builder_fn.generate(&analysis)?;
+    analysis.set_nestable();
+    let builder = generation::generate_builder(&analysis)?;
+
+    Ok(quote::quote! {
+        #function
+        #builder
+    })
 }
 
 // Internal types for testing - not exported due to proc-macro restrictions
@@ -990,10 +3683,10 @@ mod tests {
 
     #[test]
     fn test_unsupported_input_types() {
-        // Test enum - should fail
+        // Test enum with tuple variants - should fail
         let enum_input: DeriveInput = parse_quote! {
             enum Example {
-                A, B
+                A(String), B
             }
         };
         let result = generate_builder_implementation(&enum_input);
@@ -1013,4 +3706,34 @@ mod tests {
         let result = generate_builder_implementation(&unit_input);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_builder_fn_expansion() {
+        let function: syn::ItemFn = parse_quote! {
+            fn connect(host: String, port: Option<u16>) -> String {
+                format!("{host}:{}", port.unwrap_or(80))
+            }
+        };
+        let code = expand_builder_fn(quote::quote! {}, function)
+            .unwrap()
+            .to_string();
+        assert!(code.contains("struct ConnectArgs"));
+        assert!(code.contains("fn connect () -> ConnectArgsBuilderStart"));
+        assert!(code.contains("impl ConnectArgsBuilderReady"));
+        assert!(code.contains("let ConnectArgs { host , port , .. } = self . build () ;"));
+
+        for (args, error) in [
+            (quote::quote! { lite }, "cannot be combined with `lite`"),
+            (
+                quote::quote! { hide_states },
+                "cannot be combined with `hide_states`",
+            ),
+        ] {
+            let function: syn::ItemFn = parse_quote! {
+                fn connect(host: String) {}
+            };
+            let message = expand_builder_fn(args, function).unwrap_err().to_string();
+            assert!(message.contains(error), "{message}");
+        }
+    }
 }
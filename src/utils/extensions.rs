@@ -196,6 +196,13 @@ impl TypeExt for Type {
             Type::Ptr(type_ptr) => {
                 return type_ptr.elem.references_generics(declared_generics);
             }
+            Type::Group(type_group) => {
+                // Types passed through `macro_rules!` as `$ty:ty` fragments
+                return type_group.elem.references_generics(declared_generics);
+            }
+            Type::Paren(type_paren) => {
+                return type_paren.elem.references_generics(declared_generics);
+            }
             _ => {
                 // Other types are less common in struct fields
             }
@@ -210,6 +217,7 @@ impl TypeExt for Type {
                 type_path.path.segments.len() == 1
                     && type_path.path.segments[0].arguments.is_empty()
             }
+            Type::Group(type_group) => type_group.elem.is_simple_path(),
             _ => false,
         }
     }
@@ -267,6 +275,12 @@ impl _UnusedTypeAnalysis for Type {
             Type::Ptr(type_ptr) => {
                 type_ptr.elem.collect_generic_names_recursive(names);
             }
+            Type::Group(type_group) => {
+                type_group.elem.collect_generic_names_recursive(names);
+            }
+            Type::Paren(type_paren) => {
+                type_paren.elem.collect_generic_names_recursive(names);
+            }
             _ => {}
         }
     }
@@ -310,6 +324,12 @@ impl _UnusedTypeAnalysis for Type {
             Type::Ptr(type_ptr) => {
                 type_ptr.elem.collect_lifetime_names_recursive(names);
             }
+            Type::Group(type_group) => {
+                type_group.elem.collect_lifetime_names_recursive(names);
+            }
+            Type::Paren(type_paren) => {
+                type_paren.elem.collect_lifetime_names_recursive(names);
+            }
             _ => {}
         }
     }
@@ -386,6 +406,12 @@ mod tests {
 
         let complex_type: Type = parse_quote!(Vec<T>);
         assert!(complex_type.references_generics(&declared_generics));
+
+        let grouped_type = Type::Group(syn::TypeGroup {
+            group_token: Default::default(),
+            elem: Box::new(parse_quote!(Option<T>)),
+        });
+        assert!(grouped_type.references_generics(&declared_generics));
     }
 
     #[test]
@@ -145,7 +145,7 @@ pub fn resolve_setter_parameter_config(
     } else if use_impl_into {
         // impl_into case
         SetterParameterConfig {
-            param_type: quote::quote! { impl Into<#field_type> },
+            param_type: quote::quote! { impl ::core::convert::Into<#field_type> },
             field_assignment_expr: quote::quote! { value.into() },
         }
     } else {
@@ -157,6 +157,29 @@ pub fn resolve_setter_parameter_config(
     }
 }
 
+/// Determines setter parameter configuration for a converter chained with `impl_into`.
+///
+/// The setter accepts `impl Into<I>`, where `I` is the converter's parameter
+/// type, and passes the converted value to the converter.
+///
+/// # Arguments
+///
+/// * `converter` - The converter closure expression
+///
+/// # Returns
+///
+/// A `SetterParameterConfig`, or `None` if the converter's parameter type
+/// cannot be extracted.
+pub fn resolve_converter_into_parameter_config(
+    converter: &syn::Expr,
+) -> Option<SetterParameterConfig> {
+    let input_type = extract_closure_parameter_type(converter)?;
+    Some(SetterParameterConfig {
+        param_type: quote::quote! { impl ::core::convert::Into<#input_type> },
+        field_assignment_expr: quote::quote! { (#converter)(value.into()) },
+    })
+}
+
 /// Extracts the parameter type from a closure expression.
 ///
 /// This function parses a closure expression like `|value: Vec<&str>| ...`
@@ -198,6 +221,64 @@ fn extract_closure_parameter_type(expr: &syn::Expr) -> Option<proc_macro2::Token
     }
 }
 
+/// Extracts the input type from an `accumulate` closure.
+///
+/// This function parses a closure expression like
+/// `|tags, tag: &str| tags.push(tag.to_string())` and extracts the type of its
+/// second parameter, `&str`, which the setter takes as its argument.
+///
+/// # Arguments
+///
+/// * `expr` - The closure expression to parse
+///
+/// # Returns
+///
+/// An `Option<proc_macro2::TokenStream>` containing the parameter type, or
+/// `None` if the expression is not a closure with exactly two parameters, the
+/// second of which has an explicit type.
+pub fn extract_accumulator_parameter_type(expr: &syn::Expr) -> Option<proc_macro2::TokenStream> {
+    let syn::Expr::Closure(closure) = expr else {
+        return None;
+    };
+    if closure.inputs.len() != 2 {
+        return None;
+    }
+    match &closure.inputs[1] {
+        syn::Pat::Type(pat_type) => {
+            let param_type = &pat_type.ty;
+            Some(quote::quote! { #param_type })
+        }
+        _ => None,
+    }
+}
+
+/// Annotates the first parameter of a closure with a type, unless it has one.
+///
+/// A closure called where it is defined, like `(|v| v.len())(&value)`, does
+/// not get its parameter types from the call, so generated code types the
+/// parameter the macro passes in.
+///
+/// # Arguments
+///
+/// * `expr` - The closure expression
+/// * `param_type` - The type of the first parameter
+///
+/// # Returns
+///
+/// The closure with its first parameter typed, or the expression unchanged if
+/// it is not a closure or the parameter is already typed.
+pub fn type_first_closure_parameter(expr: &syn::Expr, param_type: &syn::Type) -> syn::Expr {
+    let mut expr = expr.clone();
+    if let syn::Expr::Closure(closure) = &mut expr {
+        if let Some(first) = closure.inputs.first_mut() {
+            if !matches!(first, syn::Pat::Type(_)) {
+                *first = syn::Pat::Type(syn::parse_quote! { #first: #param_type });
+            }
+        }
+    }
+    expr
+}
+
 /// Information extracted from a closure expression for const fn generation.
 #[derive(Debug)]
 pub struct ClosureInfo {
@@ -260,6 +341,557 @@ pub fn extract_closure_info(expr: &syn::Expr) -> Option<ClosureInfo> {
     }
 }
 
+/// Extracts `T` from an `Option<T>` field type.
+///
+/// Recognizes `Option<T>` as well as the qualified `core::option::Option<T>` and
+/// `std::option::Option<T>` paths. Type aliases of `Option` are not resolved.
+///
+/// # Arguments
+///
+/// * `ty` - The field type to inspect
+///
+/// # Returns
+///
+/// `Some(&T)` if the type is an `Option<T>`, `None` otherwise.
+pub fn extract_option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    extract_single_type_argument(ty, "Option", &["core", "std"], "option")
+}
+
+/// Extracts `T` from a `Vec<T>` field type.
+///
+/// Recognizes `Vec<T>` as well as the qualified `alloc::vec::Vec<T>` and
+/// `std::vec::Vec<T>` paths. Type aliases of `Vec` are not resolved.
+///
+/// # Arguments
+///
+/// * `ty` - The field type to inspect
+///
+/// # Returns
+///
+/// `Some(&T)` if the type is a `Vec<T>`, `None` otherwise.
+pub fn extract_vec_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    extract_single_type_argument(ty, "Vec", &["alloc", "std"], "vec")
+}
+
+/// Extracts the single type argument of `name<T>`, optionally qualified as
+/// `krate::module::name<T>` for one of the given crates.
+fn extract_single_type_argument<'a>(
+    ty: &'a syn::Type,
+    name: &str,
+    crates: &[&str],
+    module: &str,
+) -> Option<&'a syn::Type> {
+    let syn::Type::Path(type_path) = peel_type_groups(ty) else {
+        return None;
+    };
+    if type_path.qself.is_some() {
+        return None;
+    }
+
+    let segments: Vec<_> = type_path.path.segments.iter().collect();
+    let (last, prefix) = segments.split_last()?;
+    let prefix: Vec<String> = prefix.iter().map(|seg| seg.ident.to_string()).collect();
+    let is_expected_path = match prefix.as_slice() {
+        [] => true,
+        [krate, path_module] => crates.contains(&krate.as_str()) && path_module == module,
+        _ => false,
+    };
+    if !is_expected_path || last.ident != name {
+        return None;
+    }
+
+    match &last.arguments {
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+            match args.args.first() {
+                Some(syn::GenericArgument::Type(inner)) => Some(inner),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Looks through the invisible groups and parentheses around a type.
+///
+/// A type passed into `macro_rules!` as a `$ty:ty` fragment reaches the derive
+/// wrapped in an invisible group, so `Option<T>` arrives as `Type::Group`.
+/// Peeling these keeps macro-generated structs working like written ones.
+///
+/// # Arguments
+///
+/// * `ty` - The type to inspect
+///
+/// # Returns
+///
+/// The innermost type that is neither a group nor parenthesized.
+pub fn peel_type_groups(ty: &syn::Type) -> &syn::Type {
+    match ty {
+        syn::Type::Group(group) => peel_type_groups(&group.elem),
+        syn::Type::Paren(paren) => peel_type_groups(&paren.elem),
+        _ => ty,
+    }
+}
+
+/// Determines the setter parameter configuration for an `into_iter` setter.
+///
+/// The setter accepts any iterable whose items convert into the `Vec<T>`
+/// element type and collects them into the field.
+///
+/// # Arguments
+///
+/// * `field_type` - The type of the field being set
+///
+/// # Returns
+///
+/// `Some(SetterParameterConfig)` if the field type is `Vec<T>`, `None` otherwise.
+pub fn resolve_into_iter_parameter_config(field_type: &syn::Type) -> Option<SetterParameterConfig> {
+    let item_type = extract_vec_inner_type(field_type)?;
+    Some(SetterParameterConfig {
+        param_type: quote::quote! {
+            impl ::core::iter::IntoIterator<Item = impl ::core::convert::Into<#item_type>>
+        },
+        field_assignment_expr: quote::quote! {
+            ::core::iter::Iterator::collect(
+                ::core::iter::Iterator::map(
+                    ::core::iter::IntoIterator::into_iter(value),
+                    ::core::convert::Into::into,
+                ),
+            )
+        },
+    })
+}
+
+/// Returns `true` if the field type is `String`.
+///
+/// Recognizes `String` as well as the qualified `alloc::string::String` and
+/// `std::string::String` paths. Type aliases of `String` are not resolved.
+///
+/// # Arguments
+///
+/// * `ty` - The field type to inspect
+pub fn is_string_type(ty: &syn::Type) -> bool {
+    let syn::Type::Path(type_path) = peel_type_groups(ty) else {
+        return false;
+    };
+    if type_path.qself.is_some() {
+        return false;
+    }
+
+    let segments: Vec<String> = type_path
+        .path
+        .segments
+        .iter()
+        .map(|seg| seg.ident.to_string())
+        .collect();
+    let no_arguments = type_path
+        .path
+        .segments
+        .iter()
+        .all(|seg| seg.arguments.is_none());
+    no_arguments
+        && matches!(
+            segments
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .as_slice(),
+            ["String"] | ["alloc" | "std", "string", "String"]
+        )
+}
+
+/// Determines the setter parameter configuration for a `clone_from_ref` setter.
+///
+/// The setter borrows its argument and stores an owned copy: `String` fields
+/// take `&str`, `Vec<T>` fields take `&[T]` and other fields take `&T`. The
+/// conversions go through `From` and `Clone`, which are both in `core`.
+///
+/// # Arguments
+///
+/// * `field_type` - The type of the field being set
+///
+/// # Returns
+///
+/// A `SetterParameterConfig` with the borrowed parameter type.
+pub fn resolve_clone_from_ref_parameter_config(field_type: &syn::Type) -> SetterParameterConfig {
+    if is_string_type(field_type) {
+        SetterParameterConfig {
+            param_type: quote::quote! { &str },
+            field_assignment_expr: quote::quote! {
+                <#field_type as ::core::convert::From<&str>>::from(value)
+            },
+        }
+    } else if let Some(item_type) = extract_vec_inner_type(field_type) {
+        SetterParameterConfig {
+            param_type: quote::quote! { &[#item_type] },
+            field_assignment_expr: quote::quote! {
+                <#field_type as ::core::convert::From<&[#item_type]>>::from(value)
+            },
+        }
+    } else {
+        SetterParameterConfig {
+            param_type: quote::quote! { &#field_type },
+            field_assignment_expr: quote::quote! {
+                <#field_type as ::core::clone::Clone>::clone(value)
+            },
+        }
+    }
+}
+
+/// Builds the path of a builder alias generated for a nested field's type.
+///
+/// Every struct deriving `TypeStateBuilder` exposes `{Struct}BuilderStart` and
+/// `{Struct}BuilderReady` aliases next to itself. This rewrites the last path
+/// segment of the field type, keeping its module path and generic arguments,
+/// so `crate::geo::Address<T>` becomes `crate::geo::AddressBuilderStart<T>`.
+///
+/// # Arguments
+///
+/// * `ty` - The nested field type
+/// * `suffix` - The alias suffix, e.g. `"BuilderStart"`
+///
+/// # Returns
+///
+/// `Some(syn::Type)` for plain path types, `None` for any other type.
+pub fn nested_builder_alias(ty: &syn::Type, suffix: &str) -> Option<syn::Type> {
+    let syn::Type::Path(type_path) = peel_type_groups(ty) else {
+        return None;
+    };
+    if type_path.qself.is_some() {
+        return None;
+    }
+
+    let mut alias_path = type_path.clone();
+    let last = alias_path.path.segments.last_mut()?;
+    if matches!(last.arguments, syn::PathArguments::Parenthesized(_)) {
+        return None;
+    }
+    last.ident = syn::Ident::new(&format!("{}{suffix}", last.ident), last.ident.span());
+    Some(syn::Type::Path(alias_path))
+}
+
+/// Builds the converter closure of a `#[builder(wrap = ...)]` field.
+///
+/// The closure takes the inner type and passes it to the constructor. Without
+/// an explicit constructor the field type itself is called as a tuple struct,
+/// with its generic arguments written as a turbofish, so `Id<u8>` becomes
+/// `Id::<u8>(value)`. `Option<T>` fields wrap the value into `T` and store it
+/// as `Some`.
+///
+/// # Arguments
+///
+/// * `inner` - The type the setter takes
+/// * `constructor` - The constructor given with `with = path`, if any
+/// * `field_type` - The field type the closure produces
+///
+/// # Returns
+///
+/// The closure expression, or an error when the newtype is not a plain path
+/// type and no constructor was given.
+pub fn wrap_converter(
+    inner: &syn::Type,
+    constructor: Option<&syn::Path>,
+    field_type: &syn::Type,
+) -> syn::Result<syn::Expr> {
+    let newtype = extract_option_inner_type(field_type).unwrap_or(field_type);
+    let constructor = match constructor {
+        Some(path) => path.clone(),
+        None => {
+            let type_path = match peel_type_groups(newtype) {
+                syn::Type::Path(type_path) if type_path.qself.is_none() => type_path,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        field_type,
+                        "#[builder(wrap)] needs a newtype such as `UserId` or a constructor given as `wrap(Inner, with = path)`",
+                    ));
+                }
+            };
+            let mut path = type_path.path.clone();
+            for segment in &mut path.segments {
+                if let syn::PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+                    arguments.colon2_token = Some(Default::default());
+                }
+            }
+            path
+        }
+    };
+
+    let value = if extract_option_inner_type(field_type).is_some() {
+        quote::quote! { ::core::option::Option::Some(#constructor(value)) }
+    } else {
+        quote::quote! { #constructor(value) }
+    };
+    Ok(syn::parse_quote! {
+        |value: #inner| -> #field_type { #value }
+    })
+}
+
+/// Builds the converter closure of a `#[builder(setter_input = ...)]` field converted with `From`.
+///
+/// # Arguments
+///
+/// * `input` - The type the setter takes
+/// * `field_type` - The field type the closure produces
+///
+/// # Returns
+///
+/// The closure expression, which calls `From::from` through the field type.
+pub fn from_input_converter(input: &syn::Type, field_type: &syn::Type) -> syn::Expr {
+    syn::parse_quote! {
+        |value: #input| -> #field_type { <#field_type as ::core::convert::From<#input>>::from(value) }
+    }
+}
+
+/// Builds the default of a `default_through_converter` field.
+///
+/// The default is written in the converter's input type. A closure with a
+/// typed parameter is inlined as a block binding the default to the
+/// parameter, which also works in const builders, where closures cannot be
+/// called. Other converters are called with the default.
+///
+/// # Arguments
+///
+/// * `converter` - The field's converter, after `wrap` and built-in converters are expanded
+/// * `default` - The `default` expression in the converter's input type
+///
+/// # Returns
+///
+/// The expression producing the default in the field type.
+pub fn converted_default(converter: &syn::Expr, default: &syn::Expr) -> syn::Expr {
+    match extract_closure_info(converter) {
+        Some(ClosureInfo {
+            param_name,
+            param_type,
+            body,
+        }) => syn::parse_quote! {
+            {
+                let #param_name: #param_type = #default;
+                #body
+            }
+        },
+        None => syn::parse_quote! { (#converter)(#default) },
+    }
+}
+
+/// Determines the setter parameter configuration for a `dyn_into` setter.
+///
+/// The field type must be `Box<dyn Trait>`, `Rc<dyn Trait>` or
+/// `Arc<dyn Trait>`, optionally inside an `Option`. The setter takes
+/// `impl Trait` with the trait object's bounds, plus `'static` when they name
+/// no lifetime, and calls the pointer's `new` through the path written in the
+/// field type, so `Arc<dyn Handler + Send>` becomes `Arc::new(value) as _`.
+///
+/// # Arguments
+///
+/// * `field_type` - The type of the field being set
+///
+/// # Returns
+///
+/// A `SetterParameterConfig`, or an error when the field type is not a
+/// supported pointer to a trait object.
+pub fn resolve_dyn_into_parameter_config(
+    field_type: &syn::Type,
+) -> syn::Result<SetterParameterConfig> {
+    let option_inner = extract_option_inner_type(field_type);
+    let pointer_type = option_inner.unwrap_or(field_type);
+    let (pointer_path, bounds) = extract_dyn_pointer(pointer_type).ok_or_else(|| {
+        syn::Error::new_spanned(
+            field_type,
+            "#[builder(dyn_into)] needs a `Box<dyn Trait>`, `Rc<dyn Trait>` or `Arc<dyn Trait>` field",
+        )
+    })?;
+
+    let has_lifetime = bounds
+        .iter()
+        .any(|bound| matches!(bound, syn::TypeParamBound::Lifetime(_)));
+    let static_bound = (!has_lifetime).then(|| quote::quote! { + 'static });
+    let pointer = quote::quote! { #pointer_path::new(value) as #pointer_type };
+    let field_assignment_expr = if option_inner.is_some() {
+        quote::quote! { ::core::option::Option::Some(#pointer) }
+    } else {
+        pointer
+    };
+    Ok(SetterParameterConfig {
+        param_type: quote::quote! { impl #bounds #static_bound },
+        field_assignment_expr,
+    })
+}
+
+/// Splits a `Box`, `Rc` or `Arc` of a trait object into its pointer path and bounds.
+///
+/// The returned path is the type's path without the generic arguments of its
+/// last segment, so `std::sync::Arc<dyn Handler>` yields `std::sync::Arc`.
+fn extract_dyn_pointer(
+    ty: &syn::Type,
+) -> Option<(
+    syn::Path,
+    &syn::punctuated::Punctuated<syn::TypeParamBound, syn::Token![+]>,
+)> {
+    let syn::Type::Path(type_path) = peel_type_groups(ty) else {
+        return None;
+    };
+    if type_path.qself.is_some() {
+        return None;
+    }
+    let last = type_path.path.segments.last()?;
+    if !matches!(last.ident.to_string().as_str(), "Box" | "Rc" | "Arc") {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(arguments) = &last.arguments else {
+        return None;
+    };
+    if arguments.args.len() != 1 {
+        return None;
+    }
+    let Some(syn::GenericArgument::Type(pointee)) = arguments.args.first() else {
+        return None;
+    };
+    let syn::Type::TraitObject(trait_object) = peel_type_groups(pointee) else {
+        return None;
+    };
+
+    let mut path = type_path.path.clone();
+    if let Some(segment) = path.segments.last_mut() {
+        segment.arguments = syn::PathArguments::None;
+    }
+    Some((path, &trait_object.bounds))
+}
+
+/// Returns `true` if the type is a built-in numeric, `bool` or `char` type.
+///
+/// Only the bare primitive names are recognized; these types can never be
+/// initialized from a string literal.
+///
+/// # Arguments
+///
+/// * `ty` - The field type to inspect
+///
+/// # Returns
+///
+/// `true` for types such as `u32`, `f64`, `bool` or `char`, `false` otherwise.
+pub fn is_scalar_primitive_type(ty: &syn::Type) -> bool {
+    const SCALARS: &[&str] = &[
+        "bool", "char", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64",
+        "u128", "usize", "f32", "f64",
+    ];
+
+    match ty {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => type_path
+            .path
+            .get_ident()
+            .is_some_and(|ident| SCALARS.iter().any(|scalar| ident == scalar)),
+        syn::Type::Paren(paren) => is_scalar_primitive_type(&paren.elem),
+        syn::Type::Group(group) => is_scalar_primitive_type(&group.elem),
+        _ => false,
+    }
+}
+
+/// Returns `true` for numeric types that an unsuffixed literal does not fall back to.
+///
+/// Behind an `impl Into<T>` parameter the compiler cannot pick the literal's
+/// type from `T`, so it falls back to `i32` or `f64`. For any other numeric
+/// type, such as `u16`, a call like `.port(8080)` then fails to compile.
+///
+/// # Arguments
+///
+/// * `ty` - The field type to inspect
+///
+/// # Returns
+///
+/// `true` for types such as `u16`, `usize` or `f32`, `false` otherwise.
+pub fn needs_typed_literal(ty: &syn::Type) -> bool {
+    const NUMERICS: &[&str] = &[
+        "i8", "i16", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize", "f32",
+    ];
+
+    match peel_type_groups(ty) {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => type_path
+            .path
+            .get_ident()
+            .is_some_and(|ident| NUMERICS.iter().any(|numeric| ident == numeric)),
+        _ => false,
+    }
+}
+
+/// Returns `true` if a type borrows with an elided or anonymous lifetime.
+///
+/// Such types are fine as setter parameters, but cannot be stored in a struct
+/// field, which has to name every lifetime it borrows for.
+///
+/// # Arguments
+///
+/// * `ty` - The type to inspect
+///
+/// # Returns
+///
+/// `true` for types such as `&str` or `Cow<'_, str>`, `false` for `&'a str` or `String`.
+pub fn has_elided_lifetime(ty: &syn::Type) -> bool {
+    let is_anonymous = |lifetime: &syn::Lifetime| lifetime.ident == "_";
+    match ty {
+        syn::Type::Reference(reference) => {
+            reference.lifetime.as_ref().map_or(true, is_anonymous)
+                || has_elided_lifetime(&reference.elem)
+        }
+        syn::Type::Path(type_path) => type_path.path.segments.iter().any(|segment| {
+            let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return false;
+            };
+            args.args.iter().any(|arg| match arg {
+                syn::GenericArgument::Lifetime(lifetime) => is_anonymous(lifetime),
+                syn::GenericArgument::Type(inner) => has_elided_lifetime(inner),
+                _ => false,
+            })
+        }),
+        syn::Type::Tuple(tuple) => tuple.elems.iter().any(has_elided_lifetime),
+        syn::Type::Array(array) => has_elided_lifetime(&array.elem),
+        syn::Type::Slice(slice) => has_elided_lifetime(&slice.elem),
+        syn::Type::Paren(paren) => has_elided_lifetime(&paren.elem),
+        syn::Type::Group(group) => has_elided_lifetime(&group.elem),
+        _ => false,
+    }
+}
+
+/// Extracts the fields a `resolve` closure reads, in parameter order.
+///
+/// The first closure parameter receives the field's own value; every further
+/// parameter must be a plain identifier (optionally typed) naming another field,
+/// e.g. `|path: PathBuf, base_dir: &PathBuf| base_dir.join(path)` reads `base_dir`.
+///
+/// # Arguments
+///
+/// * `expr` - The `resolve` expression to inspect
+///
+/// # Returns
+///
+/// `Some` with the identifiers of the fields read by the closure, or `None` if
+/// the expression is not a closure with at least one parameter or uses a
+/// pattern other than an identifier for a field parameter.
+pub fn extract_resolve_dependencies(expr: &syn::Expr) -> Option<Vec<syn::Ident>> {
+    let syn::Expr::Closure(closure) = expr else {
+        return None;
+    };
+    if closure.inputs.is_empty() {
+        return None;
+    }
+
+    closure
+        .inputs
+        .iter()
+        .skip(1)
+        .map(|param| {
+            let pat = match param {
+                syn::Pat::Type(pat_type) => &*pat_type.pat,
+                pat => pat,
+            };
+            match pat {
+                syn::Pat::Ident(pat_ident) if pat_ident.subpat.is_none() => {
+                    Some(pat_ident.ident.clone())
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
 /// Generates a const fn converter function name for a field.
 ///
 /// # Arguments
@@ -361,7 +993,10 @@ mod tests {
 
         let config = resolve_setter_parameter_config(&field_type, None, true);
 
-        assert_eq!(config.param_type.to_string(), "impl Into < String >");
+        assert_eq!(
+            config.param_type.to_string(),
+            "impl :: core :: convert :: Into < String >"
+        );
         assert_eq!(config.field_assignment_expr.to_string(), "value . into ()");
     }
 
@@ -384,7 +1019,7 @@ mod tests {
         let config = resolve_setter_parameter_config(&field_type, None, true);
         assert_eq!(
             config.param_type.to_string(),
-            "impl Into < HashMap < String , Vec < i32 > > >"
+            "impl :: core :: convert :: Into < HashMap < String , Vec < i32 > > >"
         );
 
         // Regular case
@@ -455,4 +1090,329 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_resolve_converter_into_parameter_config() {
+        let converter: syn::Expr = syn::parse_quote!(|name: String| name.trim().to_lowercase());
+        let config = resolve_converter_into_parameter_config(&converter).unwrap();
+
+        assert_eq!(
+            config.param_type.to_string(),
+            "impl :: core :: convert :: Into < String >"
+        );
+        assert_eq!(
+            config.field_assignment_expr.to_string(),
+            "(| name : String | name . trim () . to_lowercase ()) (value . into ())"
+        );
+
+        let untyped: syn::Expr = syn::parse_quote!(|name| name);
+        assert!(resolve_converter_into_parameter_config(&untyped).is_none());
+    }
+
+    #[test]
+    fn test_extract_option_inner_type() {
+        let inner =
+            |ty: syn::Type| extract_option_inner_type(&ty).map(|t| quote::quote!(#t).to_string());
+
+        assert_eq!(
+            inner(syn::parse_quote!(Option<u32>)),
+            Some("u32".to_string())
+        );
+        assert_eq!(
+            inner(syn::parse_quote!(::core::option::Option<String>)),
+            Some("String".to_string())
+        );
+        assert_eq!(
+            inner(syn::parse_quote!(std::option::Option<Vec<u8>>)),
+            Some("Vec < u8 >".to_string())
+        );
+        assert_eq!(inner(syn::parse_quote!(u32)), None);
+        assert_eq!(inner(syn::parse_quote!(Vec<u32>)), None);
+        assert_eq!(inner(syn::parse_quote!(my::Option<u32>)), None);
+        assert_eq!(inner(syn::parse_quote!(Option)), None);
+    }
+
+    #[test]
+    fn test_extract_accumulator_parameter_type() {
+        let input =
+            |expr: syn::Expr| extract_accumulator_parameter_type(&expr).map(|t| t.to_string());
+
+        assert_eq!(
+            input(syn::parse_quote!(
+                |tags, tag: &str| tags.push(tag.to_string())
+            )),
+            Some("& str".to_string())
+        );
+        assert_eq!(
+            input(syn::parse_quote!(|total: &mut u32, n: u32| *total += n)),
+            Some("u32".to_string())
+        );
+        assert_eq!(input(syn::parse_quote!(|tags, tag| tags.push(tag))), None);
+        assert_eq!(input(syn::parse_quote!(|tag: &str| tag.len())), None);
+        assert_eq!(input(syn::parse_quote!(push_tag)), None);
+    }
+
+    #[test]
+    fn test_type_first_closure_parameter() {
+        let typed = |expr: syn::Expr| {
+            let expr = type_first_closure_parameter(&expr, &syn::parse_quote!(&u16));
+            quote::quote!(#expr).to_string()
+        };
+
+        assert_eq!(
+            typed(syn::parse_quote!(|port| *port > 0)),
+            "| port : & u16 | * port > 0"
+        );
+        assert_eq!(
+            typed(syn::parse_quote!(|port: &u16| *port > 0)),
+            "| port : & u16 | * port > 0"
+        );
+        assert_eq!(typed(syn::parse_quote!(is_valid)), "is_valid");
+    }
+
+    #[test]
+    fn test_extract_vec_inner_type() {
+        let inner =
+            |ty: syn::Type| extract_vec_inner_type(&ty).map(|t| quote::quote!(#t).to_string());
+
+        assert_eq!(
+            inner(syn::parse_quote!(Vec<String>)),
+            Some("String".to_string())
+        );
+        assert_eq!(
+            inner(syn::parse_quote!(::alloc::vec::Vec<u8>)),
+            Some("u8".to_string())
+        );
+        assert_eq!(
+            inner(syn::parse_quote!(std::vec::Vec<Vec<u8>>)),
+            Some("Vec < u8 >".to_string())
+        );
+        assert_eq!(inner(syn::parse_quote!(Option<u32>)), None);
+        assert_eq!(inner(syn::parse_quote!(core::vec::Vec<u32>)), None);
+        assert_eq!(inner(syn::parse_quote!(VecDeque<u32>)), None);
+    }
+
+    #[test]
+    fn test_is_string_type() {
+        assert!(is_string_type(&syn::parse_quote!(String)));
+        assert!(is_string_type(&syn::parse_quote!(alloc::string::String)));
+        assert!(!is_string_type(&syn::parse_quote!(str)));
+        assert!(!is_string_type(&syn::parse_quote!(my::String)));
+    }
+
+    #[test]
+    fn test_resolve_clone_from_ref_parameter_config() {
+        let param = |ty: syn::Type| {
+            resolve_clone_from_ref_parameter_config(&ty)
+                .param_type
+                .to_string()
+        };
+        assert_eq!(param(syn::parse_quote!(String)), "& str");
+        assert_eq!(param(syn::parse_quote!(Vec<u8>)), "& [u8]");
+        assert_eq!(param(syn::parse_quote!(Config)), "& Config");
+
+        let config = resolve_clone_from_ref_parameter_config(&syn::parse_quote!(Config));
+        assert_eq!(
+            config.field_assignment_expr.to_string(),
+            "< Config as :: core :: clone :: Clone > :: clone (value)"
+        );
+    }
+
+    #[test]
+    fn test_resolve_into_iter_parameter_config() {
+        let field_type: syn::Type = syn::parse_quote!(Vec<String>);
+        let config = resolve_into_iter_parameter_config(&field_type).unwrap();
+
+        assert_eq!(
+            config.param_type.to_string(),
+            "impl :: core :: iter :: IntoIterator < Item = impl :: core :: convert :: Into < String >>"
+        );
+        assert!(resolve_into_iter_parameter_config(&syn::parse_quote!(String)).is_none());
+    }
+
+    #[test]
+    fn test_resolve_dyn_into_parameter_config() {
+        let config = |field_type: syn::Type| {
+            resolve_dyn_into_parameter_config(&field_type).map(|config| {
+                (
+                    config.param_type.to_string(),
+                    config.field_assignment_expr.to_string(),
+                )
+            })
+        };
+
+        let (param_type, assignment) = config(syn::parse_quote!(Box<dyn Handler>)).unwrap();
+        assert_eq!(param_type, "impl Handler + 'static");
+        assert_eq!(assignment, "Box :: new (value) as Box < dyn Handler >");
+
+        let (param_type, assignment) = config(syn::parse_quote!(
+            Option<std::sync::Arc<dyn Fn(u8) + Send + Sync>>
+        ))
+        .unwrap();
+        assert_eq!(param_type, "impl Fn (u8) + Send + Sync + 'static");
+        assert!(assignment
+            .starts_with(":: core :: option :: Option :: Some (std :: sync :: Arc :: new (value)"));
+
+        let (param_type, _) = config(syn::parse_quote!(Rc<dyn Render + 'a>)).unwrap();
+        assert_eq!(param_type, "impl Render + 'a");
+
+        for field_type in [
+            syn::parse_quote!(Box<Logger>),
+            syn::parse_quote!(Vec<Box<dyn Handler>>),
+            syn::parse_quote!(Cell<dyn Handler>),
+        ] {
+            let error = config(field_type).unwrap_err().to_string();
+            assert!(error.contains("#[builder(dyn_into)] needs"));
+        }
+    }
+
+    #[test]
+    fn test_wrap_converter() {
+        let code = |inner: syn::Type, constructor: Option<syn::Path>, field_type: syn::Type| {
+            let expr = wrap_converter(&inner, constructor.as_ref(), &field_type).unwrap();
+            quote::quote!(#expr).to_string()
+        };
+
+        assert_eq!(
+            code(syn::parse_quote!(u64), None, syn::parse_quote!(UserId)),
+            "| value : u64 | -> UserId { UserId (value) }"
+        );
+        assert!(code(
+            syn::parse_quote!(u8),
+            None,
+            syn::parse_quote!(ids::Tagged<u8>)
+        )
+        .contains("{ ids :: Tagged :: < u8 > (value) }"));
+        assert!(code(
+            syn::parse_quote!(u64),
+            None,
+            syn::parse_quote!(Option<UserId>)
+        )
+        .contains(":: core :: option :: Option :: Some (UserId (value))"));
+        assert!(code(
+            syn::parse_quote!(&str),
+            Some(syn::parse_quote!(Email::parse)),
+            syn::parse_quote!(Email)
+        )
+        .contains("{ Email :: parse (value) }"));
+
+        let error = wrap_converter(&syn::parse_quote!(u64), None, &syn::parse_quote!((u64,)))
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("#[builder(wrap)] needs a newtype"));
+    }
+
+    #[test]
+    fn test_converted_default() {
+        let code = |converter: syn::Expr, default: syn::Expr| {
+            let expr = converted_default(&converter, &default);
+            quote::quote!(#expr).to_string()
+        };
+
+        assert_eq!(
+            code(
+                syn::parse_quote!(|kib: u32| kib as usize * 1024),
+                syn::parse_quote!(8)
+            ),
+            "{ let kib : u32 = 8 ; kib as usize * 1024 }"
+        );
+        assert_eq!(
+            code(syn::parse_quote!(parse_size), syn::parse_quote!("8 KiB")),
+            "(parse_size) (\"8 KiB\")"
+        );
+    }
+
+    #[test]
+    fn test_nested_builder_alias() {
+        let alias = |ty: syn::Type| {
+            nested_builder_alias(&ty, "BuilderStart").map(|t| quote::quote!(#t).to_string())
+        };
+
+        assert_eq!(
+            alias(syn::parse_quote!(Address)),
+            Some("AddressBuilderStart".to_string())
+        );
+        assert_eq!(
+            alias(syn::parse_quote!(crate::geo::Point<'a, f64>)),
+            Some("crate :: geo :: PointBuilderStart < 'a , f64 >".to_string())
+        );
+        assert_eq!(alias(syn::parse_quote!(&'a Address)), None);
+        assert_eq!(alias(syn::parse_quote!((u8, u8))), None);
+    }
+
+    #[test]
+    fn test_type_groups_are_peeled() {
+        // `$ty:ty` fragments from `macro_rules!` arrive as invisible groups
+        let group = |elem: syn::Type| {
+            syn::Type::Group(syn::TypeGroup {
+                group_token: Default::default(),
+                elem: Box::new(elem),
+            })
+        };
+
+        let option = group(syn::parse_quote!(Option<String>));
+        assert!(extract_option_inner_type(&option).is_some());
+        let vec = group(group(syn::parse_quote!(Vec<u8>)));
+        assert!(extract_vec_inner_type(&vec).is_some());
+
+        let nested = group(syn::parse_quote!(geo::Address));
+        let alias = nested_builder_alias(&nested, "BuilderStart").unwrap();
+        assert_eq!(
+            quote::quote!(#alias).to_string(),
+            "geo :: AddressBuilderStart"
+        );
+    }
+
+    #[test]
+    fn test_extract_resolve_dependencies() {
+        let deps = |expr: syn::Expr| {
+            extract_resolve_dependencies(&expr)
+                .map(|idents| idents.iter().map(ToString::to_string).collect::<Vec<_>>())
+        };
+
+        assert_eq!(
+            deps(syn::parse_quote!(|path: String, base: &String, r#type| {
+                path
+            })),
+            Some(vec!["base".to_string(), "r#type".to_string()])
+        );
+        assert_eq!(deps(syn::parse_quote!(|value| value * 2)), Some(vec![]));
+        assert_eq!(deps(syn::parse_quote!(|| 1)), None);
+        assert_eq!(deps(syn::parse_quote!(|value, (a, b)| value)), None);
+        assert_eq!(deps(syn::parse_quote!(str::trim)), None);
+    }
+
+    #[test]
+    fn test_is_scalar_primitive_type() {
+        assert!(is_scalar_primitive_type(&syn::parse_quote!(u32)));
+        assert!(is_scalar_primitive_type(&syn::parse_quote!(f64)));
+        assert!(is_scalar_primitive_type(&syn::parse_quote!(bool)));
+        assert!(is_scalar_primitive_type(&syn::parse_quote!(char)));
+        assert!(!is_scalar_primitive_type(&syn::parse_quote!(String)));
+        assert!(!is_scalar_primitive_type(&syn::parse_quote!(&'static str)));
+        assert!(!is_scalar_primitive_type(&syn::parse_quote!(Option<u32>)));
+        assert!(!is_scalar_primitive_type(&syn::parse_quote!(my::u32)));
+    }
+
+    #[test]
+    fn test_needs_typed_literal() {
+        assert!(needs_typed_literal(&syn::parse_quote!(u16)));
+        assert!(needs_typed_literal(&syn::parse_quote!(usize)));
+        assert!(needs_typed_literal(&syn::parse_quote!(f32)));
+        assert!(!needs_typed_literal(&syn::parse_quote!(i32)));
+        assert!(!needs_typed_literal(&syn::parse_quote!(f64)));
+        assert!(!needs_typed_literal(&syn::parse_quote!(bool)));
+        assert!(!needs_typed_literal(&syn::parse_quote!(Option<u16>)));
+    }
+
+    #[test]
+    fn test_has_elided_lifetime() {
+        assert!(has_elided_lifetime(&syn::parse_quote!(&str)));
+        assert!(has_elided_lifetime(&syn::parse_quote!(&'_ [u8])));
+        assert!(has_elided_lifetime(&syn::parse_quote!(Cow<'_, str>)));
+        assert!(has_elided_lifetime(&syn::parse_quote!((u8, Option<&Path>))));
+        assert!(!has_elided_lifetime(&syn::parse_quote!(&'a str)));
+        assert!(!has_elided_lifetime(&syn::parse_quote!(Vec<String>)));
+        assert!(!has_elided_lifetime(&syn::parse_quote!(&'static [u8; 4])));
+    }
 }
@@ -27,181 +27,6 @@ use quote::quote;
 use std::collections::BTreeSet;
 use syn::{GenericParam, Generics, Type};
 
-/// Determines if a type contains generics or lifetimes that require `PhantomData` tracking.
-///
-/// This function performs proper analysis of the type structure to determine
-/// if it contains generic parameters or lifetimes that would require `PhantomData`
-/// tracking in the generated builder.
-///
-/// # Purpose
-///
-/// Used to determine whether a builder struct needs `PhantomData` fields to properly
-/// track generic parameters and lifetimes from the original struct.
-///
-/// # Arguments
-///
-/// * `ty` - The type to analyze for generic content
-///
-/// # Returns
-///
-/// `true` if the type contains generics or lifetimes, `false` otherwise.
-///
-/// # Implementation Details
-///
-/// The function recursively analyzes the type structure to detect:
-/// - Generic type parameters (`T`, `U`, etc.)
-/// - Lifetime parameters (`'a`, `'b`, etc.)
-/// - Const generic parameters
-/// - Complex types containing generics (`Vec<T>`, `Option<U>`, etc.)
-///
-/// This precise analysis ensures that `PhantomData` is only included when
-/// actually needed, reducing generated code size while maintaining correctness.
-pub fn has_generics_or_lifetimes(ty: &Type) -> bool {
-    match ty {
-        Type::Path(type_path) => {
-            // Check if this looks like a generic parameter (single uppercase identifier)
-            if type_path.path.segments.len() == 1 {
-                let segment = &type_path.path.segments[0];
-                if segment.arguments.is_empty() {
-                    let ident_str = segment.ident.to_string();
-                    // Heuristic: single uppercase letter or CamelCase starting with uppercase
-                    // is likely a generic parameter
-                    if is_likely_generic_name(&ident_str) {
-                        return true;
-                    }
-                }
-            }
-
-            // Check for generic arguments in any path segment
-            for segment in &type_path.path.segments {
-                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
-                    // If there are angle brackets, there are generics
-                    for arg in &args.args {
-                        match arg {
-                            syn::GenericArgument::Type(inner_ty) => {
-                                if has_generics_or_lifetimes(inner_ty) {
-                                    return true;
-                                }
-                            }
-                            syn::GenericArgument::Lifetime(_) => {
-                                // Found a lifetime parameter
-                                return true;
-                            }
-                            syn::GenericArgument::Const(_) => {
-                                // Found a const generic parameter
-                                return true;
-                            }
-                            _ => {}
-                        }
-                    }
-                }
-            }
-        }
-        Type::Reference(type_ref) => {
-            // References may have lifetimes
-            if type_ref.lifetime.is_some() {
-                return true;
-            }
-            // Check the referenced type
-            return has_generics_or_lifetimes(&type_ref.elem);
-        }
-        Type::Tuple(type_tuple) => {
-            // Check all tuple elements
-            for elem in &type_tuple.elems {
-                if has_generics_or_lifetimes(elem) {
-                    return true;
-                }
-            }
-        }
-        Type::Array(type_array) => {
-            // Check the array element type and length (if it's a const generic)
-            return has_generics_or_lifetimes(&type_array.elem);
-        }
-        Type::Slice(type_slice) => {
-            // Check the slice element type
-            return has_generics_or_lifetimes(&type_slice.elem);
-        }
-        Type::Ptr(type_ptr) => {
-            // Check the pointed-to type
-            return has_generics_or_lifetimes(&type_ptr.elem);
-        }
-        Type::BareFn(bare_fn) => {
-            // Function pointers may have lifetimes in their signature
-            if bare_fn.lifetimes.is_some() {
-                return true;
-            }
-            // Check input and output types
-            for input in &bare_fn.inputs {
-                if has_generics_or_lifetimes(&input.ty) {
-                    return true;
-                }
-            }
-            if let syn::ReturnType::Type(_, output_ty) = &bare_fn.output {
-                if has_generics_or_lifetimes(output_ty) {
-                    return true;
-                }
-            }
-        }
-        Type::TraitObject(trait_object) => {
-            // Trait objects may have lifetime bounds
-            for bound in &trait_object.bounds {
-                if let syn::TypeParamBound::Lifetime(_) = bound {
-                    return true;
-                }
-            }
-        }
-        _ => {
-            // Other types (Infer, Never, etc.) typically don't contain generics
-        }
-    }
-
-    false
-}
-
-/// Heuristic to determine if an identifier name is likely a generic parameter.
-///
-/// This function uses naming conventions to guess whether an identifier
-/// represents a generic type parameter.
-///
-/// # Arguments
-///
-/// * `name` - The identifier name to analyze
-///
-/// # Returns
-///
-/// `true` if the name is likely a generic parameter, `false` otherwise.
-///
-/// # Heuristics
-///
-/// The function considers these patterns as likely generic parameters:
-/// - Single uppercase letters: T, U, V, etc.
-/// - Short CamelCase names: Item, Key, Value, etc.
-/// - Common generic parameter names: Self, etc.
-///
-fn is_likely_generic_name(name: &str) -> bool {
-    // Single uppercase letter (T, U, K, V, etc.)
-    // Single uppercase letter (T, U, K, V, etc.) - safe since we checked length
-    if name.len() == 1 && name.chars().next().is_some_and(|c| c.is_uppercase()) {
-        return true;
-    }
-
-    // Short CamelCase names that are likely generic parameters
-    if name.len() <= 8 && name.starts_with(char::is_uppercase) {
-        // Check if it's not a common concrete type
-        match name {
-            // Common concrete types that start with uppercase
-            "String" | "Vec" | "HashMap" | "HashSet" | "BTreeMap" | "BTreeSet" | "Option"
-            | "Result" | "Box" | "Arc" | "Rc" | "Cell" | "RefCell" | "Mutex" | "RwLock"
-            | "AtomicBool" | "AtomicI32" | "AtomicU32" | "PathBuf" | "OsString" | "CString"
-            | "Duration" | "Instant" => false,
-            // Everything else that's short and CamelCase is likely generic
-            _ => true,
-        }
-    } else {
-        false
-    }
-}
-
 /// Extracts all declared generic parameter names from struct generics.
 ///
 /// This function collects the names of type and const generic parameters
@@ -394,6 +219,11 @@ pub fn transform_type_for_phantom_data(
                 quote! { () }
             }
         }
+        Type::Group(type_group) => {
+            // Types passed through `macro_rules!` as `$ty:ty` fragments are
+            // wrapped in an invisible group
+            transform_type_for_phantom_data(&type_group.elem, declared_generics)
+        }
         Type::Tuple(_) => {
             // Tuple types like (T, U) or (String, i32)
             // For simplicity, replace with () - individual generic parameters
@@ -513,7 +343,9 @@ pub fn generate_phantom_data_type<'a>(
     let declared_generics = collect_declared_generic_names(struct_generics);
 
     // Transform all field types for PhantomData inclusion
+    let mut used = BTreeSet::new();
     for field_type in field_types {
+        collect_generic_usage(quote! { #field_type }, &mut used);
         let transformed = transform_type_for_phantom_data(field_type, &declared_generics);
         phantom_types.push(transformed);
     }
@@ -527,9 +359,14 @@ pub fn generate_phantom_data_type<'a>(
                 let ident = &type_param.ident;
                 phantom_types.push(quote! { #ident });
             }
-            GenericParam::Lifetime(_) => {
+            GenericParam::Lifetime(lifetime_param) => {
                 // Lifetimes are handled within the field type transformations
-                // through reference types like &'a ()
+                // through reference types like &'a (), except for those no
+                // field refers to, as in the builder of an enum variant
+                let lifetime = &lifetime_param.lifetime;
+                if !used.contains(&lifetime.to_string()) {
+                    phantom_types.push(quote! { &#lifetime () });
+                }
             }
             GenericParam::Const(_) => {
                 // Const generics are handled within the field type transformations
@@ -550,102 +387,392 @@ pub fn generate_phantom_data_type<'a>(
 
 /// Determines if PhantomData is needed for a given set of generics and fields.
 ///
-/// This function provides a more precise check than `has_generics_or_lifetimes`
-/// by analyzing whether the struct actually needs PhantomData based on its
-/// generic parameters and field usage.
+/// Every builder state stores every field of the struct, so the builder only
+/// needs PhantomData when a declared type parameter or lifetime is not
+/// referenced by any field type. Const parameters never need it.
 ///
 /// # Purpose
 ///
 /// Used to determine whether to include a PhantomData field in generated
-/// builder structs. PhantomData is only needed when:
-/// - The struct has generic parameters
-/// - The fields use types that contain generics or lifetimes
+/// builder structs. Omitting it where possible keeps `Debug` output clean and
+/// lets the builder's auto traits and variance follow the fields exactly.
 ///
 /// # Arguments
 ///
 /// * `struct_generics` - The generic parameter list from the struct
-/// * `field_types` - Iterator over all field types in the struct
+/// * `field_types` - Iterator over all field types stored in the builder
 ///
 /// # Returns
 ///
 /// `true` if PhantomData is needed, `false` otherwise.
-/// # Implementation Details
 ///
-/// The function checks:
-/// 1. Whether the struct declares any generic parameters
-/// 2. Whether any field types potentially contain generics or lifetimes
+/// # Implementation Details
 ///
-/// This is more efficient than always including PhantomData, though the
-/// current implementation of `has_generics_or_lifetimes` is conservative
-/// and always returns true for safety.
+/// The field types are scanned token by token for parameter names and
+/// lifetimes. Identifiers following `::` are ignored since they name
+/// associated items (`<U as Trait>::T`), not parameters. A macro invocation
+/// in type position can hide its expansion, so any macro makes the check
+/// fall back to emitting PhantomData.
 pub fn needs_phantom_data<'a>(
     struct_generics: &Generics,
     field_types: impl Iterator<Item = &'a Type>,
 ) -> bool {
-    // If struct has generic parameters, we likely need PhantomData
-    if !struct_generics.params.is_empty() {
-        return true;
+    if struct_generics.params.is_empty() {
+        return false;
     }
 
-    // Check if any field types contain generics or lifetimes
-    // This uses the conservative approach of the current implementation
+    let mut used = BTreeSet::new();
     for field_type in field_types {
-        if has_generics_or_lifetimes(field_type) {
+        if !collect_generic_usage(quote! { #field_type }, &mut used) {
             return true;
         }
     }
 
-    false
+    struct_generics.params.iter().any(|param| match param {
+        GenericParam::Type(type_param) => !used.contains(&type_param.ident.to_string()),
+        GenericParam::Lifetime(lifetime_param) => {
+            !used.contains(&lifetime_param.lifetime.to_string())
+        }
+        GenericParam::Const(_) => false,
+    })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use syn::parse_quote;
+/// The number of movable trait bounds from which a type parameter gets a helper
+/// trait. With fewer, the helper trait is longer than the bounds it replaces.
+pub const MIN_HELPER_BOUNDS: usize = 3;
+
+/// Trait bounds of one type parameter that are stated once in a helper trait.
+///
+/// The helper trait has the bounds as supertraits and a blanket implementation
+/// for every type satisfying them, so `T: Helper` implies all of them. Builder
+/// impl blocks then carry the one helper bound instead of the full list.
+#[derive(Debug, Clone)]
+pub struct BoundHelper {
+    /// Name of the generated helper trait
+    pub trait_ident: syn::Ident,
+    /// The bounds the helper trait stands for
+    pub bounds: Vec<syn::TypeParamBound>,
+}
 
-    #[test]
-    fn test_has_generics_or_lifetimes() {
-        let generic_ty: Type = parse_quote!(Vec<T>);
-        assert!(has_generics_or_lifetimes(&generic_ty));
+/// Moves the trait bounds of bound-heavy type parameters into helper traits.
+///
+/// A type parameter whose inline and `where` clause bounds include at least
+/// [`MIN_HELPER_BOUNDS`] trait bounds that mention no generic parameter gets a helper
+/// trait named `__{Struct}{Param}Bounds`. Those bounds are replaced by the
+/// helper in the returned generics; lifetime bounds, `?Sized` and bounds
+/// mentioning generic parameters stay where they are.
+///
+/// # Arguments
+///
+/// * `struct_name` - The struct the helper traits are named after
+/// * `generics` - The generic parameters of the struct
+///
+/// # Returns
+///
+/// The generics to use for builder impl blocks and the helper traits they refer to.
+pub fn consolidate_bounds(
+    struct_name: &syn::Ident,
+    generics: &Generics,
+) -> (Generics, Vec<BoundHelper>) {
+    let mut declared = collect_declared_generic_names(generics);
+    declared.extend(generics.lifetimes().map(|param| param.lifetime.to_string()));
+    // Bounds mentioning generic parameters would need them on the helper trait
+    let is_movable = |bound: &syn::TypeParamBound| {
+        let syn::TypeParamBound::Trait(trait_bound) = bound else {
+            return false;
+        };
+        let mut used = BTreeSet::new();
+        matches!(trait_bound.modifier, syn::TraitBoundModifier::None)
+            && collect_generic_usage(quote! { #trait_bound }, &mut used)
+            && used.is_disjoint(&declared)
+    };
+
+    let mut consolidated = generics.clone();
+    let mut helpers = Vec::new();
+    let type_params: Vec<syn::Ident> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    for param_ident in type_params {
+        let bounds_param = |predicate: &syn::PredicateType| {
+            predicate.lifetimes.is_none()
+                && matches!(&predicate.bounded_ty, Type::Path(path)
+                    if path.qself.is_none() && path.path.is_ident(&param_ident))
+        };
+        let inline = consolidated
+            .type_params()
+            .find(|param| param.ident == param_ident)
+            .into_iter()
+            .flat_map(|param| param.bounds.iter());
+        let from_where = consolidated
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| where_clause.predicates.iter())
+            .filter_map(|predicate| match predicate {
+                syn::WherePredicate::Type(predicate) if bounds_param(predicate) => {
+                    Some(predicate.bounds.iter())
+                }
+                _ => None,
+            })
+            .flatten();
+        let bounds: Vec<syn::TypeParamBound> = inline
+            .chain(from_where)
+            .filter(|bound| is_movable(bound))
+            .cloned()
+            .collect();
+        if bounds.len() < MIN_HELPER_BOUNDS {
+            continue;
+        }
 
-        let concrete_ty: Type = parse_quote!(String);
-        assert!(!has_generics_or_lifetimes(&concrete_ty)); // Now properly analyzed
+        let trait_ident = quote::format_ident!(
+            "__{}{}Bounds",
+            struct_name,
+            crate::utils::identifiers::strip_raw_identifier_prefix(&param_ident.to_string())
+        );
+        let retain_unmoved =
+            |bounds: &mut syn::punctuated::Punctuated<syn::TypeParamBound, syn::Token![+]>| {
+                *bounds = std::mem::take(bounds)
+                    .into_iter()
+                    .filter(|bound| !is_movable(bound))
+                    .collect();
+            };
+        if let Some(where_clause) = consolidated.where_clause.as_mut() {
+            for predicate in where_clause.predicates.iter_mut() {
+                if let syn::WherePredicate::Type(predicate) = predicate {
+                    if bounds_param(predicate) {
+                        retain_unmoved(&mut predicate.bounds);
+                    }
+                }
+            }
+            where_clause.predicates = std::mem::take(&mut where_clause.predicates)
+                .into_iter()
+                .filter(|predicate| {
+                    !matches!(predicate, syn::WherePredicate::Type(predicate)
+                        if predicate.bounds.is_empty())
+                })
+                .collect();
+            if where_clause.predicates.is_empty() {
+                consolidated.where_clause = None;
+            }
+        }
+        if let Some(param) = consolidated
+            .type_params_mut()
+            .find(|param| param.ident == param_ident)
+        {
+            retain_unmoved(&mut param.bounds);
+            param.colon_token = Some(Default::default());
+            param.bounds.push(syn::parse_quote! { #trait_ident });
+        }
+        helpers.push(BoundHelper {
+            trait_ident,
+            bounds,
+        });
+    }
 
-        let reference_ty: Type = parse_quote!(&'a str);
-        assert!(has_generics_or_lifetimes(&reference_ty));
+    (consolidated, helpers)
+}
 
-        let simple_generic: Type = parse_quote!(T);
-        assert!(has_generics_or_lifetimes(&simple_generic));
+/// Collects the types whose `Default` implementation an expression calls.
+///
+/// This recognizes calls such as `T::default()`, `<T as Default>::default()`
+/// and `Vec::<T>::default()` anywhere in the expression, and a plain
+/// `Default::default()` making up the whole expression, whose type is the
+/// field type. Other trait calls, e.g. `T::new()`, are not recognized.
+///
+/// # Arguments
+///
+/// * `expr` - The default expression to scan
+/// * `field_type` - The type of the field the expression initializes
+///
+/// # Returns
+///
+/// The types in the order their calls appear, possibly with duplicates.
+pub fn default_call_types(expr: &syn::Expr, field_type: &Type) -> Vec<Type> {
+    let mut types = Vec::new();
+    let mut whole = expr;
+    while let syn::Expr::Paren(syn::ExprParen { expr, .. })
+    | syn::Expr::Group(syn::ExprGroup { expr, .. }) = whole
+    {
+        whole = expr;
+    }
+    if let syn::Expr::Call(call) = whole {
+        if let syn::Expr::Path(path) = call.func.as_ref() {
+            let mut idents = path
+                .path
+                .segments
+                .iter()
+                .rev()
+                .map(|segment| &segment.ident);
+            let is_trait_call = path.qself.is_none()
+                && call.args.is_empty()
+                && idents.next().is_some_and(|ident| ident == "default")
+                && idents.next().is_some_and(|ident| ident == "Default");
+            if is_trait_call {
+                types.push(field_type.clone());
+            }
+        }
+    }
+    collect_default_calls(expr, &mut types);
+    types
+}
+
+/// Collects the types of the `Default::default` calls in an expression and its
+/// subexpressions.
+fn collect_default_calls(expr: &syn::Expr, types: &mut Vec<Type>) {
+    let subexpressions: Vec<&syn::Expr> = match expr {
+        syn::Expr::Call(call) => {
+            types.extend(default_call_type(call));
+            std::iter::once(call.func.as_ref())
+                .chain(&call.args)
+                .collect()
+        }
+        syn::Expr::MethodCall(call) => std::iter::once(call.receiver.as_ref())
+            .chain(&call.args)
+            .collect(),
+        syn::Expr::Array(array) => array.elems.iter().collect(),
+        syn::Expr::Tuple(tuple) => tuple.elems.iter().collect(),
+        syn::Expr::Binary(binary) => vec![&binary.left, &binary.right],
+        syn::Expr::Unary(unary) => vec![&unary.expr],
+        syn::Expr::Cast(cast) => vec![&cast.expr],
+        syn::Expr::Field(field) => vec![&field.base],
+        syn::Expr::Group(group) => vec![&group.expr],
+        syn::Expr::Paren(paren) => vec![&paren.expr],
+        syn::Expr::Reference(reference) => vec![&reference.expr],
+        syn::Expr::Index(index) => vec![&index.expr, &index.index],
+        syn::Expr::Repeat(repeat) => vec![&repeat.expr],
+        syn::Expr::Struct(expr_struct) => expr_struct
+            .fields
+            .iter()
+            .map(|field| &field.expr)
+            .chain(expr_struct.rest.as_deref())
+            .collect(),
+        syn::Expr::If(expr_if) => {
+            collect_block_default_calls(&expr_if.then_branch, types);
+            std::iter::once(expr_if.cond.as_ref())
+                .chain(
+                    expr_if
+                        .else_branch
+                        .as_ref()
+                        .map(|(_, branch)| branch.as_ref()),
+                )
+                .collect()
+        }
+        syn::Expr::Match(expr_match) => std::iter::once(expr_match.expr.as_ref())
+            .chain(expr_match.arms.iter().map(|arm| arm.body.as_ref()))
+            .collect(),
+        syn::Expr::Block(block) => {
+            collect_block_default_calls(&block.block, types);
+            Vec::new()
+        }
+        syn::Expr::Unsafe(block) => {
+            collect_block_default_calls(&block.block, types);
+            Vec::new()
+        }
+        _ => Vec::new(),
+    };
+    for subexpression in subexpressions {
+        collect_default_calls(subexpression, types);
+    }
+}
 
-        let complex_generic: Type = parse_quote!(HashMap<K, V>);
-        assert!(has_generics_or_lifetimes(&complex_generic));
+/// Collects the types of the `Default::default` calls in a block's statements.
+fn collect_block_default_calls(block: &syn::Block, types: &mut Vec<Type>) {
+    for stmt in &block.stmts {
+        match stmt {
+            syn::Stmt::Local(local) => {
+                if let Some(init) = &local.init {
+                    collect_default_calls(&init.expr, types);
+                }
+            }
+            syn::Stmt::Expr(expr, _) => collect_default_calls(expr, types),
+            _ => {}
+        }
+    }
+}
 
-        let no_generics: Type = parse_quote!(i32);
-        assert!(!has_generics_or_lifetimes(&no_generics));
+/// Returns the type a call such as `T::default()` or `<T as Default>::default()`
+/// calls `Default::default` on.
+fn default_call_type(call: &syn::ExprCall) -> Option<Type> {
+    let syn::Expr::Path(path) = call.func.as_ref() else {
+        return None;
+    };
+    let segments = &path.path.segments;
+    if !call.args.is_empty() || segments.last()?.ident != "default" {
+        return None;
     }
 
-    #[test]
-    fn test_is_likely_generic_name() {
-        // Single uppercase letters are likely generic
-        assert!(is_likely_generic_name("T"));
-        assert!(is_likely_generic_name("U"));
-        assert!(is_likely_generic_name("K"));
-
-        // Common concrete types are not generic
-        assert!(!is_likely_generic_name("String"));
-        assert!(!is_likely_generic_name("Vec"));
-        assert!(!is_likely_generic_name("HashMap"));
-
-        // Short CamelCase names are likely generic
-        assert!(is_likely_generic_name("Item"));
-        assert!(is_likely_generic_name("Key"));
-        assert!(is_likely_generic_name("Value"));
-
-        // Lowercase names are not generic
-        assert!(!is_likely_generic_name("field"));
-        assert!(!is_likely_generic_name("value"));
+    // `<T>::default()` and `<T as Default>::default()`
+    if let Some(qself) = &path.qself {
+        return (segments.len() == qself.position + 1).then(|| (*qself.ty).clone());
     }
 
+    // `T::default()`, but not the trait's own `Default::default()`
+    let owner = segments.iter().nth_back(1)?;
+    if owner.ident == "Default" {
+        return None;
+    }
+    let type_path = syn::Path {
+        leading_colon: path.path.leading_colon,
+        segments: segments.iter().take(segments.len() - 1).cloned().collect(),
+    };
+    Some(Type::Path(syn::TypePath {
+        qself: None,
+        path: type_path,
+    }))
+}
+
+/// Collects the identifiers and lifetimes a type refers to.
+///
+/// Lifetimes are recorded with their leading apostrophe (`'a`) so they cannot
+/// collide with type parameter names.
+///
+/// # Arguments
+///
+/// * `tokens` - The tokens of the type to scan
+/// * `used` - Set receiving the referenced names
+///
+/// # Returns
+///
+/// `false` if the type contains a macro invocation whose usage cannot be
+/// determined, `true` otherwise.
+fn collect_generic_usage(tokens: proc_macro2::TokenStream, used: &mut BTreeSet<String>) -> bool {
+    use proc_macro2::{Spacing, TokenTree};
+
+    let mut tokens = tokens.into_iter().peekable();
+    let mut previous: Option<TokenTree> = None;
+    let mut after_path_separator = false;
+
+    while let Some(token) = tokens.next() {
+        match &token {
+            TokenTree::Group(group) if !collect_generic_usage(group.stream(), used) => {
+                return false
+            }
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                if let Some(TokenTree::Ident(ident)) = tokens.next() {
+                    used.insert(format!("'{ident}"));
+                }
+            }
+            TokenTree::Punct(punct) if punct.as_char() == '!' => return false,
+            TokenTree::Ident(ident) if !after_path_separator => {
+                used.insert(ident.to_string());
+            }
+            _ => {}
+        }
+
+        after_path_separator = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == ':')
+            && matches!(&previous, Some(TokenTree::Punct(punct))
+                if punct.as_char() == ':' && punct.spacing() == Spacing::Joint);
+        previous = Some(token);
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
     #[test]
     fn test_collect_declared_generic_names() {
         // Simple type parameters
@@ -712,9 +839,31 @@ mod tests {
 
     #[test]
     fn test_needs_phantom_data() {
-        // With generics - should need PhantomData
+        // Generics used directly by the fields - no PhantomData needed
+        let generics: Generics = parse_quote!(<'a, T, const N: usize>);
+        let field_types = [parse_quote!(&'a T), parse_quote!([u8; N])];
+        assert!(!needs_phantom_data(&generics, field_types.iter()));
+
+        // Const expressions over const generics, as with `generic_const_exprs`
+        let generics: Generics = parse_quote!(<T, const N: usize>);
+        let field_types = [parse_quote!([T; N * 2]), parse_quote!(Buffer<{ N + 1 }>)];
+        assert!(!needs_phantom_data(&generics, field_types.iter()));
+
+        // Unused type parameter or lifetime - should need PhantomData
+        let generics: Generics = parse_quote!(<'a, T>);
+        let field_types = [parse_quote!(&'a str)];
+        assert!(needs_phantom_data(&generics, field_types.iter()));
+        let field_types = [parse_quote!(Vec<T>)];
+        assert!(needs_phantom_data(&generics, field_types.iter()));
+
+        // Associated items named like a parameter don't count as usage
+        let generics: Generics = parse_quote!(<T, U>);
+        let field_types = [parse_quote!(<U as Iterator>::T)];
+        assert!(needs_phantom_data(&generics, field_types.iter()));
+
+        // Macros in type position are handled conservatively
         let generics: Generics = parse_quote!(<T>);
-        let field_types = [parse_quote!(T)];
+        let field_types = [parse_quote!(Vec<my_type!(T)>)];
         assert!(needs_phantom_data(&generics, field_types.iter()));
 
         // Without generics and concrete fields - should not need PhantomData
@@ -727,4 +876,75 @@ mod tests {
         let no_fields: Vec<Type> = vec![];
         assert!(!needs_phantom_data(&no_generics, no_fields.iter()));
     }
+
+    #[test]
+    fn test_consolidate_bounds() {
+        let struct_name: syn::Ident = parse_quote!(Cache);
+        let generics: Generics = parse_quote! {
+            <'a, K: Clone + ::core::hash::Hash + 'a, V: ?Sized + Send + Unpin, S: Into<K> + Default + Copy>
+        };
+        let generics = Generics {
+            where_clause: Some(
+                parse_quote! { where K: Eq, V: Sync, for<'b> K: PartialEq<&'b str> },
+            ),
+            ..generics
+        };
+        let (consolidated, helpers) = consolidate_bounds(&struct_name, &generics);
+
+        let (impl_generics, _, where_clause) = consolidated.split_for_impl();
+        assert_eq!(
+            quote! { #impl_generics #where_clause }.to_string(),
+            quote! {
+                <'a, K: 'a + __CacheKBounds, V: ?Sized + __CacheVBounds, S: Into<K> + Default + Copy>
+                where for<'b> K: PartialEq<&'b str>
+            }
+            .to_string()
+        );
+        // `S` keeps `Into<K>` inline, leaving too few bounds for a helper
+        let names: Vec<String> = helpers.iter().map(|h| h.trait_ident.to_string()).collect();
+        assert_eq!(names, ["__CacheKBounds", "__CacheVBounds"]);
+        let bounds = &helpers[0].bounds;
+        assert_eq!(
+            quote! { #(#bounds)+* }.to_string(),
+            quote! { Clone + ::core::hash::Hash + Eq }.to_string()
+        );
+
+        // Parameters with few bounds are left alone
+        let generics: Generics = parse_quote!(<T: Clone + Send>);
+        let (consolidated, helpers) = consolidate_bounds(&struct_name, &generics);
+        assert!(helpers.is_empty());
+        assert_eq!(consolidated, generics);
+    }
+
+    #[test]
+    fn test_default_call_types() {
+        let field_type: Type = parse_quote! { Option<T> };
+        let types = |expr: syn::Expr| -> Vec<String> {
+            default_call_types(&expr, &field_type)
+                .iter()
+                .map(|ty| quote! { #ty }.to_string())
+                .collect()
+        };
+
+        assert_eq!(types(parse_quote! { T::default() }), ["T"]);
+        assert_eq!(types(parse_quote! { <T as Default>::default() }), ["T"]);
+        assert_eq!(types(parse_quote! { <U>::default() }), ["U"]);
+        assert_eq!(
+            types(parse_quote! { Vec::<T>::default() }),
+            ["Vec :: < T >"]
+        );
+        assert_eq!(
+            types(parse_quote! { (Default::default()) }),
+            ["Option < T >"]
+        );
+        assert_eq!(
+            types(parse_quote! { { let value = T::default(); Some((value, U::default())) } }),
+            ["T", "U"]
+        );
+
+        // Calls that need no `Default` bound, or whose type is unknown
+        assert!(types(parse_quote! { Vec::<T>::new() }).is_empty());
+        assert!(types(parse_quote! { Some(Default::default()) }).is_empty());
+        assert!(types(parse_quote! { T::default_with(1) }).is_empty());
+    }
 }
@@ -93,6 +93,50 @@ pub fn snake_case_to_pascal_case(snake_case_str: &str) -> String {
         .join("")
 }
 
+/// Converts PascalCase to snake_case for method names.
+///
+/// This function transforms enum variant names like `DataFrame` into method
+/// names like `data_frame`. Runs of capitals are kept together, so acronyms
+/// stay one word.
+///
+/// # Examples
+///
+/// - `"Ping"` → `"ping"`
+/// - `"DataFrame"` → `"data_frame"`
+/// - `"HTTPRequest"` → `"http_request"`
+/// - `"Ipv4"` → `"ipv4"`
+///
+/// # Arguments
+///
+/// * `pascal_case_str` - A string in PascalCase format
+///
+/// # Returns
+///
+/// A String in snake_case format suitable for method names.
+pub fn pascal_case_to_snake_case(pascal_case_str: &str) -> String {
+    let chars: Vec<char> = pascal_case_str.chars().collect();
+    let mut snake_case = String::new();
+
+    for (index, &current) in chars.iter().enumerate() {
+        if current.is_uppercase() && index > 0 {
+            let previous = chars[index - 1];
+            let next_is_lower = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            // A new word starts after a lowercase letter or digit, or at the
+            // last capital of an acronym followed by a lowercase letter
+            if previous != '_'
+                && (previous.is_lowercase()
+                    || previous.is_ascii_digit()
+                    || (previous.is_uppercase() && next_is_lower))
+            {
+                snake_case.push('_');
+            }
+        }
+        snake_case.extend(current.to_lowercase());
+    }
+
+    snake_case
+}
+
 /// Generates a unique identifier by appending a deterministic hash suffix.
 ///
 /// This function creates unique identifiers to avoid naming conflicts in generated
@@ -196,6 +240,17 @@ mod tests {
         assert_eq!(capitalize_first_letter("_field"), "_field"); // Underscore doesn't change
     }
 
+    #[test]
+    fn test_pascal_case_to_snake_case() {
+        assert_eq!(pascal_case_to_snake_case("Ping"), "ping");
+        assert_eq!(pascal_case_to_snake_case("DataFrame"), "data_frame");
+        assert_eq!(pascal_case_to_snake_case("HTTPRequest"), "http_request");
+        assert_eq!(pascal_case_to_snake_case("Ipv4"), "ipv4");
+        assert_eq!(pascal_case_to_snake_case("Ipv4Addr"), "ipv4_addr");
+        assert_eq!(pascal_case_to_snake_case("A"), "a");
+        assert_eq!(pascal_case_to_snake_case(""), "");
+    }
+
     #[test]
     fn test_snake_case_to_pascal_case_examples() {
         // Examples from the function documentation
@@ -7,6 +7,7 @@
 //! - [`identifiers`]: Identifier processing and manipulation utilities
 //! - [`generics`]: Generic type analysis and transformation utilities  
 //! - [`field_utils`]: Field processing and utility functions
+//! - [`std_converters`]: Built-in converters expanded into closures
 //!
 //! # Design Principles
 //!
@@ -20,5 +21,6 @@ pub mod extensions;
 pub mod field_utils;
 pub mod generics;
 pub mod identifiers;
+pub mod std_converters;
 
 // Re-export commonly used utilities for convenience
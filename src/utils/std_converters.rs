@@ -0,0 +1,177 @@
+//! Built-in Converters
+//!
+//! This module expands the ready-made converters addressed as
+//! `#[builder(converter = std_converters::name)]` into the closures users
+//! would otherwise write by hand. The macro crate cannot export functions, so
+//! each converter is a closure template completed with the field's type and
+//! name; everything after attribute parsing sees an ordinary closure.
+//!
+//! The converters are only available with the `std-converters` feature:
+//!
+//! - `trim_lowercase` - `&str`, trimmed and lowercased, into the field type
+//! - `parse` - `&str` parsed with `FromStr`, panicking on invalid input;
+//!   `Option<T>` fields parse a `T`
+//! - `seconds` - `u64` seconds into a `Duration`
+//! - `csv_list` - comma-separated `&str` into a collection, each item trimmed
+//!   and parsed with `FromStr`; empty items are skipped
+
+use crate::utils::field_utils::extract_option_inner_type;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{Expr, Ident, Type};
+
+/// The path segment that addresses the built-in converters.
+const NAMESPACE: &str = "std_converters";
+
+/// The names of the built-in converters.
+pub const STD_CONVERTERS: &[&str] = &["trim_lowercase", "parse", "seconds", "csv_list"];
+
+/// Returns the converter name if the expression is a `std_converters::name` path.
+///
+/// # Arguments
+///
+/// * `expr` - The converter expression from `#[builder(converter = ...)]`
+///
+/// # Returns
+///
+/// The last path segment when the path has exactly two segments, the first of
+/// which is `std_converters`; `None` for closures and other paths.
+pub fn std_converter_name(expr: &Expr) -> Option<&Ident> {
+    let Expr::Path(path) = expr else {
+        return None;
+    };
+    if path.qself.is_some() || path.path.leading_colon.is_some() {
+        return None;
+    }
+    let segments = &path.path.segments;
+    if segments.len() != 2 || segments[0].ident != NAMESPACE {
+        return None;
+    }
+    Some(&segments[1].ident)
+}
+
+/// Checks that a built-in converter with the given name exists.
+///
+/// # Errors
+///
+/// Returns an error listing the available converters for an unknown name.
+pub fn check_std_converter(name: &Ident) -> syn::Result<()> {
+    if STD_CONVERTERS.contains(&name.to_string().as_str()) {
+        return Ok(());
+    }
+    Err(syn::Error::new(
+        name.span(),
+        format!(
+            "Unknown built-in converter `{name}`. Available converters: {}",
+            STD_CONVERTERS.join(", ")
+        ),
+    ))
+}
+
+/// Expands a built-in converter into a closure for the given field.
+///
+/// The closure's return type is the field type, so a field type the converter
+/// cannot produce is reported at the closure rather than inside the setter.
+///
+/// # Arguments
+///
+/// * `name` - The converter name, as returned by [`std_converter_name`]
+/// * `field_name` - The field name, used in panic messages
+/// * `field_type` - The field type the converter produces
+///
+/// # Returns
+///
+/// The closure expression, or an error for an unknown converter name.
+pub fn expand_std_converter(
+    name: &Ident,
+    field_name: &str,
+    field_type: &Type,
+) -> syn::Result<Expr> {
+    check_std_converter(name)?;
+    let invalid = format!("invalid value {{:?}} for `{field_name}`");
+    let closure = match name.to_string().as_str() {
+        "trim_lowercase" => quote! {
+            |value: &str| -> #field_type {
+                ::core::convert::Into::into(value.trim().to_lowercase())
+            }
+        },
+        "parse" => {
+            // `Option<T>` has no `FromStr`; parse the `T` and wrap it instead
+            let parsed = match extract_option_inner_type(field_type) {
+                Some(_) => quote! { ::core::option::Option::Some(parsed) },
+                None => quote! { parsed },
+            };
+            quote! {
+                |value: &str| -> #field_type {
+                    match value.trim().parse() {
+                        ::core::result::Result::Ok(parsed) => #parsed,
+                        ::core::result::Result::Err(_) => ::core::panic!(#invalid, value),
+                    }
+                }
+            }
+        }
+        "seconds" => quote! {
+            |value: u64| -> #field_type {
+                ::core::convert::Into::into(::core::time::Duration::from_secs(value))
+            }
+        },
+        "csv_list" => quote! {
+            |value: &str| -> #field_type {
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(|item| match item.parse() {
+                        ::core::result::Result::Ok(parsed) => parsed,
+                        ::core::result::Result::Err(_) => ::core::panic!(#invalid, item),
+                    })
+                    .collect()
+            }
+        },
+        _ => unreachable!("unknown converters are rejected above"),
+    };
+    syn::parse2(closure).map_err(|error| syn::Error::new(Span::call_site(), error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_std_converter_name() {
+        let expr: Expr = parse_quote!(std_converters::csv_list);
+        assert_eq!(std_converter_name(&expr).unwrap(), "csv_list");
+
+        let closure: Expr = parse_quote!(|value: &str| value.to_string());
+        assert!(std_converter_name(&closure).is_none());
+        let other: Expr = parse_quote!(converters::csv_list);
+        assert!(std_converter_name(&other).is_none());
+        let nested: Expr = parse_quote!(std_converters::csv::list);
+        assert!(std_converter_name(&nested).is_none());
+    }
+
+    #[test]
+    fn test_expand_std_converter() {
+        let field_type: Type = parse_quote!(Vec<u32>);
+        for name in STD_CONVERTERS {
+            let ident = Ident::new(name, Span::call_site());
+            let expr = expand_std_converter(&ident, "ports", &field_type).unwrap();
+            assert!(matches!(expr, Expr::Closure(_)), "{name}");
+        }
+
+        let csv = expand_std_converter(&parse_quote!(csv_list), "ports", &field_type).unwrap();
+        let code = quote!(#csv).to_string();
+        assert!(code.contains("-> Vec < u32 >"));
+        assert!(code.contains("invalid value {:?} for `ports`"));
+
+        let optional: Type = parse_quote!(Option<u16>);
+        let parse = expand_std_converter(&parse_quote!(parse), "port", &optional).unwrap();
+        assert!(quote!(#parse).to_string().contains("Some (parsed)"));
+
+        let error = expand_std_converter(&parse_quote!(uppercase), "name", &field_type)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("Unknown built-in converter `uppercase`"));
+    }
+}
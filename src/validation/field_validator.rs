@@ -132,23 +132,23 @@ impl<'a> FieldValidator<'a> {
             ));
         }
 
-        // Fields that skip setters cannot have setter prefixes
-        if let Some(_setter_prefix) = &field.attributes().setter_prefix {
+        // Fields that skip setters have no setter to find under an alias
+        if !field.attributes().aliases.is_empty() {
             return Err(ErrorMessages::structured_error(
                 field.name(),
-                &format!("Field '{clean_name}' has conflicting attributes: skip_setter and setter_prefix"),
-                Some("#[builder(skip_setter)] and #[builder(setter_prefix)] are incompatible"),
+                &format!("Field '{clean_name}' has conflicting attributes: skip_setter and alias"),
+                Some("#[builder(skip_setter)] and #[builder(alias)] are incompatible"),
                 Some("remove one of incompatible attributes"),
             ));
         }
 
-        // Fields that skip setters must have default values (custom or Default::default())
-        if !field.has_custom_default() && field.is_optional() {
+        // Fields that skip setters cannot have setter prefixes
+        if let Some(_setter_prefix) = &field.attributes().setter_prefix {
             return Err(ErrorMessages::structured_error(
                 field.name(),
-                &format!("Field '{clean_name}' skips setter generation but has no default value"),
-                Some("#[builder(skip_setter)] requires a way to initialize the field"),
-                Some("add #[builder(default = \"...\")] or remove skip_setter"),
+                &format!("Field '{clean_name}' has conflicting attributes: skip_setter and setter_prefix"),
+                Some("#[builder(skip_setter)] and #[builder(setter_prefix)] are incompatible"),
+                Some("remove one of incompatible attributes"),
             ));
         }
 
@@ -210,6 +210,7 @@ mod tests {
                 impl_into: None,
                 converter: None,
                 builder_method: false,
+                ..FieldAttributes::default()
             },
         );
 
@@ -234,6 +235,7 @@ mod tests {
                 impl_into: None,
                 converter: None,
                 builder_method: false,
+                ..FieldAttributes::default()
             },
         );
 
@@ -261,6 +263,7 @@ mod tests {
                 impl_into: None,
                 converter: None,
                 builder_method: false,
+                ..FieldAttributes::default()
             },
         );
 
@@ -269,9 +272,9 @@ mod tests {
     }
 
     #[test]
-    fn test_validate_skip_setter_without_default_fails() {
+    fn test_validate_skip_setter_without_default_passes() {
         let context = ValidationContext::new();
-        let _validator = FieldValidator::new(&context);
+        let validator = FieldValidator::new(&context);
 
         let field = create_test_field(
             "name",
@@ -284,15 +287,13 @@ mod tests {
                 impl_into: None,
                 converter: None,
                 builder_method: false,
+                ..FieldAttributes::default()
             },
         );
 
-        let result = field.validate_configuration();
-        assert!(result.is_err());
-        assert!(result
-            .unwrap_err()
-            .to_string()
-            .contains("must have a default value"));
+        // The field starts from Default::default(), like other optional fields
+        assert!(field.validate_configuration().is_ok());
+        assert!(validator.validate_field_configuration(&field).is_ok());
     }
 
     #[test]
@@ -311,6 +312,7 @@ mod tests {
                 impl_into: None,
                 converter: None,
                 builder_method: false,
+                ..FieldAttributes::default()
             },
         );
 
@@ -338,6 +340,7 @@ mod tests {
                 impl_into: None,
                 converter: None,
                 builder_method: false,
+                ..FieldAttributes::default()
             },
         );
 
@@ -349,6 +352,28 @@ mod tests {
             .contains("conflicting attributes: skip_setter and setter_name"));
     }
 
+    #[test]
+    fn test_validate_skip_setter_with_alias_fails() {
+        let context = ValidationContext::new();
+        let validator = FieldValidator::new(&context);
+
+        let field = create_test_field(
+            "count",
+            FieldAttributes {
+                default_value: Some(syn::parse_str("42").expect("test expr")),
+                aliases: vec!["total".to_string()],
+                skip_setter: true,
+                ..FieldAttributes::default()
+            },
+        );
+
+        let result = validator.validate_field_configuration(&field);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("conflicting attributes: skip_setter and alias"));
+    }
+
     #[test]
     fn test_validate_skip_setter_with_setter_prefix_fails() {
         let context = ValidationContext::new();
@@ -365,6 +390,7 @@ mod tests {
                 impl_into: None,
                 converter: None,
                 builder_method: false,
+                ..FieldAttributes::default()
             },
         );
 
@@ -392,6 +418,7 @@ mod tests {
                 impl_into: None,
                 converter: None,
                 builder_method: false,
+                ..FieldAttributes::default()
             },
         );
 
@@ -415,6 +442,7 @@ mod tests {
                 impl_into: None,
                 converter: None,
                 builder_method: false,
+                ..FieldAttributes::default()
             },
         );
 
@@ -438,6 +466,7 @@ mod tests {
                 impl_into: None,
                 converter: None,
                 builder_method: false,
+                ..FieldAttributes::default()
             },
         );
 
@@ -461,6 +490,7 @@ mod tests {
                 impl_into: None,
                 converter: None,
                 builder_method: false,
+                ..FieldAttributes::default()
             },
         );
 
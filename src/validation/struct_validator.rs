@@ -4,6 +4,7 @@
 //! and cross-field relationships, ensuring consistent validation behavior.
 
 use crate::analysis::StructAnalysis;
+use crate::utils::identifiers::strip_raw_identifier_prefix;
 use crate::validation::{ErrorMessages, FieldValidator, ValidationContext};
 use std::collections::HashMap;
 
@@ -69,6 +70,8 @@ impl<'a> StructValidator<'a> {
 
     /// Validates that the struct has at least one field.
     ///
+    /// Enum variants may have none: selecting a unit variant is meaningful.
+    ///
     /// # Arguments
     ///
     /// * `analysis` - The struct analysis to check
@@ -78,7 +81,7 @@ impl<'a> StructValidator<'a> {
     /// A `syn::Result<()>` indicating success or containing validation errors.
     fn validate_struct_has_fields(&self, analysis: &StructAnalysis) -> syn::Result<()> {
         let total_fields = analysis.required_fields().len() + analysis.optional_fields().len();
-        if total_fields == 0 {
+        if total_fields == 0 && analysis.variant_name().is_none() {
             return Err(ErrorMessages::structured_error_span(
                 proc_macro2::Span::call_site(),
                 &format!("Struct '{}' has no fields", analysis.struct_name()),
@@ -128,6 +131,9 @@ impl<'a> StructValidator<'a> {
         // Validate build method name doesn't conflict with setters
         self.validate_build_method_name_conflict(analysis)?;
 
+        // Validate the fields read by resolve closures
+        self.validate_resolve_dependencies(analysis)?;
+
         Ok(())
     }
 
@@ -197,6 +203,119 @@ impl<'a> StructValidator<'a> {
         Ok(())
     }
 
+    /// Validates the fields read by `resolve` closures.
+    ///
+    /// Each field parameter must name another field of the struct that has no
+    /// `resolve` closure itself, since resolved values are computed in one pass
+    /// from the values held by the builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `analysis` - The struct analysis to check
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Result<()>` indicating success or containing validation errors.
+    fn validate_resolve_dependencies(&self, analysis: &StructAnalysis) -> syn::Result<()> {
+        for field in analysis.all_fields() {
+            for dependency in field.resolve_dependencies() {
+                let dependency_name =
+                    strip_raw_identifier_prefix(&dependency.to_string()).into_owned();
+                let target = analysis
+                    .all_fields()
+                    .find(|other| other.clean_name() == dependency_name);
+
+                match target {
+                    None => {
+                        return Err(ErrorMessages::structured_error(
+                            &dependency,
+                            &format!(
+                                "field `{}`: resolve reads unknown field `{dependency_name}`",
+                                field.clean_name()
+                            ),
+                            Some(
+                                "parameters after the first name the fields passed to the closure",
+                            ),
+                            Some("rename the parameter to match a field of this struct"),
+                        ));
+                    }
+                    Some(target) if target.name() == field.name() => {
+                        return Err(ErrorMessages::structured_error(
+                            &dependency,
+                            &format!(
+                                "field `{}`: resolve cannot read its own field by name",
+                                field.clean_name()
+                            ),
+                            Some("the first closure parameter already receives the field's value"),
+                            Some("remove this parameter"),
+                        ));
+                    }
+                    Some(target)
+                        if target.attributes().default_from.is_some()
+                            || target.attributes().computed.is_some() =>
+                    {
+                        return Err(ErrorMessages::structured_error(
+                            &dependency,
+                            &format!(
+                                "field `{}`: resolve cannot read `{dependency_name}`, which is computed at build time",
+                                field.clean_name()
+                            ),
+                            Some("default_from and computed values are only known once the build method runs"),
+                            Some("read the fields the computed value depends on instead"),
+                        ));
+                    }
+                    Some(target) if target.attributes().from_base => {
+                        return Err(ErrorMessages::structured_error(
+                            &dependency,
+                            &format!(
+                                "field `{}`: resolve cannot read `{dependency_name}`, which may be taken from the base",
+                                field.clean_name()
+                            ),
+                            Some("the value of an unset field is only known once the build method evaluates `base`"),
+                            Some("give the field read by resolve a default of its own"),
+                        ));
+                    }
+                    Some(target) if target.attributes().fill_from => {
+                        return Err(ErrorMessages::structured_error(
+                            &dependency,
+                            &format!(
+                                "field `{}`: resolve cannot read `{dependency_name}`, which may be filled in by fill_from",
+                                field.clean_name()
+                            ),
+                            Some("the builder holds fields tracked for fill_from as `Option` until the build method"),
+                            Some("make the field read by resolve required"),
+                        ));
+                    }
+                    Some(target) if target.attributes().lazy_setter => {
+                        return Err(ErrorMessages::structured_error(
+                            &dependency,
+                            &format!(
+                                "field `{}`: resolve cannot read `{dependency_name}`, which has a lazy_setter",
+                                field.clean_name()
+                            ),
+                            Some("the builder may hold a closure instead of a value for lazy_setter fields"),
+                            Some("remove lazy_setter from the field read by resolve"),
+                        ));
+                    }
+                    Some(target) if target.attributes().resolve.is_some() => {
+                        return Err(ErrorMessages::structured_error(
+                            &dependency,
+                            &format!(
+                                "field `{}`: resolve cannot read `{dependency_name}`, which has its own resolve",
+                                field.clean_name()
+                            ),
+                            Some("resolve closures receive the values held by the builder, before any resolve runs"),
+                            Some("combine both transforms into a single resolve closure"),
+                        ));
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validates struct-level attributes and configuration.
     ///
     /// # Arguments
@@ -221,6 +340,284 @@ impl<'a> StructValidator<'a> {
             ));
         }
 
+        // A borrowing build method or a cloneable builder clones every field,
+        // which a boxed closure cannot do
+        let clone_conflict = if analysis.struct_attributes().get_build_by_clone() {
+            Some(("build_by = \"clone\"", "build by move"))
+        } else if analysis.struct_attributes().get_derive_clone() {
+            Some(("derive(Clone)", "remove `derive(...)`"))
+        } else {
+            None
+        };
+        if let Some((conflict, alternative)) = clone_conflict {
+            if let Some(field) = analysis
+                .all_fields()
+                .find(|field| field.attributes().lazy_setter)
+            {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{}`: `lazy_setter` cannot be used with `{}`",
+                        field_name, conflict
+                    ),
+                    Some("the builder holds a closure that runs once and cannot be cloned"),
+                    Some(&format!(
+                        "remove the `lazy_setter` attribute from this field or {alternative}"
+                    )),
+                ));
+            }
+        }
+
+        // A Copy builder cannot hold a value in a Box
+        if analysis.struct_attributes().get_derive_copy() {
+            if let Some(field) = analysis
+                .all_fields()
+                .find(|field| field.attributes().boxed_field)
+            {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{field_name}`: `boxed_field` cannot be used with `derive(Copy)`"
+                    ),
+                    Some("the builder keeps the value in a `Box`, which is not `Copy`"),
+                    Some(
+                        "remove the `boxed_field` attribute from this field or derive `Clone` only",
+                    ),
+                ));
+            }
+        }
+
+        // Serde derives write every stored value out, which a boxed closure
+        // cannot do and a sensitive value must not do
+        let struct_attributes = analysis.struct_attributes();
+        let serde_derive = if struct_attributes.get_derive_serialize() {
+            Some("derive(Serialize)")
+        } else if struct_attributes.get_derive_deserialize() {
+            Some("derive(Deserialize)")
+        } else {
+            None
+        };
+        if let Some(serde_derive) = serde_derive {
+            let conflict = analysis.all_fields().find_map(|field| {
+                if field.attributes().lazy_setter {
+                    Some((
+                        field,
+                        "lazy_setter",
+                        "the builder holds a closure that cannot be serialized",
+                    ))
+                } else if field.attributes().sensitive {
+                    Some((
+                        field,
+                        "sensitive",
+                        "a serialized draft would keep the value after the builder zeroizes it",
+                    ))
+                } else {
+                    None
+                }
+            });
+            if let Some((field, attribute, reason)) = conflict {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{}`: `{}` cannot be used with `{}`",
+                        field_name, attribute, serde_derive
+                    ),
+                    Some(reason),
+                    Some(&format!(
+                        "remove the `{attribute}` attribute from this field or remove `{serde_derive}`"
+                    )),
+                ));
+            }
+        }
+
+        // Every setter_prefix exception must name a field. Enum variants share
+        // the list, so a name may belong to another variant
+        if analysis.variant_name().is_none() {
+            for ident in analysis.struct_attributes().get_setter_prefix_except() {
+                let name =
+                    crate::utils::identifiers::strip_raw_identifier_prefix(&ident.to_string())
+                        .into_owned();
+                if !analysis
+                    .all_fields()
+                    .any(|field| field.clean_name() == name)
+                {
+                    return Err(ErrorMessages::structured_error_span(
+                        ident.span(),
+                        &format!("setter_prefix(except(...)) names unknown field `{name}`"),
+                        Some("exceptions keep the natural setter name of a field of this struct"),
+                        Some("list only field names, or remove this exception"),
+                    ));
+                }
+            }
+        }
+
+        // Field-level test values only fill the required fields of test_builder()
+        let has_test_builder = struct_attributes.get_test_default().is_some();
+        if let Some(field) = analysis.all_fields().find(|field| {
+            field.attributes().test_default.is_some() && !(has_test_builder && field.is_required())
+        }) {
+            let field_name = field.name();
+            let (reason, suggestion) = if has_test_builder {
+                (
+                    "optional fields start from their defaults in `test_builder()`",
+                    "remove `test_default` from this field or make the field required",
+                )
+            } else {
+                (
+                    "the value is only used by `test_builder()`, which the struct-level `test_default` generates",
+                    "add `#[builder(test_default)]` to the struct",
+                )
+            };
+            return Err(ErrorMessages::structured_error_span(
+                field_name.span(),
+                &format!(
+                    "field `{field_name}`: `test_default` requires a required field and the struct-level `test_default`"
+                ),
+                Some(reason),
+                Some(suggestion),
+            ));
+        }
+
+        // Lite mode has no builder to hold values until a build method runs, and
+        // required fields are passed to `new` instead of a setter
+        if analysis.struct_attributes().get_lite() {
+            for field in analysis.all_fields() {
+                let attributes = field.attributes();
+                let conflict = if attributes.builder_method {
+                    Some("builder_method")
+                } else if attributes.lazy_setter {
+                    Some("lazy_setter")
+                } else if attributes.resolve.is_some() {
+                    Some("resolve")
+                } else if attributes.default_from.is_some() {
+                    Some("default_from")
+                } else if attributes.computed.is_some() {
+                    Some("computed")
+                } else if attributes.sensitive {
+                    Some("sensitive")
+                } else if attributes.builder_only {
+                    Some("builder_only")
+                } else if attributes.boxed_field {
+                    Some("boxed_field")
+                } else if let Some(check) = attributes.validation_attribute() {
+                    Some(check)
+                } else if field.try_from_input().is_some() {
+                    Some("via = TryFrom")
+                } else if field.is_required() && attributes.setter_input.is_some() {
+                    Some("setter_input")
+                } else if field.is_required() && attributes.converter.is_some() {
+                    Some("converter")
+                } else if field.is_required() && attributes.into_iter == Some(true) {
+                    Some("into_iter")
+                } else if field.is_required() && attributes.try_setter {
+                    Some("try_setter")
+                } else if field.is_required() && attributes.also_ref {
+                    Some("setter(also_ref)")
+                } else if field.is_required() && attributes.clone_from_ref {
+                    Some("clone_from_ref")
+                } else if field.is_required() && attributes.dyn_into {
+                    Some("dyn_into")
+                } else if field.is_required() && attributes.nested {
+                    Some("nested")
+                } else {
+                    None
+                };
+                if let Some(conflict) = conflict {
+                    let field_name = field.name();
+                    return Err(ErrorMessages::structured_error_span(
+                        field_name.span(),
+                        &format!(
+                            "field `{field_name}`: `{conflict}` cannot be used with `#[builder(lite)]`"
+                        ),
+                        Some("lite mode generates `new` and `with_*` methods on the struct, without a builder or build method"),
+                        Some("remove the attribute from this field or remove `lite` from the struct"),
+                    ));
+                }
+            }
+        }
+
+        // A nested builder is started with `builder()` and must build without
+        // failing, as the nested setter has no error to return
+        if analysis.struct_attributes().get_nestable() {
+            let conflict = analysis.all_fields().find_map(|field| {
+                let attributes = field.attributes();
+                if attributes.builder_method {
+                    Some((field, "builder_method", "it replaces `builder()`, which nesting starts from"))
+                } else if !attributes.validate_on_set {
+                    attributes.validation_attribute().map(|check| {
+                        (field, check, "the build method returns a `Result`, which a nested setter cannot take")
+                    })
+                } else {
+                    None
+                }
+            });
+            if let Some((field, attribute, reason)) = conflict {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{field_name}`: `{attribute}` cannot be used with `#[builder(nestable)]`"
+                    ),
+                    Some(reason),
+                    Some(&format!(
+                        "remove `{attribute}` from this field or remove `nestable` from the struct"
+                    )),
+                ));
+            }
+        }
+
+        // The dynamic builder stores each setter's input until its build method
+        // replays the setters, so the input must be a value it can hold
+        if analysis.struct_attributes().get_also_dynamic() {
+            use crate::utils::field_utils::has_elided_lifetime;
+
+            let conflict = analysis
+                .all_fields()
+                .filter(|field| field.should_generate_setter())
+                .find_map(|field| {
+                    let attributes = field.attributes();
+                    let conflict = if attributes.dyn_into {
+                        ("dyn_into", "the setter takes `impl Trait`, which has no type to store")
+                    } else if attributes.sensitive {
+                        (
+                            "sensitive",
+                            "the dynamic builder would keep the value without zeroizing it",
+                        )
+                    } else if attributes.accumulate.is_some() {
+                        (
+                            "accumulate",
+                            "the dynamic builder stores one value per field",
+                        )
+                    } else if syn::parse2::<syn::Type>(field.setter_input_type())
+                        .is_ok_and(|input_type| has_elided_lifetime(&input_type))
+                    {
+                        (
+                            "converter",
+                            "the converter borrows its input without a named lifetime, so the input cannot be stored",
+                        )
+                    } else {
+                        return None;
+                    };
+                    Some((field, conflict))
+                });
+            if let Some((field, (attribute, reason))) = conflict {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{field_name}`: `{attribute}` cannot be used with `#[builder(also_dynamic)]`"
+                    ),
+                    Some(reason),
+                    Some(&format!(
+                        "remove `{attribute}` from this field or remove `also_dynamic` from the struct"
+                    )),
+                ));
+            }
+        }
+
         Ok(())
     }
 
@@ -229,6 +626,20 @@ impl<'a> StructValidator<'a> {
     /// When `#[builder(const)]` is enabled, this validates that:
     /// - All optional fields have explicit default values (Default::default() is not const)
     /// - No fields use `impl_into` (trait bounds not supported in const fn)
+    /// - No fields use `into_iter` (iterators cannot be collected in const fn)
+    /// - No fields use `setter_input` (trait methods cannot be called in const fn)
+    /// - No fields use `resolve` (closures cannot be called in const fn)
+    /// - No fields are `sensitive` (`Zeroizing` cannot be constructed in const fn)
+    /// - No fields use `lazy_setter` (closures cannot be boxed in const fn)
+    /// - No fields use `try_setter` (trait methods cannot be called in const fn)
+    /// - No fields use `setter(also_ref)` (values cannot be cloned in const fn)
+    /// - No fields use `clone_from_ref` (values cannot be cloned in const fn)
+    /// - No fields use `dyn_into` or `boxed_field` (values cannot be boxed in const fn)
+    /// - No fields use `default_from` or `computed` (closures cannot be called in const fn)
+    /// - No fields use `accumulate` (closures cannot be called in const fn)
+    /// - No fields use `validate`, `non_empty` or `range` (the build method is infallible)
+    /// - Default expressions and converter bodies make no calls that are known not
+    ///   to be const, such as `String::from` or `.to_string()`
     ///
     /// # Arguments
     ///
@@ -257,6 +668,223 @@ impl<'a> StructValidator<'a> {
                     Some("remove the `impl_into` attribute from this field or remove `const` from the struct"),
                 ));
             }
+
+            // Check for field-level into_iter
+            if field.attributes().into_iter == Some(true) {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{}`: `into_iter` cannot be used with `#[builder(const)]`",
+                        field_name
+                    ),
+                    Some("collecting an iterator is not supported in const fn"),
+                    Some("remove the `into_iter` attribute from this field or remove `const` from the struct"),
+                ));
+            }
+
+            // Check for setter inputs, converted by calling a trait method
+            if field.attributes().setter_input.is_some() {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{}`: `setter_input` cannot be used with `#[builder(const)]`",
+                        field_name
+                    ),
+                    Some("`From` and `TryFrom` conversions are trait methods, which cannot be called in const fn"),
+                    Some("use a `converter` with a const body or remove `const` from the struct"),
+                ));
+            }
+
+            // Check for sensitive fields
+            if field.attributes().sensitive {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{}`: `sensitive` cannot be used with `#[builder(const)]`",
+                        field_name
+                    ),
+                    Some("`Zeroizing` wrappers cannot be created or dropped in const fn"),
+                    Some("remove the `sensitive` attribute from this field or remove `const` from the struct"),
+                ));
+            }
+
+            // Check for computed defaults and values
+            let computed_attribute = if field.attributes().default_from.is_some() {
+                Some("default_from")
+            } else if field.attributes().computed.is_some() {
+                Some("computed")
+            } else {
+                None
+            };
+            if let Some(attribute) = computed_attribute {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{}`: `{attribute}` cannot be used with `#[builder(const)]`",
+                        field_name
+                    ),
+                    Some("closures cannot be called in const fn"),
+                    Some("use `default` with a const expression or remove `const` from the struct"),
+                ));
+            }
+
+            // Check for validation checks
+            if let Some(check) = field.attributes().validation_attribute() {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{}`: `{check}` cannot be used with `#[builder(const)]`",
+                        field_name
+                    ),
+                    Some("the checks run in the build method, which const builders keep infallible"),
+                    Some(&format!(
+                        "remove the `{check}` attribute from this field or remove `const` from the struct"
+                    )),
+                ));
+            }
+
+            // Check for accumulating setters
+            if field.attributes().accumulate.is_some() {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{}`: `accumulate` cannot be used with `#[builder(const)]`",
+                        field_name
+                    ),
+                    Some("closures cannot be called in const fn"),
+                    Some("remove the `accumulate` attribute from this field or remove `const` from the struct"),
+                ));
+            }
+
+            // Check for lazy setters
+            if field.attributes().lazy_setter {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{}`: `lazy_setter` cannot be used with `#[builder(const)]`",
+                        field_name
+                    ),
+                    Some("closures cannot be boxed or called in const fn"),
+                    Some("remove the `lazy_setter` attribute from this field or remove `const` from the struct"),
+                ));
+            }
+
+            // Check for fallible setters
+            if field.attributes().try_setter {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{}`: `try_setter` cannot be used with `#[builder(const)]`",
+                        field_name
+                    ),
+                    Some("trait methods such as `TryFrom::try_from` cannot be called in const fn"),
+                    Some("remove the `try_setter` attribute from this field or remove `const` from the struct"),
+                ));
+            }
+
+            // Check for by-reference setters
+            if field.attributes().also_ref {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{}`: `setter(also_ref)` cannot be used with `#[builder(const)]`",
+                        field_name
+                    ),
+                    Some("trait methods such as `Clone::clone` cannot be called in const fn"),
+                    Some("remove `setter(also_ref)` from this field or remove `const` from the struct"),
+                ));
+            }
+
+            // Check for setters cloning borrowed values
+            if field.attributes().clone_from_ref {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{}`: `clone_from_ref` cannot be used with `#[builder(const)]`",
+                        field_name
+                    ),
+                    Some("trait methods such as `Clone::clone` and `From::from` cannot be called in const fn"),
+                    Some("remove `clone_from_ref` from this field or remove `const` from the struct"),
+                ));
+            }
+
+            // Check for setters boxing trait objects
+            if field.attributes().dyn_into {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{}`: `dyn_into` cannot be used with `#[builder(const)]`",
+                        field_name
+                    ),
+                    Some("`Box::new`, `Rc::new` and `Arc::new` cannot be called in const fn"),
+                    Some("remove `dyn_into` from this field or remove `const` from the struct"),
+                ));
+            }
+
+            // Check for fields kept in a Box
+            if field.attributes().boxed_field {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{}`: `boxed_field` cannot be used with `#[builder(const)]`",
+                        field_name
+                    ),
+                    Some("`Box::new` cannot be called in const fn"),
+                    Some("remove `boxed_field` from this field or remove `const` from the struct"),
+                ));
+            }
+
+            // Check for resolve closures
+            if field.attributes().resolve.is_some() {
+                let field_name = field.name();
+                return Err(ErrorMessages::structured_error_span(
+                    field_name.span(),
+                    &format!(
+                        "field `{}`: `resolve` cannot be used with `#[builder(const)]`",
+                        field_name
+                    ),
+                    Some("closures cannot be called in const fn"),
+                    Some("remove the `resolve` attribute from this field or remove `const` from the struct"),
+                ));
+            }
+        }
+
+        // Check default expressions and converter bodies for non-const calls
+        for field in analysis.all_fields() {
+            let attributes = field.attributes();
+            let converter_body = match &attributes.converter {
+                Some(syn::Expr::Closure(closure)) => Some(("converter", closure.body.as_ref())),
+                _ => None,
+            };
+            let default = attributes
+                .default_value
+                .as_ref()
+                .map(|expr| ("default", expr));
+            for (attribute, expr) in default.into_iter().chain(converter_body) {
+                if let Some(non_const) = find_non_const_expr(expr) {
+                    return Err(ErrorMessages::structured_error(
+                        &non_const.tokens,
+                        &format!(
+                            "field `{}`: the `{attribute}` expression cannot be evaluated in const context",
+                            field.name()
+                        ),
+                        Some(&non_const.reason),
+                        Some("use literals, constants and const fn calls, or remove `const` from the struct"),
+                    ));
+                }
+            }
         }
 
         // Check that all optional fields have explicit defaults
@@ -329,10 +957,190 @@ impl<'a> StructValidator<'a> {
             ));
         }
 
+        // The trait's builder() starts from the state without required fields
+        if let Some(field) = builder_method_fields.first() {
+            if analysis.struct_attributes().get_buildable().is_some() {
+                return Err(ErrorMessages::structured_error_span(
+                    field.name().span(),
+                    "`builder_method` cannot be used with `#[builder(buildable)]`",
+                    Some("`builder_method` replaces the `builder()` the trait implementation returns"),
+                    Some("remove `builder_method` from this field or `buildable` from the struct"),
+                ));
+            }
+        }
+
         Ok(())
     }
 }
 
+/// Functions that are not `const fn`, matched by the last path segment.
+const NON_CONST_FUNCTIONS: &[&str] = &[
+    "default",
+    "from",
+    "from_iter",
+    "from_str",
+    "into",
+    "to_owned",
+    "to_string",
+    "try_from",
+    "try_into",
+    "with_capacity",
+];
+
+/// Heap-allocating types whose `new` is not a `const fn`.
+const NON_CONST_CONSTRUCTORS: &[&str] = &["Arc", "Box", "HashMap", "HashSet", "Rc"];
+
+/// Methods that are not `const fn` on the types they are usually called on.
+const NON_CONST_METHODS: &[&str] = &[
+    "clone",
+    "cloned",
+    "collect",
+    "into",
+    "into_iter",
+    "iter",
+    "join",
+    "map",
+    "parse",
+    "push",
+    "push_str",
+    "to_owned",
+    "to_string",
+    "to_vec",
+    "try_into",
+    "unwrap_or_default",
+];
+
+/// Macros that expand to code that cannot run in const context.
+const NON_CONST_MACROS: &[&str] = &["format", "vec"];
+
+/// A part of an expression that cannot be evaluated in const context.
+struct NonConstExpr {
+    /// The offending call or construct, which the error points at
+    tokens: proc_macro2::TokenStream,
+    /// Why it is not const
+    reason: String,
+}
+
+/// Finds the first call or construct in an expression that is known not to be const.
+///
+/// This is a plausibility check on the syntax only. Calls that are not listed
+/// are assumed to be `const fn`, and the compiler still reports anything missed.
+fn find_non_const_expr(expr: &syn::Expr) -> Option<NonConstExpr> {
+    use quote::ToTokens;
+
+    let non_const = |tokens: &dyn ToTokens, reason: String| {
+        Some(NonConstExpr {
+            tokens: tokens.to_token_stream(),
+            reason,
+        })
+    };
+    match expr {
+        syn::Expr::Call(call) => {
+            if let syn::Expr::Path(path) = call.func.as_ref() {
+                let segments: Vec<String> = path
+                    .path
+                    .segments
+                    .iter()
+                    .map(|segment| segment.ident.to_string())
+                    .collect();
+                let name = segments.last().map(String::as_str).unwrap_or_default();
+                let owner = segments
+                    .len()
+                    .checked_sub(2)
+                    .map(|index| segments[index].as_str());
+                let is_non_const = NON_CONST_FUNCTIONS.contains(&name)
+                    || (name == "new"
+                        && owner.is_some_and(|owner| NON_CONST_CONSTRUCTORS.contains(&owner)));
+                if is_non_const {
+                    let function = segments.join("::");
+                    return non_const(
+                        &call.func,
+                        format!("`{function}` is not a const fn and cannot be called in const fn"),
+                    );
+                }
+            }
+            std::iter::once(call.func.as_ref())
+                .chain(&call.args)
+                .find_map(find_non_const_expr)
+        }
+        syn::Expr::MethodCall(call) => {
+            let method = call.method.to_string();
+            if NON_CONST_METHODS.contains(&method.as_str()) {
+                return non_const(
+                    &call.method,
+                    format!("`.{method}()` is not a const fn and cannot be called in const fn"),
+                );
+            }
+            std::iter::once(call.receiver.as_ref())
+                .chain(&call.args)
+                .find_map(find_non_const_expr)
+        }
+        syn::Expr::Macro(mac) => {
+            let name = mac.mac.path.segments.last()?.ident.to_string();
+            if NON_CONST_MACROS.contains(&name.as_str()) {
+                return non_const(
+                    &mac.mac.path,
+                    format!("`{name}!` allocates and cannot be used in const fn"),
+                );
+            }
+            None
+        }
+        syn::Expr::ForLoop(for_loop) => non_const(
+            &for_loop.for_token,
+            "`for` loops use `Iterator::next`, which cannot be called in const fn".to_string(),
+        ),
+        syn::Expr::Try(expr_try) => non_const(
+            &expr_try.question_token,
+            "the `?` operator cannot be used in const fn".to_string(),
+        ),
+        syn::Expr::Array(array) => array.elems.iter().find_map(find_non_const_expr),
+        syn::Expr::Tuple(tuple) => tuple.elems.iter().find_map(find_non_const_expr),
+        syn::Expr::Binary(binary) => {
+            find_non_const_expr(&binary.left).or_else(|| find_non_const_expr(&binary.right))
+        }
+        syn::Expr::Unary(unary) => find_non_const_expr(&unary.expr),
+        syn::Expr::Cast(cast) => find_non_const_expr(&cast.expr),
+        syn::Expr::Field(field) => find_non_const_expr(&field.base),
+        syn::Expr::Group(group) => find_non_const_expr(&group.expr),
+        syn::Expr::Paren(paren) => find_non_const_expr(&paren.expr),
+        syn::Expr::Reference(reference) => find_non_const_expr(&reference.expr),
+        syn::Expr::Index(index) => {
+            find_non_const_expr(&index.expr).or_else(|| find_non_const_expr(&index.index))
+        }
+        syn::Expr::Repeat(repeat) => find_non_const_expr(&repeat.expr),
+        syn::Expr::Struct(expr_struct) => expr_struct
+            .fields
+            .iter()
+            .map(|field| &field.expr)
+            .chain(expr_struct.rest.as_deref())
+            .find_map(find_non_const_expr),
+        syn::Expr::If(expr_if) => find_non_const_expr(&expr_if.cond)
+            .or_else(|| find_non_const_block(&expr_if.then_branch))
+            .or_else(|| {
+                let (_, else_branch) = expr_if.else_branch.as_ref()?;
+                find_non_const_expr(else_branch)
+            }),
+        syn::Expr::Match(expr_match) => find_non_const_expr(&expr_match.expr).or_else(|| {
+            expr_match
+                .arms
+                .iter()
+                .find_map(|arm| find_non_const_expr(&arm.body))
+        }),
+        syn::Expr::Block(block) => find_non_const_block(&block.block),
+        syn::Expr::Unsafe(block) => find_non_const_block(&block.block),
+        _ => None,
+    }
+}
+
+/// Finds the first non-const call or construct in a block's statements.
+fn find_non_const_block(block: &syn::Block) -> Option<NonConstExpr> {
+    block.stmts.iter().find_map(|stmt| match stmt {
+        syn::Stmt::Local(local) => find_non_const_expr(&local.init.as_ref()?.expr),
+        syn::Stmt::Expr(expr, _) => find_non_const_expr(expr),
+        _ => None,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -427,11 +1235,77 @@ mod tests {
     }
 
     #[test]
-    fn test_const_builder_with_required_fields_passes() {
-        // const builder with required fields only (no defaults needed) should pass
-        let input = parse_quote! {
-            #[builder(const)]
-            struct Example {
+    fn test_const_builder_rejects_non_const_defaults_and_converters() {
+        let mut context = ValidationContext::new();
+        let mut error_for = |input: syn::DeriveInput| {
+            let analysis = analyze_struct(&input).unwrap();
+            StructValidator::new(&mut context)
+                .validate_struct_for_generation(&analysis)
+                .err()
+                .map(|error| error.to_string())
+        };
+
+        let error = error_for(parse_quote! {
+            #[builder(const)]
+            struct Example {
+                #[builder(default = String::from("guest"))]
+                name: String,
+            }
+        })
+        .unwrap();
+        assert!(error.contains("field `name`: the `default` expression cannot be evaluated"));
+        assert!(
+            error.contains("`String::from` is not a const fn"),
+            "{error}"
+        );
+
+        let error = error_for(parse_quote! {
+            #[builder(const)]
+            struct Example {
+                #[builder(required, converter = |value: &str| if value.is_empty() { None } else { Some(value.to_string()) })]
+                label: Option<String>,
+            }
+        })
+        .unwrap();
+        assert!(error.contains("the `converter` expression"), "{error}");
+        assert!(
+            error.contains("`.to_string()` is not a const fn"),
+            "{error}"
+        );
+
+        let error = error_for(parse_quote! {
+            #[builder(const)]
+            struct Example {
+                #[builder(default = vec![1, 2])]
+                items: Vec<u8>,
+            }
+        })
+        .unwrap();
+        assert!(error.contains("`vec!` allocates"), "{error}");
+
+        // Literals, constants and const fn calls pass
+        assert!(error_for(parse_quote! {
+            #[builder(const)]
+            struct Example {
+                #[builder(default = String::new())]
+                name: String,
+                #[builder(default = [0; 4])]
+                bytes: [u8; 4],
+                #[builder(default = u32::MAX / 2 + LIMIT)]
+                limit: u32,
+                #[builder(required, converter = |value: u64| value as u32 * 2)]
+                count: u32,
+            }
+        })
+        .is_none());
+    }
+
+    #[test]
+    fn test_const_builder_with_required_fields_passes() {
+        // const builder with required fields only (no defaults needed) should pass
+        let input = parse_quote! {
+            #[builder(const)]
+            struct Example {
                 #[builder(required)]
                 name: String,
             }
@@ -462,4 +1336,662 @@ mod tests {
         let err = result.unwrap_err().to_string();
         assert!(err.contains("impl_into") && err.contains("const"));
     }
+
+    #[test]
+    fn test_const_builder_with_field_into_iter_fails() {
+        let input = parse_quote! {
+            #[builder(const)]
+            struct Example {
+                #[builder(required, into_iter)]
+                tags: Vec<String>,
+            }
+        };
+
+        let analysis = analyze_struct(&input).unwrap();
+        let mut context = ValidationContext::new();
+        let mut validator = StructValidator::new(&mut context);
+        let err = validator
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`into_iter` cannot be used with `#[builder(const)]`"));
+    }
+
+    #[test]
+    fn test_resolve_dependencies_must_name_plain_fields() {
+        let validate = |input: syn::DeriveInput| {
+            let analysis = analyze_struct(&input).unwrap();
+            let mut context = ValidationContext::new();
+            StructValidator::new(&mut context)
+                .validate_struct_for_generation(&analysis)
+                .map_err(|e| e.to_string())
+        };
+
+        assert!(validate(parse_quote! {
+            struct Paths {
+                base: String,
+                #[builder(resolve = |path: String, base: &String| format!("{base}/{path}"))]
+                path: String,
+            }
+        })
+        .is_ok());
+
+        let unknown = validate(parse_quote! {
+            struct Paths {
+                #[builder(resolve = |path: String, root: &String| path)]
+                path: String,
+            }
+        });
+        assert!(unknown
+            .unwrap_err()
+            .contains("resolve reads unknown field `root`"));
+
+        let own = validate(parse_quote! {
+            struct Paths {
+                #[builder(resolve = |path: String, path2: &String| path)]
+                path: String,
+                #[builder(resolve = |value: String, path: &String| value)]
+                path2: String,
+            }
+        });
+        assert!(own.unwrap_err().contains("which has its own resolve"));
+
+        let itself = validate(parse_quote! {
+            struct Paths {
+                #[builder(resolve = |value: String, path: &String| value)]
+                path: String,
+            }
+        });
+        assert!(itself.unwrap_err().contains("cannot read its own field"));
+
+        let lazy = validate(parse_quote! {
+            struct Paths {
+                #[builder(lazy_setter)]
+                base: String,
+                #[builder(resolve = |path: String, base: &String| path)]
+                path: String,
+            }
+        });
+        assert!(lazy.unwrap_err().contains("which has a lazy_setter"));
+
+        let computed = validate(parse_quote! {
+            struct Paths {
+                #[builder(default_from = |b| String::new())]
+                base: String,
+                #[builder(resolve = |path: String, base: &String| path)]
+                path: String,
+            }
+        });
+        assert!(computed
+            .unwrap_err()
+            .contains("which is computed at build time"));
+
+        let from_base = validate(parse_quote! {
+            #[builder(base = "Paths::default()")]
+            struct Paths {
+                root: String,
+                #[builder(resolve = |path: String, root: &String| path)]
+                path: String,
+            }
+        });
+        assert!(from_base
+            .unwrap_err()
+            .contains("which may be taken from the base"));
+
+        let fill_from = validate(parse_quote! {
+            #[builder(fill_from)]
+            struct Paths {
+                root: String,
+                #[builder(resolve = |path: String, root: &String| path)]
+                path: String,
+            }
+        });
+        assert!(fill_from
+            .unwrap_err()
+            .contains("which may be filled in by fill_from"));
+    }
+
+    #[test]
+    fn test_const_builder_with_resolve_fails() {
+        let input = parse_quote! {
+            #[builder(const)]
+            struct Example {
+                #[builder(required, resolve = |value: u32| value + 1)]
+                count: u32,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let mut context = ValidationContext::new();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`resolve` cannot be used with `#[builder(const)]`"));
+    }
+
+    #[test]
+    fn test_const_builder_with_accumulate_fails() {
+        let input = parse_quote! {
+            #[builder(const)]
+            struct Example {
+                #[builder(default = 0, accumulate = |total, n: u32| *total += n)]
+                total: u32,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let mut context = ValidationContext::new();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`accumulate` cannot be used with `#[builder(const)]`"));
+    }
+
+    #[test]
+    fn test_const_builder_with_validation_fails() {
+        let input = parse_quote! {
+            #[builder(const)]
+            struct Example {
+                #[builder(default = 1, range = 1..=10)]
+                level: u8,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let mut context = ValidationContext::new();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`range` cannot be used with `#[builder(const)]`"));
+    }
+
+    #[test]
+    fn test_const_and_lite_builders_with_also_ref_fail() {
+        let input = parse_quote! {
+            #[builder(const)]
+            struct Example {
+                #[builder(default = 1, setter(also_ref))]
+                level: u8,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let mut context = ValidationContext::new();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`setter(also_ref)` cannot be used with `#[builder(const)]`"));
+
+        let input = parse_quote! {
+            #[builder(lite)]
+            struct Example {
+                #[builder(required, setter(also_ref))]
+                name: String,
+                #[builder(setter(also_ref))]
+                label: String,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("field `name`: `setter(also_ref)` cannot be used with `#[builder(lite)]`")
+        );
+    }
+
+    #[test]
+    fn test_const_and_lite_builders_with_clone_from_ref_fail() {
+        let input = parse_quote! {
+            #[builder(const)]
+            struct Example {
+                #[builder(required, clone_from_ref)]
+                name: &'static str,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let mut context = ValidationContext::new();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`clone_from_ref` cannot be used with `#[builder(const)]`"));
+
+        let input = parse_quote! {
+            #[builder(lite)]
+            struct Example {
+                #[builder(required, clone_from_ref)]
+                name: String,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("field `name`: `clone_from_ref` cannot be used with `#[builder(lite)]`")
+        );
+    }
+
+    #[test]
+    fn test_field_test_default_requires_required_field_and_test_builder() {
+        let mut context = ValidationContext::new();
+        let mut error = |input: syn::DeriveInput| {
+            let analysis = analyze_struct(&input).unwrap();
+            StructValidator::new(&mut context)
+                .validate_struct_for_generation(&analysis)
+                .unwrap_err()
+                .to_string()
+        };
+
+        let err = error(parse_quote! {
+            struct Example {
+                #[builder(required, test_default = 1)]
+                id: u32,
+            }
+        });
+        assert!(err.contains("field `id`: `test_default` requires a required field"));
+
+        let err = error(parse_quote! {
+            #[builder(test_default)]
+            struct Example {
+                #[builder(test_default = 1)]
+                id: u32,
+            }
+        });
+        assert!(err.contains("field `id`: `test_default` requires a required field"));
+    }
+
+    #[test]
+    fn test_const_and_lite_builders_with_dyn_into_fail() {
+        let input = parse_quote! {
+            #[builder(const)]
+            struct Example {
+                #[builder(dyn_into)]
+                handler: Option<Box<dyn Handler>>,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let mut context = ValidationContext::new();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("field `handler`: `dyn_into` cannot be used with `#[builder(const)]`"));
+
+        let input = parse_quote! {
+            #[builder(lite)]
+            struct Example {
+                #[builder(required, dyn_into)]
+                handler: Box<dyn Handler>,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("field `handler`: `dyn_into` cannot be used with `#[builder(lite)]`"));
+    }
+
+    #[test]
+    fn test_boxed_field_with_const_lite_and_copy_fails() {
+        let mut context = ValidationContext::new();
+        for (input, error) in [
+            (
+                parse_quote! {
+                    #[builder(const)]
+                    struct Example {
+                        #[builder(required, boxed_field)]
+                        data: [u8; 4096],
+                    }
+                },
+                "field `data`: `boxed_field` cannot be used with `#[builder(const)]`",
+            ),
+            (
+                parse_quote! {
+                    #[builder(lite)]
+                    struct Example {
+                        #[builder(boxed_field)]
+                        data: Option<[u8; 4096]>,
+                    }
+                },
+                "field `data`: `boxed_field` cannot be used with `#[builder(lite)]`",
+            ),
+            (
+                parse_quote! {
+                    #[builder(derive(Copy, Clone))]
+                    struct Example {
+                        #[builder(required, boxed_field)]
+                        data: [u8; 4096],
+                    }
+                },
+                "field `data`: `boxed_field` cannot be used with `derive(Copy)`",
+            ),
+        ] {
+            let analysis = analyze_struct(&input).unwrap();
+            let err = StructValidator::new(&mut context)
+                .validate_struct_for_generation(&analysis)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(error), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_setter_input_with_const_and_lite_fails() {
+        let mut context = ValidationContext::new();
+        for (input, error) in [
+            (
+                parse_quote! {
+                    #[builder(const)]
+                    struct Example {
+                        #[builder(required, setter_input = u8)]
+                        port: u16,
+                    }
+                },
+                "field `port`: `setter_input` cannot be used with `#[builder(const)]`",
+            ),
+            (
+                parse_quote! {
+                    #[builder(lite)]
+                    struct Example {
+                        #[builder(required, setter_input = u8)]
+                        port: u16,
+                    }
+                },
+                "field `port`: `setter_input` cannot be used with `#[builder(lite)]`",
+            ),
+            (
+                parse_quote! {
+                    #[builder(lite)]
+                    struct Example {
+                        #[builder(setter_input = u32, via = TryFrom)]
+                        port: u16,
+                    }
+                },
+                "field `port`: `via = TryFrom` cannot be used with `#[builder(lite)]`",
+            ),
+        ] {
+            let analysis = analyze_struct(&input).unwrap();
+            let err = StructValidator::new(&mut context)
+                .validate_struct_for_generation(&analysis)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(error), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_also_dynamic_with_unstorable_setter_input_fails() {
+        let mut context = ValidationContext::new();
+        for (input, error) in [
+            (
+                parse_quote! {
+                    #[builder(also_dynamic)]
+                    struct Example {
+                        #[builder(required, dyn_into)]
+                        handler: Box<dyn Handler>,
+                    }
+                },
+                "field `handler`: `dyn_into` cannot be used with `#[builder(also_dynamic)]`",
+            ),
+            (
+                parse_quote! {
+                    #[builder(also_dynamic)]
+                    struct Example {
+                        #[builder(converter = |name: &str| name.to_string())]
+                        name: String,
+                    }
+                },
+                "field `name`: `converter` cannot be used with `#[builder(also_dynamic)]`",
+            ),
+        ] {
+            let analysis = analyze_struct(&input).unwrap();
+            let err = StructValidator::new(&mut context)
+                .validate_struct_for_generation(&analysis)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(error), "{err}");
+        }
+
+        let input = parse_quote! {
+            #[builder(also_dynamic)]
+            struct Example<'a> {
+                #[builder(converter = |name: &'a str| name.len())]
+                len: usize,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        assert!(StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_nestable_with_unnestable_fields_fails() {
+        let mut context = ValidationContext::new();
+        for (input, error) in [
+            (
+                parse_quote! {
+                    #[builder(nestable)]
+                    struct Example {
+                        #[builder(required, builder_method)]
+                        id: u64,
+                    }
+                },
+                "field `id`: `builder_method` cannot be used with `#[builder(nestable)]`",
+            ),
+            (
+                parse_quote! {
+                    #[builder(nestable)]
+                    struct Example {
+                        #[builder(range = 1..=10)]
+                        retries: u8,
+                    }
+                },
+                "field `retries`: `range` cannot be used with `#[builder(nestable)]`",
+            ),
+        ] {
+            let analysis = analyze_struct(&input).unwrap();
+            let err = StructValidator::new(&mut context)
+                .validate_struct_for_generation(&analysis)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(error), "{err}");
+        }
+
+        // Checks in the setter leave the build infallible
+        let input = parse_quote! {
+            #[builder(nestable)]
+            struct Example {
+                #[builder(range = 1..=10, validate_on_set)]
+                retries: u8,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        assert!(StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_buildable_with_builder_method_fails() {
+        let input = parse_quote! {
+            #[builder(buildable = "Buildable")]
+            struct Example {
+                #[builder(required, builder_method)]
+                id: u64,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let mut context = ValidationContext::new();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`builder_method` cannot be used with `#[builder(buildable)]`"));
+    }
+
+    #[test]
+    fn test_build_by_clone_with_lazy_setter_fails() {
+        let input = parse_quote! {
+            #[builder(build_by = "clone")]
+            struct Example {
+                #[builder(lazy_setter)]
+                label: Option<String>,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let mut context = ValidationContext::new();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`lazy_setter` cannot be used with `build_by = \"clone\"`"));
+    }
+
+    #[test]
+    fn test_setter_prefix_exceptions_must_name_fields() {
+        let input = parse_quote! {
+            #[builder(setter_prefix = "with_", setter_prefix(except(id, naem)))]
+            struct Example {
+                id: u64,
+                name: String,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let mut context = ValidationContext::new();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("setter_prefix(except(...)) names unknown field `naem`"));
+    }
+
+    #[test]
+    fn test_derive_clone_with_lazy_setter_fails() {
+        let input = parse_quote! {
+            #[builder(derive(Clone))]
+            struct Example {
+                #[builder(lazy_setter)]
+                label: Option<String>,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let mut context = ValidationContext::new();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`lazy_setter` cannot be used with `derive(Clone)`"));
+    }
+
+    #[test]
+    fn test_serde_derive_with_lazy_setter_fails() {
+        // The parser only accepts serde derives with the `serde` feature
+        if !cfg!(feature = "serde") {
+            return;
+        }
+        let input = parse_quote! {
+            #[builder(derive(Serialize, Deserialize))]
+            struct Example {
+                #[builder(lazy_setter)]
+                label: Option<String>,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let mut context = ValidationContext::new();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`lazy_setter` cannot be used with `derive(Serialize)`"));
+    }
+
+    #[test]
+    fn test_lite_with_build_time_attributes_fails() {
+        let input = parse_quote! {
+            #[builder(lite)]
+            struct Example {
+                #[builder(required)]
+                name: String,
+                #[builder(computed = |b| b.name.len())]
+                len: usize,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let mut context = ValidationContext::new();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("field `len`: `computed` cannot be used with `#[builder(lite)]`"));
+
+        let input = parse_quote! {
+            #[builder(lite)]
+            struct Example {
+                #[builder(required, converter = |value: &str| value.to_string())]
+                name: String,
+                #[builder(converter = |value: &str| value.to_string())]
+                label: String,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("field `name`: `converter` cannot be used"));
+    }
+
+    #[test]
+    fn test_const_builder_with_build_time_closures_fails() {
+        let input = parse_quote! {
+            #[builder(const)]
+            struct Example {
+                #[builder(required, lazy_setter)]
+                count: u32,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let mut context = ValidationContext::new();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`lazy_setter` cannot be used with `#[builder(const)]`"));
+
+        let input = parse_quote! {
+            #[builder(const)]
+            struct Rect {
+                width: u32,
+                #[builder(default_from = |b| b.width)]
+                height: u32,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`default_from` cannot be used with `#[builder(const)]`"));
+
+        let input = parse_quote! {
+            #[builder(const)]
+            struct Rect {
+                width: u32,
+                #[builder(computed = |b| b.width * 2)]
+                perimeter: u32,
+            }
+        };
+        let analysis = analyze_struct(&input).unwrap();
+        let err = StructValidator::new(&mut context)
+            .validate_struct_for_generation(&analysis)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`computed` cannot be used with `#[builder(const)]`"));
+    }
 }
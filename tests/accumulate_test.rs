@@ -0,0 +1,101 @@
+use std::collections::BTreeMap;
+use type_state_builder::{type_state_builder, TypeStateBuilder};
+
+// =============================================================================
+// Accumulating setters on the type-state builder
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+pub struct Command {
+    #[builder(required)]
+    program: String,
+    #[builder(setter_name = "arg", accumulate = |args, arg: &str| args.push(arg.to_string()))]
+    args: Vec<String>,
+    #[builder(setter_name = "env", accumulate = |env: &mut BTreeMap<String, String>, (key, value): (&str, &str)| {
+        env.insert(key.to_string(), value.to_string());
+    })]
+    env: BTreeMap<String, String>,
+}
+
+#[test]
+fn test_accumulate_collects_every_call() {
+    let command = Command::builder()
+        .arg("-v")
+        .program("ls".to_string())
+        .arg("-l")
+        .env(("LANG", "C"))
+        .arg("/tmp")
+        .build();
+
+    assert_eq!(command.program, "ls");
+    assert_eq!(command.args, ["-v", "-l", "/tmp"]);
+    assert_eq!(command.env.get("LANG").map(String::as_str), Some("C"));
+}
+
+#[test]
+fn test_accumulate_starts_from_default() {
+    #[derive(TypeStateBuilder)]
+    struct Totals {
+        #[builder(default = 100, accumulate = |total, amount: u32| *total += amount)]
+        total: u32,
+    }
+
+    assert_eq!(Totals::builder().build().total, 100);
+    assert_eq!(Totals::builder().total(5).total(7).build().total, 112);
+}
+
+// =============================================================================
+// Accumulating setters on the regular builder and in lite mode
+// =============================================================================
+
+#[test]
+fn test_accumulate_without_required_fields() {
+    #[derive(TypeStateBuilder)]
+    struct Tags {
+        #[builder(setter_name = "tag", accumulate = |tags, tag: &str| tags.push(tag.to_string()))]
+        tags: Vec<String>,
+    }
+
+    let tags = Tags::builder().tag("a").tag("b").build();
+    assert_eq!(tags.tags, ["a", "b"]);
+}
+
+#[test]
+fn test_accumulate_in_lite_mode() {
+    #[derive(TypeStateBuilder)]
+    #[builder(lite)]
+    struct Query {
+        #[builder(required)]
+        table: String,
+        #[builder(accumulate = |filters, filter: &str| filters.push(filter.to_string()))]
+        filters: Vec<String>,
+    }
+
+    let query = Query::new("users".to_string())
+        .with_filters("age > 18")
+        .with_filters("active");
+    assert_eq!(query.table, "users");
+    assert_eq!(query.filters, ["age > 18", "active"]);
+}
+
+// =============================================================================
+// Builder-only accumulators
+// =============================================================================
+
+#[type_state_builder]
+pub struct Digest {
+    #[builder(setter_name = "chunk", builder_only, accumulate = |bytes, chunk: &[u8]| bytes.extend_from_slice(chunk))]
+    bytes: Vec<u8>,
+    #[builder(computed = |b| b.bytes.len())]
+    len: usize,
+    #[builder(computed = |b| b.bytes.iter().map(|&byte| u32::from(byte)).sum())]
+    checksum: u32,
+}
+
+#[test]
+fn test_builder_only_accumulator_feeds_computed_fields() {
+    let digest = Digest::builder().chunk(&[1, 2]).chunk(&[3]).build();
+
+    assert_eq!(digest.len, 3);
+    assert_eq!(digest.checksum, 6);
+}
@@ -0,0 +1,84 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(all_required)]
+struct Invoice {
+    number: u64,
+    customer: String,
+    currency: String,
+    #[builder(default = 30)]
+    payment_terms_days: u32,
+    #[builder(optional)]
+    notes: Option<String>,
+    #[builder(skip_setter, default = 1)]
+    revision: u32,
+}
+
+#[test]
+fn test_all_required_fields_must_be_set() {
+    let invoice = Invoice::builder()
+        .number(42)
+        .customer("ACME".to_string())
+        .currency("EUR".to_string())
+        .build();
+
+    assert_eq!(invoice.number, 42);
+    assert_eq!(invoice.payment_terms_days, 30);
+    assert_eq!(invoice.notes, None);
+    assert_eq!(invoice.revision, 1);
+}
+
+#[test]
+fn test_opted_out_fields_keep_optional_setters() {
+    let invoice = Invoice::builder()
+        .currency("USD".to_string())
+        .notes(Some("rush".to_string()))
+        .payment_terms_days(14)
+        .customer("Globex".to_string())
+        .number(7)
+        .build();
+
+    assert_eq!(invoice.payment_terms_days, 14);
+    assert_eq!(invoice.notes, Some("rush".to_string()));
+}
+
+#[test]
+fn test_all_required_with_other_struct_attributes() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(all_required, impl_into, build_method = "create", lints = "allow")]
+    struct Account {
+        id: u64,
+        owner: String,
+        #[builder(required, transparent)]
+        parent: Option<u64>,
+    }
+
+    let account = Account::builder()
+        .id(1u8)
+        .owner("alice")
+        .parent(None)
+        .create();
+
+    assert_eq!(account.owner, "alice");
+    assert_eq!(account.parent, None);
+}
+
+#[test]
+fn test_accumulating_fields_stay_optional() {
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(all_required)]
+    struct Order {
+        id: u64,
+        #[builder(accumulate = |lines, line: &str| lines.push(line.to_string()))]
+        lines: Vec<String>,
+    }
+
+    let empty = Order::builder().id(1).build();
+    assert!(empty.lines.is_empty());
+
+    let order = Order::builder().lines("a").id(2).lines("b").build();
+    assert_eq!(
+        (order.id, order.lines),
+        (2, vec!["a".to_string(), "b".to_string()])
+    );
+}
@@ -0,0 +1,317 @@
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Setting fields from runtime data
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(also_dynamic)]
+pub struct PluginConfig {
+    #[builder(required)]
+    name: String,
+    #[builder(required)]
+    version: u32,
+    #[builder(default = 4)]
+    threads: usize,
+    tags: Vec<String>,
+}
+
+/// Applies `key=value` lines the way a plugin manifest loader would.
+fn load(manifest: &str) -> Result<PluginConfig, String> {
+    let mut builder = PluginConfig::dyn_builder();
+    for line in manifest.lines() {
+        let (key, value) = line.split_once('=').ok_or("missing `=`")?;
+        builder = match key {
+            "name" => builder.name(value.to_string()),
+            "version" => builder.version(value.parse().map_err(|_| "bad version")?),
+            "threads" => builder.threads(value.parse().map_err(|_| "bad threads")?),
+            "tags" => builder.tags(value.split(',').map(str::to_string).collect()),
+            _ => return Err(format!("unknown key `{key}`")),
+        };
+    }
+    builder.build().map_err(|error| error.to_string())
+}
+
+#[test]
+fn test_fields_set_in_any_order() {
+    let config = load("tags=a,b\nversion=2\nname=search").unwrap();
+    assert_eq!(
+        config,
+        PluginConfig {
+            name: "search".to_string(),
+            version: 2,
+            threads: 4,
+            tags: vec!["a".to_string(), "b".to_string()],
+        }
+    );
+}
+
+#[test]
+fn test_unset_required_field_is_an_error() {
+    let builder = PluginConfig::dyn_builder().name("search".to_string());
+    let (error, line) = (builder.build().unwrap_err(), line!());
+    assert_eq!(
+        (error.field, error.message),
+        ("version", "required field is not set")
+    );
+    assert!(load("name=search")
+        .unwrap_err()
+        .starts_with("field `version`: required field is not set (at "));
+
+    // The error points at the call of `build`, not into generated code
+    assert_eq!(error.location.file(), file!());
+    assert_eq!(error.location.line(), line);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_error_converts_into_boxed_error() {
+    fn load_boxed(manifest: &str) -> Result<PluginConfig, Box<dyn std::error::Error>> {
+        let mut builder = PluginConfig::dyn_builder();
+        for line in manifest.lines() {
+            if let Some(name) = line.strip_prefix("name=") {
+                builder = builder.name(name.to_string());
+            }
+        }
+        Ok(builder.version(1).build()?)
+    }
+
+    assert_eq!(load_boxed("name=search").unwrap().name, "search");
+    let error = load_boxed("").unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("field `name`: required field is not set (at tests/also_dynamic_test.rs:"));
+}
+
+#[test]
+fn test_typestate_builder_is_unchanged() {
+    let config = PluginConfig::builder()
+        .name("search".to_string())
+        .version(1)
+        .threads(8)
+        .build();
+    assert_eq!(config.threads, 8);
+
+    let dynamic = PluginConfigDynBuilder::default()
+        .threads(8)
+        .version(1)
+        .name("search".to_string())
+        .build()
+        .unwrap();
+    assert_eq!(dynamic, config);
+}
+
+// =============================================================================
+// Setter semantics carry over
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(also_dynamic, setter_prefix = "with_")]
+pub struct Server {
+    #[builder(required, converter = |port: u32| port as u16)]
+    port: u16,
+    #[builder(setter_input = u64, via = TryFrom)]
+    backlog: u8,
+    #[builder(clone_from_ref)]
+    host: String,
+    #[builder(validate = |retries: &u8| *retries < 10, validate_message = "too many")]
+    retries: u8,
+}
+
+#[test]
+fn test_converters_and_checks_run_when_building() {
+    let server = Server::dyn_builder()
+        .with_host("localhost")
+        .with_port(8080)
+        .with_backlog(16)
+        .build()
+        .unwrap();
+    assert_eq!(
+        server,
+        Server {
+            port: 8080,
+            backlog: 16,
+            host: "localhost".to_string(),
+            retries: 0,
+        }
+    );
+
+    let error = Server::dyn_builder()
+        .with_port(80)
+        .with_backlog(1000)
+        .build()
+        .unwrap_err();
+    assert_eq!(error.field, "backlog");
+
+    let error = Server::dyn_builder()
+        .with_port(80)
+        .with_retries(20)
+        .build()
+        .unwrap_err();
+    assert_eq!((error.field, error.message), ("retries", "too many"));
+}
+
+#[test]
+fn test_generic_struct_and_builder_method() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(also_dynamic)]
+    struct Entry<'a, T> {
+        #[builder(required, builder_method)]
+        key: &'a str,
+        #[builder(required)]
+        value: T,
+        #[builder(skip_setter)]
+        hits: u32,
+    }
+
+    let entry = Entry::dyn_builder().value(3).key("id").build().unwrap();
+    assert_eq!(
+        entry,
+        Entry {
+            key: "id",
+            value: 3,
+            hits: 0,
+        }
+    );
+    assert_eq!(
+        Entry::<u8>::dyn_builder()
+            .value(1)
+            .build()
+            .unwrap_err()
+            .field,
+        "key"
+    );
+}
+
+#[derive(TypeStateBuilder, Debug, Clone, PartialEq)]
+#[builder(also_dynamic, option_into, into_iter)]
+pub struct Profile {
+    #[builder(required, impl_into)]
+    name: String,
+    #[builder(required, clone_from_ref)]
+    bio: String,
+    #[builder(clone_from_ref)]
+    roles: Vec<String>,
+    tags: Vec<String>,
+    nickname: Option<String>,
+    #[builder(impl_into, converter = |age: u32| age.min(150) as u8)]
+    age: u8,
+}
+
+#[test]
+fn test_setters_take_what_the_typestate_setters_take() {
+    let roles = vec!["admin".to_string()];
+    let typestate = Profile::builder()
+        .name("ada")
+        .bio("Mathematician")
+        .roles(&roles)
+        .tags(["math", "poetry"])
+        .nickname("countess".to_string())
+        .age(36u8)
+        .build();
+    let dynamic = Profile::dyn_builder()
+        .name("ada")
+        .bio("Mathematician")
+        .roles(&roles)
+        .tags(["math", "poetry"])
+        .nickname("countess".to_string())
+        .age(36u8)
+        .build()
+        .unwrap();
+
+    assert_eq!(dynamic, typestate);
+    assert_eq!(
+        dynamic,
+        Profile {
+            name: "ada".to_string(),
+            bio: "Mathematician".to_string(),
+            roles: vec!["admin".to_string()],
+            tags: vec!["math".to_string(), "poetry".to_string()],
+            nickname: Some("countess".to_string()),
+            age: 36,
+        }
+    );
+}
+
+// =============================================================================
+// Reusing a builder
+// =============================================================================
+
+#[test]
+fn test_reset_unsets_every_field() {
+    let roles = vec!["admin".to_string()];
+    let mut builder = Profile::dyn_builder()
+        .name("ada")
+        .bio("Mathematician")
+        .roles(&roles)
+        .tags(["math"])
+        .nickname("countess".to_string())
+        .age(36u8);
+    builder.reset();
+
+    let profile = builder.name("grace").bio("Admiral").build().unwrap();
+    assert_eq!(
+        profile,
+        Profile {
+            name: "grace".to_string(),
+            bio: "Admiral".to_string(),
+            roles: Vec::new(),
+            tags: Vec::new(),
+            nickname: None,
+            age: 0,
+        }
+    );
+
+    let mut builder = Profile::dyn_builder().name("ada").bio("Mathematician");
+    builder.reset();
+    assert_eq!(builder.build().unwrap_err().field, "name");
+}
+
+// =============================================================================
+// Returning to the typestate builder
+// =============================================================================
+
+#[test]
+fn test_into_builder_returns_the_complete_state() {
+    let builder = PluginConfig::dyn_builder()
+        .version(3)
+        .name("search".to_string())
+        .into_builder()
+        .unwrap();
+    let config = builder.tags(vec!["fast".to_string()]).build();
+    assert_eq!(
+        config,
+        PluginConfig {
+            name: "search".to_string(),
+            version: 3,
+            threads: 4,
+            tags: vec!["fast".to_string()],
+        }
+    );
+
+    let error = PluginConfig::dyn_builder().version(3).into_builder();
+    assert_eq!(error.err().map(|error| error.field), Some("name"));
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(also_dynamic, hide_states, module = "job_builder")]
+pub struct Job {
+    #[builder(required)]
+    id: u32,
+    retries: u8,
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(also_dynamic)]
+pub struct Limits {
+    max: Option<u32>,
+}
+
+#[test]
+fn test_into_builder_names_hidden_and_module_states() {
+    let job = Job::dyn_builder().id(7).into_builder().unwrap().retries(2);
+    assert_eq!(job.build(), Job { id: 7, retries: 2 });
+
+    let limits: LimitsBuilder = Limits::dyn_builder().into_builder().unwrap();
+    assert_eq!(limits.max(Some(5)).build(), Limits { max: Some(5) });
+}
@@ -0,0 +1,113 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+pub struct Request {
+    #[builder(required, setter(also_ref))]
+    url: String,
+    #[builder(setter(also_ref))]
+    body: Vec<u8>,
+    #[builder(default = 30)]
+    timeout_secs: u64,
+}
+
+// =============================================================================
+// By-reference setters on required and optional fields
+// =============================================================================
+
+#[test]
+fn test_ref_setters_clone_the_value() {
+    let url = "https://example.com".to_string();
+    let body = vec![0_u8; 1024];
+
+    let request = Request::builder().url_ref(&url).body_ref(&body).build();
+
+    assert_eq!(
+        request,
+        Request {
+            url: url.clone(),
+            body: body.clone(),
+            timeout_secs: 30
+        }
+    );
+    // The caller keeps both values
+    assert_eq!((url.len(), body.len()), (19, 1024));
+}
+
+#[test]
+fn test_ref_setters_reuse_payload_in_loop() {
+    let payload = vec![7_u8; 4096];
+
+    let requests: Vec<Request> = (0..3)
+        .map(|id| {
+            Request::builder()
+                .body_ref(&payload)
+                .url(format!("https://example.com/{id}"))
+                .build()
+        })
+        .collect();
+
+    assert!(requests.iter().all(|request| request.body == payload));
+    assert_eq!(requests[2].url, "https://example.com/2");
+}
+
+#[test]
+fn test_by_value_setters_are_kept() {
+    let request = Request::builder()
+        .url("https://example.com".to_string())
+        .body(vec![1, 2])
+        .build();
+    assert_eq!(request.body, [1, 2]);
+}
+
+// =============================================================================
+// Conversions, generics and the regular builder
+// =============================================================================
+
+#[test]
+fn test_ref_setter_with_impl_into_and_prefix() {
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(impl_into, setter_prefix = "with_")]
+    struct Profile {
+        #[builder(required, setter(also_ref))]
+        name: String,
+        #[builder(setter(also_ref))]
+        tags: Option<Vec<String>>,
+    }
+
+    let tags = Some(vec!["admin".to_string()]);
+    let profile = Profile::builder()
+        .with_name_ref(&"ada".to_string())
+        .with_tags_ref(&tags)
+        .build();
+
+    assert_eq!(profile.name, "ada");
+    assert_eq!(profile.tags, tags);
+}
+
+#[test]
+fn test_ref_setter_on_generic_field() {
+    #[derive(TypeStateBuilder, Debug)]
+    struct Batch<T: Clone> {
+        #[builder(required, setter(also_ref))]
+        items: Vec<T>,
+    }
+
+    let items = vec!['a', 'b'];
+    let batch = Batch::builder().items_ref(&items).build();
+    assert_eq!(batch.items, items);
+}
+
+#[test]
+fn test_ref_setter_on_regular_builder() {
+    #[derive(TypeStateBuilder, Debug)]
+    struct Template {
+        #[builder(setter(also_ref))]
+        source: String,
+    }
+
+    let source = "{{ name }}".to_string();
+    assert_eq!(
+        Template::builder().source_ref(&source).build().source,
+        source
+    );
+}
@@ -0,0 +1,152 @@
+//! Tests for `#[builder(apply_env)]`, which sets optional fields from environment variables.
+#![cfg(feature = "env")]
+
+use std::env;
+use type_state_builder::TypeStateBuilder;
+
+// Every test uses its own prefix, as the tests share the process environment
+
+// =============================================================================
+// Reading optional fields from the environment
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(apply_env)]
+pub struct ServerConfig {
+    #[builder(required)]
+    name: String,
+    host: Option<String>,
+    #[builder(default = 8080)]
+    port: u16,
+    verbose: bool,
+    #[builder(skip_env)]
+    tags: Vec<String>,
+}
+
+#[test]
+fn test_set_variables_are_parsed_into_the_fields() {
+    env::set_var("PARSED_HOST", "example.com");
+    env::set_var("PARSED_PORT", "9000");
+
+    let config = ServerConfig::builder()
+        .name("api".to_string())
+        .verbose(true)
+        .apply_env("PARSED")
+        .unwrap()
+        .build();
+
+    assert_eq!(
+        config,
+        ServerConfig {
+            name: "api".to_string(),
+            host: Some("example.com".to_string()),
+            port: 9000,
+            verbose: true,
+            tags: Vec::new(),
+        }
+    );
+}
+
+#[test]
+fn test_setters_called_afterwards_override_the_environment() {
+    env::set_var("LAYERED_PORT", "9000");
+    env::set_var("LAYERED_VERBOSE", "true");
+
+    let config = ServerConfig::builder()
+        .apply_env("LAYERED")
+        .unwrap()
+        .port(7000)
+        .name("api".to_string())
+        .build();
+
+    assert_eq!((config.port, config.verbose), (7000, true));
+}
+
+#[test]
+fn test_invalid_value_names_the_variable() {
+    env::set_var("INVALID_PORT", "eighty");
+
+    let error = ServerConfig::builder().apply_env("INVALID").unwrap_err();
+    assert_eq!(error.variable, "INVALID_PORT");
+    assert_eq!(
+        error.to_string(),
+        "invalid value in environment variable `INVALID_PORT`: invalid digit found in string"
+    );
+}
+
+#[test]
+fn test_error_converts_into_boxed_error() {
+    fn load(prefix: &str) -> Result<ServerConfig, Box<dyn std::error::Error>> {
+        Ok(ServerConfig::builder()
+            .apply_env(prefix)?
+            .name("app".to_string())
+            .build())
+    }
+
+    env::set_var("BOXED_PORT", "eighty");
+    assert!(load("BOXED")
+        .unwrap_err()
+        .to_string()
+        .starts_with("invalid value in environment variable `BOXED_PORT`"));
+}
+
+// =============================================================================
+// Converters and other setters
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(apply_env, setter_prefix = "with_")]
+pub struct Worker {
+    #[builder(converter = |list: &str| list.split(',').map(str::to_string).collect())]
+    queues: Vec<String>,
+    #[builder(range = 1..=64, validate_on_set)]
+    threads: u8,
+}
+
+#[test]
+fn test_converter_input_and_setter_validation() {
+    env::set_var("WORKER_QUEUES", "mail,jobs");
+    env::set_var("WORKER_THREADS", "8");
+
+    let worker = Worker::builder().apply_env("WORKER").unwrap().build();
+    assert_eq!(worker.queues, ["mail", "jobs"]);
+    assert_eq!(worker.threads, 8);
+
+    env::set_var("REJECTED_THREADS", "100");
+    let error = Worker::builder().apply_env("REJECTED").unwrap_err();
+    assert_eq!(error.variable, "REJECTED_THREADS");
+}
+
+#[test]
+fn test_generic_field_types() {
+    #[derive(TypeStateBuilder)]
+    #[builder(apply_env)]
+    struct Limit<T> {
+        max: Option<T>,
+    }
+
+    env::set_var("GENERIC_MAX", "42");
+    let limit = Limit::<u64>::builder()
+        .apply_env("GENERIC")
+        .unwrap()
+        .build();
+    assert_eq!(limit.max, Some(42));
+}
+
+#[test]
+fn test_setter_input_is_parsed_before_try_from() {
+    #[derive(TypeStateBuilder)]
+    #[builder(apply_env)]
+    struct Hops {
+        #[builder(setter_input = u64, via = TryFrom)]
+        limit: u8,
+    }
+
+    env::set_var("HOPS_LIMIT", "12");
+    let hops = Hops::builder().apply_env("HOPS").unwrap().build();
+    assert_eq!(hops.limit, 12);
+
+    env::set_var("OVERFLOW_LIMIT", "1000");
+    let error = Hops::builder().apply_env("OVERFLOW").unwrap_err();
+    assert_eq!(error.variable, "OVERFLOW_LIMIT");
+}
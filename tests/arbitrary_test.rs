@@ -0,0 +1,98 @@
+//! Tests for `#[builder(arbitrary)]`, which implements `arbitrary::Arbitrary` through the builder.
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(arbitrary)]
+struct Config {
+    #[builder(required)]
+    host: String,
+    #[builder(default = 8080)]
+    port: u16,
+    #[builder(converter = |level: u8| level.min(9) as u32)]
+    compression: u32,
+    #[builder(skip_setter, default = "v1".to_string())]
+    version: String,
+}
+
+/// Builds a value from every window of a fixed pseudo-random byte sequence.
+fn samples<T: for<'a> Arbitrary<'a>>() -> Vec<T> {
+    let bytes: Vec<u8> = (0..512u32)
+        .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+        .collect();
+    (0..64)
+        .map(|start| T::arbitrary(&mut Unstructured::new(&bytes[start..])).unwrap())
+        .collect()
+}
+
+// =============================================================================
+// Values go through setters and defaults
+// =============================================================================
+
+#[test]
+fn test_arbitrary_values_respect_converters_and_defaults() {
+    let configs = samples::<Config>();
+
+    for config in &configs {
+        assert!(config.compression <= 9, "{config:?}");
+        assert_eq!(config.version, "v1");
+    }
+    // Optional fields are sometimes left at their defaults
+    assert!(configs.iter().any(|config| config.port == 8080));
+    assert!(configs.iter().any(|config| config.port != 8080));
+}
+
+#[test]
+fn test_arbitrary_from_empty_input_uses_defaults() {
+    let config = Config::arbitrary(&mut Unstructured::new(&[])).unwrap();
+
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.compression, 0);
+    assert_eq!(config.version, "v1");
+}
+
+// =============================================================================
+// Other builder shapes
+// =============================================================================
+
+#[test]
+fn test_arbitrary_with_generics_and_regular_builder() {
+    #[derive(TypeStateBuilder, Debug)]
+    #[allow(dead_code)]
+    #[builder(arbitrary, build_method = "finish")]
+    struct Range<T: Default> {
+        start: T,
+        end: Option<T>,
+    }
+
+    let ranges = samples::<Range<i64>>();
+    assert!(ranges.iter().any(|range| range.end.is_some()));
+}
+
+#[test]
+fn test_arbitrary_with_builder_method_and_lite_mode() {
+    #[derive(TypeStateBuilder, Debug)]
+    #[allow(dead_code)]
+    #[builder(arbitrary)]
+    struct Query {
+        #[builder(required, builder_method)]
+        table: String,
+        #[builder(required)]
+        limit: u32,
+    }
+
+    #[derive(TypeStateBuilder, Debug)]
+    #[allow(dead_code)]
+    #[builder(arbitrary, lite)]
+    struct Point {
+        #[builder(required)]
+        x: i32,
+        #[builder(default = 1)]
+        scale: i32,
+    }
+
+    assert_eq!(samples::<Query>().len(), 64);
+    assert!(samples::<Point>().iter().any(|point| point.scale == 1));
+}
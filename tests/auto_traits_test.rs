@@ -0,0 +1,147 @@
+use std::sync::Arc;
+use type_state_builder::TypeStateBuilder;
+
+fn assert_send<T: Send>(_: &T) {}
+fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+// =============================================================================
+// Static assertions on every builder state
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(assert_send_sync)]
+struct Job {
+    #[builder(required)]
+    name: String,
+    #[builder(required)]
+    payload: Arc<Vec<u8>>,
+    retries: u32,
+}
+
+#[test]
+fn test_assert_send_sync_type_state_builder() {
+    let builder = Job::builder().name("sync".to_string());
+    assert_send_sync(&builder);
+
+    // Builders can move across threads between setter calls
+    let job = std::thread::spawn(move || builder.payload(Arc::new(vec![1, 2, 3])).build())
+        .join()
+        .unwrap();
+
+    assert_eq!(job.name, "sync");
+    assert_eq!(*job.payload, vec![1, 2, 3]);
+    assert_eq!(job.retries, 0);
+}
+
+#[test]
+fn test_assert_send_regular_builder() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(assert_send)]
+    struct Options {
+        // Send but not Sync
+        slot: std::cell::Cell<u8>,
+        verbose: bool,
+    }
+
+    let builder = Options::builder().verbose(true);
+    assert_send(&builder);
+
+    let options = std::thread::spawn(move || builder.build()).join().unwrap();
+    assert!(options.verbose);
+}
+
+#[test]
+fn test_assert_send_sync_with_generics() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(assert_send_sync)]
+    struct Pair<'a, T: Clone, const N: usize> {
+        #[builder(required)]
+        left: &'a T,
+        #[builder(required)]
+        right: [T; N],
+    }
+
+    let value = 1u8;
+    let pair = Pair::builder().left(&value).right([2u8; 2]).build();
+    assert_eq!(*pair.left, 1);
+    assert_eq!(pair.right, [2, 2]);
+}
+
+#[test]
+fn test_assert_send_sync_with_const_builder() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(const, assert_send_sync)]
+    struct Limits {
+        #[builder(required)]
+        max: u32,
+        #[builder(default = 0)]
+        min: u32,
+    }
+
+    const LIMITS: Limits = Limits::builder().max(10).build();
+    assert_eq!(LIMITS.max, 10);
+    assert_eq!(LIMITS.min, 0);
+}
+
+#[test]
+fn test_assert_send_sync_with_where_clause() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(assert_send_sync)]
+    struct Tagged<T>
+    where
+        T: Clone,
+    {
+        #[builder(required)]
+        tag: T,
+    }
+
+    let tagged = Tagged::builder().tag("a").build();
+    assert_eq!(tagged.tag, "a");
+}
+
+// =============================================================================
+// Listing the auto traits with static_assert
+// =============================================================================
+
+#[test]
+fn test_static_assert_listed_traits() {
+    fn assert_all<T: Send + Sync + Unpin>(_: &T) {}
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(static_assert(Send, Sync, Unpin))]
+    struct Request<T> {
+        #[builder(required)]
+        path: String,
+        #[builder(required)]
+        body: T,
+        headers: Vec<(String, String)>,
+    }
+
+    let builder = Request::builder().path("/".to_string());
+    assert_all(&builder);
+
+    let request = std::thread::spawn(move || builder.body(vec![0u8]).build())
+        .join()
+        .unwrap();
+    assert_eq!(request.path, "/");
+    assert!(request.headers.is_empty());
+}
+
+#[test]
+fn test_static_assert_sync_without_send() {
+    fn assert_sync<T: Sync>(_: &T) {}
+
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(static_assert(Sync))]
+    struct Guarded {
+        // Sync but not Send
+        #[builder(required)]
+        guard: std::sync::MutexGuard<'static, ()>,
+    }
+
+    static LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+    let builder = Guarded::builder().guard(LOCK.lock().unwrap());
+    assert_sync(&builder);
+    let guarded = builder.build();
+    assert_eq!(*guarded.guard, ());
+}
@@ -0,0 +1,133 @@
+use std::cell::Cell;
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Unset fields taken from a base evaluated at build time
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, Clone, PartialEq)]
+#[builder(base = "Server::defaults()")]
+pub struct Server {
+    #[builder(required)]
+    name: String,
+    host: String,
+    port: u16,
+    #[builder(default = 30)]
+    timeout: u32,
+    #[builder(skip_setter)]
+    tags: Vec<String>,
+}
+
+thread_local! {
+    static DEFAULT_PORT: Cell<u16> = const { Cell::new(8080) };
+}
+
+impl Server {
+    fn defaults() -> Self {
+        Server {
+            name: "defaults".to_string(),
+            host: "localhost".to_string(),
+            port: DEFAULT_PORT.with(Cell::get),
+            timeout: 5,
+            tags: vec!["base".to_string()],
+        }
+    }
+}
+
+#[test]
+fn test_unset_fields_come_from_the_base() {
+    let server = Server::builder().name("api".to_string()).build();
+
+    assert_eq!(
+        server,
+        Server {
+            name: "api".to_string(),
+            host: "localhost".to_string(),
+            port: 8080,
+            timeout: 30,
+            tags: vec!["base".to_string()],
+        }
+    );
+}
+
+#[test]
+fn test_set_fields_override_the_base() {
+    let server = Server::builder().name("api".to_string()).port(9000).build();
+
+    assert_eq!(server.host, "localhost");
+    assert_eq!(server.port, 9000);
+}
+
+#[test]
+fn test_base_is_evaluated_at_build_time() {
+    let builder = Server::builder().name("api".to_string());
+    DEFAULT_PORT.with(|port| port.set(7000));
+    let server = builder.build();
+    DEFAULT_PORT.with(|port| port.set(8080));
+
+    assert_eq!(server.port, 7000);
+}
+
+// =============================================================================
+// Layering over an existing value
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(base = Self::default())]
+pub struct Limits<T: Default> {
+    max: T,
+    enabled: bool,
+}
+
+impl<T: Default> Default for Limits<T> {
+    fn default() -> Self {
+        Limits {
+            max: T::default(),
+            enabled: true,
+        }
+    }
+}
+
+#[test]
+fn test_regular_builder_with_generic_base() {
+    let limits = Limits::<u8>::builder().build();
+    assert_eq!(limits.max, 0);
+    assert!(limits.enabled);
+
+    let limits = Limits::builder().max(64u32).enabled(false).build();
+    assert_eq!(limits.max, 64);
+    assert!(!limits.enabled);
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(
+    module = "settings_builder",
+    base = "Settings::base()",
+    build_by = "clone"
+)]
+pub struct Settings {
+    pub verbose: bool,
+    pub level: u8,
+}
+
+impl Settings {
+    fn base() -> Self {
+        Settings {
+            verbose: true,
+            level: 3,
+        }
+    }
+}
+
+#[test]
+fn test_reusable_builder_with_base() {
+    let builder = Settings::builder().level(1);
+    assert_eq!(
+        builder.build(),
+        Settings {
+            verbose: true,
+            level: 1,
+        }
+    );
+    assert_eq!(builder.build().level, 1);
+}
@@ -0,0 +1,113 @@
+use type_state_builder::TypeStateBuilder;
+
+const FRAME_LEN: usize = 256 * 1024;
+
+// =============================================================================
+// Large fields kept on the heap between states
+// =============================================================================
+
+#[derive(TypeStateBuilder)]
+pub struct Frame {
+    #[builder(required)]
+    id: u32,
+    #[builder(required, boxed_field)]
+    pixels: [u8; FRAME_LEN],
+    #[builder(boxed_field, default = [0; FRAME_LEN])]
+    mask: [u8; FRAME_LEN],
+    label: Option<String>,
+}
+
+#[test]
+fn test_build_returns_the_struct_unchanged() {
+    let frame: Frame = Frame::builder()
+        .pixels([7; FRAME_LEN])
+        .id(1)
+        .label(Some("first".to_string()))
+        .build();
+
+    assert_eq!(frame.id, 1);
+    assert_eq!((frame.pixels[0], frame.pixels[FRAME_LEN - 1]), (7, 7));
+    assert_eq!(frame.mask[FRAME_LEN - 1], 0);
+    assert_eq!(frame.label.as_deref(), Some("first"));
+}
+
+#[test]
+fn test_states_hold_pointers_to_boxed_fields() {
+    let start = Frame::builder();
+    assert!(std::mem::size_of_val(&start) < 64);
+
+    let builder = start.id(2).pixels([1; FRAME_LEN]);
+    assert!(std::mem::size_of_val(&builder) < 64);
+    assert_eq!(builder.build().pixels[0], 1);
+}
+
+#[test]
+fn test_build_fits_on_a_small_stack() {
+    // Without `boxed_field`, every state transition copies the arrays in debug builds
+    std::thread::Builder::new()
+        .stack_size(6 * FRAME_LEN)
+        .spawn(|| {
+            let frame = Frame::builder().id(3).pixels([9; FRAME_LEN]).build();
+            assert_eq!(frame.pixels[FRAME_LEN - 1], 9);
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+// =============================================================================
+// Other builder shapes and field options
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, Clone, PartialEq)]
+#[builder(fill_from, derive(Clone))]
+pub struct Settings {
+    #[builder(boxed_field, default = 8)]
+    threads: u32,
+    #[builder(boxed_field, accumulate = |tags: &mut Vec<String>, tag: &str| tags.push(tag.to_string()))]
+    tags: Vec<String>,
+    #[builder(boxed_field, default_from = |builder| builder.tags.first().cloned())]
+    primary: Option<String>,
+    #[builder(boxed_field)]
+    comment: Option<String>,
+}
+
+#[test]
+fn test_regular_builder_with_field_options() {
+    let builder = Settings::builder().tags("fast").tags("safe");
+    let settings = builder.clone().threads(4).build();
+    assert_eq!(
+        settings,
+        Settings {
+            threads: 4,
+            tags: vec!["fast".to_string(), "safe".to_string()],
+            primary: Some("fast".to_string()),
+            comment: None,
+        }
+    );
+
+    let filled = builder.fill_from(&settings).build();
+    assert_eq!((filled.threads, filled.tags.len()), (4, 2));
+}
+
+#[test]
+fn test_lazy_setter_and_debug_output() {
+    #[derive(TypeStateBuilder)]
+    struct Report {
+        #[builder(required, boxed_field, redact)]
+        secret: String,
+        #[builder(boxed_field, lazy_setter)]
+        body: String,
+    }
+
+    let builder = Report::builder()
+        .body_with(|| "text".to_string())
+        .secret("s".to_string());
+    assert!(format!("{builder:?}").contains("***"));
+
+    let report = builder.build();
+    assert_eq!(
+        (report.secret.as_str(), report.body.as_str()),
+        ("s", "text")
+    );
+}
@@ -0,0 +1,126 @@
+use type_state_builder::TypeStateBuilder;
+
+const FRAME_LEN: usize = 256 * 1024;
+
+// =============================================================================
+// Large structs built on the heap
+// =============================================================================
+
+#[derive(TypeStateBuilder)]
+#[builder(boxed)]
+pub struct Frame {
+    #[builder(required)]
+    id: u32,
+    #[builder(required)]
+    pixels: [u8; FRAME_LEN],
+    #[builder(default = [0; FRAME_LEN])]
+    mask: [u8; FRAME_LEN],
+    label: Option<String>,
+}
+
+#[test]
+fn test_build_returns_box() {
+    let frame: Box<Frame> = Frame::builder()
+        .pixels([7; FRAME_LEN])
+        .id(1)
+        .label(Some("first".to_string()))
+        .build();
+
+    assert_eq!(frame.id, 1);
+    assert_eq!((frame.pixels[0], frame.pixels[FRAME_LEN - 1]), (7, 7));
+    assert_eq!(frame.mask[FRAME_LEN - 1], 0);
+    assert_eq!(frame.label.as_deref(), Some("first"));
+}
+
+#[test]
+fn test_builder_moves_only_pointers_between_states() {
+    let builder = Frame::builder().id(2).pixels([1; FRAME_LEN]);
+    assert_eq!(
+        std::mem::size_of_val(&builder),
+        3 * std::mem::size_of::<Box<u8>>() + std::mem::size_of::<Box<Option<String>>>()
+    );
+
+    let frame: Box<Frame> = builder.into();
+    assert_eq!(frame.id, 2);
+}
+
+#[test]
+fn test_build_fits_on_a_small_stack() {
+    // Without `boxed`, every state transition copies the arrays in debug builds
+    std::thread::Builder::new()
+        .stack_size(6 * FRAME_LEN)
+        .spawn(|| {
+            let frame = Frame::builder().id(3).pixels([9; FRAME_LEN]).build();
+            assert_eq!(frame.pixels[FRAME_LEN - 1], 9);
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+// =============================================================================
+// Other builder shapes and field options
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(boxed)]
+pub struct Settings {
+    #[builder(default = 8)]
+    threads: u32,
+    #[builder(accumulate = |tags: &mut Vec<String>, tag: &str| tags.push(tag.to_string()))]
+    tags: Vec<String>,
+    #[builder(lazy_setter)]
+    name: String,
+    #[builder(computed = |builder| builder.tags.len())]
+    tag_count: usize,
+}
+
+#[test]
+fn test_regular_builder_with_field_options() {
+    let builder = Settings::builder()
+        .tags("fast")
+        .tags("safe")
+        .name_with(|| "worker".to_string());
+
+    let settings = builder.threads(4).build();
+    assert_eq!(
+        *settings,
+        Settings {
+            threads: 4,
+            tags: vec!["fast".to_string(), "safe".to_string()],
+            name: "worker".to_string(),
+            tag_count: 2,
+        }
+    );
+}
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(boxed)]
+pub struct Range {
+    #[builder(required, validate = |start: &u32| *start < 100)]
+    start: u32,
+    #[builder(required, resolve = |end: u32, start: &u32| end.max(*start))]
+    end: u32,
+}
+
+#[test]
+fn test_validated_and_resolved_fields() {
+    let range = Range::builder().start(10).end(5).build().unwrap();
+    assert_eq!((range.start, range.end), (10, 10));
+
+    let error = Range::builder().start(200).end(300).build().unwrap_err();
+    assert_eq!(error.field, "start");
+}
+
+#[test]
+fn test_build_into_converts_the_box() {
+    #[derive(TypeStateBuilder)]
+    #[builder(boxed)]
+    struct Point {
+        #[builder(required)]
+        x: i32,
+    }
+
+    let point: std::rc::Rc<Point> = Point::builder().x(3).build_into();
+    assert_eq!(point.x, 3);
+}
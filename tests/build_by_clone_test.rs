@@ -0,0 +1,68 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(build_by = "clone", state_traits)]
+struct Fixture {
+    #[builder(required)]
+    name: String,
+    #[builder(required)]
+    tags: Vec<String>,
+    #[builder(default = 1)]
+    revision: u32,
+    #[builder(computed = |b| b.tags.len())]
+    tag_count: usize,
+}
+
+// =============================================================================
+// Reusable builders
+// =============================================================================
+
+#[test]
+fn test_build_leaves_builder_reusable() {
+    let builder = Fixture::builder()
+        .name("base".to_string())
+        .tags(vec!["a".to_string(), "b".to_string()]);
+
+    let first = builder.build();
+    let second = builder.build();
+    assert_eq!(first, second);
+    assert_eq!(first.tag_count, 2);
+
+    let third = builder.revision(3).build();
+    assert_eq!(third.revision, 3);
+    assert_eq!(third.name, "base");
+}
+
+#[test]
+fn test_state_trait_and_from_use_borrowing_build() {
+    fn finish<B: FixtureBuilderFinish>(builder: B) -> B::Target {
+        builder.build()
+    }
+
+    let builder = Fixture::builder().tags(Vec::new()).name("x".to_string());
+    assert_eq!(finish(builder).revision, 1);
+
+    let fixture: Fixture = Fixture::builder()
+        .name("y".to_string())
+        .tags(Vec::new())
+        .into();
+    assert_eq!(fixture.name, "y");
+}
+
+#[test]
+fn test_build_by_clone_with_regular_builder_and_generics() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(build_by = "clone", build_method = "create")]
+    struct Settings<T> {
+        values: Vec<T>,
+        #[builder(default_from = |b| b.values.len() * 2)]
+        capacity: usize,
+    }
+
+    let builder = Settings::builder().values(vec![1u8, 2]);
+    let settings: Vec<Settings<u8>> = (0..3).map(|_| builder.create()).collect();
+
+    assert!(settings
+        .iter()
+        .all(|s| s.values == [1, 2] && s.capacity == 4));
+}
@@ -0,0 +1,103 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+struct Job {
+    #[builder(required)]
+    command: String,
+    #[builder(required)]
+    args: Vec<String>,
+    #[builder(default = 3)]
+    retries: u8,
+}
+
+// =============================================================================
+// Cloning finisher
+// =============================================================================
+
+#[test]
+fn test_build_clone_leaves_builder_intact() {
+    let template = Job::builder()
+        .command("cargo".to_string())
+        .args(vec!["test".to_string()]);
+
+    let first = template.build_clone();
+    let second = template.retries(1).build();
+
+    assert_eq!(first.retries, 3);
+    assert_eq!(second.retries, 1);
+    assert_eq!(first.command, second.command);
+}
+
+#[test]
+fn test_build_clone_with_non_clone_field_still_compiles() {
+    struct Handle;
+
+    #[derive(TypeStateBuilder)]
+    struct Connection {
+        #[builder(required)]
+        handle: Handle,
+        label: Option<String>,
+    }
+
+    // `build_clone` is unavailable here, but the consuming build still works
+    let connection = Connection::builder().handle(Handle).build();
+    assert!(connection.label.is_none());
+    let Handle = connection.handle;
+}
+
+#[test]
+fn test_build_clone_with_lifetimes() {
+    #[derive(TypeStateBuilder, Debug)]
+    struct Pair<'a, 'b> {
+        #[builder(required)]
+        first: &'a str,
+        #[builder(required)]
+        second: &'b str,
+        pair: Option<(&'a str, &'b str)>,
+        fallback: Option<&'static str>,
+    }
+
+    let first = "left".to_string();
+    let builder = Pair::builder().second("right").first(&first);
+
+    let pair = builder.build_clone();
+    assert_eq!(pair.first, "left");
+    assert_eq!(pair.second, "right");
+    assert_eq!(pair.pair, None);
+    assert_eq!(
+        builder.fallback(Some("none")).build().fallback,
+        Some("none")
+    );
+}
+
+// =============================================================================
+// Builder variants
+// =============================================================================
+
+#[test]
+fn test_build_clone_follows_build_method_name_and_regular_builder() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(build_method = "create")]
+    struct Settings<T: Clone> {
+        values: Vec<T>,
+        verbose: bool,
+    }
+
+    let builder = Settings::builder().values(vec![1, 2]);
+    assert_eq!(builder.create_clone(), builder.verbose(false).create());
+}
+
+#[test]
+fn test_build_clone_with_const_builder() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(const)]
+    struct Limits {
+        #[builder(required)]
+        max: u32,
+        #[builder(default = 0)]
+        min: u32,
+    }
+
+    const BUILDER: LimitsBuilder_HasMax = Limits::builder().max(10);
+    assert_eq!(BUILDER.build_clone(), BUILDER.build());
+}
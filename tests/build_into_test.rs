@@ -0,0 +1,72 @@
+use std::sync::Arc;
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Generic `build_into` finisher
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq, Clone)]
+pub struct Point {
+    #[builder(required)]
+    x: i32,
+    #[builder(required)]
+    y: i32,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Shape {
+    Dot(Point),
+    Empty,
+}
+
+impl From<Point> for Shape {
+    fn from(point: Point) -> Self {
+        Shape::Dot(point)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Origin(Point);
+
+impl From<Point> for Origin {
+    fn from(point: Point) -> Self {
+        Origin(point)
+    }
+}
+
+#[test]
+fn test_build_into_enum_and_newtype() {
+    let shape: Shape = Point::builder().x(1).y(2).build_into();
+    assert_eq!(shape, Shape::Dot(Point { x: 1, y: 2 }));
+    assert_ne!(shape, Shape::Empty);
+
+    let origin = Point::builder().y(0).x(0).build_into::<Origin>();
+    assert_eq!(origin, Origin(Point { x: 0, y: 0 }));
+}
+
+#[test]
+fn test_build_into_std_wrappers() {
+    let boxed: Box<Point> = Point::builder().x(3).y(4).build_into();
+    assert_eq!(*boxed, Point { x: 3, y: 4 });
+
+    let shared: Arc<Point> = Point::builder().x(5).y(6).build_into();
+    assert_eq!(shared.x, 5);
+
+    let shapes: Vec<Shape> = vec![Point::builder().x(7).y(8).build_into()];
+    assert_eq!(shapes.len(), 1);
+}
+
+#[test]
+fn test_build_into_with_custom_build_method_and_regular_builder() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(build_method = "create")]
+    struct Options<T> {
+        value: Option<T>,
+    }
+
+    let boxed: Box<Options<u8>> = Options::builder().value(Some(1)).create_into();
+    assert_eq!(boxed.value, Some(1));
+
+    let same: Options<u8> = Options::builder().create_into();
+    assert_eq!(same, Options { value: None });
+}
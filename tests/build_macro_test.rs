@@ -0,0 +1,66 @@
+use type_state_builder::{build, TypeStateBuilder};
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+struct User {
+    #[builder(required)]
+    name: String,
+    #[builder(required)]
+    email: String,
+    #[builder(default = 18)]
+    age: u32,
+    nickname: Option<String>,
+}
+
+// =============================================================================
+// Struct-literal construction
+// =============================================================================
+
+#[test]
+fn test_build_macro_sets_fields_in_any_order() {
+    let user = build!(User {
+        email: "ada@example.com".to_string(),
+        name: "Ada".to_string(),
+        age: 36,
+    });
+
+    assert_eq!(
+        user,
+        User {
+            name: "Ada".to_string(),
+            email: "ada@example.com".to_string(),
+            age: 36,
+            nickname: None,
+        }
+    );
+}
+
+#[test]
+fn test_build_macro_with_shorthand_fields_and_defaults() {
+    let name = "Ada".to_string();
+    let email = "ada@example.com".to_string();
+    let user = build!(User { name, email });
+
+    assert_eq!(user.age, 18);
+    assert_eq!(user.nickname, None);
+}
+
+#[test]
+fn test_build_macro_with_generics_and_regular_builder() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Range<T> {
+        start: Option<T>,
+        end: Option<T>,
+    }
+
+    let range = build!(Range::<u8> { end: Some(9) });
+    assert_eq!(
+        range,
+        Range {
+            start: None,
+            end: Some(9)
+        }
+    );
+
+    let empty: Range<u8> = build!(Range {});
+    assert_eq!(empty.end, None);
+}
@@ -0,0 +1,98 @@
+// =============================================================================
+// A private build method behind a validating finisher
+// =============================================================================
+
+mod accounts {
+    use type_state_builder::TypeStateBuilder;
+
+    #[derive(Debug, PartialEq)]
+    pub enum AccountError {
+        EmptyOwner,
+        Overdrawn,
+    }
+
+    fn check_account(account: &Account) -> Result<(), AccountError> {
+        if account.owner.is_empty() {
+            return Err(AccountError::EmptyOwner);
+        }
+        if account.balance < 0 {
+            return Err(AccountError::Overdrawn);
+        }
+        Ok(())
+    }
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(
+        build_vis = "pub(self)",
+        finishers(open(validate = check_account, error = AccountError))
+    )]
+    pub struct Account {
+        #[builder(required)]
+        pub owner: String,
+        #[builder(default = 0)]
+        pub balance: i64,
+    }
+
+    /// The defining module can still build without validation
+    pub fn unchecked(owner: &str, balance: i64) -> Account {
+        Account::builder()
+            .owner(owner.to_string())
+            .balance(balance)
+            .build()
+    }
+}
+
+#[test]
+fn test_finisher_stays_public() {
+    let account = accounts::Account::builder()
+        .owner("Ada".to_string())
+        .balance(10)
+        .open()
+        .unwrap();
+    assert_eq!(account.balance, 10);
+
+    let overdrawn = accounts::Account::builder()
+        .owner("Ada".to_string())
+        .balance(-1)
+        .open();
+    assert_eq!(overdrawn, Err(accounts::AccountError::Overdrawn));
+}
+
+#[test]
+fn test_build_is_available_where_visible() {
+    let account = accounts::unchecked("", -5);
+    assert_eq!(account.owner, "");
+    assert_eq!(account.balance, -5);
+}
+
+// =============================================================================
+// Restricted visibility in a builder module
+// =============================================================================
+
+mod network {
+    use type_state_builder::TypeStateBuilder;
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(module = "endpoint_builder", build_vis = "pub(super)")]
+    pub struct Endpoint {
+        #[builder(required)]
+        pub host: String,
+        #[builder(default = 443)]
+        pub port: u16,
+    }
+}
+
+#[test]
+fn test_build_vis_is_relative_to_the_struct() {
+    // `pub(super)` on `network::Endpoint` reaches this module, even though the
+    // build method is generated one module further down
+    let endpoint = network::Endpoint::builder()
+        .host("example.com".to_string())
+        .build();
+    assert_eq!(endpoint.port, 443);
+
+    let endpoint: network::Endpoint = network::Endpoint::builder()
+        .host("example.com".to_string())
+        .build_into();
+    assert_eq!(endpoint.host, "example.com");
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use type_state_builder::TypeStateBuilder;
+
+/// The trait frameworks declare to start the builders of any struct.
+pub trait Buildable {
+    type Builder;
+
+    fn builder() -> Self::Builder;
+}
+
+/// Starts the builder of any buildable struct.
+fn start<T: Buildable>() -> T::Builder {
+    T::builder()
+}
+
+// =============================================================================
+// Starting builders through the trait
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(buildable = "crate::Buildable")]
+pub struct User {
+    #[builder(required)]
+    name: String,
+    age: Option<u32>,
+}
+
+#[test]
+fn test_trait_builder_is_the_initial_state() {
+    let user = start::<User>()
+        .name("Alice".to_string())
+        .age(Some(30))
+        .build();
+    assert_eq!(
+        user,
+        User {
+            name: "Alice".to_string(),
+            age: Some(30),
+        }
+    );
+
+    let user = <User as Buildable>::builder()
+        .name("Bob".to_string())
+        .build();
+    assert_eq!(user.age, None);
+}
+
+#[derive(TypeStateBuilder, Debug, Default, PartialEq)]
+#[builder(buildable = Buildable)]
+pub struct Settings {
+    verbose: bool,
+    level: u8,
+}
+
+#[test]
+fn test_registry_of_builder_constructors() {
+    let mut registry: HashMap<&str, fn() -> Settings> = HashMap::new();
+    registry.insert("quiet", || start::<Settings>().build());
+    registry.insert("loud", || {
+        start::<Settings>().verbose(true).level(3).build()
+    });
+
+    assert_eq!(registry["quiet"](), Settings::default());
+    assert_eq!(
+        registry["loud"](),
+        Settings {
+            verbose: true,
+            level: 3,
+        }
+    );
+}
+
+// =============================================================================
+// Other builder shapes
+// =============================================================================
+
+#[test]
+fn test_generic_struct_with_default_bounds() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(buildable = "Buildable")]
+    struct Pair<T> {
+        #[builder(required)]
+        left: T,
+        right: T,
+    }
+
+    let pair = start::<Pair<u8>>().left(1).build();
+    assert_eq!(pair, Pair { left: 1, right: 0 });
+}
+
+#[test]
+fn test_hidden_states_and_fallible_build() {
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(buildable = "Buildable", hide_states)]
+    struct Port {
+        #[builder(required, validate = |port: &u16| *port > 0)]
+        number: u16,
+    }
+
+    assert_eq!(start::<Port>().number(80).build().unwrap().number, 80);
+    assert!(start::<Port>().number(0).build().is_err());
+}
@@ -0,0 +1,114 @@
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Debug output of builders
+// =============================================================================
+
+#[derive(TypeStateBuilder)]
+#[allow(dead_code)]
+struct Job {
+    #[builder(required)]
+    name: String,
+    #[builder(required)]
+    priority: u8,
+    #[builder(default = Box::new(|| {}))]
+    on_done: Box<dyn Fn()>,
+    #[builder(skip_debug)]
+    token: String,
+}
+
+#[test]
+fn test_debug_prints_fields_and_placeholders() {
+    let builder = Job::builder().name("backup".to_string());
+    let output = format!("{builder:?}");
+
+    assert!(output.starts_with("JobBuilder_HasName_MissingPriority {"));
+    assert!(output.contains(r#"name: "backup""#));
+    assert!(output.contains("priority: None"));
+    assert!(output.contains("on_done: <non-Debug>"));
+    assert!(!output.contains("token"));
+    assert!(output.ends_with(".. }"));
+}
+
+#[test]
+fn test_debug_on_complete_builder() {
+    let builder = Job::builder()
+        .name("sync".to_string())
+        .priority(3)
+        .token("secret".to_string());
+    let output = format!("{builder:?}");
+
+    assert!(output.contains("priority: 3"));
+    assert!(!output.contains("secret"));
+}
+
+#[test]
+fn test_debug_with_generics_and_regular_builder() {
+    struct Opaque;
+
+    #[derive(TypeStateBuilder)]
+    #[allow(dead_code)]
+    struct Wrapper<T, U: std::fmt::Debug> {
+        #[builder(required)]
+        inner: T,
+        label: Option<U>,
+    }
+
+    // Unbounded generic fields print as placeholders
+    let builder = Wrapper::<Opaque, &str>::builder()
+        .inner(Opaque)
+        .label(Some("x"));
+    assert_eq!(
+        format!("{builder:?}"),
+        r#"WrapperBuilder_HasInner { inner: <non-Debug>, label: Some("x") }"#
+    );
+
+    #[derive(TypeStateBuilder)]
+    #[allow(dead_code)]
+    struct Settings {
+        r#type: u32,
+        enabled: bool,
+    }
+
+    let builder = Settings::builder().r#type(7);
+    assert_eq!(
+        format!("{builder:?}"),
+        "SettingsBuilder { type: 7, enabled: false }"
+    );
+}
+
+// =============================================================================
+// Redacted fields
+// =============================================================================
+
+#[derive(TypeStateBuilder)]
+#[allow(dead_code)]
+struct Credentials {
+    #[builder(required)]
+    user: String,
+    #[builder(required, redact)]
+    password: String,
+    #[builder(redact)]
+    api_token: Option<String>,
+}
+
+#[test]
+fn test_redacted_fields_never_print_their_value() {
+    let builder = Credentials::builder()
+        .user("admin".to_string())
+        .password("hunter2".to_string())
+        .api_token(Some("tok_123".to_string()));
+    let output = format!("{builder:?}");
+
+    assert_eq!(
+        output,
+        r#"CredentialsBuilder_HasUser_HasPassword { user: "admin", password: "***", api_token: "***" }"#
+    );
+    assert!(!format!("{builder:#?}").contains("hunter2"));
+}
+
+#[test]
+fn test_redacted_field_before_it_is_set() {
+    let builder = Credentials::builder().user("admin".to_string());
+    assert!(format!("{builder:?}").contains(r#"password: "***""#));
+}
@@ -0,0 +1,159 @@
+use std::marker::PhantomData;
+use type_state_builder::TypeStateBuilder;
+
+fn assert_copy<T: Copy>(_: &T) {}
+
+// =============================================================================
+// Copy builders in const and table-driven code
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(const, derive(Copy, Clone))]
+pub struct Rgb {
+    #[builder(required)]
+    red: u8,
+    #[builder(required)]
+    green: u8,
+    #[builder(default = 0)]
+    blue: u8,
+}
+
+const REDDISH: RgbBuilder_HasRed_MissingGreen = Rgb::builder().red(200);
+
+#[test]
+fn test_copy_builder_states_are_reused() {
+    let dark = REDDISH.green(10).build();
+    let light = REDDISH.green(180).blue(90).build();
+
+    assert_eq!(
+        dark,
+        Rgb {
+            red: 200,
+            green: 10,
+            blue: 0
+        }
+    );
+    assert_eq!(
+        light,
+        Rgb {
+            red: 200,
+            green: 180,
+            blue: 90
+        }
+    );
+}
+
+#[test]
+fn test_every_state_is_copy() {
+    let start = Rgb::builder();
+    let partial = start.green(1);
+    let complete = partial.red(2);
+    assert_copy(&start);
+    assert_copy(&partial);
+    assert_copy(&complete);
+
+    let table = [complete.blue(1), complete.blue(2), complete.blue(3)];
+    let blues: Vec<u8> = table.iter().map(|builder| builder.build().blue).collect();
+    assert_eq!(blues, vec![1, 2, 3]);
+}
+
+// =============================================================================
+// Generic parameters and markers
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(derive(Copy, Clone))]
+pub struct Handle<T> {
+    #[builder(required)]
+    id: u32,
+    kind: PhantomData<T>,
+}
+
+#[test]
+fn test_marker_parameters_need_no_copy_bound() {
+    // `String` is not `Copy`, but the builder only stores a marker for it
+    let builder = Handle::<String>::builder().id(7);
+    assert_copy(&builder);
+    assert_eq!(builder.build().id, 7);
+    assert_eq!(builder.build().id, 7);
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(derive(Copy, Clone))]
+pub struct Labeled<T> {
+    #[builder(required)]
+    value: T,
+    label: Option<&'static str>,
+}
+
+#[test]
+fn test_states_are_copy_when_their_values_are() {
+    let builder = Labeled::builder().value(3u16);
+    assert_copy(&builder);
+    assert_eq!(builder.build().value, 3);
+
+    // A `String` value makes the builder `Clone` but not `Copy`
+    let builder = Labeled::builder().value(String::from("text"));
+    let copy = builder.clone();
+    assert_eq!(builder.build(), copy.build());
+}
+
+// =============================================================================
+// Clone-only builders
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(derive(Clone))]
+pub struct Query {
+    #[builder(required)]
+    table: String,
+    columns: Vec<String>,
+    limit: Option<u32>,
+}
+
+#[test]
+fn test_clone_partially_configured_builder() {
+    let base = Query::builder().columns(vec!["id".to_string()]);
+    let users = base.clone().table("users".to_string()).build();
+    let orders = base.table("orders".to_string()).limit(Some(5)).build();
+
+    assert_eq!(users.columns, orders.columns);
+    assert_eq!(users.table, "users");
+    assert_eq!(orders.limit, Some(5));
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(derive(Copy, Clone))]
+pub struct Margins {
+    top: u16,
+    bottom: u16,
+}
+
+#[test]
+fn test_regular_builder_is_copy() {
+    let builder = Margins::builder().top(4);
+    assert_copy(&builder);
+    assert_eq!(builder.bottom(2).build(), Margins { top: 4, bottom: 2 });
+    assert_eq!(builder.build(), Margins { top: 4, bottom: 0 });
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(derive(Copy, Clone))]
+pub enum Shape {
+    Circle {
+        #[builder(required)]
+        radius: u32,
+    },
+    Point,
+}
+
+#[test]
+fn test_enum_builders_are_copy() {
+    let selection = Shape::builder();
+    assert_copy(&selection);
+    let circle = selection.circle();
+    assert_copy(&circle);
+
+    assert_eq!(selection.point().build(), Shape::Point);
+    assert_eq!(circle.radius(2).build(), Shape::Circle { radius: 2 });
+}
@@ -0,0 +1,28 @@
+//! `#[builder_fn]` expansions compiled with every warning denied.
+
+#![deny(warnings)]
+
+use type_state_builder::builder_fn;
+
+/// A body that is a single expression.
+#[builder_fn]
+pub fn area(width: u32, height: u32) -> u32 {
+    width * height
+}
+
+/// A body with statements before its tail expression.
+#[builder_fn]
+pub fn perimeter(width: u32, height: u32) -> u32 {
+    let sides = width + height;
+    sides * 2
+}
+
+#[test]
+fn test_single_expression_body_without_warnings() {
+    assert_eq!(area().height(3).width(4).call(), 12);
+}
+
+#[test]
+fn test_statement_body_without_warnings() {
+    assert_eq!(perimeter().height(3).width(4).call(), 14);
+}
@@ -0,0 +1,138 @@
+use std::future::Future;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+use std::time::Duration;
+use type_state_builder::builder_fn;
+
+// =============================================================================
+// Named arguments for free functions
+// =============================================================================
+
+#[derive(Debug, PartialEq)]
+pub struct Client {
+    address: String,
+    timeout: Option<Duration>,
+    retries: u32,
+}
+
+/// Connects to a server.
+#[builder_fn]
+pub fn connect(
+    host: &str,
+    port: u16,
+    timeout: Option<Duration>,
+    #[builder(default = 3)] mut retries: u32,
+) -> Client {
+    retries = retries.min(5);
+    Client {
+        address: format!("{host}:{port}"),
+        timeout,
+        retries,
+    }
+}
+
+#[test]
+fn test_arguments_in_any_order() {
+    let client = connect()
+        .port(5432)
+        .timeout(Some(Duration::from_secs(1)))
+        .host("db")
+        .call();
+
+    assert_eq!(
+        client,
+        Client {
+            address: "db:5432".to_string(),
+            timeout: Some(Duration::from_secs(1)),
+            retries: 3,
+        }
+    );
+}
+
+#[test]
+fn test_optional_arguments_can_be_left_out() {
+    let client = connect().host("db").port(1).retries(9).call();
+    assert_eq!((client.timeout, client.retries), (None, 5));
+}
+
+#[test]
+fn test_arguments_struct_is_public() {
+    let builder: ConnectArgsBuilder_HasHost_HasPort<'_> = connect().host("db").port(1);
+    assert_eq!(builder.call().address, "db:1");
+}
+
+// =============================================================================
+// Generics, lifetimes and builder options
+// =============================================================================
+
+#[builder_fn]
+fn first_or<'a, T: ?Sized>(items: &'a [&'a T], fallback: &'a T) -> &'a T {
+    items.first().copied().unwrap_or(fallback)
+}
+
+#[builder_fn]
+fn longest(words: &[String]) -> &str {
+    words
+        .iter()
+        .max_by_key(|word| word.len())
+        .map_or("", |word| word.as_str())
+}
+
+#[builder_fn]
+fn parse<T: std::str::FromStr>(text: &str) -> Option<T> {
+    text.trim().parse().ok()
+}
+
+#[test]
+fn test_lifetimes_and_generics() {
+    let empty: [&str; 0] = [];
+    assert_eq!(first_or().items(&empty).fallback("none").call(), "none");
+
+    let words = vec!["a".to_string(), "abc".to_string()];
+    assert_eq!(longest().words(&words).call(), "abc");
+
+    assert_eq!(parse::<u8>().text(" 7 ").call(), Some(7));
+}
+
+#[builder_fn(setter_prefix = "with_", build_method = "finish")]
+async fn fetch(#[builder(impl_into)] url: String, attempts: Option<u8>) -> String {
+    format!("{url} x{}", attempts.unwrap_or(1))
+}
+
+/// Polls a future that completes without waiting.
+fn now<F: Future>(future: F) -> F::Output {
+    fn noop_raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker {
+            noop_raw_waker()
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut context = Context::from_waker(&waker);
+    match std::pin::pin!(future).poll(&mut context) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("the future is not ready"),
+    }
+}
+
+#[test]
+fn test_async_function_with_struct_attributes() {
+    let future = fetch()
+        .with_url("https://example.com")
+        .with_attempts(Some(2))
+        .call();
+    assert_eq!(now(future), "https://example.com x2");
+}
+
+#[test]
+fn test_function_without_required_arguments() {
+    #[builder_fn]
+    fn greet(name: Option<&str>) -> String {
+        format!("Hello, {}!", name.unwrap_or("world"))
+    }
+
+    assert_eq!(greet().call(), "Hello, world!");
+    assert_eq!(greet().name(Some("Ada")).call(), "Hello, Ada!");
+}
@@ -0,0 +1,108 @@
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Domain types whose builders are generated into child modules
+// =============================================================================
+
+mod model {
+    use super::TypeStateBuilder;
+
+    const DEFAULT_ROLE: &str = "member";
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(module = "user_builder", state_traits, nestable)]
+    pub struct User {
+        #[builder(required)]
+        pub name: String,
+        #[builder(default = DEFAULT_ROLE.to_string())]
+        pub role: String,
+    }
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(module = "settings_builder")]
+    pub struct Settings<T: Clone> {
+        pub value: Option<T>,
+        pub verbose: bool,
+    }
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(module = "team_builder")]
+    pub(crate) struct Team {
+        #[builder(required)]
+        pub name: String,
+        #[builder(required, nested)]
+        pub lead: User,
+    }
+
+    // Private structs get builders that are still usable from this module
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(module = "secret_builder")]
+    struct Secret {
+        #[builder(required)]
+        key: u64,
+    }
+
+    pub fn secret_key() -> u64 {
+        let builder: secret_builder::SecretBuilder_MissingKey = Secret::builder();
+        builder.key(7).build().key
+    }
+}
+
+/// All builders in one place, apart from the domain types
+mod builders {
+    pub use crate::model::settings_builder::*;
+    pub(crate) use crate::model::team_builder::*;
+    pub use crate::model::user_builder::*;
+}
+
+use model::{Settings, Team, User};
+
+#[test]
+fn test_builder_types_live_in_the_module() {
+    let builder: builders::UserBuilder_MissingName = User::builder();
+    let complete: builders::UserBuilder_HasName = builder.name("Ada".to_string());
+    let user = complete.build();
+
+    assert_eq!(user.name, "Ada");
+    assert_eq!(user.role, "member");
+}
+
+#[test]
+fn test_state_traits_from_the_module() {
+    fn finish<B: builders::UserBuilderFinish>(builder: B) -> B::Target {
+        builder.build()
+    }
+
+    let user = finish(
+        User::builder()
+            .name("Grace".to_string())
+            .role("admin".to_string()),
+    );
+    assert_eq!(user.role, "admin");
+}
+
+#[test]
+fn test_regular_builder_with_generics_in_module() {
+    let builder: builders::SettingsBuilder<u8> = Settings::builder();
+    let settings = builder.value(Some(3)).verbose(true).build();
+
+    assert_eq!(settings.value, Some(3));
+    assert!(settings.verbose);
+}
+
+#[test]
+fn test_nested_builder_generated_into_module() {
+    let builder: builders::TeamBuilder_MissingName_MissingLead = Team::builder();
+    let team = builder
+        .lead_with(|lead| lead.name("Linus".to_string()))
+        .name("kernel".to_string())
+        .build();
+
+    assert_eq!(team.lead.name, "Linus");
+    assert_eq!(team.name, "kernel");
+}
+
+#[test]
+fn test_private_struct_builder_in_module() {
+    assert_eq!(model::secret_key(), 7);
+}
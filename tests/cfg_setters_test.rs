@@ -0,0 +1,97 @@
+//! Tests for `#[builder(cfg(...))]` feature-gated setters.
+
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+struct Service {
+    #[builder(required)]
+    name: String,
+
+    // Always enabled: `all()` is true
+    #[builder(cfg(all()), default = 8)]
+    workers: usize,
+
+    // Never enabled: `any()` is false, so the field keeps its default
+    #[builder(cfg(any()), default = 9090)]
+    metrics_port: u16,
+
+    #[builder(cfg(test))]
+    tracing: bool,
+}
+
+#[test]
+fn test_enabled_setters_are_generated() {
+    let service = Service::builder()
+        .name("api".to_string())
+        .workers(2)
+        .tracing(true)
+        .build();
+
+    assert_eq!(service.workers, 2);
+    assert!(service.tracing);
+}
+
+#[test]
+fn test_disabled_setter_field_uses_default() {
+    let service = Service::builder().name("api".to_string()).build();
+    assert_eq!(service.metrics_port, 9090);
+    assert_eq!(service.workers, 8);
+}
+
+#[test]
+fn test_cfg_setters_in_regular_builder() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Options {
+        #[builder(cfg(not(test)))]
+        verbose: bool,
+        level: u8,
+    }
+
+    let options = Options::builder().level(3).build();
+    assert_eq!(
+        options,
+        Options {
+            verbose: false,
+            level: 3
+        }
+    );
+}
+
+#[test]
+fn test_cfg_fields_stay_optional_under_all_required() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(all_required)]
+    struct Job {
+        id: u32,
+        #[builder(cfg(any()))]
+        retries: u8,
+    }
+
+    // `retries` does not have to be set, and cannot be
+    let job = Job::builder().id(1).build();
+    assert_eq!(job, Job { id: 1, retries: 0 });
+}
+
+#[test]
+fn test_cfg_setters_in_const_builder() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(const)]
+    struct Limits {
+        #[builder(required)]
+        max: u32,
+        #[builder(cfg(all()), default = 0)]
+        min: u32,
+        #[builder(cfg(any()), default = 1, converter = |value: u8| value as u32)]
+        step: u32,
+    }
+
+    const LIMITS: Limits = Limits::builder().max(10).min(2).build();
+    assert_eq!(
+        LIMITS,
+        Limits {
+            max: 10,
+            min: 2,
+            step: 1
+        }
+    );
+}
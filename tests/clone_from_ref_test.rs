@@ -0,0 +1,109 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    accent: u32,
+}
+
+// =============================================================================
+// Borrowed arguments on the type-state builder
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+pub struct Profile {
+    #[builder(required, clone_from_ref)]
+    name: String,
+    #[builder(required, clone_from_ref)]
+    tags: Vec<String>,
+    #[builder(clone_from_ref, default = Theme { accent: 0 })]
+    theme: Theme,
+    #[builder(clone_from_ref)]
+    bio: std::string::String,
+}
+
+#[test]
+fn test_setters_take_references() {
+    let name = String::from("ada");
+    let tags = vec!["admin".to_string()];
+    let theme = Theme { accent: 7 };
+
+    let profile = Profile::builder()
+        .tags(&tags)
+        .name(&name)
+        .theme(&theme)
+        .bio("mathematician")
+        .build();
+
+    // The caller keeps its values
+    assert_eq!(name, "ada");
+    assert_eq!(
+        profile,
+        Profile {
+            name,
+            tags,
+            theme,
+            bio: "mathematician".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_borrowed_slices_and_substrings() {
+    let words = ["x".to_string(), "y".to_string(), "z".to_string()];
+    let line = "grace hopper";
+
+    let profile = Profile::builder()
+        .name(&line[..5])
+        .tags(&words[1..])
+        .build();
+
+    assert_eq!(profile.name, "grace");
+    assert_eq!(profile.tags, ["y", "z"]);
+    assert_eq!(profile.theme, Theme { accent: 0 });
+}
+
+// =============================================================================
+// Entry points, struct-level conversions and validation
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(impl_into, into_iter)]
+pub struct Request {
+    #[builder(required, builder_method, clone_from_ref)]
+    path: String,
+    #[builder(clone_from_ref)]
+    headers: Vec<(String, String)>,
+    user_agent: String,
+}
+
+#[test]
+fn test_clone_from_ref_overrides_struct_level_conversions() {
+    let headers = vec![("accept".to_string(), "*/*".to_string())];
+
+    let request = Request::path("/index")
+        .headers(&headers)
+        .user_agent("curl")
+        .build();
+
+    assert_eq!(request.path, "/index");
+    assert_eq!(request.headers, headers);
+    assert_eq!(request.user_agent, "curl");
+}
+
+#[test]
+fn test_clone_from_ref_with_validation_and_regular_builder() {
+    #[derive(TypeStateBuilder, Debug)]
+    struct Label {
+        #[builder(clone_from_ref, non_empty, validate_on_set)]
+        text: String,
+        #[builder(clone_from_ref)]
+        parts: Vec<u8>,
+    }
+
+    let label = Label::builder().text("ok").unwrap().parts(&[1, 2]).build();
+    assert_eq!(
+        (label.text.as_str(), label.parts.as_slice()),
+        ("ok", &[1, 2][..])
+    );
+    assert_eq!(Label::builder().text("").unwrap_err().field, "text");
+}
@@ -0,0 +1,81 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use type_state_builder::TypeStateBuilder;
+
+fn hash(value: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+struct Document {
+    #[builder(required)]
+    name: String,
+    #[builder(computed = |b| hash(&b.name))]
+    name_hash: u64,
+    tags: Vec<String>,
+    #[builder(computed = |b| b.tags.len())]
+    tag_count: usize,
+}
+
+// =============================================================================
+// Computed values
+// =============================================================================
+
+#[test]
+fn test_computed_fields_derive_from_other_fields() {
+    let document = Document::builder()
+        .tags(vec!["a".to_string(), "b".to_string()])
+        .name("readme".to_string())
+        .build();
+
+    assert_eq!(document.name_hash, hash("readme"));
+    assert_eq!(document.tag_count, 2);
+}
+
+#[test]
+fn test_computed_fields_have_no_setter_and_show_in_debug() {
+    let builder = Document::builder().name("readme".to_string());
+    let debug = format!("{builder:?}");
+
+    assert!(debug.contains("name_hash: \"<computed>\""), "{debug}");
+    assert!(debug.contains("tag_count: \"<computed>\""), "{debug}");
+}
+
+#[test]
+fn test_computed_field_with_regular_builder_and_default_from() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Rect {
+        #[builder(default = 2)]
+        width: u32,
+        #[builder(default_from = |b| b.width)]
+        height: u32,
+        #[builder(computed = |b| b.width * b.height.unwrap_or(b.width))]
+        area: u32,
+    }
+
+    assert_eq!(
+        Rect::builder().width(3).build(),
+        Rect {
+            width: 3,
+            height: 3,
+            area: 9
+        }
+    );
+    assert_eq!(Rect::builder().height(5).build().area, 10);
+}
+
+#[test]
+fn test_computed_field_with_generic_type() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Tagged<T: Clone> {
+        #[builder(required)]
+        items: Vec<T>,
+        #[builder(computed = |b| b.items.first().cloned())]
+        first: Option<T>,
+    }
+
+    let tagged = Tagged::builder().items(vec![3, 4]).build();
+    assert_eq!(tagged.first, Some(3));
+}
@@ -259,3 +259,50 @@ fn test_regular_builder_converter_defaults() {
     assert_eq!(instance.joined, "only-this".to_string());
     assert_eq!(instance.sum, 0); // Default::default()
 }
+
+// Converters chained with a field-level impl_into
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(impl_into)]
+struct ChainedConverter {
+    #[builder(required, impl_into, converter = |name: String| name.trim().to_lowercase())]
+    username: String,
+
+    #[builder(impl_into, converter = |port: u16| Some(port))]
+    port: Option<u16>,
+
+    // Struct-level impl_into leaves converter setters unchanged
+    #[builder(converter = |tags: Vec<&str>| tags.join(","))]
+    tags: String,
+}
+
+#[test]
+fn test_converter_chained_with_impl_into() {
+    let instance = ChainedConverter::builder()
+        .username("  Ada ")
+        .port(8080_u16)
+        .tags(vec!["a", "b"])
+        .build();
+
+    assert_eq!(instance.username, "ada");
+    assert_eq!(instance.port, Some(8080));
+    assert_eq!(instance.tags, "a,b");
+
+    let instance = ChainedConverter::builder()
+        .username(String::from("GRACE"))
+        .port(80_u8)
+        .build();
+    assert_eq!(instance.username, "grace");
+    assert_eq!(instance.port, Some(80));
+}
+
+#[test]
+fn test_converter_with_impl_into_false() {
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(impl_into)]
+    struct Label {
+        #[builder(impl_into = false, converter = |text: &str| text.to_uppercase())]
+        text: String,
+    }
+
+    assert_eq!(Label::builder().text("hi").build().text, "HI");
+}
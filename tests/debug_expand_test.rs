@@ -0,0 +1,38 @@
+// `debug_expand` reports the generated code as a deprecation warning
+#![allow(deprecated)]
+
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(debug_expand)]
+struct Config<'a, T: Clone> {
+    #[builder(required)]
+    name: &'a str,
+    value: Option<T>,
+}
+
+#[test]
+fn test_debug_expand_leaves_the_builder_unchanged() {
+    let config = Config::builder().name("app").value(Some(3)).build();
+
+    assert_eq!(
+        config,
+        Config {
+            name: "app",
+            value: Some(3)
+        }
+    );
+}
+
+#[test]
+fn test_debug_expand_with_lite_mode() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(lite, debug_expand)]
+    struct Point {
+        #[builder(required)]
+        x: i32,
+        y: i32,
+    }
+
+    assert_eq!(Point::new(1).with_y(2), Point { x: 1, y: 2 });
+}
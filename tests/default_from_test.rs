@@ -0,0 +1,89 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+struct Pool {
+    #[builder(required)]
+    max_connections: usize,
+    #[builder(default_from = |b| b.max_connections * 2)]
+    capacity: usize,
+    #[builder(default = 30)]
+    timeout_secs: u64,
+    #[builder(default_from = |b| format!("pool-{}", b.max_connections))]
+    name: String,
+}
+
+// =============================================================================
+// Computed defaults
+// =============================================================================
+
+#[test]
+fn test_default_from_reads_other_fields() {
+    let pool = Pool::builder().max_connections(16).build();
+
+    assert_eq!(pool.capacity, 32);
+    assert_eq!(pool.name, "pool-16");
+    assert_eq!(pool.timeout_secs, 30);
+}
+
+#[test]
+fn test_setter_overrides_default_from() {
+    let pool = Pool::builder()
+        .capacity(5)
+        .max_connections(16)
+        .name("primary".to_string())
+        .build();
+
+    assert_eq!(pool.capacity, 5);
+    assert_eq!(pool.name, "primary");
+}
+
+#[test]
+fn test_default_from_independent_of_setter_order() {
+    let early = Pool::builder().timeout_secs(5).max_connections(4).build();
+    let late = Pool::builder().max_connections(4).timeout_secs(5).build();
+
+    assert_eq!(early, late);
+    assert_eq!(early.capacity, 8);
+}
+
+#[test]
+fn test_default_from_reads_optional_fields() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Rect {
+        width: u32,
+        #[builder(default_from = |b| b.width)]
+        height: u32,
+    }
+
+    assert_eq!(
+        Rect::builder().width(3).build(),
+        Rect {
+            width: 3,
+            height: 3
+        }
+    );
+    assert_eq!(
+        Rect::builder().width(3).height(4).build(),
+        Rect {
+            width: 3,
+            height: 4
+        }
+    );
+}
+
+#[test]
+fn test_default_from_with_resolve_and_generics() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Labeled<T: Clone + core::fmt::Debug> {
+        #[builder(required)]
+        value: T,
+        #[builder(default_from = |b| vec![b.value.clone()])]
+        history: Vec<T>,
+        #[builder(resolve = |label: String, value: &T| format!("{label}({value:?})"))]
+        label: String,
+    }
+
+    let labeled = Labeled::builder().value(1u8).build();
+    assert_eq!(labeled.history, vec![1]);
+    assert_eq!(labeled.label, "(1)");
+}
@@ -0,0 +1,195 @@
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Optional fields taken from a template instance
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(default_struct = "Server::template()")]
+pub struct Server {
+    #[builder(required)]
+    name: String,
+    host: String,
+    port: u16,
+    #[builder(default = 30)]
+    timeout: u32,
+    tags: Vec<String>,
+}
+
+impl Server {
+    fn template() -> Self {
+        Server {
+            name: "template".to_string(),
+            host: "localhost".to_string(),
+            port: 8080,
+            timeout: 5,
+            tags: vec!["default".to_string()],
+        }
+    }
+}
+
+#[test]
+fn test_unset_fields_come_from_the_template() {
+    let server = Server::builder().name("api".to_string()).build();
+
+    assert_eq!(
+        server,
+        Server {
+            name: "api".to_string(),
+            host: "localhost".to_string(),
+            port: 8080,
+            timeout: 30,
+            tags: vec!["default".to_string()],
+        }
+    );
+}
+
+#[test]
+fn test_set_fields_override_the_template() {
+    let server = Server::builder()
+        .name("api".to_string())
+        .port(9000)
+        .tags(vec![])
+        .build();
+
+    assert_eq!(server.host, "localhost");
+    assert_eq!(server.port, 9000);
+    assert!(server.tags.is_empty());
+}
+
+// =============================================================================
+// Templates referring to `Self`
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(default_struct = Self::default())]
+pub struct Limits<T: Default> {
+    #[builder(required)]
+    label: String,
+    max: T,
+    enabled: bool,
+}
+
+impl<T: Default> Default for Limits<T> {
+    fn default() -> Self {
+        Limits {
+            label: String::new(),
+            max: T::default(),
+            enabled: true,
+        }
+    }
+}
+
+#[test]
+fn test_generic_template_via_self() {
+    let limits = Limits::<u8>::builder().label("cpu".to_string()).build();
+    assert_eq!(limits.max, 0);
+    assert!(limits.enabled);
+
+    let limits = Limits::builder()
+        .label("mem".to_string())
+        .max(64u32)
+        .enabled(false)
+        .build();
+    assert_eq!(limits.max, 64);
+    assert!(!limits.enabled);
+}
+
+// =============================================================================
+// Regular and lite builders
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(default_struct = "Theme::dark()")]
+pub struct Theme {
+    background: String,
+    foreground: String,
+}
+
+impl Theme {
+    fn dark() -> Self {
+        Theme {
+            background: "black".to_string(),
+            foreground: "white".to_string(),
+        }
+    }
+}
+
+#[test]
+fn test_regular_builder_uses_the_template() {
+    let theme = Theme::builder().foreground("green".to_string()).build();
+    assert_eq!(theme.background, "black");
+    assert_eq!(theme.foreground, "green");
+
+    let theme = ThemeBuilder::default().build();
+    assert_eq!(theme, Theme::dark());
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(lite, default_struct = "Window::standard()")]
+pub struct Window {
+    #[builder(required)]
+    title: String,
+    width: u32,
+    height: u32,
+}
+
+impl Window {
+    fn standard() -> Self {
+        Window {
+            title: String::new(),
+            width: 800,
+            height: 600,
+        }
+    }
+}
+
+#[test]
+fn test_lite_builder_uses_the_template() {
+    let window = Window::new("main".to_string()).with_height(400);
+    assert_eq!(
+        window,
+        Window {
+            title: "main".to_string(),
+            width: 800,
+            height: 400,
+        }
+    );
+}
+
+// =============================================================================
+// Builder modules
+// =============================================================================
+
+mod settings {
+    use type_state_builder::TypeStateBuilder;
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(module = "settings_builder", default_struct = "Settings::base()")]
+    pub struct Settings {
+        #[builder(required)]
+        pub profile: String,
+        pub verbose: bool,
+        pub level: u8,
+    }
+
+    impl Settings {
+        fn base() -> Self {
+            Settings {
+                profile: "base".to_string(),
+                verbose: true,
+                level: 3,
+            }
+        }
+    }
+}
+
+#[test]
+fn test_template_in_builder_module() {
+    let settings = settings::Settings::builder()
+        .profile("dev".to_string())
+        .level(1)
+        .build();
+    assert!(settings.verbose);
+    assert_eq!(settings.level, 1);
+}
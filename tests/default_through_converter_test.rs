@@ -0,0 +1,87 @@
+use type_state_builder::TypeStateBuilder;
+
+fn parse_size(size: &str) -> usize {
+    let (number, unit) = size.split_once(' ').unwrap_or((size, "B"));
+    let number: usize = number.parse().unwrap();
+    match unit {
+        "KiB" => number * 1024,
+        "MiB" => number * 1024 * 1024,
+        _ => number,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Port(u16);
+
+// =============================================================================
+// Defaults in the setter's input type
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+pub struct Cache {
+    #[builder(required)]
+    name: String,
+    #[builder(
+        converter = |size: &str| parse_size(size),
+        default = "8 KiB",
+        default_through_converter
+    )]
+    capacity: usize,
+    #[builder(wrap = u16, default = 6379, default_through_converter)]
+    port: Port,
+}
+
+#[test]
+fn test_default_passes_through_the_converter() {
+    let cache = Cache::builder().name("sessions".to_string()).build();
+    assert_eq!(
+        cache,
+        Cache {
+            name: "sessions".to_string(),
+            capacity: 8 * 1024,
+            port: Port(6379),
+        }
+    );
+}
+
+#[test]
+fn test_setter_and_default_share_a_representation() {
+    let cache = Cache::builder()
+        .capacity("2 MiB")
+        .port(7000)
+        .name("pages".to_string())
+        .build();
+    assert_eq!((cache.capacity, cache.port), (2 * 1024 * 1024, Port(7000)));
+}
+
+// =============================================================================
+// Other builder shapes
+// =============================================================================
+
+#[test]
+fn test_const_and_lite_builders() {
+    #[derive(TypeStateBuilder)]
+    #[builder(const)]
+    struct Timeout {
+        #[builder(converter = |secs: u64| secs * 1000, default = 30, default_through_converter)]
+        millis: u64,
+        #[builder(default = 3)]
+        retries: u8,
+    }
+
+    #[derive(TypeStateBuilder)]
+    #[builder(lite)]
+    struct Buffer {
+        #[builder(
+            converter = |size: &str| parse_size(size),
+            default = "1 KiB",
+            default_through_converter
+        )]
+        size: usize,
+    }
+
+    const TIMEOUT: Timeout = Timeout::builder().build();
+    assert_eq!((TIMEOUT.millis, TIMEOUT.retries), (30_000, 3));
+    assert_eq!(Buffer::new().size, 1024);
+    assert_eq!(Buffer::new().with_size("4 KiB").size, 4096);
+}
@@ -0,0 +1,55 @@
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Setters renamed away from their field
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(setter_prefix = "with_", setter_prefix(except(idle)), setter_trait)]
+pub struct Pool {
+    #[builder(required, alias = "max_connections", alias = "pool_size")]
+    max_conns: u32,
+    #[builder(setter_name = "timeout", alias = "timeout")]
+    timeout_secs: Option<u64>,
+    #[builder(alias = "idle")]
+    idle: bool,
+}
+
+#[test]
+fn test_aliased_setters_keep_their_names() {
+    let pool = Pool::builder()
+        .with_max_conns(8)
+        .with_timeout(Some(30))
+        .idle(true)
+        .build();
+
+    assert_eq!(
+        pool,
+        Pool {
+            max_conns: 8,
+            timeout_secs: Some(30),
+            idle: true,
+        }
+    );
+}
+
+// =============================================================================
+// Other builder shapes
+// =============================================================================
+
+#[test]
+fn test_aliases_on_builder_method_and_const_builders() {
+    #[derive(TypeStateBuilder)]
+    #[builder(const)]
+    struct Point {
+        #[builder(required, builder_method, setter_name = "at_x", alias = "x_coord")]
+        x: i32,
+        #[builder(required, setter_prefix = "with_")]
+        y: i32,
+        #[builder(default = 0, alias = "depth")]
+        z: i32,
+    }
+
+    const POINT: Point = Point::at_x(1).with_y(2).z(3).build();
+    assert_eq!((POINT.x, POINT.y, POINT.z), (1, 2, 3));
+}
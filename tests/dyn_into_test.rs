@@ -0,0 +1,112 @@
+use std::rc::Rc;
+use std::sync::Arc;
+use type_state_builder::TypeStateBuilder;
+
+pub trait Greeter {
+    fn greet(&self, name: &str) -> String;
+}
+
+pub struct English;
+
+impl Greeter for English {
+    fn greet(&self, name: &str) -> String {
+        format!("Hello, {name}")
+    }
+}
+
+pub struct Formal {
+    title: &'static str,
+}
+
+impl Greeter for Formal {
+    fn greet(&self, name: &str) -> String {
+        format!("Good day, {} {name}", self.title)
+    }
+}
+
+// =============================================================================
+// Setters taking the concrete value
+// =============================================================================
+
+#[derive(TypeStateBuilder)]
+pub struct Service {
+    #[builder(required, dyn_into)]
+    greeter: Arc<dyn Greeter + Send + Sync>,
+    #[builder(dyn_into)]
+    fallback: Option<Box<dyn Greeter>>,
+    #[builder(dyn_into, default = Rc::new(English))]
+    logger: Rc<dyn Greeter>,
+}
+
+#[test]
+fn test_setters_allocate_the_trait_object() {
+    let service = Service::builder()
+        .greeter(Formal { title: "Dr." })
+        .fallback(English)
+        .build();
+
+    assert_eq!(service.greeter.greet("Ada"), "Good day, Dr. Ada");
+    assert_eq!(service.fallback.unwrap().greet("Ada"), "Hello, Ada");
+    assert_eq!(service.logger.greet("Ada"), "Hello, Ada");
+}
+
+#[test]
+fn test_closures_as_trait_objects() {
+    #[derive(TypeStateBuilder)]
+    struct Hooks {
+        #[builder(dyn_into)]
+        on_event: Option<std::boxed::Box<dyn Fn(u32) -> u32>>,
+    }
+
+    let offset = 10;
+    let hooks = Hooks::builder().on_event(move |n| n + offset).build();
+    assert_eq!((hooks.on_event.unwrap())(5), 15);
+    assert!(Hooks::builder().build().on_event.is_none());
+}
+
+// =============================================================================
+// Other builder shapes
+// =============================================================================
+
+#[test]
+fn test_dyn_into_with_borrowed_trait_objects() {
+    #[derive(TypeStateBuilder)]
+    struct Report<'a> {
+        #[builder(required, dyn_into)]
+        greeter: Box<dyn Greeter + 'a>,
+    }
+
+    struct Borrowing<'a>(&'a str);
+
+    impl Greeter for Borrowing<'_> {
+        fn greet(&self, name: &str) -> String {
+            format!("{}, {name}", self.0)
+        }
+    }
+
+    let salutation = String::from("Hi");
+    let report = Report::builder().greeter(Borrowing(&salutation)).build();
+    assert_eq!(report.greeter.greet("Ada"), "Hi, Ada");
+}
+
+#[test]
+fn test_dyn_into_with_builder_method_and_validate_on_set() {
+    #[derive(TypeStateBuilder)]
+    struct Pipeline {
+        #[builder(required, dyn_into, builder_method)]
+        first: Box<dyn Greeter>,
+        #[builder(
+            dyn_into,
+            validate = |g: &Option<Arc<dyn Greeter>>| g.is_some(),
+            validate_on_set
+        )]
+        second: Option<Arc<dyn Greeter>>,
+    }
+
+    let pipeline = Pipeline::first(English)
+        .second(Formal { title: "Ms." })
+        .unwrap()
+        .build();
+    assert_eq!(pipeline.first.greet("Bo"), "Hello, Bo");
+    assert_eq!(pipeline.second.unwrap().greet("Bo"), "Good day, Ms. Bo");
+}
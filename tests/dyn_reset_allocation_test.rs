@@ -0,0 +1,77 @@
+//! Allocations of a reused `{Struct}DynBuilder`, counted by a global allocator.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use type_state_builder::TypeStateBuilder;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // Counted per thread, so tests running in parallel do not interfere
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(also_dynamic)]
+pub struct Message {
+    #[builder(required, clone_from_ref)]
+    topic: String,
+    #[builder(clone_from_ref)]
+    payload: Vec<u8>,
+    #[builder(into_iter)]
+    ids: Vec<u64>,
+}
+
+#[test]
+fn test_reset_keeps_the_capacity_of_copied_fields() {
+    let before = allocations();
+    let mut builder = Message::dyn_builder()
+        .topic("metrics")
+        .payload(&[0; 256])
+        .ids(0..64u64);
+    assert!(allocations() - before >= 3);
+    builder.reset();
+
+    let before = allocations();
+    let builder = builder.topic("logs").payload(&[1; 128]).ids(0..32u64);
+    assert_eq!(allocations() - before, 0);
+
+    assert_eq!(
+        builder.build().unwrap(),
+        Message {
+            topic: "logs".to_string(),
+            payload: vec![1; 128],
+            ids: (0..32).collect(),
+        }
+    );
+}
+
+#[test]
+fn test_fields_set_twice_reuse_their_buffer() {
+    let builder = Message::dyn_builder().topic("a longer topic").ids(0..64u64);
+
+    let before = allocations();
+    let builder = builder.topic("short").ids(0..8u64);
+    assert_eq!(allocations() - before, 0);
+
+    let message = builder.build().unwrap();
+    assert_eq!((message.topic.as_str(), message.ids.len()), ("short", 8));
+}
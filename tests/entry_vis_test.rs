@@ -0,0 +1,102 @@
+// =============================================================================
+// Construction kept inside the defining module
+// =============================================================================
+
+mod registry {
+    use type_state_builder::TypeStateBuilder;
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(entry_vis = "pub(self)")]
+    pub struct Handle {
+        #[builder(required)]
+        pub id: u32,
+        pub label: Option<String>,
+    }
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(entry_vis = "pub(self)")]
+    pub struct Options {
+        #[builder(default = 4)]
+        pub workers: usize,
+    }
+
+    /// The only way to start a `Handle` builder outside this module
+    pub fn handle(id: u32) -> HandleBuilder_HasId {
+        Handle::builder().id(id)
+    }
+
+    pub fn options() -> OptionsBuilder {
+        Options::builder()
+    }
+}
+
+#[test]
+fn test_returned_builder_stays_usable() {
+    let handle = registry::handle(7).label(Some("main".to_string())).build();
+    assert_eq!(
+        handle,
+        registry::Handle {
+            id: 7,
+            label: Some("main".to_string()),
+        }
+    );
+
+    let options = registry::options().workers(8).build();
+    assert_eq!(options.workers, 8);
+    assert_eq!(registry::options().build().workers, 4);
+}
+
+// =============================================================================
+// Entry visibility with builder_method and lite mode
+// =============================================================================
+
+mod shapes {
+    use type_state_builder::TypeStateBuilder;
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(entry_vis = "pub(super)")]
+    pub struct Circle {
+        #[builder(required, builder_method)]
+        pub radius: u32,
+        #[builder(required)]
+        pub x: i32,
+    }
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(lite, entry_vis = "pub(crate)")]
+    pub struct Square {
+        #[builder(required)]
+        pub side: u32,
+        pub filled: Option<bool>,
+    }
+}
+
+#[test]
+fn test_entry_vis_applies_to_every_entry_point() {
+    let circle = shapes::Circle::radius(3).x(-1).build();
+    assert_eq!(circle, shapes::Circle { radius: 3, x: -1 });
+
+    let square = shapes::Square::new(2).with_filled(Some(true));
+    assert_eq!(square.filled, Some(true));
+}
+
+// =============================================================================
+// Relative visibilities in a builder module
+// =============================================================================
+
+mod nodes {
+    use type_state_builder::TypeStateBuilder;
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(module = "node_builder", entry_vis = "pub(super)")]
+    pub struct Node {
+        #[builder(required)]
+        pub name: String,
+    }
+}
+
+#[test]
+fn test_entry_vis_is_relative_to_the_struct() {
+    let node = nodes::Node::builder().name("root".to_string()).build();
+    assert_eq!(node.name, "root");
+}
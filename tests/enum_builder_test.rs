@@ -0,0 +1,178 @@
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Selecting the variant before setting its fields
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+pub enum Message {
+    Ping,
+    Data {
+        #[builder(required)]
+        stream: u32,
+        #[builder(required)]
+        payload: Vec<u8>,
+        #[builder(default = 0)]
+        flags: u8,
+    },
+    Close {
+        reason: Option<String>,
+    },
+}
+
+#[test]
+fn test_unit_variant() {
+    let message = Message::builder().ping().build();
+    assert_eq!(message, Message::Ping);
+}
+
+#[test]
+fn test_variant_with_required_fields() {
+    let message = Message::builder()
+        .data()
+        .payload(vec![1, 2, 3])
+        .stream(7)
+        .build();
+
+    assert_eq!(
+        message,
+        Message::Data {
+            stream: 7,
+            payload: vec![1, 2, 3],
+            flags: 0,
+        }
+    );
+}
+
+#[test]
+fn test_variant_with_only_optional_fields() {
+    let message = Message::builder().close().build();
+    assert_eq!(message, Message::Close { reason: None });
+
+    let message = Message::builder()
+        .close()
+        .reason(Some("done".to_string()))
+        .build();
+    assert_eq!(
+        message,
+        Message::Close {
+            reason: Some("done".to_string())
+        }
+    );
+}
+
+#[test]
+fn test_complete_variant_builder_converts_into_the_enum() {
+    let message: Message = Message::builder()
+        .data()
+        .stream(1)
+        .payload(Vec::new())
+        .into();
+    assert!(matches!(message, Message::Data { stream: 1, .. }));
+}
+
+// =============================================================================
+// Generics and struct-level attributes
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(impl_into, build_method = "finish")]
+pub enum Shape<'a, T> {
+    Circle {
+        #[builder(required)]
+        label: &'a str,
+        #[builder(required)]
+        radius: T,
+    },
+    Rectangle {
+        #[builder(required)]
+        width: T,
+        #[builder(required)]
+        height: T,
+    },
+    HttpEmpty,
+}
+
+#[test]
+fn test_generic_enum() {
+    let circle = Shape::builder()
+        .circle()
+        .label("unit")
+        .radius(1.5f64)
+        .finish();
+    assert_eq!(
+        circle,
+        Shape::Circle {
+            label: "unit",
+            radius: 1.5
+        }
+    );
+
+    let rectangle: Shape<'_, u32> = Shape::builder()
+        .rectangle()
+        .width(2u8)
+        .height(3u32)
+        .finish();
+    assert_eq!(
+        rectangle,
+        Shape::Rectangle {
+            width: 2,
+            height: 3
+        }
+    );
+
+    let empty: Shape<'static, u8> = Shape::builder().http_empty().finish();
+    assert_eq!(empty, Shape::HttpEmpty);
+}
+
+#[test]
+fn test_selection_builder_debug() {
+    let selection = Message::builder();
+    assert_eq!(format!("{selection:?}"), "MessageBuilder");
+}
+
+// =============================================================================
+// Finishers validate the enum
+// =============================================================================
+
+fn check_request(request: &Request) -> Result<(), String> {
+    match request {
+        Request::Get { path } if path.is_empty() => Err("empty path".to_string()),
+        _ => Ok(()),
+    }
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(finishers(send(validate = check_request, error = String)))]
+pub enum Request {
+    Get {
+        #[builder(required)]
+        path: String,
+    },
+    Post {
+        #[builder(required)]
+        path: String,
+        body: Vec<u8>,
+    },
+}
+
+#[test]
+fn test_finishers_on_variant_builders() {
+    let request = Request::builder().get().path("/".to_string()).send();
+    assert_eq!(
+        request,
+        Ok(Request::Get {
+            path: "/".to_string()
+        })
+    );
+
+    let request = Request::builder().get().path(String::new()).send();
+    assert_eq!(request, Err("empty path".to_string()));
+
+    let request = Request::builder()
+        .post()
+        .path("/upload".to_string())
+        .body(vec![0])
+        .send();
+    assert!(request.is_ok());
+}
@@ -0,0 +1,107 @@
+//! Tests for `#[builder(field_descriptors)]`, which describes the fields in a `FIELDS` constant.
+#![cfg(feature = "metadata")]
+
+use type_state_builder::TypeStateBuilder;
+
+/// The descriptor an admin UI renders its form from.
+#[derive(Debug, PartialEq)]
+pub struct FieldDescriptor {
+    pub name: &'static str,
+    pub required: bool,
+    pub type_name: &'static str,
+    pub has_default: bool,
+    pub doc: &'static str,
+}
+
+// =============================================================================
+// Describing the fields of a struct
+// =============================================================================
+
+#[allow(dead_code)]
+#[derive(TypeStateBuilder)]
+#[builder(field_descriptors = "crate::FieldDescriptor")]
+pub struct ServerConfig {
+    /// Address to listen on.
+    #[builder(required)]
+    host: String,
+    /// Port to listen on.
+    ///
+    /// Ports below 1024 need privileges.
+    #[builder(default = 8080)]
+    port: u16,
+    tags: Option<Vec<String>>,
+    #[builder(skip_setter, default = 1)]
+    schema_version: u8,
+}
+
+#[test]
+fn test_fields_with_setters_are_described() {
+    assert_eq!(
+        ServerConfig::FIELDS,
+        &[
+            FieldDescriptor {
+                name: "host",
+                required: true,
+                type_name: "String",
+                has_default: false,
+                doc: "Address to listen on.",
+            },
+            FieldDescriptor {
+                name: "port",
+                required: false,
+                type_name: "u16",
+                has_default: true,
+                doc: "Port to listen on.\n\nPorts below 1024 need privileges.",
+            },
+            FieldDescriptor {
+                name: "tags",
+                required: false,
+                type_name: "Option<Vec<String>>",
+                has_default: false,
+                doc: "",
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_descriptors_drive_generic_code() {
+    let required: Vec<_> = ServerConfig::FIELDS
+        .iter()
+        .filter(|field| field.required)
+        .map(|field| field.name)
+        .collect();
+    assert_eq!(required, ["host"]);
+}
+
+// =============================================================================
+// Other builder shapes
+// =============================================================================
+
+#[test]
+fn test_generic_and_lite_structs() {
+    #[allow(dead_code)]
+    #[derive(TypeStateBuilder)]
+    #[builder(field_descriptors = FieldDescriptor)]
+    struct Pair<'a, T> {
+        r#type: &'a str,
+        value: T,
+    }
+
+    #[allow(dead_code)]
+    #[derive(TypeStateBuilder)]
+    #[builder(lite, field_descriptors = FieldDescriptor)]
+    struct Point {
+        #[builder(required)]
+        x: i32,
+        #[builder(default = 0)]
+        y: i32,
+    }
+
+    let names: Vec<_> = Pair::<u8>::FIELDS
+        .iter()
+        .map(|field| (field.name, field.type_name))
+        .collect();
+    assert_eq!(names, [("type", "&'a str"), ("value", "T")]);
+    assert!(Point::FIELDS[1].has_default);
+}
@@ -0,0 +1,454 @@
+use std::convert::TryFrom;
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Validated fields on the type-state builder
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+pub struct Listener {
+    #[builder(required, validate = |host: &String| !host.is_empty(), validate_message = "host must not be empty")]
+    host: String,
+    #[builder(default = 8080, validate = |port| *port != 0)]
+    port: u16,
+}
+
+#[test]
+fn test_valid_fields_build() {
+    let listener = Listener::builder().host("localhost".to_string()).build();
+
+    assert_eq!(
+        listener,
+        Ok(Listener {
+            host: "localhost".to_string(),
+            port: 8080
+        })
+    );
+}
+
+#[test]
+fn test_invalid_field_is_reported() {
+    let error = Listener::builder().host(String::new()).build().unwrap_err();
+
+    assert_eq!(
+        error,
+        ListenerValidationError {
+            field: "host",
+            message: "host must not be empty",
+            location: error.location,
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "invalid value for field `host`: host must not be empty"
+    );
+}
+
+#[test]
+fn test_default_message_and_first_failure() {
+    let error = Listener::builder()
+        .host(String::new())
+        .port(0)
+        .build()
+        .unwrap_err();
+    assert_eq!(error.field, "host");
+
+    let error = Listener::builder()
+        .host("localhost".to_string())
+        .port(0)
+        .build()
+        .unwrap_err();
+    assert_eq!(error.field, "port");
+    assert_eq!(error.message, "validation failed");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_error_converts_into_boxed_error() {
+    fn listen(host: &str) -> Result<Listener, Box<dyn std::error::Error>> {
+        Ok(Listener::builder().host(host.to_string()).build()?)
+    }
+
+    assert_eq!(listen("localhost").unwrap().port, 8080);
+    assert_eq!(
+        listen("").unwrap_err().to_string(),
+        "invalid value for field `host`: host must not be empty"
+    );
+}
+
+#[test]
+fn test_complete_builder_converts_with_try_from() {
+    let builder = Listener::builder().host("localhost".to_string());
+    assert!(Listener::try_from(builder).is_ok());
+
+    let builder = Listener::builder().host(String::new());
+    assert!(Listener::try_from(builder).is_err());
+}
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(finishers(build_shared(into = std::sync::Arc<Self>)))]
+pub struct Quota {
+    #[builder(required, range = 1..=10, validate_on_set)]
+    limit: u8,
+    #[builder(range = 1..=4)]
+    burst: u8,
+}
+
+#[test]
+fn test_errors_record_the_caller_location() {
+    let quota = Quota::builder().limit(5).unwrap().burst(2).build().unwrap();
+    assert_eq!((quota.limit, quota.burst), (5, 2));
+
+    let at = |error: QuotaValidationError| (error.location.file(), error.location.line());
+
+    let builder = Quota::builder().limit(5).unwrap();
+    let (error, line) = (builder.build().unwrap_err(), line!());
+    assert_eq!(at(error), (file!(), line));
+
+    let builder = Quota::builder().limit(5).unwrap();
+    let (error, line) = (builder.build_clone().unwrap_err(), line!());
+    assert_eq!(at(error), (file!(), line));
+
+    let builder = Quota::builder().limit(5).unwrap();
+    let (error, line) = (builder.build_into::<Box<Quota>>().unwrap_err(), line!());
+    assert_eq!(at(error), (file!(), line));
+
+    let builder = Quota::builder().limit(5).unwrap();
+    let (error, line) = (builder.build_shared().unwrap_err(), line!());
+    assert_eq!(at(error), (file!(), line));
+
+    let builder = Quota::builder().limit(5).unwrap();
+    let (error, line) = (Quota::try_from(builder).unwrap_err(), line!());
+    assert_eq!(at(error), (file!(), line));
+
+    let (error, line) = (Quota::builder().limit(0).unwrap_err(), line!());
+    assert_eq!(at(error), (file!(), line));
+}
+
+// =============================================================================
+// Validated fields on the regular builder and with finishers
+// =============================================================================
+
+fn is_power_of_two(value: &usize) -> bool {
+    value.is_power_of_two()
+}
+
+#[derive(TypeStateBuilder, Debug)]
+pub struct Buffer {
+    #[builder(default = 1024, validate = is_power_of_two, validate_message = "must be a power of two")]
+    capacity: usize,
+}
+
+#[test]
+fn test_validate_accepts_function_paths() {
+    assert_eq!(Buffer::builder().build().unwrap().capacity, 1024);
+    assert_eq!(
+        Buffer::builder()
+            .capacity(64)
+            .build_clone()
+            .unwrap()
+            .capacity,
+        64
+    );
+    assert_eq!(
+        Buffer::builder()
+            .capacity(1000)
+            .build()
+            .unwrap_err()
+            .message,
+        "must be a power of two"
+    );
+}
+
+#[derive(Debug, PartialEq)]
+enum ConfigError {
+    Field(&'static str),
+    TooManyWorkers,
+}
+
+impl From<PoolValidationError> for ConfigError {
+    fn from(error: PoolValidationError) -> Self {
+        ConfigError::Field(error.field)
+    }
+}
+
+fn check_pool(pool: &Pool) -> Result<(), ConfigError> {
+    if pool.workers > 64 {
+        Err(ConfigError::TooManyWorkers)
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(finishers(build_checked(validate = check_pool, error = ConfigError)))]
+pub struct Pool {
+    #[builder(required, validate = |workers| *workers > 0)]
+    workers: usize,
+}
+
+#[test]
+fn test_finishers_convert_field_validation_errors() {
+    assert_eq!(
+        Pool::builder().workers(0).build_checked().unwrap_err(),
+        ConfigError::Field("workers")
+    );
+    assert_eq!(
+        Pool::builder().workers(100).build_checked().unwrap_err(),
+        ConfigError::TooManyWorkers
+    );
+    assert_eq!(
+        Pool::builder().workers(8).build_checked().unwrap().workers,
+        8
+    );
+    let boxed: Result<Box<Pool>, PoolValidationError> = Pool::builder().workers(8).build_into();
+    assert_eq!(boxed.unwrap().workers, 8);
+}
+
+#[test]
+fn test_computed_values_are_validated() {
+    #[derive(TypeStateBuilder, Debug)]
+    struct Range {
+        #[builder(required)]
+        start: u32,
+        #[builder(required)]
+        end: u32,
+        #[builder(computed = |b| b.end.wrapping_sub(b.start), validate = |len| *len < 100)]
+        len: u32,
+    }
+
+    let range = Range::builder().start(1).end(10).build().unwrap();
+    assert_eq!((range.start, range.end, range.len), (1, 10, 9));
+    assert_eq!(
+        Range::builder().start(10).end(1).build().unwrap_err().field,
+        "len"
+    );
+}
+
+// =============================================================================
+// Builder modules, state traits and build_by = "clone"
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(module = "user_builder", state_traits, build_by = "clone")]
+pub struct User {
+    #[builder(required, validate = |name: &String| name.len() <= 8)]
+    name: String,
+}
+
+fn finish<B: user_builder::UserBuilderFinish>(
+    builder: B,
+) -> Result<B::Target, user_builder::UserValidationError> {
+    builder.build()
+}
+
+#[test]
+fn test_validation_error_in_builder_module() {
+    let builder = User::builder().name("ada".to_string());
+    assert_eq!(builder.build().unwrap().name, "ada");
+    assert_eq!(finish(builder).unwrap().name, "ada");
+
+    let error = finish(User::builder().name("augusta ada".to_string())).unwrap_err();
+    assert_eq!(error.field, "name");
+}
+
+// =============================================================================
+// Shorthand checks: non_empty and range
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug)]
+pub struct Account {
+    #[builder(required, non_empty)]
+    name: String,
+    #[builder(non_empty, validate_message = "at least one role is needed", default = vec!["user"])]
+    roles: Vec<&'static str>,
+    #[builder(default = 30, range = 1..=100)]
+    quota: u8,
+    #[builder(default = 0.5, range = 0.0..1.0)]
+    ratio: f64,
+}
+
+#[test]
+fn test_shorthand_checks_accept_valid_values() {
+    let account = Account::builder()
+        .name("ada".to_string())
+        .quota(100)
+        .build()
+        .unwrap();
+
+    assert_eq!(account.name, "ada");
+    assert_eq!(account.roles, ["user"]);
+    assert_eq!(account.quota, 100);
+    assert_eq!(account.ratio, 0.5);
+}
+
+#[test]
+fn test_shorthand_checks_reject_invalid_values() {
+    let error = Account::builder().name(String::new()).build().unwrap_err();
+    assert_eq!(
+        error,
+        AccountValidationError {
+            field: "name",
+            message: "must not be empty",
+            location: error.location,
+        }
+    );
+
+    let error = Account::builder()
+        .name("ada".to_string())
+        .roles(Vec::new())
+        .build()
+        .unwrap_err();
+    assert_eq!(error.message, "at least one role is needed");
+
+    let error = Account::builder()
+        .name("ada".to_string())
+        .quota(0)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid value for field `quota`: must be in range 1..=100"
+    );
+
+    let error = Account::builder()
+        .name("ada".to_string())
+        .ratio(1.0)
+        .build()
+        .unwrap_err();
+    assert_eq!(error.field, "ratio");
+}
+
+#[test]
+fn test_shorthand_checks_run_before_validate() {
+    const MAX_LEN: usize = 4;
+
+    #[derive(TypeStateBuilder, Debug)]
+    struct Tag {
+        #[builder(required, non_empty, range = "a".to_string().., validate = |tag: &String| tag.len() <= MAX_LEN)]
+        tag: String,
+    }
+
+    assert_eq!(
+        Tag::builder().tag("rust".to_string()).build().unwrap().tag,
+        "rust"
+    );
+    assert_eq!(
+        Tag::builder()
+            .tag(String::new())
+            .build()
+            .unwrap_err()
+            .message,
+        "must not be empty"
+    );
+    assert_eq!(
+        Tag::builder()
+            .tag("Rust".to_string())
+            .build()
+            .unwrap_err()
+            .message,
+        "must be in range \"a\".to_string().."
+    );
+    assert_eq!(
+        Tag::builder()
+            .tag("rustacean".to_string())
+            .build()
+            .unwrap_err()
+            .message,
+        "validation failed"
+    );
+}
+
+// =============================================================================
+// Checks in the setters with validate_on_set
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+pub struct Endpoint {
+    #[builder(required, non_empty, validate_on_set)]
+    host: String,
+    #[builder(default = 443, range = 1..=65535, validate_on_set)]
+    port: u32,
+    #[builder(default = 3)]
+    retries: u8,
+}
+
+#[test]
+fn test_validating_setters_return_results() {
+    let endpoint = Endpoint::builder()
+        .port(8080)
+        .unwrap()
+        .host("example.com".to_string())
+        .unwrap()
+        .retries(5)
+        .build();
+
+    assert_eq!(
+        endpoint,
+        Endpoint {
+            host: "example.com".to_string(),
+            port: 8080,
+            retries: 5
+        }
+    );
+}
+
+#[test]
+fn test_validating_setters_fail_fast() {
+    let error = Endpoint::builder().host(String::new()).unwrap_err();
+    assert_eq!(
+        error,
+        EndpointValidationError {
+            field: "host",
+            message: "must not be empty",
+            location: error.location,
+        }
+    );
+
+    let error = Endpoint::builder().port(0).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid value for field `port`: must be in range 1..=65535"
+    );
+}
+
+#[test]
+fn test_validating_setters_propagate_with_question_mark() {
+    fn endpoint(host: &str, port: u32) -> Result<Endpoint, EndpointValidationError> {
+        Ok(Endpoint::builder()
+            .host(host.to_string())?
+            .port(port)?
+            .build())
+    }
+
+    assert_eq!(endpoint("localhost", 80).unwrap().port, 80);
+    assert_eq!(endpoint("localhost", 70000).unwrap_err().field, "port");
+}
+
+#[test]
+fn test_validate_on_set_checks_converted_values() {
+    #[derive(TypeStateBuilder, Debug)]
+    struct Mailbox {
+        #[builder(required, impl_into, validate = |name: &String| name.contains('@'), validate_message = "must be an address", validate_on_set)]
+        address: String,
+        #[builder(required, non_empty)]
+        folder: String,
+    }
+
+    let builder = Mailbox::builder().address("ada@example.com").unwrap();
+    assert_eq!(
+        Mailbox::builder().address("ada").unwrap_err().message,
+        "must be an address"
+    );
+
+    // Fields checked at build time keep the fallible build
+    let error = Mailbox::builder()
+        .address("ada@example.com")
+        .unwrap()
+        .folder(String::new())
+        .build()
+        .unwrap_err();
+    assert_eq!(error.field, "folder");
+    let mailbox = builder.folder("inbox".to_string()).build().unwrap();
+    assert_eq!(mailbox.address, "ada@example.com");
+}
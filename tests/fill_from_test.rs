@@ -0,0 +1,182 @@
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Layering a builder over existing instances
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, Clone, PartialEq)]
+#[builder(fill_from)]
+pub struct Config {
+    #[builder(required)]
+    name: String,
+    host: Option<String>,
+    #[builder(default = 8080)]
+    port: u16,
+    verbose: bool,
+}
+
+fn defaults() -> Config {
+    Config {
+        name: "defaults".to_string(),
+        host: Some("localhost".to_string()),
+        port: 80,
+        verbose: false,
+    }
+}
+
+#[test]
+fn test_unset_fields_are_taken_from_the_fallback() {
+    let config = Config::builder()
+        .port(9000)
+        .fill_from(&defaults())
+        .name("app".to_string())
+        .build();
+
+    assert_eq!(
+        config,
+        Config {
+            name: "app".to_string(),
+            host: Some("localhost".to_string()),
+            port: 9000,
+            verbose: false,
+        }
+    );
+}
+
+#[test]
+fn test_earlier_layers_take_precedence() {
+    let file = Config {
+        verbose: true,
+        host: None,
+        ..defaults()
+    };
+
+    let config = Config::builder()
+        .name("app".to_string())
+        .fill_from(&file)
+        .fill_from(&defaults())
+        .build();
+
+    assert_eq!((config.host, config.port, config.verbose), (None, 80, true));
+}
+
+#[test]
+fn test_fields_never_set_nor_filled_keep_their_defaults() {
+    let config = Config::builder().name("app".to_string()).build();
+    assert_eq!(
+        (config.host, config.port, config.verbose),
+        (None, 8080, false)
+    );
+}
+
+#[test]
+fn test_required_fields_are_not_filled() {
+    let config = Config::builder()
+        .fill_from(&defaults())
+        .name("app".to_string())
+        .build();
+    assert_eq!(config.name, "app");
+}
+
+// =============================================================================
+// Other builder shapes
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(fill_from)]
+pub struct Theme<T: Clone> {
+    accent: Option<T>,
+    #[builder(default_from = |builder| builder.accent.clone().flatten())]
+    border: Option<T>,
+    #[builder(skip_setter, default = 1)]
+    version: u8,
+}
+
+#[test]
+fn test_regular_generic_builder() {
+    let saved = Theme {
+        accent: Some("blue"),
+        border: Some("gray"),
+        version: 2,
+    };
+
+    let theme = Theme::builder()
+        .accent(Some("red"))
+        .fill_from(&saved)
+        .build();
+    assert_eq!(
+        theme,
+        Theme {
+            accent: Some("red"),
+            border: Some("gray"),
+            version: 2,
+        }
+    );
+
+    let theme = Theme::builder().accent(Some("red")).build();
+    assert_eq!((theme.border, theme.version), (Some("red"), 1));
+}
+
+#[test]
+fn test_fill_from_with_base_and_boxed() {
+    #[derive(TypeStateBuilder, Debug, Clone, PartialEq, Default)]
+    #[builder(fill_from, boxed, base = "Limits { retries: 3, timeout: 30 }")]
+    struct Limits {
+        retries: u32,
+        timeout: u32,
+    }
+
+    let saved = Limits {
+        retries: 5,
+        timeout: 60,
+    };
+    let limits = Limits::builder().timeout(10).fill_from(&saved).build();
+    assert_eq!(
+        *limits,
+        Limits {
+            retries: 5,
+            timeout: 10
+        }
+    );
+    assert_eq!(Limits::builder().build().retries, 3);
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(fill_from)]
+pub struct Layer<T> {
+    #[builder(required)]
+    name: String,
+    value: T,
+    extra: Option<T>,
+}
+
+#[test]
+fn test_unbounded_generic_fields_are_filled_and_defaulted() {
+    let saved = Layer {
+        name: "saved".to_string(),
+        value: 7u8,
+        extra: Some(9),
+    };
+
+    let layer = Layer::builder()
+        .value(1)
+        .fill_from(&saved)
+        .name("app".to_string())
+        .build();
+    assert_eq!((layer.value, layer.extra), (1, Some(9)));
+
+    let layer = Layer::<u8>::builder().name("app".to_string()).build();
+    assert_eq!((layer.value, layer.extra), (0, None));
+}
+
+#[test]
+fn test_generic_fields_need_clone_only_for_fill_from() {
+    #[derive(Debug, Default, PartialEq)]
+    struct Handle(u32);
+
+    let layer = Layer::builder()
+        .name("app".to_string())
+        .value(Handle(1))
+        .build();
+    assert_eq!((layer.value, layer.extra), (Handle(1), None));
+}
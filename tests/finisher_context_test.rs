@@ -0,0 +1,99 @@
+use type_state_builder::TypeStateBuilder;
+
+// The `garde` shorthand expands to paths under `::garde`. This crate stands in
+// for it with a minimal `Validate` trait and `Report` type.
+extern crate self as garde;
+
+pub trait Validate {
+    type Context;
+
+    fn validate_with(&self, context: &Self::Context) -> Result<(), Report>;
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Report(pub String);
+
+// =============================================================================
+// Validation functions taking a context
+// =============================================================================
+
+pub struct Limits {
+    max_port: u16,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PortTooHigh(u16);
+
+fn check_port(server: &Server, limits: &Limits) -> Result<(), PortTooHigh> {
+    if server.port > limits.max_port {
+        return Err(PortTooHigh(server.port));
+    }
+    Ok(())
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(finishers(build, try_build(validate = check_port, error = PortTooHigh, context = Limits)))]
+pub struct Server {
+    #[builder(required)]
+    port: u16,
+}
+
+#[test]
+fn test_finisher_passes_context_to_validate() {
+    let limits = Limits { max_port: 1024 };
+
+    let server = Server::builder().port(80).try_build(&limits).unwrap();
+    assert_eq!(server.port, 80);
+
+    let error = Server::builder().port(8080).try_build(&limits);
+    assert_eq!(error, Err(PortTooHigh(8080)));
+
+    // The regular build method takes no context
+    assert_eq!(Server::builder().port(8080).build().port, 8080);
+}
+
+// =============================================================================
+// The garde shorthand
+// =============================================================================
+
+pub struct Policy {
+    min_length: usize,
+}
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(finishers(build_validated(garde)))]
+pub struct Account {
+    #[builder(required)]
+    username: String,
+}
+
+impl Validate for Account {
+    type Context = Policy;
+
+    fn validate_with(&self, context: &Policy) -> Result<(), Report> {
+        if self.username.len() < context.min_length {
+            return Err(Report(format!(
+                "username: shorter than {}",
+                context.min_length
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_garde_finisher_returns_report() {
+    let policy = Policy { min_length: 3 };
+
+    let account = Account::builder()
+        .username("ada".to_string())
+        .build_validated(&policy)
+        .unwrap();
+    assert_eq!(account.username, "ada");
+
+    let report = Account::builder()
+        .username("al".to_string())
+        .build_validated(&policy)
+        .unwrap_err();
+    assert_eq!(report, Report("username: shorter than 3".to_string()));
+}
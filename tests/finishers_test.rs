@@ -0,0 +1,134 @@
+use std::rc::Rc;
+use std::sync::Arc;
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Validating and converting finishers next to the build method
+// =============================================================================
+
+#[derive(Debug, PartialEq)]
+pub enum ConfigError {
+    EmptyHost,
+    PortZero,
+}
+
+fn check_config(config: &Config) -> Result<(), ConfigError> {
+    if config.host.is_empty() {
+        return Err(ConfigError::EmptyHost);
+    }
+    if config.port == 0 {
+        return Err(ConfigError::PortZero);
+    }
+    Ok(())
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(finishers(
+    build,
+    try_build(validate = check_config, error = ConfigError),
+    build_arc(into = "Arc<Self>"),
+    try_build_rc(validate = check_config, error = ConfigError, into = Rc<Self>)
+))]
+pub struct Config {
+    #[builder(required)]
+    host: String,
+    #[builder(default = 8080)]
+    port: u16,
+}
+
+#[test]
+fn test_build_stays_infallible() {
+    let config = Config::builder().host(String::new()).port(0).build();
+    assert_eq!(config.port, 0);
+}
+
+#[test]
+fn test_validating_finisher() {
+    let config = Config::builder()
+        .host("localhost".to_string())
+        .try_build()
+        .unwrap();
+    assert_eq!(config.port, 8080);
+
+    let empty = Config::builder().host(String::new()).try_build();
+    assert_eq!(empty, Err(ConfigError::EmptyHost));
+
+    let zero = Config::builder().host("a".to_string()).port(0).try_build();
+    assert_eq!(zero, Err(ConfigError::PortZero));
+}
+
+#[test]
+fn test_converting_finishers() {
+    let shared: Arc<Config> = Config::builder().host("a".to_string()).build_arc();
+    assert_eq!(shared.host, "a");
+
+    let local: Rc<Config> = Config::builder()
+        .host("b".to_string())
+        .try_build_rc()
+        .unwrap();
+    assert_eq!(local.host, "b");
+    assert!(Config::builder()
+        .host("c".to_string())
+        .port(0)
+        .try_build_rc()
+        .is_err());
+}
+
+#[test]
+fn test_finishers_with_regular_builder_and_generics() {
+    #[derive(Debug)]
+    struct TooLong;
+
+    fn check_len<T>(list: &Bounded<T>) -> Result<(), TooLong> {
+        if list.items.len() > 2 {
+            Err(TooLong)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(
+        build_method = "finish",
+        finishers(finish, done, checked(validate = check_len, error = TooLong))
+    )]
+    struct Bounded<T> {
+        items: Vec<T>,
+    }
+
+    let list = Bounded::builder().items(vec![1, 2]).checked().unwrap();
+    assert_eq!(list.items, vec![1, 2]);
+    assert!(Bounded::builder().items(vec![1, 2, 3]).checked().is_err());
+    assert_eq!(Bounded::<u8>::builder().done(), Bounded::builder().finish());
+}
+
+#[test]
+fn test_validation_error_converted_with_from() {
+    #[derive(Debug, PartialEq)]
+    struct AppError(String);
+
+    impl From<ConfigError> for AppError {
+        fn from(error: ConfigError) -> Self {
+            AppError(format!("{error:?}"))
+        }
+    }
+
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(finishers(try_build(validate = check_port, error = AppError)))]
+    struct Listener {
+        #[builder(required)]
+        port: u16,
+    }
+
+    fn check_port(listener: &Listener) -> Result<(), ConfigError> {
+        if listener.port == 0 {
+            Err(ConfigError::PortZero)
+        } else {
+            Ok(())
+        }
+    }
+
+    let error = Listener::builder().port(0).try_build().unwrap_err();
+    assert_eq!(error, AppError("PortZero".to_string()));
+    assert_eq!(Listener::builder().port(1).try_build().unwrap().port, 1);
+}
@@ -0,0 +1,45 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(build_method = "finish")]
+struct Request<T: Clone> {
+    #[builder(required)]
+    url: String,
+    #[builder(required)]
+    body: T,
+    retries: u8,
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+struct Options {
+    verbose: bool,
+}
+
+fn send<T: Clone>(request: impl Into<Request<T>>) -> Request<T> {
+    request.into()
+}
+
+#[test]
+fn test_complete_type_state_builder_converts_into_struct() {
+    let builder = Request::builder()
+        .url("https://example.com".to_string())
+        .body(vec![1u8, 2])
+        .retries(3);
+
+    let request = send(builder);
+    assert_eq!(request.url, "https://example.com");
+    assert_eq!(request.body, vec![1, 2]);
+    assert_eq!(request.retries, 3);
+}
+
+#[test]
+fn test_regular_builder_converts_into_struct() {
+    let options: Options = Options::builder().verbose(true).into();
+    assert_eq!(options, Options { verbose: true });
+}
+
+#[test]
+fn test_from_matches_build_method() {
+    let make = || Request::builder().url("a".to_string()).body(1u32);
+    assert_eq!(Request::from(make()), make().finish());
+}
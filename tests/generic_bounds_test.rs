@@ -45,3 +45,203 @@ fn test_only_optional_generic_with_bounds() {
     assert_eq!(instance.field1, "hello");
     assert_eq!(instance.field2, Some("test".to_string()));
 }
+
+// =============================================================================
+// Bounds stated once in helper traits
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(build_by = "clone")]
+pub struct Pipeline<I, K: Ord + Clone>
+where
+    I: Iterator<Item = K> + Clone + std::fmt::Debug,
+    K: std::fmt::Debug,
+{
+    #[builder(required)]
+    source: I,
+    #[builder(required)]
+    first: Option<I::Item>,
+    #[builder(required)]
+    keys: Vec<K>,
+}
+
+#[test]
+fn test_helper_traits_imply_bounds_and_associated_types() {
+    let builder = Pipeline::builder()
+        .keys(vec![3, 1])
+        .first(Some(0))
+        .source(vec![1, 2].into_iter());
+    let pipeline = builder.build();
+
+    assert_eq!(pipeline.source.clone().max(), Some(2));
+    assert_eq!(pipeline.first, Some(0));
+    assert_eq!(builder.build().keys.iter().max(), Some(&3));
+}
+
+#[test]
+fn test_helper_traits_with_module_hidden_states_and_lite_mode() {
+    mod model {
+        use type_state_builder::TypeStateBuilder;
+
+        #[derive(TypeStateBuilder)]
+        #[builder(module = "builders", hide_states)]
+        pub struct Tagged<T: Clone + Default + PartialEq> {
+            #[builder(required)]
+            pub tag: T,
+            #[builder(required)]
+            pub count: u32,
+        }
+    }
+
+    #[derive(TypeStateBuilder)]
+    #[builder(lite)]
+    struct Pair<T: Copy + Default + PartialEq> {
+        #[builder(required)]
+        left: T,
+        #[builder(default = T::default())]
+        right: T,
+    }
+
+    let tagged = model::Tagged::builder().count(2).tag('x').build();
+    assert_eq!((tagged.tag, tagged.count), ('x', 2));
+
+    let pair = Pair::new(1.5).with_right(2.5);
+    assert!(pair.left != pair.right);
+}
+
+// =============================================================================
+// Default bounds only on the constructors
+// =============================================================================
+
+/// A value without a `Default` implementation
+#[derive(Debug, Clone, PartialEq)]
+struct NoDefault(u8);
+
+#[derive(TypeStateBuilder, Debug)]
+struct Slot<T> {
+    #[builder(required)]
+    name: String,
+    value: T,
+}
+
+/// A builder state that can be named without `T: Default`
+fn named<T>(builder: SlotBuilder_MissingName<T>) -> SlotBuilder_HasName<T> {
+    builder.name("slot".to_string())
+}
+
+#[test]
+fn test_default_bounds_only_required_to_start_the_builder() {
+    let slot = named(Slot::<u32>::builder()).value(7).build();
+    assert_eq!((slot.name.as_str(), slot.value), ("slot", 7));
+}
+
+#[test]
+fn test_default_bounds_skip_fields_with_explicit_defaults() {
+    #[derive(TypeStateBuilder, Debug)]
+    struct Fallback<T: Clone> {
+        #[builder(required)]
+        fallback: T,
+        #[builder(default = None)]
+        current: Option<T>,
+    }
+
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(lite)]
+    struct Entry<T> {
+        #[builder(required)]
+        key: T,
+        #[builder(default = Vec::new())]
+        history: Vec<T>,
+    }
+
+    let fallback = Fallback::builder().fallback(NoDefault(1)).build();
+    assert_eq!(fallback.fallback, NoDefault(1));
+    assert_eq!(fallback.current, None);
+
+    let entry = Entry::new(NoDefault(2)).with_history(vec![NoDefault(3)]);
+    assert_eq!(
+        (entry.key, entry.history),
+        (NoDefault(2), vec![NoDefault(3)])
+    );
+}
+
+#[test]
+fn test_default_bounds_with_builder_method_and_regular_builder() {
+    #[derive(TypeStateBuilder, Debug)]
+    struct Keyed<K, V> {
+        #[builder(required, builder_method)]
+        key: K,
+        value: V,
+    }
+
+    #[derive(TypeStateBuilder, Debug)]
+    struct Settings<T> {
+        value: T,
+        #[builder(default = 1)]
+        level: u8,
+    }
+
+    let keyed = Keyed::key(NoDefault(4)).value(String::from("v")).build();
+    assert_eq!((keyed.key, keyed.value.as_str()), (NoDefault(4), "v"));
+
+    let settings = Settings::<Vec<u8>>::builder().build();
+    assert!(settings.value.is_empty());
+    assert_eq!(settings.level, 1);
+}
+
+// =============================================================================
+// Defaults referencing generic parameters
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug)]
+struct Sample<T, U> {
+    #[builder(required)]
+    label: String,
+    #[builder(default = T::default())]
+    value: T,
+    #[builder(default = Vec::<U>::new())]
+    history: Vec<U>,
+    #[builder(default = { let fallback = <U as Default>::default(); Some(fallback) })]
+    fallback: Option<U>,
+}
+
+/// The builder states of `Sample` need no `Default` bounds
+fn labelled<T, U>(builder: SampleBuilder_MissingLabel<T, U>) -> SampleBuilder_HasLabel<T, U> {
+    builder.label("sample".to_string())
+}
+
+#[test]
+fn test_defaults_calling_default_on_generic_parameters() {
+    let sample = labelled(Sample::<u32, char>::builder()).build();
+
+    assert_eq!(sample.label, "sample");
+    assert_eq!(sample.value, 0);
+    assert!(sample.history.is_empty());
+    assert_eq!(sample.fallback, Some('\0'));
+}
+
+#[test]
+fn test_generic_defaults_with_regular_builder_and_lite_mode() {
+    #[derive(TypeStateBuilder, Debug)]
+    struct Counter<T> {
+        #[builder(default = Default::default())]
+        count: T,
+        #[builder(default = (T::default(), 1))]
+        range: (T, u8),
+    }
+
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(lite)]
+    struct Cell<T> {
+        #[builder(required)]
+        key: NoDefault,
+        #[builder(default = T::default())]
+        value: T,
+    }
+
+    let counter = Counter::<i64>::builder().build();
+    assert_eq!((counter.count, counter.range), (0, (0, 1)));
+
+    let cell = Cell::<String>::new(NoDefault(5)).with_value("set".to_string());
+    assert_eq!((cell.key, cell.value.as_str()), (NoDefault(5), "set"));
+}
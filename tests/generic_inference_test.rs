@@ -0,0 +1,93 @@
+//! Tests that generic parameters are bound by the setters instead of
+//! requiring a turbofish on `builder()`.
+
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+struct Pair<K, V> {
+    #[builder(required)]
+    key: K,
+    #[builder(required)]
+    value: V,
+}
+
+#[test]
+fn test_required_setters_bind_generics() {
+    let pair = Pair::builder().key("id").value(42u64).build();
+    assert_eq!(
+        pair,
+        Pair {
+            key: "id",
+            value: 42
+        }
+    );
+}
+
+#[test]
+fn test_generics_bound_in_any_setter_order() {
+    let pair = Pair::builder().value(vec![1u8]).key('k').build();
+    assert_eq!(pair.key, 'k');
+    assert_eq!(pair.value, vec![1]);
+}
+
+#[test]
+fn test_builder_stored_before_setters_are_called() {
+    // The parameters stay open until a setter or the binding pins them
+    let builder = Pair::builder();
+    let builder = builder.key(1i32);
+    let pair: Pair<i32, &str> = builder.value("one").build();
+    assert_eq!(pair.value, "one");
+}
+
+#[test]
+fn test_optional_setters_bind_generics() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Settings<T: Default> {
+        #[builder(required)]
+        name: String,
+        value: T,
+    }
+
+    let settings = Settings::builder()
+        .name("retries".to_string())
+        .value(3u8)
+        .build();
+    assert_eq!(settings.value, 3u8);
+}
+
+#[test]
+fn test_regular_builder_binds_generics() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Wrapper<T> {
+        value: Option<T>,
+    }
+
+    let wrapper = Wrapper::builder().value(Some(2.5f32)).build();
+    assert_eq!(wrapper.value, Some(2.5));
+}
+
+#[test]
+fn test_generic_bound_through_container_type() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Batch<T: Clone> {
+        #[builder(required)]
+        items: Vec<T>,
+    }
+
+    let batch = Batch::builder().items(vec!["a", "b"]).build();
+    assert_eq!(batch.items, ["a", "b"]);
+}
+
+#[test]
+fn test_impl_into_setters_need_annotation() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(impl_into)]
+    struct Label<T> {
+        #[builder(required)]
+        text: T,
+    }
+
+    // `impl Into<T>` cannot pin `T`, so it comes from the binding instead
+    let label: Label<String> = Label::builder().text("hello").build();
+    assert_eq!(label.text, "hello");
+}
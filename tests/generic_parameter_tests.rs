@@ -204,3 +204,31 @@ fn test_const_generic_parameters() {
     assert_eq!(instance.name, "array");
     assert_eq!(instance.optional, None);
 }
+
+#[test]
+fn test_const_expressions_in_field_types() {
+    const HEADER_LEN: usize = 4;
+
+    #[derive(Debug, PartialEq)]
+    struct Chunk<const M: usize>([u8; M]);
+
+    // Stable Rust accepts const items in array lengths and braced parameters;
+    // arithmetic on `N` itself needs `generic_const_exprs`
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Frame<const N: usize> {
+        #[builder(required)]
+        header: [u8; HEADER_LEN * 2],
+
+        #[builder(required)]
+        body: [u8; N],
+
+        #[builder(default = Chunk([0; N]))]
+        trailer: Chunk<{ N }>,
+    }
+
+    let frame = Frame::<2>::builder().body([1, 2]).header([0; 8]).build();
+
+    assert_eq!(frame.header, [0; 8]);
+    assert_eq!(frame.body, [1, 2]);
+    assert_eq!(frame.trailer, Chunk([0, 0]));
+}
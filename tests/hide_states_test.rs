@@ -0,0 +1,82 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(hide_states, state_traits)]
+pub struct Server {
+    #[builder(required)]
+    host: String,
+    #[builder(required)]
+    port: u16,
+    #[builder(default = 4)]
+    workers: usize,
+}
+
+// The state names are free because the states live in an anonymous const
+#[allow(non_camel_case_types, dead_code)]
+struct ServerBuilder_MissingHost_MissingPort;
+
+// =============================================================================
+// Hidden builder states
+// =============================================================================
+
+#[test]
+fn test_hidden_states_build() {
+    let server = Server::builder()
+        .port(8080)
+        .host("localhost".to_string())
+        .build();
+
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.workers, 4);
+}
+
+#[test]
+fn test_initial_state_is_nameable_through_alias() {
+    fn with_defaults(builder: ServerBuilder) -> Server {
+        builder.host("example.com".to_string()).port(443).build()
+    }
+
+    assert_eq!(with_defaults(Server::builder()).host, "example.com");
+}
+
+#[test]
+fn test_state_traits_reach_hidden_states() {
+    fn finish<B: ServerBuilderFinish>(builder: B) -> B::Target {
+        builder.build()
+    }
+
+    let server = finish(Server::builder().host("a".to_string()).port(1).workers(2));
+    assert_eq!(server.workers, 2);
+}
+
+// =============================================================================
+// Nesting and generics
+// =============================================================================
+
+#[test]
+fn test_nested_hidden_builder() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(hide_states, nestable)]
+    struct Endpoint<T: Clone> {
+        #[builder(required)]
+        path: String,
+        #[builder(required)]
+        payload: T,
+    }
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(hide_states)]
+    struct Route {
+        #[builder(required, nested)]
+        endpoint: Endpoint<u8>,
+        name: Option<String>,
+    }
+
+    let route = Route::builder()
+        .endpoint_with(|b| b.payload(7).path("/health".to_string()))
+        .build();
+
+    assert_eq!(route.endpoint.path, "/health");
+    assert_eq!(route.endpoint.payload, 7);
+    assert_eq!(route.name, None);
+}
@@ -0,0 +1,77 @@
+use type_state_builder::TypeStateBuilder;
+
+type Formatter = Box<dyn for<'a> Fn(&'a str) -> String + Send>;
+
+#[derive(TypeStateBuilder)]
+struct Pipeline<'s, T> {
+    #[builder(required)]
+    callback: Box<dyn for<'a> Fn(&'a str) -> String + Send>,
+
+    #[builder(required, skip_debug)]
+    project: Box<dyn for<'a> Fn(&'a T) -> &'a str + Send + Sync + 's>,
+
+    #[builder(default = Box::new(|text| text.len()))]
+    measure: Box<dyn for<'a> Fn(&'a str) -> usize + Send>,
+
+    #[builder(lazy_setter)]
+    fallback: Option<Formatter>,
+}
+
+// =============================================================================
+// Higher-ranked trait object fields
+// =============================================================================
+
+#[test]
+fn test_higher_ranked_trait_object_fields() {
+    let pipeline = Pipeline::builder()
+        .project(Box::new(|value: &String| value.as_str()))
+        .callback(Box::new(|text| text.to_uppercase()))
+        .fallback_with(|| Some(Box::new(|text: &str| text.to_string()) as Formatter))
+        .build();
+
+    let value = "hrtb".to_string();
+    assert_eq!((pipeline.callback)((pipeline.project)(&value)), "HRTB");
+    assert_eq!((pipeline.measure)("four"), 4);
+    assert!(pipeline.fallback.is_some());
+}
+
+#[test]
+fn test_higher_ranked_fields_in_debug_output() {
+    let builder = Pipeline::<u8>::builder().callback(Box::new(|text| text.to_string()));
+    let debug = format!("{builder:?}");
+
+    assert!(debug.contains("callback: <non-Debug>"), "{debug}");
+    assert!(!debug.contains("project"), "{debug}");
+}
+
+// =============================================================================
+// Higher-ranked bounds and function pointers
+// =============================================================================
+
+#[test]
+fn test_higher_ranked_bounds_and_function_pointers() {
+    #[derive(TypeStateBuilder, Debug)]
+    struct Splitter<F>
+    where
+        F: for<'a> Fn(&'a str) -> &'a str,
+    {
+        #[builder(required)]
+        head: F,
+
+        #[builder(required)]
+        tail: for<'a> fn(&'a str) -> &'a str,
+    }
+
+    fn head(text: &str) -> &str {
+        text.split(':').next().unwrap_or("")
+    }
+
+    fn tail(text: &str) -> &str {
+        text.split_once(':').map_or("", |(_, tail)| tail)
+    }
+
+    let splitter = Splitter::builder().tail(tail).head(head).build();
+
+    assert_eq!((splitter.head)("key:value"), "key");
+    assert_eq!((splitter.tail)("key:value"), "value");
+}
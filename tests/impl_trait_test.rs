@@ -0,0 +1,112 @@
+use std::any::type_name;
+use type_state_builder::TypeStateBuilder;
+
+/// A trait for handling the builders of different structs uniformly.
+pub trait Described {
+    fn describe(&self) -> &'static str {
+        type_name::<Self>()
+    }
+}
+
+pub trait Plugin<Config> {
+    const PRIORITY: u8 = 0;
+
+    fn priority(&self) -> u8 {
+        Self::PRIORITY
+    }
+}
+
+// =============================================================================
+// User traits on every type-state builder state
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(impl_trait = "crate::Described", impl_trait = Plugin<String>)]
+pub struct Server {
+    #[builder(required)]
+    host: String,
+    #[builder(required)]
+    port: u16,
+    workers: usize,
+}
+
+#[test]
+fn test_every_state_implements_user_traits() {
+    let empty = Server::builder();
+    let with_host = Server::builder().host("localhost".to_string());
+    let with_port = Server::builder().port(8080);
+    let complete = with_host.port(8080).workers(4);
+
+    assert!(empty
+        .describe()
+        .ends_with("ServerBuilder_MissingHost_MissingPort"));
+    assert!(with_port
+        .describe()
+        .ends_with("ServerBuilder_HasPort_MissingHost"));
+    assert!(complete
+        .describe()
+        .ends_with("ServerBuilder_HasHost_HasPort"));
+    assert_eq!(Plugin::<String>::priority(&complete), 0);
+
+    let server = complete.build();
+    assert_eq!(
+        (server.host.as_str(), server.port, server.workers),
+        ("localhost", 8080, 4)
+    );
+}
+
+// =============================================================================
+// Registries of heterogeneous builders
+// =============================================================================
+
+#[derive(TypeStateBuilder)]
+#[builder(impl_trait = "Described")]
+pub struct Logger {
+    level: u8,
+}
+
+#[derive(TypeStateBuilder)]
+#[builder(impl_trait = "Described", hide_states)]
+pub struct Cache<K: Ord> {
+    #[builder(required)]
+    capacity: usize,
+    keys: Vec<K>,
+}
+
+#[derive(TypeStateBuilder)]
+#[builder(impl_trait = "super::Described", module = "queue_builder")]
+pub struct Queue {
+    #[builder(required)]
+    name: String,
+}
+
+#[test]
+fn test_registry_of_builders() {
+    let registry: Vec<Box<dyn Described>> = vec![
+        Box::new(Logger::builder().level(3)),
+        Box::new(Cache::<String>::builder()),
+        Box::new(Queue::builder().name("jobs".to_string())),
+    ];
+
+    let names: Vec<&str> = registry.iter().map(|builder| builder.describe()).collect();
+    assert!(names[0].ends_with("LoggerBuilder"));
+    assert!(names[1].contains("CacheBuilder"));
+    assert!(names[2].ends_with("queue_builder::QueueBuilder_HasName"));
+}
+
+#[test]
+fn test_generic_builders_implement_user_traits() {
+    fn assert_described<B: Described>(_: &B) {}
+
+    let cache = Cache::<u32>::builder().capacity(16);
+    assert_described(&cache);
+
+    let cache = cache.keys(vec![1, 2]).build();
+    assert_eq!(cache.capacity, 16);
+    assert_eq!(cache.keys, [1, 2]);
+
+    let logger = Logger::builder().level(3).build();
+    assert_eq!(logger.level, 3);
+    let queue = Queue::builder().name("jobs".to_string()).build();
+    assert_eq!(queue.name, "jobs");
+}
@@ -0,0 +1,94 @@
+use std::collections::BTreeSet;
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Field-level into_iter
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+struct Post {
+    #[builder(required)]
+    title: String,
+    #[builder(into_iter)]
+    tags: Vec<String>,
+    #[builder(required, into_iter)]
+    authors: Vec<String>,
+}
+
+#[test]
+fn test_into_iter_setters_accept_arrays_of_convertible_items() {
+    let post = Post::builder()
+        .title("Hello".to_string())
+        .authors(["alice"])
+        .tags(["rust", "builders"])
+        .build();
+
+    assert_eq!(post.authors, vec!["alice".to_string()]);
+    assert_eq!(post.tags, vec!["rust".to_string(), "builders".to_string()]);
+}
+
+#[test]
+fn test_into_iter_setters_accept_any_iterator() {
+    let names = BTreeSet::from(["b", "a"]);
+    let post = Post::builder()
+        .title("Sorted".to_string())
+        .authors(names.iter().copied())
+        .tags(vec![String::from("owned")])
+        .build();
+
+    assert_eq!(post.authors, vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(post.tags, vec!["owned".to_string()]);
+}
+
+// =============================================================================
+// Struct-level into_iter and precedence over impl_into
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(into_iter, impl_into)]
+struct Command {
+    #[builder(required)]
+    program: String,
+    args: Vec<String>,
+    #[builder(into_iter = false)]
+    env: Vec<(String, String)>,
+    codes: Vec<i64>,
+}
+
+#[test]
+fn test_struct_level_into_iter_applies_to_vec_fields_only() {
+    let command = Command::builder()
+        .program("ls")
+        .args(["-l", "-a"])
+        .env(vec![("LANG".to_string(), "C".to_string())])
+        .codes([0u8, 1, 2])
+        .build();
+
+    assert_eq!(command.program, "ls");
+    assert_eq!(command.args, vec!["-l".to_string(), "-a".to_string()]);
+    assert_eq!(command.env, vec![("LANG".to_string(), "C".to_string())]);
+    assert_eq!(command.codes, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_into_iter_with_regular_builder_and_builder_method() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(into_iter)]
+    struct Filters {
+        include: Vec<String>,
+        exclude: Vec<String>,
+    }
+
+    let filters = Filters::builder().include(["*.rs"]).build();
+    assert_eq!(filters.include, vec!["*.rs".to_string()]);
+    assert!(filters.exclude.is_empty());
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Batch {
+        #[builder(required, builder_method, into_iter)]
+        ids: Vec<u64>,
+    }
+
+    let batch = Batch::ids([1u32, 2, 3]).build();
+    assert_eq!(batch.ids, vec![1, 2, 3]);
+}
@@ -0,0 +1,128 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+struct Config {
+    #[builder(required, lazy_setter)]
+    name: String,
+    #[builder(lazy_setter, default = "8".to_string())]
+    threads: String,
+    retries: u32,
+}
+
+// =============================================================================
+// Deferred evaluation
+// =============================================================================
+
+#[test]
+fn test_lazy_setters_run_at_build_time() {
+    let calls = Rc::new(Cell::new(0));
+
+    let builder = {
+        let calls = calls.clone();
+        Config::builder().name_with(move || {
+            calls.set(calls.get() + 1);
+            "server".to_string()
+        })
+    };
+    let builder = builder.threads_with(|| "16".to_string()).retries(3);
+    assert_eq!(calls.get(), 0);
+
+    let config = builder.build();
+    assert_eq!(calls.get(), 1);
+    assert_eq!(
+        config,
+        Config {
+            name: "server".to_string(),
+            threads: "16".to_string(),
+            retries: 3,
+        }
+    );
+}
+
+#[test]
+fn test_overridden_lazy_value_is_never_computed() {
+    let calls = Rc::new(Cell::new(0));
+    let expensive = {
+        let calls = calls.clone();
+        move || {
+            calls.set(calls.get() + 1);
+            "computed".to_string()
+        }
+    };
+
+    let config = Config::builder()
+        .name("plain".to_string())
+        .threads_with(expensive)
+        .threads("4".to_string())
+        .build();
+
+    assert_eq!(calls.get(), 0);
+    assert_eq!(config.threads, "4");
+}
+
+#[test]
+fn test_lazy_setter_keeps_default_and_regular_setter() {
+    let config = Config::builder().name("plain".to_string()).build();
+    assert_eq!(config.threads, "8");
+
+    let config = Config::builder()
+        .threads_with(|| "2".to_string())
+        .threads("6".to_string())
+        .name_with(|| "late".to_string())
+        .build();
+    assert_eq!(config.name, "late");
+    assert_eq!(config.threads, "6");
+}
+
+#[test]
+fn test_debug_shows_deferred_values() {
+    let builder = Config::builder()
+        .name_with(|| "hidden".to_string())
+        .threads("4".to_string());
+    let debug = format!("{builder:?}");
+
+    assert!(debug.contains("name: \"<deferred>\""), "{debug}");
+    assert!(debug.contains("threads: \"4\""), "{debug}");
+}
+
+// =============================================================================
+// Regular builders, prefixes and generics
+// =============================================================================
+
+#[test]
+fn test_lazy_setter_with_regular_builder_and_prefix() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(setter_prefix = "with_")]
+    struct Options {
+        #[builder(lazy_setter)]
+        cache_size: usize,
+        verbose: bool,
+    }
+
+    let options = Options::builder()
+        .with_cache_size_with(|| 1024)
+        .with_verbose(true)
+        .build();
+
+    assert_eq!(
+        options,
+        Options {
+            cache_size: 1024,
+            verbose: true
+        }
+    );
+}
+
+#[test]
+fn test_lazy_setter_with_generic_field() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Holder<T: 'static> {
+        #[builder(required, lazy_setter)]
+        value: T,
+    }
+
+    let holder = Holder::builder().value_with(|| vec![1, 2, 3]).build();
+    assert_eq!(holder.value, vec![1, 2, 3]);
+}
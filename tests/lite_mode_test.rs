@@ -0,0 +1,98 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(lite)]
+struct Config {
+    #[builder(required)]
+    host: String,
+    #[builder(required)]
+    port: u16,
+    #[builder(default = 4)]
+    workers: usize,
+    tls: Option<bool>,
+    #[builder(skip_setter, default = "v1".to_string())]
+    version: String,
+}
+
+// =============================================================================
+// Methods on the struct
+// =============================================================================
+
+#[test]
+fn test_lite_new_takes_required_fields() {
+    let config = Config::new("localhost".to_string(), 8080);
+
+    assert_eq!(
+        config,
+        Config {
+            host: "localhost".to_string(),
+            port: 8080,
+            workers: 4,
+            tls: None,
+            version: "v1".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_lite_with_methods_chain_on_the_struct() {
+    let config = Config::new("localhost".to_string(), 443)
+        .with_tls(Some(true))
+        .with_workers(8);
+
+    assert_eq!(config.tls, Some(true));
+    assert_eq!(config.workers, 8);
+}
+
+// =============================================================================
+// Combined attributes
+// =============================================================================
+
+#[test]
+fn test_lite_with_conversions_and_generics() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(lite, impl_into, option_into, setter_prefix = "set_")]
+    struct Labelled<T> {
+        #[builder(required)]
+        label: String,
+        #[builder(required, impl_into = false)]
+        value: T,
+        note: Option<String>,
+    }
+
+    let labelled = Labelled::new("answer", 42).set_note("checked".to_string());
+
+    assert_eq!(labelled.label, "answer");
+    assert_eq!(labelled.value, 42);
+    assert_eq!(labelled.note.as_deref(), Some("checked"));
+}
+
+#[test]
+fn test_lite_with_const_constructor() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(lite, const)]
+    struct Limits {
+        #[builder(required)]
+        max: u32,
+        #[builder(default = 0)]
+        min: u32,
+    }
+
+    const LIMITS: Limits = Limits::new(10).with_min(1);
+    assert_eq!(LIMITS, Limits { max: 10, min: 1 });
+}
+
+#[test]
+fn test_lite_skip_setter_without_default() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(lite)]
+    struct Counter {
+        #[builder(required)]
+        name: String,
+        #[builder(skip_setter)]
+        hits: u64,
+    }
+
+    let counter = Counter::new("page".to_string());
+    assert_eq!(counter.hits, 0);
+}
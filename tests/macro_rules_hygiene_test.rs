@@ -0,0 +1,113 @@
+use type_state_builder::TypeStateBuilder;
+
+mod units {
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    pub struct Meters(pub u32);
+}
+
+macro_rules! define_config {
+    ($name:ident { $($field:ident: $ty:ty),* $(,)? }) => {
+        #[derive(TypeStateBuilder, Debug, PartialEq)]
+        #[builder(nestable)]
+        pub struct $name {
+            #[builder(required)]
+            pub id: u32,
+            $(pub $field: $ty,)*
+            #[builder(default = $crate::units::Meters(5))]
+            pub range: $crate::units::Meters,
+            #[builder(computed = |b| b.id * 2)]
+            pub double: u32,
+            #[builder(resolve = |value: String, id: &u32| format!("{value}-{id}"))]
+            pub label: String,
+        }
+    };
+}
+
+define_config!(Sensor { name: String, offset: Option<i32> });
+
+macro_rules! define_pair {
+    ($name:ident, $first:ident, $second:ident, $value:ty) => {
+        #[derive(TypeStateBuilder, Debug)]
+        struct $name<T: Clone> {
+            #[builder(required)]
+            $first: T,
+            #[builder(required, converter = |value: $value| value.into())]
+            $second: String,
+            #[builder(required, nested)]
+            inner: Sensor,
+        }
+    };
+}
+
+#[test]
+fn test_macro_generated_struct_with_crate_paths() {
+    let sensor = Sensor::builder()
+        .name("probe".to_string())
+        .id(7)
+        .label("s".to_string())
+        .build();
+
+    assert_eq!(sensor.id, 7);
+    assert_eq!(sensor.range, units::Meters(5));
+    assert_eq!(sensor.double, 14);
+    assert_eq!(sensor.label, "s-7");
+    assert_eq!(sensor.offset, None);
+}
+
+#[test]
+fn test_macro_generated_field_names_and_types() {
+    define_pair!(Pair, left, right, &str);
+
+    let pair = Pair::builder()
+        .right("r")
+        .inner_with(|b| b.id(1).label("inner".to_string()))
+        .left(1u8)
+        .build();
+    assert_eq!(pair.left, 1);
+    assert_eq!(pair.right, "r");
+    assert_eq!(pair.inner.label, "inner-1");
+}
+
+macro_rules! define_with_fragments {
+    ($name:ident, $(#[$attr:meta])* $field:ident: $ty:ty, $nested_ty:ty) => {
+        #[derive(TypeStateBuilder, Debug)]
+        #[builder(into_iter, option_into)]
+        struct $name {
+            $(#[$attr])*
+            $field: $ty,
+            tags: Vec<String>,
+            list: $ty,
+            maybe: Option<$ty>,
+            #[builder(nested)]
+            nested: $nested_ty,
+            #[builder(required, transparent)]
+            note: Option<String>,
+        }
+    };
+}
+
+#[derive(TypeStateBuilder, Debug, Default, PartialEq)]
+#[builder(nestable)]
+struct Leaf {
+    value: u8,
+}
+
+#[test]
+fn test_macro_fragments_are_seen_through() {
+    define_with_fragments!(Fragments, #[builder(required)] items: Vec<String>, Leaf);
+
+    let fragments = Fragments::builder()
+        .items(["a", "b"])
+        .note(None)
+        .list(["c"])
+        .maybe(vec!["d".to_string()])
+        .nested_with(|b| b.value(3))
+        .build();
+
+    assert_eq!(fragments.items, vec!["a", "b"]);
+    assert_eq!(fragments.list, vec!["c"]);
+    assert_eq!(fragments.maybe, Some(vec!["d".to_string()]));
+    assert_eq!(fragments.nested, Leaf { value: 3 });
+    assert!(fragments.tags.is_empty());
+    assert_eq!(fragments.note, None);
+}
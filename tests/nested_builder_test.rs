@@ -0,0 +1,162 @@
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Nested type-state and regular builders
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(nestable)]
+pub struct Address {
+    #[builder(required)]
+    city: String,
+    #[builder(required)]
+    zip: String,
+    line2: Option<String>,
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq, Default)]
+#[builder(nestable)]
+pub struct Preferences {
+    newsletter: bool,
+    language: Option<String>,
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+pub struct Customer {
+    #[builder(required)]
+    name: String,
+    #[builder(required, nested)]
+    address: Address,
+    #[builder(nested)]
+    preferences: Preferences,
+}
+
+#[test]
+fn test_nested_required_field_transitions_state() {
+    let customer = Customer::builder()
+        .address_with(|b| b.city("Paris".to_string()).zip("75001".to_string()))
+        .name("Alice".to_string())
+        .build();
+
+    assert_eq!(customer.address.city, "Paris");
+    assert_eq!(customer.address.zip, "75001");
+    assert_eq!(customer.address.line2, None);
+    assert_eq!(customer.preferences, Preferences::default());
+}
+
+#[test]
+fn test_nested_optional_field_with_regular_builder() {
+    let customer = Customer::builder()
+        .name("Bob".to_string())
+        .preferences_with(|b| b.newsletter(true).language(Some("fr".to_string())))
+        .address_with(|b| {
+            b.zip("10115".to_string())
+                .line2(Some("Hinterhaus".to_string()))
+                .city("Berlin".to_string())
+        })
+        .build();
+
+    assert!(customer.preferences.newsletter);
+    assert_eq!(customer.preferences.language, Some("fr".to_string()));
+    assert_eq!(customer.address.line2, Some("Hinterhaus".to_string()));
+}
+
+#[test]
+fn test_regular_setter_still_available() {
+    let address = Address::builder()
+        .city("Rome".to_string())
+        .zip("00100".to_string())
+        .build();
+
+    let customer = Customer::builder()
+        .name("Carla".to_string())
+        .address(address)
+        .build();
+
+    assert_eq!(customer.address.city, "Rome");
+}
+
+// =============================================================================
+// Generics, module paths, prefixes and impl_into
+// =============================================================================
+
+mod geo {
+    use type_state_builder::TypeStateBuilder;
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(build_method = "finish", nestable)]
+    pub struct Point<T: Copy> {
+        #[builder(required)]
+        pub x: T,
+        #[builder(required)]
+        pub y: T,
+    }
+}
+
+#[test]
+fn test_nested_generic_type_in_other_module() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(setter_prefix = "with_", impl_into)]
+    struct Segment {
+        #[builder(required, nested)]
+        start: geo::Point<i32>,
+        #[builder(required, nested)]
+        end: geo::Point<i32>,
+        #[builder(required)]
+        label: String,
+    }
+
+    let segment = Segment::builder()
+        .with_start_with(|b| b.x(0).y(0))
+        .with_end_with(|b| b.y(4).x(3))
+        .with_label("diagonal")
+        .build();
+
+    assert_eq!(segment.start, geo::Point { x: 0, y: 0 });
+    assert_eq!(segment.end, geo::Point { x: 3, y: 4 });
+}
+
+#[test]
+fn test_nested_with_const_outer_builder() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(const, nestable)]
+    struct Limits {
+        #[builder(required)]
+        max: u32,
+        #[builder(default = 0)]
+        min: u32,
+    }
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(const)]
+    struct Quota {
+        #[builder(required, nested)]
+        limits: Limits,
+    }
+
+    const QUOTA: Quota = Quota::builder()
+        .limits(Limits::builder().max(5).build())
+        .build();
+
+    let quota = Quota::builder().limits_with(|b| b.max(5)).build();
+    assert_eq!(quota, QUOTA);
+}
+
+// Without `nestable`, the derive adds no nesting items next to the struct
+#[derive(TypeStateBuilder, Debug)]
+pub struct Widget {
+    #[builder(required)]
+    pub id: u32,
+}
+
+#[allow(dead_code)]
+pub struct WidgetBuilderStart;
+
+#[allow(dead_code)]
+pub type WidgetBuilderReady = ();
+
+#[test]
+fn test_items_named_like_nesting_aliases_do_not_clash() {
+    let widget = Widget::builder().id(3).build();
+    assert_eq!(widget.id, 3);
+}
@@ -0,0 +1,119 @@
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Building collection elements in one chain
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(nestable)]
+pub struct Node {
+    #[builder(required)]
+    label: String,
+    #[builder(nested_each = "add_child")]
+    children: Vec<Node>,
+}
+
+#[test]
+fn test_elements_are_pushed_in_order() {
+    let root = Node::builder()
+        .add_child(|b| b.label("a".to_string()))
+        .label("root".to_string())
+        .add_child(|b| {
+            b.label("b".to_string())
+                .add_child(|b| b.label("b1".to_string()))
+        })
+        .build();
+
+    let leaf = |label: &str| Node {
+        label: label.to_string(),
+        children: Vec::new(),
+    };
+    assert_eq!(
+        root,
+        Node {
+            label: "root".to_string(),
+            children: vec![
+                leaf("a"),
+                Node {
+                    label: "b".to_string(),
+                    children: vec![leaf("b1")],
+                },
+            ],
+        }
+    );
+}
+
+#[test]
+fn test_regular_setter_still_available() {
+    let leaf = Node::builder().label("leaf".to_string()).build();
+    let root = Node::builder()
+        .label("root".to_string())
+        .children(vec![leaf])
+        .add_child(|b| b.label("extra".to_string()))
+        .build();
+
+    let labels: Vec<_> = root.children.iter().map(|n| n.label.as_str()).collect();
+    assert_eq!(labels, ["leaf", "extra"]);
+}
+
+// =============================================================================
+// Other builder shapes
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(nestable)]
+pub struct Column<T> {
+    #[builder(required)]
+    name: String,
+    value: Option<T>,
+}
+
+#[test]
+fn test_regular_builder_with_generic_elements() {
+    #[derive(TypeStateBuilder)]
+    #[builder(all_required)]
+    struct Query<T> {
+        #[builder(nested_each = "column")]
+        columns: Vec<Column<T>>,
+    }
+
+    let query = Query::builder()
+        .column(|b| b.name("id".to_string()).value(Some(1u8)))
+        .column(|b| b.name("age".to_string()))
+        .build();
+
+    assert_eq!(
+        query.columns,
+        vec![
+            Column {
+                name: "id".to_string(),
+                value: Some(1)
+            },
+            Column {
+                name: "age".to_string(),
+                value: None
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_nested_each_with_prefixed_setters() {
+    #[derive(TypeStateBuilder)]
+    #[builder(setter_prefix = "with_")]
+    struct Table {
+        #[builder(required)]
+        name: String,
+        #[builder(nested_each = "add_column", default = Vec::with_capacity(4))]
+        columns: Vec<Column<bool>>,
+    }
+
+    let table = Table::builder()
+        .add_column(|b| b.name("active".to_string()))
+        .with_name("users".to_string())
+        .build();
+
+    assert_eq!(table.name, "users");
+    assert_eq!(table.columns.len(), 1);
+    assert!(table.columns.capacity() >= 4);
+}
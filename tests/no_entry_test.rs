@@ -0,0 +1,85 @@
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Custom entry functions returning a mid-state builder
+// =============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Runtime {
+    id: u32,
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(no_entry, nestable)]
+pub struct Client {
+    #[builder(required)]
+    runtime: Runtime,
+    #[builder(required)]
+    name: String,
+    #[builder(default = 3)]
+    retries: u8,
+}
+
+impl Client {
+    /// Starts a client builder on the given runtime
+    pub fn on(runtime: Runtime) -> ClientBuilder_HasRuntime_MissingName {
+        ClientBuilder_MissingRuntime_MissingName::new().runtime(runtime)
+    }
+}
+
+#[test]
+fn test_custom_entry_prepopulates_fields() {
+    let client = Client::on(Runtime { id: 1 })
+        .name("api".to_string())
+        .retries(5)
+        .build();
+
+    assert_eq!(
+        client,
+        Client {
+            runtime: Runtime { id: 1 },
+            name: "api".to_string(),
+            retries: 5,
+        }
+    );
+}
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(no_entry, nestable)]
+pub struct Limits {
+    #[builder(default = 10)]
+    max: u32,
+    min: u32,
+}
+
+#[test]
+fn test_no_entry_regular_builder() {
+    let limits = LimitsBuilder::new().min(1).build();
+    assert_eq!(limits, Limits { max: 10, min: 1 });
+
+    let limits = LimitsBuilder::default().build();
+    assert_eq!(limits, Limits { max: 10, min: 0 });
+}
+
+// =============================================================================
+// Nesting a builder without an entry method
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+pub struct Service {
+    #[builder(required, nested)]
+    client: Client,
+    #[builder(required, nested)]
+    limits: Limits,
+}
+
+#[test]
+fn test_nested_builders_start_from_the_initial_state() {
+    let service = Service::builder()
+        .client_with(|b| b.runtime(Runtime { id: 2 }).name("db".to_string()))
+        .limits_with(|b| b.max(20))
+        .build();
+
+    assert_eq!(service.client.runtime, Runtime { id: 2 });
+    assert_eq!(service.limits, Limits { max: 20, min: 0 });
+}
@@ -0,0 +1,83 @@
+use type_state_builder::TypeStateBuilder;
+
+/// Other crates can only construct this struct through its builder
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Endpoint {
+    #[builder(required)]
+    pub host: String,
+    #[builder(default = 443)]
+    pub port: u16,
+    pub path: Option<String>,
+}
+
+// =============================================================================
+// Non-exhaustive structs
+// =============================================================================
+
+#[test]
+fn test_non_exhaustive_struct_with_type_state_builder() {
+    let endpoint = Endpoint::builder()
+        .host("example.com".to_string())
+        .path(Some("/api".to_string()))
+        .build();
+
+    assert_eq!(endpoint.host, "example.com");
+    assert_eq!(endpoint.port, 443);
+    assert_eq!(endpoint.path.as_deref(), Some("/api"));
+}
+
+#[test]
+fn test_non_exhaustive_struct_with_regular_builder_and_lite_mode() {
+    #[derive(TypeStateBuilder, Debug, Default, PartialEq)]
+    #[non_exhaustive]
+    pub struct Limits {
+        pub max: Option<u32>,
+        #[builder(default = 1)]
+        pub min: u32,
+    }
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(lite)]
+    #[non_exhaustive]
+    pub struct Tag {
+        #[builder(required)]
+        pub name: String,
+        pub color: Option<String>,
+    }
+
+    let limits = Limits::builder().max(Some(10)).build();
+    assert_eq!((limits.max, limits.min), (Some(10), 1));
+
+    let tag = Tag::new("urgent".to_string()).with_color(Some("red".to_string()));
+    assert_eq!(tag.color.as_deref(), Some("red"));
+}
+
+// =============================================================================
+// Builder types marked non_exhaustive
+// =============================================================================
+
+/// The builder states stay exhaustive although the struct is not
+#[derive(TypeStateBuilder, Debug)]
+#[builder(non_exhaustive = false, module = "session_builder")]
+#[non_exhaustive]
+pub struct Session {
+    #[builder(required, builder_method)]
+    pub user: String,
+    pub ttl: Option<u64>,
+}
+
+#[test]
+fn test_builder_non_exhaustive_attribute() {
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(non_exhaustive, hide_states, state_traits)]
+    pub struct Exhaustive {
+        #[builder(required)]
+        pub id: u32,
+    }
+
+    assert_eq!(Exhaustive::builder().id(7).build().id, 7);
+
+    let session = Session::user("ada".to_string()).ttl(Some(60)).build();
+    assert_eq!((session.user.as_str(), session.ttl), ("ada", Some(60)));
+}
@@ -0,0 +1,67 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(option_into)]
+struct Profile {
+    #[builder(required)]
+    name: String,
+    nickname: Option<String>,
+    age: Option<u32>,
+    #[builder(impl_into = false)]
+    email: Option<String>,
+    score: u32,
+}
+
+// =============================================================================
+// Option setters
+// =============================================================================
+
+#[test]
+fn test_option_setters_accept_bare_values_and_options() {
+    let profile = Profile::builder()
+        .name("Ada".to_string())
+        .nickname("ada".to_string())
+        .age(Some(36))
+        .build();
+
+    assert_eq!(profile.nickname, Some("ada".to_string()));
+    assert_eq!(profile.age, Some(36));
+
+    let cleared = Profile::builder()
+        .name("Ada".to_string())
+        .age(36)
+        .age(None)
+        .build();
+    assert_eq!(cleared.age, None);
+}
+
+#[test]
+fn test_field_level_impl_into_false_and_non_option_fields_unchanged() {
+    let profile = Profile::builder()
+        .email(Some("ada@example.com".to_string()))
+        .score(7)
+        .name("Ada".to_string())
+        .build();
+
+    assert_eq!(profile.email.as_deref(), Some("ada@example.com"));
+    assert_eq!(profile.score, 7);
+}
+
+#[test]
+fn test_option_into_with_regular_builder_and_generics() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(option_into)]
+    struct Filter<T> {
+        min: Option<T>,
+        max: Option<T>,
+    }
+
+    let filter = Filter::builder().min(1).max(None).build();
+    assert_eq!(
+        filter,
+        Filter {
+            min: Some(1),
+            max: None
+        }
+    );
+}
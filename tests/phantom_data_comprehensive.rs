@@ -405,3 +405,31 @@ fn test_struct_with_only_phantom_data_and_defaults() {
     assert_eq!(instance.auto_string, "default");
     assert_eq!(instance.auto_number, 42);
 }
+
+// ===== BUILDERS WITHOUT PHANTOM DATA =====
+
+#[test]
+fn test_builder_auto_traits_follow_fields() {
+    // Sync but not Send
+    struct Shared(PhantomData<std::sync::MutexGuard<'static, ()>>);
+
+    #[derive(TypeStateBuilder)]
+    struct View<'a, T> {
+        #[builder(required)]
+        item: &'a T,
+        label: Option<String>,
+    }
+
+    fn assert_send<S: Send>(_: &S) {}
+
+    // `&'a T` is Send whenever `T: Sync`, and so is every builder state
+    let shared = Shared(PhantomData);
+    let builder = View::builder();
+    assert_send(&builder);
+    let builder = builder.item(&shared);
+    assert_send(&builder);
+
+    let view = builder.label(Some("shared".to_string())).build();
+    assert!(std::ptr::eq(view.item, &shared));
+    assert_eq!(view.label.as_deref(), Some("shared"));
+}
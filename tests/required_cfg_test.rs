@@ -0,0 +1,117 @@
+use type_state_builder::{type_state_builder, TypeStateBuilder};
+
+// =============================================================================
+// Fields required under a predicate that holds
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+pub struct StrictServer {
+    #[builder(required)]
+    host: String,
+    #[builder(required(cfg(test)), default = 8080)]
+    port: u16,
+}
+
+#[test]
+fn test_field_is_required_when_predicate_holds() {
+    // The builder only has a build method once the port is set
+    let builder: StrictServerBuilder_MissingHost_MissingPort = StrictServer::builder();
+    let server = builder.port(443).host("example.com".to_string()).build();
+
+    assert_eq!(
+        server,
+        StrictServer {
+            host: "example.com".to_string(),
+            port: 443
+        }
+    );
+}
+
+// =============================================================================
+// Fields optional under a predicate that does not hold
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+pub struct RelaxedServer {
+    #[builder(required)]
+    host: String,
+    #[builder(required(cfg(not(test))), default = 8080)]
+    port: u16,
+}
+
+#[test]
+fn test_field_keeps_default_when_predicate_does_not_hold() {
+    let server = RelaxedServer::builder()
+        .host("example.com".to_string())
+        .build();
+    assert_eq!(server.port, 8080);
+
+    let server = RelaxedServer::builder()
+        .host("example.com".to_string())
+        .port(443)
+        .build();
+    assert_eq!(server.port, 443);
+}
+
+#[test]
+fn test_only_optional_fields_get_regular_builder() {
+    #[derive(TypeStateBuilder)]
+    struct Retry {
+        #[builder(required(cfg(not(test))), default = 3)]
+        attempts: u32,
+    }
+
+    assert_eq!(Retry::builder().build().attempts, 3);
+}
+
+// =============================================================================
+// Several predicates and other entry points
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug)]
+pub struct Limits {
+    #[builder(required(cfg(test)))]
+    max_connections: u32,
+    #[builder(required(cfg(all(test, not(test)))), default = 30)]
+    timeout_secs: u64,
+    #[builder(required(cfg(any(test, debug_assertions))))]
+    queue_len: usize,
+}
+
+#[test]
+fn test_each_field_uses_its_own_predicate() {
+    let limits = Limits::builder().max_connections(100).queue_len(16).build();
+
+    assert_eq!(limits.max_connections, 100);
+    assert_eq!(limits.timeout_secs, 30);
+    assert_eq!(limits.queue_len, 16);
+}
+
+#[test]
+fn test_all_required_keeps_predicate() {
+    #[derive(TypeStateBuilder)]
+    #[builder(all_required)]
+    struct Pool {
+        name: String,
+        #[builder(required(cfg(not(test))), default = 4)]
+        workers: usize,
+    }
+
+    let pool = Pool::builder().name("io".to_string()).build();
+    assert_eq!(pool.name, "io");
+    assert_eq!(pool.workers, 4);
+}
+
+#[type_state_builder]
+pub struct Token {
+    #[builder(required(cfg(test)), builder_only)]
+    secret: String,
+    #[builder(computed = |b| b.secret.len())]
+    secret_len: usize,
+}
+
+#[test]
+fn test_required_cfg_with_attribute_macro() {
+    let token = Token::builder().secret("hunter2".to_string()).build();
+    assert_eq!(token.secret_len, 7);
+}
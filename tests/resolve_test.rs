@@ -0,0 +1,98 @@
+//! Tests for `#[builder(resolve = ...)]` build-time transforms that read other fields.
+
+use std::path::PathBuf;
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+struct Workspace {
+    #[builder(required)]
+    base_dir: PathBuf,
+
+    #[builder(required, resolve = |path: PathBuf, base_dir: &PathBuf| base_dir.join(path))]
+    config: PathBuf,
+
+    #[builder(
+        default = PathBuf::from("target"),
+        resolve = |path: PathBuf, base_dir: &PathBuf| base_dir.join(path)
+    )]
+    output: PathBuf,
+}
+
+#[test]
+fn test_resolve_reads_already_set_field() {
+    let workspace = Workspace::builder()
+        .base_dir(PathBuf::from("/srv/app"))
+        .config(PathBuf::from("app.toml"))
+        .build();
+
+    assert_eq!(workspace.config, PathBuf::from("/srv/app/app.toml"));
+    assert_eq!(workspace.output, PathBuf::from("/srv/app/target"));
+}
+
+#[test]
+fn test_resolve_independent_of_setter_order() {
+    let workspace = Workspace::builder()
+        .config(PathBuf::from("app.toml"))
+        .output(PathBuf::from("/tmp/out"))
+        .base_dir(PathBuf::from("/srv/app"))
+        .build();
+
+    assert_eq!(workspace.config, PathBuf::from("/srv/app/app.toml"));
+    // Joining an absolute path replaces the base
+    assert_eq!(workspace.output, PathBuf::from("/tmp/out"));
+}
+
+#[test]
+fn test_resolve_in_regular_builder_with_several_fields() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Endpoint {
+        #[builder(default = "localhost".to_string())]
+        host: String,
+        #[builder(default = 80)]
+        port: u16,
+        #[builder(resolve = |url: String, host: &String, port: &u16| {
+            if url.is_empty() { format!("http://{host}:{port}") } else { url }
+        })]
+        url: String,
+    }
+
+    let endpoint = Endpoint::builder().port(8080).build();
+    assert_eq!(endpoint.url, "http://localhost:8080");
+
+    let explicit = Endpoint::builder()
+        .url("https://example.com".to_string())
+        .build();
+    assert_eq!(explicit.url, "https://example.com");
+}
+
+#[test]
+fn test_resolve_computes_skipped_field() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Rect {
+        #[builder(required)]
+        width: u32,
+        #[builder(required)]
+        height: u32,
+        #[builder(
+            skip_setter,
+            default = 0,
+            resolve = |_area: u32, width: &u32, height: &u32| width * height
+        )]
+        area: u32,
+    }
+
+    let rect = Rect::builder().width(3).height(4).build();
+    assert_eq!(rect.area, 12);
+}
+
+#[test]
+fn test_resolve_without_other_fields() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Tag {
+        #[builder(required, resolve = |name: String| name.to_lowercase())]
+        name: String,
+    }
+
+    let tag: Tag = Tag::builder().name("RUST".to_string()).into();
+    assert_eq!(tag.name, "rust");
+}
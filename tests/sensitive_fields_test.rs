@@ -0,0 +1,125 @@
+//! Tests for `#[builder(sensitive)]` fields, held in `zeroize::Zeroizing` by the builder.
+#![cfg(feature = "zeroize")]
+
+use std::cell::Cell;
+use std::rc::Rc;
+use type_state_builder::TypeStateBuilder;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A secret that counts how often it was wiped.
+#[derive(Debug, Default, PartialEq)]
+struct Secret {
+    value: String,
+    wipes: Rc<Cell<usize>>,
+}
+
+impl Secret {
+    fn new(value: &str, wipes: &Rc<Cell<usize>>) -> Self {
+        Self {
+            value: value.to_string(),
+            wipes: Rc::clone(wipes),
+        }
+    }
+}
+
+impl Zeroize for Secret {
+    fn zeroize(&mut self) {
+        self.value.zeroize();
+        self.wipes.set(self.wipes.get() + 1);
+    }
+}
+
+#[derive(TypeStateBuilder, Debug)]
+struct Credentials {
+    #[builder(required)]
+    user: String,
+    #[builder(required, sensitive)]
+    password: Secret,
+    #[builder(sensitive)]
+    api_token: String,
+}
+
+#[test]
+fn test_abandoned_builder_wipes_sensitive_fields() {
+    let wipes = Rc::new(Cell::new(0));
+
+    let builder = Credentials::builder().password(Secret::new("hunter2", &wipes));
+    // Moving between states does not wipe the value
+    let builder = builder.api_token("tok_123".to_string());
+    assert_eq!(wipes.get(), 0);
+
+    drop(builder);
+    assert_eq!(wipes.get(), 1);
+}
+
+#[test]
+fn test_build_moves_sensitive_value_into_struct() {
+    let wipes = Rc::new(Cell::new(0));
+
+    let credentials = Credentials::builder()
+        .user("admin".to_string())
+        .password(Secret::new("hunter2", &wipes))
+        .api_token("tok_123".to_string())
+        .build();
+
+    assert_eq!(credentials.user, "admin");
+    assert_eq!(credentials.password.value, "hunter2");
+    assert_eq!(credentials.api_token, "tok_123");
+}
+
+#[test]
+fn test_sensitive_fields_are_redacted_in_debug() {
+    let wipes = Rc::new(Cell::new(0));
+    let builder = Credentials::builder()
+        .user("admin".to_string())
+        .password(Secret::new("hunter2", &wipes));
+    let output = format!("{builder:?}");
+
+    assert!(output.contains(r#"password: "***""#));
+    assert!(output.contains(r#"api_token: "***""#));
+    assert!(!output.contains("hunter2"));
+}
+
+#[test]
+fn test_all_sensitive_builder_is_zeroize_on_drop() {
+    #[derive(TypeStateBuilder)]
+    struct Keys {
+        #[builder(required, sensitive)]
+        private: Vec<u8>,
+        #[builder(sensitive, impl_into)]
+        passphrase: String,
+    }
+
+    fn assert_zeroize_on_drop<T: ZeroizeOnDrop>(_: &T) {}
+
+    let builder = Keys::builder();
+    assert_zeroize_on_drop(&builder);
+    let builder = builder.passphrase("open sesame").private(vec![1, 2, 3]);
+    assert_zeroize_on_drop(&builder);
+
+    let keys = builder.build();
+    assert_eq!(keys.private, [1, 2, 3]);
+    assert_eq!(keys.passphrase, "open sesame");
+}
+
+#[test]
+fn test_sensitive_regular_builder_and_builder_method() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Token {
+        #[builder(sensitive, default = "anonymous".to_string())]
+        value: String,
+    }
+
+    assert_eq!(Token::builder().build().value, "anonymous");
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Session {
+        #[builder(required, sensitive, builder_method)]
+        key: String,
+        #[builder(required)]
+        ttl: u32,
+    }
+
+    let session = Session::key("k".to_string()).ttl(60).build();
+    assert_eq!(session.key, "k");
+}
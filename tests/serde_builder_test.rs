@@ -0,0 +1,192 @@
+//! Tests for `#[builder(derive(Serialize, Deserialize))]`, which persists partially filled builders.
+#![cfg(feature = "serde")]
+
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Drafts of a typestate builder
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(derive(Serialize, Deserialize), state_aliases)]
+pub struct Signup {
+    #[builder(required)]
+    name: String,
+    #[builder(required)]
+    email: String,
+    #[builder(default = 18)]
+    age: u32,
+    #[builder(computed = |builder| builder.name.len())]
+    name_len: usize,
+}
+
+#[allow(non_camel_case_types)]
+type SignupDraft = SignupBuilder_HasName_MissingEmail;
+
+#[test]
+fn test_partial_builder_round_trips() {
+    let draft = Signup::builder().name("ada".to_string()).age(36);
+    let json = serde_json::to_string(&draft).unwrap();
+    assert_eq!(json, r#"{"name":"ada","age":36}"#);
+
+    let restored: SignupDraft = serde_json::from_str(&json).unwrap();
+    let signup = restored.email("ada@example.com".to_string()).build();
+    assert_eq!(
+        signup,
+        Signup {
+            name: "ada".to_string(),
+            email: "ada@example.com".to_string(),
+            age: 36,
+            name_len: 3,
+        }
+    );
+}
+
+#[test]
+fn test_field_names_do_not_depend_on_the_state() {
+    let start = serde_json::to_value(Signup::builder()).unwrap();
+    let complete = serde_json::to_value(
+        Signup::builder()
+            .email("ada@example.com".to_string())
+            .name("ada".to_string()),
+    )
+    .unwrap();
+
+    assert_eq!(start, serde_json::json!({ "age": 18 }));
+    assert_eq!(
+        complete,
+        serde_json::json!({ "name": "ada", "email": "ada@example.com", "age": 18 })
+    );
+
+    let restored: SignupBuilderComplete = serde_json::from_value(complete).unwrap();
+    assert_eq!(restored.build().email, "ada@example.com");
+}
+
+#[test]
+fn test_draft_missing_a_set_field_is_rejected() {
+    let error = serde_json::from_str::<SignupBuilderComplete>(r#"{"name":"ada","age":18}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("missing field `email`"), "{error}");
+}
+
+// =============================================================================
+// Other builder shapes
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(derive(Serialize, Deserialize))]
+pub struct Preferences<T> {
+    theme: Option<String>,
+    #[builder(default = 12)]
+    font_size: u32,
+    extra: Vec<T>,
+}
+
+#[test]
+fn test_regular_generic_builder_round_trips() {
+    let builder = Preferences::builder()
+        .theme(Some("dark".to_string()))
+        .extra(vec![1u8, 2]);
+    let json = serde_json::to_string(&builder).unwrap();
+    assert_eq!(json, r#"{"theme":"dark","font_size":12,"extra":[1,2]}"#);
+
+    let restored: PreferencesBuilder<u8> = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        restored.font_size(14).build(),
+        Preferences {
+            theme: Some("dark".to_string()),
+            font_size: 14,
+            extra: vec![1, 2],
+        }
+    );
+}
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(derive(Serialize))]
+pub struct Event {
+    #[builder(required)]
+    kind: String,
+    payload: Option<String>,
+}
+
+#[test]
+fn test_serialize_only() {
+    let builder = Event::builder().payload(Some("{}".to_string()));
+    assert_eq!(
+        serde_json::to_string(&builder).unwrap(),
+        r#"{"payload":"{}"}"#
+    );
+    let event = builder.kind("click".to_string()).build();
+    assert_eq!(
+        (event.kind.as_str(), event.payload.as_deref()),
+        ("click", Some("{}"))
+    );
+}
+
+// =============================================================================
+// Drafts of the dynamic builder
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(derive(Serialize, Deserialize), also_dynamic)]
+pub struct Ticket<T> {
+    #[builder(required)]
+    title: String,
+    #[builder(required)]
+    assignee: String,
+    #[builder(clone_from_ref)]
+    labels: Vec<String>,
+    priority: u8,
+    attachment: Option<T>,
+}
+
+#[test]
+fn test_dynamic_draft_round_trips_in_any_state() {
+    let draft = Ticket::<u32>::dyn_builder()
+        .labels(&["bug".to_string()])
+        .title("Crash on start".to_string());
+    let json = serde_json::to_string(&draft).unwrap();
+    assert_eq!(json, r#"{"title":"Crash on start","labels":["bug"]}"#);
+
+    // Whatever is set, the draft has one type to restore into
+    let restored: TicketDynBuilder<u32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        restored.build().unwrap_err().field,
+        "assignee",
+        "the restored draft is still missing `assignee`"
+    );
+
+    let restored: TicketDynBuilder<u32> = serde_json::from_str(&json).unwrap();
+    let ticket = restored
+        .assignee("ada".to_string())
+        .into_builder()
+        .unwrap()
+        .attachment(Some(7))
+        .build();
+    assert_eq!(
+        ticket,
+        Ticket {
+            title: "Crash on start".to_string(),
+            assignee: "ada".to_string(),
+            labels: vec!["bug".to_string()],
+            priority: 0,
+            attachment: Some(7),
+        }
+    );
+}
+
+#[test]
+fn test_typestate_draft_restores_into_the_dynamic_builder() {
+    let draft = Ticket::<u32>::builder()
+        .title("Crash on start".to_string())
+        .priority(2);
+    let json = serde_json::to_string(&draft).unwrap();
+
+    let restored: TicketDynBuilder<u32> = serde_json::from_str(&json).unwrap();
+    let ticket = restored.assignee("ada".to_string()).build().unwrap();
+    assert_eq!(
+        (ticket.title.as_str(), ticket.priority),
+        ("Crash on start", 2)
+    );
+}
@@ -0,0 +1,66 @@
+use serde::de::value::{Error, MapDeserializer};
+use serde::Deserialize;
+use type_state_builder::TypeStateBuilder;
+
+mod defaults {
+    pub fn port() -> u16 {
+        8080
+    }
+
+    pub fn tags() -> Vec<String> {
+        vec!["web".to_string()]
+    }
+}
+
+#[derive(TypeStateBuilder, Deserialize, Debug, PartialEq)]
+#[builder(all_required)]
+struct Config {
+    host: String,
+    #[serde(default = "defaults::port")]
+    #[builder(default = serde)]
+    port: u16,
+    #[builder(default = serde)]
+    #[serde(rename = "labels", default = "defaults::tags")]
+    tags: Vec<String>,
+    #[serde(default)]
+    #[builder(default = serde)]
+    verbose: bool,
+}
+
+fn deserialize(entries: &[(&'static str, &'static str)]) -> Config {
+    let deserializer = MapDeserializer::<_, Error>::new(entries.iter().copied());
+    Config::deserialize(deserializer).unwrap()
+}
+
+// =============================================================================
+// Shared defaults
+// =============================================================================
+
+#[test]
+fn test_builder_uses_serde_default_functions() {
+    let config = Config::builder().host("localhost".to_string()).build();
+
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.tags, vec!["web"]);
+    assert!(!config.verbose);
+}
+
+#[test]
+fn test_builder_and_deserialization_agree() {
+    let built = Config::builder().host("localhost".to_string()).build();
+    let deserialized = deserialize(&[("host", "localhost")]);
+
+    assert_eq!(built, deserialized);
+}
+
+#[test]
+fn test_serde_default_fields_keep_their_setters() {
+    let config = Config::builder()
+        .port(443)
+        .host("example.com".to_string())
+        .verbose(true)
+        .build();
+
+    assert_eq!(config.port, 443);
+    assert!(config.verbose);
+}
@@ -0,0 +1,115 @@
+use std::num::TryFromIntError;
+use type_state_builder::TypeStateBuilder;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct UserId(u64);
+
+impl From<u64> for UserId {
+    fn from(id: u64) -> Self {
+        UserId(id)
+    }
+}
+
+// =============================================================================
+// Converting with From
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+pub struct Session {
+    #[builder(required, setter_input = "u64")]
+    user: UserId,
+    #[builder(setter_input = u8, default = 30)]
+    timeout: u64,
+}
+
+#[test]
+fn test_from_on_required_and_optional_fields() {
+    let session = Session::builder().user(7).timeout(5).build();
+    assert_eq!(
+        session,
+        Session {
+            user: UserId(7),
+            timeout: 5,
+        }
+    );
+
+    let session = Session::builder().user(8).build();
+    assert_eq!(session.timeout, 30);
+}
+
+#[test]
+fn test_explicit_from_on_regular_builder() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct Record {
+        #[builder(setter_input = u64, via = From)]
+        owner: UserId,
+        #[builder(setter_input = u32)]
+        size: u64,
+    }
+
+    let record = Record::builder().owner(1).size(4).build();
+    assert_eq!(
+        record,
+        Record {
+            owner: UserId(1),
+            size: 4,
+        }
+    );
+}
+
+// =============================================================================
+// Converting with TryFrom
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+pub struct Packet {
+    #[builder(required, setter_input = u32, via = TryFrom)]
+    ttl: u8,
+    #[builder(setter_input = i64, via = TryFrom)]
+    length: u16,
+}
+
+#[test]
+fn test_try_from_returns_the_conversion_error() {
+    let packet = Packet::builder()
+        .ttl(64)
+        .unwrap()
+        .length(1500)
+        .unwrap()
+        .build();
+    assert_eq!(
+        packet,
+        Packet {
+            ttl: 64,
+            length: 1500,
+        }
+    );
+
+    let error: TryFromIntError = Packet::builder().ttl(300).unwrap_err();
+    assert!(!error.to_string().is_empty());
+
+    let result = Packet::builder().ttl(1).unwrap().length(-1);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_try_from_next_to_validating_setters() {
+    #[derive(TypeStateBuilder, Debug)]
+    struct Limits {
+        #[builder(required, setter_input = u64, via = TryFrom)]
+        threads: u16,
+        #[builder(range = 1..=10, validate_on_set)]
+        retries: u8,
+    }
+
+    assert!(Limits::builder().threads(70_000).is_err());
+
+    let limits = Limits::builder()
+        .threads(8)
+        .unwrap()
+        .retries(3)
+        .unwrap()
+        .build();
+    assert_eq!((limits.threads, limits.retries), (8, 3));
+    assert!(Limits::builder().threads(8).unwrap().retries(0).is_err());
+}
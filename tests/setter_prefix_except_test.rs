@@ -0,0 +1,180 @@
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Prefixed setters with exceptions on the type-state builder
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(setter_prefix = "with_", setter_prefix(except(id)))]
+pub struct Order {
+    #[builder(required, builder_method)]
+    id: u64,
+    #[builder(required)]
+    customer: String,
+    #[builder(default = 1)]
+    quantity: u32,
+    note: Option<String>,
+}
+
+#[test]
+fn test_entry_point_keeps_natural_name() {
+    let order = Order::id(7)
+        .with_customer("ada".to_string())
+        .with_quantity(3)
+        .build();
+
+    assert_eq!(
+        order,
+        Order {
+            id: 7,
+            customer: "ada".to_string(),
+            quantity: 3,
+            note: None
+        }
+    );
+}
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(setter_prefix = "set_", setter_prefix(except(r#type, label)))]
+pub struct Token {
+    #[builder(required)]
+    r#type: String,
+    #[builder(required)]
+    value: String,
+    #[builder(setter_prefix = "with_")]
+    label: Option<String>,
+    #[builder(setter_name = "position")]
+    offset: usize,
+}
+
+#[test]
+fn test_exceptions_with_raw_identifiers_and_field_prefixes() {
+    let token = Token::builder()
+        .r#type("ident".to_string())
+        .set_value("x".to_string())
+        .with_label(Some("name".to_string()))
+        .set_position(4)
+        .build();
+
+    assert_eq!(token.r#type, "ident");
+    assert_eq!(token.value, "x");
+    assert_eq!(token.label.as_deref(), Some("name"));
+    assert_eq!(token.offset, 4);
+}
+
+// =============================================================================
+// Regular and lite builders
+// =============================================================================
+
+#[test]
+fn test_exceptions_on_regular_builder() {
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(setter_prefix = "with_", setter_prefix(except(verbose)))]
+    struct Flags {
+        verbose: bool,
+        level: u8,
+    }
+
+    let flags = Flags::builder().verbose(true).with_level(2).build();
+    assert!(flags.verbose);
+    assert_eq!(flags.level, 2);
+}
+
+#[test]
+fn test_exceptions_on_lite_builder() {
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(lite, setter_prefix = "with_", setter_prefix(except(tags)))]
+    struct Post {
+        #[builder(required)]
+        title: String,
+        tags: Vec<String>,
+        draft: bool,
+    }
+
+    let post = Post::new("hello".to_string())
+        .tags(vec!["rust".to_string()])
+        .with_draft(true);
+    assert_eq!(post.title, "hello");
+    assert_eq!(post.tags, ["rust"]);
+    assert!(post.draft);
+}
+
+// =============================================================================
+// Prefixing optional setters only
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(setter_prefix = "with_", prefix_scope = "optional")]
+pub struct Request {
+    #[builder(required)]
+    url: String,
+    #[builder(required, setter_prefix = "set_")]
+    method: String,
+    #[builder(default = 30)]
+    timeout: u32,
+    headers: Vec<String>,
+}
+
+#[test]
+fn test_prefix_scope_keeps_required_setters_bare() {
+    let request = Request::builder()
+        .with_timeout(5)
+        .url("https://example.com".to_string())
+        .set_method("GET".to_string())
+        .with_headers(vec!["accept: */*".to_string()])
+        .build();
+
+    assert_eq!(
+        request,
+        Request {
+            url: "https://example.com".to_string(),
+            method: "GET".to_string(),
+            timeout: 5,
+            headers: vec!["accept: */*".to_string()],
+        }
+    );
+}
+
+#[test]
+fn test_prefix_scope_with_all_required_and_exceptions() {
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(
+        all_required,
+        setter_prefix = "with_",
+        prefix_scope = "optional",
+        setter_prefix(except(retries))
+    )]
+    struct Job {
+        name: String,
+        #[builder(optional)]
+        priority: u8,
+        #[builder(optional)]
+        retries: u8,
+    }
+
+    let job = Job::builder()
+        .name("sync".to_string())
+        .with_priority(2)
+        .retries(3)
+        .build();
+    assert_eq!(
+        (job.name.as_str(), job.priority, job.retries),
+        ("sync", 2, 3)
+    );
+}
+
+#[test]
+fn test_prefix_scope_follows_required_cfg() {
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(setter_prefix = "with_", prefix_scope = "optional")]
+    struct Retry {
+        #[builder(required(cfg(test)), default = 5)]
+        retries: u8,
+        #[builder(required(cfg(not(test))), default = 100)]
+        delay_ms: u64,
+    }
+
+    // `cfg(test)` holds, so `retries` is required and `delay_ms` optional
+    let retry = Retry::builder().retries(3).with_delay_ms(50).build();
+    assert_eq!((retry.retries, retry.delay_ms), (3, 50));
+}
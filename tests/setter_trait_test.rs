@@ -0,0 +1,158 @@
+use std::time::Duration;
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Helpers written against the optional setters trait
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(setter_trait, state_traits)]
+pub struct Client {
+    #[builder(required)]
+    url: String,
+    #[builder(required)]
+    token: String,
+    #[builder(default = Duration::from_secs(30))]
+    timeout: Duration,
+    #[builder(default = 3)]
+    retries: u32,
+    user_agent: Option<String>,
+}
+
+/// Applies the team's defaults at any point in the required-setter order.
+fn common_tuning(builder: impl ClientBuilderSetters) -> impl ClientBuilderSetters {
+    builder
+        .timeout(Duration::from_secs(5))
+        .retries(5)
+        .user_agent(Some("tuned/1.0".to_string()))
+}
+
+/// Keeps the concrete state, so the result can still be finished.
+fn fast<B: ClientBuilderSetters>(builder: B) -> B {
+    builder.timeout(Duration::from_millis(100))
+}
+
+#[test]
+fn test_helper_works_on_every_state() {
+    let tuned_first = fast(Client::builder())
+        .url("https://example.com".to_string())
+        .token("secret".to_string())
+        .build();
+    let tuned_between = fast(Client::builder().token("secret".to_string()))
+        .url("https://example.com".to_string())
+        .build();
+    let tuned_last = fast(
+        Client::builder()
+            .url("https://example.com".to_string())
+            .token("secret".to_string()),
+    )
+    .build();
+
+    assert_eq!(tuned_first, tuned_between);
+    assert_eq!(tuned_first, tuned_last);
+    assert_eq!(tuned_first.timeout, Duration::from_millis(100));
+    assert_eq!(tuned_first.retries, 3);
+}
+
+#[test]
+fn test_helper_returning_impl_trait() {
+    fn finish<B: ClientBuilderFinish<Target = Client>>(builder: B) -> Client {
+        builder.build()
+    }
+
+    let builder = Client::builder()
+        .url("https://example.com".to_string())
+        .token("secret".to_string());
+    // The opaque return type only exposes the setters
+    let _tuned = common_tuning(Client::builder());
+
+    let client = finish(fast(builder).retries(1));
+    assert_eq!(client.retries, 1);
+    assert_eq!(client.user_agent, None);
+}
+
+// =============================================================================
+// Generic structs, the regular builder and extra setter methods
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(setter_trait)]
+pub struct Pool<T: Clone> {
+    #[builder(required)]
+    items: Vec<T>,
+    #[builder(lazy_setter)]
+    label: Option<String>,
+    #[builder(try_setter, default = 4)]
+    workers: u8,
+}
+
+fn labelled<T: Clone, B: PoolBuilderSetters<T>>(builder: B) -> B {
+    builder.label_with(|| Some("lazy".to_string()))
+}
+
+#[test]
+fn test_generic_setters_trait() {
+    let pool = labelled(Pool::builder())
+        .try_workers(8_u32)
+        .unwrap()
+        .items(vec!['a', 'b'])
+        .build();
+
+    assert_eq!(pool.items, ['a', 'b']);
+    assert_eq!(pool.label.as_deref(), Some("lazy"));
+    assert_eq!(pool.workers, 8);
+    assert!(PoolBuilderSetters::<u8>::try_workers(Pool::<u8>::builder(), 300_u32).is_err());
+}
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(setter_trait, hide_states)]
+pub struct Retry {
+    #[builder(required)]
+    attempts: u32,
+    backoff_ms: u64,
+}
+
+#[test]
+fn test_setters_trait_with_hidden_states() {
+    fn slow(builder: impl RetryBuilderSetters) -> impl RetryBuilderSetters {
+        builder.backoff_ms(500)
+    }
+    let _ = slow(Retry::builder());
+
+    let retry = Retry::builder().backoff_ms(10).attempts(3).build();
+    assert_eq!((retry.attempts, retry.backoff_ms), (3, 10));
+}
+
+#[test]
+fn test_setters_trait_on_regular_and_const_builders() {
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(setter_trait)]
+    struct Flags {
+        verbose: bool,
+        level: u8,
+    }
+
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(setter_trait, const)]
+    struct Limits {
+        #[builder(required)]
+        max: u32,
+        #[builder(default = 1)]
+        min: u32,
+    }
+
+    fn loud<B: FlagsBuilderSetters>(builder: B) -> B {
+        builder.verbose(true).level(3)
+    }
+    let flags = loud(Flags::builder()).build();
+    assert!(flags.verbose);
+    assert_eq!(flags.level, 3);
+
+    fn lower<B: LimitsBuilderSetters>(builder: B) -> B {
+        builder.min(0)
+    }
+    const LIMITS: Limits = Limits::builder().max(10).build();
+    let limits = lower(Limits::builder()).max(5).build();
+    assert_eq!((LIMITS.min, LIMITS.max), (1, 10));
+    assert_eq!((limits.min, limits.max), (0, 5));
+}
@@ -0,0 +1,92 @@
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Public API built on the stable names
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(stable_names, state_traits)]
+pub struct Account {
+    #[builder(required)]
+    owner: String,
+    #[builder(required)]
+    email: String,
+    #[builder(default = 1)]
+    tier: u8,
+}
+
+// The generated state names are not public, so they stay free
+#[allow(non_camel_case_types, dead_code)]
+struct AccountBuilder_HasOwner_MissingEmail;
+#[allow(non_camel_case_types, dead_code)]
+struct AccountBuilder_HasOwner_HasEmail;
+
+pub fn new_account() -> AccountBuilderStart {
+    Account::builder()
+}
+
+pub fn with_contact(builder: AccountBuilder, owner: &str, email: &str) -> AccountBuilderComplete {
+    builder.owner(owner.to_string()).email(email.to_string())
+}
+
+#[test]
+fn test_aliases_name_the_states() {
+    let account = with_contact(new_account(), "ada", "ada@example.com")
+        .tier(3)
+        .build();
+
+    assert_eq!(
+        account,
+        Account {
+            owner: "ada".to_string(),
+            email: "ada@example.com".to_string(),
+            tier: 3,
+        }
+    );
+}
+
+#[test]
+fn test_intermediate_states_through_state_traits() {
+    fn finish<B: AccountBuilderFinish>(builder: B) -> B::Target {
+        builder.build()
+    }
+
+    let halfway = Account::builder().email("ada@example.com".to_string());
+    assert_eq!(finish(halfway.owner("ada".to_string())).tier, 1);
+}
+
+// =============================================================================
+// Custom alias names and other builder shapes
+// =============================================================================
+
+#[derive(TypeStateBuilder)]
+#[builder(
+    stable_names,
+    state_aliases(start = "NewOrder", complete = "ReadyOrder")
+)]
+pub struct Order<T> {
+    #[builder(required)]
+    item: T,
+    quantity: Option<u32>,
+}
+
+#[test]
+fn test_custom_alias_names() {
+    let start: NewOrder<&str> = Order::builder();
+    let ready: ReadyOrder<&str> = start.item("tea");
+    let order = ready.quantity(Some(2)).build();
+    assert_eq!((order.item, order.quantity), ("tea", Some(2)));
+}
+
+#[derive(TypeStateBuilder)]
+#[builder(stable_names)]
+pub struct Options {
+    verbose: bool,
+}
+
+#[test]
+fn test_regular_builder_aliases() {
+    let builder: OptionsBuilderStart = Options::builder();
+    let ready: OptionsBuilderComplete = builder.verbose(true);
+    assert!(ready.build().verbose);
+}
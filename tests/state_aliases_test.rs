@@ -0,0 +1,141 @@
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Default alias names
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(state_aliases)]
+pub struct User {
+    #[builder(required)]
+    name: String,
+    #[builder(required)]
+    email: String,
+    age: Option<u32>,
+}
+
+/// Stores a builder until the remaining fields are known.
+struct Signup {
+    pending: UserBuilderComplete,
+}
+
+fn start() -> UserBuilderStart {
+    User::builder()
+}
+
+fn with_contact(builder: UserBuilderStart, name: &str, email: &str) -> UserBuilderComplete {
+    builder.name(name.to_string()).email(email.to_string())
+}
+
+#[test]
+fn test_aliases_name_start_and_complete_states() {
+    let signup = Signup {
+        pending: with_contact(start(), "ada", "ada@example.com"),
+    };
+    let user = signup.pending.age(Some(36)).build();
+
+    assert_eq!(
+        user,
+        User {
+            name: "ada".to_string(),
+            email: "ada@example.com".to_string(),
+            age: Some(36)
+        }
+    );
+}
+
+// =============================================================================
+// Custom names, generics and other builder shapes
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(state_aliases(start = "NewPair", complete = "ReadyPair"))]
+pub struct Pair<'a, T: Clone> {
+    #[builder(required)]
+    left: &'a T,
+    #[builder(required)]
+    right: T,
+}
+
+#[test]
+fn test_custom_alias_names_with_generics() {
+    let left = 1_u8;
+    let start: NewPair<'_, u8> = Pair::builder();
+    let ready: ReadyPair<'_, u8> = start.right(2).left(&left);
+    let pair = ready.build();
+    assert_eq!((*pair.left, pair.right), (1, 2));
+}
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(state_aliases(complete = "ReadyOrder"))]
+pub struct Order {
+    #[builder(required, builder_method)]
+    id: u64,
+    #[builder(required)]
+    customer: String,
+}
+
+#[test]
+fn test_start_alias_with_builder_method() {
+    let start: OrderBuilderStart = Order::id(7);
+    let ready: ReadyOrder = start.customer("ada".to_string());
+    let order = ready.build();
+    assert_eq!((order.id, order.customer.as_str()), (7, "ada"));
+}
+
+#[derive(TypeStateBuilder, Debug)]
+#[builder(state_aliases, module = "ticket_builder")]
+pub struct Ticket {
+    #[builder(required)]
+    seat: u32,
+}
+
+#[test]
+fn test_aliases_with_hidden_states_and_modules() {
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(state_aliases, hide_states)]
+    struct Secret {
+        #[builder(required)]
+        key: String,
+    }
+
+    let secret: SecretBuilderComplete = SecretBuilderStart::new().key("k".to_string());
+    assert_eq!(secret.build().key, "k");
+
+    let ticket: ticket_builder::TicketBuilderComplete = Ticket::builder().seat(12);
+    assert_eq!(ticket.build().seat, 12);
+}
+
+#[test]
+fn test_aliases_on_regular_builder() {
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(state_aliases)]
+    struct Flags {
+        verbose: bool,
+    }
+
+    let start: FlagsBuilderStart = Flags::builder();
+    let complete: FlagsBuilderComplete = start.verbose(true);
+    assert!(complete.build().verbose);
+}
+
+#[test]
+fn test_start_alias_shared_with_nested_builders() {
+    #[derive(TypeStateBuilder, Debug)]
+    #[builder(state_aliases, nestable)]
+    struct Address {
+        #[builder(required)]
+        city: String,
+    }
+
+    #[derive(TypeStateBuilder, Debug)]
+    struct Person {
+        #[builder(required, nested)]
+        address: Address,
+    }
+
+    let person = Person::builder()
+        .address_with(|address: AddressBuilderStart| address.city("Oslo".to_string()))
+        .build();
+    assert_eq!(person.address.city, "Oslo");
+}
@@ -0,0 +1,126 @@
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Extension traits written against the generated state traits
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(state_traits)]
+pub struct Server {
+    #[builder(required)]
+    host: String,
+    #[builder(required)]
+    port: u16,
+    #[builder(default = 4)]
+    workers: usize,
+}
+
+/// Adds a combinator to every complete `Server` builder.
+trait BuildBoxed: ServerBuilderFinish + Sized {
+    fn build_boxed(self) -> Box<Self::Target> {
+        Box::new(self.build())
+    }
+}
+
+impl<B: ServerBuilderFinish> BuildBoxed for B {}
+
+/// Works on any builder state, complete or not.
+fn target_name<B: ServerBuilderState>(_builder: &B) -> &'static str {
+    core::any::type_name::<B::Target>()
+}
+
+#[test]
+fn test_finish_trait_extension() {
+    let server = Server::builder()
+        .host("localhost".to_string())
+        .port(8080)
+        .workers(8)
+        .build_boxed();
+
+    assert_eq!(server.host, "localhost");
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.workers, 8);
+}
+
+#[test]
+fn test_state_trait_implemented_by_every_state() {
+    let initial = Server::builder();
+    assert!(target_name(&initial).ends_with("Server"));
+
+    let partial = initial.port(80);
+    assert!(target_name(&partial).ends_with("Server"));
+
+    let complete = partial.host("example.com".to_string());
+    assert!(target_name(&complete).ends_with("Server"));
+}
+
+#[test]
+fn test_inherent_build_still_preferred() {
+    // The inherent method is used; the trait method just forwards to it.
+    let server = Server::builder().host("a".to_string()).port(1).build();
+    assert_eq!(server.workers, 4);
+}
+
+// =============================================================================
+// Custom build method names, generics and regular builders
+// =============================================================================
+
+#[test]
+fn test_state_traits_with_custom_build_method_and_generics() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(state_traits, build_method = "create")]
+    struct Wrapper<T: Clone> {
+        #[builder(required)]
+        value: T,
+    }
+
+    fn finish<B: WrapperBuilderFinish>(builder: B) -> B::Target {
+        builder.create()
+    }
+
+    let wrapper: Wrapper<u32> = finish(Wrapper::builder().value(7));
+    assert_eq!(wrapper.value, 7);
+}
+
+#[test]
+fn test_state_traits_with_regular_builder() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(state_traits)]
+    struct Options {
+        verbose: bool,
+        level: u8,
+    }
+
+    fn finish<B: OptionsBuilderFinish>(builder: B) -> B::Target {
+        builder.build()
+    }
+
+    let options = finish(Options::builder().level(3));
+    assert_eq!(
+        options,
+        Options {
+            verbose: false,
+            level: 3
+        }
+    );
+}
+
+#[test]
+fn test_state_traits_with_const_builder() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(const, state_traits)]
+    struct Limits {
+        #[builder(required)]
+        max: u32,
+        #[builder(default = 0)]
+        min: u32,
+    }
+
+    const LIMITS: Limits = Limits::builder().max(10).build();
+
+    fn finish<B: LimitsBuilderFinish>(builder: B) -> B::Target {
+        builder.build()
+    }
+
+    assert_eq!(finish(Limits::builder().max(10)), LIMITS);
+}
@@ -0,0 +1,103 @@
+//! Tests for the built-in converters enabled by the `std-converters` feature
+#![cfg(feature = "std-converters")]
+
+use std::collections::BTreeSet;
+use std::time::Duration;
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Built-in converters on required and optional fields
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+pub struct Server {
+    #[builder(required, converter = std_converters::trim_lowercase)]
+    host: String,
+    #[builder(required, converter = std_converters::parse)]
+    port: u16,
+    #[builder(converter = std_converters::seconds)]
+    timeout: Duration,
+    #[builder(converter = std_converters::csv_list)]
+    tags: Vec<String>,
+    #[builder(converter = std_converters::csv_list)]
+    allowed_ports: BTreeSet<u16>,
+}
+
+#[test]
+fn test_builtin_converters() {
+    let server = Server::builder()
+        .host("  API.Example.COM ")
+        .port(" 8080 ")
+        .timeout(30)
+        .tags("edge, eu-west,, primary ")
+        .allowed_ports("443,80,443")
+        .build();
+
+    assert_eq!(
+        server,
+        Server {
+            host: "api.example.com".to_string(),
+            port: 8080,
+            timeout: Duration::from_secs(30),
+            tags: vec![
+                "edge".to_string(),
+                "eu-west".to_string(),
+                "primary".to_string()
+            ],
+            allowed_ports: BTreeSet::from([80, 443]),
+        }
+    );
+}
+
+#[test]
+fn test_unset_optional_fields_keep_their_defaults() {
+    let server = Server::builder().host("localhost").port("80").build();
+    assert_eq!(server.timeout, Duration::ZERO);
+    assert!(server.tags.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "invalid value \"eighty\" for `port`")]
+fn test_parse_panics_on_invalid_input() {
+    let _ = Server::builder().host("localhost").port("eighty");
+}
+
+#[test]
+#[should_panic(expected = "invalid value \"x\" for `allowed_ports`")]
+fn test_csv_list_panics_on_invalid_item() {
+    let _ = Server::builder()
+        .host("localhost")
+        .port("80")
+        .allowed_ports("80,x");
+}
+
+// =============================================================================
+// Generic and optional field types
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+pub struct Limits<T: std::str::FromStr> {
+    #[builder(converter = std_converters::parse)]
+    max: Option<u32>,
+    #[builder(converter = std_converters::csv_list)]
+    values: Vec<T>,
+    #[builder(converter = std_converters::trim_lowercase)]
+    label: Option<String>,
+    #[builder(converter = std_converters::seconds)]
+    window: Option<Duration>,
+}
+
+#[test]
+fn test_converters_on_generic_and_optional_fields() {
+    let limits = Limits::<f64>::builder()
+        .max("64")
+        .values("1.5, 2.5")
+        .label(" CPU ")
+        .window(60)
+        .build();
+
+    assert_eq!(limits.max, Some(64));
+    assert_eq!(limits.values, vec![1.5f64, 2.5]);
+    assert_eq!(limits.label.as_deref(), Some("cpu"));
+    assert_eq!(limits.window, Some(Duration::from_secs(60)));
+}
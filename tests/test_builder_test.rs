@@ -0,0 +1,114 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Email(String);
+
+// =============================================================================
+// Starting tests from a complete builder
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(test_default)]
+pub struct User {
+    #[builder(required)]
+    name: String,
+    #[builder(required, test_default = Email("test@example.com".to_string()))]
+    email: Email,
+    #[builder(required)]
+    age: u8,
+    #[builder(default = true)]
+    active: bool,
+    nickname: Option<String>,
+}
+
+#[test]
+fn test_required_fields_take_test_values() {
+    let user = User::test_builder().build();
+    assert_eq!(
+        user,
+        User {
+            name: String::new(),
+            email: Email("test@example.com".to_string()),
+            age: 0,
+            active: true,
+            nickname: None,
+        }
+    );
+}
+
+#[test]
+fn test_only_the_relevant_fields_are_overridden() {
+    let user = User::test_builder()
+        .age(42)
+        .nickname(Some("ada".to_string()))
+        .build();
+    assert_eq!((user.age, user.nickname.as_deref()), (42, Some("ada")));
+    assert_eq!(user.email, Email("test@example.com".to_string()));
+}
+
+// =============================================================================
+// Other builder shapes
+// =============================================================================
+
+#[test]
+fn test_test_builder_of_generic_struct() {
+    #[derive(TypeStateBuilder)]
+    #[builder(test_default, setter_prefix = "with_")]
+    struct Pair<K, V> {
+        #[builder(required)]
+        key: K,
+        #[builder(required, test_default = Vec::new())]
+        values: Vec<V>,
+        label: Option<String>,
+    }
+
+    let pair = Pair::<u8, i32>::test_builder().with_key(3).build();
+    assert_eq!((pair.key, pair.values, pair.label), (3, vec![], None));
+}
+
+#[test]
+fn test_test_builder_with_builder_method() {
+    #[derive(TypeStateBuilder)]
+    #[builder(test_default)]
+    struct Job {
+        #[builder(required, builder_method, test_default = 7)]
+        id: u32,
+        #[builder(required)]
+        command: String,
+        retries: u8,
+    }
+
+    let job = Job::test_builder().retries(2).build();
+    assert_eq!((job.id, job.command.as_str(), job.retries), (7, "", 2));
+
+    let job = Job::test_builder().command("make".to_string()).build();
+    assert_eq!(job.command, "make");
+}
+
+#[test]
+fn test_test_builder_with_hidden_states_and_validating_setters() {
+    #[derive(TypeStateBuilder)]
+    #[builder(test_default, hide_states, impl_into)]
+    struct Server {
+        #[builder(required, impl_into = false, test_default = 8080, range = 1..=65535, validate_on_set)]
+        port: u32,
+        #[builder(required)]
+        host: String,
+    }
+
+    let server = Server::test_builder().host("example.com").build();
+    assert_eq!((server.port, server.host.as_str()), (8080, "example.com"));
+    assert!(Server::test_builder().port(0).is_err());
+}
+
+#[test]
+fn test_test_builder_without_required_fields() {
+    #[derive(TypeStateBuilder)]
+    #[builder(test_default(cfg(any(test, doc))))]
+    struct Options {
+        #[builder(default = 3)]
+        level: u8,
+    }
+
+    assert_eq!(Options::test_builder().build().level, 3);
+}
@@ -202,3 +202,83 @@ fn test_nested_lifetime_references() {
     assert_eq!(*result.nested_ref, "nested");
     assert_eq!(result.count, Some(42));
 }
+
+#[test]
+fn test_elided_lifetimes_in_function_pointer_fields() {
+    #[derive(TypeStateBuilder)]
+    struct Parser<'a> {
+        #[builder(required)]
+        input: &'a str,
+
+        #[builder(required, lazy_setter)]
+        trim: fn(&str) -> &str,
+
+        #[builder(default_from = |b| b.input.len())]
+        limit: usize,
+
+        on_error: Option<fn(&str) -> String>,
+    }
+
+    let parser = Parser::builder()
+        .trim_with(|| str::trim)
+        .input("  padded  ")
+        .build();
+
+    assert_eq!((parser.trim)(parser.input), "padded");
+    assert_eq!(parser.limit, 10);
+    assert!(parser.on_error.is_none());
+}
+
+#[test]
+fn test_elided_lifetimes_in_bounds_and_higher_ranked_types() {
+    #[derive(TypeStateBuilder)]
+    struct Matcher<F, T>
+    where
+        F: Fn(&str) -> &str,
+    {
+        #[builder(required)]
+        normalize: F,
+
+        #[builder(required)]
+        extract: for<'x> fn(&'x T) -> &'x str,
+
+        fallback: Option<fn(&T) -> Option<&str>>,
+    }
+
+    let matcher = Matcher::builder()
+        .extract(|value: &String| value.as_str())
+        .normalize(|text: &str| text.trim_start_matches('#'))
+        .build();
+
+    let value = "#tag".to_string();
+    assert_eq!((matcher.normalize)((matcher.extract)(&value)), "tag");
+    assert!(matcher.fallback.is_none());
+}
+
+#[test]
+fn test_elided_lifetimes_with_phantom_data_marker() {
+    // A macro in type position hides its expansion, so the builder adds a
+    // PhantomData marker for the declared parameters
+    macro_rules! callback {
+        () => { fn(&str) -> &str };
+    }
+
+    #[derive(TypeStateBuilder)]
+    struct Hook<'a, T> {
+        #[builder(required)]
+        name: &'a str,
+
+        #[builder(required)]
+        callback: callback!(),
+
+        parse: Option<fn(&str) -> T>,
+    }
+
+    let hook = Hook::<u8>::builder()
+        .callback(|text| &text[1..])
+        .name("xhook")
+        .build();
+
+    assert_eq!((hook.callback)(hook.name), "hook");
+    assert!(hook.parse.is_none());
+}
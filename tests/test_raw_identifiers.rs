@@ -20,3 +20,96 @@ fn test_raw_identifiers() {
     assert_eq!(instance.r#type, "test");
     assert_eq!(instance.r#async, Some(true));
 }
+
+// =============================================================================
+// Raw identifiers with prefixes and derived setter names
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+#[builder(setter_prefix = "with_")]
+struct PrefixedKeywords {
+    #[builder(required)]
+    r#type: String,
+    #[builder(required, setter_prefix = "set_")]
+    r#match: u32,
+    #[builder(setter_name = "r#loop")]
+    count: Option<u32>,
+    #[builder(lazy_setter, setter(also_ref))]
+    r#ref: String,
+    #[builder(try_setter)]
+    r#async: u8,
+}
+
+#[test]
+fn test_prefix_drops_raw_marker() {
+    let instance = PrefixedKeywords::builder()
+        .with_type("test".to_string())
+        .set_match(3)
+        .with_loop(Some(4))
+        .with_ref_ref(&"borrowed".to_string())
+        .try_with_async(7u32)
+        .unwrap()
+        .build();
+
+    assert_eq!(
+        instance,
+        PrefixedKeywords {
+            r#type: "test".to_string(),
+            r#match: 3,
+            count: Some(4),
+            r#ref: "borrowed".to_string(),
+            r#async: 7,
+        }
+    );
+
+    let instance = PrefixedKeywords::builder()
+        .set_match(1)
+        .with_type("lazy".to_string())
+        .with_ref_with(|| "computed".to_string())
+        .build();
+    assert_eq!(instance.r#ref, "computed");
+}
+
+#[test]
+fn test_suffixed_setters_of_unprefixed_raw_fields() {
+    #[derive(TypeStateBuilder)]
+    struct Suffixed {
+        #[builder(lazy_setter)]
+        r#type: String,
+        #[builder(try_setter)]
+        r#for: u8,
+    }
+
+    let instance = Suffixed::builder()
+        .type_with(|| "lazy".to_string())
+        .try_for(9u64)
+        .unwrap()
+        .build();
+    assert_eq!((instance.r#type.as_str(), instance.r#for), ("lazy", 9));
+}
+
+#[test]
+fn test_state_names_drop_raw_marker() {
+    #[allow(non_camel_case_types)]
+    type Halfway = PrefixedKeywordsBuilder_HasType_MissingMatch;
+
+    let builder: Halfway = PrefixedKeywords::builder().with_type("state".to_string());
+    assert_eq!(builder.set_match(2).build().r#type, "state");
+}
+
+#[test]
+fn test_lite_mode_with_raw_identifiers() {
+    #[derive(TypeStateBuilder)]
+    #[builder(lite)]
+    struct LiteKeywords {
+        #[builder(required)]
+        r#type: String,
+        r#async: Option<bool>,
+    }
+
+    let instance = LiteKeywords::new("lite".to_string()).with_async(Some(true));
+    assert_eq!(
+        (instance.r#type.as_str(), instance.r#async),
+        ("lite", Some(true))
+    );
+}
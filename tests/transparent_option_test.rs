@@ -0,0 +1,83 @@
+use type_state_builder::TypeStateBuilder;
+
+// =============================================================================
+// Required Option<T> fields with #[builder(required, transparent)]
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+struct Deployment {
+    #[builder(required)]
+    name: String,
+
+    /// The caller must decide on a region, but "no region" is a valid answer.
+    #[builder(required, transparent)]
+    region: Option<String>,
+
+    replicas: Option<u32>,
+}
+
+#[test]
+fn test_transparent_field_accepts_some() {
+    let deployment = Deployment::builder()
+        .name("api".to_string())
+        .region(Some("eu-west-1".to_string()))
+        .build();
+
+    assert_eq!(deployment.region, Some("eu-west-1".to_string()));
+    assert_eq!(deployment.replicas, None);
+}
+
+#[test]
+fn test_transparent_field_accepts_explicit_none() {
+    let deployment = Deployment::builder()
+        .region(None)
+        .name("api".to_string())
+        .replicas(Some(3))
+        .build();
+
+    assert_eq!(deployment.region, None);
+    assert_eq!(deployment.replicas, Some(3));
+}
+
+#[test]
+fn test_transparent_ignores_struct_level_impl_into() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(impl_into)]
+    struct Profile {
+        #[builder(required)]
+        name: String,
+        #[builder(required, transparent)]
+        nickname: Option<String>,
+    }
+
+    // `name` accepts &str through impl_into, while `nickname` takes the
+    // Option itself so a bare `None` needs no type annotation.
+    let profile = Profile::builder().name("Alice").nickname(None).build();
+
+    assert_eq!(profile.name, "Alice");
+    assert_eq!(profile.nickname, None);
+}
+
+#[test]
+fn test_transparent_with_builder_method_and_const() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(const)]
+    struct Limit {
+        #[builder(required, transparent, builder_method)]
+        max: Option<u32>,
+        #[builder(default = 0)]
+        min: u32,
+    }
+
+    const UNBOUNDED: Limit = Limit::max(None).build();
+    const BOUNDED: Limit = Limit::max(Some(10)).min(1).build();
+
+    assert_eq!(UNBOUNDED.max, None);
+    assert_eq!(
+        BOUNDED,
+        Limit {
+            max: Some(10),
+            min: 1
+        }
+    );
+}
@@ -0,0 +1,73 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+struct Color {
+    #[builder(required, try_setter)]
+    rgb: [u8; 3],
+    #[builder(try_setter)]
+    alpha: u8,
+}
+
+// =============================================================================
+// Fixed-size arrays
+// =============================================================================
+
+#[test]
+fn test_array_setter_accepts_array() {
+    let color = Color::builder().rgb([1, 2, 3]).build();
+    assert_eq!(color.rgb, [1, 2, 3]);
+}
+
+#[test]
+fn test_try_setter_converts_vec_and_slice() {
+    let color = Color::builder().try_rgb(vec![4, 5, 6]).unwrap().build();
+    assert_eq!(color.rgb, [4, 5, 6]);
+
+    let bytes = [7u8, 8, 9];
+    let color = Color::builder().try_rgb(&bytes[..]).unwrap().build();
+    assert_eq!(color.rgb, [7, 8, 9]);
+}
+
+#[test]
+fn test_try_setter_reports_length_mismatch() {
+    // Vec<T> hands the rejected vector back
+    let rejected = Color::builder().try_rgb(vec![1, 2]).unwrap_err();
+    assert_eq!(rejected, vec![1, 2]);
+
+    let bytes = [1u8, 2, 3, 4];
+    assert!(Color::builder().try_rgb(&bytes[..]).is_err());
+}
+
+#[test]
+fn test_try_setter_on_optional_field() {
+    let color = Color::builder()
+        .try_alpha(200u32)
+        .unwrap()
+        .rgb([0, 0, 0])
+        .build();
+    assert_eq!(color.alpha, 200);
+
+    assert!(Color::builder().try_alpha(300u32).is_err());
+}
+
+// =============================================================================
+// Const generics and setter prefixes
+// =============================================================================
+
+#[test]
+fn test_try_setter_with_const_generic_array() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(setter_prefix = "with_")]
+    struct Samples<const N: usize> {
+        #[builder(required, try_setter)]
+        values: [f32; N],
+    }
+
+    let samples = Samples::<2>::builder()
+        .try_with_values(vec![0.5, 1.5])
+        .unwrap()
+        .build();
+    assert_eq!(samples.values, [0.5, 1.5]);
+
+    assert!(Samples::<2>::builder().try_with_values(vec![0.5]).is_err());
+}
@@ -0,0 +1,103 @@
+use type_state_builder::type_state_builder;
+
+#[type_state_builder]
+#[derive(Debug, PartialEq)]
+struct Greeting {
+    #[builder(required, builder_only)]
+    name: String,
+    #[builder(builder_only, default = false)]
+    shout: bool,
+    #[builder(computed = |b| {
+        let text = format!("Hello, {}!", b.name);
+        if b.shout { text.to_uppercase() } else { text }
+    })]
+    text: String,
+}
+
+// =============================================================================
+// Builder-only fields
+// =============================================================================
+
+#[test]
+fn test_builder_only_fields_feed_computed_fields() {
+    let greeting = Greeting::builder().name("Ada".to_string()).build();
+    assert_eq!(
+        greeting,
+        Greeting {
+            text: "Hello, Ada!".to_string()
+        }
+    );
+
+    let loud = Greeting::builder()
+        .shout(true)
+        .name("Ada".to_string())
+        .build();
+    assert_eq!(loud.text, "HELLO, ADA!");
+}
+
+#[test]
+fn test_builder_only_fields_with_resolve_and_default_from() {
+    #[type_state_builder]
+    #[derive(Debug)]
+    struct Path {
+        #[builder(required, builder_only)]
+        root: String,
+        #[builder(required, resolve = |path: String, root: &String| format!("{root}/{path}"))]
+        path: String,
+        #[builder(default_from = |b| b.root.len())]
+        depth: usize,
+    }
+
+    let path = Path::builder()
+        .root("/srv".to_string())
+        .path("data".to_string())
+        .build();
+
+    assert_eq!(path.path, "/srv/data");
+    assert_eq!(path.depth, 4);
+}
+
+// =============================================================================
+// Attribute arguments
+// =============================================================================
+
+#[test]
+fn test_attribute_arguments_are_struct_attributes() {
+    #[type_state_builder(build_method = "create", setter_prefix = "with_")]
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        #[builder(required)]
+        x: i32,
+        #[builder(default = 0)]
+        y: i32,
+    }
+
+    assert_eq!(
+        Point::builder().with_x(1).with_y(2).create(),
+        Point { x: 1, y: 2 }
+    );
+}
+
+#[test]
+fn test_attribute_macro_with_regular_builder_and_generics() {
+    /// Documentation stays on the struct.
+    #[type_state_builder]
+    #[derive(Debug, PartialEq)]
+    struct Wrapper<T: Default> {
+        /// And on the fields.
+        value: T,
+        #[builder(builder_only)]
+        scale: Option<u8>,
+        #[builder(computed = |b| b.scale.is_some())]
+        scaled: bool,
+    }
+
+    let wrapper = Wrapper::<u8>::builder().value(3).scale(Some(2)).build();
+    assert_eq!(
+        wrapper,
+        Wrapper {
+            value: 3,
+            scaled: true
+        }
+    );
+}
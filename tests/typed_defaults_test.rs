@@ -0,0 +1,66 @@
+//! Tests for literal defaults written directly in the field's type.
+
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+struct Tuning {
+    #[builder(required)]
+    name: &'static str,
+    #[builder(default = 42)]
+    retries: u32,
+    #[builder(default = -1)]
+    offset: i64,
+    #[builder(default = 2.5)]
+    ratio: f64,
+    #[builder(default = true)]
+    enabled: bool,
+    #[builder(default = 'x')]
+    marker: char,
+    #[builder(default = "fast")]
+    profile: &'static str,
+    #[builder(default = b'a')]
+    byte: u8,
+}
+
+#[test]
+fn test_literal_defaults() {
+    let tuning = Tuning::builder().name("default").build();
+
+    assert_eq!(
+        tuning,
+        Tuning {
+            name: "default",
+            retries: 42,
+            offset: -1,
+            ratio: 2.5,
+            enabled: true,
+            marker: 'x',
+            profile: "fast",
+            byte: b'a',
+        }
+    );
+}
+
+#[test]
+fn test_literal_defaults_in_const_builder() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(const)]
+    struct Limits {
+        #[builder(default = 10)]
+        max: u16,
+        #[builder(default = 0.5)]
+        threshold: f32,
+        #[builder(default = false)]
+        strict: bool,
+    }
+
+    const LIMITS: Limits = Limits::builder().strict(true).build();
+    assert_eq!(
+        LIMITS,
+        Limits {
+            max: 10,
+            threshold: 0.5,
+            strict: true
+        }
+    );
+}
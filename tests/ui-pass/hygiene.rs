@@ -0,0 +1,251 @@
+// Every path in the generated code must resolve to the same item no matter
+// what the surrounding module defines. These items shadow the prelude, the
+// `core`/`std` crate names and common macros. `Box` is the one exception:
+// `lazy_setter` takes it from the prelude so no_std crates can import it.
+#![allow(dead_code, non_camel_case_types, unused_macros)]
+
+use type_state_builder::{type_state_builder, TypeStateBuilder};
+
+struct Option;
+struct Some;
+struct None;
+struct Result;
+struct Ok;
+struct Err;
+struct Vec;
+struct String;
+struct PhantomData;
+struct Duration;
+trait Into {}
+trait From {}
+trait TryFrom {}
+trait TryInto {}
+trait Default {}
+trait Clone {}
+trait Copy {}
+trait Debug {}
+trait PartialEq {}
+trait Iterator {}
+trait IntoIterator {}
+trait Extend {}
+trait FnOnce {}
+trait Fn {}
+trait FnMut {}
+trait Send {}
+trait Sync {}
+trait Sized {}
+trait Drop {}
+trait ToString {}
+trait ToOwned {}
+trait AsRef {}
+mod core {}
+mod std {}
+mod alloc {}
+
+macro_rules! panic {
+    ($($tt:tt)*) => {
+        compile_error!("the shadowing panic! was used")
+    };
+}
+macro_rules! format {
+    ($($tt:tt)*) => {
+        compile_error!("the shadowing format! was used")
+    };
+}
+macro_rules! write {
+    ($($tt:tt)*) => {
+        compile_error!("the shadowing write! was used")
+    };
+}
+macro_rules! matches {
+    ($($tt:tt)*) => {
+        compile_error!("the shadowing matches! was used")
+    };
+}
+macro_rules! assert {
+    ($($tt:tt)*) => {
+        compile_error!("the shadowing assert! was used")
+    };
+}
+macro_rules! vec {
+    ($($tt:tt)*) => {
+        compile_error!("the shadowing vec! was used")
+    };
+}
+
+type Text = ::std::string::String;
+type List<T> = ::std::vec::Vec<T>;
+type Maybe<T> = ::core::option::Option<T>;
+
+fn check_server(server: &Server) -> ::core::result::Result<(), Text> {
+    if server.port == 0 {
+        return ::core::result::Result::Err(::std::string::String::from("port 0"));
+    }
+    ::core::result::Result::Ok(())
+}
+
+#[derive(TypeStateBuilder, ::core::fmt::Debug, ::core::clone::Clone)]
+#[builder(
+    setter_prefix = "with_",
+    state_traits,
+    finishers(checked(validate = check_server, error = Text))
+)]
+struct Server {
+    #[builder(required, impl_into)]
+    host: Text,
+    #[builder(required, try_setter)]
+    port: u16,
+    #[builder(default = 30)]
+    timeout: u32,
+    #[builder(into_iter)]
+    tags: ::std::vec::Vec<Text>,
+    #[builder(impl_into)]
+    label: ::core::option::Option<Text>,
+    #[builder(redact)]
+    token: Maybe<Text>,
+    #[builder(converter = |value: &str| value.len())]
+    name_length: usize,
+    #[builder(resolve = |value: u32, timeout: &u32| value.max(*timeout))]
+    retries: u32,
+    #[builder(default_from = |b| b.timeout * 2)]
+    deadline: u32,
+    #[builder(computed = |b| b.host.len())]
+    host_length: usize,
+    #[builder(lazy_setter)]
+    workers: usize,
+    #[builder(nested)]
+    limits: Limits,
+}
+
+#[derive(TypeStateBuilder, ::core::fmt::Debug, ::core::clone::Clone, ::core::default::Default)]
+#[builder(option_into, into_iter, assert_send_sync, base = "Limits::base()", nestable)]
+struct Limits {
+    max: ::core::option::Option<u32>,
+    names: ::std::vec::Vec<Text>,
+    #[builder(skip_setter)]
+    fixed: u8,
+}
+
+impl Limits {
+    fn base() -> Self {
+        Limits {
+            max: ::core::option::Option::None,
+            names: ::std::vec::Vec::new(),
+            fixed: 1,
+        }
+    }
+}
+
+#[derive(TypeStateBuilder, ::core::fmt::Debug)]
+#[builder(module = "config_builder", hide_states)]
+pub struct Config<'a, T: ::core::clone::Clone> {
+    #[builder(required)]
+    pub name: &'a str,
+    #[builder(required, transparent)]
+    pub value: ::core::option::Option<T>,
+    pub items: List<T>,
+}
+
+pub struct Wrapped(pub usize);
+
+impl<'a, T: ::core::clone::Clone> ::core::convert::From<Config<'a, T>> for Wrapped {
+    fn from(config: Config<'a, T>) -> Self {
+        Wrapped(config.items.len())
+    }
+}
+
+#[derive(TypeStateBuilder)]
+#[builder(const)]
+struct Point {
+    #[builder(required)]
+    x: i32,
+    #[builder(default = 0)]
+    y: i32,
+}
+
+#[derive(TypeStateBuilder, ::core::fmt::Debug)]
+#[builder(lite, default_struct = "Window::standard()")]
+struct Window {
+    #[builder(required)]
+    title: Text,
+    width: u32,
+}
+
+impl Window {
+    fn standard() -> Self {
+        Window {
+            title: ::std::string::String::new(),
+            width: 800,
+        }
+    }
+}
+
+#[derive(TypeStateBuilder, ::core::fmt::Debug, ::core::clone::Clone)]
+#[builder(build_by = "clone")]
+struct Reusable {
+    #[builder(required)]
+    name: Text,
+    size: Maybe<u8>,
+}
+
+#[derive(TypeStateBuilder, ::core::fmt::Debug)]
+#[builder(all_required)]
+struct Pair {
+    #[builder(builder_method)]
+    left: u8,
+    right: u8,
+}
+
+#[type_state_builder]
+struct Request {
+    #[builder(required)]
+    path: Text,
+    #[builder(builder_only, default = 1)]
+    scale: u32,
+    #[builder(default_from = |b| b.scale * 10)]
+    size: u32,
+}
+
+#[derive(TypeStateBuilder, ::core::fmt::Debug)]
+enum Message {
+    Ping,
+    Data {
+        #[builder(required)]
+        payload: List<u8>,
+        flags: u8,
+    },
+}
+
+fn main() {
+    let builder = Server::builder()
+        .with_host("localhost")
+        .with_port(8080)
+        .with_tags(["a", "b"])
+        .with_label(::core::option::Option::Some(::std::string::String::from("x")))
+        .with_name_length("abc")
+        .with_retries(1)
+        .with_workers_with(|| 4)
+        .with_limits_with(|limits| limits.max(9).names(["n"]));
+    let _ = ::std::format!("{builder:?}");
+    let server = builder.checked().unwrap();
+    let _ = server.host_length;
+    let _ = Server::builder()
+        .with_host("localhost")
+        .try_with_port(8080u32);
+
+    let _ = Limits::builder().build();
+    let reusable = Reusable::builder().name(::std::string::String::new());
+    let _ = reusable.build();
+    let _ = reusable.build();
+    let _: Wrapped = Config::builder()
+        .name("c")
+        .value(::core::option::Option::Some(1u8))
+        .build_into();
+    const POINT: Point = Point::builder().x(1).build();
+    let _ = POINT.x;
+    let _ = Window::new(::std::string::String::from("main")).with_width(2);
+    let _ = Pair::left(1).right(2).build();
+    let _ = Request::builder().path(::std::string::String::new()).scale(2).build();
+    let _ = Message::builder().ping().build();
+    let _ = Message::builder().data().payload(::std::vec::Vec::new()).build();
+}
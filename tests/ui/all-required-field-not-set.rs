@@ -0,0 +1,14 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+#[builder(all_required)]
+struct Invoice {
+    number: u64,
+    customer: String,
+    #[builder(optional)]
+    notes: Option<String>,
+}
+
+fn main() {
+    let _invoice = Invoice::builder().number(1).build();
+}
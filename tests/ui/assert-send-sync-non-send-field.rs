@@ -0,0 +1,15 @@
+use std::rc::Rc;
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+#[builder(assert_send_sync)]
+struct Session {
+    #[builder(required)]
+    id: u64,
+
+    cache: Rc<Vec<u8>>,
+}
+
+fn main() {
+    let _session = Session::builder().id(1).build();
+}
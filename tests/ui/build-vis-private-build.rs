@@ -0,0 +1,27 @@
+mod accounts {
+    use type_state_builder::TypeStateBuilder;
+
+    fn check_account(_account: &Account) -> Result<(), String> {
+        Ok(())
+    }
+
+    #[derive(TypeStateBuilder)]
+    #[builder(
+        build_vis = "pub(self)",
+        finishers(open(validate = check_account, error = String))
+    )]
+    pub struct Account {
+        #[builder(required)]
+        pub owner: String,
+    }
+}
+
+fn main() {
+    // Only the validating finisher can build the struct outside its module
+    let _account = accounts::Account::builder()
+        .owner("Ada".to_string())
+        .build();
+    let _converted: accounts::Account = accounts::Account::builder()
+        .owner("Ada".to_string())
+        .into();
+}
@@ -0,0 +1,12 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+struct Greeting {
+    #[builder(required, builder_only)]
+    name: String,
+
+    #[builder(computed = |b| format!("Hello, {}!", b.name))]
+    text: String,
+}
+
+fn main() {}
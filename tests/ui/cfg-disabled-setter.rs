@@ -0,0 +1,17 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+struct Service {
+    #[builder(required)]
+    name: String,
+
+    #[builder(cfg(any()))]
+    metrics_port: u16,
+}
+
+fn main() {
+    let _service = Service::builder()
+        .name("api".to_string())
+        .metrics_port(9090)
+        .build();
+}
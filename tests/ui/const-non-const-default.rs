@@ -0,0 +1,12 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+#[builder(const)]
+struct Config {
+    #[builder(required)]
+    host: &'static str,
+    #[builder(default = String::from("guest"))]
+    user: String,
+}
+
+fn main() {}
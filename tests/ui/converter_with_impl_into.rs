@@ -6,4 +6,7 @@ struct TestStruct {
     name: String,
 }
 
-fn main() {}
+fn main() {
+    // The converter input must be reachable through `Into`
+    let _ = TestStruct::builder().name(42).build();
+}
@@ -0,0 +1,20 @@
+#![deny(deprecated)]
+
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+struct Config {
+    #[builder(required)]
+    name: String,
+
+    #[deprecated(since = "0.2.0", note = "use `name` instead")]
+    label: String,
+}
+
+fn main() {
+    // This should fail: the setter of a deprecated field is deprecated too
+    let _config = Config::builder()
+        .name("app".to_string())
+        .label("legacy".to_string())
+        .build();
+}
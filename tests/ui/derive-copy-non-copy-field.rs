@@ -0,0 +1,17 @@
+// With `derive(Copy, Clone)`, a builder state is only `Copy` when the values
+// it stores are, so a `String` field makes the builder move on use
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+#[builder(derive(Copy, Clone))]
+struct Message {
+    #[builder(required)]
+    id: u32,
+    text: String,
+}
+
+fn main() {
+    let builder = Message::builder().id(1);
+    let _first = builder.build();
+    let _second = builder.build();
+}
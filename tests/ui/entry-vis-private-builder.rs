@@ -0,0 +1,24 @@
+mod registry {
+    use type_state_builder::TypeStateBuilder;
+
+    #[derive(TypeStateBuilder)]
+    #[builder(entry_vis = "pub(self)")]
+    pub struct Handle {
+        #[builder(required)]
+        pub id: u32,
+    }
+
+    #[derive(TypeStateBuilder)]
+    #[builder(entry_vis = "pub(self)")]
+    pub struct Options {
+        pub workers: Option<usize>,
+    }
+}
+
+fn main() {
+    // Neither `builder()` nor the builder constructors are reachable here
+    let _handle = registry::Handle::builder();
+    let _start = registry::HandleBuilder_MissingId::new();
+    let _options = registry::OptionsBuilder::new();
+    let _default: registry::OptionsBuilder = Default::default();
+}
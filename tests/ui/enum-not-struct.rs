@@ -1,6 +1,6 @@
 use type_state_builder::TypeStateBuilder;
 
-// This should be an error: enums are not supported, only structs
+// This should be an error: enum variants need named fields or no fields
 #[derive(TypeStateBuilder)]
 enum NotAStruct {
     Variant1(String),
@@ -0,0 +1,15 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+pub enum Message {
+    Ping,
+    Data {
+        #[builder(required)]
+        payload: Vec<u8>,
+    },
+}
+
+fn main() {
+    // The variant has to be selected before setting fields or building
+    let _message = Message::builder().payload(vec![1]).build();
+}
@@ -0,0 +1,14 @@
+use type_state_builder::TypeStateBuilder;
+
+fn check(_: &Config) -> Result<(), String> {
+    Ok(())
+}
+
+#[derive(TypeStateBuilder)]
+#[builder(finishers(try_build(validate = check)))]
+struct Config {
+    #[builder(required)]
+    host: String,
+}
+
+fn main() {}
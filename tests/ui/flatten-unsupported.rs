@@ -0,0 +1,15 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+struct Address {
+    #[builder(required)]
+    city: String,
+}
+
+#[derive(TypeStateBuilder)]
+struct Customer {
+    #[builder(required, flatten)]
+    address: Address,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+#![deny(deprecated)]
+
+use type_state_builder::TypeStateBuilder;
+
+// This should fail: the impl_into lint warning is denied, and the setter
+// could not be called with a plain `8080` literal
+#[derive(TypeStateBuilder)]
+#[builder(impl_into)]
+struct Server {
+    #[builder(required)]
+    host: String,
+
+    port: u16,
+}
+
+fn main() {
+    let _server = Server::builder().host("localhost").port(8080u16).build();
+}
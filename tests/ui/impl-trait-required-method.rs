@@ -0,0 +1,15 @@
+use type_state_builder::TypeStateBuilder;
+
+pub trait Plugin {
+    fn name(&self) -> &'static str;
+}
+
+// The builder impls are empty, so every trait item needs a default
+#[derive(TypeStateBuilder)]
+#[builder(impl_trait = "Plugin")]
+struct Config {
+    #[builder(required)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+struct Post {
+    #[builder(required, into_iter)]
+    tags: String,
+}
+
+fn main() {}
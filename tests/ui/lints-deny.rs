@@ -0,0 +1,14 @@
+use type_state_builder::TypeStateBuilder;
+
+// This should fail: `lints = "deny"` turns builder lints into errors
+#[derive(TypeStateBuilder)]
+#[builder(lints = "deny")]
+struct Server {
+    #[builder(required)]
+    host: String,
+
+    #[builder(impl_into)]
+    port: u16,
+}
+
+fn main() {}
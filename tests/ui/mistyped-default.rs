@@ -0,0 +1,15 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+struct Server {
+    #[builder(required)]
+    host: String,
+    #[builder(required)]
+    name: String,
+
+    // The default is checked against the field type once, at the attribute
+    #[builder(default = vec![80])]
+    port: u16,
+}
+
+fn main() {}
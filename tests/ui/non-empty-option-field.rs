@@ -0,0 +1,11 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+struct Post {
+    #[builder(required)]
+    title: String,
+    #[builder(non_empty)]
+    tags: Option<Vec<String>>,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#![deny(deprecated)]
+
+use type_state_builder::TypeStateBuilder;
+
+// This should fail: the redundant converter warning is denied
+#[derive(TypeStateBuilder)]
+struct Document {
+    #[builder(required, converter = |title: String| title)]
+    title: String,
+}
+
+fn main() {
+    let _document = Document::builder().title("Notes".to_string()).build();
+}
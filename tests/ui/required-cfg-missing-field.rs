@@ -0,0 +1,18 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+struct Server {
+    #[builder(required)]
+    host: String,
+
+    // `all()` always holds, so the port is required
+    #[builder(required(cfg(all())), default = 8080)]
+    port: u16,
+}
+
+fn main() {
+    // This should fail: the port has no default while its predicate holds
+    let server = Server::builder()
+        .host("example.com".to_string())
+        .build();
+}
@@ -0,0 +1,12 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+struct Workspace {
+    #[builder(required)]
+    base_dir: String,
+
+    #[builder(required, resolve = |path: String, root: &String| format!("{root}/{path}"))]
+    config: String,
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+mod outer {
+    pub mod config {
+        use type_state_builder::TypeStateBuilder;
+
+        #[derive(TypeStateBuilder)]
+        #[builder(module = "settings_builder")]
+        pub(in crate::outer) struct Settings<T: Clone + Send + Sync> {
+            #[builder(required)]
+            pub(in crate::outer) value: T,
+        }
+    }
+
+    mod local {
+        use type_state_builder::TypeStateBuilder;
+
+        #[derive(TypeStateBuilder)]
+        #[builder(module = "local_builder")]
+        struct Local<T: Clone + Send + Sync> {
+            #[builder(required)]
+            value: T,
+        }
+    }
+
+    // The helper traits of a private struct stay private to its module
+    fn local_bounded<T: local::__LocalTBounds>() {}
+}
+
+// The builder types and helper traits are no more visible than the struct
+type Start = outer::config::settings_builder::SettingsBuilder_MissingValue<u8>;
+
+fn bounded<T: outer::config::__SettingsTBounds>() {}
+
+fn main() {}
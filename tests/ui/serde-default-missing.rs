@@ -0,0 +1,13 @@
+use type_state_builder::TypeStateBuilder;
+
+// This should fail: `default = serde` needs a serde default on the field
+#[derive(TypeStateBuilder)]
+struct Config {
+    #[builder(required)]
+    host: String,
+
+    #[builder(default = serde)]
+    port: u16,
+}
+
+fn main() {}
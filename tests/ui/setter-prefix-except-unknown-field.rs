@@ -0,0 +1,13 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+#[builder(setter_prefix = "with_", setter_prefix(except(id, nmae)))]
+struct Order {
+    #[builder(required, builder_method)]
+    id: u64,
+
+    // This should be an error: the exception misspells `name`
+    name: String,
+}
+
+fn main() {}
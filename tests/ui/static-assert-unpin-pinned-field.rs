@@ -0,0 +1,15 @@
+use std::marker::PhantomPinned;
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+#[builder(static_assert(Send, Unpin))]
+struct Task {
+    #[builder(required)]
+    id: u64,
+
+    pinned: PhantomPinned,
+}
+
+fn main() {
+    let _task = Task::builder().id(1).build();
+}
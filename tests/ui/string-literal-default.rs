@@ -0,0 +1,9 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+struct Config {
+    #[builder(default = "8080")]
+    port: u16,
+}
+
+fn main() {}
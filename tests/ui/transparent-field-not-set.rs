@@ -0,0 +1,15 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+struct Config {
+    #[builder(required)]
+    name: String,
+
+    #[builder(required, transparent)]
+    region: Option<String>,
+}
+
+fn main() {
+    // This should fail: a transparent field is still required, even though it may be None
+    let config = Config::builder().name("app".to_string()).build();
+}
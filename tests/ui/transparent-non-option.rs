@@ -0,0 +1,9 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(TypeStateBuilder)]
+struct Config {
+    #[builder(required, transparent)]
+    timeout: u64,
+}
+
+fn main() {}
@@ -2,7 +2,8 @@
 ///
 /// These tests verify that the macro properly rejects invalid code with appropriate
 /// compile-time error messages. They use the `trybuild` crate to test compilation
-/// failures and ensure error messages are helpful and accurate.
+/// failures and ensure error messages are helpful and accurate. The `ui-pass`
+/// tests must compile, e.g. with the prelude shadowed by user items.
 ///
 /// To run UI tests locally: `cargo test --features ui-tests`
 
@@ -11,4 +12,5 @@
 fn ui_tests() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/ui/*.rs");
+    t.pass("tests/ui-pass/*.rs");
 }
@@ -12,8 +12,8 @@
 //! | `setter_prefix` | `required`, `setter_name`, `impl_into`, `converter`, `default` | `skip_setter` |
 //! | `default` | `setter_name`, `setter_prefix`, `impl_into`, `converter`, `skip_setter` | `required` |
 //! | `skip_setter` | `default` | `required`, `setter_name`, `setter_prefix`, `impl_into`, `converter` |
-//! | `impl_into` | `required`, `setter_name`, `setter_prefix`, `default` | `skip_setter`, `converter` |
-//! | `converter` | `required`, `setter_name`, `setter_prefix`, `default` | `skip_setter`, `impl_into` |
+//! | `impl_into` | `required`, `setter_name`, `setter_prefix`, `default`, `converter` | `skip_setter` |
+//! | `converter` | `required`, `setter_name`, `setter_prefix`, `default`, `impl_into` | `skip_setter` |
 
 use type_state_builder::TypeStateBuilder;
 
@@ -154,8 +154,18 @@ mod field_level_attributes {
     #[derive(TypeStateBuilder, Debug, PartialEq)]
     struct SkipSetterWithoutDefault {
         name: String,
-        #[builder(skip_setter, default = Default::default())]
+        #[builder(skip_setter)]
         count: i32, // Default::default() = 0
+        #[builder(skip_setter)]
+        tags: Vec<String>,
+    }
+
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    struct SkipSetterWithoutDefaultRequired {
+        #[builder(required)]
+        name: String,
+        #[builder(skip_setter)]
+        count: i32,
     }
 
     #[test]
@@ -165,6 +175,12 @@ mod field_level_attributes {
             .build();
         assert_eq!(instance.name, "test");
         assert_eq!(instance.count, 0);
+        assert!(instance.tags.is_empty());
+
+        let instance = SkipSetterWithoutDefaultRequired::builder()
+            .name("test".to_string())
+            .build();
+        assert_eq!(instance.count, 0);
     }
 
     // Test 10: impl_into field-level (overriding struct setting)
@@ -952,7 +968,7 @@ mod documentation_examples {
         #[builder(required, setter_name = "host")]
         hostname: String,
 
-        #[builder(required)]
+        #[builder(required, impl_into = false)]
         port: u16,
 
         #[builder(converter = |env: &str| matches!(env.to_lowercase().as_str(), "true" | "1" | "yes" | "on"))]
@@ -961,7 +977,7 @@ mod documentation_examples {
         #[builder(converter = |paths: Vec<&str>| paths.into_iter().map(std::path::PathBuf::from).collect(), default = vec![])]
         cert_paths: Vec<std::path::PathBuf>,
 
-        #[builder(default = 1)]
+        #[builder(default = 1, impl_into = false)]
         worker_threads: usize,
 
         #[builder(skip_setter, default = String::from("server-v1.0"))]
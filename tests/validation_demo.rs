@@ -12,15 +12,6 @@ struct RequiredWithDefault {
 }
 */
 
-// This would fail with: Fields with skip_setter must have a default value
-/*
-#[derive(TypeStateBuilder)]
-struct SkipSetterWithoutDefault {
-    #[builder(skip_setter)]
-    name: String,
-}
-*/
-
 // This would fail with: Required fields cannot skip setters
 /*
 #[derive(TypeStateBuilder)]
@@ -42,6 +33,9 @@ struct ValidConfig {
     #[builder(skip_setter, default = String::new())]
     id: String,
 
+    #[builder(skip_setter)]
+    revision: u32,
+
     optional: Option<String>,
 }
 
@@ -56,6 +50,7 @@ fn test_valid_config_works() {
     assert_eq!(instance.name, "test");
     assert_eq!(instance.count, 100);
     assert_eq!(instance.id, ""); // String::new()
+    assert_eq!(instance.revision, 0); // Default::default()
     assert_eq!(instance.optional, Some("optional".to_string()));
 }
 
@@ -64,8 +59,7 @@ fn test_validation_catches_invalid_configs() {
     // This test documents that the following configurations are now properly rejected:
     println!("✅ Validation now catches:");
     println!("  1. required + default combination");
-    println!("  2. skip_setter without default");
-    println!("  3. required + skip_setter + default combination");
+    println!("  2. required + skip_setter + default combination");
 
     // The commented out structs above would fail compilation due to validation
     // This demonstrates that the error handling has been fixed
@@ -37,6 +37,63 @@ mod test_module {
     }
 }
 
+// Test 5: Restricted paths are reproduced exactly, with and without a builder module
+mod outer {
+    pub mod config {
+        use type_state_builder::TypeStateBuilder;
+
+        #[derive(TypeStateBuilder)]
+        pub(in crate::outer) struct PathConfig {
+            #[builder(required)]
+            pub(in crate::outer) name: String,
+        }
+
+        #[derive(TypeStateBuilder)]
+        #[builder(module = "path_module_builder")]
+        pub(in crate::outer) struct PathModuleConfig<T: Clone + Send + Sync> {
+            #[builder(required)]
+            pub(in crate::outer) value: T,
+        }
+
+        #[derive(TypeStateBuilder)]
+        #[builder(module = "self_builder", hide_states, state_traits)]
+        #[allow(clippy::needless_pub_self)]
+        pub(self) struct SelfConfig {
+            #[builder(required)]
+            name: String,
+        }
+
+        #[derive(TypeStateBuilder)]
+        #[builder(module = "super_builder")]
+        pub(super) struct SuperPathConfig {
+            #[builder(required)]
+            pub(super) name: String,
+        }
+
+        pub(in crate::outer) fn self_config_name() -> String {
+            SelfConfig::builder().name("self".to_string()).build().name
+        }
+    }
+
+    pub fn build_all() -> Vec<String> {
+        let path = config::PathConfig::builder()
+            .name("path".to_string())
+            .build();
+        let module = config::PathModuleConfig::builder()
+            .value("module".to_string())
+            .build();
+        let parent = config::SuperPathConfig::builder()
+            .name("super".to_string())
+            .build();
+        vec![
+            path.name,
+            module.value,
+            config::self_config_name(),
+            parent.name,
+        ]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,4 +174,9 @@ mod tests {
         assert_eq!(config.value, Some(42));
         assert_eq!(config.name, Some("test".to_string()));
     }
+
+    #[test]
+    fn test_restricted_path_visibility() {
+        assert_eq!(outer::build_all(), ["path", "module", "self", "super"]);
+    }
 }
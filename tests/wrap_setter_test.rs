@@ -0,0 +1,87 @@
+use type_state_builder::TypeStateBuilder;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(transparent)]
+pub struct UserId(u64);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Email(String);
+
+impl Email {
+    fn parse(address: &str) -> Self {
+        Email(address.trim().to_lowercase())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tagged<T>(T);
+
+// =============================================================================
+// Setters taking the inner type
+// =============================================================================
+
+#[derive(TypeStateBuilder, Debug, PartialEq)]
+pub struct Account {
+    #[builder(required, wrap = u64)]
+    id: UserId,
+    #[builder(required, wrap(&str, with = Email::parse))]
+    email: Email,
+    #[builder(wrap = u64)]
+    referrer: Option<UserId>,
+    #[builder(wrap = u8, default = Tagged(1))]
+    level: Tagged<u8>,
+}
+
+#[test]
+fn test_setters_wrap_their_argument() {
+    let account = Account::builder()
+        .id(5)
+        .email(" Ada@Example.com ")
+        .referrer(2)
+        .build();
+
+    assert_eq!(
+        account,
+        Account {
+            id: UserId(5),
+            email: Email("ada@example.com".to_string()),
+            referrer: Some(UserId(2)),
+            level: Tagged(1),
+        }
+    );
+}
+
+#[test]
+fn test_generic_newtype_uses_turbofish_constructor() {
+    let account = Account::builder().email("a@b.c").id(1).level(9).build();
+    assert_eq!((account.level, account.referrer), (Tagged(9), None));
+}
+
+// =============================================================================
+// Other builder shapes
+// =============================================================================
+
+#[test]
+fn test_wrap_with_impl_into() {
+    #[derive(TypeStateBuilder)]
+    struct Session {
+        #[builder(required, wrap = u64, impl_into)]
+        user: UserId,
+    }
+
+    let session = Session::builder().user(7u32).build();
+    assert_eq!(session.user, UserId(7));
+}
+
+#[test]
+fn test_wrap_on_const_builder() {
+    #[derive(TypeStateBuilder, Debug, PartialEq)]
+    #[builder(const)]
+    struct Key {
+        #[builder(required, wrap = u64)]
+        owner: UserId,
+    }
+
+    const KEY: Key = Key::builder().owner(3).build();
+    assert_eq!(KEY, Key { owner: UserId(3) });
+}